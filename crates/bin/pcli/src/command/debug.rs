@@ -1,4 +1,5 @@
-use anyhow::Result;
+use anyhow::{Context as _, Result};
+use penumbra_custody::audit::{self, EntryHash};
 use serde::Serialize;
 use std::path::PathBuf;
 use std::process::Command;
@@ -7,6 +8,15 @@ use std::process::Command;
 pub enum DebugCmd {
     /// Emit debugging info, useful for requesting support
     Info,
+    /// Verify the custody audit log, which records every transaction authorization decision.
+    ///
+    /// This detects entries that were modified, reordered or removed.  Removal of the latest
+    /// entries is only detected if the head hash printed by an earlier run is provided.
+    VerifyAuditLog {
+        /// The head hash printed by an earlier verification, which must still be in the log.
+        #[clap(long)]
+        head: Option<EntryHash>,
+    },
 }
 
 impl DebugCmd {
@@ -24,6 +34,19 @@ impl DebugCmd {
                 println!("{d}");
                 Ok(())
             }
+            DebugCmd::VerifyAuditLog { head } => {
+                let path = data_dir.join(crate::AUDIT_LOG_FILE_NAME);
+                let entries =
+                    audit::verify(&path, *head).context("custody audit log failed verification")?;
+                let latest = entries.last().map(|entry| entry.hash).unwrap_or_default();
+                println!(
+                    "Verified {} entries in the audit log at {}",
+                    entries.len(),
+                    path.display()
+                );
+                println!("Head hash: {latest}");
+                Ok(())
+            }
         }
     }
 }
@@ -5,13 +5,20 @@ use std::{
 
 use anyhow::Result;
 use camino::Utf8PathBuf;
-use penumbra_custody::threshold;
-use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+use penumbra_custody::threshold::{self, network::SessionCode};
+use penumbra_keys::{
+    keys::{Bip44Path, SeedPhrase, SpendKey},
+    FullViewingKey,
+};
+use penumbra_proto::custody::v1::{
+    custody_service_client::CustodyServiceClient, ExportFullViewingKeyRequest,
+};
 use rand_core::OsRng;
 use termion::screen::IntoAlternateScreen;
 use url::Url;
 
 use crate::{
+    command::threshold::peer_terminal,
     config::{CustodyConfig, GovernanceCustodyConfig, PcliConfig},
     terminal::ActualTerminal,
 };
@@ -36,6 +43,18 @@ pub struct InitCmd {
     /// This has no effect on a view only service.
     #[clap(long, action)]
     encrypted: bool,
+    /// Encrypt the local view database, with a password asked for whenever it's opened.
+    ///
+    /// This needs pcli to be built with the `sqlcipher` feature.
+    #[clap(long, action)]
+    encrypt_view: bool,
+    /// The URL of a note detection service to synchronize the local view service through.
+    ///
+    /// The detection service only sends the notes which might belong to the wallet, so syncing
+    /// is faster, but notes sent to one-time addresses, or to accounts after the first 16, are
+    /// missed, so pcli refuses to generate those addresses.
+    #[clap(long, parse(try_from_str = Url::parse))]
+    detection_url: Option<Url>,
 }
 
 #[derive(Debug, Clone, clap::Subcommand)]
@@ -65,6 +84,15 @@ pub enum InitSubCmd {
     /// Initialize using a manual threshold signing backend.
     #[clap(subcommand, display_order = 150)]
     Threshold(ThresholdInitCmd),
+    /// Initialize using a remote custody service, such as `pclientd` in custody mode.
+    ///
+    /// The full viewing key is fetched from the remote custody service.
+    #[clap(display_order = 175)]
+    Remote {
+        /// The URL of the remote custody service's gRPC endpoint.
+        #[clap(parse(try_from_str = Url::parse))]
+        url: Url,
+    },
     // This is not accessible directly by the user, because it's impermissible to initialize the
     // governance subkey as view-only.
     #[clap(skip, display_order = 200)]
@@ -182,6 +210,36 @@ pub enum ThresholdInitCmd {
         /// The maximum number of signers that can make a signature
         #[clap(short, long)]
         num_participants: u16,
+        /// The URL of a coordinator server to exchange the DKG messages through.
+        ///
+        /// If this is not set, messages are copied and pasted between participants.
+        #[clap(long)]
+        coordinator: Option<Url>,
+        /// The session code shared by the participant who started the DKG.
+        ///
+        /// If a coordinator is used without a session code, a new code is printed,
+        /// which should be shared with the other participants.
+        #[clap(long, requires = "coordinator")]
+        session: Option<SessionCode>,
+    },
+    /// Join a threshold wallet, receiving a share of its key when its signers reshare it.
+    ///
+    /// The current signers take part by running `pcli threshold reshare`.
+    Reshare {
+        /// The minimum number of signers required to make a signature (>= 2).
+        #[clap(short, long)]
+        threshold: u16,
+        /// The number of signers taking part, including any who are joining or leaving.
+        #[clap(short, long)]
+        num_participants: u16,
+        /// The URL of a coordinator server to exchange the messages through.
+        ///
+        /// If this is not set, messages are copied and pasted between signers.
+        #[clap(long)]
+        coordinator: Option<Url>,
+        /// The session code shared by the signer who started resharing.
+        #[clap(long, requires = "coordinator")]
+        session: Option<SessionCode>,
     },
 }
 
@@ -230,7 +288,10 @@ fn exec_deal(
                 full_viewing_key,
                 grpc_url: grpc_url.clone(),
                 view_url: None,
+                coordinator_url: None,
+                detection_url: None,
                 disable_warning: false,
+                encrypt_view: false,
                 governance_custody: None,
             }
         } else {
@@ -252,6 +313,18 @@ fn exec_deal(
     Ok(())
 }
 
+/// Fetch the full viewing key from a remote custody service.
+async fn fetch_remote_fvk(url: &Url) -> Result<FullViewingKey> {
+    let channel = crate::network::endpoint(url)?.connect().await?;
+    CustodyServiceClient::new(channel)
+        .export_full_viewing_key(ExportFullViewingKeyRequest {})
+        .await?
+        .into_inner()
+        .full_viewing_key
+        .ok_or_else(|| anyhow::anyhow!("remote custody service did not return a full viewing key"))?
+        .try_into()
+}
+
 /// Which kind of initialization are we doing?
 #[derive(Clone, Debug, Copy)]
 enum InitType {
@@ -321,15 +394,36 @@ impl InitCmd {
             }
             (
                 _,
-                InitSubCmd::Threshold(ThresholdInitCmd::Dkg {
-                    threshold,
-                    num_participants,
-                }),
+                InitSubCmd::Threshold(
+                    cmd @ (ThresholdInitCmd::Dkg { .. } | ThresholdInitCmd::Reshare { .. }),
+                ),
                 false,
             ) => {
-                let config =
-                    threshold::dkg(*threshold, *num_participants, &ActualTerminal::default())
-                        .await?;
+                let config = match cmd {
+                    ThresholdInitCmd::Dkg {
+                        threshold,
+                        num_participants,
+                        coordinator,
+                        session,
+                    } => {
+                        let terminal = peer_terminal(coordinator.as_ref(), *session).await?;
+                        threshold::dkg(*threshold, *num_participants, &terminal).await?
+                    }
+                    ThresholdInitCmd::Reshare {
+                        threshold,
+                        num_participants,
+                        coordinator,
+                        session,
+                    } => {
+                        let terminal = peer_terminal(coordinator.as_ref(), *session).await?;
+                        threshold::reshare(None, *threshold, *num_participants, true, &terminal)
+                            .await?
+                            .ok_or_else(|| anyhow::anyhow!("resharing did not produce a share"))?
+                    }
+                    ThresholdInitCmd::Deal { .. } => {
+                        unreachable!("this should already have been handled above")
+                    }
+                };
                 let fvk = config.fvk().clone();
                 let custody_config = if self.encrypted {
                     let password = ActualTerminal::get_confirmed_password().await?;
@@ -342,6 +436,18 @@ impl InitCmd {
                 };
                 (fvk, custody_config)
             }
+            (InitType::SpendKey, InitSubCmd::Remote { url }, false) => {
+                let full_viewing_key = fetch_remote_fvk(url).await?;
+                println!(
+                    "Using remote custody service at {} with wallet ID {}",
+                    url,
+                    full_viewing_key.wallet_id()
+                );
+                (full_viewing_key, CustodyConfig::Remote { url: url.clone() })
+            }
+            (InitType::GovernanceKey, InitSubCmd::Remote { .. }, false) => {
+                anyhow::bail!("governance keys can't be initialized with a remote custody backend");
+            }
             (_, InitSubCmd::Threshold(ThresholdInitCmd::Deal { .. }), _) => {
                 unreachable!("this should already have been handled above")
             }
@@ -371,6 +477,8 @@ impl InitCmd {
                 let custody = match custody {
                     x @ CustodyConfig::ViewOnly => x,
                     x @ CustodyConfig::Encrypted(_) => x,
+                    // The keys of a remote custody service are managed by that service.
+                    x @ CustodyConfig::Remote { .. } => x,
                     CustodyConfig::SoftKms(spend_key) => {
                         let password = ActualTerminal::get_confirmed_password().await?;
                         CustodyConfig::Encrypted(penumbra_custody::encrypted::Config::create(
@@ -411,7 +519,10 @@ impl InitCmd {
                 full_viewing_key,
                 grpc_url: self.grpc_url.clone(),
                 view_url: None,
+                coordinator_url: None,
+                detection_url: self.detection_url.clone(),
                 disable_warning: false,
+                encrypt_view: self.encrypt_view,
                 governance_custody: None,
             }
         } else {
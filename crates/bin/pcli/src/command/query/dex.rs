@@ -56,6 +56,9 @@ pub enum DexCmd {
         /// The height to query for the swap execution.
         #[clap(long)]
         height: u64,
+        /// The denomination the arbitrage was performed with, if not the staking token.
+        #[clap(long)]
+        token: Option<String>,
     },
     /// Display information about all liquidity positions known to the chain.
     #[clap(display_order(900))]
@@ -133,11 +136,17 @@ impl DexCmd {
             .context("cannot parse batch swap output data")
     }
 
-    pub async fn get_arb_execution(&self, app: &mut App, height: &u64) -> Result<SwapExecution> {
+    pub async fn get_arb_execution(
+        &self,
+        app: &mut App,
+        height: &u64,
+        arb_token: Option<asset::Id>,
+    ) -> Result<SwapExecution> {
         let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
         client
             .arb_execution(ArbExecutionRequest {
                 height: *height,
+                arb_token: arb_token.map(Into::into),
                 ..Default::default()
             })
             .await?
@@ -382,8 +391,11 @@ impl DexCmd {
 
                 self.print_swap_execution(app, &swap_execution).await?;
             }
-            DexCmd::ArbExecution { height } => {
-                let swap_execution = self.get_arb_execution(app, height).await?;
+            DexCmd::ArbExecution { height, token } => {
+                let arb_token = token
+                    .as_ref()
+                    .map(|token| asset::REGISTRY.parse_unit(token.as_str()).base().id());
+                let swap_execution = self.get_arb_execution(app, height, arb_token).await?;
 
                 self.print_swap_execution(app, &swap_execution).await?;
             }
@@ -1,23 +1,175 @@
-use anyhow::Result;
-use penumbra_custody::threshold::Terminal;
+use std::net::SocketAddr;
+
+use anyhow::{anyhow, Context, Result};
+use camino::Utf8Path;
+use penumbra_custody::{
+    encrypted::{self, InnerConfig},
+    threshold::{
+        network::{CoordinatorServer, NetworkTerminal, SessionCode},
+        Terminal,
+    },
+};
+use penumbra_proto::custody::threshold::v1::{
+    coordinator_service_client::CoordinatorServiceClient,
+    coordinator_service_server::CoordinatorServiceServer,
+};
+use rand_core::OsRng;
+use url::Url;
 
 use crate::{
-    config::{CustodyConfig, GovernanceCustodyConfig},
-    terminal::ActualTerminal,
+    config::{CustodyConfig, GovernanceCustodyConfig, PcliConfig},
+    terminal::{ActualTerminal, CoordinatorTerminal},
     App,
 };
 
 #[derive(Debug, clap::Subcommand)]
 pub enum ThresholdCmd {
     /// Contribute to signing a transaction with threshold custody
-    Sign,
+    Sign {
+        /// The URL of a coordinator server to read the signing messages from.
+        ///
+        /// If this is not set, messages are copied and pasted between signers.
+        #[clap(long, requires = "session")]
+        coordinator: Option<Url>,
+        /// The session code shared by the signer coordinating the transaction.
+        #[clap(long, requires = "coordinator")]
+        session: Option<SessionCode>,
+    },
+    /// Run a coordinator server, relaying messages between threshold signers.
+    ///
+    /// The server never sees the contents of the messages, which are encrypted
+    /// under a session code that the signers share among themselves, and each
+    /// message is signed by its sender's key.
+    Coordinator {
+        /// The address to bind the coordinator's gRPC server to.
+        #[clap(long, default_value = "127.0.0.1:8083")]
+        bind: SocketAddr,
+    },
+    /// Reshare this wallet's threshold key, to change the signers or the threshold.
+    ///
+    /// Current signers run this command, while new signers run `pcli init threshold reshare`.
+    /// The full viewing key, and so every address, stays the same. Resharing between the same
+    /// signers, with the same threshold, refreshes everyone's share.
+    Reshare {
+        /// The minimum number of signers required to make a signature with the new shares (>= 2).
+        #[clap(short, long)]
+        threshold: u16,
+        /// The number of signers taking part, including any who are joining or leaving.
+        #[clap(short, long)]
+        num_participants: u16,
+        /// Deal out this signer's share without receiving a new one, making this wallet view-only.
+        #[clap(long)]
+        leave: bool,
+        /// The URL of a coordinator server to exchange the messages through.
+        ///
+        /// If this is not set, messages are copied and pasted between signers.
+        #[clap(long)]
+        coordinator: Option<Url>,
+        /// The session code shared by the signer who started resharing.
+        ///
+        /// If a coordinator is used without a session code, a new code is printed,
+        /// which should be shared with the other signers.
+        #[clap(long, requires = "coordinator")]
+        session: Option<SessionCode>,
+    },
+}
+
+/// The terminal to use for protocols where every participant reads everyone's messages.
+///
+/// With a coordinator, messages are relayed in the session with the given code, or in a new
+/// session, whose code is printed so that it can be shared with the other participants.
+pub(crate) async fn peer_terminal(
+    coordinator: Option<&Url>,
+    session: Option<SessionCode>,
+) -> Result<CoordinatorTerminal> {
+    let coordinator = match coordinator {
+        Some(coordinator) => coordinator,
+        None => return Ok(CoordinatorTerminal::Manual(ActualTerminal::default())),
+    };
+    let client =
+        CoordinatorServiceClient::new(crate::network::endpoint(coordinator)?.connect().await?);
+    let terminal = match session {
+        Some(session) => NetworkTerminal::join(ActualTerminal::default(), client, session).await?,
+        None => {
+            let session = SessionCode::generate(&mut OsRng);
+            let terminal =
+                NetworkTerminal::create(ActualTerminal::default(), client, session).await?;
+            println!("Share this session code with the other participants: {session}");
+            terminal
+        }
+    };
+    Ok(CoordinatorTerminal::Network(terminal))
 }
 
 impl ThresholdCmd {
     pub fn offline(&self) -> bool {
         match self {
-            ThresholdCmd::Sign => true,
+            ThresholdCmd::Sign { .. } => true,
+            ThresholdCmd::Coordinator { .. } => true,
+            ThresholdCmd::Reshare { .. } => true,
+        }
+    }
+
+    /// Run a coordinator server, which doesn't need any of the wallet's configuration.
+    pub async fn exec_coordinator(bind: SocketAddr) -> Result<()> {
+        println!("running threshold coordinator on {}", bind);
+        tonic::transport::Server::builder()
+            .add_service(CoordinatorServiceServer::new(CoordinatorServer::new()))
+            .serve(bind)
+            .await
+            .context("coordinator server failed")
+    }
+
+    /// Reshare the key in the config in this home directory, replacing it with the new share.
+    pub async fn exec_reshare(&self, home_dir: &Utf8Path) -> Result<()> {
+        let (threshold, num_participants, leave, coordinator, session) = match self {
+            ThresholdCmd::Reshare {
+                threshold,
+                num_participants,
+                leave,
+                coordinator,
+                session,
+            } => (*threshold, *num_participants, *leave, coordinator, *session),
+            _ => unreachable!("only the reshare command is executed here"),
+        };
+        let config_path = home_dir.join(crate::CONFIG_FILE_NAME);
+        let mut config = PcliConfig::load(&config_path)?;
+        let (current, password) = match config.custody.clone() {
+            CustodyConfig::Threshold(current) => (current, None),
+            CustodyConfig::Encrypted(current) => {
+                let password = ActualTerminal::default().get_password().await?;
+                let current = current
+                    .convert_to_threshold(&password)?
+                    .ok_or(anyhow!("cannot reshare a non-threshold custody backend"))?;
+                (current, Some(password))
+            }
+            _ => anyhow::bail!("cannot reshare a non-threshold custody backend"),
+        };
+
+        let terminal = peer_terminal(coordinator.as_ref(), session).await?;
+        let reshared = penumbra_custody::threshold::reshare(
+            Some(&current),
+            threshold,
+            num_participants,
+            !leave,
+            &terminal,
+        )
+        .await?;
+
+        config.custody = match (reshared, password) {
+            (Some(reshared), Some(password)) => CustodyConfig::Encrypted(
+                encrypted::Config::create(&password, InnerConfig::Threshold(reshared))?,
+            ),
+            (Some(reshared), None) => CustodyConfig::Threshold(reshared),
+            (None, _) => CustodyConfig::ViewOnly,
+        };
+        config.save(&config_path)?;
+        if leave {
+            println!("Dealt out this signer's share; this wallet is now view-only.");
+        } else {
+            println!("Reshared the key with a threshold of {threshold}.");
         }
+        Ok(())
     }
 
     #[tracing::instrument(skip(self, app))]
@@ -38,7 +190,31 @@ impl ThresholdCmd {
             _ => None,              // If not threshold, we can't sign using governance config
         };
         match self {
-            ThresholdCmd::Sign => {
+            ThresholdCmd::Sign {
+                coordinator: Some(coordinator),
+                session: Some(session),
+            } => {
+                let client = CoordinatorServiceClient::new(
+                    crate::network::endpoint(coordinator)?.connect().await?,
+                );
+                // Only the coordinator's messages are read, and they must come from a signer
+                // of one of our configs.
+                let configs: Vec<_> = config.iter().chain(governance_config.iter()).collect();
+                let terminal = NetworkTerminal::follower(
+                    ActualTerminal::default(),
+                    client,
+                    *session,
+                    &configs,
+                )
+                .await?;
+                penumbra_custody::threshold::follow(
+                    config.as_ref(),
+                    governance_config.as_ref(),
+                    &terminal,
+                )
+                .await
+            }
+            ThresholdCmd::Sign { .. } => {
                 penumbra_custody::threshold::follow(
                     config.as_ref(),
                     governance_config.as_ref(),
@@ -46,6 +222,9 @@ impl ThresholdCmd {
                 )
                 .await
             }
+            ThresholdCmd::Coordinator { .. } | ThresholdCmd::Reshare { .. } => {
+                unreachable!("command already executed")
+            }
         }
     }
 }
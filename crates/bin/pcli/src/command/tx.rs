@@ -30,7 +30,7 @@ use penumbra_asset::{asset, asset::Metadata, Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_dex::{lp::position, swap_claim::SwapClaimPlan};
 use penumbra_fee::FeeTier;
 use penumbra_governance::{proposal::ProposalToml, proposal_state::State as ProposalState, Vote};
-use penumbra_keys::{keys::AddressIndex, Address};
+use penumbra_keys::{keys::AddressIndex, Address, PaymentRequest};
 use penumbra_num::Amount;
 use penumbra_proto::{
     core::component::{
@@ -66,7 +66,7 @@ use penumbra_shielded_pool::Ics20Withdrawal;
 use penumbra_stake::rate::RateData;
 use penumbra_stake::{DelegationToken, IdentityKey, Penalty, UnbondingToken, UndelegateClaimPlan};
 use penumbra_transaction::{gas::swap_claim_gas_cost, Transaction};
-use penumbra_view::{SpendableNoteRecord, ViewClient};
+use penumbra_view::{SpendableNoteRecord, ViewClient, DEFAULT_MAX_SPENDS};
 use penumbra_wallet::plan::{self, Planner};
 use proposal::ProposalCmd;
 use tonic::transport::{Channel, ClientTlsConfig};
@@ -130,6 +130,21 @@ pub enum TxCmd {
         #[clap(short, long, default_value_t)]
         fee_tier: FeeTier,
     },
+    /// Pay a payment request, given as a `penumbra:` URI.
+    #[clap(display_order = 110)]
+    Pay {
+        /// The payment request URI, as created by `pcli view invoice create`.
+        uri: String,
+        /// The amounts to pay, written as typed values 1.87penumbra, 12cubes, etc. Only allowed
+        /// if the request doesn't say what to pay.
+        values: Vec<String>,
+        /// Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0", display_order = 300)]
+        source: u32,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, default_value_t)]
+        fee_tier: FeeTier,
+    },
     /// Deposit stake into a validator's delegation pool.
     #[clap(display_order = 200)]
     Delegate {
@@ -334,6 +349,7 @@ impl TxCmd {
     pub fn offline(&self) -> bool {
         match self {
             TxCmd::Send { .. } => false,
+            TxCmd::Pay { .. } => false,
             TxCmd::Sweep { .. } => false,
             TxCmd::Swap { .. } => false,
             TxCmd::Delegate { .. } => false,
@@ -392,17 +408,86 @@ impl TxCmd {
                 for value in values.iter().cloned() {
                     planner.output(value, to.clone());
                 }
-                let plan = planner
-                    .memo(memo.clone().unwrap_or_default())
-                    .plan(
+                planner.memo(memo.clone().unwrap_or_default());
+
+                // If the send takes too many notes for one transaction, some of them are first
+                // consolidated by transactions of their own.
+                let mut plans = planner.into_sequence(AddressIndex::new(*from), DEFAULT_MAX_SPENDS);
+                while let Some(plan) = plans
+                    .next_plan(
                         app.view
                             .as_mut()
                             .context("view service must be initialized")?,
-                        AddressIndex::new(*from),
                     )
                     .await
-                    .context("can't build send transaction")?;
-                app.build_and_submit_transaction(plan).await?;
+                    .context("can't build send transaction")?
+                {
+                    if !plans.is_finished() {
+                        if app.save_transaction_here_instead.is_some() {
+                            anyhow::bail!("sending these values takes more notes than fit in one transaction, so it can't be saved; sweep the source account and try again");
+                        }
+                        println!("consolidating notes, since sending takes more of them than fit in one transaction...");
+                    }
+                    app.build_and_submit_transaction(plan).await?;
+                }
+            }
+            TxCmd::Pay {
+                uri,
+                values,
+                source: from,
+                fee_tier,
+            } => {
+                let request = uri
+                    .parse::<PaymentRequest>()
+                    .context("invalid payment request")?;
+                let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+                if request.is_expired(now) {
+                    anyhow::bail!("the payment request has expired");
+                }
+                let values = match (request.values.is_empty(), values.is_empty()) {
+                    (false, true) => request.values.clone(),
+                    (true, false) => values
+                        .iter()
+                        .map(|v| v.parse())
+                        .collect::<Result<Vec<Value>, _>>()?,
+                    (false, false) => {
+                        anyhow::bail!("the payment request already says what to pay")
+                    }
+                    (true, true) => {
+                        anyhow::bail!(
+                            "the payment request doesn't say what to pay, so values must be given"
+                        )
+                    }
+                };
+
+                let mut planner = Planner::new(OsRng);
+
+                planner
+                    .set_gas_prices(gas_prices)
+                    .set_fee_tier((*fee_tier).into());
+                for value in values.iter().cloned() {
+                    planner.output(value, request.address.clone());
+                }
+                planner.memo(request.payment_memo().unwrap_or_default().to_string());
+
+                let mut plans = planner.into_sequence(AddressIndex::new(*from), DEFAULT_MAX_SPENDS);
+                while let Some(plan) = plans
+                    .next_plan(
+                        app.view
+                            .as_mut()
+                            .context("view service must be initialized")?,
+                    )
+                    .await
+                    .context("can't build payment transaction")?
+                {
+                    if !plans.is_finished() {
+                        if app.save_transaction_here_instead.is_some() {
+                            anyhow::bail!("paying these values takes more notes than fit in one transaction, so it can't be saved; sweep the source account and try again");
+                        }
+                        println!("consolidating notes, since paying takes more of them than fit in one transaction...");
+                    }
+                    app.build_and_submit_transaction(plan).await?;
+                }
             }
             TxCmd::CommunityPoolDeposit {
                 values,
@@ -431,6 +516,9 @@ impl TxCmd {
                     .await?;
                 app.build_and_submit_transaction(plan).await?;
             }
+            // Sweeping doesn't need a `PlanSequence`: each plan spends its own group of
+            // `SWEEP_COUNT` notes, well within a transaction's limits, and sweeping again until
+            // there's nothing left consolidates the notes that the sweep created.
             TxCmd::Sweep => loop {
                 let plans = plan::sweep(
                     app.view
@@ -464,6 +552,7 @@ impl TxCmd {
 
                 // If a source address was specified, use it for the swap, otherwise,
                 // use the default address.
+                app.config.check_detected(*source, false)?;
                 let (claim_address, _dtk_d) =
                     fvk.incoming().payment_address(AddressIndex::new(*source));
 
@@ -1022,10 +1111,15 @@ impl TxCmd {
             } => {
                 let destination_chain_address = to;
 
-                let (ephemeral_return_address, _) = app
-                    .config
-                    .full_viewing_key
-                    .ephemeral_address(OsRng, AddressIndex::from(*source));
+                // A one-time return address can't be detected, so refunds would be missed when
+                // synchronizing through a detection service.
+                let fvk = &app.config.full_viewing_key;
+                let (return_address, _) = if app.config.detection_url.is_some() {
+                    app.config.check_detected(*source, false)?;
+                    fvk.payment_address(AddressIndex::from(*source))
+                } else {
+                    fvk.ephemeral_address(OsRng, AddressIndex::from(*source))
+                };
 
                 let timeout_height = match timeout_height {
                     Some(h) => h.clone(),
@@ -1129,7 +1223,7 @@ impl TxCmd {
                     amount,
                     timeout_height,
                     timeout_time: timeout_timestamp,
-                    return_address: ephemeral_return_address,
+                    return_address,
                     // TODO: impl From<u64> for ChannelId
                     source_channel: ChannelId::from_str(format!("channel-{}", channel).as_ref())?,
                     use_compat_address: *use_compat_address,
@@ -1374,6 +1468,7 @@ impl TxCmd {
 
                 let address = if let Ok(index) = index {
                     // address index provided
+                    app.config.check_detected(index, *ephemeral)?;
                     let (address, _dtk) = match ephemeral {
                         false => fvk.incoming().payment_address(index.into()),
                         true => fvk.incoming().ephemeral_address(OsRng, index.into()),
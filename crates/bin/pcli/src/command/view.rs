@@ -1,7 +1,11 @@
 use anyhow::Result;
+use penumbra_view::Storage;
 
 use address::AddressCmd;
+use address_book::AddressBookCmd;
 use balance::BalanceCmd;
+use export::ExportCmd;
+use invoice::InvoiceCmd;
 use lps::LiquidityPositionsCmd;
 use noble_address::NobleAddressCmd;
 use staked::StakedCmd;
@@ -9,13 +13,16 @@ use transaction_hashes::TransactionHashesCmd;
 use tx::TxCmd;
 use wallet_id::WalletIdCmd;
 
-use crate::App;
+use crate::{config::PcliConfig, opt::view_database_key, App};
 
 use self::auction::AuctionCmd;
 
 mod address;
+mod address_book;
 mod auction;
 mod balance;
+mod export;
+mod invoice;
 mod lps;
 mod noble_address;
 mod staked;
@@ -40,6 +47,10 @@ pub enum ViewCmd {
     Staked(StakedCmd),
     /// Deletes all scanned data and local state, while leaving keys untouched.
     Reset(Reset),
+    /// Rolls back the scanned data from a given block height onwards, and scans it again.
+    ///
+    /// This can recover a damaged view database without scanning the whole chain again.
+    Rescan(Rescan),
     /// Synchronizes the client, privately scanning the chain state.
     ///
     /// `pcli` syncs automatically prior to any action requiring chain state,
@@ -53,6 +64,19 @@ pub enum ViewCmd {
     /// View information about the liquidity positions you control.
     #[clap(visible_alias = "lps")]
     LiquidityPositions(LiquidityPositionsCmd),
+    /// Export a ledger of your transactions, with the cost basis of the assets you sent, for
+    /// accounting.
+    ///
+    /// Values are priced using the DEX batch swaps seen while syncing, so assets which were never
+    /// traded against the numeraire have no value or cost basis.
+    Export(ExportCmd),
+    /// Manage your address book: the labels of your contacts and of your own accounts, which are
+    /// shown in place of their addresses when displaying transactions.
+    #[clap(subcommand)]
+    AddressBook(AddressBookCmd),
+    /// Manage your invoices: payment requests you've sent, and the payments received for them.
+    #[clap(subcommand)]
+    Invoice(InvoiceCmd),
 }
 
 impl ViewCmd {
@@ -65,10 +89,14 @@ impl ViewCmd {
             ViewCmd::Balance(balance_cmd) => balance_cmd.offline(),
             ViewCmd::Staked(staked_cmd) => staked_cmd.offline(),
             ViewCmd::Reset(_) => true,
+            ViewCmd::Rescan(_) => true,
             ViewCmd::Sync => false,
             ViewCmd::ListTransactionHashes(transactions_cmd) => transactions_cmd.offline(),
             ViewCmd::Tx(tx_cmd) => tx_cmd.offline(),
             ViewCmd::LiquidityPositions(lps_cmd) => lps_cmd.offline(),
+            ViewCmd::Export(export_cmd) => export_cmd.offline(),
+            ViewCmd::AddressBook(address_book_cmd) => address_book_cmd.offline(),
+            ViewCmd::Invoice(invoice_cmd) => invoice_cmd.offline(),
         }
     }
 
@@ -99,11 +127,14 @@ impl ViewCmd {
             ViewCmd::Reset(_reset) => {
                 // The wallet has already been reset by a short-circuiting path.
             }
+            ViewCmd::Rescan(_rescan) => {
+                // The wallet has already been rolled back by a short-circuiting path.
+            }
             ViewCmd::Address(address_cmd) => {
-                address_cmd.exec(&full_viewing_key)?;
+                address_cmd.exec(&app.config)?;
             }
             ViewCmd::NobleAddress(noble_address_cmd) => {
-                noble_address_cmd.exec(&full_viewing_key)?;
+                noble_address_cmd.exec(&app.config)?;
             }
             ViewCmd::Balance(balance_cmd) => {
                 let view_client = app.view();
@@ -117,6 +148,18 @@ impl ViewCmd {
                     .await?;
             }
             ViewCmd::LiquidityPositions(cmd) => cmd.exec(app).await?,
+            ViewCmd::Export(export_cmd) => {
+                let view_client = app.view();
+                export_cmd.exec(view_client).await?;
+            }
+            ViewCmd::AddressBook(address_book_cmd) => {
+                let view_client = app.view();
+                address_book_cmd.exec(view_client).await?;
+            }
+            ViewCmd::Invoice(invoice_cmd) => {
+                let view_client = app.view();
+                invoice_cmd.exec(view_client).await?;
+            }
         }
 
         Ok(())
@@ -124,7 +167,12 @@ impl ViewCmd {
 }
 
 #[derive(Debug, clap::Parser)]
-pub struct Reset;
+pub struct Reset {
+    /// The height of the first block to scan after resetting, such as the height at which the
+    /// wallet was created, rather than scanning from genesis.
+    #[clap(long)]
+    pub birthday: Option<u64>,
+}
 
 impl Reset {
     pub fn exec(&self, data_path: impl AsRef<camino::Utf8Path>) -> Result<()> {
@@ -138,7 +186,7 @@ impl Reset {
                 "Expected view data at {} but found something that is not a file; refusing to delete it",
                 view_path
             );
-        } else {
+        } else if self.birthday.is_none() {
             anyhow::bail!(
                 "No view data exists at {}, so it cannot be deleted",
                 view_path
@@ -147,4 +195,70 @@ impl Reset {
 
         Ok(())
     }
+
+    /// Create a new view database which starts scanning at the birthday.
+    pub async fn set_birthday(
+        &self,
+        data_path: impl AsRef<camino::Utf8Path>,
+        config: &PcliConfig,
+    ) -> Result<()> {
+        let Some(birthday) = self.birthday else {
+            return Ok(());
+        };
+        if config.view_url.is_some() {
+            anyhow::bail!("pcli is configured to use a remote view service, whose birthday can't be set from here");
+        }
+        let view_path = data_path.as_ref().join(crate::VIEW_FILE_NAME);
+        let key = view_database_key(config, &view_path).await?;
+        let storage = Storage::load_or_initialize(
+            Some(&view_path),
+            key,
+            &config.full_viewing_key,
+            config.grpc_url.clone(),
+        )
+        .await?;
+        storage
+            .set_birthday(birthday, config.grpc_url.clone())
+            .await?;
+        println!("Created view data at {view_path}, which will be scanned from height {birthday}");
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct Rescan {
+    /// The height of the first block to scan again.
+    #[clap(long)]
+    pub from: u64,
+}
+
+impl Rescan {
+    pub async fn exec(
+        &self,
+        data_path: impl AsRef<camino::Utf8Path>,
+        config: &PcliConfig,
+    ) -> Result<()> {
+        if config.view_url.is_some() {
+            anyhow::bail!("pcli is configured to use a remote view service, whose data can't be rescanned from here");
+        }
+        let view_path = data_path.as_ref().join(crate::VIEW_FILE_NAME);
+        if !view_path.is_file() {
+            anyhow::bail!(
+                "No view data exists at {}, so it cannot be rescanned",
+                view_path
+            );
+        }
+
+        tracing::info!(from = self.from, "rolling back client state");
+        let key = view_database_key(config, &view_path).await?;
+        let storage = Storage::load(&view_path, key).await?;
+        storage.rescan_from(self.from).await?;
+        println!(
+            "Rolled back view data at {view_path} to height {}; it will be scanned again on the next sync",
+            self.from
+        );
+
+        Ok(())
+    }
 }
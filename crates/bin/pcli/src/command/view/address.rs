@@ -5,6 +5,8 @@ use std::str::FromStr;
 
 use penumbra_keys::{keys::AddressIndex, Address, FullViewingKey};
 
+use crate::config::PcliConfig;
+
 #[derive(Debug, clap::Parser)]
 pub struct AddressCmd {
     /// The address to provide information about
@@ -33,12 +35,14 @@ impl AddressCmd {
         true
     }
 
-    pub fn exec(&self, fvk: &FullViewingKey) -> Result<()> {
+    pub fn exec(&self, config: &PcliConfig) -> Result<()> {
+        let fvk = &config.full_viewing_key;
         let index: Result<u32, _> = self.address_or_index.parse();
 
         if let Ok(index) = index {
             //index provided
 
+            config.check_detected(index, self.ephemeral)?;
             let (address, _dtk) = match self.ephemeral {
                 false => fvk.incoming().payment_address(index.into()),
                 true => fvk.incoming().ephemeral_address(OsRng, index.into()),
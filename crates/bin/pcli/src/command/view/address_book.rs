@@ -0,0 +1,95 @@
+use anyhow::{Context, Result};
+use comfy_table::{presets, Table};
+use penumbra_keys::Address;
+use penumbra_view::ViewClient;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum AddressBookCmd {
+    /// List your contacts and the labels of your accounts.
+    List,
+    /// Add a contact, or relabel an existing one.
+    Add {
+        /// The contact's address.
+        address: String,
+        /// The label to show for the contact.
+        label: String,
+    },
+    /// Remove a contact.
+    Remove {
+        /// The contact's address.
+        address: String,
+    },
+    /// Label one of your accounts, replacing any existing label.
+    Label {
+        /// The number of the account.
+        account: u32,
+        /// The label to show for the account.
+        label: String,
+    },
+    /// Remove the label of one of your accounts.
+    Unlabel {
+        /// The number of the account.
+        account: u32,
+    },
+}
+
+impl AddressBookCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec<V: ViewClient>(&self, view: &mut V) -> Result<()> {
+        match self {
+            AddressBookCmd::List => {
+                let address_book = view.address_book().await?;
+
+                let mut accounts = Table::new();
+                accounts.load_preset(presets::NOTHING);
+                accounts.set_header(vec!["Account", "Label"]);
+                for (account, label) in &address_book.accounts {
+                    accounts.add_row(vec![account.to_string(), label.clone()]);
+                }
+                println!("{accounts}\n");
+
+                let mut contacts = Table::new();
+                contacts.load_preset(presets::NOTHING);
+                contacts.set_header(vec!["Contact", "Address"]);
+                let mut rows: Vec<_> = address_book.contacts.iter().collect();
+                rows.sort_by(|(_, a), (_, b)| a.cmp(b));
+                for (address, label) in rows {
+                    contacts.add_row(vec![label.clone(), address.to_string()]);
+                }
+                println!("{contacts}");
+            }
+            AddressBookCmd::Add { address, label } => {
+                view.set_contact(parse_address(address)?, label.clone())
+                    .await?;
+                println!("Saved contact {label}");
+            }
+            AddressBookCmd::Remove { address } => {
+                if !view.delete_contact(parse_address(address)?).await? {
+                    anyhow::bail!("no contact has the address {address}");
+                }
+                println!("Removed contact");
+            }
+            AddressBookCmd::Label { account, label } => {
+                view.set_account_label(*account, label.clone()).await?;
+                println!("Labeled account {account} as {label}");
+            }
+            AddressBookCmd::Unlabel { account } => {
+                if !view.delete_account_label(*account).await? {
+                    anyhow::bail!("account {account} has no label");
+                }
+                println!("Removed the label of account {account}");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_address(address: &str) -> Result<Address> {
+    address
+        .parse()
+        .with_context(|| format!("invalid address {address}"))
+}
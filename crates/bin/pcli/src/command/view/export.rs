@@ -0,0 +1,93 @@
+use std::{fs::File, io::Write, path::PathBuf};
+
+use anyhow::{Context, Result};
+use penumbra_asset::asset;
+use penumbra_view::{CostBasisMethod, Ledger, LedgerRow, ViewClient};
+
+#[derive(Clone, clap::ValueEnum, Debug, Default)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+#[derive(Clone, clap::ValueEnum, Debug, Default)]
+pub enum CostBasis {
+    /// Amounts sent are taken from the oldest amounts received first.
+    #[default]
+    Fifo,
+    /// Amounts sent are taken from the newest amounts received first.
+    Lifo,
+}
+
+impl From<CostBasis> for CostBasisMethod {
+    fn from(cost_basis: CostBasis) -> Self {
+        match cost_basis {
+            CostBasis::Fifo => CostBasisMethod::Fifo,
+            CostBasis::Lifo => CostBasisMethod::Lifo,
+        }
+    }
+}
+
+#[derive(Debug, clap::Args)]
+pub struct ExportCmd {
+    /// The format of the exported ledger.
+    #[clap(long, value_enum, default_value_t)]
+    pub format: ExportFormat,
+    /// How the amounts of an asset sent are matched to the amounts received, to find their cost
+    /// basis.
+    #[clap(long, value_enum, default_value_t)]
+    pub cost_basis: CostBasis,
+    /// The asset to value the ledger in, using the prices of the DEX batch swaps against it.
+    #[clap(long, default_value = "penumbra")]
+    pub numeraire: String,
+    /// Only export transactions at or after this height.
+    ///
+    /// Earlier transactions are still used to find the cost basis of later ones.
+    #[clap(short, long)]
+    pub start_height: Option<u64>,
+    /// Only export transactions at or before this height.
+    #[clap(short, long)]
+    pub end_height: Option<u64>,
+    /// Write the ledger to this file, rather than to stdout.
+    #[clap(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+impl ExportCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec<V: ViewClient>(&self, view: &mut V) -> Result<()> {
+        let numeraire = asset::REGISTRY.parse_unit(&self.numeraire).base();
+        let asset_cache = view.assets().await?;
+
+        // The whole history is needed to find the cost basis, even if only part of it is shown.
+        let mut ledger =
+            Ledger::fetch(view, numeraire.id(), self.cost_basis.clone().into()).await?;
+        ledger.entries.retain(|entry| {
+            self.start_height
+                .map_or(true, |start| entry.height >= start)
+                && self.end_height.map_or(true, |end| entry.height <= end)
+        });
+        let rows = ledger.rows(&asset_cache, &numeraire);
+
+        let mut writer: Box<dyn Write> = match &self.output {
+            Some(path) => Box::new(
+                File::create(path).with_context(|| format!("cannot create file {path:?}"))?,
+            ),
+            None => Box::new(std::io::stdout()),
+        };
+        match self.format {
+            ExportFormat::Csv => LedgerRow::write_csv(&rows, &mut writer)?,
+            ExportFormat::Json => {
+                serde_json::to_writer_pretty(&mut writer, &rows)?;
+                writeln!(writer)?;
+            }
+        }
+        writer.flush()?;
+
+        Ok(())
+    }
+}
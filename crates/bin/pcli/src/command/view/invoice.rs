@@ -0,0 +1,138 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use comfy_table::{presets, Table};
+use penumbra_asset::{asset, Value};
+use penumbra_keys::keys::AddressIndex;
+use penumbra_proto::view::v1::CreateInvoiceRequest;
+use penumbra_view::ViewClient;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum InvoiceCmd {
+    /// Create an invoice, printing the payment request URI to send to the payer.
+    ///
+    /// Payments are recognized by the memo of the transaction which pays the invoice, or, with
+    /// `--dedicated-address`, by being sent to a one-time address used only for this invoice.
+    Create {
+        /// The amounts to request, written as typed values 1.87penumbra, 12cubes, etc. If none are
+        /// given, the payer chooses what to send.
+        values: Vec<String>,
+        /// The account to be paid into.
+        #[clap(long, default_value = "0")]
+        account: u32,
+        /// The memo the payment should have. Defaults to the invoice ID.
+        #[clap(long)]
+        memo: Option<String>,
+        /// How long the request can be paid for, such as "1h" or "7days".
+        #[clap(long)]
+        expires_in: Option<humantime::Duration>,
+        /// The ID of the invoice. A random one is used if it isn't given.
+        #[clap(long)]
+        id: Option<String>,
+        /// Request payment to a one-time address, rather than matching payments by memo.
+        ///
+        /// This can't be used when synchronizing through a detection service.
+        #[clap(long)]
+        dedicated_address: bool,
+    },
+    /// List your invoices which haven't been paid in full.
+    List {
+        /// Also list the invoices which have been paid in full.
+        #[clap(long)]
+        all: bool,
+    },
+    /// Remove an invoice.
+    Remove {
+        /// The ID of the invoice.
+        id: String,
+    },
+}
+
+impl InvoiceCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec<V: ViewClient>(&self, view: &mut V) -> Result<()> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+
+        match self {
+            InvoiceCmd::Create {
+                values,
+                account,
+                memo,
+                expires_in,
+                id,
+                dedicated_address,
+            } => {
+                let values = values
+                    .iter()
+                    .map(|v| v.parse())
+                    .collect::<Result<Vec<Value>, _>>()?;
+                let request = view
+                    .create_invoice(CreateInvoiceRequest {
+                        account: Some(AddressIndex::new(*account).into()),
+                        values: values.into_iter().map(Into::into).collect(),
+                        memo: memo.clone().unwrap_or_default(),
+                        expiry: expires_in
+                            .as_ref()
+                            .map(|expires_in| now + expires_in.as_secs())
+                            .unwrap_or_default(),
+                        invoice_id: id.clone().unwrap_or_default(),
+                        dedicated_address: *dedicated_address,
+                    })
+                    .await?;
+                println!("{request}");
+            }
+            InvoiceCmd::List { all } => {
+                let asset_cache = view.assets().await?;
+                let invoices = view.invoices(*all).await?;
+
+                let mut table = Table::new();
+                table.load_preset(presets::NOTHING);
+                table.set_header(vec!["ID", "Status", "Requested", "Received", "URI"]);
+                for invoice in invoices {
+                    let status = if invoice.is_paid() {
+                        "paid"
+                    } else if invoice.request.is_expired(now) {
+                        "expired"
+                    } else {
+                        "unpaid"
+                    };
+                    let requested =
+                        format_values(invoice.request.values.iter().copied(), &asset_cache);
+                    let received = format_values(
+                        invoice
+                            .received()
+                            .into_iter()
+                            .map(|(asset_id, amount)| Value { amount, asset_id }),
+                        &asset_cache,
+                    );
+                    table.add_row(vec![
+                        invoice.request.invoice_id.clone().unwrap_or_default(),
+                        status.to_string(),
+                        requested,
+                        received,
+                        invoice.request.to_uri(),
+                    ]);
+                }
+                println!("{table}");
+            }
+            InvoiceCmd::Remove { id } => {
+                if !view.delete_invoice(id.clone()).await? {
+                    anyhow::bail!("no invoice has the ID {id}");
+                }
+                println!("Removed invoice {id}");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn format_values(values: impl Iterator<Item = Value>, asset_cache: &asset::Cache) -> String {
+    values
+        .map(|value| value.format(asset_cache))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -1,7 +1,9 @@
 use anyhow::Result;
 use rand_core::OsRng;
 
-use penumbra_keys::{Address, FullViewingKey};
+use penumbra_keys::Address;
+
+use crate::config::PcliConfig;
 
 #[derive(Debug, clap::Parser)]
 pub struct NobleAddressCmd {
@@ -22,11 +24,13 @@ impl NobleAddressCmd {
         true
     }
 
-    pub fn exec(&self, fvk: &FullViewingKey) -> Result<()> {
+    pub fn exec(&self, config: &PcliConfig) -> Result<()> {
+        let fvk = &config.full_viewing_key;
         let index: Result<u32, _> = self.address_or_index.parse();
 
         let address = if let Ok(index) = index {
             // address index provided
+            config.check_detected(index, self.ephemeral)?;
             let (address, _dtk) = match self.ephemeral {
                 false => fvk.incoming().payment_address(index.into()),
                 true => fvk.incoming().ephemeral_address(OsRng, index.into()),
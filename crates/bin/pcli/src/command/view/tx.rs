@@ -67,7 +67,8 @@ impl TxCmd {
             );
         } else {
             use crate::transaction_view_ext::TransactionViewExt;
-            tx_info.view.render_terminal();
+            let address_book = app.view().address_book().await?;
+            tx_info.view.render_terminal(&address_book);
         }
 
         Ok(())
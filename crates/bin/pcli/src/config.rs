@@ -20,9 +20,18 @@ pub struct PcliConfig {
     pub grpc_url: Url,
     /// If set, use a remote view service instead of local synchronization.
     pub view_url: Option<Url>,
+    /// If set, relay the messages of threshold signing through this coordinator,
+    /// instead of copying and pasting them between signers.
+    pub coordinator_url: Option<Url>,
+    /// If set, synchronize the local view service through this note detection service, which
+    /// only sends the notes which might belong to this wallet.
+    pub detection_url: Option<Url>,
     /// Disable the scary "you will lose all your money" warning.
     #[serde(default, skip_serializing_if = "is_default")]
     pub disable_warning: bool,
+    /// Encrypt the local view database, with a password asked for whenever it's opened.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub encrypt_view: bool,
     /// The FVK used for viewing chain data.
     #[serde_as(as = "DisplayFromStr")]
     pub full_viewing_key: FullViewingKey,
@@ -58,6 +67,15 @@ impl PcliConfig {
         };
         GovernanceKey(fvk.spend_verification_key().clone())
     }
+
+    /// Checks that notes sent to an address of the given account will be found, which they might
+    /// not be when synchronizing through a detection service.
+    pub fn check_detected(&self, account: u32, ephemeral: bool) -> Result<()> {
+        if self.detection_url.is_some() {
+            penumbra_view::check_detected(account, ephemeral)?;
+        }
+        Ok(())
+    }
 }
 
 /// The custody backend to use.
@@ -73,6 +91,14 @@ pub enum CustodyConfig {
     Threshold(ThresholdConfig),
     /// An encrypted custody service.
     Encrypted(EncryptedConfig),
+    /// A remote custody service, reached over gRPC.
+    ///
+    /// This can be any implementation of the custody protocol, such as `pclientd`
+    /// running in custody mode on a separate signing host.
+    Remote {
+        /// The URL of the custody service's gRPC endpoint.
+        url: Url,
+    },
 }
 
 /// The governance custody backend to use.
@@ -117,7 +143,10 @@ mod tests {
         let config = PcliConfig {
             grpc_url: Url::parse("https://grpc.testnet.penumbra.zone").unwrap(),
             disable_warning: false,
+            encrypt_view: false,
             view_url: None,
+            coordinator_url: None,
+            detection_url: None,
             full_viewing_key: penumbra_keys::test_keys::FULL_VIEWING_KEY.clone(),
             custody: CustodyConfig::SoftKms(SoftKmsConfig::from(
                 penumbra_keys::test_keys::SPEND_KEY.clone(),
@@ -128,11 +157,24 @@ mod tests {
         let mut config2 = config.clone();
         config2.custody = CustodyConfig::ViewOnly;
         config2.disable_warning = true;
+        config2.encrypt_view = true;
+        config2.detection_url = Some(Url::parse("https://detection.example.com").unwrap());
+
+        let mut config3 = config.clone();
+        config3.custody = CustodyConfig::Remote {
+            url: Url::parse("https://custody.example.com:8081").unwrap(),
+        };
+        config3.coordinator_url = Some(Url::parse("https://coordinator.example.com").unwrap());
 
         let toml_config = toml::to_string_pretty(&config).unwrap();
         let toml_config2 = toml::to_string_pretty(&config2).unwrap();
+        let toml_config3 = toml::to_string_pretty(&config3).unwrap();
 
         println!("{}", toml_config);
         println!("{}", toml_config2);
+        println!("{}", toml_config3);
+
+        assert_eq!(config2, toml::from_str(&toml_config2).unwrap());
+        assert_eq!(config3, toml::from_str(&toml_config3).unwrap());
     }
 }
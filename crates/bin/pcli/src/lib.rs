@@ -27,6 +27,9 @@ mod transaction_view_ext;
 
 const CONFIG_FILE_NAME: &str = "config.toml";
 const VIEW_FILE_NAME: &str = "pcli-view.sqlite";
+const POLICY_STATE_FILE_NAME: &str = "custody-policy-state.json";
+const GOVERNANCE_POLICY_STATE_FILE_NAME: &str = "governance-custody-policy-state.json";
+const AUDIT_LOG_FILE_NAME: &str = "custody-audit-log.jsonl";
 
 #[derive(Debug)]
 pub struct App {
@@ -37,6 +37,14 @@ async fn main() -> Result<()> {
     // view service running.
     if let Command::View(ViewCmd::Reset(reset)) = &opt.cmd {
         reset.exec(opt.home.as_path())?;
+        reset
+            .set_birthday(opt.home.as_path(), &opt.load_config()?)
+            .await?;
+        return Ok(());
+    }
+    // Likewise, rescanning rolls back the view database, which must not be in use.
+    if let Command::View(ViewCmd::Rescan(rescan)) = &opt.cmd {
+        rescan.exec(opt.home.as_path(), &opt.load_config()?).await?;
         return Ok(());
     }
     // The debug command takes the home dir directly
@@ -46,6 +54,17 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
+    // The threshold coordinator only relays messages, so it doesn't need a wallet.
+    if let Command::Threshold(ThresholdCmd::Coordinator { bind }) = &opt.cmd {
+        ThresholdCmd::exec_coordinator(*bind).await?;
+        return Ok(());
+    }
+    // Resharing replaces the custody config, so it takes the home dir directly.
+    if let Command::Threshold(cmd @ ThresholdCmd::Reshare { .. }) = &opt.cmd {
+        cmd.exec_reshare(opt.home.as_path()).await?;
+        return Ok(());
+    }
+
     let (mut app, cmd) = opt.into_app().await?;
 
     if !cmd.offline() {
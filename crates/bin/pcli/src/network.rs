@@ -12,8 +12,9 @@ use penumbra_stake::validator::Validator;
 use penumbra_transaction::{txhash::TransactionId, Transaction, TransactionPlan};
 use penumbra_view::ViewClient;
 use std::{fs, future::Future};
-use tonic::transport::{Channel, ClientTlsConfig};
+use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
 use tracing::instrument;
+use url::Url;
 
 use crate::App;
 
@@ -179,17 +180,7 @@ impl App {
 
     // TODO: why do we need this here but not in the view crate?
     pub async fn pd_channel(&self) -> anyhow::Result<Channel> {
-        match self.config.grpc_url.scheme() {
-            "http" => Ok(Channel::from_shared(self.config.grpc_url.to_string())?
-                .connect()
-                .await?),
-            "https" => Ok(Channel::from_shared(self.config.grpc_url.to_string())?
-                .tls_config(ClientTlsConfig::new())?
-                .connect()
-                .await?),
-            other => Err(anyhow::anyhow!("unknown url scheme {other}"))
-                .with_context(|| format!("could not connect to {}", self.config.grpc_url)),
-        }
+        Ok(endpoint(&self.config.grpc_url)?.connect().await?)
     }
 
     pub async fn tendermint_proxy_client(
@@ -199,3 +190,13 @@ impl App {
         Ok(TendermintProxyServiceClient::new(channel))
     }
 }
+
+/// Constructs a gRPC [`Endpoint`] for the given URL, using TLS for `https` URLs.
+pub(crate) fn endpoint(url: &Url) -> anyhow::Result<Endpoint> {
+    match url.scheme() {
+        "http" => Ok(Endpoint::from_shared(url.to_string())?),
+        "https" => Ok(Endpoint::from_shared(url.to_string())?.tls_config(ClientTlsConfig::new())?),
+        other => Err(anyhow::anyhow!("unknown url scheme {other}"))
+            .with_context(|| format!("could not connect to {}", url)),
+    }
+}
@@ -1,21 +1,26 @@
 use crate::{
     config::{CustodyConfig, GovernanceCustodyConfig, PcliConfig},
     default_home,
-    terminal::ActualTerminal,
+    terminal::{ActualTerminal, CoordinatorTerminal},
     App, Command,
 };
 use anyhow::Result;
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 use clap::Parser;
-use penumbra_custody::{null_kms::NullKms, soft_kms::SoftKms};
+use penumbra_custody::{
+    audit::AuditLog, null_kms::NullKms, policy::PolicyState, soft_kms::SoftKms,
+    threshold::network::NetworkTerminal,
+};
+use penumbra_keys::FullViewingKey;
 use penumbra_proto::box_grpc_svc;
 use penumbra_proto::{
+    custody::threshold::v1::coordinator_service_client::CoordinatorServiceClient,
     custody::v1::{
         custody_service_client::CustodyServiceClient, custody_service_server::CustodyServiceServer,
     },
     view::v1::{view_service_client::ViewServiceClient, view_service_server::ViewServiceServer},
 };
-use penumbra_view::ViewServer;
+use penumbra_view::{DatabaseKey, ViewServer};
 use std::io::IsTerminal as _;
 use tracing_subscriber::EnvFilter;
 use url::Url;
@@ -62,9 +67,28 @@ impl Opt {
         Ok(config)
     }
 
+    /// Open the log in which the custody service records its authorization decisions.
+    fn audit_log(&self) -> Result<AuditLog> {
+        AuditLog::open(self.home.join(crate::AUDIT_LOG_FILE_NAME))
+    }
+
+    /// Load the state the custody service uses to enforce stateful policies, like spend
+    /// limits.  Since pcli runs a new process for every command, the state must be
+    /// persisted for those policies to be enforced at all.
+    fn policy_state(&self) -> Result<PolicyState> {
+        PolicyState::load_or_initialize(self.home.join(crate::POLICY_STATE_FILE_NAME))
+    }
+
+    /// Load the policy state of the separate governance custody service, which may hold a
+    /// different key, so it doesn't share a ledger with the main custody service.
+    fn governance_policy_state(&self) -> Result<PolicyState> {
+        PolicyState::load_or_initialize(self.home.join(crate::GOVERNANCE_POLICY_STATE_FILE_NAME))
+    }
+
     pub async fn into_app(self) -> Result<(App, Command)> {
         let config = self.load_config()?;
         let fvk = config.full_viewing_key.clone();
+        let coordinator_url = config.coordinator_url.clone();
 
         // Build the custody service...
         let custody = match &config.custody {
@@ -76,7 +100,9 @@ impl Opt {
             }
             CustodyConfig::SoftKms(config) => {
                 tracing::info!("using software KMS custody service");
-                let soft_kms = SoftKms::new(config.clone());
+                let soft_kms = SoftKms::new(config.clone())
+                    .with_policy_state(self.policy_state()?)
+                    .with_audit_log(self.audit_log()?);
                 let custody_svc = CustodyServiceServer::new(soft_kms);
                 CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
             }
@@ -84,10 +110,9 @@ impl Opt {
                 tracing::info!("using manual threshold custody service");
                 let threshold_kms = penumbra_custody::threshold::Threshold::new(
                     config.clone(),
-                    ActualTerminal {
-                        fvk: Some(fvk.clone()),
-                    },
-                );
+                    threshold_terminal(coordinator_url.as_ref(), &fvk)?,
+                )
+                .with_audit_log(self.audit_log()?);
                 let custody_svc = CustodyServiceServer::new(threshold_kms);
                 CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
             }
@@ -95,13 +120,20 @@ impl Opt {
                 tracing::info!("using encrypted custody service");
                 let encrypted_kms = penumbra_custody::encrypted::Encrypted::new(
                     config.clone(),
-                    ActualTerminal {
-                        fvk: Some(fvk.clone()),
-                    },
-                );
+                    threshold_terminal(coordinator_url.as_ref(), &fvk)?,
+                )
+                .with_policy_state(self.policy_state()?)
+                .with_audit_log(self.audit_log()?);
                 let custody_svc = CustodyServiceServer::new(encrypted_kms);
                 CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
             }
+            CustodyConfig::Remote { url } => {
+                tracing::info!(%url, "using remote custody service");
+                // Connect lazily, so that commands which never need custody
+                // (including offline ones) work even if the signer is unreachable.
+                let ep = crate::network::endpoint(url)?;
+                CustodyServiceClient::new(box_grpc_svc::connect_lazy(ep))
+            }
         };
 
         // Build the governance custody service...
@@ -111,7 +143,8 @@ impl Opt {
                     tracing::info!(
                         "using separate software KMS custody service for validator voting"
                     );
-                    let soft_kms = SoftKms::new(config.clone());
+                    let soft_kms = SoftKms::new(config.clone())
+                        .with_policy_state(self.governance_policy_state()?);
                     let custody_svc = CustodyServiceServer::new(soft_kms);
                     CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
                 }
@@ -121,7 +154,7 @@ impl Opt {
                     );
                     let threshold_kms = penumbra_custody::threshold::Threshold::new(
                         config.clone(),
-                        ActualTerminal { fvk: Some(fvk) },
+                        threshold_terminal(coordinator_url.as_ref(), &fvk)?,
                     );
                     let custody_svc = CustodyServiceServer::new(threshold_kms);
                     CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
@@ -130,8 +163,9 @@ impl Opt {
                     tracing::info!("using separate encrypted custody service for validator voting");
                     let encrypted_kms = penumbra_custody::encrypted::Encrypted::new(
                         config.clone(),
-                        ActualTerminal { fvk: Some(fvk) },
-                    );
+                        threshold_terminal(coordinator_url.as_ref(), &fvk)?,
+                    )
+                    .with_policy_state(self.governance_policy_state()?);
                     let custody_svc = CustodyServiceServer::new(encrypted_kms);
                     CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
                 }
@@ -163,11 +197,14 @@ impl Opt {
                     None
                 };
 
+                let key = view_database_key(&config, &path).await?;
                 let svc = ViewServer::load_or_initialize(
                     Some(path),
+                    key,
                     registry_path,
                     &config.full_viewing_key,
                     config.grpc_url.clone(),
+                    config.detection_url.clone(),
                 )
                 .await?;
 
@@ -187,3 +224,40 @@ impl Opt {
         Ok((app, self.cmd))
     }
 }
+
+/// The key to open the local view database at `view_path` with, if the config encrypts it.
+///
+/// This asks for the password of the database, which is confirmed if the database is new.
+pub async fn view_database_key(
+    config: &PcliConfig,
+    view_path: &Utf8Path,
+) -> Result<Option<DatabaseKey>> {
+    if !config.encrypt_view {
+        return Ok(None);
+    }
+    let password = ActualTerminal::get_view_password(!view_path.exists()).await?;
+    Ok(Some(DatabaseKey::Password(password)))
+}
+
+/// The terminal used for threshold custody, relaying messages through the configured
+/// coordinator, if there is one.
+fn threshold_terminal(
+    coordinator_url: Option<&Url>,
+    fvk: &FullViewingKey,
+) -> Result<CoordinatorTerminal> {
+    let terminal = ActualTerminal {
+        fvk: Some(fvk.clone()),
+    };
+    match coordinator_url {
+        Some(url) => {
+            tracing::info!(%url, "relaying threshold signing through coordinator");
+            // Connect lazily, so that commands which never need to sign don't need the coordinator.
+            let client =
+                CoordinatorServiceClient::new(crate::network::endpoint(url)?.connect_lazy());
+            Ok(CoordinatorTerminal::Network(NetworkTerminal::coordinator(
+                terminal, client,
+            )))
+        }
+        None => Ok(CoordinatorTerminal::Manual(terminal)),
+    }
+}
@@ -4,16 +4,19 @@ use anyhow::Result;
 use decaf377::{Element, Fq};
 use decaf377_rdsa::{Domain, Signature, VerificationKey};
 use penumbra_asset::{asset::Cache, balance::Commitment};
-use penumbra_custody::threshold::{SigningRequest, Terminal};
+use penumbra_custody::threshold::{network::NetworkTerminal, Config, SigningRequest, Terminal};
 use penumbra_keys::{
     symmetric::{OvkWrappedKey, WrappedMemoKey},
     FullViewingKey, PayloadKey,
 };
 use penumbra_proof_params::GROTH16_PROOF_LENGTH_BYTES;
+use penumbra_proto::DomainType;
 use penumbra_sct::Nullifier;
 use penumbra_shielded_pool::{Note, NoteView};
 use penumbra_tct::structure::Hash;
 use penumbra_transaction::{view, ActionPlan, ActionView, TransactionPlan, TransactionView};
+use penumbra_view::AddressBook;
+use serde::{de::DeserializeOwned, Serialize};
 use termion::{color, input::TermRead};
 use tonic::async_trait;
 
@@ -187,7 +190,7 @@ fn pretty_print_transaction_plan(
         },
     };
 
-    view.render_terminal();
+    view.render_terminal(&AddressBook::default());
 
     Ok(())
 }
@@ -304,4 +307,99 @@ impl ActualTerminal {
             return Ok(password);
         }
     }
+
+    /// Ask for the password of the view database, and to confirm it if the database is new.
+    pub async fn get_view_password(create: bool) -> Result<String> {
+        loop {
+            let password = read_password("Enter View Database Password: ").await?;
+            if !create {
+                return Ok(password);
+            }
+            let confirmed = read_password("Confirm View Database Password: ").await?;
+            if password != confirmed {
+                println!("Password mismatch, please try again.");
+                continue;
+            }
+            return Ok(password);
+        }
+    }
+}
+
+/// The terminal used to coordinate threshold signing.
+///
+/// Messages are either copied and pasted between signers, or relayed through a coordinator
+/// server, if one is configured.
+#[derive(Clone)]
+pub enum CoordinatorTerminal {
+    Manual(ActualTerminal),
+    Network(NetworkTerminal<ActualTerminal>),
+}
+
+#[async_trait]
+impl Terminal for CoordinatorTerminal {
+    async fn confirm_request(&self, signing_request: &SigningRequest) -> Result<bool> {
+        match self {
+            Self::Manual(terminal) => terminal.confirm_request(signing_request).await,
+            Self::Network(terminal) => terminal.confirm_request(signing_request).await,
+        }
+    }
+
+    fn explain(&self, msg: &str) -> Result<()> {
+        match self {
+            Self::Manual(terminal) => terminal.explain(msg),
+            Self::Network(terminal) => terminal.explain(msg),
+        }
+    }
+
+    async fn broadcast(&self, data: &str) -> Result<()> {
+        match self {
+            Self::Manual(terminal) => terminal.broadcast(data).await,
+            Self::Network(terminal) => terminal.broadcast(data).await,
+        }
+    }
+
+    async fn send<D>(&self, message: &D) -> Result<()>
+    where
+        D: DomainType + Sync,
+        anyhow::Error: From<<D as TryFrom<<D as DomainType>::Proto>>::Error>,
+        <D as DomainType>::Proto: Serialize,
+    {
+        match self {
+            Self::Manual(terminal) => terminal.send(message).await,
+            Self::Network(terminal) => terminal.send(message).await,
+        }
+    }
+
+    async fn start_session(&self, config: &Config) -> Result<()> {
+        match self {
+            Self::Manual(terminal) => terminal.start_session(config).await,
+            Self::Network(terminal) => terminal.start_session(config).await,
+        }
+    }
+
+    async fn next_response<D>(&self) -> Result<D>
+    where
+        D: DomainType,
+        anyhow::Error: From<<D as TryFrom<<D as DomainType>::Proto>>::Error>,
+        <D as DomainType>::Proto: DeserializeOwned,
+    {
+        match self {
+            Self::Manual(terminal) => terminal.next_response().await,
+            Self::Network(terminal) => terminal.next_response().await,
+        }
+    }
+
+    async fn read_line_raw(&self) -> Result<String> {
+        match self {
+            Self::Manual(terminal) => terminal.read_line_raw().await,
+            Self::Network(terminal) => terminal.read_line_raw().await,
+        }
+    }
+
+    async fn get_password(&self) -> Result<String> {
+        match self {
+            Self::Manual(terminal) => terminal.get_password().await,
+            Self::Network(terminal) => terminal.get_password().await,
+        }
+    }
 }
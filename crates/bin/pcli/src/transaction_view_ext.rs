@@ -12,6 +12,7 @@ use penumbra_num::Amount;
 use penumbra_shielded_pool::SpendView;
 use penumbra_transaction::view::action_view::OutputView;
 use penumbra_transaction::TransactionView;
+use penumbra_view::AddressBook;
 
 // Issues identified:
 // TODO: FeeView
@@ -94,24 +95,30 @@ fn format_opaque_bytes(bytes: &[u8]) -> String {
 
 // feels like these functions should be extension traits of their respective structs
 // propose moving this to core/keys/src/address/view.rs
-fn format_address_view(address_view: &AddressView) -> String {
+fn format_address_view(address_view: &AddressView, address_book: &AddressBook) -> String {
+    let label = address_book.label(address_view);
     match address_view {
         AddressView::Decoded {
             address: _,
             index,
             wallet_id: _,
         } => {
+            let account = match label {
+                Some(label) => format!("account {:?}: {}", index.account, label),
+                None => format!("account {:?}", index.account),
+            };
             if !index.is_ephemeral() {
-                format!("[account {:?}]", index.account)
+                format!("[{}]", account)
             } else {
-                format!("[account {:?} (one-time address)]", index.account)
+                format!("[{} (one-time address)]", account)
             }
         }
-        AddressView::Opaque { address } => {
+        AddressView::Opaque { address } => match label {
+            Some(label) => format!("[contact: {}]", label),
             // The address being opaque just means we can't see the internal structure,
             // we should render the content so it can be copy-pasted.
-            format!("{}", address)
-        }
+            None => format!("{}", address),
+        },
     }
 }
 
@@ -179,12 +186,12 @@ fn value_view_amount(value_view: &ValueView) -> Amount {
 }
 
 pub trait TransactionViewExt {
-    /// Render this transaction view on stdout.
-    fn render_terminal(&self);
+    /// Render this transaction view on stdout, labeling addresses found in the address book.
+    fn render_terminal(&self, address_book: &AddressBook);
 }
 
 impl TransactionViewExt for TransactionView {
-    fn render_terminal(&self) {
+    fn render_terminal(&self, address_book: &AddressBook) {
         let fee = &self.body_view.transaction_parameters.fee;
         // the denomination should be visible here... does a FeeView exist?
         println!("Fee: {}", format_fee(&fee));
@@ -200,7 +207,14 @@ impl TransactionViewExt for TransactionView {
                     plaintext,
                     ciphertext: _,
                 } => {
-                    println!("Memo Sender: {}", &plaintext.return_address.address());
+                    match address_book.label(&plaintext.return_address) {
+                        Some(label) => println!(
+                            "Memo Sender: {} ({})",
+                            label,
+                            &plaintext.return_address.address()
+                        ),
+                        None => println!("Memo Sender: {}", &plaintext.return_address.address()),
+                    }
                     println!("Memo Text: \n{}\n", &plaintext.text);
                 }
                 penumbra_transaction::MemoView::Opaque { ciphertext } => {
@@ -223,7 +237,7 @@ impl TransactionViewExt for TransactionView {
                         SpendView::Visible { spend: _, note } => {
                             action = format!(
                                 "{} -> {}",
-                                format_address_view(&note.address),
+                                format_address_view(&note.address, address_book),
                                 format_value_view(&note.value)
                             );
                             ["Spend", &action]
@@ -245,7 +259,7 @@ impl TransactionViewExt for TransactionView {
                             action = format!(
                                 "{} -> {}",
                                 format_value_view(&note.value),
-                                format_address_view(&note.address),
+                                format_address_view(&note.address, address_book),
                             );
                             ["Output", &action]
                         }
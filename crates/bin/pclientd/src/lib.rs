@@ -8,7 +8,8 @@ use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
 use clap::Parser;
 use directories::ProjectDirs;
-use penumbra_custody::policy::{AuthPolicy, PreAuthorizationPolicy};
+use penumbra_custody::audit::{self, AuditLog, EntryHash};
+use penumbra_custody::policy::{ActionKind, AuthPolicy, PolicyState, PreAuthorizationPolicy};
 use penumbra_custody::soft_kms::{self, SoftKms};
 use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
 use penumbra_keys::FullViewingKey;
@@ -19,7 +20,7 @@ use penumbra_proto::{
     custody::v1::custody_service_server::CustodyServiceServer,
     view::v1::view_service_server::ViewServiceServer,
 };
-use penumbra_view::{Storage, ViewServer};
+use penumbra_view::{DatabaseKey, Storage, ViewServer};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 
@@ -51,6 +52,9 @@ pub struct PclientdConfig {
     pub bind_addr: SocketAddr,
     /// Optional KMS config for custody mode
     pub kms_config: Option<soft_kms::Config>,
+    /// If set, the URL of a note detection service to synchronize through, which only sends the
+    /// notes which might belong to the wallet.
+    pub detection_url: Option<Url>,
 }
 
 impl PclientdConfig {
@@ -85,6 +89,13 @@ pub struct Opt {
     pub home: Utf8PathBuf,
 }
 
+/// The environment variable holding the password that encrypts the view database, if any.
+///
+/// A database created with a password can only be opened with the same password. This needs
+/// pclientd to be built with the `sqlcipher` feature.  The password is only taken from the
+/// environment, so that it doesn't appear in the process list or shell history.
+pub const VIEW_PASSWORD_ENV: &str = "PENUMBRA_PCLIENTD_VIEW_PASSWORD";
+
 #[derive(Debug, clap::Subcommand)]
 pub enum Command {
     /// Generate configs for `pclientd` in view or custody mode.
@@ -107,11 +118,30 @@ pub enum Command {
         /// Sets the address to bind to serve gRPC.
         #[clap(long, display_order = 900, default_value = "127.0.0.1:8081")]
         bind_addr: SocketAddr,
+        /// Sets the URL of a note detection service to synchronize through.
+        ///
+        /// Notes sent to one-time addresses are missed when synchronizing through a detection
+        /// service.
+        #[clap(
+            long,
+            display_order = 900,
+            parse(try_from_str = Url::parse)
+        )]
+        detection_url: Option<Url>,
     },
     /// Start running `pclientd`.
     Start {},
     /// Delete `pclientd` storage to reset local state.
     Reset {},
+    /// Verify the custody audit log, which records every authorization decision in custody mode.
+    ///
+    /// This detects entries that were modified, reordered or removed.  Removal of the latest
+    /// entries is only detected if the head hash printed by an earlier run is provided.
+    VerifyAuditLog {
+        /// The head hash printed by an earlier verification, which must still be in the log.
+        #[clap(long)]
+        head: Option<EntryHash>,
+    },
 }
 
 impl Opt {
@@ -127,6 +157,26 @@ impl Opt {
         path
     }
 
+    fn view_database_key(&self) -> Result<Option<DatabaseKey>> {
+        match std::env::var(VIEW_PASSWORD_ENV) {
+            Ok(password) => Ok(Some(DatabaseKey::Password(password))),
+            Err(std::env::VarError::NotPresent) => Ok(None),
+            Err(e) => Err(e).with_context(|| format!("could not read {VIEW_PASSWORD_ENV}")),
+        }
+    }
+
+    fn policy_state_path(&self) -> Utf8PathBuf {
+        let mut path = self.home.clone();
+        path.push("custody-policy-state.json");
+        path
+    }
+
+    fn audit_log_path(&self) -> Utf8PathBuf {
+        let mut path = self.home.clone();
+        path.push("custody-audit-log.jsonl");
+        path
+    }
+
     fn check_home_nonempty(&self) -> Result<()> {
         if self.home.exists() {
             if !self.home.is_dir() {
@@ -158,12 +208,18 @@ impl Opt {
             .into_inner()
             .try_into()?;
 
-        Storage::initialize(Some(self.sqlite_path()), fvk.clone(), params).await
+        Storage::initialize(
+            Some(self.sqlite_path()),
+            self.view_database_key()?,
+            fvk.clone(),
+            params,
+        )
+        .await
     }
 
     async fn load_or_init_sqlite(&self, fvk: &FullViewingKey, grpc_url: &Url) -> Result<Storage> {
         if self.sqlite_path().exists() {
-            Ok(Storage::load(self.sqlite_path()).await?)
+            Ok(Storage::load(self.sqlite_path(), self.view_database_key()?).await?)
         } else {
             self.init_sqlite(fvk, grpc_url).await
         }
@@ -182,11 +238,25 @@ impl Opt {
 
                 Ok(())
             }
+            Command::VerifyAuditLog { head } => {
+                let entries = audit::verify(opt.audit_log_path(), *head)
+                    .context("Custody audit log failed verification")?;
+                let latest = entries.last().map(|entry| entry.hash).unwrap_or_default();
+                println!(
+                    "Verified {} entries in the audit log at {}",
+                    entries.len(),
+                    opt.audit_log_path()
+                );
+                println!("Head hash: {latest}");
+
+                Ok(())
+            }
             Command::Init {
                 view,
                 custody,
                 grpc_url,
                 bind_addr,
+                detection_url,
             } => {
                 // Check that the home directory is empty.
                 opt.check_home_nonempty()?;
@@ -256,7 +326,9 @@ impl Opt {
                                     .0,
                             ],
                         },
-                        AuthPolicy::OnlyIbcRelay,
+                        AuthPolicy::ActionAllowList {
+                            actions: ActionKind::IBC_RELAY.to_vec(),
+                        },
                         AuthPolicy::PreAuthorization(PreAuthorizationPolicy::Ed25519 {
                             required_signatures: 1,
                             allowed_signers: vec![pvk],
@@ -273,6 +345,7 @@ impl Opt {
                     full_viewing_key,
                     grpc_url: grpc_url.clone(),
                     bind_addr: *bind_addr,
+                    detection_url: detection_url.clone(),
                 };
 
                 let encoded = toml::to_string_pretty(&client_config)
@@ -316,11 +389,28 @@ impl Opt {
                 let compact_block_query_proxy = CompactBlockQueryProxy(proxy_channel.clone());
                 let tendermint_proxy_proxy = TendermintProxyProxy(proxy_channel.clone());
 
-                let view_service =
-                    ViewServiceServer::new(ViewServer::new(storage, config.grpc_url).await?);
-                let custody_service = config.kms_config.as_ref().map(|kms_config| {
-                    CustodyServiceServer::new(SoftKms::new(kms_config.spend_key.clone().into()))
-                });
+                let view_server = match config.detection_url {
+                    Some(detection_url) => {
+                        ViewServer::with_detection_service(storage, config.grpc_url, detection_url)
+                            .await?
+                    }
+                    None => ViewServer::new(storage, config.grpc_url).await?,
+                };
+                let view_service = ViewServiceServer::new(view_server);
+                let custody_service = match &config.kms_config {
+                    Some(kms_config) => {
+                        let policy_state = PolicyState::load_or_initialize(opt.policy_state_path())
+                            .context("Failed to load custody policy state")?;
+                        let audit_log = AuditLog::open(opt.audit_log_path())
+                            .context("Failed to open custody audit log")?;
+                        Some(CustodyServiceServer::new(
+                            SoftKms::new(kms_config.clone())
+                                .with_policy_state(policy_state)
+                                .with_audit_log(audit_log),
+                        ))
+                    }
+                    None => None,
+                };
 
                 let server = Server::builder()
                     .accept_http1(true)
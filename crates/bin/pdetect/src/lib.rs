@@ -0,0 +1,390 @@
+// Requires nightly.
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+//! A delegated note detection service, using fuzzy message detection.
+//!
+//! Users register the detection keys of their addresses with the service, which streams compact
+//! blocks from a fullnode with the notes that can't have been sent to those addresses rolled up
+//! into their commitments. Their view service then only trial-decrypts the notes the service
+//! detected, which are the notes sent to them and a fraction of other notes set by the chain's
+//! FMD parameters.
+//!
+//! Blocks where nothing was detected are streamed without their state payloads, since the view
+//! service can advance its state commitment tree with the block root alone. Their nullifiers are
+//! kept, since spends can't be detected by FMD.
+
+use std::{collections::BTreeSet, net::SocketAddr, pin::Pin};
+
+use anyhow::{Context, Result};
+use async_stream::try_stream;
+use clap::Parser;
+use decaf377_fmd::{Clue, DetectionKey};
+use penumbra_compact_block::{CompactBlock, StatePayload};
+use penumbra_proto::{
+    core::{
+        app::v1::{
+            query_service_client::QueryServiceClient as AppQueryServiceClient,
+            TransactionsByHeightRequest,
+        },
+        component::compact_block::v1::{
+            query_service_client::QueryServiceClient as CompactBlockQueryServiceClient,
+            CompactBlockRangeRequest,
+        },
+    },
+    view::v1::{
+        self as pb,
+        detection_service_server::{DetectionService, DetectionServiceServer},
+    },
+};
+use penumbra_sct::CommitmentSource;
+use penumbra_tct::StateCommitment;
+use penumbra_transaction::Transaction;
+use tonic::transport::{Channel, Server};
+use url::Url;
+
+/// The maximum size of a compact block, in bytes (12MB).
+const MAX_CB_SIZE_BYTES: usize = 12 * 1024 * 1024;
+
+/// The most detection keys which can be registered in a single request.
+pub const MAX_DETECTION_KEYS: usize = 1024;
+
+#[derive(Debug, Parser)]
+#[clap(
+    name = "pdetect",
+    about = "A delegated note detection service for Penumbra.",
+    version
+)]
+pub struct Opt {
+    /// The URL of the gRPC endpoint of the pd node to detect notes from.
+    #[clap(
+        long,
+        env = "PENUMBRA_PDETECT_GRPC_URL",
+        parse(try_from_str = Url::parse)
+    )]
+    pub grpc_url: Url,
+    /// The address to bind to serve gRPC.
+    #[clap(
+        long,
+        env = "PENUMBRA_PDETECT_BIND_ADDR",
+        default_value = "127.0.0.1:8082"
+    )]
+    pub bind_addr: SocketAddr,
+}
+
+impl Opt {
+    pub async fn exec(self) -> Result<()> {
+        tracing::info!(%self.grpc_url, %self.bind_addr, "starting pdetect");
+        let node = Channel::from_shared(self.grpc_url.to_string())
+            .with_context(|| "could not parse node URI")?
+            .connect()
+            .await
+            .with_context(|| "could not connect to grpc server")?;
+
+        Server::builder()
+            .accept_http1(true)
+            .add_service(tonic_web::enable(DetectionServiceServer::new(
+                DetectionServer::new(node),
+            )))
+            .add_service(tonic_web::enable(
+                tonic_reflection::server::Builder::configure()
+                    .register_encoded_file_descriptor_set(penumbra_proto::FILE_DESCRIPTOR_SET)
+                    .build()
+                    .with_context(|| "could not configure grpc reflection service")?,
+            ))
+            .serve(self.bind_addr)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// A [`DetectionService`] which filters the compact blocks of a fullnode.
+///
+/// The service keeps no state: the detection keys are only used for the request which registers
+/// them.
+#[derive(Clone)]
+pub struct DetectionServer {
+    node: Channel,
+}
+
+impl DetectionServer {
+    /// Constructs a new [`DetectionServer`], which detects notes in the blocks of `node`.
+    pub fn new(node: Channel) -> Self {
+        Self { node }
+    }
+
+    /// Rolls up the notes in `block` which weren't detected by any of `detection_keys`, or leaves
+    /// out its state payloads if none of them were.
+    async fn filter_block(
+        &self,
+        mut block: CompactBlock,
+        detection_keys: &[DetectionKey],
+    ) -> Result<CompactBlock> {
+        // Only notes created by transactions have clues, so the transactions only need to be
+        // fetched if there are any.
+        let has_tx_notes = block.state_payloads.iter().any(|payload| {
+            matches!(
+                payload,
+                StatePayload::Note {
+                    source: CommitmentSource::Transaction { .. },
+                    ..
+                }
+            )
+        });
+        if !has_tx_notes {
+            return Ok(block);
+        }
+
+        let transactions = fetch_transactions(self.node.clone(), block.height).await?;
+        let undetected = undetected_notes(&transactions, detection_keys);
+        tracing::debug!(
+            height = block.height,
+            undetected = undetected.len(),
+            "filtered block"
+        );
+        filter(&mut block, &undetected);
+        Ok(block)
+    }
+}
+
+#[tonic::async_trait]
+impl DetectionService for DetectionServer {
+    type FilteredCompactBlockRangeStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<pb::FilteredCompactBlockRangeResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
+
+    async fn filtered_compact_block_range(
+        &self,
+        request: tonic::Request<pb::FilteredCompactBlockRangeRequest>,
+    ) -> Result<tonic::Response<Self::FilteredCompactBlockRangeStream>, tonic::Status> {
+        let pb::FilteredCompactBlockRangeRequest {
+            detection_keys,
+            start_height,
+            end_height,
+            keep_alive,
+        } = request.into_inner();
+
+        if detection_keys.len() > MAX_DETECTION_KEYS {
+            return Err(tonic::Status::invalid_argument(format!(
+                "at most {MAX_DETECTION_KEYS} detection keys can be registered at once"
+            )));
+        }
+        let detection_keys = detection_keys
+            .into_iter()
+            .map(|bytes| {
+                let bytes = <[u8; 32]>::try_from(bytes.as_slice())
+                    .map_err(|_| anyhow::anyhow!("detection keys must be 32 bytes"))?;
+                DetectionKey::from_bytes(bytes).map_err(anyhow::Error::from)
+            })
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("invalid detection key: {e:#}"))
+            })?;
+
+        let mut blocks = CompactBlockQueryServiceClient::new(self.node.clone())
+            .max_decoding_message_size(MAX_CB_SIZE_BYTES)
+            .compact_block_range(CompactBlockRangeRequest {
+                start_height,
+                end_height,
+                keep_alive,
+            })
+            .await?
+            .into_inner();
+
+        let server = self.clone();
+        let stream = try_stream! {
+            while let Some(response) = blocks.message().await? {
+                let block = CompactBlock::try_from(response).map_err(|e| {
+                    tonic::Status::internal(format!("invalid compact block from node: {e:#}"))
+                })?;
+                let block = server
+                    .filter_block(block, &detection_keys)
+                    .await
+                    .map_err(|e| tonic::Status::unavailable(format!("{e:#}")))?;
+                yield pb::FilteredCompactBlockRangeResponse {
+                    compact_block: Some(block.into()),
+                };
+            }
+        };
+
+        Ok(tonic::Response::new(Box::pin(stream)))
+    }
+}
+
+/// Whether any of the clues of a transaction is detected by any of the detection keys.
+fn detected(clues: &[Clue], detection_keys: &[DetectionKey]) -> bool {
+    clues
+        .iter()
+        .any(|clue| detection_keys.iter().any(|key| key.examine(clue)))
+}
+
+/// The commitments of the notes output by transactions whose clues match none of the detection
+/// keys.
+///
+/// The clues of a transaction aren't in the same order as its outputs, so each transaction is
+/// detected or not as a whole. Transactions without clues are never filtered out.
+pub fn undetected_notes(
+    transactions: &[Transaction],
+    detection_keys: &[DetectionKey],
+) -> BTreeSet<StateCommitment> {
+    transactions
+        .iter()
+        .filter(|tx| {
+            let clues = tx
+                .transaction_body
+                .detection_data
+                .as_ref()
+                .map(|data| data.fmd_clues.as_slice())
+                .unwrap_or_default();
+            !clues.is_empty() && !detected(clues, detection_keys)
+        })
+        .flat_map(|tx| {
+            tx.outputs()
+                .map(|output| output.body.note_payload.note_commitment)
+        })
+        .collect()
+}
+
+/// Leaves out the state payloads of `block` if they're all notes with the given commitments, since
+/// its block root is all that is needed to keep the state commitment tree in sync, and otherwise
+/// rolls up those notes.
+pub fn filter(block: &mut CompactBlock, undetected: &BTreeSet<StateCommitment>) {
+    let detected_none = block.state_payloads.iter().all(|payload| {
+        matches!(
+            payload,
+            StatePayload::Note { note, .. } if undetected.contains(&note.note_commitment)
+        )
+    });
+    if detected_none {
+        block.state_payloads.clear();
+    } else {
+        roll_up(block, undetected);
+    }
+}
+
+/// Replaces the payloads of the notes with the given commitments by their commitments, which is
+/// all that is needed to keep the state commitment tree in sync.
+pub fn roll_up(block: &mut CompactBlock, undetected: &BTreeSet<StateCommitment>) {
+    for payload in &mut block.state_payloads {
+        if let StatePayload::Note { source, note } = payload {
+            if undetected.contains(&note.note_commitment) {
+                *payload = StatePayload::RolledUp {
+                    source: source.clone(),
+                    commitment: note.note_commitment,
+                };
+            }
+        }
+    }
+}
+
+async fn fetch_transactions(node: Channel, block_height: u64) -> Result<Vec<Transaction>> {
+    let mut client = AppQueryServiceClient::new(node);
+    let response = client
+        .transactions_by_height(TransactionsByHeightRequest {
+            block_height,
+            ..Default::default()
+        })
+        .await
+        .with_context(|| format!("could not fetch the transactions at height {block_height}"))?;
+    response
+        .into_inner()
+        .transactions
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use decaf377_fmd::Precision;
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_keys::{keys::AddressIndex, test_keys};
+    use penumbra_sct::Nullifier;
+    use penumbra_shielded_pool::Note;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn clues_are_detected_by_the_key_of_their_address() -> Result<()> {
+        let (address, detection_key) =
+            test_keys::FULL_VIEWING_KEY.payment_address(AddressIndex::new(0));
+        let (_, other_key) = test_keys::FULL_VIEWING_KEY.payment_address(AddressIndex::new(1));
+        let clue = address
+            .clue_key()
+            .expand()?
+            .create_clue(Precision::MAX, OsRng)?;
+
+        assert!(detected(
+            &[clue.clone()],
+            &[other_key.clone(), detection_key]
+        ));
+        // With the maximum precision, another key has a negligible chance of detecting the clue.
+        assert!(!detected(&[clue], &[other_key]));
+        assert!(!detected(&[], &[]));
+        Ok(())
+    }
+
+    #[test]
+    fn only_undetected_notes_are_rolled_up() {
+        let note = |amount: u64| {
+            let value = Value {
+                amount: amount.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            };
+            Note::generate(&mut OsRng, &test_keys::ADDRESS_0, value).payload()
+        };
+        let (kept, rolled_up) = (note(1), note(2));
+        let mut block = CompactBlock {
+            state_payloads: vec![
+                (kept.clone(), CommitmentSource::transaction()).into(),
+                (rolled_up.clone(), CommitmentSource::transaction()).into(),
+            ],
+            ..Default::default()
+        };
+
+        roll_up(&mut block, &[rolled_up.note_commitment].into());
+
+        assert!(matches!(
+            &block.state_payloads[0],
+            StatePayload::Note { note, .. } if note.note_commitment == kept.note_commitment
+        ));
+        assert!(matches!(
+            &block.state_payloads[1],
+            StatePayload::RolledUp { commitment, source: CommitmentSource::Transaction { .. } }
+                if *commitment == rolled_up.note_commitment
+        ));
+    }
+
+    #[test]
+    fn blocks_without_detected_notes_only_keep_their_roots() {
+        let note = Note::generate(
+            &mut OsRng,
+            &test_keys::ADDRESS_0,
+            Value {
+                amount: 1u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+        )
+        .payload();
+        let block = CompactBlock {
+            state_payloads: vec![(note.clone(), CommitmentSource::transaction()).into()],
+            nullifiers: vec![Nullifier(1u64.into())],
+            ..Default::default()
+        };
+
+        // A block with a detected note is rolled up rather than emptied...
+        let mut detected = block.clone();
+        filter(&mut detected, &BTreeSet::new());
+        assert_eq!(detected.state_payloads.len(), 1);
+
+        // ... while one without loses its payloads, but keeps its root and nullifiers.
+        let mut undetected = block.clone();
+        filter(&mut undetected, &[note.note_commitment].into());
+        assert!(undetected.state_payloads.is_empty());
+        assert_eq!(undetected.block_root, block.block_root);
+        assert_eq!(undetected.nullifiers, block.nullifiers);
+    }
+}
@@ -154,8 +154,15 @@ impl Opt {
         };
         let db_path: Utf8PathBuf = path.join(VIEW_FILE_NAME);
 
-        let svc: ViewServer =
-            ViewServer::load_or_initialize(Some(db_path), registry_path, &fvk, grpc_url).await?;
+        let svc: ViewServer = ViewServer::load_or_initialize(
+            Some(db_path),
+            None,
+            registry_path,
+            &fvk,
+            grpc_url,
+            None,
+        )
+        .await?;
 
         let svc: ViewServiceServer<ViewServer> = ViewServiceServer::new(svc);
         let view_service = ViewServiceClient::new(box_grpc_svc::local(svc));
@@ -258,9 +265,12 @@ impl Opt {
         let pcli_config = PcliConfig {
             grpc_url: grpc_url.clone(),
             view_url: None,
+            coordinator_url: None,
+            detection_url: None,
             governance_custody: None,
             full_viewing_key: fvk.clone(),
             disable_warning: true,
+            encrypt_view: false,
             custody: pcli::config::CustodyConfig::ViewOnly,
         };
 
@@ -440,6 +450,7 @@ impl Opt {
                     // Check if the account has been migrated
                     let storage = Storage::load_or_initialize(
                         Some(active_path.join(VIEW_FILE_NAME)),
+                        None,
                         &active_fvk,
                         pmonitor_config.grpc_url(),
                     )
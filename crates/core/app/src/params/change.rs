@@ -1,10 +1,11 @@
+use std::collections::BTreeSet;
 use std::fmt::Display;
 
 use anyhow::Context;
 use anyhow::Result;
 use penumbra_auction::params::AuctionParameters;
 use penumbra_community_pool::params::CommunityPoolParameters;
-use penumbra_dex::DexParameters;
+use penumbra_dex::{DexParameters, MAX_ARB_TOKENS};
 use penumbra_distributions::params::DistributionsParameters;
 use penumbra_fee::FeeParameters;
 use penumbra_funding::params::FundingParameters;
@@ -108,6 +109,7 @@ impl AppParameters {
                     max_hops: _,
                     max_positions_per_pair: _,
                     max_execution_budget: _,
+                    arb_tokens: _,
                 },
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
@@ -203,10 +205,26 @@ impl AppParameters {
                     max_hops: _,
                     max_positions_per_pair: _,
                     max_execution_budget: _,
+                    arb_tokens,
                 },
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
 
+        let arb_token_ids = arb_tokens
+            .iter()
+            .map(|arb_token| arb_token.asset_id)
+            .collect::<BTreeSet<_>>();
+        check_all([
+            (
+                arb_tokens.len() <= MAX_ARB_TOKENS,
+                format!("there must be at most {MAX_ARB_TOKENS} arb tokens"),
+            ),
+            (
+                arb_token_ids.len() == arb_tokens.len(),
+                "each arb token must be distinct".to_string(),
+            ),
+        ])?;
+
         check_all([
             (!chain_id.is_empty(), "chain ID must be a non-empty string"),
             (
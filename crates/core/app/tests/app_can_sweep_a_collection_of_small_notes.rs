@@ -117,9 +117,11 @@ async fn app_can_sweep_a_collection_of_small_notes() -> anyhow::Result<()> {
     let view_server = {
         penumbra_view::ViewServer::load_or_initialize(
             None::<&camino::Utf8Path>,
+            None,
             None::<&camino::Utf8Path>,
             &*test_keys::FULL_VIEWING_KEY,
             grpc_url,
+            None,
         )
         .await
         // TODO(kate): the goal is to communicate with the `ViewServiceServer`.
@@ -5,25 +5,70 @@ use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateDelta, StateWrite};
 use penumbra_asset::{asset, Value};
+use penumbra_community_pool::StateWriteExt as _;
+use penumbra_num::Amount;
 use penumbra_proto::{DomainType as _, StateWriteProto as _};
 use penumbra_sct::component::clock::EpochRead;
 use tracing::instrument;
 
 use crate::{
     component::{ExecutionCircuitBreaker, InternalDexWrite, ValueCircuitBreaker},
-    event, SwapExecution,
+    event, ArbToken, SwapExecution,
 };
 
 use super::router::{RouteAndFill, RoutingParams};
 
 #[async_trait]
 pub trait Arbitrage: StateWrite + Sized {
+    /// Performs arbitrage with each of the arb tokens in the DEX parameters, depositing
+    /// the profits into the community pool, and returns the profits.
+    ///
+    /// A failed arb search doesn't prevent the search with the next token.
+    #[instrument(skip(self, routing_params))]
+    async fn arbitrage_all(
+        self: &mut Arc<Self>,
+        routing_params: RoutingParams,
+    ) -> Result<Vec<Value>>
+    where
+        Self: 'static,
+    {
+        let arb_tokens = self.get_dex_params().await?.active_arb_tokens();
+
+        let mut profits = Vec::new();
+        for ArbToken { asset_id, budget } in arb_tokens {
+            match self
+                .arbitrage(asset_id, budget, routing_params.clone())
+                .await
+            {
+                // The arb search completed successfully, and surfaced some surplus.
+                Ok(Some(profit)) => {
+                    tracing::info!(surplus = ?profit, "arbitrage successful!");
+                    Arc::get_mut(self)
+                        .expect("state should be uniquely referenced after arbitrage")
+                        .community_pool_deposit(profit)
+                        .await;
+                    profits.push(profit);
+                }
+                // The arb completed without errors, but resulted in no surplus, so
+                // the state fork was discarded.
+                Ok(None) => tracing::debug!(?asset_id, "no arbitrage found"),
+                // The arbitrage search should not error, but if it does, we should
+                // simply not perform arbitrage, rather than halting the entire chain.
+                Err(e) => tracing::warn!(?e, ?asset_id, "error processing arb, this is a bug"),
+            }
+        }
+
+        Ok(profits)
+    }
+
     /// Attempts to extract as much as possible of the `arb_token` from the available
-    /// liquidity positions, and returns the amount of `arb_token` extracted.
+    /// liquidity positions, trading at most `budget` of it, and returns the amount of
+    /// `arb_token` extracted.
     #[instrument(skip(self, arb_token, routing_params))]
     async fn arbitrage(
         self: &mut Arc<Self>,
         arb_token: asset::Id,
+        budget: Amount,
         routing_params: RoutingParams,
     ) -> Result<Option<Value>>
     where
@@ -37,10 +82,10 @@ pub trait Arbitrage: StateWrite + Sized {
         // discover at the end that the arb wasn't profitable).
         let mut this = Arc::new(StateDelta::new(self.clone()));
 
-        // Create a flash-loan 2^64 of the arb token to ourselves.
+        // Create a flash-loan of the budget of the arb token to ourselves.
         let flash_loan = Value {
             asset_id: arb_token,
-            amount: u64::MAX.into(),
+            amount: budget,
         };
 
         let execution_budget = self.get_dex_params().await?.max_execution_budget;
@@ -24,7 +24,7 @@ use super::eviction_manager::EvictionManager;
 use super::{
     chandelier::Chandelier,
     router::{HandleBatchSwaps, RoutingParams},
-    Arbitrage, PositionManager, PositionRead as _, ValueCircuitBreaker,
+    Arbitrage, PositionManager, PositionRead as _, PriceOracle as _, ValueCircuitBreaker,
 };
 
 pub struct Dex {}
@@ -188,43 +188,13 @@ impl Component for Dex {
             }
         }
 
-        // 3. Perform arbitrage to ensure all prices are consistent post-execution:
-
-        // For arbitrage, we extend the path search by 2 hops to allow a path out of the
-        // staking token and back.
-
-        // Extend the fixed candidate set to include recently accessed assets, to have
-        // more arbitrage execution against newly opened positions.
-        let fixed_candidates = Arc::new(
-            routing_params
-                .fixed_candidates
-                .iter()
-                .cloned()
-                // The set of recently accessed assets is already limited to avoid
-                // potentially blowing up routing time.
-                .chain(state.recently_accessed_assets().iter().cloned())
-                .collect::<Vec<_>>(),
-        );
-
-        let arb_routing_params = RoutingParams {
-            max_hops: routing_params.max_hops + 2,
-            fixed_candidates,
-            price_limit: Some(1u64.into()),
-        };
-
-        match state
-            .arbitrage(*STAKING_TOKEN_ASSET_ID, arb_routing_params)
+        // 3. Perform arbitrage with each arb token to ensure all prices are consistent
+        // post-execution, routing the profits to the community pool:
+        let arb_routing_params = arb_routing_params(state.as_ref(), &routing_params);
+        let _ = state
+            .arbitrage_all(arb_routing_params)
             .await
-        {
-            // The arb search completed successfully, and surfaced some surplus.
-            Ok(Some(v)) => tracing::info!(surplus = ?v, "arbitrage successful!"),
-            // The arb completed without errors, but resulted in no surplus, so
-            // the state fork was discarded.
-            Ok(None) => tracing::debug!("no arbitrage found"),
-            // The arbitrage search should not error, but if it does, we should
-            // simply not perform arbitrage, rather than halting the entire chain.
-            Err(e) => tracing::warn!(?e, "error processing arb, this is a bug"),
-        }
+            .map_err(|e| tracing::warn!(?e, "error processing arb, this is a bug"));
 
         // 4. Inspect trading pairs that saw new position opened during this block, and
         // evict their excess LPs if any are found.
@@ -234,15 +204,29 @@ impl Component for Dex {
             .await
             .map_err(|e| tracing::error!(?e, "error evicting positions, skipping"));
 
-        // 5. Close all positions queued for closure at the end of the block.
+        // 5. Close all positions queued for closure at the end of the block, and those which
+        // expire at this height.
         // It's important to do this after execution, to allow block-scoped JIT liquidity.
-        Arc::get_mut(state)
-            .expect("state should be uniquely referenced after batch swaps complete")
+        let state_ref = Arc::get_mut(state)
+            .expect("state should be uniquely referenced after batch swaps complete");
+        state_ref
+            .close_expired_positions(end_block.height as u64)
+            .await
+            .expect("closing expired positions should not fail");
+        state_ref
             .close_queued_positions()
             .await
             .expect("closing queued positions should not fail");
 
-        // 5. Finalize the candlestick data for the block.
+        // 6. Observe the prices of the trading pairs whose positions changed during
+        // the block, once the book has settled.
+        Arc::get_mut(state)
+            .expect("state should be uniquely referenced after batch swaps complete")
+            .update_price_accumulators()
+            .await
+            .expect("updating price accumulators should not fail");
+
+        // 7. Finalize the candlestick data for the block.
         Arc::get_mut(state)
             .expect("state should be uniquely referenced after batch swaps complete")
             .finalize_block_candlesticks()
@@ -256,6 +240,34 @@ impl Component for Dex {
     }
 }
 
+/// Returns the routing parameters used to search for arbitrage after the batch swaps.
+pub(crate) fn arb_routing_params<S: StateRead + ?Sized>(
+    state: &S,
+    routing_params: &RoutingParams,
+) -> RoutingParams {
+    // For arbitrage, we extend the path search by 2 hops to allow a path out of the
+    // staking token and back.
+
+    // Extend the fixed candidate set to include recently accessed assets, to have
+    // more arbitrage execution against newly opened positions.
+    let fixed_candidates = Arc::new(
+        routing_params
+            .fixed_candidates
+            .iter()
+            .cloned()
+            // The set of recently accessed assets is already limited to avoid
+            // potentially blowing up routing time.
+            .chain(state.recently_accessed_assets().iter().cloned())
+            .collect::<Vec<_>>(),
+    );
+
+    RoutingParams {
+        max_hops: routing_params.max_hops + 2,
+        fixed_candidates,
+        price_limit: Some(1u64.into()),
+    }
+}
+
 /// Provides public read access to DEX data.
 #[async_trait]
 pub trait StateReadExt: StateRead {
@@ -293,6 +305,16 @@ pub trait StateReadExt: StateRead {
         self.get(&state_key::arb_execution(height)).await
     }
 
+    /// Gets the arbitrage performed with the given token at the given height, if any.
+    async fn arb_execution_for_token(
+        &self,
+        height: u64,
+        arb_token: asset::Id,
+    ) -> Result<Option<SwapExecution>> {
+        self.get(&state_key::arb_execution_for_token(height, &arb_token))
+            .await
+    }
+
     /// Return a set of [`TradingPair`]s for which liquidity positions were opened
     /// during this block.
     fn get_active_trading_pairs_in_block(&self) -> BTreeSet<TradingPair> {
@@ -412,7 +434,11 @@ pub(crate) trait InternalDexWrite: StateWrite {
     }
 
     fn set_arb_execution(&mut self, height: u64, execution: SwapExecution) {
-        self.put(state_key::arb_execution(height), execution);
+        let arb_token = execution.input.asset_id;
+        self.put(
+            state_key::arb_execution_for_token(height, &arb_token),
+            execution,
+        );
     }
 }
 
@@ -45,6 +45,11 @@ pub fn register_metrics() {
         Unit::Seconds,
         "The time spent processing a SimulateTrade RPC request"
     );
+    describe_histogram!(
+        DEX_RPC_SIMULATE_BATCH_DURATION,
+        Unit::Seconds,
+        "The time spent processing a SimulateBatch RPC request"
+    );
 }
 
 // We configure buckets for the DEX routing times manually, in order to ensure
@@ -78,6 +83,8 @@ pub const DEX_ARB_DURATION: &str = "penumbra_dex_arb_duration_seconds";
 pub const DEX_BATCH_DURATION: &str = "penumbra_dex_batch_duration_seconds";
 pub const DEX_RPC_SIMULATE_TRADE_DURATION: &str =
     "penumbra_dex_rpc_simulate_trade_duration_seconds";
+pub const DEX_RPC_SIMULATE_BATCH_DURATION: &str =
+    "penumbra_dex_rpc_simulate_batch_duration_seconds";
 
 /// An extension trait providing DEX-related interfaces for [`PrometheusBuilder`].
 ///
@@ -111,5 +118,9 @@ impl PrometheusBuilderExt for metrics_exporter_prometheus::PrometheusBuilder {
             Full(DEX_RPC_SIMULATE_TRADE_DURATION.to_owned()),
             GENERIC_DEX_BUCKETS,
         )
+        .set_buckets_for_metric(
+            Full(DEX_RPC_SIMULATE_BATCH_DURATION.to_owned()),
+            GENERIC_DEX_BUCKETS,
+        )
     }
 }
@@ -15,6 +15,7 @@ mod eviction_manager;
 mod flow;
 mod position_manager;
 mod swap_manager;
+mod twap;
 
 pub use dex::{Dex, StateReadExt, StateWriteExt};
 pub use position_manager::PositionManager;
@@ -22,6 +23,7 @@ pub use position_manager::PositionManager;
 // Read data from the Dex component;
 pub use position_manager::PositionRead;
 pub use swap_manager::SwapDataRead;
+pub use twap::{TwapRead, MAX_TWAP_BLOCKS};
 
 pub(crate) use arb::Arbitrage;
 pub(crate) use circuit_breaker::ExecutionCircuitBreaker;
@@ -30,6 +32,7 @@ pub use circuit_breaker::ValueCircuitBreakerRead;
 pub(crate) use dex::InternalDexWrite;
 pub(crate) use swap_manager::SwapDataWrite;
 pub(crate) use swap_manager::SwapManager;
+pub(crate) use twap::PriceOracle;
 
 #[cfg(test)]
 pub(crate) mod tests;
@@ -10,6 +10,7 @@ use futures::StreamExt;
 use penumbra_asset::{asset, Balance};
 use penumbra_proto::DomainType;
 use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_sct::component::clock::EpochRead as _;
 use tap::Tap;
 use tracing::instrument;
 
@@ -17,8 +18,8 @@ use crate::component::{
     dex::InternalDexWrite,
     dex::StateReadExt as _,
     position_manager::{
-        base_liquidity_index::AssetByLiquidityIndex, inventory_index::PositionByInventoryIndex,
-        price_index::PositionByPriceIndex,
+        base_liquidity_index::AssetByLiquidityIndex, expiry_index::PositionByExpiryIndex,
+        inventory_index::PositionByInventoryIndex, price_index::PositionByPriceIndex,
     },
 };
 use crate::lp::Reserves;
@@ -26,17 +27,19 @@ use crate::{
     component::position_manager::counter::PositionCounter,
     component::ValueCircuitBreaker,
     lp::position::{self, Position},
-    state_key::engine,
+    state_key::{engine, expiry_index},
     DirectedTradingPair,
 };
 use crate::{event, state_key};
 
 use super::chandelier::Chandelier;
+use super::twap::PriceOracle as _;
 
 const DYNAMIC_ASSET_LIMIT: usize = 10;
 
 mod base_liquidity_index;
 pub(crate) mod counter;
+mod expiry_index;
 pub(crate) mod inventory_index;
 pub(crate) mod price_index;
 
@@ -96,6 +99,20 @@ pub trait PositionRead: StateRead {
         positions_by_price.next().await.transpose()
     }
 
+    /// Returns a stream of the [`position::Id`]s of the opened positions expiring at `height`.
+    fn positions_expiring_at(
+        &self,
+        height: u64,
+    ) -> Pin<Box<dyn Stream<Item = Result<position::Id>> + Send + 'static>> {
+        let prefix = expiry_index::by_height(height);
+        self.nonverifiable_prefix_raw(&prefix)
+            .map(|entry| match entry {
+                Ok((k, _)) => Ok(position::Id(expiry_index::parse_id_from_key(k)?)),
+                Err(e) => Err(e),
+            })
+            .boxed()
+    }
+
     /// Fetch the list of pending position closures.
     fn pending_position_closures(&self) -> im::Vector<position::Id> {
         self.object_get(state_key::pending_position_closures())
@@ -217,6 +234,25 @@ pub trait PositionManager: StateWrite + PositionRead {
         self.object_put(state_key::pending_position_closures(), to_close);
     }
 
+    /// Close all opened positions expiring at `height`, recording an expiry event for each.
+    #[instrument(skip(self))]
+    async fn close_expired_positions(&mut self, height: u64) -> Result<()> {
+        use futures::TryStreamExt;
+        let expired: Vec<position::Id> = self.positions_expiring_at(height).try_collect().await?;
+        for id in expired {
+            tracing::debug!(position_id = ?id, "closing expired position");
+            self.close_position_by_id(&id).await?;
+            self.record_proto(
+                event::EventPositionExpire {
+                    position_id: id,
+                    expiry_height: height,
+                }
+                .to_proto(),
+            );
+        }
+        Ok(())
+    }
+
     /// Close all positions that have been queued for closure.
     #[instrument(skip_all)]
     async fn close_queued_positions(&mut self) -> Result<()> {
@@ -252,6 +288,15 @@ pub trait PositionManager: StateWrite + PositionRead {
             anyhow::bail!("attempted to open a position with a state besides `Opened`");
         }
 
+        // Check that the position hasn't already expired.
+        if let Some(expiry_height) = position.expiry_height {
+            let height = self.get_block_height().await?;
+            ensure!(
+                !position.is_expired(height),
+                "attempted to open a position expiring at height {expiry_height}, before the current height {height}"
+            );
+        }
+
         // Validate that the position ID doesn't collide
         if let Some(existing_lp) = self.position_by_id(&id).await? {
             anyhow::bail!(
@@ -490,6 +535,10 @@ trait Inner: StateWrite {
         self.update_trading_pair_position_counter(&prev_state, &new_state)
             .await?;
         self.update_position_by_price_index(&id, &prev_state, &new_state)?;
+        self.update_position_by_expiry_index(&id, &prev_state, &new_state)?;
+
+        // Reprice the trading pair at the end of the block:
+        self.mark_price_changed(new_state.phi.pair);
 
         self.put(state_key::position_by_id(&id), new_state.clone());
         Ok(new_state)
@@ -0,0 +1,55 @@
+use cnidarium::StateWrite;
+use tracing::instrument;
+
+use crate::{
+    lp::position::{self, Position},
+    state_key::expiry_index,
+};
+
+use anyhow::Result;
+use position::State::*;
+
+pub(super) trait PositionByExpiryIndex: StateWrite {
+    fn update_position_by_expiry_index(
+        &mut self,
+        position_id: &position::Id,
+        prev_state: &Option<Position>,
+        new_state: &Position,
+    ) -> Result<()> {
+        // Only opened positions can expire, so closing a position removes it from the index.
+        if let Some(prev_lp) = prev_state {
+            self.deindex_position_by_expiry(prev_lp, position_id);
+        }
+
+        if matches!(new_state.state, Opened) {
+            self.index_position_by_expiry(new_state, position_id);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> PositionByExpiryIndex for T {}
+
+trait Inner: StateWrite {
+    #[instrument(skip(self, position))]
+    fn index_position_by_expiry(&mut self, position: &position::Position, id: &position::Id) {
+        if let Some(expiry_height) = position.expiry_height {
+            tracing::trace!(expiry_height, "indexing position by expiry");
+            let key = expiry_index::key(expiry_height, id).to_vec();
+            self.nonverifiable_put_raw(key, vec![]);
+        }
+    }
+
+    fn deindex_position_by_expiry(
+        &mut self,
+        prev_position: &position::Position,
+        id: &position::Id,
+    ) {
+        if let Some(expiry_height) = prev_position.expiry_height {
+            let key = expiry_index::key(expiry_height, id).to_vec();
+            self.nonverifiable_delete(key);
+        }
+    }
+}
+impl<T: StateWrite + ?Sized> Inner for T {}
@@ -11,12 +11,11 @@ use std::sync::Arc;
 use crate::component::SwapDataRead;
 use crate::component::SwapDataWrite;
 use crate::lp::SellOrder;
-use crate::DexParameters;
 use crate::{
     component::{
-        router::{FillRoute, HandleBatchSwaps, Path},
+        router::{FillRoute, HandleBatchSwaps, Path, RoutingParams},
         tests::TempStorageExt,
-        PositionManager, PositionRead, StateReadExt, StateWriteExt,
+        Arbitrage, PositionManager, PositionRead, StateReadExt, StateWriteExt,
     },
     lp::{
         position::{self, Position},
@@ -24,6 +23,7 @@ use crate::{
     },
     DirectedTradingPair, DirectedUnitPair,
 };
+use crate::{ArbToken, DexParameters};
 
 use super::PathSearch;
 
@@ -1052,6 +1052,162 @@ async fn best_position_route_and_fill() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+/// Expired positions are never filled, even when they offer the best price.
+async fn expired_position_is_not_filled() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let pair_1 = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+
+    // Buy 1 gn at 2 penumbra until height 1, and at 1 penumbra indefinitely.
+    let mut expiring = create_buy(pair_1.clone(), 1u64.into(), 2u64.into());
+    expiring.expiry_height = Some(1);
+    let lasting = create_buy(pair_1.clone(), 1u64.into(), 1u64.into());
+    state_tx.open_position(expiring.clone()).await.unwrap();
+    state_tx.open_position(lasting.clone()).await.unwrap();
+
+    // The end of block 1 closes the expiring position.
+    state_tx.close_expired_positions(1).await?;
+    state_tx.apply();
+
+    // Sell 1 gn for penumbra at height 2.
+    let trading_pair = pair_1.into_directed_trading_pair().into();
+    let mut swap_flow = state.swap_flow(&trading_pair);
+    assert!(trading_pair.asset_1() == penumbra.id());
+    swap_flow.1 += 1u32.into();
+    Arc::get_mut(&mut state)
+        .unwrap()
+        .accumulate_swap_flow(&trading_pair, swap_flow.clone())
+        .await
+        .unwrap();
+    let routing_params = state.routing_params().await.unwrap();
+    state
+        .handle_batch_swaps(trading_pair, swap_flow, 2u32.into(), routing_params, 64)
+        .await
+        .expect("unable to process batch swaps");
+
+    // The swap is filled at the lasting position's price, leaving the expired one untouched.
+    let output_data = state.output_data(2, trading_pair).await?.unwrap();
+    assert_eq!(output_data.delta_2, 1u64.into());
+    assert_eq!(output_data.lambda_1, 1u64.into());
+    assert_eq!(output_data.unfilled_2, 0u64.into());
+
+    let expired = state
+        .position_by_id(&expiring.id())
+        .await?
+        .expect("position exists");
+    assert_eq!(expired.state, position::State::Closed);
+    assert_eq!(expired.reserves, expiring.reserves);
+    let filled = state
+        .position_by_id(&lasting.id())
+        .await?
+        .expect("position exists");
+    assert_ne!(filled.reserves, lasting.reserves);
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that cycles starting from an arb token other than the staking token are
+/// closed, up to the token's budget, with the profits going to the community pool.
+async fn arb_token_cycles_are_closed() -> anyhow::Result<()> {
+    use penumbra_community_pool::StateReadExt as _;
+
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let usd = asset::Cache::with_known_assets()
+        .get_unit("test_usd")
+        .unwrap();
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+
+    // Only arb with USD, trading at most 5 USD at a time.
+    state_tx.put_dex_params(DexParameters {
+        arb_tokens: vec![ArbToken {
+            asset_id: usd.id(),
+            budget: usd.value(5u32.into()).amount,
+        }],
+        ..DexParameters::default()
+    });
+    // Sell 10 gn at 1 USD each.
+    state_tx
+        .open_position(create_sell(
+            DirectedUnitPair::new(gn.clone(), usd.clone()),
+            10u64.into(),
+            1u64.into(),
+        ))
+        .await
+        .unwrap();
+    // Buy 100 gn at 2 gm each.
+    state_tx
+        .open_position(create_buy(
+            DirectedUnitPair::new(gn.clone(), gm.clone()),
+            100u64.into(),
+            2u64.into(),
+        ))
+        .await
+        .unwrap();
+    // Sell 100 USD at 1 gm each.
+    state_tx
+        .open_position(create_sell(
+            DirectedUnitPair::new(usd.clone(), gm.clone()),
+            100u64.into(),
+            1u64.into(),
+        ))
+        .await
+        .unwrap();
+    state_tx.apply();
+
+    // The cycle 10 USD => 10 gn => 20 gm => 20 USD is open, but the budget only
+    // allows half of it to be closed at once.
+    let routing_params = RoutingParams {
+        max_hops: 4 + 2,
+        price_limit: Some(1u64.into()),
+        fixed_candidates: Arc::new(vec![usd.id(), gm.id(), gn.id()]),
+    };
+    let profits = state.arbitrage_all(routing_params.clone()).await?;
+    assert_eq!(profits, vec![usd.value(5u32.into())]);
+
+    let arb_execution = state
+        .arb_execution_for_token(0, usd.id())
+        .await?
+        .expect("arb was performed");
+    assert_eq!(
+        arb_execution.traces,
+        vec![vec![
+            usd.value(5u32.into()),
+            gn.value(5u32.into()),
+            gm.value(10u32.into()),
+            usd.value(10u32.into()),
+        ]]
+    );
+    // The staking token isn't an arb token, so no arbitrage was performed with it.
+    assert!(state.arb_execution(0).await?.is_none());
+
+    // The next arbitrage closes the rest of the cycle, after which there is nothing left.
+    let profits = state.arbitrage_all(routing_params.clone()).await?;
+    assert_eq!(profits, vec![usd.value(5u32.into())]);
+    let profits = state.arbitrage_all(routing_params).await?;
+    assert!(profits.is_empty());
+
+    assert_eq!(
+        state.community_pool_asset_balance(usd.id()).await?,
+        usd.value(10u32.into()).amount
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn multi_hop_route_and_fill() -> anyhow::Result<()> {
     let _ = tracing_subscriber::fmt::try_init();
@@ -1,4 +1,4 @@
-use std::{pin::Pin, sync::Arc};
+use std::{collections::BTreeMap, pin::Pin, sync::Arc};
 
 use anyhow::Result;
 use async_stream::try_stream;
@@ -8,10 +8,12 @@ use tonic::Status;
 use tracing::instrument;
 
 use cnidarium::{StateDelta, Storage};
-use penumbra_asset::{asset, Value};
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_num::Amount;
 use penumbra_proto::{
     core::component::dex::v1::{
         query_service_server::QueryService,
+        simulate_batch_response::{self, SwapOutput},
         simulate_trade_request::{
             routing::{self, Setting},
             Routing,
@@ -20,26 +22,42 @@ use penumbra_proto::{
         ArbExecutionRequest, ArbExecutionResponse, ArbExecutionsRequest, ArbExecutionsResponse,
         BatchSwapOutputDataRequest, BatchSwapOutputDataResponse, CandlestickDataRequest,
         CandlestickDataResponse, CandlestickDataStreamRequest, CandlestickDataStreamResponse,
-        LiquidityPositionByIdRequest, LiquidityPositionByIdResponse, LiquidityPositionsByIdRequest,
+        DepthChartRequest, DepthChartResponse, LiquidityPositionByIdRequest,
+        LiquidityPositionByIdResponse, LiquidityPositionsByIdRequest,
         LiquidityPositionsByIdResponse, LiquidityPositionsByPriceRequest,
         LiquidityPositionsByPriceResponse, LiquidityPositionsRequest, LiquidityPositionsResponse,
-        SimulateTradeRequest, SimulateTradeResponse, SpreadRequest, SpreadResponse,
-        SwapExecutionRequest, SwapExecutionResponse, SwapExecutionsRequest, SwapExecutionsResponse,
+        SimulateBatchRequest, SimulateBatchResponse, SimulateTradeRequest, SimulateTradeResponse,
+        SpreadRequest, SpreadResponse, SwapExecutionRequest, SwapExecutionResponse,
+        SwapExecutionsRequest, SwapExecutionsResponse, TwapRequest, TwapResponse,
     },
     DomainType, StateReadProto,
 };
+use penumbra_sct::component::clock::{EpochManager, EpochRead};
 
 use super::ExecutionCircuitBreaker;
 use crate::{
     component::metrics,
-    lp::position::{self, Position},
+    lp::{
+        position::{self, Position},
+        DepthChart,
+    },
     state_key, CandlestickData, DirectedTradingPair, SwapExecution, TradingPair,
 };
 
-use super::{chandelier::CandlestickRead, router::RouteAndFill, PositionRead, StateReadExt};
+use super::{
+    chandelier::CandlestickRead,
+    dex::arb_routing_params,
+    flow::SwapFlow,
+    router::{HandleBatchSwaps, RouteAndFill},
+    Arbitrage, PositionRead, StateReadExt, SwapDataRead, SwapDataWrite, TwapRead, MAX_TWAP_BLOCKS,
+};
 
 pub mod stub;
 
+/// The most swaps a `SimulateBatch` request can simulate, since each of their
+/// trading pairs is routed and filled on its own.
+const MAX_SIMULATED_SWAPS: usize = 64;
+
 // TODO: Hide this and only expose a Router?
 pub struct Server {
     storage: Storage,
@@ -62,6 +80,8 @@ impl QueryService for Server {
                 + Send,
         >,
     >;
+    type DepthChartStream =
+        Pin<Box<dyn futures::Stream<Item = Result<DepthChartResponse, tonic::Status>> + Send>>;
     type LiquidityPositionsByIdStream = Pin<
         Box<
             dyn futures::Stream<Item = Result<LiquidityPositionsByIdResponse, tonic::Status>>
@@ -86,9 +106,10 @@ impl QueryService for Server {
         let state = self.storage.latest_snapshot();
         let request_inner = request.into_inner();
         let height = request_inner.height;
+        let arb_token = parse_arb_token(request_inner.arb_token)?;
 
         let arb_execution = state
-            .arb_execution(height)
+            .arb_execution_for_token(height, arb_token)
             .await
             .map_err(|e| tonic::Status::internal(e.to_string()))?;
 
@@ -110,8 +131,9 @@ impl QueryService for Server {
         let request_inner = request.into_inner();
         let start_height = request_inner.start_height;
         let end_height = request_inner.end_height;
+        let arb_token = parse_arb_token(request_inner.arb_token)?;
 
-        let s = state.prefix(state_key::arb_executions());
+        let s = state.prefix(&state_key::arb_executions_for_token(&arb_token));
         Ok(tonic::Response::new(
             s.filter_map(
                 move |i: anyhow::Result<(String, SwapExecution)>| async move {
@@ -293,6 +315,50 @@ impl QueryService for Server {
         ))
     }
 
+    #[instrument(skip(self, request))]
+    async fn twap(
+        &self,
+        request: tonic::Request<TwapRequest>,
+    ) -> Result<tonic::Response<TwapResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let pair: DirectedTradingPair = request
+            .trading_pair
+            .ok_or_else(|| Status::invalid_argument("missing trading_pair"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid trading_pair"))?;
+        if request.blocks == 0 {
+            return Err(Status::invalid_argument("blocks must be nonzero"));
+        }
+        if request.blocks > MAX_TWAP_BLOCKS {
+            return Err(Status::invalid_argument(format!(
+                "blocks must be at most {MAX_TWAP_BLOCKS}"
+            )));
+        }
+
+        // Average the prices up to and including the latest committed block.
+        let height = state
+            .get_block_height()
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+        let price = state
+            .twap_at(&pair, height + 1, request.blocks)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?
+            .ok_or_else(|| {
+                Status::not_found(format!(
+                    "no price observed for the trading pair in the last {} blocks",
+                    request.blocks
+                ))
+            })?;
+
+        Ok(tonic::Response::new(TwapResponse {
+            approx_price: price.into(),
+            height,
+        }))
+    }
+
     #[instrument(skip(self, request))]
     async fn swap_executions(
         &self,
@@ -475,6 +541,50 @@ impl QueryService for Server {
         Ok(tonic::Response::new(s.boxed()))
     }
 
+    #[instrument(skip(self, request))]
+    async fn depth_chart(
+        &self,
+        request: tonic::Request<DepthChartRequest>,
+    ) -> Result<tonic::Response<Self::DepthChartStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let pair: DirectedTradingPair = request
+            .trading_pair
+            .ok_or_else(|| tonic::Status::invalid_argument("missing directed trading pair"))?
+            .try_into()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!(
+                    "error parsing directed trading pair: {:#}",
+                    e
+                ))
+            })?;
+
+        let mut chart = DepthChart::new(pair, request.bucket_width_bps, request.depth as usize);
+
+        let s = try_stream! {
+            let mut positions = state.positions_by_price(&pair);
+            while let Some((_, position)) = positions.try_next().await? {
+                if let Some(level) = chart.push(&position)? {
+                    yield DepthChartResponse { level: Some(level.into()) };
+                }
+                if chart.is_full() {
+                    break;
+                }
+            }
+            if let Some(level) = chart.finish() {
+                yield DepthChartResponse { level: Some(level.into()) };
+            }
+        };
+
+        Ok(tonic::Response::new(
+            s.map_err(|e: anyhow::Error| {
+                tonic::Status::internal(format!("error aggregating positions: {:#}", e))
+            })
+            .boxed(),
+        ))
+    }
+
     #[instrument(skip(self, request))]
     async fn liquidity_positions(
         &self,
@@ -586,6 +696,19 @@ impl QueryService for Server {
     }
 }
 
+/// Parses the arb token of an arb execution request, which is the staking token if unset.
+fn parse_arb_token(
+    arb_token: Option<penumbra_proto::core::asset::v1::AssetId>,
+) -> Result<asset::Id, Status> {
+    arb_token
+        .map(TryInto::try_into)
+        .transpose()
+        .map(|arb_token| arb_token.unwrap_or(*STAKING_TOKEN_ASSET_ID))
+        .map_err(|e: anyhow::Error| {
+            tonic::Status::invalid_argument(format!("error parsing arb token: {:#}", e))
+        })
+}
+
 #[tonic::async_trait]
 impl SimulationService for Server {
     async fn simulate_trade(
@@ -695,4 +818,156 @@ impl SimulationService for Server {
 
         Ok(rsp)
     }
+
+    async fn simulate_batch(
+        &self,
+        request: tonic::Request<SimulateBatchRequest>,
+    ) -> Result<tonic::Response<SimulateBatchResponse>, Status> {
+        let swaps = request.into_inner().swaps;
+        if swaps.len() > MAX_SIMULATED_SWAPS {
+            return Err(tonic::Status::invalid_argument(format!(
+                "at most {MAX_SIMULATED_SWAPS} swaps can be simulated at once"
+            )));
+        }
+        let swaps = swaps
+            .into_iter()
+            .map(|swap| {
+                let trading_pair: TradingPair = swap
+                    .trading_pair
+                    .ok_or_else(|| anyhow::anyhow!("missing trading pair"))?
+                    .try_into()?;
+                let delta_1: Option<Amount> = swap.delta_1.map(TryInto::try_into).transpose()?;
+                let delta_2: Option<Amount> = swap.delta_2.map(TryInto::try_into).transpose()?;
+                let flow =
+                    SwapFlow::from((delta_1.unwrap_or_default(), delta_2.unwrap_or_default()));
+                anyhow::Ok((trading_pair, flow))
+            })
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| tonic::Status::invalid_argument(format!("error parsing swap: {:#}", e)))?;
+
+        let start_time = std::time::Instant::now();
+        let state = self.storage.latest_snapshot();
+
+        // Simulate the batch as it would be executed in the next block.
+        let height =
+            state.get_block_height().await.map_err(|e| {
+                tonic::Status::internal(format!("error getting block height: {:#}", e))
+            })? + 1;
+        let routing_params = state
+            .routing_params()
+            .await
+            .expect("dex routing params are set");
+        let execution_budget = state
+            .get_dex_params()
+            .await
+            .expect("dex parameters are set")
+            .max_execution_budget;
+
+        let mut state_tx = Arc::new(StateDelta::new(state));
+        let state_mut = Arc::get_mut(&mut state_tx).expect("state is uniquely referenced");
+        state_mut.put_block_height(height);
+        for (trading_pair, flow) in swaps.iter() {
+            state_mut
+                .accumulate_swap_flow(trading_pair, flow.clone())
+                .await
+                .map_err(|e| {
+                    tonic::Status::internal(format!("error accumulating swap flow: {:#}", e))
+                })?;
+        }
+
+        // Run the batch swaps against the hypothetical swap flows, the same way as at the end of
+        // the block. Swap flows only exist while a block is executed, so the committed state has
+        // none pending, and swaps in the mempool aren't included.
+        let mut bsods = BTreeMap::new();
+        let mut batches = Vec::new();
+        for (trading_pair, swap_flow) in state_tx.swap_flows() {
+            let bsod = state_tx
+                .handle_batch_swaps(
+                    trading_pair,
+                    swap_flow,
+                    height,
+                    routing_params
+                        .clone()
+                        .with_extra_candidates([trading_pair.asset_1(), trading_pair.asset_2()]),
+                    execution_budget,
+                )
+                .await
+                .map_err(|e| {
+                    tonic::Status::internal(format!("error simulating batch swaps: {:#}", e))
+                })?;
+
+            let swap_execution_1_for_2 = state_tx
+                .swap_execution(
+                    height,
+                    DirectedTradingPair::new(trading_pair.asset_1(), trading_pair.asset_2()),
+                )
+                .await
+                .map_err(|e| {
+                    tonic::Status::internal(format!("error getting swap execution: {:#}", e))
+                })?;
+            let swap_execution_2_for_1 = state_tx
+                .swap_execution(
+                    height,
+                    DirectedTradingPair::new(trading_pair.asset_2(), trading_pair.asset_1()),
+                )
+                .await
+                .map_err(|e| {
+                    tonic::Status::internal(format!("error getting swap execution: {:#}", e))
+                })?;
+
+            batches.push(simulate_batch_response::Batch {
+                output_data: Some(bsod.into()),
+                swap_execution_1_for_2: swap_execution_1_for_2.map(Into::into),
+                swap_execution_2_for_1: swap_execution_2_for_1.map(Into::into),
+            });
+            bsods.insert(trading_pair, bsod);
+        }
+
+        let outputs = swaps
+            .iter()
+            .map(|(trading_pair, flow)| {
+                let (lambda_1, lambda_2) = bsods
+                    .get(trading_pair)
+                    .expect("a batch was executed for every swap")
+                    .pro_rata_outputs((flow.0, flow.1));
+                SwapOutput {
+                    lambda_1: Some(lambda_1.into()),
+                    lambda_2: Some(lambda_2.into()),
+                }
+            })
+            .collect();
+
+        // Then perform the arbitrage that would follow the batch swaps.
+        let arb_routing_params = arb_routing_params(state_tx.as_ref(), &routing_params);
+        let profits = state_tx
+            .arbitrage_all(arb_routing_params)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error simulating arbitrage: {:#}", e)))?;
+        let mut arb_executions = Vec::new();
+        for profit in profits {
+            let arb_execution = state_tx
+                .arb_execution_for_token(height, profit.asset_id)
+                .await
+                .map_err(|e| {
+                    tonic::Status::internal(format!("error getting arb execution: {:#}", e))
+                })?
+                .ok_or_else(|| {
+                    tonic::Status::internal(format!(
+                        "no arb execution was recorded for {}",
+                        profit.asset_id
+                    ))
+                })?;
+            arb_executions.push(arb_execution.into());
+        }
+
+        let rsp = tonic::Response::new(SimulateBatchResponse {
+            batches,
+            outputs,
+            arb_executions,
+        });
+
+        metrics::histogram!(metrics::DEX_RPC_SIMULATE_BATCH_DURATION).record(start_time.elapsed());
+
+        Ok(rsp)
+    }
 }
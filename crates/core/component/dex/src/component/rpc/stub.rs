@@ -17,4 +17,14 @@ impl SimulationService for SimulationsDisabled {
              Run pd with `--enable-expensive-rpc` to use this RPC.",
         ))
     }
+
+    async fn simulate_batch(
+        &self,
+        _: tonic::Request<SimulateBatchRequest>,
+    ) -> Result<tonic::Response<SimulateBatchResponse>, Status> {
+        Err(Status::unimplemented(
+            "SimulationService::simulate_batch() is not enabled on this node.\
+             Run pd with `--enable-expensive-rpc` to use this RPC.",
+        ))
+    }
 }
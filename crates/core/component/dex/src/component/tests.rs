@@ -846,7 +846,9 @@ async fn basic_cycle_arb() -> anyhow::Result<()> {
         price_limit: Some(1u64.into()),
         fixed_candidates: Arc::new(vec![penumbra.id(), gm.id(), gn.id()]),
     };
-    state.arbitrage(penumbra.id(), routing_params).await?;
+    state
+        .arbitrage(penumbra.id(), u64::MAX.into(), routing_params)
+        .await?;
 
     let arb_execution = state.arb_execution(0).await?.expect("arb was performed");
     assert_eq!(
@@ -948,7 +950,7 @@ async fn reproduce_arbitrage_loop_testnet_53() -> anyhow::Result<()> {
 
     let arb_profit = tokio::time::timeout(
         tokio::time::Duration::from_secs(2),
-        state.arbitrage(penumbra.id(), routing_params),
+        state.arbitrage(penumbra.id(), u64::MAX.into(), routing_params),
     )
     .await??;
 
@@ -1144,3 +1146,95 @@ async fn check_routable_asset_ordering() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+/// Test that positions are closed at the end of the block they expire at, with an event.
+async fn positions_expire() -> anyhow::Result<()> {
+    use penumbra_proto::{core::component::dex::v1 as pb, event::ProtoEvent as _, Name as _};
+    use penumbra_sct::component::clock::EpochManager as _;
+
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pair = DirectedUnitPair::new(gm.clone(), gn.clone());
+
+    // The expiring position offers the better price.
+    let mut expiring = create_buy(pair.clone(), 1u64.into(), 2u64.into());
+    expiring.expiry_height = Some(2);
+    let lasting = create_buy(pair.clone(), 1u64.into(), 1u64.into());
+    state_tx.open_position(expiring.clone()).await?;
+    state_tx.open_position(lasting.clone()).await?;
+
+    // The position is still open until the end of the block it expires at.
+    state_tx.close_expired_positions(1).await?;
+    let positions_by_price: Vec<_> = state_tx
+        .positions_by_price(&pair.into_directed_trading_pair())
+        .collect()
+        .await;
+    assert_eq!(positions_by_price.len(), 2);
+    assert_eq!(
+        state_tx
+            .best_position(&pair.into_directed_trading_pair())
+            .await?
+            .map(|(id, _)| id),
+        Some(expiring.id())
+    );
+    state_tx.apply();
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.close_expired_positions(2).await?;
+    let (_, events) = state_tx.apply();
+
+    let position = state
+        .position_by_id(&expiring.id())
+        .await?
+        .expect("position exists");
+    assert_eq!(position.state, position::State::Closed);
+    assert_eq!(
+        state
+            .position_by_id(&lasting.id())
+            .await?
+            .expect("position exists")
+            .state,
+        position::State::Opened
+    );
+    assert_eq!(
+        state
+            .best_position(&pair.into_directed_trading_pair())
+            .await?
+            .map(|(id, _)| id),
+        Some(lasting.id())
+    );
+    assert!(state
+        .positions_expiring_at(2)
+        .collect::<Vec<_>>()
+        .await
+        .is_empty());
+
+    let expiry = events
+        .iter()
+        .find(|event| event.kind == pb::EventPositionExpire::full_name())
+        .expect("an expiry event is recorded");
+    let expiry: crate::event::EventPositionExpire =
+        pb::EventPositionExpire::from_event(expiry)?.try_into()?;
+    assert_eq!(expiry.position_id, expiring.id());
+    assert_eq!(expiry.expiry_height, 2);
+    assert!(events
+        .iter()
+        .any(|event| event.kind == pb::EventPositionClose::full_name()));
+
+    // Positions which have already expired can't be opened.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(3);
+    let mut expired = create_buy(pair.clone(), 1u64.into(), 1u64.into());
+    expired.expiry_height = Some(2);
+    assert!(state_tx.open_position(expired.clone()).await.is_err());
+    expired.expiry_height = Some(3);
+    state_tx.open_position(expired).await?;
+
+    Ok(())
+}
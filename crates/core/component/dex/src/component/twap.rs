@@ -0,0 +1,485 @@
+use anyhow::{ensure, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use futures::{StreamExt as _, TryStreamExt as _};
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::{DomainType, StateReadProto, StateWriteProto};
+use penumbra_sct::component::clock::EpochRead as _;
+use penumbra_shielded_pool::component::AssetRegistryRead as _;
+
+use crate::{
+    component::PositionRead as _, state_key::twap, DirectedTradingPair, PriceAccumulator,
+    TradingPair,
+};
+
+/// The reserves which the best positions on one side of a book must hold
+/// between them for their price to be observed, in base units of the asset
+/// they sell, if the asset's metadata isn't registered.
+///
+/// This is one unit of a token with six decimals, like the staking token.
+const DEFAULT_MIN_OBSERVED_DEPTH: u64 = 1_000_000;
+
+/// The most blocks a price can be averaged over, which is a day of five second
+/// blocks.
+///
+/// Only the observations needed to average over this many blocks are kept.
+pub const MAX_TWAP_BLOCKS: u64 = 17_280;
+
+/// Provides time-weighted average prices of directed trading pairs.
+///
+/// The price of a pair is observed at the end of each block in which its
+/// positions changed, as the midpoint between the prices to buy and sell one
+/// display unit of liquidity on each side of the book, so that a dust position
+/// can't set the price on its own. If only one side of the book has that much
+/// liquidity, its price is used alone, and if neither does, the previous price
+/// stays in effect.
+#[async_trait]
+pub trait TwapRead: StateRead {
+    /// Gets the accumulator for the last price observed for the trading pair, if any.
+    async fn price_accumulator(
+        &self,
+        trading_pair: &DirectedTradingPair,
+    ) -> Result<Option<PriceAccumulator>> {
+        self.nonverifiable_get(twap::data::latest(trading_pair).as_bytes())
+            .await
+    }
+
+    /// Returns the sum of the prices of the trading pair at the end of each block
+    /// before `height`, or `None` if no price had been observed before it.
+    async fn cumulative_price(
+        &self,
+        trading_pair: &DirectedTradingPair,
+        height: u64,
+    ) -> Result<Option<U128x128>> {
+        // The first observation at or after the height knows the price that was in
+        // effect at the height; if there is none, the latest observation does.
+        let prefix = twap::data::by_pair(trading_pair);
+        let height_key = format!("{height:020}").as_bytes().to_vec();
+        let next_observation = self
+            .nonverifiable_range_raw(Some(prefix.as_bytes()), height_key..)?
+            .boxed()
+            .next()
+            .await
+            .transpose()?;
+
+        let accumulator = match next_observation {
+            Some((_, value)) => PriceAccumulator::decode(value.as_ref())?,
+            None => match self.price_accumulator(trading_pair).await? {
+                Some(accumulator) => accumulator,
+                None => return Ok(None),
+            },
+        };
+        accumulator.cumulative_price_at(height)
+    }
+
+    /// Returns the time-weighted average price of the trading pair over the `blocks`
+    /// blocks before `height`, or `None` if no price had been observed by the start
+    /// of that range.
+    ///
+    /// At most [`MAX_TWAP_BLOCKS`] blocks before the current one can be averaged
+    /// over, since older observations are discarded.
+    async fn twap_at(
+        &self,
+        trading_pair: &DirectedTradingPair,
+        height: u64,
+        blocks: u64,
+    ) -> Result<Option<U128x128>> {
+        ensure!(blocks > 0, "cannot average the price over zero blocks");
+        ensure!(
+            blocks <= MAX_TWAP_BLOCKS,
+            "cannot average the price over more than {MAX_TWAP_BLOCKS} blocks"
+        );
+
+        let Some(start_height) = height.checked_sub(blocks) else {
+            return Ok(None);
+        };
+        let Some(start) = self.cumulative_price(trading_pair, start_height).await? else {
+            return Ok(None);
+        };
+        let Some(end) = self.cumulative_price(trading_pair, height).await? else {
+            return Ok(None);
+        };
+
+        Ok(Some(((end - start)? / U128x128::from(blocks))?))
+    }
+
+    /// Returns the time-weighted average price of the trading pair over the `blocks`
+    /// blocks before the current one.
+    ///
+    /// Since the current block is excluded, the price can't be moved by trades
+    /// within it.
+    async fn twap(
+        &self,
+        trading_pair: &DirectedTradingPair,
+        blocks: u64,
+    ) -> Result<Option<U128x128>> {
+        let height = self.get_block_height().await?;
+        self.twap_at(trading_pair, height, blocks).await
+    }
+}
+
+impl<T: StateRead + ?Sized> TwapRead for T {}
+
+#[async_trait]
+pub(crate) trait PriceOracle: StateWrite {
+    /// Records that the prices of the trading pair may have changed in this block.
+    fn mark_price_changed(&mut self, trading_pair: TradingPair) {
+        let mut changed = self.changed_trading_pairs();
+        changed.insert(trading_pair);
+        self.object_put(twap::object::changed_trading_pairs(), changed);
+    }
+
+    /// Observes the prices of the trading pairs whose positions changed in this block,
+    /// in both directions, and updates their accumulators.
+    ///
+    /// The observations of those pairs which are too old to be averaged over are
+    /// discarded.
+    async fn update_price_accumulators(&mut self) -> Result<()> {
+        let height = self.get_block_height().await?;
+        // The averages taken once this block is committed start at this height at the
+        // earliest, and the first observation at or after it is all they need.
+        let oldest_needed = (height + 1).saturating_sub(MAX_TWAP_BLOCKS);
+
+        for trading_pair in self.changed_trading_pairs() {
+            let pair_1_to_2 =
+                DirectedTradingPair::new(trading_pair.asset_1(), trading_pair.asset_2());
+            for directed_pair in [pair_1_to_2, pair_1_to_2.flip()] {
+                self.prune_price_history(&directed_pair, oldest_needed)
+                    .await?;
+
+                let Some(price) = self.mid_price(&directed_pair).await? else {
+                    continue;
+                };
+
+                let accumulator = match self.price_accumulator(&directed_pair).await? {
+                    Some(accumulator) => accumulator.observe(height, price)?,
+                    None => PriceAccumulator::new(height, price),
+                };
+                tracing::debug!(?directed_pair, ?accumulator, "observed price");
+
+                self.nonverifiable_put(
+                    twap::data::by_pair_and_height(&directed_pair, height).into(),
+                    accumulator,
+                );
+                self.nonverifiable_put(twap::data::latest(&directed_pair).into(), accumulator);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Deletes the observations of the trading pair before `height`.
+    async fn prune_price_history(
+        &mut self,
+        trading_pair: &DirectedTradingPair,
+        height: u64,
+    ) -> Result<()> {
+        let prefix = twap::data::by_pair(trading_pair);
+        let height_key = format!("{height:020}").as_bytes().to_vec();
+        let keys = self
+            .nonverifiable_range_raw(Some(prefix.as_bytes()), ..height_key)?
+            .map(|entry| entry.map(|(key, _)| key))
+            .boxed()
+            .try_collect::<Vec<_>>()
+            .await?;
+        for key in keys {
+            self.nonverifiable_delete(key);
+        }
+        Ok(())
+    }
+
+    fn changed_trading_pairs(&self) -> im::OrdSet<TradingPair> {
+        self.object_get(twap::object::changed_trading_pairs())
+            .unwrap_or_default()
+    }
+
+    /// Returns the midpoint between the prices to sell and buy the start asset
+    /// against one display unit of liquidity, in units of the end asset.
+    async fn mid_price(&self, trading_pair: &DirectedTradingPair) -> Result<Option<U128x128>> {
+        // Selling the start asset trades against positions priced in the start asset.
+        let bid = self
+            .depth_price(trading_pair)
+            .await?
+            .and_then(|price| U128x128::from(1u64).checked_div(&price).ok());
+        // Buying the start asset trades against positions priced in the end asset.
+        let ask = self.depth_price(&trading_pair.flip()).await?;
+
+        match (bid, ask) {
+            (Some(bid), Some(ask)) => Ok(Some(((bid + ask)? / U128x128::from(2u64))?)),
+            (Some(price), None) | (None, Some(price)) => Ok(Some(price)),
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// Returns the effective price, in units of the start asset, of the position
+    /// at which the best positions on the trading pair first hold one display
+    /// unit of the end asset between them, if they hold that much.
+    ///
+    /// If the end asset's metadata isn't registered, [`DEFAULT_MIN_OBSERVED_DEPTH`]
+    /// base units are needed instead.
+    async fn depth_price(&self, trading_pair: &DirectedTradingPair) -> Result<Option<U128x128>> {
+        let min_depth = match self.denom_metadata_by_asset(&trading_pair.end).await {
+            Some(metadata) => metadata.default_unit().unit_amount(),
+            None => Amount::from(DEFAULT_MIN_OBSERVED_DEPTH),
+        };
+        let mut depth = Amount::zero();
+        let mut positions = self.positions_by_price(trading_pair);
+        while let Some((_, position)) = positions.next().await.transpose()? {
+            let (Some(phi), Some(reserves)) = (
+                position.phi.orient_start(trading_pair.start),
+                position.reserves_for(trading_pair.end),
+            ) else {
+                continue;
+            };
+            depth = depth.saturating_add(&reserves);
+            if depth >= min_depth {
+                return Ok(Some(phi.effective_price()));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<T: StateWrite + ?Sized> PriceOracle for T {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use cnidarium::{ArcStateDeltaExt as _, StateDelta, TempStorage};
+    use penumbra_asset::asset;
+    use penumbra_sct::component::clock::EpochManager as _;
+    use penumbra_shielded_pool::component::AssetRegistry as _;
+    use rand_core::OsRng;
+
+    use crate::{
+        component::{router::create_buy, tests::TempStorageExt as _, PositionManager as _},
+        lp::{position::Position, Reserves},
+        DirectedUnitPair,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    /// Test that the prices observed at the end of blocks are averaged over time.
+    async fn twap_averages_observed_prices() -> anyhow::Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
+        let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+        let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+        let penumbra = asset::Cache::with_known_assets()
+            .get_unit("penumbra")
+            .unwrap();
+        let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+        let pair_gn_penumbra = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+        let gn_penumbra = pair_gn_penumbra.into_directed_trading_pair();
+
+        // At height 1, buy 1 gn at 2 penumbra.
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_block_height(1);
+        state_tx
+            .open_position(create_buy(
+                pair_gn_penumbra.clone(),
+                1u64.into(),
+                2u64.into(),
+            ))
+            .await?;
+        state_tx.update_price_accumulators().await?;
+        state_tx.apply();
+
+        // At height 3, buy 1 gn at 4 penumbra.
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_block_height(3);
+        state_tx
+            .open_position(create_buy(
+                pair_gn_penumbra.clone(),
+                1u64.into(),
+                4u64.into(),
+            ))
+            .await?;
+        state_tx.update_price_accumulators().await?;
+        state_tx.apply();
+
+        let two = U128x128::from(2u64);
+        let three = U128x128::from(3u64);
+        let four = U128x128::from(4u64);
+
+        // The prices at the end of blocks 1 to 4 are 2, 2, 4, 4.
+        assert_eq!(state.twap_at(&gn_penumbra, 5, 4).await?, Some(three));
+        assert_eq!(state.twap_at(&gn_penumbra, 5, 2).await?, Some(four));
+        assert_eq!(state.twap_at(&gn_penumbra, 3, 2).await?, Some(two));
+        assert_eq!(state.twap_at(&gn_penumbra, 4, 2).await?, Some(three));
+        // No price was observed before block 1.
+        assert_eq!(state.twap_at(&gn_penumbra, 5, 5).await?, None);
+
+        // The price of penumbra in gn is observed as well.
+        assert_eq!(
+            state.twap_at(&gn_penumbra.flip(), 3, 2).await?,
+            Some(U128x128::ratio(1u64, 2u64)?)
+        );
+
+        // At the current height, the average excludes the current block.
+        Arc::get_mut(&mut state).unwrap().put_block_height(4);
+        assert_eq!(state.twap(&gn_penumbra, 2).await?, Some(three));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// Test that observations too old to be averaged over are discarded.
+    async fn old_observations_are_pruned() -> anyhow::Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
+        let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+        let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+        let penumbra = asset::Cache::with_known_assets()
+            .get_unit("penumbra")
+            .unwrap();
+        let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+        let pair_gn_penumbra = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+        let gn_penumbra = pair_gn_penumbra.into_directed_trading_pair();
+
+        // Buy 1 gn at 2 penumbra at height 1, and at 4 penumbra at heights 3 and
+        // `MAX_TWAP_BLOCKS + 2`.
+        for (height, price) in [(1, 2u64), (3, 4), (MAX_TWAP_BLOCKS + 2, 4)] {
+            let mut state_tx = state.try_begin_transaction().unwrap();
+            state_tx.put_block_height(height);
+            state_tx
+                .open_position(create_buy(
+                    pair_gn_penumbra.clone(),
+                    1u64.into(),
+                    price.into(),
+                ))
+                .await?;
+            state_tx.update_price_accumulators().await?;
+            state_tx.apply();
+        }
+
+        // The first observation is no longer needed, so it was discarded...
+        let key = |height| twap::data::by_pair_and_height(&gn_penumbra, height);
+        assert_eq!(state.nonverifiable_get_raw(key(1).as_bytes()).await?, None);
+        assert!(state
+            .nonverifiable_get_raw(key(3).as_bytes())
+            .await?
+            .is_some());
+
+        // ... while the longest average still starts from the second.
+        let height = MAX_TWAP_BLOCKS + 3;
+        assert_eq!(
+            state.twap_at(&gn_penumbra, height, MAX_TWAP_BLOCKS).await?,
+            Some(U128x128::from(4u64))
+        );
+        assert!(state
+            .twap_at(&gn_penumbra, height, MAX_TWAP_BLOCKS + 1)
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// Test that a position too small to trade against doesn't set the price.
+    async fn dust_positions_are_not_observed() -> anyhow::Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
+        let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+        let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+        let penumbra = asset::Cache::with_known_assets()
+            .get_unit("penumbra")
+            .unwrap();
+        let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+        let pair_gn_penumbra = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+        let gn_penumbra = pair_gn_penumbra.into_directed_trading_pair();
+
+        // At height 1, buy 1 gn at 2 penumbra.
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_block_height(1);
+        state_tx
+            .open_position(create_buy(
+                pair_gn_penumbra.clone(),
+                1u64.into(),
+                2u64.into(),
+            ))
+            .await?;
+        state_tx.update_price_accumulators().await?;
+        state_tx.apply();
+
+        // At height 3, bid 4 penumbra for gn, with a single base unit of penumbra.
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_block_height(3);
+        state_tx
+            .open_position(Position::new(
+                OsRng,
+                gn_penumbra,
+                0u32,
+                Amount::from(4u64) * penumbra.unit_amount(),
+                gn.unit_amount(),
+                Reserves {
+                    r1: Amount::zero(),
+                    r2: 1u64.into(),
+                },
+            ))
+            .await?;
+        state_tx.update_price_accumulators().await?;
+        state_tx.apply();
+
+        // The dust position is skipped, so the price stays at 2.
+        assert_eq!(
+            state.twap_at(&gn_penumbra, 5, 4).await?,
+            Some(U128x128::from(2u64))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// Test that the depth needed to observe a price is one display unit of the asset sold.
+    async fn depth_is_denominated_in_display_units() -> anyhow::Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
+        let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+        let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+        let btc = asset::Cache::with_known_assets()
+            .get_unit("test_btc")
+            .unwrap();
+        let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+        let gn_btc = DirectedTradingPair::new(gn.id(), btc.id());
+        // Bid 1 test_btc for gn, with half a test_btc.
+        let half_btc_bid = || {
+            Position::new(
+                OsRng,
+                gn_btc,
+                0u32,
+                btc.unit_amount(),
+                gn.unit_amount(),
+                Reserves {
+                    r1: Amount::zero(),
+                    r2: 50_000_000u64.into(),
+                },
+            )
+        };
+
+        // At height 1, half a test_btc is far more than the default depth, but less
+        // than a unit of test_btc, so no price is observed.
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_block_height(1);
+        state_tx.register_denom(&btc.base()).await;
+        state_tx.open_position(half_btc_bid()).await?;
+        state_tx.update_price_accumulators().await?;
+        state_tx.apply();
+        assert_eq!(state.twap_at(&gn_btc, 2, 1).await?, None);
+
+        // At height 2, another half a test_btc makes up a whole one.
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_block_height(2);
+        state_tx.open_position(half_btc_bid()).await?;
+        state_tx.update_price_accumulators().await?;
+        state_tx.apply();
+        assert_eq!(
+            state.twap_at(&gn_btc, 3, 1).await?,
+            Some(U128x128::from(100u64))
+        );
+
+        Ok(())
+    }
+}
@@ -254,6 +254,42 @@ impl DomainType for EventPositionClose {
     type Proto = pb::EventPositionClose;
 }
 
+#[derive(Clone, Debug)]
+pub struct EventPositionExpire {
+    pub position_id: position::Id,
+    pub expiry_height: u64,
+}
+
+impl TryFrom<pb::EventPositionExpire> for EventPositionExpire {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::EventPositionExpire) -> Result<Self, Self::Error> {
+        fn inner(value: pb::EventPositionExpire) -> anyhow::Result<EventPositionExpire> {
+            Ok(EventPositionExpire {
+                position_id: value
+                    .position_id
+                    .ok_or(anyhow!("missing `position_id`"))?
+                    .try_into()?,
+                expiry_height: value.expiry_height,
+            })
+        }
+        inner(value).context(format!("parsing {}", pb::EventPositionExpire::NAME))
+    }
+}
+
+impl From<EventPositionExpire> for pb::EventPositionExpire {
+    fn from(value: EventPositionExpire) -> Self {
+        Self {
+            position_id: Some(value.position_id.into()),
+            expiry_height: value.expiry_height,
+        }
+    }
+}
+
+impl DomainType for EventPositionExpire {
+    type Proto = pb::EventPositionExpire;
+}
+
 #[derive(Clone, Debug)]
 pub struct EventQueuePositionClose {
     pub position_id: position::Id,
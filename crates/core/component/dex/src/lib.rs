@@ -10,12 +10,14 @@ pub mod state_key;
 mod batch_swap_output_data;
 mod candlestick;
 mod params;
+mod price_accumulator;
 mod swap_execution;
 mod trading_pair;
 
 pub use batch_swap_output_data::BatchSwapOutputData;
 pub use candlestick::CandlestickData;
-pub use params::DexParameters;
+pub use params::{ArbToken, DexParameters, MAX_ARB_TOKENS};
+pub use price_accumulator::PriceAccumulator;
 pub use swap_execution::SwapExecution;
 pub use trading_pair::{DirectedTradingPair, DirectedUnitPair, TradingPair, TradingPairVar};
 
@@ -0,0 +1,264 @@
+use anyhow::{anyhow, ensure, Result};
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_proto::penumbra::core::component::dex::v1 as pb;
+
+use crate::DirectedTradingPair;
+
+use super::position::Position;
+
+/// The aggregated liquidity of the positions within a range of effective prices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepthLevel {
+    /// The best effective price in the level, in units of the start asset per
+    /// unit of the end asset.
+    pub price: U128x128,
+    /// The total reserves of the end asset available in the level, saturating
+    /// at the largest amount.
+    pub reserves: Amount,
+    /// The number of positions in the level.
+    pub num_positions: u32,
+}
+
+impl From<DepthLevel> for pb::DepthLevel {
+    fn from(level: DepthLevel) -> Self {
+        Self {
+            price: level.price.into(),
+            reserves: Some(level.reserves.into()),
+            num_positions: level.num_positions,
+        }
+    }
+}
+
+/// Aggregates the positions on a [`DirectedTradingPair`] into [`DepthLevel`]s.
+///
+/// Positions must be pushed in order of effective price, best first, as they
+/// are returned by the price index. A level collects every position whose
+/// price is within `bucket_width_bps` of the best price in the level, and is
+/// returned once a position falls outside of it.
+#[derive(Debug, Clone)]
+pub struct DepthChart {
+    pair: DirectedTradingPair,
+    /// The upper bound (inclusive) of the prices in the current level, as a
+    /// multiple of the level's best price.
+    width: U128x128,
+    /// The number of levels which can still be returned.
+    remaining: usize,
+    current: Option<DepthLevel>,
+}
+
+impl DepthChart {
+    /// Creates a depth chart with levels `bucket_width_bps` wide, returning at
+    /// most `depth` levels, or all of them if `depth` is zero.
+    pub fn new(pair: DirectedTradingPair, bucket_width_bps: u32, depth: usize) -> Self {
+        let width = U128x128::ratio(10_000 + u64::from(bucket_width_bps), 10_000)
+            .expect("denominator is nonzero");
+        let remaining = if depth != 0 { depth } else { usize::MAX };
+
+        Self {
+            pair,
+            width,
+            remaining,
+            current: None,
+        }
+    }
+
+    /// Returns whether no further levels can be returned, so that no more
+    /// positions need to be pushed.
+    pub fn is_full(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// Adds the next position to the chart, returning the previous level if
+    /// the position's price is outside of it.
+    pub fn push(&mut self, position: &Position) -> Result<Option<DepthLevel>> {
+        if self.is_full() {
+            return Ok(None);
+        }
+
+        let price = position
+            .phi
+            .orient_start(self.pair.start)
+            .ok_or_else(|| anyhow!("position is not on the trading pair {:?}", self.pair))?
+            .effective_price();
+        let reserves = position
+            .reserves_for(self.pair.end)
+            .ok_or_else(|| anyhow!("position is not on the trading pair {:?}", self.pair))?;
+
+        if let Some(current) = self.current.as_mut() {
+            ensure!(
+                price >= current.price,
+                "positions must be pushed in order of effective price"
+            );
+
+            // A bound too large to represent is above every price.
+            let within_level = match current.price * self.width {
+                Ok(bound) => price <= bound,
+                Err(_) => true,
+            };
+            if within_level {
+                // Positions can be opened with any reserves, so their sum can overflow.
+                current.reserves = current.reserves.saturating_add(&reserves);
+                current.num_positions = current.num_positions.saturating_add(1);
+                return Ok(None);
+            }
+        }
+
+        let next = DepthLevel {
+            price,
+            reserves,
+            num_positions: 1,
+        };
+        let Some(previous) = self.current.replace(next) else {
+            return Ok(None);
+        };
+
+        self.remaining -= 1;
+        if self.is_full() {
+            self.current = None;
+        }
+        Ok(Some(previous))
+    }
+
+    /// Returns the level which is still being aggregated, if any.
+    pub fn finish(self) -> Option<DepthLevel> {
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::asset;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::lp::Reserves;
+
+    fn position(pair: DirectedTradingPair, price: u64, reserves: u64) -> Position {
+        Position::new(
+            OsRng,
+            pair,
+            0,
+            1u64.into(),
+            price.into(),
+            Reserves {
+                r1: 0u64.into(),
+                r2: reserves.into(),
+            },
+        )
+    }
+
+    fn chart(
+        pair: DirectedTradingPair,
+        width: u32,
+        depth: usize,
+        prices: &[u64],
+    ) -> Vec<(u64, u64, u32)> {
+        let mut chart = DepthChart::new(pair, width, depth);
+        let mut levels = Vec::new();
+        for price in prices {
+            if chart.is_full() {
+                break;
+            }
+            levels.extend(chart.push(&position(pair, *price, 10)).unwrap());
+        }
+        levels.extend(chart.finish());
+        levels
+            .into_iter()
+            .map(|level| {
+                (
+                    f64::from(level.price) as u64,
+                    level.reserves.value() as u64,
+                    level.num_positions,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn positions_are_aggregated_into_levels() {
+        let gm = asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .unwrap()
+            .id();
+        let gn = asset::Cache::with_known_assets()
+            .get_unit("gn")
+            .unwrap()
+            .id();
+        let pair = DirectedTradingPair::new(gm, gn);
+        let prices = [100, 100, 101, 104, 106, 200];
+
+        // Without a bucket width, every distinct price is its own level.
+        assert_eq!(
+            chart(pair, 0, 0, &prices),
+            vec![
+                (100, 20, 2),
+                (101, 10, 1),
+                (104, 10, 1),
+                (106, 10, 1),
+                (200, 10, 1)
+            ]
+        );
+        // Levels are 5% wider than their best price.
+        assert_eq!(
+            chart(pair, 500, 0, &prices),
+            vec![(100, 40, 4), (106, 10, 1), (200, 10, 1)]
+        );
+        // Only the best levels are returned.
+        assert_eq!(
+            chart(pair, 500, 2, &prices),
+            vec![(100, 40, 4), (106, 10, 1)]
+        );
+    }
+
+    #[test]
+    fn positions_must_be_ordered_by_price() {
+        let gm = asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .unwrap()
+            .id();
+        let gn = asset::Cache::with_known_assets()
+            .get_unit("gn")
+            .unwrap()
+            .id();
+        let pair = DirectedTradingPair::new(gm, gn);
+
+        let mut chart = DepthChart::new(pair, 0, 0);
+        chart.push(&position(pair, 2, 10)).unwrap();
+        assert!(chart.push(&position(pair, 1, 10)).is_err());
+    }
+
+    #[test]
+    fn extreme_positions_do_not_overflow() {
+        let gm = asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .unwrap()
+            .id();
+        let gn = asset::Cache::with_known_assets()
+            .get_unit("gn")
+            .unwrap()
+            .id();
+        let pair = DirectedTradingPair::new(gm, gn);
+        let position = || {
+            Position::new(
+                OsRng,
+                pair,
+                0,
+                1u64.into(),
+                u128::MAX.into(),
+                Reserves {
+                    r1: 0u64.into(),
+                    r2: u128::MAX.into(),
+                },
+            )
+        };
+
+        // The bound of the level can't be represented, and the reserves of its
+        // positions add up to more than the largest amount.
+        let mut chart = DepthChart::new(pair, 500, 0);
+        assert_eq!(chart.push(&position()).unwrap(), None);
+        assert_eq!(chart.push(&position()).unwrap(), None);
+        let level = chart.finish().unwrap();
+        assert_eq!(level.reserves, u128::MAX.into());
+        assert_eq!(level.num_positions, 2);
+    }
+}
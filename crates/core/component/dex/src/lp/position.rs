@@ -35,6 +35,9 @@ pub struct Position {
     /// Set to `true` if a position is a limit-order, meaning that it will be closed after being
     /// filled against.
     pub close_on_fill: bool,
+    /// If set, the last block height at which the position can be filled: the DEX closes it at
+    /// the end of that block.
+    pub expiry_height: Option<u64>,
 }
 
 impl std::fmt::Debug for Position {
@@ -44,6 +47,7 @@ impl std::fmt::Debug for Position {
             .field("reserves", &self.reserves)
             .field("phi", &self.phi)
             .field("nonce", &hex::encode(self.nonce))
+            .field("expiry_height", &self.expiry_height)
             .finish()
     }
 }
@@ -96,6 +100,7 @@ impl Position {
             state: State::Opened,
             reserves,
             close_on_fill: false,
+            expiry_height: None,
         }
     }
 
@@ -138,6 +143,7 @@ impl Position {
             state: State::Opened,
             reserves,
             close_on_fill: false,
+            expiry_height: None,
         }
     }
 
@@ -183,11 +189,18 @@ impl Position {
             Err(anyhow!("cyclical pairs aren't allowed"))
         } else if self.phi.component.fee > MAX_FEE_BPS {
             Err(anyhow!("fee cannot be greater than 50% (5000bps)"))
+        } else if self.expiry_height == Some(0) {
+            Err(anyhow!("expiry height must be nonzero"))
         } else {
             Ok(())
         }
     }
 
+    /// Returns whether the position can no longer be filled at the given height.
+    pub fn is_expired(&self, height: u64) -> bool {
+        self.expiry_height.is_some_and(|expiry| height > expiry)
+    }
+
     /// Returns the amount of the given asset that is currently in the position's reserves.
     pub fn reserves_for(&self, asset: asset::Id) -> Option<Amount> {
         if asset == self.phi.pair.asset_1() {
@@ -398,6 +411,7 @@ impl From<Position> for pb::Position {
             phi: Some(p.phi.into()),
             nonce: p.nonce.to_vec(),
             close_on_fill: p.close_on_fill,
+            expiry_height: p.expiry_height.unwrap_or_default(),
         }
     }
 }
@@ -424,6 +438,7 @@ impl TryFrom<pb::Position> for Position {
                 .try_into()
                 .context("expected 32-byte nonce")?,
             close_on_fill: p.close_on_fill,
+            expiry_height: Some(p.expiry_height).filter(|&height| height != 0),
         })
     }
 }
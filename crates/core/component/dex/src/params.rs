@@ -1,8 +1,14 @@
+use anyhow::anyhow;
 use penumbra_asset::{asset, STAKING_TOKEN_ASSET_ID};
+use penumbra_num::Amount;
 use penumbra_proto::penumbra::core::component::dex::v1 as pb;
 use penumbra_proto::DomainType;
 use serde::{Deserialize, Serialize};
 
+/// The most arb tokens which can be configured, since arbitrage is performed
+/// with each of them at the end of every block.
+pub const MAX_ARB_TOKENS: usize = 8;
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(try_from = "pb::DexParameters", into = "pb::DexParameters")]
 /// The configuration parameters for the DEX component.
@@ -12,6 +18,28 @@ pub struct DexParameters {
     pub max_hops: u32,
     pub max_positions_per_pair: u32,
     pub max_execution_budget: u32,
+    pub arb_tokens: Vec<ArbToken>,
+}
+
+impl DexParameters {
+    /// Returns the tokens to perform arbitrage with, skipping those with no budget.
+    ///
+    /// If no arb tokens are configured, arbitrage is performed with the staking token,
+    /// with a budget of 2^64.
+    pub fn active_arb_tokens(&self) -> Vec<ArbToken> {
+        if self.arb_tokens.is_empty() {
+            return vec![ArbToken {
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+                budget: u64::MAX.into(),
+            }];
+        }
+
+        self.arb_tokens
+            .iter()
+            .filter(|arb_token| arb_token.budget != Amount::zero())
+            .cloned()
+            .collect()
+    }
 }
 
 impl DomainType for DexParameters {
@@ -32,6 +60,11 @@ impl TryFrom<pb::DexParameters> for DexParameters {
             max_hops: msg.max_hops,
             max_positions_per_pair: msg.max_positions_per_pair,
             max_execution_budget: msg.max_execution_budget,
+            arb_tokens: msg
+                .arb_tokens
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -48,6 +81,7 @@ impl From<DexParameters> for pb::DexParameters {
             max_hops: params.max_hops,
             max_positions_per_pair: params.max_positions_per_pair,
             max_execution_budget: params.max_execution_budget,
+            arb_tokens: params.arb_tokens.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -72,6 +106,56 @@ impl Default for DexParameters {
             max_hops: 4,
             max_positions_per_pair: 1_000,
             max_execution_budget: 64,
+            arb_tokens: vec![
+                ArbToken {
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                    budget: u64::MAX.into(),
+                },
+                ArbToken {
+                    asset_id: cache.get_unit("test_usd").unwrap().id(),
+                    budget: u64::MAX.into(),
+                },
+            ],
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::ArbToken", into = "pb::ArbToken")]
+/// A token that the DEX performs arbitrage with at the end of each block.
+pub struct ArbToken {
+    /// The asset to search for cyclic arbitrage from and back to.
+    pub asset_id: asset::Id,
+    /// The largest amount of the asset that a single arbitrage can trade.
+    pub budget: Amount,
+}
+
+impl DomainType for ArbToken {
+    type Proto = pb::ArbToken;
+}
+
+impl TryFrom<pb::ArbToken> for ArbToken {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ArbToken) -> anyhow::Result<Self> {
+        Ok(ArbToken {
+            asset_id: msg
+                .asset_id
+                .ok_or_else(|| anyhow!("missing asset id"))?
+                .try_into()?,
+            budget: msg
+                .budget
+                .ok_or_else(|| anyhow!("missing budget"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<ArbToken> for pb::ArbToken {
+    fn from(arb_token: ArbToken) -> Self {
+        pb::ArbToken {
+            asset_id: Some(arb_token.asset_id.into()),
+            budget: Some(arb_token.budget.into()),
         }
     }
 }
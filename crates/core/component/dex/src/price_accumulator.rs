@@ -0,0 +1,158 @@
+use anyhow::{ensure, Result};
+use penumbra_num::fixpoint::U128x128;
+use penumbra_proto::{core::component::dex::v1 as pb, DomainType};
+
+/// The cumulative price of a directed trading pair, from which time-weighted
+/// average prices are computed.
+///
+/// Prices are of the start asset in units of the end asset. The price observed
+/// at the end of a block is in effect for every following block, until the next
+/// observation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriceAccumulator {
+    /// The height at which the price was observed.
+    pub height: u64,
+    /// The sum of the prices in effect at the end of each block before `height`.
+    pub cumulative_price: U128x128,
+    /// The price at the end of block `height`.
+    pub price: U128x128,
+    /// The price in effect before `height`, if one had been observed.
+    pub previous_price: Option<U128x128>,
+}
+
+impl PriceAccumulator {
+    /// Creates the accumulator for the first price observed for a trading pair.
+    pub fn new(height: u64, price: U128x128) -> Self {
+        Self {
+            height,
+            cumulative_price: U128x128::default(),
+            price,
+            previous_price: None,
+        }
+    }
+
+    /// Returns the accumulator after observing a new price at the given height.
+    pub fn observe(&self, height: u64, price: U128x128) -> Result<Self> {
+        ensure!(
+            height >= self.height,
+            "cannot observe a price at height {height}, before the last observation at {}",
+            self.height
+        );
+
+        // A price replaced within the same block was never in effect.
+        if height == self.height {
+            return Ok(Self { price, ..*self });
+        }
+
+        Ok(Self {
+            height,
+            cumulative_price: self
+                .cumulative_price_at(height)?
+                .expect("height is not earlier"),
+            price,
+            previous_price: Some(self.price),
+        })
+    }
+
+    /// Returns the cumulative price at the given height, or `None` if it is
+    /// before any price was observed.
+    ///
+    /// A height earlier than this observation must be after the previous one.
+    pub fn cumulative_price_at(&self, height: u64) -> Result<Option<U128x128>> {
+        if height >= self.height {
+            let blocks = U128x128::from(height - self.height);
+            let accumulated = (self.price * blocks)?;
+            return Ok(Some((self.cumulative_price + accumulated)?));
+        }
+
+        let Some(previous_price) = self.previous_price else {
+            return Ok(None);
+        };
+        let blocks = U128x128::from(self.height - height);
+        let accumulated = (previous_price * blocks)?;
+        Ok(Some(self.cumulative_price.checked_sub(&accumulated)?))
+    }
+}
+
+impl DomainType for PriceAccumulator {
+    type Proto = pb::PriceAccumulator;
+}
+
+impl From<PriceAccumulator> for pb::PriceAccumulator {
+    fn from(accumulator: PriceAccumulator) -> Self {
+        Self {
+            height: accumulator.height,
+            cumulative_price: accumulator.cumulative_price.to_bytes().to_vec(),
+            price: accumulator.price.to_bytes().to_vec(),
+            previous_price: accumulator
+                .previous_price
+                .map(|price| price.to_bytes().to_vec())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl TryFrom<pb::PriceAccumulator> for PriceAccumulator {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::PriceAccumulator) -> Result<Self, Self::Error> {
+        let price_from_bytes =
+            |bytes: Vec<u8>| -> Result<U128x128> { Ok(bytes.as_slice().try_into()?) };
+
+        Ok(Self {
+            height: msg.height,
+            cumulative_price: price_from_bytes(msg.cumulative_price)?,
+            price: price_from_bytes(msg.price)?,
+            previous_price: if msg.previous_price.is_empty() {
+                None
+            } else {
+                Some(price_from_bytes(msg.previous_price)?)
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prices_accumulate_between_observations() -> Result<()> {
+        let two = U128x128::from(2u64);
+        let four = U128x128::from(4u64);
+
+        let first = PriceAccumulator::new(1, two);
+        assert_eq!(first.cumulative_price_at(0)?, None);
+        assert_eq!(first.cumulative_price_at(1)?, Some(U128x128::from(0u64)));
+        assert_eq!(first.cumulative_price_at(3)?, Some(four));
+
+        let second = first.observe(3, four)?;
+        assert_eq!(second.cumulative_price, four);
+        assert_eq!(second.previous_price, Some(two));
+        // Earlier heights are recovered from the previous price.
+        assert_eq!(second.cumulative_price_at(2)?, Some(two));
+        assert_eq!(second.cumulative_price_at(5)?, Some(U128x128::from(12u64)));
+
+        // Observing again in the same block replaces the price.
+        let replaced = second.observe(3, two)?;
+        assert_eq!(replaced.cumulative_price, four);
+        assert_eq!(replaced.previous_price, Some(two));
+        assert_eq!(replaced.price, two);
+
+        assert!(second.observe(2, two).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn price_accumulator_roundtrips() -> Result<()> {
+        let first = PriceAccumulator::new(1, U128x128::ratio(1u64, 3u64)?);
+        let second = first.observe(7, U128x128::from(5u64))?;
+        for accumulator in [first, second] {
+            assert_eq!(
+                PriceAccumulator::decode(accumulator.encode_to_vec().as_slice())?,
+                accumulator
+            );
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use std::string::String;
 
-use penumbra_asset::asset;
+use penumbra_asset::{asset, STAKING_TOKEN_ASSET_ID};
 
 use crate::{lp::position, DirectedTradingPair, TradingPair};
 
@@ -61,6 +61,30 @@ pub mod candlesticks {
     }
 }
 
+pub mod twap {
+    pub mod object {
+        pub fn changed_trading_pairs() -> &'static str {
+            "dex/twap/object/changed_trading_pairs"
+        }
+    }
+
+    pub mod data {
+        use crate::DirectedTradingPair;
+
+        pub fn latest(pair: &DirectedTradingPair) -> String {
+            format!("dex/twap/latest/{}/{}", &pair.start, &pair.end)
+        }
+
+        pub fn by_pair_and_height(pair: &DirectedTradingPair, height: u64) -> String {
+            format!("{}{height:020}", by_pair(pair))
+        }
+
+        pub fn by_pair(pair: &DirectedTradingPair) -> String {
+            format!("dex/twap/history/{}/{}/", &pair.start, &pair.end)
+        }
+    }
+}
+
 pub mod block_scoped {
     pub mod active {
         pub fn trading_pairs() -> &'static str {
@@ -97,6 +121,24 @@ pub fn arb_executions() -> &'static str {
     "dex/arb_execution/"
 }
 
+/// Arb executions with the staking token are kept under [`arb_execution`], for
+/// compatibility with those recorded before other arb tokens were supported.
+pub fn arb_execution_for_token(height: u64, arb_token: &asset::Id) -> String {
+    if *arb_token == *STAKING_TOKEN_ASSET_ID {
+        arb_execution(height)
+    } else {
+        format!("dex/arb_execution_for_token/{arb_token}/{height:020}")
+    }
+}
+
+pub fn arb_executions_for_token(arb_token: &asset::Id) -> String {
+    if *arb_token == *STAKING_TOKEN_ASSET_ID {
+        arb_executions().to_string()
+    } else {
+        format!("dex/arb_execution_for_token/{arb_token}/")
+    }
+}
+
 pub fn swap_flows() -> &'static str {
     "dex/swap_flows"
 }
@@ -219,6 +261,32 @@ pub(crate) mod engine {
     }
 }
 
+pub(crate) mod expiry_index {
+    use crate::lp::position;
+    use anyhow::ensure;
+
+    /// The prefix of the keys of the positions expiring at `height`.
+    pub(crate) fn by_height(height: u64) -> [u8; 34] {
+        let mut prefix = [0u8; 34];
+        prefix[0..26].copy_from_slice(b"dex/internal/expiry_index/");
+        prefix[26..34].copy_from_slice(&height.to_be_bytes());
+        prefix
+    }
+
+    pub(crate) fn key(height: u64, id: &position::Id) -> [u8; 66] {
+        let mut full_key = [0u8; 66];
+        full_key[0..34].copy_from_slice(&by_height(height));
+        full_key[34..66].copy_from_slice(&id.0);
+        full_key
+    }
+
+    pub(crate) fn parse_id_from_key(key: Vec<u8>) -> anyhow::Result<[u8; 32]> {
+        ensure!(key.len() == 66, "key must be 66 bytes");
+        let k = &key[34..66];
+        Ok(k.try_into()?)
+    }
+}
+
 pub(crate) mod eviction_queue {
     pub(crate) mod inventory_index {
         use crate::lp::position;
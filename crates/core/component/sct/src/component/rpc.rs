@@ -3,7 +3,7 @@ use pbjson_types::Timestamp;
 use penumbra_proto::core::component::sct::v1::query_service_server::QueryService;
 use penumbra_proto::core::component::sct::v1::{
     AnchorByHeightRequest, AnchorByHeightResponse, EpochByHeightRequest, EpochByHeightResponse,
-    TimestampByHeightRequest, TimestampByHeightResponse,
+    SctFrontierRequest, SctFrontierResponse, TimestampByHeightRequest, TimestampByHeightResponse,
 };
 use tonic::Status;
 use tracing::instrument;
@@ -79,4 +79,37 @@ impl QueryService for Server {
             }),
         }))
     }
+
+    #[instrument(skip(self, request))]
+    async fn sct_frontier(
+        &self,
+        request: tonic::Request<SctFrontierRequest>,
+    ) -> Result<tonic::Response<SctFrontierResponse>, Status> {
+        // The version of the state is the height of the last block committed to it.
+        let state = match request.get_ref().height {
+            0 => self.storage.latest_snapshot(),
+            height => self.storage.snapshot(height).ok_or_else(|| {
+                tonic::Status::not_found(format!(
+                    "the state at height {height} is no longer available"
+                ))
+            })?,
+        };
+
+        let height = state
+            .get_block_height()
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("could not get block height: {e}")))?;
+        // Every commitment in the tree is inserted without a witness, so it is already just the
+        // frontier.
+        let tree = state.get_sct().await;
+        let compact_frontier = bincode::serialize(&tree).map_err(|e| {
+            tonic::Status::internal(format!("could not serialize the frontier: {e}"))
+        })?;
+
+        Ok(tonic::Response::new(SctFrontierResponse {
+            height,
+            anchor: Some(tree.root().into()),
+            compact_frontier,
+        }))
+    }
 }
@@ -48,3 +48,14 @@ impl std::fmt::Display for WalletId {
         ))
     }
 }
+
+impl std::str::FromStr for WalletId {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        pb::WalletId {
+            inner: bech32str::decode(s, bech32str::wallet_id::BECH32_PREFIX, bech32str::Bech32m)?,
+        }
+        .try_into()
+    }
+}
@@ -8,12 +8,14 @@ use decaf377_rdsa as rdsa;
 
 pub mod address;
 pub mod keys;
+pub mod payment_request;
 pub mod prf;
 pub mod symmetric;
 pub mod test_keys;
 
 pub use address::{Address, AddressVar, AddressView};
 pub use keys::FullViewingKey;
+pub use payment_request::PaymentRequest;
 pub use symmetric::PayloadKey;
 
 fn fmt_hex<T: AsRef<[u8]>>(data: T, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -0,0 +1,260 @@
+//! Payment requests, which ask for a payment to an address and can be shared as a URI.
+//!
+//! A payment request is encoded as a `penumbra:` URI, with the address as its path, and the rest
+//! of the request as query parameters:
+//!
+//! ```text
+//! penumbra:<address>?value=<value>&value=<value>&memo=<memo>&expiry=<expiry>&invoice=<invoice id>
+//! ```
+//!
+//! Values are written as they are on the command line, such as `1.5penumbra`, and are encoded
+//! in base units of their asset ID, so they can be parsed without knowing the asset's
+//! denomination. The expiry is in seconds since the UNIX epoch. Every parameter is optional, and
+//! `value` may be repeated. Unknown parameters are ignored, unless their name starts with
+//! `req-`, which marks parameters which must be understood to pay the request.
+
+use std::{fmt, str::FromStr};
+
+use anyhow::Context;
+use penumbra_asset::Value;
+use penumbra_proto::{penumbra::core::keys::v1 as pb, DomainType};
+use url::{form_urlencoded, Url};
+
+use crate::Address;
+
+/// The scheme of payment request URIs.
+pub const URI_SCHEME: &str = "penumbra";
+
+/// A request for a payment to an address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaymentRequest {
+    /// The address to pay.
+    pub address: Address,
+    /// The values requested. If empty, the payer chooses what to send.
+    pub values: Vec<Value>,
+    /// The memo text to attach to the payment.
+    pub memo: Option<String>,
+    /// The time after which the request should no longer be paid, in seconds since the UNIX
+    /// epoch.
+    pub expiry: Option<u64>,
+    /// The identifier of the invoice the payment is for.
+    pub invoice_id: Option<String>,
+}
+
+impl PaymentRequest {
+    /// A request for any payment to `address`.
+    pub fn new(address: Address) -> Self {
+        Self {
+            address,
+            values: Vec::new(),
+            memo: None,
+            expiry: None,
+            invoice_id: None,
+        }
+    }
+
+    /// The memo text to attach to a payment of this request: its memo, or else its invoice ID,
+    /// so that the payee can tell which invoice was paid.
+    pub fn payment_memo(&self) -> Option<&str> {
+        self.memo.as_deref().or(self.invoice_id.as_deref())
+    }
+
+    /// Whether the request has expired at the given time, in seconds since the UNIX epoch.
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expiry.is_some_and(|expiry| now >= expiry)
+    }
+
+    /// Encode the request as a `penumbra:` URI.
+    pub fn to_uri(&self) -> String {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        for value in &self.values {
+            query.append_pair("value", &format!("{}{}", value.amount, value.asset_id));
+        }
+        if let Some(memo) = &self.memo {
+            query.append_pair("memo", memo);
+        }
+        if let Some(expiry) = self.expiry {
+            query.append_pair("expiry", &expiry.to_string());
+        }
+        if let Some(invoice_id) = &self.invoice_id {
+            query.append_pair("invoice", invoice_id);
+        }
+        let query = query.finish();
+
+        if query.is_empty() {
+            format!("{URI_SCHEME}:{}", self.address)
+        } else {
+            format!("{URI_SCHEME}:{}?{query}", self.address)
+        }
+    }
+}
+
+impl fmt::Display for PaymentRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_uri())
+    }
+}
+
+impl FromStr for PaymentRequest {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let uri = Url::parse(s).context("payment request is not a valid URI")?;
+        anyhow::ensure!(
+            uri.scheme() == URI_SCHEME,
+            "payment request URIs must start with {URI_SCHEME}:"
+        );
+        let address = uri
+            .path()
+            .parse()
+            .context("payment request has an invalid address")?;
+
+        let mut request = PaymentRequest::new(address);
+        for (key, value) in uri.query_pairs() {
+            match key.as_ref() {
+                "value" => request.values.push(
+                    value
+                        .parse()
+                        .with_context(|| format!("payment request has an invalid value {value}"))?,
+                ),
+                "memo" => request.memo = Some(value.into_owned()),
+                "expiry" => {
+                    request.expiry = Some(value.parse().with_context(|| {
+                        format!("payment request has an invalid expiry {value}")
+                    })?)
+                }
+                "invoice" => request.invoice_id = Some(value.into_owned()),
+                key if key.starts_with("req-") => {
+                    anyhow::bail!("payment request needs an unsupported parameter {key}")
+                }
+                _ => {}
+            }
+        }
+
+        Ok(request)
+    }
+}
+
+impl DomainType for PaymentRequest {
+    type Proto = pb::PaymentRequest;
+}
+
+impl From<PaymentRequest> for pb::PaymentRequest {
+    fn from(request: PaymentRequest) -> Self {
+        Self {
+            address: Some(request.address.into()),
+            values: request.values.into_iter().map(Into::into).collect(),
+            memo: request.memo.unwrap_or_default(),
+            expiry: request.expiry.unwrap_or_default(),
+            invoice_id: request.invoice_id.unwrap_or_default(),
+        }
+    }
+}
+
+impl TryFrom<pb::PaymentRequest> for PaymentRequest {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::PaymentRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: proto
+                .address
+                .context("payment request has no address")?
+                .try_into()?,
+            values: proto
+                .values
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+            memo: Some(proto.memo).filter(|memo| !memo.is_empty()),
+            expiry: Some(proto.expiry).filter(|&expiry| expiry != 0),
+            invoice_id: Some(proto.invoice_id).filter(|id| !id.is_empty()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+
+    use super::*;
+    use crate::test_keys;
+
+    fn request() -> PaymentRequest {
+        PaymentRequest {
+            address: test_keys::ADDRESS_0.clone(),
+            values: vec![Value {
+                amount: 1_500_000u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            }],
+            memo: Some("order #12 & co".to_string()),
+            expiry: Some(1_700_000_000),
+            invoice_id: Some("inv-12".to_string()),
+        }
+    }
+
+    #[test]
+    fn uri_round_trip() -> anyhow::Result<()> {
+        let request = request();
+        let uri = request.to_uri();
+        assert!(uri.starts_with("penumbra:penumbra1"));
+        assert_eq!(uri.parse::<PaymentRequest>()?, request);
+
+        let bare = PaymentRequest::new(test_keys::ADDRESS_1.clone());
+        assert_eq!(bare.to_uri(), format!("penumbra:{}", *test_keys::ADDRESS_1));
+        assert_eq!(bare.to_uri().parse::<PaymentRequest>()?, bare);
+        Ok(())
+    }
+
+    #[test]
+    fn parses_values_by_denomination() -> anyhow::Result<()> {
+        let uri = format!(
+            "penumbra:{}?value=1.5penumbra&memo=thanks%21&unknown=ignored",
+            *test_keys::ADDRESS_0
+        );
+        let request: PaymentRequest = uri.parse()?;
+        assert_eq!(request.values, self::request().values);
+        assert_eq!(request.memo.as_deref(), Some("thanks!"));
+        assert_eq!(request.payment_memo(), Some("thanks!"));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_requests() {
+        let address = &*test_keys::ADDRESS_0;
+        for uri in [
+            format!("bitcoin:{address}"),
+            "penumbra:notanaddress".to_string(),
+            format!("penumbra:{address}?value=lots"),
+            format!("penumbra:{address}?expiry=tomorrow"),
+            format!("penumbra:{address}?req-refund=1"),
+        ] {
+            assert!(uri.parse::<PaymentRequest>().is_err(), "{uri}");
+        }
+    }
+
+    #[test]
+    fn expiry_and_memo() {
+        let mut request = request();
+        assert!(!request.is_expired(1_699_999_999));
+        assert!(request.is_expired(1_700_000_000));
+        request.memo = None;
+        assert_eq!(request.payment_memo(), Some("inv-12"));
+        request.expiry = None;
+        assert!(!request.is_expired(u64::MAX));
+    }
+
+    #[test]
+    fn proto_round_trip() -> anyhow::Result<()> {
+        let request = request();
+        assert_eq!(
+            PaymentRequest::try_from(pb::PaymentRequest::from(request.clone()))?,
+            request
+        );
+        let bare = PaymentRequest::new(test_keys::ADDRESS_0.clone());
+        assert_eq!(
+            PaymentRequest::try_from(pb::PaymentRequest::from(bare.clone()))?,
+            bare
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,454 @@
+//! A tamper-evident log of the authorization decisions made by a custody service.
+//!
+//! Every transaction authorization request a custody service decides on is appended to
+//! the log as a line of JSON.  Each entry commits to the hash of the entry before it, so
+//! that modifying, reordering or removing an entry breaks the chain from that point on.
+//!
+//! Removing entries from the *end* of the log cannot be detected from the log alone, so
+//! [`verify`] optionally accepts the hash of an entry recorded elsewhere, such as the head
+//! of the log at the time of a previous verification, and checks that it is still present.
+
+use std::{
+    fmt,
+    io::Write as _,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context as _;
+use penumbra_keys::FullViewingKey;
+use penumbra_proto::{
+    core::transaction::v1::TransactionPlan as ProtoTransactionPlan, Message as _,
+};
+use penumbra_txhash::EffectHash;
+use serde::{Deserialize, Serialize};
+use serde_with::{hex::Hex, DisplayFromStr};
+
+use crate::{AuthorizeRequest, PreAuthorizationKey};
+
+/// The hash of an [`AuditEntry`], which the next entry in the log commits to.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EntryHash(pub [u8; 32]);
+
+impl fmt::Display for EntryHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&hex::encode(self.0))
+    }
+}
+
+impl fmt::Debug for EntryHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("EntryHash")
+            .field(&hex::encode(self.0))
+            .finish()
+    }
+}
+
+impl FromStr for EntryHash {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode(s).context("audit log entry hash is not valid hex")?;
+        Ok(Self(bytes.try_into().map_err(|_| {
+            anyhow::anyhow!("audit log entry hash must be 32 bytes")
+        })?))
+    }
+}
+
+/// The decision a custody service made about an authorization request.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "decision", rename_all = "snake_case")]
+pub enum Outcome {
+    /// The request was authorized.
+    Approved,
+    /// The request was rejected, by a policy or because it could not be signed.
+    Rejected { reason: String },
+}
+
+/// A single authorization decision recorded in an [`AuditLog`].
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AuditEntry {
+    /// The position of this entry in the log, starting from zero.
+    pub sequence: u64,
+    /// The time of the decision, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The effect hash of the transaction plan, absent if the plan was malformed.
+    #[serde_as(as = "Option<Hex>")]
+    pub effect_hash: Option<[u8; 64]>,
+    /// The decision made about the request.
+    pub outcome: Outcome,
+    /// The keys of the valid pre-authorizations attached to the request.
+    pub pre_authorizations: Vec<PreAuthorizationKey>,
+    /// The hash of the previous entry, or all zeros for the first entry.
+    #[serde_as(as = "DisplayFromStr")]
+    pub previous: EntryHash,
+    /// The hash of this entry.
+    #[serde_as(as = "DisplayFromStr")]
+    pub hash: EntryHash,
+}
+
+impl AuditEntry {
+    /// Compute the hash of this entry, over every field except the hash itself.
+    pub fn compute_hash(&self) -> EntryHash {
+        let mut state = blake2b_simd::Params::new()
+            .hash_length(32)
+            .personal(b"custody-audit")
+            .to_state();
+        state.update(&self.sequence.to_le_bytes());
+        state.update(&self.timestamp.to_le_bytes());
+        match &self.effect_hash {
+            Some(effect_hash) => state.update(&[1]).update(effect_hash),
+            None => state.update(&[0]),
+        };
+        match &self.outcome {
+            Outcome::Approved => state.update(&[0]),
+            Outcome::Rejected { reason } => state
+                .update(&[1])
+                .update(&(reason.len() as u64).to_le_bytes())
+                .update(reason.as_bytes()),
+        };
+        state.update(&(self.pre_authorizations.len() as u64).to_le_bytes());
+        for key in &self.pre_authorizations {
+            let scheme = match key {
+                PreAuthorizationKey::Ed25519(_) => 0u8,
+                PreAuthorizationKey::EcdsaP256(_) => 1,
+                PreAuthorizationKey::EcdsaSecp256k1(_) => 2,
+            };
+            let bytes = key.to_bytes();
+            state
+                .update(&[scheme])
+                .update(&(bytes.len() as u64).to_le_bytes())
+                .update(&bytes);
+        }
+        state.update(&self.previous.0);
+        EntryHash(
+            state
+                .finalize()
+                .as_bytes()
+                .try_into()
+                .expect("hash length is 32 bytes"),
+        )
+    }
+}
+
+/// The position of the end of the log, which the next entry extends.
+#[derive(Debug, Default)]
+struct Head {
+    sequence: u64,
+    hash: EntryHash,
+}
+
+/// An append-only, hash-chained log of authorization decisions.
+///
+/// The log is held in memory and, if a path is provided, every entry is appended to the
+/// file there before the decision takes effect.  Clones of the log share the same chain.
+#[derive(Clone, Debug, Default)]
+pub struct AuditLog {
+    head: Arc<Mutex<Head>>,
+    path: Option<PathBuf>,
+}
+
+impl AuditLog {
+    /// Create an empty audit log that is only held in memory.
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Open the audit log at `path` for appending, creating it if there is no file there yet.
+    ///
+    /// The existing entries are verified first, so that a custody service refuses to start
+    /// on top of a log that has been tampered with.
+    pub fn open(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+        let head = if path.exists() {
+            match verify(&path, None)?.last() {
+                Some(last) => Head {
+                    sequence: last.sequence + 1,
+                    hash: last.hash,
+                },
+                None => Head::default(),
+            }
+        } else {
+            Head::default()
+        };
+        Ok(Self {
+            head: Arc::new(Mutex::new(head)),
+            path: Some(path),
+        })
+    }
+
+    /// The hash of the latest entry, or all zeros if the log is empty.
+    ///
+    /// Recording this somewhere else allows [`verify`] to detect truncation of the log.
+    pub fn head(&self) -> anyhow::Result<EntryHash> {
+        Ok(self
+            .head
+            .lock()
+            .map_err(|_| anyhow::anyhow!("audit log lock was poisoned"))?
+            .hash)
+    }
+
+    /// Record the decision made about `request`, whose outcome is `result`.
+    ///
+    /// If the entry cannot be written, an error is returned, which should cause an approved
+    /// request to be rejected rather than authorized without a record.
+    pub fn record_request<T>(
+        &self,
+        fvk: &FullViewingKey,
+        request: &AuthorizeRequest,
+        result: &anyhow::Result<T>,
+    ) -> anyhow::Result<AuditEntry> {
+        let effect_hash = request.plan.effect_hash(fvk).ok();
+        // Pre-authorizations sign the encoded plan, and only the valid ones are recorded,
+        // so that the log cannot be filled with signers who did not approve the request.
+        let signed_data = ProtoTransactionPlan::from(request.plan.clone()).encode_to_vec();
+        let pre_authorizations = request
+            .pre_authorizations
+            .iter()
+            .filter(|pre_auth| pre_auth.verify(&signed_data).is_ok())
+            .map(|pre_auth| pre_auth.key())
+            .collect();
+        let outcome = match result {
+            Ok(_) => Outcome::Approved,
+            Err(e) => Outcome::Rejected {
+                reason: format!("{e:#}"),
+            },
+        };
+        self.record(effect_hash, pre_authorizations, outcome)
+    }
+
+    /// Append a new entry to the log.
+    pub fn record(
+        &self,
+        effect_hash: Option<EffectHash>,
+        pre_authorizations: Vec<PreAuthorizationKey>,
+        outcome: Outcome,
+    ) -> anyhow::Result<AuditEntry> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .context("system clock is set before the Unix epoch")?
+            .as_secs();
+        self.record_at(now, effect_hash, pre_authorizations, outcome)
+    }
+
+    fn record_at(
+        &self,
+        now: u64,
+        effect_hash: Option<EffectHash>,
+        pre_authorizations: Vec<PreAuthorizationKey>,
+        outcome: Outcome,
+    ) -> anyhow::Result<AuditEntry> {
+        let mut head = self
+            .head
+            .lock()
+            .map_err(|_| anyhow::anyhow!("audit log lock was poisoned"))?;
+
+        let mut entry = AuditEntry {
+            sequence: head.sequence,
+            timestamp: now,
+            effect_hash: effect_hash.map(|hash| hash.0),
+            outcome,
+            pre_authorizations,
+            previous: head.hash,
+            hash: EntryHash::default(),
+        };
+        entry.hash = entry.compute_hash();
+
+        // Append before updating the in-memory head, so that a failure to write leaves
+        // the chain where it was on disk.
+        if let Some(path) = &self.path {
+            append(path, &entry)?;
+        }
+        head.sequence += 1;
+        head.hash = entry.hash;
+
+        Ok(entry)
+    }
+}
+
+fn append(path: &Path, entry: &AuditEntry) -> anyhow::Result<()> {
+    let mut line = serde_json::to_vec(entry)?;
+    line.push(b'\n');
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("failed to open audit log at {}", path.display()))?;
+    file.write_all(&line)
+        .and_then(|()| file.sync_data())
+        .with_context(|| format!("failed to write to audit log at {}", path.display()))?;
+    Ok(())
+}
+
+/// Verify the audit log at `path`, returning its entries if it is intact.
+///
+/// This checks that every entry is complete, numbered in order, chained to the entry
+/// before it, and has the hash it claims.  If `expected` is provided, it must be the hash
+/// of one of the entries, which detects removal of the entries at the end of the log up
+/// to and including that one.
+pub fn verify(
+    path: impl AsRef<Path>,
+    expected: Option<EntryHash>,
+) -> anyhow::Result<Vec<AuditEntry>> {
+    let path = path.as_ref();
+    let contents = std::fs::read(path)
+        .with_context(|| format!("failed to read audit log from {}", path.display()))?;
+    if contents.is_empty() {
+        return Ok(Vec::new());
+    }
+    // Every entry is written with a trailing newline, so one missing means the write of
+    // the last entry was cut short.
+    let contents = contents
+        .strip_suffix(b"\n")
+        .ok_or_else(|| anyhow::anyhow!("the last entry of the audit log is incomplete"))?;
+
+    let mut entries = Vec::<AuditEntry>::new();
+    for (index, line) in contents.split(|byte| *byte == b'\n').enumerate() {
+        let entry: AuditEntry = serde_json::from_slice(line)
+            .with_context(|| format!("failed to parse audit log line {}", index + 1))?;
+        let previous = entries.last().map(|last| last.hash).unwrap_or_default();
+        if entry.sequence != index as u64 {
+            anyhow::bail!(
+                "audit log line {} has sequence number {}, so entries have been removed or reordered",
+                index + 1,
+                entry.sequence,
+            );
+        }
+        if entry.previous != previous {
+            anyhow::bail!(
+                "audit log entry {} does not follow the entry before it",
+                entry.sequence,
+            );
+        }
+        if entry.compute_hash() != entry.hash {
+            anyhow::bail!("audit log entry {} has been modified", entry.sequence);
+        }
+        entries.push(entry);
+    }
+
+    if let Some(expected) = expected {
+        if !entries.iter().any(|entry| entry.hash == expected) {
+            anyhow::bail!(
+                "audit log does not contain the entry with hash {}, so it has been truncated or rewritten",
+                expected,
+            );
+        }
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record_decisions(log: &AuditLog) -> anyhow::Result<()> {
+        log.record_at(1_000, Some(EffectHash([1; 64])), vec![], Outcome::Approved)?;
+        log.record_at(
+            1_001,
+            Some(EffectHash([2; 64])),
+            vec![],
+            Outcome::Rejected {
+                reason: "over the spend limit".to_string(),
+            },
+        )?;
+        log.record_at(1_002, None, vec![], Outcome::Approved)?;
+        Ok(())
+    }
+
+    fn rewrite_lines(path: &Path, f: impl FnOnce(&mut Vec<String>)) -> anyhow::Result<()> {
+        let mut lines = std::fs::read_to_string(path)?
+            .lines()
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        f(&mut lines);
+        std::fs::write(path, lines.join("\n") + "\n")?;
+        Ok(())
+    }
+
+    #[test]
+    fn log_verifies_and_continues_after_reopening() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("audit-log.jsonl");
+
+        let log = AuditLog::open(&path)?;
+        record_decisions(&log)?;
+        let head = log.head()?;
+
+        let reopened = AuditLog::open(&path)?;
+        assert_eq!(reopened.head()?, head);
+        let entry = reopened.record_at(1_003, None, vec![], Outcome::Approved)?;
+        assert_eq!(entry.sequence, 3);
+        assert_eq!(entry.previous, head);
+
+        let entries = verify(&path, Some(head))?;
+        assert_eq!(entries.len(), 4);
+        assert_eq!(
+            entries[1].outcome,
+            Outcome::Rejected {
+                reason: "over the spend limit".to_string()
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn modified_entry_is_detected() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("audit-log.jsonl");
+        record_decisions(&AuditLog::open(&path)?)?;
+
+        rewrite_lines(&path, |lines| {
+            lines[1] = lines[1].replace("rejected", "approved");
+        })?;
+        assert!(verify(&path, None).is_err());
+        assert!(AuditLog::open(&path).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn removed_entry_is_detected() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("audit-log.jsonl");
+        record_decisions(&AuditLog::open(&path)?)?;
+
+        rewrite_lines(&path, |lines| {
+            lines.remove(1);
+        })?;
+        assert!(verify(&path, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn truncation_is_detected_against_a_known_head() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("audit-log.jsonl");
+        let log = AuditLog::open(&path)?;
+        record_decisions(&log)?;
+        let head = log.head()?;
+
+        rewrite_lines(&path, |lines| {
+            lines.pop();
+        })?;
+        // The remaining entries are still a valid chain...
+        assert_eq!(verify(&path, None)?.len(), 2);
+        // ...but no longer reach the head recorded before truncation.
+        assert!(verify(&path, Some(head)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn incomplete_entry_is_detected() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("audit-log.jsonl");
+        record_decisions(&AuditLog::open(&path)?)?;
+
+        let contents = std::fs::read(&path)?;
+        std::fs::write(&path, &contents[..contents.len() - 10])?;
+        assert!(verify(&path, None).is_err());
+        Ok(())
+    }
+}
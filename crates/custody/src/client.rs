@@ -52,9 +52,30 @@ where
         async move {
             Ok(self2
                 .authorize(tonic::Request::new(request.into()))
-                .await?
+                .await
+                .map_err(status_to_error)?
                 .into_inner())
         }
         .boxed()
     }
 }
+
+/// Converts an error status returned by a custody service into an error that carries
+/// the service's explanation, such as the policy that rejected the request, without
+/// the transport-level noise of the raw [`tonic::Status`].
+fn status_to_error(status: tonic::Status) -> anyhow::Error {
+    match status.code() {
+        tonic::Code::Unauthenticated | tonic::Code::PermissionDenied => anyhow::anyhow!(
+            "custody service refused to authorize the request: {}",
+            status.message()
+        ),
+        tonic::Code::Unavailable => {
+            anyhow::anyhow!("could not reach the custody service: {}", status.message())
+        }
+        _ => anyhow::anyhow!(
+            "custody service returned an error ({:?}): {}",
+            status.code(),
+            status.message()
+        ),
+    }
+}
@@ -5,7 +5,7 @@ use serde_with::{formats::Uppercase, hex::Hex};
 use tokio::sync::OnceCell;
 use tonic::{async_trait, Request, Response, Status};
 
-use crate::{soft_kms, terminal::Terminal, threshold};
+use crate::{audit::AuditLog, policy::PolicyState, soft_kms, terminal::Terminal, threshold};
 
 mod encryption {
     use anyhow::anyhow;
@@ -193,19 +193,42 @@ impl Config {
 pub struct Encrypted<T> {
     config: Config,
     terminal: T,
+    policy_state: PolicyState,
+    audit_log: AuditLog,
     inner: OnceCell<anyhow::Result<Box<dyn pb::custody_service_server::CustodyService>>>,
 }
 
 impl<T: Terminal + Clone + Send + Sync + 'static> Encrypted<T> {
     /// Create a new encrypted config, using the terminal to ask for a password
+    ///
+    /// As with [`soft_kms::SoftKms::new`], the state of any stateful policies is only
+    /// held in memory unless a [`PolicyState`] is provided with
+    /// [`Encrypted::with_policy_state`].
     pub fn new(config: Config, terminal: T) -> Self {
         Self {
             config,
             terminal,
+            policy_state: PolicyState::in_memory(),
+            audit_log: AuditLog::in_memory(),
             inner: Default::default(),
         }
     }
 
+    /// Use the given [`PolicyState`] to enforce the stateful policies of the wrapped
+    /// service.
+    pub fn with_policy_state(self, policy_state: PolicyState) -> Self {
+        Self {
+            policy_state,
+            ..self
+        }
+    }
+
+    /// Record every transaction authorization decision of the wrapped service in the
+    /// given [`AuditLog`].
+    pub fn with_audit_log(self, audit_log: AuditLog) -> Self {
+        Self { audit_log, ..self }
+    }
+
     async fn get_inner(&self) -> Result<&dyn pb::custody_service_server::CustodyService, Status> {
         Ok(self
             .inner
@@ -214,10 +237,15 @@ impl<T: Terminal + Clone + Send + Sync + 'static> Encrypted<T> {
 
                 let inner = self.config.clone().decrypt(&password)?;
                 let out: Box<dyn pb::custody_service_server::CustodyService> = match inner {
-                    InnerConfig::SoftKms(c) => Box::new(soft_kms::SoftKms::new(c)),
-                    InnerConfig::Threshold(c) => {
-                        Box::new(threshold::Threshold::new(c, self.terminal.clone()))
-                    }
+                    InnerConfig::SoftKms(c) => Box::new(
+                        soft_kms::SoftKms::new(c)
+                            .with_policy_state(self.policy_state.clone())
+                            .with_audit_log(self.audit_log.clone()),
+                    ),
+                    InnerConfig::Threshold(c) => Box::new(
+                        threshold::Threshold::new(c, self.terminal.clone())
+                            .with_audit_log(self.audit_log.clone()),
+                    ),
                 };
                 Ok(out)
             })
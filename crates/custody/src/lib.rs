@@ -15,6 +15,7 @@ mod pre_auth;
 mod request;
 mod terminal;
 
+pub mod audit;
 pub mod encrypted;
 pub mod null_kms;
 pub mod policy;
@@ -22,7 +23,7 @@ pub mod soft_kms;
 pub mod threshold;
 
 pub use client::CustodyClient;
-pub use pre_auth::PreAuthorization;
+pub use pre_auth::{PreAuthorization, PreAuthorizationKey};
 pub use request::{
     AuthorizeRequest, AuthorizeValidatorDefinitionRequest, AuthorizeValidatorVoteRequest,
 };
@@ -1,7 +1,5 @@
 //! A set of basic spend authorization policies.
 
-use std::collections::HashSet;
-
 use penumbra_keys::Address;
 use penumbra_proto::{
     core::{
@@ -13,14 +11,20 @@ use penumbra_proto::{
     },
     Message as _,
 };
-use penumbra_transaction::plan::ActionPlan;
+use penumbra_transaction::TransactionPlan;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     AuthorizeRequest, AuthorizeValidatorDefinitionRequest, AuthorizeValidatorVoteRequest,
-    PreAuthorization,
+    PreAuthorization, PreAuthorizationKey,
 };
 
+mod action_kind;
+mod spend_limit;
+
+pub use action_kind::ActionKind;
+pub use spend_limit::{AuthorizedOutflow, PolicyState, SpendLimitPolicy};
+
 /// A trait for checking whether a transaction plan is allowed by a policy.
 pub trait Policy {
     /// Checks whether the proposed transaction plan is allowed by this policy.
@@ -36,12 +40,43 @@ pub trait Policy {
     fn check_validator_vote(&self, _request: &AuthorizeValidatorVoteRequest) -> anyhow::Result<()>;
 }
 
+/// The error returned when a policy doesn't allow a well-formed request.
+///
+/// Policies can fail for other reasons, such as a pre-authorization whose signature
+/// doesn't verify; those errors aren't rejections, and [`AuthPolicy::Not`] doesn't
+/// negate them.
+#[derive(Clone, Debug)]
+pub struct PolicyRejection(String);
+
+impl PolicyRejection {
+    /// Returns whether the error is a policy rejection, rather than some other failure.
+    pub fn is_rejection(error: &anyhow::Error) -> bool {
+        error.downcast_ref::<PolicyRejection>().is_some()
+    }
+}
+
+impl std::fmt::Display for PolicyRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for PolicyRejection {}
+
+fn reject(message: String) -> anyhow::Error {
+    PolicyRejection(message).into()
+}
+
 /// A set of basic spend authorization policies.
 ///
 /// These policies are intended to be simple enough that they can be written by hand in a config
 /// file.  More complex policy logic than should be implemented by a custom implementation of
 /// the [`Policy`] trait.
 ///
+/// Policies can be combined with [`AuthPolicy::AllOf`], [`AuthPolicy::AnyOf`] and
+/// [`AuthPolicy::Not`].  A list of policies in a config file must all allow a request,
+/// as if they were wrapped in an `AllOf`.
+///
 /// These policies do not permit validator votes or validator definition updates, so a custom policy
 /// must be used to approve these actions.
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -53,14 +88,63 @@ pub enum AuthPolicy {
         #[serde(with = "address_as_string")]
         allowed_destination_addresses: Vec<Address>,
     },
+    /// Only allow transactions whose actions are all of the listed kinds.
+    ActionAllowList { actions: Vec<ActionKind> },
     /// Intended for relayers, only allows `Spend`, `Output`, and `IbcAction`
     /// actions in transactions.
     ///
-    /// This policy should be combined with an `AllowList` to prevent sending
-    /// funds outside of the relayer account.
+    /// This is equivalent to an `ActionAllowList` of those actions, which should be
+    /// preferred in new configs.  It should be combined with a `DestinationAllowList`
+    /// to prevent sending funds outside of the relayer account.
     OnlyIbcRelay,
     /// Require specific pre-authorizations for submitted [`TransactionPlan`](penumbra_transaction::TransactionPlan)s.
     PreAuthorization(PreAuthorizationPolicy),
+    /// Limit the amount of an asset that can leave the wallet over a rolling window of time.
+    ///
+    /// Unlike the other policies, this one depends on previously authorized requests,
+    /// so it is only enforced by custody services that track a [`PolicyState`].
+    SpendLimit(SpendLimitPolicy),
+    /// Allow requests that are allowed by every one of the policies.
+    ///
+    /// An empty list allows every request.
+    AllOf { policies: Vec<AuthPolicy> },
+    /// Allow requests that are allowed by at least one of the policies.
+    ///
+    /// An empty list allows no requests.  Spend limits can't be used inside this
+    /// policy, since whether they apply would depend on which alternative matched.
+    AnyOf { policies: Vec<AuthPolicy> },
+    /// Allow requests that are rejected by the policy.
+    ///
+    /// Only [`PolicyRejection`]s are negated: if the policy fails for any other
+    /// reason, such as an invalid pre-authorization, the request is denied.
+    /// Spend limits can't be used inside this policy.
+    Not { policy: Box<AuthPolicy> },
+}
+
+impl AuthPolicy {
+    /// The spend limits that apply to every request allowed by this policy.
+    ///
+    /// These are the spend limits that aren't nested inside of an `AnyOf` or `Not`.
+    pub fn spend_limits(&self) -> Vec<&SpendLimitPolicy> {
+        match self {
+            AuthPolicy::SpendLimit(limit) => vec![limit],
+            AuthPolicy::AllOf { policies } => {
+                policies.iter().flat_map(AuthPolicy::spend_limits).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn contains_spend_limit(&self) -> bool {
+        match self {
+            AuthPolicy::SpendLimit(_) => true,
+            AuthPolicy::AllOf { policies } | AuthPolicy::AnyOf { policies } => {
+                policies.iter().any(AuthPolicy::contains_spend_limit)
+            }
+            AuthPolicy::Not { policy } => policy.contains_spend_limit(),
+            _ => false,
+        }
+    }
 }
 
 /// A set of pre-authorization policies.
@@ -81,51 +165,113 @@ pub enum PreAuthorizationPolicy {
         #[serde(with = "ed25519_vec_base64")]
         allowed_signers: Vec<ed25519_consensus::VerificationKey>,
     },
+    /// ECDSA signatures over the NIST P-256 curve.
+    EcdsaP256 {
+        /// The number of distinct pre-authorizations required to authorize a transaction plan.
+        ///
+        /// Each `allowed_signer`'s contributions count only once towards this total.
+        required_signatures: u32,
+        /// A list of SEC1-encoded pre-authorization keys that can be used to authorize a
+        /// transaction plan.
+        #[serde(with = "sec1_vec_base64")]
+        allowed_signers: Vec<p256::ecdsa::VerifyingKey>,
+    },
+    /// ECDSA signatures over the secp256k1 curve.
+    EcdsaSecp256k1 {
+        /// The number of distinct pre-authorizations required to authorize a transaction plan.
+        ///
+        /// Each `allowed_signer`'s contributions count only once towards this total.
+        required_signatures: u32,
+        /// A list of SEC1-encoded pre-authorization keys that can be used to authorize a
+        /// transaction plan.
+        #[serde(with = "sec1_vec_base64")]
+        allowed_signers: Vec<k256::ecdsa::VerifyingKey>,
+    },
+    /// A quorum of signers which may each use a different signature scheme.
+    ///
+    /// In toml, each signer is written as an inline table, e.g.
+    /// `{ scheme = 'EcdsaP256', key = '<base64>' }`.
+    Quorum {
+        /// The number of distinct pre-authorizations required to authorize a transaction plan.
+        ///
+        /// Each `allowed_signer`'s contributions count only once towards this total.
+        required_signatures: u32,
+        /// A list of pre-authorization keys that can be used to authorize a transaction plan.
+        allowed_signers: Vec<PreAuthorizationKey>,
+    },
 }
 
 impl PreAuthorizationPolicy {
+    /// The number of required signatures, and the keys allowed to provide them.
+    fn quorum(&self) -> (u32, Vec<PreAuthorizationKey>) {
+        match self {
+            PreAuthorizationPolicy::Ed25519 {
+                required_signatures,
+                allowed_signers,
+            } => (
+                *required_signatures,
+                allowed_signers
+                    .iter()
+                    .copied()
+                    .map(PreAuthorizationKey::Ed25519)
+                    .collect(),
+            ),
+            PreAuthorizationPolicy::EcdsaP256 {
+                required_signatures,
+                allowed_signers,
+            } => (
+                *required_signatures,
+                allowed_signers
+                    .iter()
+                    .copied()
+                    .map(PreAuthorizationKey::EcdsaP256)
+                    .collect(),
+            ),
+            PreAuthorizationPolicy::EcdsaSecp256k1 {
+                required_signatures,
+                allowed_signers,
+            } => (
+                *required_signatures,
+                allowed_signers
+                    .iter()
+                    .copied()
+                    .map(PreAuthorizationKey::EcdsaSecp256k1)
+                    .collect(),
+            ),
+            PreAuthorizationPolicy::Quorum {
+                required_signatures,
+                allowed_signers,
+            } => (*required_signatures, allowed_signers.clone()),
+        }
+    }
+
     fn check_pre_authorizations(
         &self,
         pre_authorizations: &[PreAuthorization],
         signed_data: impl AsRef<[u8]>,
     ) -> anyhow::Result<()> {
         let signed_data = signed_data.as_ref();
-        match self {
-            PreAuthorizationPolicy::Ed25519 {
-                required_signatures,
-                allowed_signers,
-            } => {
-                #[allow(clippy::unnecessary_filter_map)]
-                let ed25519_pre_auths =
-                    pre_authorizations
-                        .iter()
-                        .filter_map(|pre_auth| match pre_auth {
-                            PreAuthorization::Ed25519(pre_auth) => Some(pre_auth),
-                            // _ => None,
-                        });
-
-                let mut allowed_signers = allowed_signers.iter().cloned().collect::<HashSet<_>>();
-                let mut seen_signers = HashSet::new();
-
-                for pre_auth in ed25519_pre_auths {
-                    // Remove the signer from the allowed signers set, so that
-                    // each signer can only submit one pre-authorization.
-                    if let Some(signer) = allowed_signers.take(&pre_auth.vk) {
-                        pre_auth.verify(signed_data)?;
-                        seen_signers.insert(signer);
-                    }
-                }
+        let (required_signatures, mut allowed_signers) = self.quorum();
+        let mut seen_signers = 0usize;
 
-                if seen_signers.len() < *required_signatures as usize {
-                    anyhow::bail!(
-                        "required {} pre-authorization signatures but only saw {}",
-                        required_signatures,
-                        seen_signers.len(),
-                    );
-                }
-                Ok(())
+        for pre_auth in pre_authorizations {
+            // Remove the signer from the allowed signers, so that
+            // each signer can only submit one pre-authorization.
+            let key = pre_auth.key();
+            if let Some(index) = allowed_signers.iter().position(|signer| *signer == key) {
+                allowed_signers.swap_remove(index);
+                pre_auth.verify(signed_data)?;
+                seen_signers += 1;
             }
         }
+
+        if seen_signers < required_signatures as usize {
+            return Err(reject(format!(
+                "required {} pre-authorization signatures but only saw {}",
+                required_signatures, seen_signers,
+            )));
+        }
+        Ok(())
     }
 }
 
@@ -200,6 +346,65 @@ mod ed25519_vec_base64 {
     }
 }
 
+/// A serde helper to serialize SEC1-encoded ECDSA keys as base64-encoded data,
+/// matching the encoding used for Ed25519 keys.
+mod sec1_vec_base64 {
+    use base64::prelude::*;
+
+    pub trait Sec1Key: Sized {
+        fn to_sec1_bytes(&self) -> Vec<u8>;
+        fn from_sec1_bytes(bytes: &[u8]) -> anyhow::Result<Self>;
+    }
+
+    impl Sec1Key for p256::ecdsa::VerifyingKey {
+        fn to_sec1_bytes(&self) -> Vec<u8> {
+            self.to_encoded_point(true).as_bytes().to_vec()
+        }
+        fn from_sec1_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+            p256::ecdsa::VerifyingKey::from_sec1_bytes(bytes)
+                .map_err(|_| anyhow::anyhow!("invalid P-256 verification key"))
+        }
+    }
+
+    impl Sec1Key for k256::ecdsa::VerifyingKey {
+        fn to_sec1_bytes(&self) -> Vec<u8> {
+            self.to_encoded_point(true).as_bytes().to_vec()
+        }
+        fn from_sec1_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+            k256::ecdsa::VerifyingKey::from_sec1_bytes(bytes)
+                .map_err(|_| anyhow::anyhow!("invalid secp256k1 verification key"))
+        }
+    }
+
+    pub fn serialize<K: Sec1Key, S: serde::Serializer>(
+        keys: &[K],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use serde::Serialize;
+        let mut base64_keys = Vec::with_capacity(keys.len());
+        for key in keys {
+            base64_keys.push(BASE64_STANDARD.encode(key.to_sec1_bytes()));
+        }
+        base64_keys.serialize(serializer)
+    }
+    pub fn deserialize<'de, K: Sec1Key, D>(deserializer: D) -> Result<Vec<K>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::Deserialize;
+        let base64_keys: Vec<String> = Vec::deserialize(deserializer)?;
+        let mut vks = Vec::with_capacity(base64_keys.len());
+        for base64_key in base64_keys {
+            let bytes = BASE64_STANDARD
+                .decode(base64_key)
+                .map_err(serde::de::Error::custom)?;
+            let vk = K::from_sec1_bytes(&bytes).map_err(serde::de::Error::custom)?;
+            vks.push(vk);
+        }
+        Ok(vks)
+    }
+}
+
 impl Policy for AuthPolicy {
     fn check_transaction(&self, request: &AuthorizeRequest) -> anyhow::Result<()> {
         let plan = &request.plan;
@@ -209,30 +414,73 @@ impl Policy for AuthPolicy {
             } => {
                 for output in plan.output_plans() {
                     if !allowed_destination_addresses.contains(&output.dest_address) {
-                        anyhow::bail!("output {:?} has dest_address not in allow list", output);
+                        return Err(reject(format!(
+                            "output {:?} has dest_address not in allow list",
+                            output
+                        )));
                     }
                 }
                 for swap in plan.swap_plans() {
                     if !allowed_destination_addresses.contains(&swap.swap_plaintext.claim_address) {
-                        anyhow::bail!("swap {:?} has claim_address not in allow list", swap);
+                        return Err(reject(format!(
+                            "swap {:?} has claim_address not in allow list",
+                            swap
+                        )));
                     }
                 }
                 Ok(())
             }
-            AuthPolicy::OnlyIbcRelay => {
-                for action in &plan.actions {
-                    match action {
-                        ActionPlan::Spend { .. }
-                        | ActionPlan::Output { .. }
-                        | ActionPlan::IbcAction { .. } => {}
-                        _ => {
-                            anyhow::bail!("action {:?} not allowed by OnlyRelay policy", action);
+            AuthPolicy::ActionAllowList { actions } => check_actions(plan, actions),
+            AuthPolicy::OnlyIbcRelay => check_actions(plan, ActionKind::IBC_RELAY),
+            AuthPolicy::PreAuthorization(policy) => policy.check_transaction(request),
+            // Spend limits can't be checked without knowing what was authorized before,
+            // so they're enforced separately, by `PolicyState::check_and_record`.
+            AuthPolicy::SpendLimit(_) => Ok(()),
+            AuthPolicy::AllOf { policies } => {
+                for policy in policies {
+                    policy.check_transaction(request)?;
+                }
+                Ok(())
+            }
+            AuthPolicy::AnyOf { policies } => {
+                if self.contains_spend_limit() {
+                    anyhow::bail!("spend limits cannot be used inside an AnyOf policy");
+                }
+                let mut errors = Vec::with_capacity(policies.len());
+                let mut all_rejections = true;
+                for policy in policies {
+                    match policy.check_transaction(request) {
+                        Ok(()) => return Ok(()),
+                        Err(e) => {
+                            all_rejections &= PolicyRejection::is_rejection(&e);
+                            errors.push(format!("{e:#}"));
                         }
                     }
                 }
-                Ok(())
+                let message = format!(
+                    "request not allowed by any of the alternative policies: [{}]",
+                    errors.join("; ")
+                );
+                // If any alternative failed for another reason, so does this policy.
+                if all_rejections {
+                    Err(reject(message))
+                } else {
+                    Err(anyhow::anyhow!(message))
+                }
+            }
+            AuthPolicy::Not { policy } => {
+                if policy.contains_spend_limit() {
+                    anyhow::bail!("spend limits cannot be used inside a Not policy");
+                }
+                match policy.check_transaction(request) {
+                    Ok(()) => Err(reject(format!(
+                        "request is allowed by negated policy {:?}",
+                        policy
+                    ))),
+                    Err(e) if PolicyRejection::is_rejection(&e) => Ok(()),
+                    Err(e) => Err(e),
+                }
             }
-            AuthPolicy::PreAuthorization(policy) => policy.check_transaction(request),
         }
     }
 
@@ -248,6 +496,19 @@ impl Policy for AuthPolicy {
     }
 }
 
+fn check_actions(plan: &TransactionPlan, allowed: &[ActionKind]) -> anyhow::Result<()> {
+    for action in &plan.actions {
+        let kind = ActionKind::of(action);
+        if !allowed.contains(&kind) {
+            return Err(reject(format!(
+                "action {:?} not allowed by action allow list",
+                kind
+            )));
+        }
+    }
+    Ok(())
+}
+
 impl Policy for PreAuthorizationPolicy {
     fn check_transaction(&self, request: &AuthorizeRequest) -> anyhow::Result<()> {
         self.check_pre_authorizations(
@@ -273,3 +534,311 @@ impl Policy for PreAuthorizationPolicy {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use p256::ecdsa::signature::Signer as _;
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_governance::ProposalWithdraw;
+    use penumbra_keys::{
+        keys::{Bip44Path, SeedPhrase, SpendKey},
+        test_keys,
+    };
+    use penumbra_proto::{custody::v1 as pb, DomainType};
+    use penumbra_shielded_pool::{Note, OutputPlan, SpendPlan};
+    use penumbra_transaction::plan::ActionPlan;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::pre_auth;
+
+    const MESSAGE: &[u8] = b"transaction plan";
+
+    struct Signers {
+        ed25519: ed25519_consensus::SigningKey,
+        p256: p256::ecdsa::SigningKey,
+        k256: k256::ecdsa::SigningKey,
+    }
+
+    impl Signers {
+        fn generate() -> Self {
+            Self {
+                ed25519: ed25519_consensus::SigningKey::new(rand_core::OsRng),
+                p256: p256::ecdsa::SigningKey::random(&mut rand_core::OsRng),
+                k256: k256::ecdsa::SigningKey::random(&mut rand_core::OsRng),
+            }
+        }
+
+        fn keys(&self) -> Vec<PreAuthorizationKey> {
+            vec![
+                PreAuthorizationKey::Ed25519(self.ed25519.verification_key()),
+                PreAuthorizationKey::EcdsaP256(*self.p256.verifying_key()),
+                PreAuthorizationKey::EcdsaSecp256k1(*self.k256.verifying_key()),
+            ]
+        }
+
+        fn ed25519(&self, message: &[u8]) -> PreAuthorization {
+            PreAuthorization::Ed25519(pre_auth::Ed25519 {
+                vk: self.ed25519.verification_key(),
+                sig: self.ed25519.sign(message),
+            })
+        }
+
+        fn p256(&self, message: &[u8]) -> PreAuthorization {
+            PreAuthorization::EcdsaP256(pre_auth::EcdsaP256 {
+                vk: *self.p256.verifying_key(),
+                sig: self.p256.sign(message),
+            })
+        }
+
+        fn k256(&self, message: &[u8]) -> PreAuthorization {
+            PreAuthorization::EcdsaSecp256k1(pre_auth::EcdsaSecp256k1 {
+                vk: *self.k256.verifying_key(),
+                sig: self.k256.sign(message),
+            })
+        }
+    }
+
+    #[test]
+    fn mixed_scheme_quorum() -> anyhow::Result<()> {
+        let signers = Signers::generate();
+        let policy = PreAuthorizationPolicy::Quorum {
+            required_signatures: 2,
+            allowed_signers: signers.keys(),
+        };
+
+        policy
+            .check_pre_authorizations(&[signers.p256(MESSAGE), signers.k256(MESSAGE)], MESSAGE)?;
+        policy.check_pre_authorizations(
+            &[signers.ed25519(MESSAGE), signers.k256(MESSAGE)],
+            MESSAGE,
+        )?;
+        // A single signer only counts once, however many times it signs.
+        assert!(policy
+            .check_pre_authorizations(&[signers.p256(MESSAGE), signers.p256(MESSAGE)], MESSAGE)
+            .is_err());
+        // Signatures over anything else are rejected.
+        assert!(policy
+            .check_pre_authorizations(
+                &[signers.ed25519(MESSAGE), signers.k256(b"something else")],
+                MESSAGE
+            )
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn single_scheme_policies_ignore_other_schemes() -> anyhow::Result<()> {
+        let signers = Signers::generate();
+        let policy = PreAuthorizationPolicy::EcdsaP256 {
+            required_signatures: 1,
+            allowed_signers: vec![*signers.p256.verifying_key()],
+        };
+
+        policy.check_pre_authorizations(
+            &[signers.ed25519(MESSAGE), signers.p256(MESSAGE)],
+            MESSAGE,
+        )?;
+        assert!(policy
+            .check_pre_authorizations(&[signers.ed25519(MESSAGE), signers.k256(MESSAGE)], MESSAGE)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn ecdsa_pre_authorizations_round_trip_through_proto() -> anyhow::Result<()> {
+        let signers = Signers::generate();
+        for pre_auth in [signers.p256(MESSAGE), signers.k256(MESSAGE)] {
+            let decoded = PreAuthorization::decode(pre_auth.encode_to_vec().as_slice())?;
+            assert_eq!(decoded.key(), pre_auth.key());
+            decoded.verify(MESSAGE)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn ecdsa_pre_authorizations_accept_der_signatures() -> anyhow::Result<()> {
+        let signers = Signers::generate();
+        let sig: p256::ecdsa::Signature = signers.p256.sign(MESSAGE);
+        let pre_auth = PreAuthorization::try_from(pb::PreAuthorization {
+            pre_authorization: Some(pb::pre_authorization::PreAuthorization::EcdsaP256(
+                pb::pre_authorization::EcdsaP256 {
+                    vk: PreAuthorizationKey::EcdsaP256(*signers.p256.verifying_key()).to_bytes(),
+                    sig: sig.to_der().as_bytes().to_vec(),
+                },
+            )),
+        })?;
+        pre_auth.verify(MESSAGE)
+    }
+
+    fn other_address() -> Address {
+        SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(OsRng), &Bip44Path::new(0))
+            .incoming_viewing_key()
+            .payment_address(Default::default())
+            .0
+    }
+
+    /// A request to send 100 upenumbra to `dest`.
+    fn payment(dest: Address) -> AuthorizeRequest {
+        let value = Value {
+            amount: 100u64.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        };
+        let note = Note::generate(&mut OsRng, &test_keys::ADDRESS_0, value);
+        AuthorizeRequest {
+            plan: TransactionPlan {
+                actions: vec![
+                    ActionPlan::Spend(SpendPlan::new(&mut OsRng, note, 0u64.into())),
+                    ActionPlan::Output(OutputPlan::new(&mut OsRng, value, dest)),
+                ],
+                ..Default::default()
+            },
+            pre_authorizations: Vec::new(),
+        }
+    }
+
+    fn proposal_withdraw() -> AuthorizeRequest {
+        AuthorizeRequest {
+            plan: TransactionPlan {
+                actions: vec![ActionPlan::ProposalWithdraw(ProposalWithdraw {
+                    proposal: 1,
+                    reason: "test".to_string(),
+                })],
+                ..Default::default()
+            },
+            pre_authorizations: Vec::new(),
+        }
+    }
+
+    /// Adds the pre-authorizations made by `sign` over the request's plan.
+    fn pre_authorize(
+        mut request: AuthorizeRequest,
+        sign: impl Fn(&[u8]) -> Vec<PreAuthorization>,
+    ) -> AuthorizeRequest {
+        let message = ProtoTransactionPlan::from(request.plan.clone()).encode_to_vec();
+        request.pre_authorizations = sign(&message);
+        request
+    }
+
+    #[test]
+    fn action_allow_list() {
+        let policy = AuthPolicy::ActionAllowList {
+            actions: ActionKind::IBC_RELAY.to_vec(),
+        };
+        assert!(policy.check_transaction(&payment(other_address())).is_ok());
+        assert!(policy.check_transaction(&proposal_withdraw()).is_err());
+        // The legacy policy behaves the same way.
+        assert!(AuthPolicy::OnlyIbcRelay
+            .check_transaction(&proposal_withdraw())
+            .is_err());
+    }
+
+    #[test]
+    fn any_of_alternatives() {
+        let signers = Signers::generate();
+        let allowed = test_keys::ADDRESS_1.clone();
+        // Either withdraw a proposal, or pay an allowed address with 2-of-3 pre-authorizations.
+        let policy = AuthPolicy::AnyOf {
+            policies: vec![
+                AuthPolicy::ActionAllowList {
+                    actions: vec![ActionKind::ProposalWithdraw],
+                },
+                AuthPolicy::AllOf {
+                    policies: vec![
+                        AuthPolicy::DestinationAllowList {
+                            allowed_destination_addresses: vec![allowed.clone()],
+                        },
+                        AuthPolicy::PreAuthorization(PreAuthorizationPolicy::Quorum {
+                            required_signatures: 2,
+                            allowed_signers: signers.keys(),
+                        }),
+                    ],
+                },
+            ],
+        };
+
+        let quorum = |m: &[u8]| vec![signers.p256(m), signers.k256(m)];
+        let single = |m: &[u8]| vec![signers.ed25519(m)];
+
+        assert!(policy.check_transaction(&proposal_withdraw()).is_ok());
+        let request = pre_authorize(payment(allowed.clone()), quorum);
+        assert!(policy.check_transaction(&request).is_ok());
+        let request = pre_authorize(payment(allowed), single);
+        assert!(policy.check_transaction(&request).is_err());
+        let request = pre_authorize(payment(other_address()), quorum);
+        assert!(policy.check_transaction(&request).is_err());
+        assert!(AuthPolicy::AnyOf { policies: vec![] }
+            .check_transaction(&proposal_withdraw())
+            .is_err());
+    }
+
+    #[test]
+    fn not_inverts_policy() {
+        let policy = AuthPolicy::Not {
+            policy: Box::new(AuthPolicy::ActionAllowList {
+                actions: vec![ActionKind::ProposalWithdraw],
+            }),
+        };
+        assert!(policy.check_transaction(&proposal_withdraw()).is_err());
+        assert!(policy.check_transaction(&payment(other_address())).is_ok());
+    }
+
+    #[test]
+    fn not_only_negates_rejections() {
+        let signers = Signers::generate();
+        let pre_authorization = AuthPolicy::PreAuthorization(PreAuthorizationPolicy::EcdsaP256 {
+            required_signatures: 1,
+            allowed_signers: vec![*signers.p256.verifying_key()],
+        });
+        let policy = AuthPolicy::Not {
+            policy: Box::new(pre_authorization.clone()),
+        };
+
+        // A request without pre-authorizations is rejected by the inner policy.
+        assert!(policy.check_transaction(&payment(other_address())).is_ok());
+        let request = pre_authorize(payment(other_address()), |m| vec![signers.p256(m)]);
+        let error = policy.check_transaction(&request).unwrap_err();
+        assert!(PolicyRejection::is_rejection(&error));
+        // A pre-authorization that doesn't verify is an error, not a rejection.
+        let request = pre_authorize(payment(other_address()), |_| {
+            vec![signers.p256(b"something else")]
+        });
+        let error = policy.check_transaction(&request).unwrap_err();
+        assert!(!PolicyRejection::is_rejection(&error));
+        // The same holds when the failure is one of several alternatives.
+        let policy = AuthPolicy::Not {
+            policy: Box::new(AuthPolicy::AnyOf {
+                policies: vec![
+                    AuthPolicy::ActionAllowList {
+                        actions: vec![ActionKind::ProposalWithdraw],
+                    },
+                    pre_authorization,
+                ],
+            }),
+        };
+        assert!(policy.check_transaction(&payment(other_address())).is_ok());
+        assert!(policy.check_transaction(&request).is_err());
+    }
+
+    #[test]
+    fn spend_limits_only_combine_with_all_of() {
+        let limit = SpendLimitPolicy {
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+            limit: 1_000,
+            window_seconds: 60,
+        };
+        let all_of = AuthPolicy::AllOf {
+            policies: vec![
+                AuthPolicy::OnlyIbcRelay,
+                AuthPolicy::SpendLimit(limit.clone()),
+            ],
+        };
+        assert_eq!(all_of.spend_limits(), vec![&limit]);
+
+        let any_of = AuthPolicy::AnyOf {
+            policies: vec![all_of, AuthPolicy::OnlyIbcRelay],
+        };
+        assert!(any_of.spend_limits().is_empty());
+        assert!(any_of.check_transaction(&payment(other_address())).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+//! Names for the kinds of actions a transaction plan can contain.
+
+use penumbra_transaction::plan::ActionPlan;
+use serde::{Deserialize, Serialize};
+
+/// The kind of an [`ActionPlan`], without any of its contents.
+///
+/// These are named after the variants of [`ActionPlan`], so that they can be listed
+/// by name in an [`AuthPolicy::ActionAllowList`](super::AuthPolicy::ActionAllowList).
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ActionKind {
+    Spend,
+    Output,
+    Delegate,
+    Undelegate,
+    UndelegateClaim,
+    ValidatorDefinition,
+    Swap,
+    SwapClaim,
+    IbcAction,
+    ProposalSubmit,
+    ProposalWithdraw,
+    DelegatorVote,
+    ValidatorVote,
+    ProposalDepositClaim,
+    PositionOpen,
+    PositionClose,
+    PositionWithdraw,
+    CommunityPoolSpend,
+    CommunityPoolOutput,
+    CommunityPoolDeposit,
+    Ics20Withdrawal,
+    ActionDutchAuctionSchedule,
+    ActionDutchAuctionEnd,
+    ActionDutchAuctionWithdraw,
+}
+
+impl ActionKind {
+    /// The actions needed to relay IBC packets.
+    pub const IBC_RELAY: &'static [ActionKind] =
+        &[ActionKind::Spend, ActionKind::Output, ActionKind::IbcAction];
+
+    /// The kind of the given action.
+    pub fn of(action: &ActionPlan) -> Self {
+        match action {
+            ActionPlan::Spend(_) => ActionKind::Spend,
+            ActionPlan::Output(_) => ActionKind::Output,
+            ActionPlan::Delegate(_) => ActionKind::Delegate,
+            ActionPlan::Undelegate(_) => ActionKind::Undelegate,
+            ActionPlan::UndelegateClaim(_) => ActionKind::UndelegateClaim,
+            ActionPlan::ValidatorDefinition(_) => ActionKind::ValidatorDefinition,
+            ActionPlan::Swap(_) => ActionKind::Swap,
+            ActionPlan::SwapClaim(_) => ActionKind::SwapClaim,
+            ActionPlan::IbcAction(_) => ActionKind::IbcAction,
+            ActionPlan::ProposalSubmit(_) => ActionKind::ProposalSubmit,
+            ActionPlan::ProposalWithdraw(_) => ActionKind::ProposalWithdraw,
+            ActionPlan::DelegatorVote(_) => ActionKind::DelegatorVote,
+            ActionPlan::ValidatorVote(_) => ActionKind::ValidatorVote,
+            ActionPlan::ProposalDepositClaim(_) => ActionKind::ProposalDepositClaim,
+            ActionPlan::PositionOpen(_) => ActionKind::PositionOpen,
+            ActionPlan::PositionClose(_) => ActionKind::PositionClose,
+            ActionPlan::PositionWithdraw(_) => ActionKind::PositionWithdraw,
+            ActionPlan::CommunityPoolSpend(_) => ActionKind::CommunityPoolSpend,
+            ActionPlan::CommunityPoolOutput(_) => ActionKind::CommunityPoolOutput,
+            ActionPlan::CommunityPoolDeposit(_) => ActionKind::CommunityPoolDeposit,
+            ActionPlan::Ics20Withdrawal(_) => ActionKind::Ics20Withdrawal,
+            ActionPlan::ActionDutchAuctionSchedule(_) => ActionKind::ActionDutchAuctionSchedule,
+            ActionPlan::ActionDutchAuctionEnd(_) => ActionKind::ActionDutchAuctionEnd,
+            ActionPlan::ActionDutchAuctionWithdraw(_) => ActionKind::ActionDutchAuctionWithdraw,
+        }
+    }
+}
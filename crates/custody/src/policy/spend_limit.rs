@@ -0,0 +1,372 @@
+//! A stateful policy limiting how much of an asset can leave the wallet over time.
+
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context as _;
+use penumbra_asset::asset;
+use penumbra_keys::FullViewingKey;
+use penumbra_transaction::TransactionPlan;
+use serde::{Deserialize, Serialize};
+use serde_with::DisplayFromStr;
+
+use super::AuthPolicy;
+use crate::AuthorizeRequest;
+
+/// Limits the total amount of a single asset that may leave the wallet within a
+/// rolling window of time.
+///
+/// The outflow of a plan is the value of the notes of the limited asset it spends,
+/// minus the value it returns to the wallet's own addresses as outputs.  Everything
+/// else, including payments to other addresses, swap inputs, liquidity provision,
+/// fees and IBC withdrawals, counts towards the limit.
+///
+/// This policy can only be enforced by a custody service that keeps a
+/// [`PolicyState`] recording the outflows it has already authorized.
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SpendLimitPolicy {
+    /// The asset whose outflow is limited.
+    #[serde_as(as = "DisplayFromStr")]
+    pub asset_id: asset::Id,
+    /// The maximum amount of the asset, in base units, that may leave the wallet
+    /// within any window.
+    #[serde_as(as = "DisplayFromStr")]
+    pub limit: u128,
+    /// The length of the rolling window, in seconds.
+    pub window_seconds: u64,
+}
+
+impl SpendLimitPolicy {
+    /// Computes the amount of the limited asset that `plan` moves out of the wallet
+    /// controlled by `fvk`.
+    pub fn outflow(&self, fvk: &FullViewingKey, plan: &TransactionPlan) -> u128 {
+        let spent = plan
+            .spend_plans()
+            .map(|spend| spend.note.value())
+            .filter(|value| value.asset_id == self.asset_id)
+            .fold(0u128, |acc, value| acc.saturating_add(value.amount.value()));
+
+        let returned = plan
+            .output_plans()
+            .filter(|output| output.value.asset_id == self.asset_id)
+            .filter(|output| fvk.address_index(&output.dest_address).is_some())
+            .fold(0u128, |acc, output| {
+                acc.saturating_add(output.value.amount.value())
+            });
+
+        spent.saturating_sub(returned)
+    }
+}
+
+/// A record of an outflow authorized by a custody service.
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct AuthorizedOutflow {
+    /// The time the outflow was authorized, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The asset that left the wallet.
+    #[serde_as(as = "DisplayFromStr")]
+    pub asset_id: asset::Id,
+    /// The amount of the asset that left the wallet, in base units.
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: u128,
+}
+
+/// The state needed to enforce stateful policies such as [`SpendLimitPolicy`].
+///
+/// The state is a ledger of the outflows authorized within the longest configured
+/// window.  It is held in memory and, if a path is provided, persisted to disk after
+/// every authorization, so that limits survive a restart of the custody service.
+/// Clones share the same ledger.
+#[derive(Clone, Debug, Default)]
+pub struct PolicyState {
+    ledger: Arc<Mutex<Vec<AuthorizedOutflow>>>,
+    path: Option<PathBuf>,
+}
+
+impl PolicyState {
+    /// Create an empty policy state that is only held in memory.
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Load the policy state persisted at `path`, or create an empty one if there is
+    /// no file there yet.
+    pub fn load_or_initialize(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+        let ledger = if path.exists() {
+            let contents = std::fs::read(&path)
+                .with_context(|| format!("failed to read policy state from {}", path.display()))?;
+            serde_json::from_slice(&contents)
+                .with_context(|| format!("failed to parse policy state in {}", path.display()))?
+        } else {
+            Vec::new()
+        };
+        Ok(Self {
+            ledger: Arc::new(Mutex::new(ledger)),
+            path: Some(path),
+        })
+    }
+
+    /// Check the request against every [`AuthPolicy::SpendLimit`] in `policies`,
+    /// including those nested in an [`AuthPolicy::AllOf`], and if it is allowed by all
+    /// of them, call `authorize` and record the request's outflows.
+    ///
+    /// The outflows are only recorded if `authorize` succeeds, so a request that fails
+    /// to be authorized doesn't use up any of the limit.  The check, the authorization
+    /// and the update happen atomically, so concurrent requests cannot jointly exceed
+    /// a limit.
+    pub fn check_and_record<T>(
+        &self,
+        policies: &[AuthPolicy],
+        fvk: &FullViewingKey,
+        request: &AuthorizeRequest,
+        authorize: impl FnOnce() -> anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .context("system clock is set before the Unix epoch")?
+            .as_secs();
+        self.check_and_record_at(now, policies, fvk, request, authorize)
+    }
+
+    fn check_and_record_at<T>(
+        &self,
+        now: u64,
+        policies: &[AuthPolicy],
+        fvk: &FullViewingKey,
+        request: &AuthorizeRequest,
+        authorize: impl FnOnce() -> anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        let limits = policies
+            .iter()
+            .flat_map(AuthPolicy::spend_limits)
+            .collect::<Vec<_>>();
+        if limits.is_empty() {
+            return authorize();
+        }
+
+        let mut ledger = self
+            .ledger
+            .lock()
+            .map_err(|_| anyhow::anyhow!("policy state lock was poisoned"))?;
+
+        let mut new_outflows = Vec::new();
+        for limit in limits.iter().copied() {
+            let outflow = limit.outflow(fvk, &request.plan);
+            if outflow == 0 {
+                continue;
+            }
+            let window_start = now.saturating_sub(limit.window_seconds);
+            let previous = ledger
+                .iter()
+                .filter(|entry| entry.asset_id == limit.asset_id && entry.timestamp > window_start)
+                .fold(0u128, |acc, entry| acc.saturating_add(entry.amount));
+            if previous.saturating_add(outflow) > limit.limit {
+                anyhow::bail!(
+                    "outflow of {} {} would exceed the limit of {} per {} seconds ({} already authorized in this window)",
+                    outflow,
+                    limit.asset_id,
+                    limit.limit,
+                    limit.window_seconds,
+                    previous,
+                );
+            }
+            // Several limits may apply to the same asset, but its outflow must only be recorded once.
+            if !new_outflows
+                .iter()
+                .any(|entry: &AuthorizedOutflow| entry.asset_id == limit.asset_id)
+            {
+                new_outflows.push(AuthorizedOutflow {
+                    timestamp: now,
+                    asset_id: limit.asset_id,
+                    amount: outflow,
+                });
+            }
+        }
+
+        // Entries that have left every window can no longer affect any decision.
+        let longest_window = limits
+            .iter()
+            .map(|limit| limit.window_seconds)
+            .max()
+            .unwrap_or_default();
+        let oldest_relevant = now.saturating_sub(longest_window);
+        let mut updated = ledger
+            .iter()
+            .filter(|entry| entry.timestamp > oldest_relevant)
+            .cloned()
+            .collect::<Vec<_>>();
+        updated.extend(new_outflows);
+
+        // The lock is held while authorizing, so that no other request can be checked
+        // against a ledger that doesn't yet include this one.
+        let authorized = authorize()?;
+
+        // Persist before updating the in-memory state, so that a failure to write
+        // rejects the request rather than silently forgetting the outflow.
+        if let Some(path) = &self.path {
+            persist(path, &updated)?;
+        }
+        *ledger = updated;
+
+        Ok(authorized)
+    }
+}
+
+fn persist(path: &PathBuf, ledger: &[AuthorizedOutflow]) -> anyhow::Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    std::fs::write(&tmp_path, serde_json::to_vec(ledger)?)
+        .with_context(|| format!("failed to write policy state to {}", tmp_path.display()))?;
+    std::fs::rename(&tmp_path, path)
+        .with_context(|| format!("failed to write policy state to {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_keys::{
+        keys::{Bip44Path, SeedPhrase, SpendKey},
+        test_keys,
+    };
+    use penumbra_shielded_pool::{Note, OutputPlan, SpendPlan};
+    use penumbra_transaction::plan::ActionPlan;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    const DAY: u64 = 24 * 60 * 60;
+
+    /// An authorization that always succeeds.
+    fn ok() -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn daily_limit(amount: u128) -> SpendLimitPolicy {
+        SpendLimitPolicy {
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+            limit: amount,
+            window_seconds: DAY,
+        }
+    }
+
+    /// A request that spends a note of `spent` upenumbra, sending `sent` to another
+    /// wallet and the rest back to ourselves as change.
+    fn payment(spent: u64, sent: u64) -> AuthorizeRequest {
+        let value = |amount: u64| Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        };
+        let own_address = test_keys::ADDRESS_0.clone();
+        let other_address =
+            SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(OsRng), &Bip44Path::new(0))
+                .incoming_viewing_key()
+                .payment_address(Default::default())
+                .0;
+        let note = Note::generate(&mut OsRng, &own_address, value(spent));
+
+        AuthorizeRequest {
+            plan: TransactionPlan {
+                actions: vec![
+                    ActionPlan::Spend(SpendPlan::new(&mut OsRng, note, 0u64.into())),
+                    ActionPlan::Output(OutputPlan::new(&mut OsRng, value(sent), other_address)),
+                    ActionPlan::Output(OutputPlan::new(
+                        &mut OsRng,
+                        value(spent - sent),
+                        own_address,
+                    )),
+                ],
+                ..Default::default()
+            },
+            pre_authorizations: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn change_does_not_count_towards_outflow() {
+        let policy = daily_limit(0);
+        let request = payment(1_000, 300);
+        assert_eq!(
+            policy.outflow(&test_keys::FULL_VIEWING_KEY, &request.plan),
+            300
+        );
+    }
+
+    #[test]
+    fn outflows_accumulate_within_window() -> anyhow::Result<()> {
+        let state = PolicyState::in_memory();
+        let policies = [AuthPolicy::SpendLimit(daily_limit(500))];
+        let fvk = &*test_keys::FULL_VIEWING_KEY;
+
+        state.check_and_record_at(1_000, &policies, fvk, &payment(1_000, 300), ok)?;
+        assert!(state
+            .check_and_record_at(1_001, &policies, fvk, &payment(1_000, 300), ok)
+            .is_err());
+        // The rejected request must not have been recorded.
+        state.check_and_record_at(1_002, &policies, fvk, &payment(1_000, 200), ok)?;
+        assert!(state
+            .check_and_record_at(1_003, &policies, fvk, &payment(1_000, 1), ok)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn outflows_expire_after_window() -> anyhow::Result<()> {
+        let state = PolicyState::in_memory();
+        let policies = [AuthPolicy::SpendLimit(daily_limit(500))];
+        let fvk = &*test_keys::FULL_VIEWING_KEY;
+
+        state.check_and_record_at(1_000, &policies, fvk, &payment(1_000, 500), ok)?;
+        assert!(state
+            .check_and_record_at(1_000 + DAY - 1, &policies, fvk, &payment(1_000, 100), ok)
+            .is_err());
+        state.check_and_record_at(1_000 + DAY, &policies, fvk, &payment(1_000, 500), ok)?;
+        Ok(())
+    }
+
+    #[test]
+    fn failed_authorization_is_not_recorded() -> anyhow::Result<()> {
+        let state = PolicyState::in_memory();
+        let policies = [AuthPolicy::SpendLimit(daily_limit(500))];
+        let fvk = &*test_keys::FULL_VIEWING_KEY;
+
+        assert!(state
+            .check_and_record_at(
+                1_000,
+                &policies,
+                fvk,
+                &payment(1_000, 500),
+                || -> anyhow::Result<()> { anyhow::bail!("authorization failed") }
+            )
+            .is_err());
+        // The whole limit is still available.
+        state.check_and_record_at(1_001, &policies, fvk, &payment(1_000, 500), ok)?;
+        Ok(())
+    }
+
+    #[test]
+    fn state_persists_across_loads() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("policy-state.json");
+        let policies = [AuthPolicy::SpendLimit(daily_limit(500))];
+        let fvk = &*test_keys::FULL_VIEWING_KEY;
+
+        PolicyState::load_or_initialize(&path)?.check_and_record_at(
+            1_000,
+            &policies,
+            fvk,
+            &payment(1_000, 400),
+            ok,
+        )?;
+        let reloaded = PolicyState::load_or_initialize(&path)?;
+        assert!(reloaded
+            .check_and_record_at(1_001, &policies, fvk, &payment(1_000, 200), ok)
+            .is_err());
+        Ok(())
+    }
+}
@@ -9,6 +9,106 @@ use serde::{Deserialize, Serialize};
 #[serde(try_from = "pb::PreAuthorization", into = "pb::PreAuthorization")]
 pub enum PreAuthorization {
     Ed25519(Ed25519),
+    EcdsaP256(EcdsaP256),
+    EcdsaSecp256k1(EcdsaSecp256k1),
+}
+
+impl PreAuthorization {
+    /// The key that made this pre-authorization.
+    pub fn key(&self) -> PreAuthorizationKey {
+        match self {
+            PreAuthorization::Ed25519(pre_auth) => PreAuthorizationKey::Ed25519(pre_auth.vk),
+            PreAuthorization::EcdsaP256(pre_auth) => PreAuthorizationKey::EcdsaP256(pre_auth.vk),
+            PreAuthorization::EcdsaSecp256k1(pre_auth) => {
+                PreAuthorizationKey::EcdsaSecp256k1(pre_auth.vk)
+            }
+        }
+    }
+
+    /// Verifies the pre-authorization over the provided message.
+    pub fn verify(&self, message: impl AsRef<[u8]>) -> anyhow::Result<()> {
+        match self {
+            PreAuthorization::Ed25519(pre_auth) => pre_auth.verify(message),
+            PreAuthorization::EcdsaP256(pre_auth) => pre_auth.verify(message),
+            PreAuthorization::EcdsaSecp256k1(pre_auth) => pre_auth.verify(message),
+        }
+    }
+}
+
+/// A key that can make pre-authorizations, under any of the supported schemes.
+///
+/// In config files, keys are written as a `scheme` and a base64-encoded `key`,
+/// which for the ECDSA schemes is the SEC1 encoding of the public key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "PreAuthorizationKeyBase64",
+    into = "PreAuthorizationKeyBase64"
+)]
+pub enum PreAuthorizationKey {
+    Ed25519(ed25519_consensus::VerificationKey),
+    EcdsaP256(p256::ecdsa::VerifyingKey),
+    EcdsaSecp256k1(k256::ecdsa::VerifyingKey),
+}
+
+impl PreAuthorizationKey {
+    /// Encodes the key as bytes: the raw key for Ed25519, and the compressed
+    /// SEC1 encoding for the ECDSA schemes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            PreAuthorizationKey::Ed25519(vk) => vk.to_bytes().to_vec(),
+            PreAuthorizationKey::EcdsaP256(vk) => vk.to_encoded_point(true).as_bytes().to_vec(),
+            PreAuthorizationKey::EcdsaSecp256k1(vk) => {
+                vk.to_encoded_point(true).as_bytes().to_vec()
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "scheme", content = "key")]
+enum PreAuthorizationKeyBase64 {
+    Ed25519(String),
+    EcdsaP256(String),
+    EcdsaSecp256k1(String),
+}
+
+impl From<PreAuthorizationKey> for PreAuthorizationKeyBase64 {
+    fn from(value: PreAuthorizationKey) -> Self {
+        use base64::prelude::*;
+        let encoded = BASE64_STANDARD.encode(value.to_bytes());
+        match value {
+            PreAuthorizationKey::Ed25519(_) => Self::Ed25519(encoded),
+            PreAuthorizationKey::EcdsaP256(_) => Self::EcdsaP256(encoded),
+            PreAuthorizationKey::EcdsaSecp256k1(_) => Self::EcdsaSecp256k1(encoded),
+        }
+    }
+}
+
+impl TryFrom<PreAuthorizationKeyBase64> for PreAuthorizationKey {
+    type Error = anyhow::Error;
+    fn try_from(value: PreAuthorizationKeyBase64) -> Result<Self, Self::Error> {
+        use base64::prelude::*;
+        Ok(match value {
+            PreAuthorizationKeyBase64::Ed25519(encoded) => {
+                let bytes = BASE64_STANDARD.decode(encoded)?;
+                Self::Ed25519(bytes.as_slice().try_into()?)
+            }
+            PreAuthorizationKeyBase64::EcdsaP256(encoded) => {
+                let bytes = BASE64_STANDARD.decode(encoded)?;
+                Self::EcdsaP256(
+                    p256::ecdsa::VerifyingKey::from_sec1_bytes(&bytes)
+                        .map_err(|_| anyhow::anyhow!("invalid P-256 verification key"))?,
+                )
+            }
+            PreAuthorizationKeyBase64::EcdsaSecp256k1(encoded) => {
+                let bytes = BASE64_STANDARD.decode(encoded)?;
+                Self::EcdsaSecp256k1(
+                    k256::ecdsa::VerifyingKey::from_sec1_bytes(&bytes)
+                        .map_err(|_| anyhow::anyhow!("invalid secp256k1 verification key"))?,
+                )
+            }
+        })
+    }
 }
 
 /// An Ed25519-based preauthorization, containing an Ed25519 signature over the
@@ -33,6 +133,61 @@ impl Ed25519 {
     }
 }
 
+/// An ECDSA-based preauthorization over the NIST P-256 curve, containing an
+/// ECDSA/SHA-256 signature over the `TransactionPlan`.
+///
+/// The signature must be directly over the encoded plan.  WebAuthn assertions,
+/// which sign the authenticator data and a hash of the client data instead,
+/// are not accepted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::pre_authorization::EcdsaP256",
+    into = "pb::pre_authorization::EcdsaP256"
+)]
+pub struct EcdsaP256 {
+    /// The verification key used to pre-authorize the `TransactionPlan`.
+    pub vk: p256::ecdsa::VerifyingKey,
+    /// An ECDSA signature over the `TransactionPlan`.
+    pub sig: p256::ecdsa::Signature,
+}
+
+impl EcdsaP256 {
+    /// Verifies the provided `TransactionPlan`.
+    pub fn verify(&self, message: impl AsRef<[u8]>) -> anyhow::Result<()> {
+        use p256::ecdsa::signature::Verifier as _;
+        self.vk
+            .verify(message.as_ref(), &self.sig)
+            .map_err(|e| anyhow::anyhow!("invalid ECDSA P-256 pre-authorization: {e}"))
+    }
+}
+
+/// An ECDSA-based preauthorization over the secp256k1 curve, containing an
+/// ECDSA/SHA-256 signature over the `TransactionPlan`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::pre_authorization::EcdsaSecp256k1",
+    into = "pb::pre_authorization::EcdsaSecp256k1"
+)]
+pub struct EcdsaSecp256k1 {
+    /// The verification key used to pre-authorize the `TransactionPlan`.
+    pub vk: k256::ecdsa::VerifyingKey,
+    /// An ECDSA signature over the `TransactionPlan`.
+    pub sig: k256::ecdsa::Signature,
+}
+
+impl EcdsaSecp256k1 {
+    /// Verifies the provided `TransactionPlan`.
+    pub fn verify(&self, message: impl AsRef<[u8]>) -> anyhow::Result<()> {
+        use k256::ecdsa::signature::Verifier as _;
+        // Signers outside of the Bitcoin ecosystem don't necessarily produce
+        // low-S signatures, which the verifier would otherwise reject.
+        let sig = self.sig.normalize_s().unwrap_or(self.sig);
+        self.vk
+            .verify(message.as_ref(), &sig)
+            .map_err(|e| anyhow::anyhow!("invalid ECDSA secp256k1 pre-authorization: {e}"))
+    }
+}
+
 impl DomainType for PreAuthorization {
     type Proto = pb::PreAuthorization;
 }
@@ -44,6 +199,12 @@ impl TryFrom<pb::PreAuthorization> for PreAuthorization {
             Some(pb::pre_authorization::PreAuthorization::Ed25519(ed)) => {
                 Self::Ed25519(ed.try_into()?)
             }
+            Some(pb::pre_authorization::PreAuthorization::EcdsaP256(ecdsa)) => {
+                Self::EcdsaP256(ecdsa.try_into()?)
+            }
+            Some(pb::pre_authorization::PreAuthorization::EcdsaSecp256k1(ecdsa)) => {
+                Self::EcdsaSecp256k1(ecdsa.try_into()?)
+            }
             None => {
                 anyhow::bail!("missing pre-authorization");
             }
@@ -58,6 +219,12 @@ impl From<PreAuthorization> for pb::PreAuthorization {
                 PreAuthorization::Ed25519(ed) => {
                     pb::pre_authorization::PreAuthorization::Ed25519(ed.into())
                 }
+                PreAuthorization::EcdsaP256(ecdsa) => {
+                    pb::pre_authorization::PreAuthorization::EcdsaP256(ecdsa.into())
+                }
+                PreAuthorization::EcdsaSecp256k1(ecdsa) => {
+                    pb::pre_authorization::PreAuthorization::EcdsaSecp256k1(ecdsa.into())
+                }
             }),
         }
     }
@@ -85,3 +252,57 @@ impl From<Ed25519> for pb::pre_authorization::Ed25519 {
         }
     }
 }
+
+impl DomainType for EcdsaP256 {
+    type Proto = pb::pre_authorization::EcdsaP256;
+}
+
+impl TryFrom<pb::pre_authorization::EcdsaP256> for EcdsaP256 {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::pre_authorization::EcdsaP256) -> Result<Self, Self::Error> {
+        use p256::ecdsa::{Signature, VerifyingKey};
+        Ok(Self {
+            vk: VerifyingKey::from_sec1_bytes(&value.vk)
+                .map_err(|_| anyhow::anyhow!("invalid P-256 verification key"))?,
+            sig: Signature::from_slice(&value.sig)
+                .or_else(|_| Signature::from_der(&value.sig))
+                .map_err(|_| anyhow::anyhow!("invalid ECDSA P-256 signature encoding"))?,
+        })
+    }
+}
+
+impl From<EcdsaP256> for pb::pre_authorization::EcdsaP256 {
+    fn from(value: EcdsaP256) -> pb::pre_authorization::EcdsaP256 {
+        Self {
+            vk: value.vk.to_encoded_point(true).as_bytes().to_vec(),
+            sig: value.sig.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl DomainType for EcdsaSecp256k1 {
+    type Proto = pb::pre_authorization::EcdsaSecp256k1;
+}
+
+impl TryFrom<pb::pre_authorization::EcdsaSecp256k1> for EcdsaSecp256k1 {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::pre_authorization::EcdsaSecp256k1) -> Result<Self, Self::Error> {
+        use k256::ecdsa::{Signature, VerifyingKey};
+        Ok(Self {
+            vk: VerifyingKey::from_sec1_bytes(&value.vk)
+                .map_err(|_| anyhow::anyhow!("invalid secp256k1 verification key"))?,
+            sig: Signature::from_slice(&value.sig)
+                .or_else(|_| Signature::from_der(&value.sig))
+                .map_err(|_| anyhow::anyhow!("invalid ECDSA secp256k1 signature encoding"))?,
+        })
+    }
+}
+
+impl From<EcdsaSecp256k1> for pb::pre_authorization::EcdsaSecp256k1 {
+    fn from(value: EcdsaSecp256k1) -> pb::pre_authorization::EcdsaSecp256k1 {
+        Self {
+            vk: value.vk.to_encoded_point(true).as_bytes().to_vec(),
+            sig: value.sig.to_bytes().to_vec(),
+        }
+    }
+}
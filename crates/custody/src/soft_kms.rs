@@ -15,8 +15,9 @@ use rand_core::OsRng;
 use tonic::{async_trait, Request, Response, Status};
 
 use crate::{
-    policy::Policy, AuthorizeRequest, AuthorizeValidatorDefinitionRequest,
-    AuthorizeValidatorVoteRequest,
+    audit::AuditLog,
+    policy::{Policy, PolicyState},
+    AuthorizeRequest, AuthorizeValidatorDefinitionRequest, AuthorizeValidatorVoteRequest,
 };
 
 mod config;
@@ -27,24 +28,65 @@ pub use config::Config;
 /// presents as an asynchronous signer.
 pub struct SoftKms {
     config: Config,
+    policy_state: PolicyState,
+    audit_log: AuditLog,
 }
 
 impl SoftKms {
     /// Initialize with the given [`Config`].
+    ///
+    /// Any stateful policies are enforced against an in-memory [`PolicyState`]; use
+    /// [`SoftKms::with_policy_state`] to persist it across restarts.  Likewise,
+    /// authorization decisions are recorded in an in-memory [`AuditLog`] unless
+    /// another is provided with [`SoftKms::with_audit_log`].
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self {
+            config,
+            policy_state: PolicyState::in_memory(),
+            audit_log: AuditLog::in_memory(),
+        }
+    }
+
+    /// Use the given [`PolicyState`] to enforce stateful policies.
+    pub fn with_policy_state(self, policy_state: PolicyState) -> Self {
+        Self {
+            policy_state,
+            ..self
+        }
+    }
+
+    /// Record every transaction authorization decision in the given [`AuditLog`].
+    pub fn with_audit_log(self, audit_log: AuditLog) -> Self {
+        Self { audit_log, ..self }
     }
 
     /// Attempt to authorize the requested [`TransactionPlan`](penumbra_transaction::TransactionPlan).
+    ///
+    /// The decision is recorded in the audit log, and if it cannot be recorded,
+    /// the request is rejected.
     #[tracing::instrument(skip(self, request), name = "softhsm_sign")]
     pub fn sign(&self, request: &AuthorizeRequest) -> anyhow::Result<AuthorizationData> {
         tracing::debug!(?request.plan);
 
+        let result = self.check_and_authorize(request);
+        self.audit_log.record_request(
+            self.config.spend_key.full_viewing_key(),
+            request,
+            &result,
+        )?;
+        result
+    }
+
+    fn check_and_authorize(&self, request: &AuthorizeRequest) -> anyhow::Result<AuthorizationData> {
         for policy in &self.config.auth_policy {
             policy.check_transaction(request)?;
         }
-
-        Ok(request.plan.authorize(OsRng, &self.config.spend_key)?)
+        self.policy_state.check_and_record(
+            &self.config.auth_policy,
+            self.config.spend_key.full_viewing_key(),
+            request,
+            || Ok(request.plan.authorize(OsRng, &self.config.spend_key)?),
+        )
     }
 
     /// Attempt to authorize the requested validator definition.
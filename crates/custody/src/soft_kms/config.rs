@@ -40,7 +40,10 @@ fn is_default<T: Default + Eq>(value: &T) -> bool {
 mod tests {
     use penumbra_keys::keys::{Bip44Path, SeedPhrase};
 
-    use crate::policy::PreAuthorizationPolicy;
+    use crate::{
+        policy::{ActionKind, PreAuthorizationPolicy, SpendLimitPolicy},
+        PreAuthorizationKey,
+    };
 
     use super::*;
 
@@ -51,6 +54,8 @@ mod tests {
 
         let pak = ed25519_consensus::SigningKey::new(rand_core::OsRng);
         let pvk = pak.verification_key();
+        let p256_vk = *p256::ecdsa::SigningKey::random(&mut rand_core::OsRng).verifying_key();
+        let k256_vk = *k256::ecdsa::SigningKey::random(&mut rand_core::OsRng).verifying_key();
 
         let auth_policy = vec![
             AuthPolicy::OnlyIbcRelay,
@@ -66,6 +71,47 @@ mod tests {
                 required_signatures: 1,
                 allowed_signers: vec![pvk],
             }),
+            AuthPolicy::PreAuthorization(PreAuthorizationPolicy::EcdsaP256 {
+                required_signatures: 1,
+                allowed_signers: vec![p256_vk],
+            }),
+            AuthPolicy::PreAuthorization(PreAuthorizationPolicy::EcdsaSecp256k1 {
+                required_signatures: 1,
+                allowed_signers: vec![k256_vk],
+            }),
+            AuthPolicy::PreAuthorization(PreAuthorizationPolicy::Quorum {
+                required_signatures: 2,
+                allowed_signers: vec![
+                    PreAuthorizationKey::Ed25519(pvk),
+                    PreAuthorizationKey::EcdsaP256(p256_vk),
+                    PreAuthorizationKey::EcdsaSecp256k1(k256_vk),
+                ],
+            }),
+            AuthPolicy::SpendLimit(SpendLimitPolicy {
+                asset_id: *penumbra_asset::STAKING_TOKEN_ASSET_ID,
+                limit: 50_000_000_000,
+                window_seconds: 24 * 60 * 60,
+            }),
+            AuthPolicy::AnyOf {
+                policies: vec![
+                    AuthPolicy::ActionAllowList {
+                        actions: ActionKind::IBC_RELAY.to_vec(),
+                    },
+                    AuthPolicy::AllOf {
+                        policies: vec![
+                            AuthPolicy::Not {
+                                policy: Box::new(AuthPolicy::ActionAllowList {
+                                    actions: vec![ActionKind::Swap],
+                                }),
+                            },
+                            AuthPolicy::PreAuthorization(PreAuthorizationPolicy::Ed25519 {
+                                required_signatures: 1,
+                                allowed_signers: vec![pvk],
+                            }),
+                        ],
+                    },
+                ],
+            },
         ];
 
         let example = Config {
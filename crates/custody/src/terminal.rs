@@ -3,9 +3,11 @@ use penumbra_governance::ValidatorVoteBody;
 use penumbra_proto::DomainType;
 use penumbra_stake::validator::Validator;
 use penumbra_transaction::TransactionPlan;
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Serialize};
 use tonic::async_trait;
 
+use crate::threshold::Config;
+
 #[derive(Debug, Clone)]
 pub enum SigningRequest {
     TransactionPlan(TransactionPlan),
@@ -36,6 +38,29 @@ pub trait Terminal: Sync {
     /// Broadcast a message to other users.
     async fn broadcast(&self, data: &str) -> Result<()>;
 
+    /// Broadcast a typed message to other users, to be read with [`Terminal::next_response`].
+    ///
+    /// By default, this broadcasts the JSON encoding of the message.
+    async fn send<D>(&self, message: &D) -> Result<()>
+    where
+        D: DomainType + Sync,
+        anyhow::Error: From<<D as TryFrom<<D as DomainType>::Proto>>::Error>,
+        <D as DomainType>::Proto: Serialize,
+    {
+        self.broadcast(&serde_json::to_string(&message.to_proto())?)
+            .await
+    }
+
+    /// Start taking part in a new run of a protocol, such as signing a new request, as the
+    /// holder of this config.
+    ///
+    /// Terminals that exchange messages with other users over a shared channel can use this
+    /// to keep the messages of separate runs apart, and to identify their messages with the
+    /// config's signing key.  By default, this does nothing.
+    async fn start_session(&self, _config: &Config) -> Result<()> {
+        Ok(())
+    }
+
     /// Try to read a typed message from the terminal, retrying until
     /// the message parses successfully or the user interrupts the program.
     async fn next_response<D>(&self) -> Result<D>
@@ -1,13 +1,16 @@
 use anyhow::{anyhow, Result};
 use penumbra_transaction::AuthorizationData;
 use rand_core::OsRng;
-use serde::Serialize;
+use serde::de::DeserializeOwned;
 use tonic::{async_trait, Request, Response, Status};
 
 use penumbra_keys::{keys::AddressIndex, Address, FullViewingKey};
 use penumbra_proto::{custody::v1 as pb, DomainType};
 
-use crate::{AuthorizeRequest, AuthorizeValidatorDefinitionRequest, AuthorizeValidatorVoteRequest};
+use crate::{
+    audit::AuditLog, AuthorizeRequest, AuthorizeValidatorDefinitionRequest,
+    AuthorizeValidatorVoteRequest,
+};
 
 pub use self::config::Config;
 use self::sign::no_signature_response;
@@ -15,6 +18,9 @@ pub use crate::terminal::{SigningRequest, Terminal};
 
 mod config;
 mod dkg;
+pub mod network;
+mod reshare;
+mod roast;
 mod sign;
 
 /// Authorization data returned in response to some signing request, which may be a request to
@@ -35,19 +41,13 @@ impl From<AuthorizationData> for SigningResponse {
     }
 }
 
-fn to_json<T>(data: &T) -> Result<String>
-where
-    T: DomainType,
-    anyhow::Error: From<<T as TryFrom<<T as DomainType>::Proto>>::Error>,
-    <T as DomainType>::Proto: Serialize,
-{
-    Ok(serde_json::to_string(&data.to_proto())?)
-}
-
 /// Act as a follower in the signing protocol.
 ///
 /// All this function does is produce side effects on the terminal, potentially returning
 /// early if the user on the other end did not want to sign the transaction.
+///
+/// After the first message, this keeps replying to the coordinator's signing attempts,
+/// until the coordinator says that signing is over.
 pub async fn follow(
     config: Option<&Config>,
     governance_config: Option<&Config>,
@@ -74,15 +74,59 @@ pub async fn follow(
     {
         return Ok(());
     }
-    let (round1_reply, round1_state) = sign::follower_round1(&mut OsRng, config, round1_message)?;
-    terminal.explain("Send this message to the coordinator:")?;
-    terminal.broadcast(&to_json(&round1_reply)?).await?;
-    // Round 2
-    terminal.explain("Paste the coordinator's second message:")?;
-    let round2_message = terminal.next_response::<sign::CoordinatorRound2>().await?;
-    let round2_reply = sign::follower_round2(config, round1_state, round2_message)?;
+    terminal.start_session(config).await?;
+    let (reply, mut state) = roast::follower_start(&mut OsRng, config, round1_message);
     terminal.explain("Send this message to the coordinator:")?;
-    terminal.broadcast(&to_json(&round2_reply)?).await?;
+    terminal.send(&reply).await?;
+    loop {
+        terminal.explain("Paste the coordinator's next message:")?;
+        match terminal
+            .next_response::<roast::CoordinatorMessage>()
+            .await?
+        {
+            roast::CoordinatorMessage::Attempt {
+                attempt,
+                commitments,
+            } => {
+                match roast::follower_respond(&mut OsRng, config, &mut state, attempt, commitments)
+                {
+                    Ok(Some(reply)) => {
+                        terminal.explain(&format!(
+                            "Send this message to the coordinator, for signing attempt {attempt}:"
+                        ))?;
+                        terminal.send(&reply).await?;
+                    }
+                    Ok(None) => {
+                        terminal.explain(&format!(
+                            "This signer is not part of signing attempt {attempt}."
+                        ))?;
+                    }
+                    Err(e) => {
+                        terminal.explain(&format!(
+                            "Not taking part in signing attempt {attempt}: {e:#}"
+                        ))?;
+                    }
+                }
+            }
+            roast::CoordinatorMessage::Finished {
+                success,
+                misbehaving,
+            } => {
+                for pk in misbehaving {
+                    terminal.explain(&format!(
+                        "The coordinator reported that signer {} misbehaved.",
+                        hex::encode(pk.as_bytes())
+                    ))?;
+                }
+                if success {
+                    terminal.explain("Signing succeeded.")?;
+                } else {
+                    terminal.explain("Signing failed.")?;
+                }
+                break;
+            }
+        }
+    }
 
     Ok(())
 }
@@ -99,77 +143,104 @@ pub async fn dkg(t: u16, n: u16, terminal: &impl Terminal) -> Result<Config> {
     // Round 1 top
     let (round1_message, state) = dkg::round1(&mut OsRng, t, n)?;
     terminal.explain("Round 1/2: Send this message to all other participants:")?;
-    terminal.broadcast(&to_json(&round1_message)?).await?;
+    terminal.send(&round1_message).await?;
     // Round 1 bottom
     terminal.explain(&format!(
         "Round 1/2: Gather {expected_responses} messages from the other participants:"
     ))?;
-    let round1_replies = {
-        let mut acc: Vec<dkg::Round1> = Vec::new();
-        while acc.len() < expected_responses {
-            let rsp = terminal.next_response::<dkg::Round1>().await?;
-            // Before we accept, check that the user hasn't double-pasted the same message.
-            if acc
-                .iter()
-                // Inefficient but good enough.
-                .any(|existing| existing.encode_to_vec() == rsp.encode_to_vec())
-            {
-                terminal.explain("Received a duplicate message, ignoring")?;
-                continue;
-            }
-            // Before we accept, check that the user hasn't pasted their own message.
-            if round1_message.encode_to_vec() == rsp.encode_to_vec() {
-                terminal.explain("Received our own outbound message by mistake, ignoring")?;
-                continue;
-            }
-            acc.push(rsp);
-            terminal.explain(&format!(
-                "Received {}/{} responses...",
-                acc.len(),
-                expected_responses
-            ))?;
-        }
-        acc
-    };
+    let round1_replies = gather(terminal, Some(&round1_message), expected_responses).await?;
 
     // Round 2 top
     let (round2_message, state) = dkg::round2(&mut OsRng, state, round1_replies)?;
     terminal.explain("Round 2/2: Send this message to all other participants:")?;
-    terminal.broadcast(&to_json(&round2_message)?).await?;
+    terminal.send(&round2_message).await?;
     // Round 2 bottom
     terminal.explain(&format!(
         "Round 2/2: Gather {expected_responses} messages from the other participants:"
     ))?;
-    let round2_replies = {
-        let mut acc: Vec<dkg::Round2> = Vec::new();
-        while acc.len() < expected_responses {
-            let rsp = terminal.next_response::<dkg::Round2>().await?;
-            // Before we accept, check that the user hasn't double-pasted the same message.
-            if acc
-                .iter()
-                // Inefficient but good enough.
-                .any(|existing| existing.encode_to_vec() == rsp.encode_to_vec())
-            {
-                terminal.explain("Received a duplicate message, ignoring")?;
-                continue;
-            }
-            // Before we accept, check that the user hasn't pasted their own message.
-            if round2_message.encode_to_vec() == rsp.encode_to_vec() {
-                terminal.explain("Received our own outbound message by mistake, ignoring")?;
-                continue;
-            }
-            acc.push(rsp);
-            terminal.explain(&format!(
-                "Received {}/{} responses...",
-                acc.len(),
-                expected_responses
-            ))?;
-        }
-        acc
-    };
+    let round2_replies = gather(terminal, Some(&round2_message), expected_responses).await?;
     dkg::round3(&mut OsRng, state, round2_replies)
 }
 
+/// Reshare a threshold key, keeping the same full viewing key.
+///
+/// Current share holders pass in their config, and deal out their share: at least as many
+/// of them as the current threshold need to take part. Participants with `receive` set get
+/// back a config holding a share of the reshared key, with threshold `t`. `n` is the number of
+/// participants taking part, whether they're dealing, receiving, or both.
+///
+/// Shares of the key from before resharing can't be combined with the new shares, so resharing
+/// can remove participants, and resharing to the same participants with the same threshold
+/// refreshes everyone's share, making any previously leaked shares useless.
+pub async fn reshare(
+    config: Option<&Config>,
+    t: u16,
+    n: u16,
+    receive: bool,
+    terminal: &impl Terminal,
+) -> Result<Option<Config>> {
+    let expected_responses = n.saturating_sub(1) as usize;
+    // Round 1 top
+    let (round1_message, state) = reshare::round1(&mut OsRng, config, t, receive)?;
+    terminal.explain("Round 1/2: Send this message to all other participants:")?;
+    terminal.send(&round1_message).await?;
+    // Round 1 bottom
+    terminal.explain(&format!(
+        "Round 1/2: Gather {expected_responses} messages from the other participants:"
+    ))?;
+    let round1_replies = gather(terminal, Some(&round1_message), expected_responses).await?;
+
+    // Round 2 top
+    let (round2_message, state) = reshare::round2(&mut OsRng, state, round1_replies)?;
+    if let Some(round2_message) = &round2_message {
+        terminal.explain("Round 2/2: Send this message to all other participants:")?;
+        terminal.send(round2_message).await?;
+    }
+    // Round 2 bottom
+    let expected_responses = state.expected_messages();
+    if expected_responses > 0 {
+        terminal.explain(&format!(
+            "Round 2/2: Gather {expected_responses} messages from the participants dealing out their share:"
+        ))?;
+    }
+    let round2_replies = gather(terminal, round2_message.as_ref(), expected_responses).await?;
+    reshare::round3(&mut OsRng, state, round2_replies)
+}
+
+/// Gather messages from the other participants, until we have `expected` distinct ones.
+async fn gather<M>(
+    terminal: &impl Terminal,
+    own_message: Option<&M>,
+    expected: usize,
+) -> Result<Vec<M>>
+where
+    M: DomainType,
+    anyhow::Error: From<<M as TryFrom<<M as DomainType>::Proto>>::Error>,
+    <M as DomainType>::Proto: DeserializeOwned,
+{
+    let mut acc: Vec<M> = Vec::new();
+    while acc.len() < expected {
+        let rsp = terminal.next_response::<M>().await?;
+        // Before we accept, check that the user hasn't double-pasted the same message.
+        if acc
+            .iter()
+            // Inefficient but good enough.
+            .any(|existing| existing.encode_to_vec() == rsp.encode_to_vec())
+        {
+            terminal.explain("Received a duplicate message, ignoring")?;
+            continue;
+        }
+        // Before we accept, check that the user hasn't pasted their own message.
+        if own_message.map(|own| own.encode_to_vec()) == Some(rsp.encode_to_vec()) {
+            terminal.explain("Received our own outbound message by mistake, ignoring")?;
+            continue;
+        }
+        acc.push(rsp);
+        terminal.explain(&format!("Received {}/{} responses...", acc.len(), expected))?;
+    }
+    Ok(acc)
+}
+
 /// A custody backend using threshold signing.
 ///
 /// This backend is initialized with a full viewing key, but only a share
@@ -179,11 +250,21 @@ pub async fn dkg(t: u16, n: u16, terminal: &impl Terminal) -> Result<Config> {
 pub struct Threshold<T> {
     config: Config,
     terminal: T,
+    audit_log: AuditLog,
 }
 
 impl<T> Threshold<T> {
     pub fn new(config: Config, terminal: T) -> Self {
-        Threshold { config, terminal }
+        Threshold {
+            config,
+            terminal,
+            audit_log: AuditLog::in_memory(),
+        }
+    }
+
+    /// Record every transaction authorization decision in the given [`AuditLog`].
+    pub fn with_audit_log(self, audit_log: AuditLog) -> Self {
+        Self { audit_log, ..self }
     }
 }
 
@@ -195,42 +276,56 @@ impl<T: Terminal> Threshold<T> {
         if let Some(out) = no_signature_response(self.config.fvk(), &request)? {
             return Ok(out);
         }
-        // Round 1
-        let (round1_message, state1) = sign::coordinator_round1(&mut OsRng, &self.config, request)?;
+        self.terminal.start_session(&self.config).await?;
+        let (request_message, mut state) = roast::coordinator_start(&self.config, request)?;
         self.terminal
             .explain("Send this message to the other signers:")?;
-        self.terminal.broadcast(&to_json(&round1_message)?).await?;
+        self.terminal.send(&request_message).await?;
         self.terminal.explain(&format!(
-            "Now, gather at least {} replies from the other signers, and paste them below:",
+            "Now, paste the replies from the other signers below. Signing starts once {} of them have replied, and continues with whoever responds:",
             self.config.threshold() - 1
         ))?;
-        let round1_replies = {
-            let mut acc = Vec::<sign::FollowerRound1>::new();
-            // We need 1 less, since we've already included ourselves.
-            for _ in 1..self.config.threshold() {
-                acc.push(self.terminal.next_response().await?);
+        loop {
+            let reply = self
+                .terminal
+                .next_response::<roast::FollowerMessage>()
+                .await?;
+            match state.receive(&self.config, reply)? {
+                roast::Received::Accepted => {}
+                roast::Received::Ignored(reason) => {
+                    self.terminal
+                        .explain(&format!("Ignoring a reply: {reason}"))?;
+                }
+                roast::Received::Misbehaved(pk, reason) => {
+                    self.terminal.explain(&format!(
+                        "Signer {} misbehaved, and won't take part in further attempts: {reason}",
+                        hex::encode(pk.as_bytes())
+                    ))?;
+                    if !state.can_succeed(&self.config) {
+                        self.terminal
+                            .explain("Send this message to the other signers:")?;
+                        self.terminal.send(&state.finished(false)).await?;
+                        anyhow::bail!(
+                            "too many signers misbehaved to reach the threshold of {}",
+                            self.config.threshold()
+                        );
+                    }
+                }
+                roast::Received::Signed(response) => {
+                    self.terminal.explain(
+                        "Signing succeeded. Send this message to the other signers, so that they stop waiting:",
+                    )?;
+                    self.terminal.send(&state.finished(true)).await?;
+                    return Ok(response);
+                }
             }
-            acc
-        };
-        // Round 2
-        let (round2_message, state2) =
-            sign::coordinator_round2(&self.config, state1, &round1_replies)?;
-        self.terminal
-            .explain("Send this message to the other signers:")?;
-        self.terminal.broadcast(&to_json(&round2_message)?).await?;
-        self.terminal.explain(
-            "Now, gather the replies from the *same* signers as Round 1, and paste them below:",
-        )?;
-        let round2_replies = {
-            let mut acc = Vec::<sign::FollowerRound2>::new();
-            // We need 1 less, since we've already included ourselves.
-            for _ in 1..self.config.threshold() {
-                acc.push(self.terminal.next_response().await?);
+            for attempt in state.start_attempts(&mut OsRng, &self.config)? {
+                self.terminal.explain(
+                    "Send this message, starting a new signing attempt, to the other signers:",
+                )?;
+                self.terminal.send(&attempt).await?;
             }
-            acc
-        };
-        // Round 3
-        sign::coordinator_round3(&self.config, state2, &round2_replies)
+        }
     }
 
     /// Return the full viewing key.
@@ -258,14 +353,19 @@ impl<T: Terminal + Sync + Send + 'static> pb::custody_service_server::CustodySer
             .into_inner()
             .try_into()
             .map_err(|e| Status::invalid_argument(format!("{e}")))?;
-        let data = self
-            .authorize(SigningRequest::TransactionPlan(request.plan))
-            .await
+        let result = self
+            .authorize(SigningRequest::TransactionPlan(request.plan.clone()))
+            .await;
+        self.audit_log
+            .record_request(self.config.fvk(), &request, &result)
             .map_err(|e| {
-                Status::internal(format!(
-                    "Failed to process transaction authorization request: {e}"
-                ))
+                Status::internal(format!("Failed to record authorization decision: {e:#}"))
             })?;
+        let data = result.map_err(|e| {
+            Status::internal(format!(
+                "Failed to process transaction authorization request: {e}"
+            ))
+        })?;
         let SigningResponse::Transaction(data) = data else {
             return Err(Status::internal(
                 "expected transaction authorization but custody service returned another kind of authorization data"
@@ -362,11 +462,20 @@ impl<T: Terminal + Sync + Send + 'static> pb::custody_service_server::CustodySer
 mod test {
     use std::collections::HashMap;
 
+    use penumbra_proto::custody::threshold::v1::{
+        coordinator_service_client::CoordinatorServiceClient,
+        coordinator_service_server::CoordinatorServiceServer,
+    };
     use penumbra_transaction::TransactionPlan;
+    use prost::Message;
 
     use tokio::sync;
+    use tonic::transport::Channel;
 
-    use super::*;
+    use super::{
+        network::{CoordinatorServer, NetworkTerminal, SessionCode},
+        *,
+    };
 
     struct FollowerTerminal {
         incoming: sync::Mutex<sync::mpsc::Receiver<String>>,
@@ -520,9 +629,7 @@ mod test {
         Ok(())
     }
 
-    #[tokio::test]
-    async fn test_transaction_signing() -> Result<()> {
-        const TEST_PLAN: &'static str = r#"
+    const TEST_PLAN: &'static str = r#"
 {
     "actions": [
         {
@@ -618,7 +725,10 @@ mod test {
         "key": "3plOcPZzKKj8KT3sVdKnblUUFDRzCmMWYtgwB3BqfXQ="
     }
 }
-        "#;
+"#;
+
+    #[tokio::test]
+    async fn test_transaction_signing() -> Result<()> {
         const T: u16 = 3;
         const N: u16 = 3;
 
@@ -626,7 +736,15 @@ mod test {
             let mut configs = run_dkg(T, N).await?;
             (configs.pop().unwrap(), configs)
         };
-        let (coordinator_terminal, follower_terminals) = make_terminals((N - 1) as usize);
+        sign_test_plan(coordinator_config, follower_configs).await
+    }
+
+    /// Sign the test plan with these configs, checking the resulting authorization data.
+    async fn sign_test_plan(
+        coordinator_config: Config,
+        follower_configs: Vec<Config>,
+    ) -> Result<()> {
+        let (coordinator_terminal, follower_terminals) = make_terminals(follower_configs.len());
         for (config, terminal) in follower_configs
             .into_iter()
             .zip(follower_terminals.into_iter())
@@ -638,6 +756,207 @@ mod test {
         let authorization_data = Threshold::new(coordinator_config, coordinator_terminal)
             .authorize(SigningRequest::TransactionPlan(plan.clone()))
             .await?;
+        check_transaction_authorization(&plan, &fvk, authorization_data)
+    }
+
+    /// Start robust signing of the test plan, with the first config as the coordinator.
+    fn start_roast(
+        configs: &[Config],
+    ) -> Result<(sign::CoordinatorRound1, roast::CoordinatorState)> {
+        let plan = serde_json::from_str::<TransactionPlan>(TEST_PLAN)?;
+        roast::coordinator_start(&configs[0], SigningRequest::TransactionPlan(plan))
+    }
+
+    /// Expect exactly one new attempt, returning its number and commitments.
+    fn one_attempt(attempts: Vec<roast::CoordinatorMessage>) -> (u64, sign::CoordinatorRound2) {
+        assert_eq!(attempts.len(), 1);
+        match attempts.into_iter().next().unwrap() {
+            roast::CoordinatorMessage::Attempt {
+                attempt,
+                commitments,
+            } => (attempt, commitments),
+            other => panic!("expected an attempt, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_roast_tolerates_unresponsive_signer() -> Result<()> {
+        let configs = Config::deal(&mut OsRng, 2, 3)?;
+        let (request, mut state) = start_roast(&configs)?;
+        let (first_a, _) = roast::follower_start(&mut OsRng, &configs[1], request.clone());
+        let (first_b, mut b) = roast::follower_start(&mut OsRng, &configs[2], request);
+
+        // The first signer to reply is asked to sign, and then goes silent.
+        assert!(matches!(
+            state.receive(&configs[0], first_a)?,
+            roast::Received::Accepted
+        ));
+        one_attempt(state.start_attempts(&mut OsRng, &configs[0])?);
+
+        // Meanwhile, the other signer's reply starts a concurrent attempt, which succeeds.
+        assert!(matches!(
+            state.receive(&configs[0], first_b)?,
+            roast::Received::Accepted
+        ));
+        let (attempt, commitments) = one_attempt(state.start_attempts(&mut OsRng, &configs[0])?);
+        assert_eq!(attempt, 2);
+        let reply = roast::follower_respond(
+            &mut OsRng,
+            &configs[2],
+            &mut b,
+            attempt,
+            commitments.clone(),
+        )?
+        .expect("signer is part of the attempt");
+        // Nonces are never reused, even if the same attempt is repeated.
+        assert!(
+            roast::follower_respond(&mut OsRng, &configs[2], &mut b, attempt, commitments).is_err()
+        );
+        let response = match state.receive(&configs[0], reply)? {
+            roast::Received::Signed(response) => response,
+            other => panic!("expected a signature, got {other:?}"),
+        };
+        let plan = serde_json::from_str::<TransactionPlan>(TEST_PLAN)?;
+        check_transaction_authorization(&plan, configs[0].fvk(), response)
+    }
+
+    #[test]
+    fn test_roast_excludes_misbehaving_signer() -> Result<()> {
+        let configs = Config::deal(&mut OsRng, 2, 3)?;
+        let (request, mut state) = start_roast(&configs)?;
+        let (first_a, mut a) = roast::follower_start(&mut OsRng, &configs[1], request.clone());
+        let (first_b, mut b) = roast::follower_start(&mut OsRng, &configs[2], request);
+
+        state.receive(&configs[0], first_a)?;
+        let (attempt, commitments) = one_attempt(state.start_attempts(&mut OsRng, &configs[0])?);
+        let reply = roast::follower_respond(&mut OsRng, &configs[1], &mut a, attempt, commitments)?
+            .expect("signer is part of the attempt");
+        // Replace the share with a bogus one, keeping the message correctly signed.
+        let mut reply = penumbra_proto::custody::threshold::v1::RoastFollowerMessage::from(reply);
+        let inner = reply.inner.as_mut().unwrap();
+        inner.shares[0].scalar = decaf377::Fr::from(1u64).to_bytes().to_vec();
+        reply.sig = Some(penumbra_proto::custody::threshold::v1::Signature {
+            inner: configs[1]
+                .signing_key()
+                .sign(&inner.encode_to_vec())
+                .to_bytes()
+                .to_vec(),
+        });
+        let culprit = configs[1].signing_key().verification_key();
+        match state.receive(&configs[0], reply.try_into()?)? {
+            roast::Received::Misbehaved(pk, _) => assert_eq!(pk, culprit),
+            other => panic!("expected misbehavior, got {other:?}"),
+        }
+        assert!(state.can_succeed(&configs[0]));
+
+        // The honest signer completes a new attempt without the misbehaving one.
+        state.receive(&configs[0], first_b)?;
+        let (attempt, commitments) = one_attempt(state.start_attempts(&mut OsRng, &configs[0])?);
+        let reply = roast::follower_respond(&mut OsRng, &configs[2], &mut b, attempt, commitments)?
+            .expect("signer is part of the attempt");
+        assert!(matches!(
+            state.receive(&configs[0], reply)?,
+            roast::Received::Signed(_)
+        ));
+        match state.finished(true) {
+            roast::CoordinatorMessage::Finished { misbehaving, .. } => {
+                assert_eq!(misbehaving, vec![culprit])
+            }
+            other => panic!("expected the end of signing, got {other:?}"),
+        }
+        Ok(())
+    }
+
+    /// Run a resharing between participants, each with an optional current config,
+    /// and a flag for whether they receive a new share.
+    ///
+    /// Not every participant sends a message in the second round, so this relays messages
+    /// through a coordinator, rather than reading from each participant in turn.
+    async fn run_reshare(
+        t: u16,
+        participants: Vec<(Option<Config>, bool)>,
+    ) -> Result<Vec<Option<Config>>> {
+        let n = participants.len() as u16;
+        let client = start_coordinator().await?;
+        let code = SessionCode::generate(&mut OsRng);
+        let mut handles = Vec::new();
+        for (i, (config, receive)) in participants.into_iter().enumerate() {
+            let terminal = peer_terminal(&client, code, i == 0).await?;
+            handles.push(tokio::spawn(async move {
+                reshare(config.as_ref(), t, n, receive, &terminal).await
+            }));
+        }
+        let mut out = Vec::new();
+        for handle in handles {
+            out.push(handle.await??);
+        }
+        Ok(out)
+    }
+
+    #[tokio::test]
+    async fn test_reshare_to_new_participants() -> Result<()> {
+        let mut configs = run_dkg(2, 3).await?;
+        let fvk = configs[0].fvk().clone();
+        // One current holder drops out, one deals without keeping a share,
+        // one keeps theirs, and three newcomers join, with a higher threshold.
+        let leaving = configs.pop().unwrap();
+        let staying = configs.pop().unwrap();
+        let reshared = run_reshare(
+            3,
+            vec![
+                (Some(leaving), false),
+                (Some(staying.clone()), true),
+                (None, true),
+                (None, true),
+                (None, true),
+            ],
+        )
+        .await?;
+        assert!(reshared[0].is_none());
+        let mut reshared: Vec<Config> = reshared.into_iter().flatten().collect();
+        assert_eq!(reshared.len(), 4);
+        for config in &reshared {
+            assert_eq!(config.fvk(), &fvk);
+            assert_eq!(config.threshold(), 3);
+            assert_eq!(config.verification_keys().len(), 4);
+        }
+        // Current holders keep their identity.
+        assert_eq!(
+            reshared[0].signing_key().verification_key(),
+            staying.signing_key().verification_key()
+        );
+        // Any three of the new shares can sign.
+        let coordinator = reshared.pop().unwrap();
+        reshared.remove(0);
+        sign_test_plan(coordinator, reshared).await
+    }
+
+    #[tokio::test]
+    async fn test_refresh_shares() -> Result<()> {
+        let configs = run_dkg(2, 3).await?;
+        let refreshed: Vec<Config> = run_reshare(
+            2,
+            configs.iter().cloned().map(|c| (Some(c), true)).collect(),
+        )
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+        assert_eq!(refreshed.len(), 3);
+        for (old, new) in configs.iter().zip(&refreshed) {
+            assert_eq!(old.fvk(), new.fvk());
+            assert_eq!(old.verification_keys(), new.verification_keys());
+            assert_ne!(old.spend_key_share(), new.spend_key_share());
+        }
+        sign_test_plan(refreshed[0].clone(), vec![refreshed[1].clone()]).await
+    }
+
+    /// Check that `authorization_data` correctly authorizes `plan`.
+    fn check_transaction_authorization(
+        plan: &TransactionPlan,
+        fvk: &FullViewingKey,
+        authorization_data: SigningResponse,
+    ) -> Result<()> {
         let tx_authorization_data = match authorization_data {
             SigningResponse::Transaction(tx) => tx,
             _ => panic!("expected transaction authorization data"),
@@ -664,4 +983,134 @@ mod test {
         }
         Ok(())
     }
+
+    /// A terminal that confirms every request, forwarding anything it explains.
+    struct AutoTerminal {
+        explanations: Option<sync::mpsc::UnboundedSender<String>>,
+    }
+
+    #[async_trait]
+    impl Terminal for AutoTerminal {
+        async fn confirm_request(&self, _request: &SigningRequest) -> Result<bool> {
+            Ok(true)
+        }
+
+        fn explain(&self, msg: &str) -> Result<()> {
+            if let Some(explanations) = &self.explanations {
+                explanations.send(msg.to_owned())?;
+            }
+            Ok(())
+        }
+
+        async fn broadcast(&self, _data: &str) -> Result<()> {
+            anyhow::bail!("messages should go through the coordinator")
+        }
+
+        async fn read_line_raw(&self) -> Result<String> {
+            anyhow::bail!("messages should come from the coordinator")
+        }
+
+        async fn get_password(&self) -> Result<String> {
+            Ok(Default::default())
+        }
+    }
+
+    /// Start a coordinator on localhost, returning a client connected to it.
+    async fn start_coordinator() -> Result<CoordinatorServiceClient<Channel>> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(CoordinatorServiceServer::new(CoordinatorServer::new()))
+                .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener)),
+        );
+        Ok(CoordinatorServiceClient::connect(format!("http://{addr}")).await?)
+    }
+
+    /// Create a session for a protocol where everyone reads everyone's messages, or join it.
+    async fn peer_terminal(
+        client: &CoordinatorServiceClient<Channel>,
+        code: SessionCode,
+        create: bool,
+    ) -> Result<NetworkTerminal<AutoTerminal>> {
+        let terminal = AutoTerminal { explanations: None };
+        if create {
+            NetworkTerminal::create(terminal, client.clone(), code).await
+        } else {
+            NetworkTerminal::join(terminal, client.clone(), code).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dkg_over_network() -> Result<()> {
+        const T: u16 = 2;
+        const N: u16 = 3;
+
+        let client = start_coordinator().await?;
+        let code = SessionCode::generate(&mut OsRng);
+        let mut handles = Vec::new();
+        for i in 0..N {
+            let terminal = peer_terminal(&client, code, i == 0).await?;
+            handles.push(tokio::spawn(async move { dkg(T, N, &terminal).await }));
+        }
+        let mut configs = Vec::new();
+        for handle in handles {
+            configs.push(handle.await??);
+        }
+        for config in &configs[1..] {
+            assert_eq!(configs[0].fvk(), config.fvk());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transaction_signing_over_network() -> Result<()> {
+        const T: u16 = 3;
+        const N: u16 = 3;
+
+        let (coordinator_config, follower_configs) = {
+            let mut configs = run_dkg(T, N).await?;
+            (configs.pop().unwrap(), configs)
+        };
+        let client = start_coordinator().await?;
+        let (explanations, mut explained) = sync::mpsc::unbounded_channel();
+        let coordinator_terminal = NetworkTerminal::coordinator(
+            AutoTerminal {
+                explanations: Some(explanations),
+            },
+            client.clone(),
+        );
+        let plan = serde_json::from_str::<TransactionPlan>(TEST_PLAN)?;
+        let fvk = coordinator_config.fvk().clone();
+        let authorization = {
+            let plan = plan.clone();
+            tokio::spawn(async move {
+                Threshold::new(coordinator_config, coordinator_terminal)
+                    .authorize(SigningRequest::TransactionPlan(plan))
+                    .await
+            })
+        };
+        // The followers join using the code the coordinator shares.
+        let code: SessionCode = loop {
+            let msg = explained
+                .recv()
+                .await
+                .expect("coordinator should explain the session code");
+            if let Some(code) = msg.strip_prefix("Share this session code with the other signers: ")
+            {
+                break code.parse()?;
+            }
+        };
+        for config in follower_configs {
+            let terminal = NetworkTerminal::follower(
+                AutoTerminal { explanations: None },
+                client.clone(),
+                code,
+                &[&config],
+            )
+            .await?;
+            tokio::spawn(async move { follow(Some(&config), Some(&config), &terminal).await });
+        }
+        check_transaction_authorization(&plan, &fvk, authorization.await??)
+    }
 }
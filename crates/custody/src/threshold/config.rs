@@ -149,6 +149,23 @@ impl Config {
         }
     }
 
+    /// Create a config from a share produced by resharing an existing key.
+    pub(crate) fn from_reshared(
+        threshold: u16,
+        fvk: FullViewingKey,
+        spend_key_share: frost::keys::SigningShare,
+        signing_key: SigningKey,
+        verifying_shares: HashMap<VerificationKey, frost::keys::VerifyingShare>,
+    ) -> Self {
+        Self {
+            threshold,
+            fvk,
+            spend_key_share,
+            signing_key,
+            verifying_shares,
+        }
+    }
+
     pub fn deal(mut rng: &mut impl CryptoRngCore, t: u16, n: u16) -> Result<Vec<Self>> {
         let signing_keys = (0..n)
             .map(|_| {
@@ -247,6 +264,17 @@ impl Config {
         frost::keys::PublicKeyPackage::new(signer_pubkeys, self.group_public())
     }
 
+    pub(crate) fn spend_key_share(&self) -> &frost::keys::SigningShare {
+        &self.spend_key_share
+    }
+
+    pub(crate) fn verifying_share(
+        &self,
+        vk: &VerificationKey,
+    ) -> Option<&frost::keys::VerifyingShare> {
+        self.verifying_shares.get(vk)
+    }
+
     pub fn signing_key(&self) -> &SigningKey {
         &self.signing_key
     }
@@ -0,0 +1,893 @@
+//! Exchanging the messages of the threshold protocols over the network.
+//!
+//! Instead of copying messages between terminals, participants can connect to a
+//! [`CoordinatorServer`], which relays messages between everyone in a session.
+//! Messages are encrypted under a [`SessionCode`] that the participants share
+//! out-of-band, so the server can't read them, and signed with their sender's
+//! identity key, so that neither the server nor the other participants can forge them.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    pin::Pin,
+    str::FromStr,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+use chacha20poly1305::{
+    aead::{AeadInPlace, NewAead},
+    ChaCha20Poly1305, Key as SymmetricKey,
+};
+use ed25519_consensus::{Signature, SigningKey, VerificationKey};
+use futures::Stream;
+use penumbra_proto::{
+    custody::threshold::v1::{
+        self as pb, coordinator_service_client::CoordinatorServiceClient,
+        coordinator_service_server::CoordinatorService,
+    },
+    DomainType, Name,
+};
+use rand_core::{CryptoRngCore, OsRng, RngCore};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tokio::sync::watch;
+use tonic::{async_trait, transport::Channel, Request, Response, Status};
+
+use super::Config;
+use crate::terminal::{SigningRequest, Terminal};
+
+/// The number of bytes in a session code.
+const CODE_SIZE: usize = 32;
+/// The number of bytes in a session identifier.
+const SESSION_ID_SIZE: usize = 32;
+/// The number of bytes in our AEAD's authentication tag.
+const TAG_SIZE: usize = 16;
+/// The number of bytes in our nonce.
+const NONCE_SIZE: usize = 12;
+
+/// How long the coordinator keeps a session's messages around.
+const SESSION_LIFETIME: Duration = Duration::from_secs(24 * 60 * 60);
+/// The maximum number of sessions the coordinator holds at once.
+const MAX_SESSIONS: usize = 1024;
+/// The maximum number of participants a session can be restricted to.
+const MAX_PARTICIPANTS: usize = 256;
+/// The maximum number of messages the coordinator accepts in a single session.
+const MAX_MESSAGES_PER_SESSION: usize = 1024;
+/// The maximum size of a single message the coordinator accepts.
+const MAX_MESSAGE_SIZE: usize = 1 << 20;
+/// The maximum total size of the messages the coordinator holds, across every session.
+const MAX_BUFFERED_BYTES: usize = 256 << 20;
+
+/// A secret shared between the participants of a session.
+///
+/// The code identifies the session to the coordinator, and provides the key that
+/// every message in the session is encrypted with.  Anyone holding the code can
+/// read the messages in the session, so it should only be shared with the other
+/// participants, over a channel the coordinator doesn't control.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SessionCode([u8; CODE_SIZE]);
+
+impl SessionCode {
+    /// Generate a code for a new session.
+    pub fn generate(rng: &mut impl CryptoRngCore) -> Self {
+        let mut code = [0u8; CODE_SIZE];
+        rng.fill_bytes(&mut code);
+        Self(code)
+    }
+
+    fn derive(&self, personalization: &[u8]) -> [u8; 32] {
+        blake2b_simd::Params::new()
+            .personal(personalization)
+            .hash_length(32)
+            .to_state()
+            .update(&self.0)
+            .finalize()
+            .as_bytes()
+            .try_into()
+            .expect("hash length is 32 bytes")
+    }
+
+    /// The identifier the coordinator knows this session by.
+    fn session_id(&self) -> Vec<u8> {
+        self.derive(b"relay-session").to_vec()
+    }
+
+    fn key(&self) -> SymmetricKey {
+        self.derive(b"relay-encryption").into()
+    }
+
+    /// Encrypt a message from `sender`.
+    ///
+    /// ciphertext = nonce || <aead tag> || <encrypted data>
+    fn seal(&self, rng: &mut impl CryptoRngCore, sender: &[u8], message: &[u8]) -> Vec<u8> {
+        let mut nonce = [0u8; NONCE_SIZE];
+        rng.fill_bytes(&mut nonce);
+        let mut ciphertext = Vec::with_capacity(NONCE_SIZE + TAG_SIZE + message.len());
+        ciphertext.extend_from_slice(&nonce);
+        // Reserve space for the tag
+        ciphertext.extend_from_slice(&[0u8; TAG_SIZE]);
+        // Include the message, which will be written over in place
+        ciphertext.extend_from_slice(message);
+        // The sender is authenticated, so that the coordinator can't misattribute messages.
+        let tag = ChaCha20Poly1305::new(&self.key())
+            .encrypt_in_place_detached(
+                &nonce.into(),
+                sender,
+                &mut ciphertext[NONCE_SIZE + TAG_SIZE..],
+            )
+            .expect("chacha20poly1305 encryption should not fail");
+        ciphertext[NONCE_SIZE..NONCE_SIZE + TAG_SIZE].copy_from_slice(&tag);
+        ciphertext
+    }
+
+    /// Decrypt a message from `sender`.
+    fn open(&self, sender: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>> {
+        if ciphertext.len() < NONCE_SIZE + TAG_SIZE {
+            anyhow::bail!("failed to decrypt ciphertext");
+        }
+        let (header, message) = ciphertext.split_at(NONCE_SIZE + TAG_SIZE);
+        let mut message = message.to_owned();
+        ChaCha20Poly1305::new(&self.key())
+            .decrypt_in_place_detached(
+                header[..NONCE_SIZE].into(),
+                sender,
+                &mut message,
+                header[NONCE_SIZE..].into(),
+            )
+            .map_err(|_| anyhow!("failed to decrypt ciphertext"))?;
+        Ok(message)
+    }
+}
+
+impl fmt::Display for SessionCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&hex::encode(self.0))
+    }
+}
+
+impl fmt::Debug for SessionCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Don't leak the code into logs.
+        f.write_str("SessionCode(..)")
+    }
+}
+
+impl FromStr for SessionCode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode(s.trim())?;
+        Ok(Self(bytes.try_into().map_err(|_| {
+            anyhow!("session code must be {} hex-encoded bytes", CODE_SIZE)
+        })?))
+    }
+}
+
+/// The data a session's creator signs, binding the session to its participants.
+fn create_message(session_id: &[u8], participants: &[Vec<u8>]) -> Vec<u8> {
+    let mut message = b"penumbra-threshold-create".to_vec();
+    message.extend_from_slice(session_id);
+    for participant in participants {
+        message.extend_from_slice(participant);
+    }
+    message
+}
+
+/// The data a participant signs when posting a message to a session.
+fn post_message(session_id: &[u8], ciphertext: &[u8]) -> Vec<u8> {
+    let mut message = b"penumbra-threshold-post".to_vec();
+    message.extend_from_slice(session_id);
+    message.extend_from_slice(ciphertext);
+    message
+}
+
+fn parse_session_id(session_id: &[u8]) -> Result<(), Status> {
+    if session_id.len() != SESSION_ID_SIZE {
+        return Err(Status::invalid_argument(format!(
+            "session id must be {SESSION_ID_SIZE} bytes"
+        )));
+    }
+    Ok(())
+}
+
+fn parse_verification_key(bytes: &[u8]) -> Result<VerificationKey, Status> {
+    VerificationKey::try_from(bytes)
+        .map_err(|_| Status::invalid_argument("invalid verification key"))
+}
+
+fn verify(vk: &VerificationKey, sig: &[u8], message: &[u8]) -> Result<(), Status> {
+    let sig =
+        Signature::try_from(sig).map_err(|_| Status::invalid_argument("invalid signature"))?;
+    vk.verify(&sig, message)
+        .map_err(|_| Status::unauthenticated("signature does not verify"))
+}
+
+/// A server relaying messages between the participants of threshold sessions.
+///
+/// Sessions must be created before they're used, and are only held in memory, for
+/// up to a day.  The number of sessions, and the size of the messages held across
+/// all of them, are capped.
+#[derive(Clone, Default)]
+pub struct CoordinatorServer {
+    state: Arc<Mutex<ServerState>>,
+}
+
+#[derive(Default)]
+struct ServerState {
+    sessions: HashMap<Vec<u8>, Session>,
+    /// The total size of the messages held in every session.
+    buffered_bytes: usize,
+}
+
+impl ServerState {
+    /// Drop expired sessions, which closes the streams of their subscribers.
+    fn expire(&mut self) {
+        let mut freed = 0;
+        self.sessions.retain(|_, session| {
+            let live = session.created.elapsed() < SESSION_LIFETIME;
+            if !live {
+                freed += session.buffered_bytes;
+            }
+            live
+        });
+        self.buffered_bytes -= freed;
+    }
+}
+
+struct Session {
+    created: Instant,
+    /// The participants allowed to post, or empty if anyone can.
+    participants: HashSet<VerificationKey>,
+    /// The total size of the messages held in this session.
+    buffered_bytes: usize,
+    messages: Arc<watch::Sender<Vec<pb::SubscribeResponse>>>,
+}
+
+impl CoordinatorServer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lock the server's state, after dropping any expired sessions.
+    fn state(&self) -> Result<MutexGuard<'_, ServerState>, Status> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|_| Status::internal("session lock was poisoned"))?;
+        state.expire();
+        Ok(state)
+    }
+}
+
+#[async_trait]
+impl CoordinatorService for CoordinatorServer {
+    type SubscribeStream =
+        Pin<Box<dyn Stream<Item = Result<pb::SubscribeResponse, Status>> + Send + 'static>>;
+
+    async fn create(
+        &self,
+        request: Request<pb::CreateRequest>,
+    ) -> Result<Response<pb::CreateResponse>, Status> {
+        let request = request.into_inner();
+        parse_session_id(&request.session_id)?;
+        if request.participants.len() > MAX_PARTICIPANTS {
+            return Err(Status::invalid_argument(
+                "session has too many participants",
+            ));
+        }
+        let creator = parse_verification_key(&request.creator)?;
+        let participants = request
+            .participants
+            .iter()
+            .map(|participant| parse_verification_key(participant))
+            .collect::<Result<HashSet<_>, _>>()?;
+        if !participants.is_empty() && !participants.contains(&creator) {
+            return Err(Status::invalid_argument(
+                "session creator must be one of its participants",
+            ));
+        }
+        verify(
+            &creator,
+            &request.sig,
+            &create_message(&request.session_id, &request.participants),
+        )?;
+
+        let mut state = self.state()?;
+        if state.sessions.contains_key(&request.session_id) {
+            return Err(Status::already_exists("session already exists"));
+        }
+        if state.sessions.len() >= MAX_SESSIONS {
+            return Err(Status::resource_exhausted(
+                "coordinator has too many sessions",
+            ));
+        }
+        state.sessions.insert(
+            request.session_id,
+            Session {
+                created: Instant::now(),
+                participants,
+                buffered_bytes: 0,
+                messages: Arc::new(watch::channel(Vec::new()).0),
+            },
+        );
+        Ok(Response::new(pb::CreateResponse {}))
+    }
+
+    async fn post(
+        &self,
+        request: Request<pb::PostRequest>,
+    ) -> Result<Response<pb::PostResponse>, Status> {
+        let request = request.into_inner();
+        parse_session_id(&request.session_id)?;
+        if request.ciphertext.len() > MAX_MESSAGE_SIZE {
+            return Err(Status::invalid_argument("message is too large"));
+        }
+        let sender = parse_verification_key(&request.sender)?;
+        verify(
+            &sender,
+            &request.sig,
+            &post_message(&request.session_id, &request.ciphertext),
+        )?;
+
+        let mut guard = self.state()?;
+        let state = &mut *guard;
+        let session = state
+            .sessions
+            .get_mut(&request.session_id)
+            .ok_or_else(|| Status::not_found("session does not exist"))?;
+        if !session.participants.is_empty() && !session.participants.contains(&sender) {
+            return Err(Status::permission_denied(
+                "sender is not a participant in this session",
+            ));
+        }
+        if session.messages.borrow().len() >= MAX_MESSAGES_PER_SESSION {
+            return Err(Status::resource_exhausted("session has too many messages"));
+        }
+        let size = request.ciphertext.len();
+        if state.buffered_bytes + size > MAX_BUFFERED_BYTES {
+            return Err(Status::resource_exhausted(
+                "coordinator is holding too many messages",
+            ));
+        }
+        state.buffered_bytes += size;
+        session.buffered_bytes += size;
+        session.messages.send_modify(|messages| {
+            messages.push(pb::SubscribeResponse {
+                sender: request.sender,
+                ciphertext: request.ciphertext,
+                sig: request.sig,
+            })
+        });
+        Ok(Response::new(pb::PostResponse {}))
+    }
+
+    async fn subscribe(
+        &self,
+        request: Request<pb::SubscribeRequest>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        let session_id = request.into_inner().session_id;
+        let mut updates = self
+            .state()?
+            .sessions
+            .get(&session_id)
+            .ok_or_else(|| Status::not_found("session does not exist"))?
+            .messages
+            .subscribe();
+        let stream = async_stream::stream! {
+            let mut next = 0;
+            loop {
+                let new = updates.borrow_and_update()[next..].to_vec();
+                next += new.len();
+                for message in new {
+                    yield Ok::<_, Status>(message);
+                }
+                // This fails once the session expires, ending the stream.
+                if updates.changed().await.is_err() {
+                    break;
+                }
+            }
+        };
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+/// The part a participant plays in a session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Role {
+    /// Coordinates signing, starting a new session for each request.
+    Coordinator,
+    /// Follows a coordinator's signing session, reading only the coordinator's messages.
+    Follower,
+    /// Takes part in a session where everyone's messages are read, such as a DKG.
+    Peer,
+}
+
+/// The plaintext of a relayed message.
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    /// The full name of the message's proto type, or empty for untyped messages.
+    message_type: String,
+    message: String,
+}
+
+#[derive(Clone)]
+struct ActiveSession {
+    code: SessionCode,
+    /// The key our messages are signed with, once we know which one to use.
+    signing_key: Option<SigningKey>,
+    /// The participants whose messages we read, or `None` to read anyone's.
+    participants: Option<HashSet<VerificationKey>>,
+    /// For followers, the participant coordinating the session, once they've posted.
+    coordinator: Option<VerificationKey>,
+}
+
+impl ActiveSession {
+    /// Check who a message comes from, returning `None` if we shouldn't read it.
+    fn sender(&self, message: &pb::SubscribeResponse) -> Option<VerificationKey> {
+        let sender = VerificationKey::try_from(message.sender.as_slice()).ok()?;
+        let sig = Signature::try_from(message.sig.as_slice()).ok()?;
+        sender
+            .verify(
+                &sig,
+                &post_message(&self.code.session_id(), &message.ciphertext),
+            )
+            .ok()?;
+        if let Some(signing_key) = &self.signing_key {
+            if sender == signing_key.verification_key() {
+                return None;
+            }
+        }
+        if let Some(participants) = &self.participants {
+            if !participants.contains(&sender) {
+                return None;
+            }
+        }
+        if let Some(coordinator) = self.coordinator {
+            if sender != coordinator {
+                return None;
+            }
+        }
+        Some(sender)
+    }
+}
+
+/// A [`Terminal`] exchanging protocol messages through a [`CoordinatorServer`].
+///
+/// Everything else, such as confirming requests and explaining what is happening,
+/// is handled by the inner terminal.
+#[derive(Clone)]
+pub struct NetworkTerminal<T> {
+    inner: T,
+    client: CoordinatorServiceClient<Channel>,
+    role: Role,
+    session: Arc<Mutex<Option<ActiveSession>>>,
+    incoming: Arc<tokio::sync::Mutex<Option<tonic::Streaming<pb::SubscribeResponse>>>>,
+}
+
+impl<T: Terminal> NetworkTerminal<T> {
+    fn new(inner: T, client: CoordinatorServiceClient<Channel>, role: Role) -> Self {
+        Self {
+            inner,
+            client,
+            role,
+            session: Default::default(),
+            incoming: Default::default(),
+        }
+    }
+
+    /// Create a terminal for coordinating signing sessions.
+    ///
+    /// A new session, with a new code, is started for each request, and its code is
+    /// explained through the inner terminal so that it can be shared with the followers.
+    /// Only the holders of the signing config can post to the session.
+    pub fn coordinator(inner: T, client: CoordinatorServiceClient<Channel>) -> Self {
+        Self::new(inner, client, Role::Coordinator)
+    }
+
+    /// Create a terminal following the coordinator of the signing session with this code.
+    ///
+    /// Only messages signed by the holders of one of these configs are read, and the
+    /// first of them to post is taken to be the coordinator.  Our own messages are signed
+    /// with the key of whichever config the coordinator's request is signed with.
+    pub async fn follower(
+        inner: T,
+        client: CoordinatorServiceClient<Channel>,
+        code: SessionCode,
+        configs: &[&Config],
+    ) -> Result<Self> {
+        let participants = configs
+            .iter()
+            .flat_map(|config| config.verification_keys())
+            .collect();
+        let terminal = Self::new(inner, client, Role::Follower);
+        terminal
+            .open(ActiveSession {
+                code,
+                signing_key: None,
+                participants: Some(participants),
+                coordinator: None,
+            })
+            .await?;
+        Ok(terminal)
+    }
+
+    /// Create a session with this code, for a protocol where everyone's messages are
+    /// read, such as a DKG, and join it.
+    ///
+    /// The participants of these protocols aren't known in advance, so anyone can post
+    /// to the session; each participant signs their messages with a fresh key, which
+    /// keeps their messages from being forged, and the protocols authenticate each other.
+    pub async fn create(
+        inner: T,
+        client: CoordinatorServiceClient<Channel>,
+        code: SessionCode,
+    ) -> Result<Self> {
+        let terminal = Self::new(inner, client, Role::Peer);
+        let session = ActiveSession {
+            code,
+            signing_key: Some(SigningKey::new(OsRng)),
+            participants: None,
+            coordinator: None,
+        };
+        terminal.create_session(&session).await?;
+        terminal.open(session).await?;
+        Ok(terminal)
+    }
+
+    /// Join the session with this code, which another participant created with
+    /// [`NetworkTerminal::create`].
+    pub async fn join(
+        inner: T,
+        client: CoordinatorServiceClient<Channel>,
+        code: SessionCode,
+    ) -> Result<Self> {
+        let terminal = Self::new(inner, client, Role::Peer);
+        terminal
+            .open(ActiveSession {
+                code,
+                signing_key: Some(SigningKey::new(OsRng)),
+                participants: None,
+                coordinator: None,
+            })
+            .await?;
+        Ok(terminal)
+    }
+
+    /// Ask the coordinator to create a session, which only its participants can post to.
+    async fn create_session(&self, session: &ActiveSession) -> Result<()> {
+        let signing_key = session
+            .signing_key
+            .as_ref()
+            .ok_or_else(|| anyhow!("the session's creator must have a signing key"))?;
+        let session_id = session.code.session_id();
+        let participants: Vec<Vec<u8>> = session
+            .participants
+            .iter()
+            .flatten()
+            .map(|vk| vk.as_bytes().to_vec())
+            .collect();
+        let sig = signing_key.sign(&create_message(&session_id, &participants));
+        self.client
+            .clone()
+            .create(pb::CreateRequest {
+                session_id,
+                participants,
+                creator: signing_key.verification_key().as_bytes().to_vec(),
+                sig: sig.to_bytes().to_vec(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Subscribe to a session, replacing any previous session.
+    async fn open(&self, session: ActiveSession) -> Result<()> {
+        let incoming = self
+            .client
+            .clone()
+            .subscribe(pb::SubscribeRequest {
+                session_id: session.code.session_id(),
+            })
+            .await?
+            .into_inner();
+        *self.incoming.lock().await = Some(incoming);
+        *self.lock_session()? = Some(session);
+        Ok(())
+    }
+
+    fn lock_session(&self) -> Result<MutexGuard<'_, Option<ActiveSession>>> {
+        self.session
+            .lock()
+            .map_err(|_| anyhow!("session lock was poisoned"))
+    }
+
+    fn active_session(&self) -> Result<ActiveSession> {
+        self.lock_session()?
+            .clone()
+            .ok_or_else(|| anyhow!("no session has been started"))
+    }
+
+    async fn post(&self, envelope: Envelope) -> Result<()> {
+        let session = self.active_session()?;
+        let signing_key = session
+            .signing_key
+            .ok_or_else(|| anyhow!("no identity has been chosen for this session"))?;
+        let sender = signing_key.verification_key().as_bytes().to_vec();
+        let session_id = session.code.session_id();
+        let ciphertext = session
+            .code
+            .seal(&mut OsRng, &sender, &serde_json::to_vec(&envelope)?);
+        let sig = signing_key.sign(&post_message(&session_id, &ciphertext));
+        self.client
+            .clone()
+            .post(pb::PostRequest {
+                session_id,
+                sender,
+                ciphertext,
+                sig: sig.to_bytes().to_vec(),
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Wait for the next message from another participant that we should read.
+    async fn next_envelope(&self) -> Result<Envelope> {
+        let mut incoming = self.incoming.lock().await;
+        let incoming = incoming
+            .as_mut()
+            .ok_or_else(|| anyhow!("no session has been started"))?;
+        loop {
+            let message = incoming
+                .message()
+                .await?
+                .ok_or_else(|| anyhow!("the coordinator closed the session"))?;
+            let session = self.active_session()?;
+            let Some(sender) = session.sender(&message) else {
+                continue;
+            };
+            let plaintext = match session.code.open(&message.sender, &message.ciphertext) {
+                Ok(plaintext) => plaintext,
+                Err(_) => {
+                    self.inner
+                        .explain("Ignoring a message that could not be decrypted")?;
+                    continue;
+                }
+            };
+            match serde_json::from_slice(&plaintext) {
+                Ok(envelope) => {
+                    // Once the coordinator has been heard from, we only read their messages.
+                    if self.role == Role::Follower && session.coordinator.is_none() {
+                        if let Some(session) = self.lock_session()?.as_mut() {
+                            session.coordinator = Some(sender);
+                        }
+                    }
+                    return Ok(envelope);
+                }
+                Err(e) => {
+                    self.inner
+                        .explain(&format!("Ignoring a malformed message: {:#}", e))?;
+                    continue;
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<T: Terminal + Send + Sync> Terminal for NetworkTerminal<T> {
+    async fn confirm_request(&self, request: &SigningRequest) -> Result<bool> {
+        self.inner.confirm_request(request).await
+    }
+
+    fn explain(&self, msg: &str) -> Result<()> {
+        self.inner.explain(msg)
+    }
+
+    async fn broadcast(&self, data: &str) -> Result<()> {
+        self.post(Envelope {
+            message_type: String::new(),
+            message: data.to_owned(),
+        })
+        .await
+    }
+
+    async fn send<D>(&self, message: &D) -> Result<()>
+    where
+        D: DomainType + Sync,
+        anyhow::Error: From<<D as TryFrom<<D as DomainType>::Proto>>::Error>,
+        <D as DomainType>::Proto: Serialize,
+    {
+        self.post(Envelope {
+            message_type: <D as DomainType>::Proto::full_name(),
+            message: serde_json::to_string(&message.to_proto())?,
+        })
+        .await
+    }
+
+    async fn start_session(&self, config: &Config) -> Result<()> {
+        match self.role {
+            Role::Coordinator => {
+                let session = ActiveSession {
+                    code: SessionCode::generate(&mut OsRng),
+                    signing_key: Some(config.signing_key().clone()),
+                    participants: Some(config.verification_keys()),
+                    coordinator: None,
+                };
+                let code = session.code;
+                self.create_session(&session).await?;
+                self.open(session).await?;
+                self.inner.explain(&format!(
+                    "Share this session code with the other signers: {code}"
+                ))
+            }
+            Role::Follower => {
+                // Sign our replies with the key the coordinator expects.
+                if let Some(session) = self.lock_session()?.as_mut() {
+                    session.signing_key = Some(config.signing_key().clone());
+                }
+                Ok(())
+            }
+            Role::Peer => Ok(()),
+        }
+    }
+
+    async fn next_response<D>(&self) -> Result<D>
+    where
+        D: DomainType,
+        anyhow::Error: From<<D as TryFrom<<D as DomainType>::Proto>>::Error>,
+        <D as DomainType>::Proto: DeserializeOwned,
+    {
+        let message_type = <D as DomainType>::Proto::full_name();
+        loop {
+            let envelope = self.next_envelope().await?;
+            // Messages for other rounds, or from participants that aren't needed, are skipped.
+            if envelope.message_type != message_type {
+                continue;
+            }
+            let parsed = serde_json::from_str::<<D as DomainType>::Proto>(&envelope.message)
+                .map_err(anyhow::Error::from)
+                .and_then(|proto| D::try_from(proto).map_err(anyhow::Error::from));
+            match parsed {
+                Ok(message) => return Ok(message),
+                Err(e) => {
+                    self.inner
+                        .explain(&format!("Ignoring a malformed message: {:#}", e))?;
+                    continue;
+                }
+            }
+        }
+    }
+
+    /// Lines are read from the inner terminal, since they come from the local user.
+    async fn read_line_raw(&self) -> Result<String> {
+        self.inner.read_line_raw().await
+    }
+
+    async fn get_password(&self) -> Result<String> {
+        self.inner.get_password().await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_session_code_roundtrip() -> Result<()> {
+        let code = SessionCode::generate(&mut OsRng);
+        assert_eq!(code, code.to_string().parse()?);
+
+        let sender = [1u8; 32];
+        let ciphertext = code.seal(&mut OsRng, &sender, b"round 1");
+        assert_eq!(code.open(&sender, &ciphertext)?, b"round 1");
+        // The sender is authenticated.
+        assert!(code.open(&[2u8; 32], &ciphertext).is_err());
+        // Other sessions can't read the message.
+        assert!(SessionCode::generate(&mut OsRng)
+            .open(&sender, &ciphertext)
+            .is_err());
+        Ok(())
+    }
+
+    fn create_request(
+        creator: &SigningKey,
+        session_id: &[u8],
+        participants: &[VerificationKey],
+    ) -> Request<pb::CreateRequest> {
+        let participants: Vec<Vec<u8>> = participants
+            .iter()
+            .map(|vk| vk.as_bytes().to_vec())
+            .collect();
+        Request::new(pb::CreateRequest {
+            session_id: session_id.to_vec(),
+            sig: creator
+                .sign(&create_message(session_id, &participants))
+                .to_bytes()
+                .to_vec(),
+            participants,
+            creator: creator.verification_key().as_bytes().to_vec(),
+        })
+    }
+
+    fn post_request(
+        sender: &SigningKey,
+        session_id: &[u8],
+        ciphertext: &[u8],
+    ) -> Request<pb::PostRequest> {
+        Request::new(pb::PostRequest {
+            session_id: session_id.to_vec(),
+            sender: sender.verification_key().as_bytes().to_vec(),
+            ciphertext: ciphertext.to_vec(),
+            sig: sender
+                .sign(&post_message(session_id, ciphertext))
+                .to_bytes()
+                .to_vec(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_only_participants_can_post() -> Result<()> {
+        let server = CoordinatorServer::new();
+        let alice = SigningKey::new(OsRng);
+        let bob = SigningKey::new(OsRng);
+        let mallory = SigningKey::new(OsRng);
+        let participants = [alice.verification_key(), bob.verification_key()];
+        let session_id = SessionCode::generate(&mut OsRng).session_id();
+
+        // Sessions aren't created implicitly.
+        assert!(server
+            .post(post_request(&alice, &session_id, b"round 1"))
+            .await
+            .is_err());
+        assert!(server
+            .subscribe(Request::new(pb::SubscribeRequest {
+                session_id: session_id.clone(),
+            }))
+            .await
+            .is_err());
+        // Only a participant can create the session, and only once.
+        assert!(server
+            .create(create_request(&mallory, &session_id, &participants))
+            .await
+            .is_err());
+        server
+            .create(create_request(&alice, &session_id, &participants))
+            .await?;
+        assert!(server
+            .create(create_request(&bob, &session_id, &participants))
+            .await
+            .is_err());
+
+        server
+            .post(post_request(&bob, &session_id, b"round 1"))
+            .await?;
+        assert!(server
+            .post(post_request(&mallory, &session_id, b"round 1"))
+            .await
+            .is_err());
+        // Claiming to be a participant doesn't help without their key.
+        let mut forged = post_request(&mallory, &session_id, b"round 1");
+        forged.get_mut().sender = bob.verification_key().as_bytes().to_vec();
+        assert!(server.post(forged).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_coordinator_limits_sessions() -> Result<()> {
+        let server = CoordinatorServer::new();
+        let creator = SigningKey::new(OsRng);
+        for _ in 0..MAX_SESSIONS {
+            let session_id = SessionCode::generate(&mut OsRng).session_id();
+            server
+                .create(create_request(&creator, &session_id, &[]))
+                .await?;
+        }
+        let session_id = SessionCode::generate(&mut OsRng).session_id();
+        let status = server
+            .create(create_request(&creator, &session_id, &[]))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+        Ok(())
+    }
+}
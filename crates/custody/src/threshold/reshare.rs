@@ -0,0 +1,592 @@
+//! Resharing a threshold key to new participants, without changing the key itself.
+//!
+//! Each current share holder taking part, or "dealer", weights its share by its Lagrange
+//! coefficient among the dealers, so that the weighted shares sum to the secret key. It then
+//! splits its weighted share among the recipients using a fresh polynomial, and broadcasts
+//! commitments to that polynomial's coefficients, so that recipients can check their parts.
+//! Each recipient sums the parts it receives into a share of the same key.
+//!
+//! The new shares lie on a fresh polynomial, so they can't be combined with the old ones.
+//! This means that participants who don't receive a new share lose the ability to sign,
+//! and that resharing to the same participants, with the same threshold, refreshes their shares.
+use anyhow::{anyhow, Result};
+use decaf377::{Element, Encoding, Fr};
+use decaf377_frost as frost;
+use ed25519_consensus::{Signature, SigningKey, VerificationKey};
+use penumbra_keys::FullViewingKey;
+use penumbra_proto::{custody::threshold::v1 as pb, DomainType, Message};
+use rand_core::CryptoRngCore;
+use std::collections::{HashMap, HashSet};
+
+use super::{
+    dkg::encryption::{DecryptionKey, EncryptionKey},
+    Config,
+};
+
+fn scalar_from_bytes(bytes: &[u8]) -> Result<Fr> {
+    let bytes: [u8; 32] = bytes.try_into()?;
+    Fr::from_bytes_checked(&bytes).map_err(|_| anyhow!("invalid scalar"))
+}
+
+fn element_from_bytes(bytes: &[u8]) -> Result<Element> {
+    Encoding(bytes.try_into()?)
+        .vartime_decompress()
+        .map_err(|_| anyhow!("invalid group element"))
+}
+
+/// The point at which the polynomials are evaluated for the participant with this key.
+fn identifier(vk: &VerificationKey) -> Result<Fr> {
+    scalar_from_bytes(&frost::Identifier::derive(vk.as_bytes())?.serialize())
+}
+
+/// The coefficient to weight the value at `x` with, to interpolate the value at zero from `xs`.
+fn lagrange_coefficient(x: Fr, xs: &[Fr]) -> Result<Fr> {
+    let mut numerator = Fr::ONE;
+    let mut denominator = Fr::ONE;
+    for &x_j in xs.iter().filter(|&&x_j| x_j != x) {
+        numerator = numerator * x_j;
+        denominator = denominator * (x_j - x);
+    }
+    Ok(numerator
+        * denominator.inverse().ok_or(anyhow!(
+            "duplicate identifiers when computing lagrange coefficient"
+        ))?)
+}
+
+/// Evaluate the polynomial with these coefficients at `x`.
+fn evaluate(coefficients: &[Fr], x: Fr) -> Fr {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Fr::ZERO, |acc, &coefficient| acc * x + coefficient)
+}
+
+/// Evaluate the polynomial these coefficients commit to at `x`, in the exponent.
+fn evaluate_commitment(commitment: &[Element], x: Fr) -> Element {
+    commitment
+        .iter()
+        .rev()
+        .fold(Element::default(), |acc, &coefficient| {
+            x * acc + coefficient
+        })
+}
+
+/// The message we send in round 1 of the resharing protocol.
+#[derive(Clone)]
+pub struct Round1 {
+    /// The verification key acting as our identity.
+    vk: VerificationKey,
+    /// An encryption key to receive our new share with, if we're receiving one.
+    epk: Option<EncryptionKey>,
+    /// The threshold of the current key, if we're dealing out our share of it.
+    old_threshold: Option<u16>,
+}
+
+impl From<Round1> for pb::ReshareRound1 {
+    fn from(value: Round1) -> Self {
+        Self {
+            vk: value.vk.as_bytes().to_vec(),
+            epk: value
+                .epk
+                .map(|epk| epk.as_bytes().to_vec())
+                .unwrap_or_default(),
+            old_threshold: value.old_threshold.unwrap_or_default().into(),
+        }
+    }
+}
+
+impl TryFrom<pb::ReshareRound1> for Round1 {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ReshareRound1) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            vk: value.vk.as_slice().try_into()?,
+            epk: if value.epk.is_empty() {
+                None
+            } else {
+                Some(value.epk.as_slice().try_into()?)
+            },
+            old_threshold: match value.old_threshold {
+                0 => None,
+                t => Some(t.try_into()?),
+            },
+        })
+    }
+}
+
+impl DomainType for Round1 {
+    type Proto = pb::ReshareRound1;
+}
+
+/// What a dealer sends each recipient, encrypted.
+#[derive(Clone)]
+struct Package {
+    /// The recipient's part of the dealer's weighted share.
+    share: Fr,
+    /// The full viewing key, which newcomers don't know yet.
+    fvk: FullViewingKey,
+}
+
+impl From<Package> for pb::ResharePackage {
+    fn from(value: Package) -> Self {
+        Self {
+            share: value.share.to_bytes().to_vec(),
+            full_viewing_key: Some(value.fvk.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::ResharePackage> for Package {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ResharePackage) -> std::result::Result<Self, Self::Error> {
+        Ok(Self {
+            share: scalar_from_bytes(&value.share)?,
+            fvk: value
+                .full_viewing_key
+                .ok_or(anyhow!("ResharePackage missing full viewing key"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl DomainType for Package {
+    type Proto = pb::ResharePackage;
+}
+
+fn round2_inner_to_pb(
+    dealers: &[VerificationKey],
+    threshold: u16,
+    commitment: &[Element],
+    encrypted_packages: &HashMap<VerificationKey, Vec<u8>>,
+) -> pb::reshare_round2::Inner {
+    // Need to sort to guarantee a deterministic encoding for signing.
+    let encrypted_packages = {
+        let mut acc: Vec<_> = encrypted_packages
+            .iter()
+            .map(|(k, v)| pb::reshare_round2::TargetedPackage {
+                vk: k.as_bytes().to_vec(),
+                encrypted_package: v.clone(),
+            })
+            .collect();
+        acc.sort_by_key(|x| x.vk.clone());
+        acc
+    };
+    pb::reshare_round2::Inner {
+        dealers: dealers.iter().map(|vk| vk.as_bytes().to_vec()).collect(),
+        threshold: threshold.into(),
+        commitment: commitment
+            .iter()
+            .map(|x| x.vartime_compress().0.to_vec())
+            .collect(),
+        encrypted_packages,
+    }
+}
+
+/// The message we send in round 2 of the resharing protocol, if we're dealing out our share.
+#[derive(Clone, Debug)]
+pub struct Round2 {
+    /// The dealers we computed our Lagrange coefficient with, sorted.
+    dealers: Vec<VerificationKey>,
+    /// The threshold of the new key.
+    threshold: u16,
+    /// Commitments to the coefficients of the polynomial splitting our weighted share.
+    commitment: Vec<Element>,
+    /// For each recipient, a ciphertext containing the package they need.
+    encrypted_packages: HashMap<VerificationKey, Vec<u8>>,
+    /// A declaration of our identity.
+    vk: VerificationKey,
+    /// A signature over everything else.
+    sig: Signature,
+}
+
+impl From<Round2> for pb::ReshareRound2 {
+    fn from(value: Round2) -> Self {
+        Self {
+            inner: Some(round2_inner_to_pb(
+                &value.dealers,
+                value.threshold,
+                &value.commitment,
+                &value.encrypted_packages,
+            )),
+            vk: value.vk.as_bytes().to_vec(),
+            sig: value.sig.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::ReshareRound2> for Round2 {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::ReshareRound2) -> std::result::Result<Self, Self::Error> {
+        let inner = value.inner.ok_or(anyhow!("ReshareRound2 missing inner"))?;
+        Ok(Self {
+            dealers: inner
+                .dealers
+                .iter()
+                .map(|x| Ok(x.as_slice().try_into()?))
+                .collect::<Result<_>>()?,
+            threshold: inner.threshold.try_into()?,
+            commitment: inner
+                .commitment
+                .iter()
+                .map(|x| element_from_bytes(x))
+                .collect::<Result<_>>()?,
+            encrypted_packages: inner
+                .encrypted_packages
+                .into_iter()
+                .map(|x| Ok((x.vk.as_slice().try_into()?, x.encrypted_package)))
+                .collect::<Result<HashMap<_, _>, Self::Error>>()?,
+            vk: value.vk.as_slice().try_into()?,
+            sig: value.sig.as_slice().try_into()?,
+        })
+    }
+}
+
+impl DomainType for Round2 {
+    type Proto = pb::ReshareRound2;
+}
+
+impl Round2 {
+    fn make(
+        sk: &SigningKey,
+        dealers: Vec<VerificationKey>,
+        threshold: u16,
+        commitment: Vec<Element>,
+        encrypted_packages: HashMap<VerificationKey, Vec<u8>>,
+    ) -> Self {
+        let data = round2_inner_to_pb(&dealers, threshold, &commitment, &encrypted_packages)
+            .encode_to_vec();
+        let sig = sk.sign(&data);
+        Self {
+            dealers,
+            threshold,
+            commitment,
+            encrypted_packages,
+            vk: sk.verification_key(),
+            sig,
+        }
+    }
+
+    fn verify(&self) -> Result<()> {
+        let data = round2_inner_to_pb(
+            &self.dealers,
+            self.threshold,
+            &self.commitment,
+            &self.encrypted_packages,
+        )
+        .encode_to_vec();
+        Ok(self.vk.verify(&self.sig, &data)?)
+    }
+}
+
+/// The state we need to remember after round 1.
+pub struct Round1State {
+    /// The message we sent, which determines whether we deal or receive.
+    round1: Round1,
+    /// Our current config, if we're dealing out our share.
+    config: Option<Config>,
+    /// The signing key acting as our identity, which current share holders keep.
+    sk: SigningKey,
+    /// The decryption key for our new share, if we're receiving one.
+    edk: Option<DecryptionKey>,
+    /// The threshold of the new key.
+    threshold: u16,
+}
+
+/// The state we need to remember after round 2.
+pub struct Round2State {
+    /// Our current config, if we're dealing out our share.
+    config: Option<Config>,
+    sk: SigningKey,
+    edk: Option<DecryptionKey>,
+    threshold: u16,
+    /// The dealers, sorted.
+    dealers: Vec<VerificationKey>,
+    /// The recipients of the new shares.
+    recipients: HashSet<VerificationKey>,
+    /// The message we sent this round, which counts towards our own share.
+    round2: Option<Round2>,
+}
+
+impl Round2State {
+    /// The number of round 2 messages we need from the other participants.
+    pub fn expected_messages(&self) -> usize {
+        match self.edk {
+            Some(_) => self.dealers.len() - usize::from(self.round2.is_some()),
+            None => 0,
+        }
+    }
+}
+
+pub fn round1(
+    mut rng: impl CryptoRngCore,
+    config: Option<&Config>,
+    threshold: u16,
+    receive: bool,
+) -> Result<(Round1, Round1State)> {
+    if config.is_none() && !receive {
+        anyhow::bail!("participants without a current share must receive a new share");
+    }
+    let sk = match config {
+        Some(config) => config.signing_key().clone(),
+        None => SigningKey::new(&mut rng),
+    };
+    let edk = receive.then(|| DecryptionKey::new(&mut rng));
+    let round1 = Round1 {
+        vk: sk.verification_key(),
+        epk: edk.as_ref().map(DecryptionKey::public),
+        old_threshold: config.map(Config::threshold),
+    };
+    let state = Round1State {
+        round1: round1.clone(),
+        config: config.cloned(),
+        sk,
+        edk,
+        threshold,
+    };
+    Ok((round1, state))
+}
+
+pub fn round2(
+    mut rng: impl CryptoRngCore,
+    state: Round1State,
+    messages: Vec<Round1>,
+) -> Result<(Option<Round2>, Round2State)> {
+    // Check that all verification keys are unique, and not equal to my own
+    {
+        let mut seen = HashSet::new();
+        seen.insert(state.round1.vk);
+        for m in &messages {
+            if !seen.insert(m.vk) {
+                anyhow::bail!("duplicate verification key in messages");
+            }
+        }
+    }
+    let participants: Vec<Round1> = messages.into_iter().chain([state.round1]).collect();
+
+    let dealers = {
+        let mut acc: Vec<_> = participants
+            .iter()
+            .filter_map(|x| Some((x.vk, x.old_threshold?)))
+            .collect();
+        acc.sort_by_key(|(vk, _)| vk.to_bytes());
+        acc
+    };
+    let old_threshold = match dealers.first() {
+        Some((_, t)) => *t,
+        None => anyhow::bail!("no current share holders are dealing out their shares"),
+    };
+    if dealers.iter().any(|(_, t)| *t != old_threshold) {
+        anyhow::bail!("dealers disagree on the threshold of the current key");
+    }
+    if dealers.len() < usize::from(old_threshold) {
+        anyhow::bail!(
+            "resharing needs at least {} current share holders, but only {} are dealing",
+            old_threshold,
+            dealers.len()
+        );
+    }
+    let dealers: Vec<_> = dealers.into_iter().map(|(vk, _)| vk).collect();
+
+    let recipients: HashMap<_, _> = participants
+        .iter()
+        .filter_map(|x| Some((x.vk, x.epk?)))
+        .collect();
+    if state.threshold < 2 {
+        anyhow::bail!("threshold must be >= 2");
+    }
+    if recipients.len() < usize::from(state.threshold) {
+        anyhow::bail!(
+            "a threshold of {} needs at least as many recipients, but there are only {}",
+            state.threshold,
+            recipients.len()
+        );
+    }
+
+    let round2 = match &state.config {
+        Some(config) => {
+            let xs = dealers.iter().map(identifier).collect::<Result<Vec<_>>>()?;
+            let weight = lagrange_coefficient(identifier(&state.sk.verification_key())?, &xs)?;
+            let share = scalar_from_bytes(&config.spend_key_share().serialize())?;
+            let coefficients: Vec<Fr> = std::iter::once(weight * share)
+                .chain((1..state.threshold).map(|_| Fr::rand(&mut rng)))
+                .collect();
+            let commitment = coefficients
+                .iter()
+                .map(|&coefficient| coefficient * Element::GENERATOR)
+                .collect();
+            let encrypted_packages = recipients
+                .iter()
+                .map(|(vk, epk)| {
+                    let package = Package {
+                        share: evaluate(&coefficients, identifier(vk)?),
+                        fvk: config.fvk().clone(),
+                    };
+                    Ok((*vk, epk.encrypt(&mut rng, &package.encode_to_vec())))
+                })
+                .collect::<Result<HashMap<_, _>>>()?;
+            Some(Round2::make(
+                &state.sk,
+                dealers.clone(),
+                state.threshold,
+                commitment,
+                encrypted_packages,
+            ))
+        }
+        None => None,
+    };
+
+    let state = Round2State {
+        config: state.config,
+        sk: state.sk,
+        edk: state.edk,
+        threshold: state.threshold,
+        dealers,
+        recipients: recipients.into_keys().collect(),
+        round2: round2.clone(),
+    };
+    Ok((round2, state))
+}
+
+/// Combine the dealers' messages into our new config, if we're receiving a new share.
+pub fn round3(
+    mut rng: impl CryptoRngCore,
+    state: Round2State,
+    messages: Vec<Round2>,
+) -> Result<Option<Config>> {
+    let edk = match state.edk {
+        Some(edk) => edk,
+        None => return Ok(None),
+    };
+    let messages: Vec<_> = messages.into_iter().chain(state.round2).collect();
+    {
+        let senders: HashSet<_> = messages.iter().map(|x| x.vk).collect();
+        if senders.len() != messages.len()
+            || senders != state.dealers.iter().cloned().collect::<HashSet<_>>()
+        {
+            anyhow::bail!("round 2 messages don't match the dealers from round 1");
+        }
+    }
+
+    let our_vk = state.sk.verification_key();
+    let our_x = identifier(&our_vk)?;
+    let dealer_xs = state
+        .dealers
+        .iter()
+        .map(identifier)
+        .collect::<Result<Vec<_>>>()?;
+    let mut fvk: Option<FullViewingKey> = state.config.as_ref().map(|c| c.fvk().clone());
+    let mut share = Fr::ZERO;
+    let mut group_public = Element::default();
+    for message in &messages {
+        message.verify()?;
+        if message.dealers != state.dealers {
+            anyhow::bail!("dealers disagree on who is dealing");
+        }
+        if message.threshold != state.threshold {
+            anyhow::bail!("dealers disagree on the threshold of the new key");
+        }
+        if message.commitment.len() != usize::from(state.threshold) {
+            anyhow::bail!("commitment does not match the threshold of the new key");
+        }
+        if message
+            .encrypted_packages
+            .keys()
+            .cloned()
+            .collect::<HashSet<_>>()
+            != state.recipients
+        {
+            anyhow::bail!("dealers disagree on who is receiving a new share");
+        }
+        // If we hold a current share, we can check each dealer against their current share.
+        if let Some(config) = &state.config {
+            let verifying_share = config
+                .verifying_share(&message.vk)
+                .ok_or(anyhow!("dealer does not hold a share of the current key"))?;
+            let weight = lagrange_coefficient(identifier(&message.vk)?, &dealer_xs)?;
+            if message.commitment[0] != weight * element_from_bytes(&verifying_share.serialize())? {
+                anyhow::bail!("dealer committed to a different share than the one they hold");
+            }
+        }
+        group_public = group_public + message.commitment[0];
+
+        let ciphertext = message
+            .encrypted_packages
+            .get(&our_vk)
+            .ok_or(anyhow!("no encrypted package for this recipient"))?;
+        let package = Package::decode(edk.decrypt(&mut rng, ciphertext)?.as_slice())?;
+        if package.share * Element::GENERATOR != evaluate_commitment(&message.commitment, our_x) {
+            anyhow::bail!("share does not match the dealer's commitment");
+        }
+        match &fvk {
+            Some(fvk) if *fvk != package.fvk => {
+                anyhow::bail!("dealers disagree on the full viewing key")
+            }
+            Some(_) => {}
+            None => fvk = Some(package.fvk),
+        }
+        share = share + package.share;
+    }
+    let fvk = fvk.ok_or(anyhow!("no dealers sent a full viewing key"))?;
+    if group_public != element_from_bytes(&fvk.spend_verification_key().to_bytes())? {
+        anyhow::bail!("reshared key does not match the full viewing key");
+    }
+
+    let verifying_shares = state
+        .recipients
+        .iter()
+        .map(|vk| {
+            let x = identifier(vk)?;
+            let verifying_share = messages
+                .iter()
+                .map(|message| evaluate_commitment(&message.commitment, x))
+                .fold(Element::default(), |acc, x| acc + x);
+            Ok((
+                *vk,
+                frost::keys::VerifyingShare::deserialize(
+                    verifying_share.vartime_compress().0.to_vec(),
+                )?,
+            ))
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+    Ok(Some(Config::from_reshared(
+        state.threshold,
+        fvk,
+        frost::keys::SigningShare::deserialize(share.to_bytes().to_vec())?,
+        state.sk,
+        verifying_shares,
+    )))
+}
+
+#[cfg(test)]
+mod test {
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn test_lagrange_interpolation() -> Result<()> {
+        let coefficients: Vec<Fr> = (0..3).map(|_| Fr::rand(&mut OsRng)).collect();
+        let xs: Vec<Fr> = (0..4).map(|_| Fr::rand(&mut OsRng)).collect();
+        // Any 3 points determine the polynomial, and so its value at zero.
+        for points in [&xs[..3], &xs[1..]] {
+            let interpolated = points.iter().try_fold(Fr::ZERO, |acc, &x| {
+                Ok::<_, anyhow::Error>(
+                    acc + lagrange_coefficient(x, points)? * evaluate(&coefficients, x),
+                )
+            })?;
+            assert_eq!(interpolated, coefficients[0]);
+        }
+        // Commitments evaluate to the commitment of the evaluation.
+        let commitment: Vec<Element> = coefficients
+            .iter()
+            .map(|&coefficient| coefficient * Element::GENERATOR)
+            .collect();
+        assert_eq!(
+            evaluate_commitment(&commitment, xs[0]),
+            evaluate(&coefficients, xs[0]) * Element::GENERATOR
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,585 @@
+//! Threshold signing that tolerates unresponsive or misbehaving signers, following ROAST.
+//!
+//! With plain FROST, the signers are chosen up front, and if any of them goes silent, or
+//! sends an invalid share, signing has to be restarted from scratch.  Instead, following
+//! [ROAST](https://eprint.iacr.org/2022/550), every signer sends the coordinator fresh
+//! commitments, and the coordinator starts a signing attempt whenever enough signers with
+//! fresh commitments aren't busy with another attempt.  Each reply to an attempt carries
+//! new commitments, so a responsive signer is soon available for another attempt, while an
+//! unresponsive signer only holds up the attempts it is part of.  Attempts run concurrently,
+//! and the first to gather every share produces the signature.
+//!
+//! Signers whose shares turn out to be invalid are reported, and left out of any further
+//! attempts.  As long as a threshold of signers are responsive and honest, a signature is
+//! produced after at most `n - t + 1` attempts.
+//!
+//! The coordinator is one of the signers, and takes part in every attempt.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    iter,
+};
+
+use anyhow::{anyhow, Result};
+use decaf377_frost as frost;
+use ed25519_consensus::{Signature, SigningKey, VerificationKey};
+use frost::{round1::SigningCommitments, round2::SignatureShare};
+use penumbra_proto::{penumbra::custody::threshold::v1 as pb, DomainType, Message};
+use rand_core::CryptoRngCore;
+
+use crate::terminal::SigningRequest;
+
+use super::{
+    config::Config,
+    sign::{self, CoordinatorRound1, CoordinatorRound2},
+    SigningResponse,
+};
+
+/// The FROST identifier of the signer with this verification key.
+fn identifier(pk: &VerificationKey) -> Result<frost::Identifier> {
+    Ok(frost::Identifier::derive(pk.as_bytes().as_slice())?)
+}
+
+/// A message the coordinator sends after the signing request.
+#[derive(Debug, Clone)]
+pub enum CoordinatorMessage {
+    /// A new signing attempt, with the commitments of the signers taking part.
+    Attempt {
+        attempt: u64,
+        commitments: CoordinatorRound2,
+    },
+    /// The end of signing.
+    Finished {
+        success: bool,
+        misbehaving: Vec<VerificationKey>,
+    },
+}
+
+impl From<CoordinatorMessage> for pb::RoastCoordinatorMessage {
+    fn from(value: CoordinatorMessage) -> Self {
+        let message = match value {
+            CoordinatorMessage::Attempt {
+                attempt,
+                commitments,
+            } => pb::roast_coordinator_message::Message::Attempt(
+                pb::roast_coordinator_message::Attempt {
+                    attempt,
+                    commitments: Some(commitments.into()),
+                },
+            ),
+            CoordinatorMessage::Finished {
+                success,
+                misbehaving,
+            } => pb::roast_coordinator_message::Message::Finished(
+                pb::roast_coordinator_message::Finished {
+                    success,
+                    misbehaving: misbehaving
+                        .into_iter()
+                        .map(|pk| pb::VerificationKey {
+                            inner: pk.to_bytes().to_vec(),
+                        })
+                        .collect(),
+                },
+            ),
+        };
+        Self {
+            message: Some(message),
+        }
+    }
+}
+
+impl TryFrom<pb::RoastCoordinatorMessage> for CoordinatorMessage {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::RoastCoordinatorMessage) -> Result<Self, Self::Error> {
+        match value.message.ok_or(anyhow!("missing message"))? {
+            pb::roast_coordinator_message::Message::Attempt(attempt) => Ok(Self::Attempt {
+                attempt: attempt.attempt,
+                commitments: attempt
+                    .commitments
+                    .ok_or(anyhow!("missing commitments"))?
+                    .try_into()?,
+            }),
+            pb::roast_coordinator_message::Message::Finished(finished) => Ok(Self::Finished {
+                success: finished.success,
+                misbehaving: finished
+                    .misbehaving
+                    .into_iter()
+                    .map(|pk| pk.inner.as_slice().try_into())
+                    .collect::<Result<Vec<_>, _>>()?,
+            }),
+        }
+    }
+}
+
+impl DomainType for CoordinatorMessage {
+    type Proto = pb::RoastCoordinatorMessage;
+}
+
+fn follower_inner_to_pb(
+    attempt: u64,
+    shares: Vec<SignatureShare>,
+    commitments: Vec<SigningCommitments>,
+) -> pb::roast_follower_message::Inner {
+    pb::roast_follower_message::Inner {
+        attempt,
+        shares: shares.into_iter().map(|x| x.into()).collect(),
+        commitments: commitments.into_iter().map(|x| x.into()).collect(),
+    }
+}
+
+/// The message a follower sends: its shares for an attempt, and fresh commitments.
+#[derive(Debug, Clone)]
+pub struct FollowerMessage {
+    /// The attempt the shares are for, or 0 for the first message, which has no shares.
+    attempt: u64,
+    /// A share of each signature we need to produce.
+    shares: Vec<SignatureShare>,
+    /// A fresh commitment for each signature, for the next attempt.
+    commitments: Vec<SigningCommitments>,
+    /// A verification key identifying who the sender is.
+    pk: VerificationKey,
+    /// The signature over the protobuf encoding of the rest of the message.
+    sig: Signature,
+}
+
+impl From<FollowerMessage> for pb::RoastFollowerMessage {
+    fn from(value: FollowerMessage) -> Self {
+        Self {
+            inner: Some(follower_inner_to_pb(
+                value.attempt,
+                value.shares,
+                value.commitments,
+            )),
+            pk: Some(pb::VerificationKey {
+                inner: value.pk.to_bytes().to_vec(),
+            }),
+            sig: Some(pb::Signature {
+                inner: value.sig.to_bytes().to_vec(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<pb::RoastFollowerMessage> for FollowerMessage {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::RoastFollowerMessage) -> Result<Self, Self::Error> {
+        let inner = value.inner.ok_or(anyhow!("missing inner"))?;
+        Ok(Self {
+            attempt: inner.attempt,
+            shares: inner
+                .shares
+                .into_iter()
+                .map(|x| x.try_into())
+                .collect::<Result<Vec<_>, _>>()?,
+            commitments: inner
+                .commitments
+                .into_iter()
+                .map(|x| x.try_into())
+                .collect::<Result<Vec<_>, _>>()?,
+            pk: value
+                .pk
+                .ok_or(anyhow!("missing pk"))?
+                .inner
+                .as_slice()
+                .try_into()?,
+            sig: value
+                .sig
+                .ok_or(anyhow!("missing sig"))?
+                .inner
+                .as_slice()
+                .try_into()?,
+        })
+    }
+}
+
+impl DomainType for FollowerMessage {
+    type Proto = pb::RoastFollowerMessage;
+}
+
+impl FollowerMessage {
+    // Make a message, automatically signing the right bytes
+    fn make(
+        signing_key: &SigningKey,
+        attempt: u64,
+        shares: Vec<SignatureShare>,
+        commitments: Vec<SigningCommitments>,
+    ) -> Self {
+        Self {
+            attempt,
+            shares: shares.clone(),
+            commitments: commitments.clone(),
+            pk: signing_key.verification_key(),
+            sig: signing_key
+                .sign(&follower_inner_to_pb(attempt, shares, commitments).encode_to_vec()),
+        }
+    }
+
+    // Check the signature, and return who the message is from
+    fn check(&self) -> Result<VerificationKey> {
+        self.pk.verify(
+            &self.sig,
+            &follower_inner_to_pb(self.attempt, self.shares.clone(), self.commitments.clone())
+                .encode_to_vec(),
+        )?;
+        Ok(self.pk)
+    }
+}
+
+/// What became of a message the coordinator received.
+#[derive(Debug)]
+pub enum Received {
+    /// The message was accepted.
+    Accepted,
+    /// The message was ignored, for the given reason.
+    Ignored(String),
+    /// The sender misbehaved, for the given reason, and won't take part in any further attempts.
+    Misbehaved(VerificationKey, String),
+    /// The message completed an attempt, producing a response to the request.
+    Signed(SigningResponse),
+}
+
+/// A signing attempt the coordinator is waiting on.
+struct Attempt {
+    /// The signers taking part, including the coordinator.
+    signers: Vec<(frost::Identifier, VerificationKey)>,
+    /// For each signature, the commitments of every signer.
+    all_commitments: Vec<BTreeMap<frost::Identifier, SigningCommitments>>,
+    /// The shares received so far, including the coordinator's own.
+    shares: HashMap<frost::Identifier, Vec<SignatureShare>>,
+}
+
+/// The state of the coordinator, across all of its signing attempts.
+pub struct CoordinatorState {
+    request: SigningRequest,
+    /// The followers with fresh commitments who aren't in an attempt, in the order they replied.
+    ready: Vec<(VerificationKey, Vec<SigningCommitments>)>,
+    /// The attempt each busy follower is taking part in.
+    busy: HashMap<VerificationKey, u64>,
+    /// The attempts that might still produce a signature.
+    attempts: HashMap<u64, Attempt>,
+    misbehaving: Vec<VerificationKey>,
+    last_attempt: u64,
+}
+
+/// Start signing a request, producing the message asking the followers to take part.
+pub fn coordinator_start(
+    config: &Config,
+    request: SigningRequest,
+) -> Result<(CoordinatorRound1, CoordinatorState)> {
+    // The coordinator takes part in every attempt, and needs at least one other signer.
+    if config.threshold() < 2 {
+        anyhow::bail!("threshold signing requires a threshold of at least 2");
+    }
+    let message = CoordinatorRound1 {
+        request: request.clone(),
+    };
+    let state = CoordinatorState {
+        request,
+        ready: Vec::new(),
+        busy: HashMap::new(),
+        attempts: HashMap::new(),
+        misbehaving: Vec::new(),
+        last_attempt: 0,
+    };
+    Ok((message, state))
+}
+
+impl CoordinatorState {
+    /// Handle a message from a follower.
+    pub fn receive(&mut self, config: &Config, message: FollowerMessage) -> Result<Received> {
+        let pk = match message.check() {
+            Ok(pk) => pk,
+            Err(e) => return Ok(Received::Ignored(format!("invalid signature: {e}"))),
+        };
+        if pk == config.signing_key().verification_key() {
+            return Ok(Received::Ignored("the message is our own".to_string()));
+        }
+        if !config.verification_keys().contains(&pk) {
+            return Ok(Received::Ignored(format!(
+                "unknown verification key: {}",
+                hex::encode(pk.as_bytes())
+            )));
+        }
+        if self.misbehaving.contains(&pk) {
+            return Ok(Received::Ignored(
+                "the sender was already found to misbehave".to_string(),
+            ));
+        }
+        let required = sign::required_signatures(&self.request);
+        if message.commitments.len() != required {
+            return Ok(self.exclude(
+                pk,
+                format!(
+                    "sent {} commitments instead of {}",
+                    message.commitments.len(),
+                    required
+                ),
+            ));
+        }
+
+        if message.attempt == 0 {
+            if self.busy.contains_key(&pk) || self.ready.iter().any(|(ready, _)| *ready == pk) {
+                return Ok(Received::Ignored(
+                    "the sender already sent its first message".to_string(),
+                ));
+            }
+            self.ready.push((pk, message.commitments));
+            return Ok(Received::Accepted);
+        }
+
+        if self.busy.get(&pk) != Some(&message.attempt) {
+            return Ok(Received::Ignored(format!(
+                "not waiting on the sender's shares for attempt {}",
+                message.attempt
+            )));
+        }
+        if message.shares.len() != required {
+            return Ok(self.exclude(
+                pk,
+                format!(
+                    "sent {} shares instead of {}",
+                    message.shares.len(),
+                    required
+                ),
+            ));
+        }
+        // The sender is free for another attempt, whether or not this one can still succeed.
+        self.busy.remove(&pk);
+        self.ready.push((pk, message.commitments));
+        let attempt = match self.attempts.get_mut(&message.attempt) {
+            Some(attempt) => attempt,
+            None => return Ok(Received::Accepted),
+        };
+        attempt.shares.insert(identifier(&pk)?, message.shares);
+        if attempt.shares.len() < attempt.signers.len() {
+            return Ok(Received::Accepted);
+        }
+
+        let attempt = self
+            .attempts
+            .remove(&message.attempt)
+            .ok_or(anyhow!("missing complete attempt"))?;
+        self.aggregate(config, attempt)
+    }
+
+    /// Try to produce the signatures from the shares of a complete attempt.
+    fn aggregate(&mut self, config: &Config, attempt: Attempt) -> Result<Received> {
+        let to_be_signed = self.request.to_be_signed(config)?;
+        let signing_packages = attempt
+            .all_commitments
+            .into_iter()
+            .map(|tree| frost::SigningPackage::new(tree, to_be_signed.as_ref()))
+            .collect::<Vec<_>>();
+        let share_maps = (0..signing_packages.len())
+            .map(|i| {
+                attempt
+                    .shares
+                    .iter()
+                    .map(|(id, shares)| (*id, shares[i].clone()))
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+        let error = match sign::aggregate(
+            config,
+            self.request.clone(),
+            to_be_signed,
+            &signing_packages,
+            &share_maps,
+        ) {
+            Ok(response) => return Ok(Received::Signed(response)),
+            Err(e) => e,
+        };
+        // An invalid share is attributed to the signer who sent it.
+        let culprit = match error.downcast_ref::<frost::Error>() {
+            Some(frost::Error::InvalidSignatureShare { culprit }) => *culprit,
+            _ => return Err(error),
+        };
+        let pk = attempt
+            .signers
+            .iter()
+            .find(|(id, _)| *id == culprit)
+            .map(|(_, pk)| *pk)
+            .ok_or(anyhow!(
+                "invalid signature share from a signer outside the attempt"
+            ))?;
+        if pk == config.signing_key().verification_key() {
+            anyhow::bail!("our own signature share was invalid");
+        }
+        Ok(self.exclude(pk, "sent an invalid signature share".to_string()))
+    }
+
+    /// Leave a misbehaving signer out of any further attempts.
+    fn exclude(&mut self, pk: VerificationKey, reason: String) -> Received {
+        self.misbehaving.push(pk);
+        self.ready.retain(|(ready, _)| *ready != pk);
+        self.busy.remove(&pk);
+        // Attempts the signer is part of can't succeed anymore.
+        self.attempts
+            .retain(|_, attempt| attempt.signers.iter().all(|(_, signer)| *signer != pk));
+        Received::Misbehaved(pk, reason)
+    }
+
+    /// Start as many new attempts as there are ready followers for.
+    pub fn start_attempts(
+        &mut self,
+        rng: &mut impl CryptoRngCore,
+        config: &Config,
+    ) -> Result<Vec<CoordinatorMessage>> {
+        let followers_needed = usize::from(config.threshold()) - 1;
+        let required = sign::required_signatures(&self.request);
+        let our_pk = config.signing_key().verification_key();
+        let mut out = Vec::new();
+        while self.ready.len() >= followers_needed {
+            self.last_attempt += 1;
+            let number = self.last_attempt;
+            let (our_nonces, our_commitments) = sign::commit(rng, config, required);
+
+            let mut signers = Vec::new();
+            let mut all_commitments = vec![BTreeMap::new(); required];
+            for (pk, commitments) in self
+                .ready
+                .drain(..followers_needed)
+                .chain(iter::once((our_pk, our_commitments)))
+            {
+                let id = identifier(&pk)?;
+                for (tree, commitment) in all_commitments.iter_mut().zip(commitments) {
+                    tree.insert(id, commitment);
+                }
+                if pk != our_pk {
+                    self.busy.insert(pk, number);
+                }
+                signers.push((id, pk));
+            }
+
+            let to_be_signed = self.request.to_be_signed(config)?;
+            let our_shares = sign::sign_shares(
+                config,
+                &self.request,
+                &to_be_signed,
+                all_commitments.clone(),
+                our_nonces,
+            )?;
+            self.attempts.insert(
+                number,
+                Attempt {
+                    signers,
+                    all_commitments: all_commitments.clone(),
+                    shares: HashMap::from([(identifier(&our_pk)?, our_shares)]),
+                },
+            );
+            out.push(CoordinatorMessage::Attempt {
+                attempt: number,
+                commitments: CoordinatorRound2 { all_commitments },
+            });
+        }
+        Ok(out)
+    }
+
+    /// Whether enough signers who haven't misbehaved remain to produce a signature.
+    pub fn can_succeed(&self, config: &Config) -> bool {
+        let honest = config
+            .verification_keys()
+            .iter()
+            .filter(|pk| !self.misbehaving.contains(pk))
+            .count();
+        honest >= usize::from(config.threshold())
+    }
+
+    /// The message telling the followers that signing is over.
+    pub fn finished(&self, success: bool) -> CoordinatorMessage {
+        CoordinatorMessage::Finished {
+            success,
+            misbehaving: self.misbehaving.clone(),
+        }
+    }
+}
+
+/// The state of a follower, across the attempts it takes part in.
+pub struct FollowerState {
+    request: SigningRequest,
+    /// Our latest commitments, and the nonces behind them, if they haven't been used yet.
+    pending: Option<(Vec<SigningCommitments>, Vec<frost::round1::SigningNonces>)>,
+}
+
+/// Reply to the coordinator's signing request with our first commitments.
+pub fn follower_start(
+    rng: &mut impl CryptoRngCore,
+    config: &Config,
+    coordinator: CoordinatorRound1,
+) -> (FollowerMessage, FollowerState) {
+    let (nonces, commitments) =
+        sign::commit(rng, config, sign::required_signatures(&coordinator.request));
+    let reply = FollowerMessage::make(config.signing_key(), 0, Vec::new(), commitments.clone());
+    let state = FollowerState {
+        request: coordinator.request,
+        pending: Some((commitments, nonces)),
+    };
+    (reply, state)
+}
+
+/// Reply to a signing attempt with our shares, if we're taking part in it.
+///
+/// Our latest nonces are used, and replaced with fresh ones, so that no nonce is ever
+/// used twice, even if the coordinator repeats an attempt.
+pub fn follower_respond(
+    rng: &mut impl CryptoRngCore,
+    config: &Config,
+    state: &mut FollowerState,
+    attempt: u64,
+    commitments: CoordinatorRound2,
+) -> Result<Option<FollowerMessage>> {
+    let id = identifier(&config.signing_key().verification_key())?;
+    let ours = match commitments
+        .all_commitments
+        .iter()
+        .map(|tree| tree.get(&id).cloned())
+        .collect::<Option<Vec<_>>>()
+    {
+        Some(ours) => ours,
+        None => return Ok(None),
+    };
+    let required = sign::required_signatures(&state.request);
+    if commitments.all_commitments.len() != required {
+        anyhow::bail!(
+            "attempt {} has commitments for {} signatures instead of {}",
+            attempt,
+            commitments.all_commitments.len(),
+            required
+        );
+    }
+    let encode = |commitments: &[SigningCommitments]| {
+        commitments
+            .iter()
+            .map(|c| c.to_proto().encode_to_vec())
+            .collect::<Vec<_>>()
+    };
+    let nonces = match state.pending.take() {
+        Some((pending, nonces)) if encode(&pending) == encode(&ours) => nonces,
+        pending => {
+            state.pending = pending;
+            anyhow::bail!(
+                "attempt {} doesn't use our latest commitments, which may already have been used",
+                attempt
+            );
+        }
+    };
+
+    let to_be_signed = state.request.to_be_signed(config)?;
+    let shares = sign::sign_shares(
+        config,
+        &state.request,
+        &to_be_signed,
+        commitments.all_commitments,
+        nonces,
+    )?;
+    let (nonces, commitments) = sign::commit(rng, config, required);
+    state.pending = Some((commitments.clone(), nonces));
+    Ok(Some(FollowerMessage::make(
+        config.signing_key(),
+        attempt,
+        shares,
+        commitments,
+    )))
+}
@@ -26,6 +26,17 @@ pub async fn connect(ep: Endpoint) -> anyhow::Result<BoxGrpcService> {
     Ok(BoxCloneService::new(svc))
 }
 
+/// Constructs a [`BoxGrpcService`] for the provided tonic [`Endpoint`], deferring
+/// the connection until the first request is made.
+pub fn connect_lazy(ep: Endpoint) -> BoxGrpcService {
+    let conn = ep.connect_lazy();
+    let svc = ServiceBuilder::new()
+        .map_response(|rsp: grpc::Response<transport::Body>| rsp.map(box_rsp_body))
+        .map_err(BoxError::from)
+        .service(conn);
+    BoxCloneService::new(svc)
+}
+
 /// Constructs a [`BoxGrpcService`] by erasing the type of an `S`-typed local
 /// (in-process) service instance.
 pub fn local<S, B>(svc: S) -> BoxGrpcService
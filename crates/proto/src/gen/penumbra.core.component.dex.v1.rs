@@ -607,6 +607,10 @@ pub struct Position {
     /// / immediately after being filled.
     #[prost(bool, tag = "5")]
     pub close_on_fill: bool,
+    /// If nonzero, the last block height at which the position can be filled.
+    /// The DEX closes the position at the end of that block.
+    #[prost(uint64, tag = "6")]
+    pub expiry_height: u64,
 }
 impl ::prost::Name for Position {
     const NAME: &'static str = "Position";
@@ -979,6 +983,9 @@ impl ::prost::Name for SwapExecutionResponse {
 pub struct ArbExecutionRequest {
     #[prost(uint64, tag = "2")]
     pub height: u64,
+    /// The token that the arbitrage was performed with, if not the staking token.
+    #[prost(message, optional, tag = "3")]
+    pub arb_token: ::core::option::Option<super::super::super::asset::v1::AssetId>,
 }
 impl ::prost::Name for ArbExecutionRequest {
     const NAME: &'static str = "ArbExecutionRequest";
@@ -1048,6 +1055,9 @@ pub struct ArbExecutionsRequest {
     /// If present, only return arb executions occurring before the given height.
     #[prost(uint64, tag = "3")]
     pub end_height: u64,
+    /// The token that the arbitrage was performed with, if not the staking token.
+    #[prost(message, optional, tag = "4")]
+    pub arb_token: ::core::option::Option<super::super::super::asset::v1::AssetId>,
 }
 impl ::prost::Name for ArbExecutionsRequest {
     const NAME: &'static str = "ArbExecutionsRequest";
@@ -1184,6 +1194,63 @@ impl ::prost::Name for LiquidityPositionsByPriceResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepthChartRequest {
+    /// The directed trading pair to aggregate the liquidity of.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<DirectedTradingPair>,
+    /// The width of each price level, in basis points of the level's best price.
+    ///
+    /// If zero, each distinct price is its own level.
+    #[prost(uint32, tag = "2")]
+    pub bucket_width_bps: u32,
+    /// The maximum number of levels to return, or zero for all of them.
+    #[prost(uint32, tag = "3")]
+    pub depth: u32,
+}
+impl ::prost::Name for DepthChartRequest {
+    const NAME: &'static str = "DepthChartRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepthChartResponse {
+    #[prost(message, optional, tag = "1")]
+    pub level: ::core::option::Option<DepthLevel>,
+}
+impl ::prost::Name for DepthChartResponse {
+    const NAME: &'static str = "DepthChartResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// The aggregated liquidity of the positions within a range of prices.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DepthLevel {
+    /// An approximation of the best effective price in the level, in units of the
+    /// start asset per unit of the end asset.
+    #[prost(double, tag = "1")]
+    pub price: f64,
+    /// The total reserves of the end asset available in the level.
+    #[prost(message, optional, tag = "2")]
+    pub reserves: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The number of positions in the level.
+    #[prost(uint32, tag = "3")]
+    pub num_positions: u32,
+}
+impl ::prost::Name for DepthLevel {
+    const NAME: &'static str = "DepthLevel";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SpreadRequest {
     #[prost(message, optional, tag = "2")]
     pub trading_pair: ::core::option::Option<TradingPair>,
@@ -1308,6 +1375,117 @@ impl ::prost::Name for SimulateTradeResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateBatchRequest {
+    /// The swaps to simulate, which are the only swaps in the batch. At most 64
+    /// swaps can be simulated at once.
+    #[prost(message, repeated, tag = "1")]
+    pub swaps: ::prost::alloc::vec::Vec<simulate_batch_request::Swap>,
+}
+/// Nested message and enum types in `SimulateBatchRequest`.
+pub mod simulate_batch_request {
+    /// A hypothetical swap into a batch.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Swap {
+        /// The trading pair to swap.
+        #[prost(message, optional, tag = "1")]
+        pub trading_pair: ::core::option::Option<super::TradingPair>,
+        /// The amount of asset 1 to swap for asset 2.
+        #[prost(message, optional, tag = "2")]
+        pub delta_1: ::core::option::Option<super::super::super::super::num::v1::Amount>,
+        /// The amount of asset 2 to swap for asset 1.
+        #[prost(message, optional, tag = "3")]
+        pub delta_2: ::core::option::Option<super::super::super::super::num::v1::Amount>,
+    }
+    impl ::prost::Name for Swap {
+        const NAME: &'static str = "Swap";
+        const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.dex.v1.SimulateBatchRequest.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for SimulateBatchRequest {
+    const NAME: &'static str = "SimulateBatchRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateBatchResponse {
+    /// The batches executed for each trading pair of the requested swaps.
+    #[prost(message, repeated, tag = "1")]
+    pub batches: ::prost::alloc::vec::Vec<simulate_batch_response::Batch>,
+    /// The outputs of each of the requested swaps, in the same order.
+    #[prost(message, repeated, tag = "2")]
+    pub outputs: ::prost::alloc::vec::Vec<simulate_batch_response::SwapOutput>,
+    /// The arbitrage executed after the batches with each arb token, if any.
+    #[prost(message, repeated, tag = "3")]
+    pub arb_executions: ::prost::alloc::vec::Vec<SwapExecution>,
+}
+/// Nested message and enum types in `SimulateBatchResponse`.
+pub mod simulate_batch_response {
+    /// The execution of the batch for one trading pair.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Batch {
+        /// The clearing prices of the batch.
+        #[prost(message, optional, tag = "1")]
+        pub output_data: ::core::option::Option<super::BatchSwapOutputData>,
+        /// The execution of the swaps of asset 1 for asset 2, if any were filled.
+        #[prost(message, optional, tag = "2")]
+        pub swap_execution_1_for_2: ::core::option::Option<super::SwapExecution>,
+        /// The execution of the swaps of asset 2 for asset 1, if any were filled.
+        #[prost(message, optional, tag = "3")]
+        pub swap_execution_2_for_1: ::core::option::Option<super::SwapExecution>,
+    }
+    impl ::prost::Name for Batch {
+        const NAME: &'static str = "Batch";
+        const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.dex.v1.SimulateBatchResponse.{}", Self::NAME
+            )
+        }
+    }
+    /// The outputs of a simulated swap.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SwapOutput {
+        /// The amount of asset 1 received, including any unfilled input.
+        #[prost(message, optional, tag = "1")]
+        pub lambda_1: ::core::option::Option<
+            super::super::super::super::num::v1::Amount,
+        >,
+        /// The amount of asset 2 received, including any unfilled input.
+        #[prost(message, optional, tag = "2")]
+        pub lambda_2: ::core::option::Option<
+            super::super::super::super::num::v1::Amount,
+        >,
+    }
+    impl ::prost::Name for SwapOutput {
+        const NAME: &'static str = "SwapOutput";
+        const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.core.component.dex.v1.SimulateBatchResponse.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for SimulateBatchResponse {
+    const NAME: &'static str = "SimulateBatchResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventSwap {
     /// The trading pair to swap.
     #[prost(message, optional, tag = "1")]
@@ -1420,6 +1598,23 @@ impl ::prost::Name for EventQueuePositionClose {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventPositionExpire {
+    /// The ID of the expired position, which is closed at the same time.
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// The expiry height of the position.
+    #[prost(uint64, tag = "2")]
+    pub expiry_height: u64,
+}
+impl ::prost::Name for EventPositionExpire {
+    const NAME: &'static str = "EventPositionExpire";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventPositionWithdraw {
     /// The ID of the withdrawn position.
     #[prost(message, optional, tag = "1")]
@@ -1599,6 +1794,11 @@ pub struct DexParameters {
     /// for a single pair
     #[prost(uint32, tag = "5")]
     pub max_execution_budget: u32,
+    /// The tokens to perform arbitrage with at the end of each block.
+    ///
+    /// If empty, arbitrage is only performed with the staking token.
+    #[prost(message, repeated, tag = "6")]
+    pub arb_tokens: ::prost::alloc::vec::Vec<ArbToken>,
 }
 impl ::prost::Name for DexParameters {
     const NAME: &'static str = "DexParameters";
@@ -1607,6 +1807,26 @@ impl ::prost::Name for DexParameters {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// A token that the DEX performs arbitrage with.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArbToken {
+    /// The asset to search for cyclic arbitrage from and back to.
+    #[prost(message, optional, tag = "1")]
+    pub asset_id: ::core::option::Option<super::super::super::asset::v1::AssetId>,
+    /// The largest amount of the asset that a single arbitrage can trade.
+    ///
+    /// A budget of zero disables arbitrage with the asset.
+    #[prost(message, optional, tag = "2")]
+    pub budget: ::core::option::Option<super::super::super::num::v1::Amount>,
+}
+impl ::prost::Name for ArbToken {
+    const NAME: &'static str = "ArbToken";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisContent {
@@ -1653,6 +1873,69 @@ impl ::prost::Name for CandlestickData {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// The cumulative price of a directed trading pair, from which time-weighted
+/// average prices are computed.
+///
+/// Prices are of the start asset in units of the end asset, encoded as 32-byte
+/// U128x128 fixed-point numbers.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceAccumulator {
+    /// The height at which the price was observed.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The sum of the prices in effect at the end of each block before `height`.
+    #[prost(bytes = "vec", tag = "2")]
+    pub cumulative_price: ::prost::alloc::vec::Vec<u8>,
+    /// The price at the end of block `height`, in effect until the next observation.
+    #[prost(bytes = "vec", tag = "3")]
+    pub price: ::prost::alloc::vec::Vec<u8>,
+    /// The price in effect before `height`, if one had been observed.
+    #[prost(bytes = "vec", tag = "4")]
+    pub previous_price: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for PriceAccumulator {
+    const NAME: &'static str = "PriceAccumulator";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TwapRequest {
+    /// The directed trading pair to get the price of the start asset for.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<DirectedTradingPair>,
+    /// The number of blocks to average the price over, at most 17280.
+    #[prost(uint64, tag = "2")]
+    pub blocks: u64,
+}
+impl ::prost::Name for TwapRequest {
+    const NAME: &'static str = "TwapRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TwapResponse {
+    /// An approximation of the time-weighted average price of the start asset,
+    /// in units of the end asset.
+    #[prost(double, tag = "1")]
+    pub approx_price: f64,
+    /// The height of the last block included in the average.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+}
+impl ::prost::Name for TwapResponse {
+    const NAME: &'static str = "TwapResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CandlestickDataRequest {
@@ -2126,6 +2409,40 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Get the aggregated depth of the order book on a specific pair.
+        ///
+        /// The reserves of the opened positions are grouped into price levels, which
+        /// are streamed starting from the best price.
+        pub async fn depth_chart(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DepthChartRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::DepthChartResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/DepthChart",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "DepthChart",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
         /// Get historical candlestick data for a given trading pair.
         ///
         /// Note that this RPC is directional, to get data for both directions, make a second request.
@@ -2192,6 +2509,35 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Get the time-weighted average price of a directed trading pair over a
+        /// number of blocks, ending with the latest block.
+        pub async fn twap(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TwapRequest>,
+        ) -> std::result::Result<tonic::Response<super::TwapResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/Twap",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "Twap",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated client implementations.
@@ -2316,6 +2662,42 @@ pub mod simulation_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Simulate the execution of a batch made up of hypothetical swaps.
+        ///
+        /// Unlike `SimulateTrade`, this runs the batch swaps for every trading pair
+        /// of the requested swaps, followed by arbitrage, so swaps in the same batch
+        /// affect each other's clearing prices. Only the requested swaps are in the
+        /// batch: swaps waiting in the mempool for the next block are not included.
+        pub async fn simulate_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SimulateBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateBatchResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.SimulationService/SimulateBatch",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.SimulationService",
+                        "SimulateBatch",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -2447,6 +2829,20 @@ pub mod query_service_server {
             &self,
             request: tonic::Request<super::SpreadRequest>,
         ) -> std::result::Result<tonic::Response<super::SpreadResponse>, tonic::Status>;
+        /// Server streaming response type for the DepthChart method.
+        type DepthChartStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::DepthChartResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Get the aggregated depth of the order book on a specific pair.
+        ///
+        /// The reserves of the opened positions are grouped into price levels, which
+        /// are streamed starting from the best price.
+        async fn depth_chart(
+            &self,
+            request: tonic::Request<super::DepthChartRequest>,
+        ) -> std::result::Result<tonic::Response<Self::DepthChartStream>, tonic::Status>;
         /// Get historical candlestick data for a given trading pair.
         ///
         /// Note that this RPC is directional, to get data for both directions, make a second request.
@@ -2474,6 +2870,12 @@ pub mod query_service_server {
             tonic::Response<Self::CandlestickDataStreamStream>,
             tonic::Status,
         >;
+        /// Get the time-weighted average price of a directed trading pair over a
+        /// number of blocks, ending with the latest block.
+        async fn twap(
+            &self,
+            request: tonic::Request<super::TwapRequest>,
+        ) -> std::result::Result<tonic::Response<super::TwapResponse>, tonic::Status>;
     }
     /// Query operations for the DEX component.
     #[derive(Debug)]
@@ -3039,6 +3441,53 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.dex.v1.QueryService/DepthChart" => {
+                    #[allow(non_camel_case_types)]
+                    struct DepthChartSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<super::DepthChartRequest>
+                    for DepthChartSvc<T> {
+                        type Response = super::DepthChartResponse;
+                        type ResponseStream = T::DepthChartStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DepthChartRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::depth_chart(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DepthChartSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.core.component.dex.v1.QueryService/CandlestickData" => {
                     #[allow(non_camel_case_types)]
                     struct CandlestickDataSvc<T: QueryService>(pub Arc<T>);
@@ -3137,6 +3586,50 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.dex.v1.QueryService/Twap" => {
+                    #[allow(non_camel_case_types)]
+                    struct TwapSvc<T: QueryService>(pub Arc<T>);
+                    impl<T: QueryService> tonic::server::UnaryService<super::TwapRequest>
+                    for TwapSvc<T> {
+                        type Response = super::TwapResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TwapRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::twap(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = TwapSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -3194,6 +3687,19 @@ pub mod simulation_service_server {
             tonic::Response<super::SimulateTradeResponse>,
             tonic::Status,
         >;
+        /// Simulate the execution of a batch made up of hypothetical swaps.
+        ///
+        /// Unlike `SimulateTrade`, this runs the batch swaps for every trading pair
+        /// of the requested swaps, followed by arbitrage, so swaps in the same batch
+        /// affect each other's clearing prices. Only the requested swaps are in the
+        /// batch: swaps waiting in the mempool for the next block are not included.
+        async fn simulate_batch(
+            &self,
+            request: tonic::Request<super::SimulateBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateBatchResponse>,
+            tonic::Status,
+        >;
     }
     /// Simulation for the DEX component.
     ///
@@ -3326,6 +3832,53 @@ pub mod simulation_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.dex.v1.SimulationService/SimulateBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct SimulateBatchSvc<T: SimulationService>(pub Arc<T>);
+                    impl<
+                        T: SimulationService,
+                    > tonic::server::UnaryService<super::SimulateBatchRequest>
+                    for SimulateBatchSvc<T> {
+                        type Response = super::SimulateBatchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SimulateBatchRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SimulationService>::simulate_batch(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SimulateBatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
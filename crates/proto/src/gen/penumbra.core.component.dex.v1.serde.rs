@@ -9,11 +9,17 @@ impl serde::Serialize for ArbExecutionRequest {
         if self.height != 0 {
             len += 1;
         }
+        if self.arb_token.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ArbExecutionRequest", len)?;
         if self.height != 0 {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
+        if let Some(v) = self.arb_token.as_ref() {
+            struct_ser.serialize_field("arbToken", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -25,11 +31,14 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionRequest {
     {
         const FIELDS: &[&str] = &[
             "height",
+            "arb_token",
+            "arbToken",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Height,
+            ArbToken,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -53,6 +62,7 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionRequest {
                     {
                         match value {
                             "height" => Ok(GeneratedField::Height),
+                            "arbToken" | "arb_token" => Ok(GeneratedField::ArbToken),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -73,6 +83,7 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut height__ = None;
+                let mut arb_token__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Height => {
@@ -83,6 +94,12 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionRequest {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::ArbToken => {
+                            if arb_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("arbToken"));
+                            }
+                            arb_token__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -90,6 +107,7 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionRequest {
                 }
                 Ok(ArbExecutionRequest {
                     height: height__.unwrap_or_default(),
+                    arb_token: arb_token__,
                 })
             }
         }
@@ -226,6 +244,9 @@ impl serde::Serialize for ArbExecutionsRequest {
         if self.end_height != 0 {
             len += 1;
         }
+        if self.arb_token.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ArbExecutionsRequest", len)?;
         if self.start_height != 0 {
             #[allow(clippy::needless_borrow)]
@@ -235,6 +256,9 @@ impl serde::Serialize for ArbExecutionsRequest {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
         }
+        if let Some(v) = self.arb_token.as_ref() {
+            struct_ser.serialize_field("arbToken", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -249,12 +273,15 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsRequest {
             "startHeight",
             "end_height",
             "endHeight",
+            "arb_token",
+            "arbToken",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             StartHeight,
             EndHeight,
+            ArbToken,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -279,6 +306,7 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsRequest {
                         match value {
                             "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
                             "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "arbToken" | "arb_token" => Ok(GeneratedField::ArbToken),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -300,6 +328,7 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsRequest {
             {
                 let mut start_height__ = None;
                 let mut end_height__ = None;
+                let mut arb_token__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::StartHeight => {
@@ -318,6 +347,12 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsRequest {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::ArbToken => {
+                            if arb_token__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("arbToken"));
+                            }
+                            arb_token__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -326,6 +361,7 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsRequest {
                 Ok(ArbExecutionsRequest {
                     start_height: start_height__.unwrap_or_default(),
                     end_height: end_height__.unwrap_or_default(),
+                    arb_token: arb_token__,
                 })
             }
         }
@@ -448,6 +484,119 @@ impl<'de> serde::Deserialize<'de> for ArbExecutionsResponse {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.ArbExecutionsResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ArbToken {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.budget.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ArbToken", len)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.budget.as_ref() {
+            struct_ser.serialize_field("budget", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ArbToken {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset_id",
+            "assetId",
+            "budget",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AssetId,
+            Budget,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "budget" => Ok(GeneratedField::Budget),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ArbToken;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.ArbToken")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ArbToken, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset_id__ = None;
+                let mut budget__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Budget => {
+                            if budget__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("budget"));
+                            }
+                            budget__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ArbToken {
+                    asset_id: asset_id__,
+                    budget: budget__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ArbToken", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for BareTradingFunction {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1691,7 +1840,7 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataStreamResponse {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.CandlestickDataStreamResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DexParameters {
+impl serde::Serialize for DepthChartRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1699,66 +1848,47 @@ impl serde::Serialize for DexParameters {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.is_enabled {
-            len += 1;
-        }
-        if !self.fixed_candidates.is_empty() {
-            len += 1;
-        }
-        if self.max_hops != 0 {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        if self.max_positions_per_pair != 0 {
+        if self.bucket_width_bps != 0 {
             len += 1;
         }
-        if self.max_execution_budget != 0 {
+        if self.depth != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DexParameters", len)?;
-        if self.is_enabled {
-            struct_ser.serialize_field("isEnabled", &self.is_enabled)?;
-        }
-        if !self.fixed_candidates.is_empty() {
-            struct_ser.serialize_field("fixedCandidates", &self.fixed_candidates)?;
-        }
-        if self.max_hops != 0 {
-            struct_ser.serialize_field("maxHops", &self.max_hops)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DepthChartRequest", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
         }
-        if self.max_positions_per_pair != 0 {
-            struct_ser.serialize_field("maxPositionsPerPair", &self.max_positions_per_pair)?;
+        if self.bucket_width_bps != 0 {
+            struct_ser.serialize_field("bucketWidthBps", &self.bucket_width_bps)?;
         }
-        if self.max_execution_budget != 0 {
-            struct_ser.serialize_field("maxExecutionBudget", &self.max_execution_budget)?;
+        if self.depth != 0 {
+            struct_ser.serialize_field("depth", &self.depth)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for DexParameters {
+impl<'de> serde::Deserialize<'de> for DepthChartRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "is_enabled",
-            "isEnabled",
-            "fixed_candidates",
-            "fixedCandidates",
-            "max_hops",
-            "maxHops",
-            "max_positions_per_pair",
-            "maxPositionsPerPair",
-            "max_execution_budget",
-            "maxExecutionBudget",
+            "trading_pair",
+            "tradingPair",
+            "bucket_width_bps",
+            "bucketWidthBps",
+            "depth",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            IsEnabled,
-            FixedCandidates,
-            MaxHops,
-            MaxPositionsPerPair,
-            MaxExecutionBudget,
+            TradingPair,
+            BucketWidthBps,
+            Depth,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1781,11 +1911,9 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                         E: serde::de::Error,
                     {
                         match value {
-                            "isEnabled" | "is_enabled" => Ok(GeneratedField::IsEnabled),
-                            "fixedCandidates" | "fixed_candidates" => Ok(GeneratedField::FixedCandidates),
-                            "maxHops" | "max_hops" => Ok(GeneratedField::MaxHops),
-                            "maxPositionsPerPair" | "max_positions_per_pair" => Ok(GeneratedField::MaxPositionsPerPair),
-                            "maxExecutionBudget" | "max_execution_budget" => Ok(GeneratedField::MaxExecutionBudget),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "bucketWidthBps" | "bucket_width_bps" => Ok(GeneratedField::BucketWidthBps),
+                            "depth" => Ok(GeneratedField::Depth),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1795,56 +1923,40 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DexParameters;
+            type Value = DepthChartRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.DexParameters")
+                formatter.write_str("struct penumbra.core.component.dex.v1.DepthChartRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DexParameters, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DepthChartRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut is_enabled__ = None;
-                let mut fixed_candidates__ = None;
-                let mut max_hops__ = None;
-                let mut max_positions_per_pair__ = None;
-                let mut max_execution_budget__ = None;
+                let mut trading_pair__ = None;
+                let mut bucket_width_bps__ = None;
+                let mut depth__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::IsEnabled => {
-                            if is_enabled__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("isEnabled"));
-                            }
-                            is_enabled__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::FixedCandidates => {
-                            if fixed_candidates__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fixedCandidates"));
-                            }
-                            fixed_candidates__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::MaxHops => {
-                            if max_hops__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("maxHops"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            max_hops__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            trading_pair__ = map_.next_value()?;
                         }
-                        GeneratedField::MaxPositionsPerPair => {
-                            if max_positions_per_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("maxPositionsPerPair"));
+                        GeneratedField::BucketWidthBps => {
+                            if bucket_width_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bucketWidthBps"));
                             }
-                            max_positions_per_pair__ = 
+                            bucket_width_bps__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::MaxExecutionBudget => {
-                            if max_execution_budget__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("maxExecutionBudget"));
+                        GeneratedField::Depth => {
+                            if depth__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depth"));
                             }
-                            max_execution_budget__ = 
+                            depth__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
@@ -1853,19 +1965,17 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                         }
                     }
                 }
-                Ok(DexParameters {
-                    is_enabled: is_enabled__.unwrap_or_default(),
-                    fixed_candidates: fixed_candidates__.unwrap_or_default(),
-                    max_hops: max_hops__.unwrap_or_default(),
-                    max_positions_per_pair: max_positions_per_pair__.unwrap_or_default(),
-                    max_execution_budget: max_execution_budget__.unwrap_or_default(),
+                Ok(DepthChartRequest {
+                    trading_pair: trading_pair__,
+                    bucket_width_bps: bucket_width_bps__.unwrap_or_default(),
+                    depth: depth__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DexParameters", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DepthChartRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DirectedTradingPair {
+impl serde::Serialize for DepthChartResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1873,37 +1983,29 @@ impl serde::Serialize for DirectedTradingPair {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.start.is_some() {
-            len += 1;
-        }
-        if self.end.is_some() {
+        if self.level.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DirectedTradingPair", len)?;
-        if let Some(v) = self.start.as_ref() {
-            struct_ser.serialize_field("start", v)?;
-        }
-        if let Some(v) = self.end.as_ref() {
-            struct_ser.serialize_field("end", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DepthChartResponse", len)?;
+        if let Some(v) = self.level.as_ref() {
+            struct_ser.serialize_field("level", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for DirectedTradingPair {
+impl<'de> serde::Deserialize<'de> for DepthChartResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "start",
-            "end",
+            "level",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Start,
-            End,
+            Level,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1926,8 +2028,7 @@ impl<'de> serde::Deserialize<'de> for DirectedTradingPair {
                         E: serde::de::Error,
                     {
                         match value {
-                            "start" => Ok(GeneratedField::Start),
-                            "end" => Ok(GeneratedField::End),
+                            "level" => Ok(GeneratedField::Level),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1937,47 +2038,39 @@ impl<'de> serde::Deserialize<'de> for DirectedTradingPair {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DirectedTradingPair;
+            type Value = DepthChartResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.DirectedTradingPair")
+                formatter.write_str("struct penumbra.core.component.dex.v1.DepthChartResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DirectedTradingPair, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DepthChartResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut start__ = None;
-                let mut end__ = None;
+                let mut level__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Start => {
-                            if start__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("start"));
-                            }
-                            start__ = map_.next_value()?;
-                        }
-                        GeneratedField::End => {
-                            if end__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("end"));
+                        GeneratedField::Level => {
+                            if level__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("level"));
                             }
-                            end__ = map_.next_value()?;
+                            level__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(DirectedTradingPair {
-                    start: start__,
-                    end: end__,
+                Ok(DepthChartResponse {
+                    level: level__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DirectedTradingPair", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DepthChartResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventArbExecution {
+impl serde::Serialize for DepthLevel {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1985,39 +2078,46 @@ impl serde::Serialize for EventArbExecution {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.height != 0 {
+        if self.price != 0. {
             len += 1;
         }
-        if self.swap_execution.is_some() {
+        if self.reserves.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventArbExecution", len)?;
-        if self.height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        if self.num_positions != 0 {
+            len += 1;
         }
-        if let Some(v) = self.swap_execution.as_ref() {
-            struct_ser.serialize_field("swapExecution", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DepthLevel", len)?;
+        if self.price != 0. {
+            struct_ser.serialize_field("price", &self.price)?;
+        }
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if self.num_positions != 0 {
+            struct_ser.serialize_field("numPositions", &self.num_positions)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventArbExecution {
+impl<'de> serde::Deserialize<'de> for DepthLevel {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "height",
-            "swap_execution",
-            "swapExecution",
+            "price",
+            "reserves",
+            "num_positions",
+            "numPositions",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Height,
-            SwapExecution,
+            Price,
+            Reserves,
+            NumPositions,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2040,8 +2140,9 @@ impl<'de> serde::Deserialize<'de> for EventArbExecution {
                         E: serde::de::Error,
                     {
                         match value {
-                            "height" => Ok(GeneratedField::Height),
-                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
+                            "price" => Ok(GeneratedField::Price),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "numPositions" | "num_positions" => Ok(GeneratedField::NumPositions),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2051,49 +2152,59 @@ impl<'de> serde::Deserialize<'de> for EventArbExecution {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventArbExecution;
+            type Value = DepthLevel;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventArbExecution")
+                formatter.write_str("struct penumbra.core.component.dex.v1.DepthLevel")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventArbExecution, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DepthLevel, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut height__ = None;
-                let mut swap_execution__ = None;
+                let mut price__ = None;
+                let mut reserves__ = None;
+                let mut num_positions__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Height => {
-                            if height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("height"));
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
                             }
-                            height__ = 
+                            price__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::SwapExecution => {
-                            if swap_execution__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapExecution"));
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
                             }
-                            swap_execution__ = map_.next_value()?;
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::NumPositions => {
+                            if num_positions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numPositions"));
+                            }
+                            num_positions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventArbExecution {
-                    height: height__.unwrap_or_default(),
-                    swap_execution: swap_execution__,
+                Ok(DepthLevel {
+                    price: price__.unwrap_or_default(),
+                    reserves: reserves__,
+                    num_positions: num_positions__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventArbExecution", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DepthLevel", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventBatchSwap {
+impl serde::Serialize for DexParameters {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2101,48 +2212,75 @@ impl serde::Serialize for EventBatchSwap {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.batch_swap_output_data.is_some() {
+        if self.is_enabled {
             len += 1;
         }
-        if self.swap_execution_1_for_2.is_some() {
+        if !self.fixed_candidates.is_empty() {
             len += 1;
         }
-        if self.swap_execution_2_for_1.is_some() {
+        if self.max_hops != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventBatchSwap", len)?;
-        if let Some(v) = self.batch_swap_output_data.as_ref() {
-            struct_ser.serialize_field("batchSwapOutputData", v)?;
+        if self.max_positions_per_pair != 0 {
+            len += 1;
         }
-        if let Some(v) = self.swap_execution_1_for_2.as_ref() {
-            struct_ser.serialize_field("swapExecution1For2", v)?;
+        if self.max_execution_budget != 0 {
+            len += 1;
         }
-        if let Some(v) = self.swap_execution_2_for_1.as_ref() {
-            struct_ser.serialize_field("swapExecution2For1", v)?;
+        if !self.arb_tokens.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DexParameters", len)?;
+        if self.is_enabled {
+            struct_ser.serialize_field("isEnabled", &self.is_enabled)?;
+        }
+        if !self.fixed_candidates.is_empty() {
+            struct_ser.serialize_field("fixedCandidates", &self.fixed_candidates)?;
+        }
+        if self.max_hops != 0 {
+            struct_ser.serialize_field("maxHops", &self.max_hops)?;
+        }
+        if self.max_positions_per_pair != 0 {
+            struct_ser.serialize_field("maxPositionsPerPair", &self.max_positions_per_pair)?;
+        }
+        if self.max_execution_budget != 0 {
+            struct_ser.serialize_field("maxExecutionBudget", &self.max_execution_budget)?;
+        }
+        if !self.arb_tokens.is_empty() {
+            struct_ser.serialize_field("arbTokens", &self.arb_tokens)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventBatchSwap {
+impl<'de> serde::Deserialize<'de> for DexParameters {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "batch_swap_output_data",
-            "batchSwapOutputData",
-            "swap_execution_1_for_2",
-            "swapExecution1For2",
-            "swap_execution_2_for_1",
-            "swapExecution2For1",
+            "is_enabled",
+            "isEnabled",
+            "fixed_candidates",
+            "fixedCandidates",
+            "max_hops",
+            "maxHops",
+            "max_positions_per_pair",
+            "maxPositionsPerPair",
+            "max_execution_budget",
+            "maxExecutionBudget",
+            "arb_tokens",
+            "arbTokens",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            BatchSwapOutputData,
-            SwapExecution1For2,
-            SwapExecution2For1,
+            IsEnabled,
+            FixedCandidates,
+            MaxHops,
+            MaxPositionsPerPair,
+            MaxExecutionBudget,
+            ArbTokens,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2165,9 +2303,12 @@ impl<'de> serde::Deserialize<'de> for EventBatchSwap {
                         E: serde::de::Error,
                     {
                         match value {
-                            "batchSwapOutputData" | "batch_swap_output_data" => Ok(GeneratedField::BatchSwapOutputData),
-                            "swapExecution1For2" | "swap_execution_1_for_2" => Ok(GeneratedField::SwapExecution1For2),
-                            "swapExecution2For1" | "swap_execution_2_for_1" => Ok(GeneratedField::SwapExecution2For1),
+                            "isEnabled" | "is_enabled" => Ok(GeneratedField::IsEnabled),
+                            "fixedCandidates" | "fixed_candidates" => Ok(GeneratedField::FixedCandidates),
+                            "maxHops" | "max_hops" => Ok(GeneratedField::MaxHops),
+                            "maxPositionsPerPair" | "max_positions_per_pair" => Ok(GeneratedField::MaxPositionsPerPair),
+                            "maxExecutionBudget" | "max_execution_budget" => Ok(GeneratedField::MaxExecutionBudget),
+                            "arbTokens" | "arb_tokens" => Ok(GeneratedField::ArbTokens),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2177,55 +2318,85 @@ impl<'de> serde::Deserialize<'de> for EventBatchSwap {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventBatchSwap;
+            type Value = DexParameters;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventBatchSwap")
+                formatter.write_str("struct penumbra.core.component.dex.v1.DexParameters")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventBatchSwap, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DexParameters, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut batch_swap_output_data__ = None;
-                let mut swap_execution_1_for_2__ = None;
-                let mut swap_execution_2_for_1__ = None;
+                let mut is_enabled__ = None;
+                let mut fixed_candidates__ = None;
+                let mut max_hops__ = None;
+                let mut max_positions_per_pair__ = None;
+                let mut max_execution_budget__ = None;
+                let mut arb_tokens__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::BatchSwapOutputData => {
-                            if batch_swap_output_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("batchSwapOutputData"));
+                        GeneratedField::IsEnabled => {
+                            if is_enabled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("isEnabled"));
                             }
-                            batch_swap_output_data__ = map_.next_value()?;
+                            is_enabled__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::SwapExecution1For2 => {
-                            if swap_execution_1_for_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapExecution1For2"));
+                        GeneratedField::FixedCandidates => {
+                            if fixed_candidates__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fixedCandidates"));
                             }
-                            swap_execution_1_for_2__ = map_.next_value()?;
+                            fixed_candidates__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::SwapExecution2For1 => {
-                            if swap_execution_2_for_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapExecution2For1"));
+                        GeneratedField::MaxHops => {
+                            if max_hops__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxHops"));
                             }
-                            swap_execution_2_for_1__ = map_.next_value()?;
+                            max_hops__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxPositionsPerPair => {
+                            if max_positions_per_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxPositionsPerPair"));
+                            }
+                            max_positions_per_pair__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxExecutionBudget => {
+                            if max_execution_budget__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxExecutionBudget"));
+                            }
+                            max_execution_budget__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ArbTokens => {
+                            if arb_tokens__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("arbTokens"));
+                            }
+                            arb_tokens__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventBatchSwap {
-                    batch_swap_output_data: batch_swap_output_data__,
-                    swap_execution_1_for_2: swap_execution_1_for_2__,
-                    swap_execution_2_for_1: swap_execution_2_for_1__,
+                Ok(DexParameters {
+                    is_enabled: is_enabled__.unwrap_or_default(),
+                    fixed_candidates: fixed_candidates__.unwrap_or_default(),
+                    max_hops: max_hops__.unwrap_or_default(),
+                    max_positions_per_pair: max_positions_per_pair__.unwrap_or_default(),
+                    max_execution_budget: max_execution_budget__.unwrap_or_default(),
+                    arb_tokens: arb_tokens__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventBatchSwap", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DexParameters", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventCandlestickData {
+impl serde::Serialize for DirectedTradingPair {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2233,37 +2404,37 @@ impl serde::Serialize for EventCandlestickData {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.pair.is_some() {
+        if self.start.is_some() {
             len += 1;
         }
-        if self.stick.is_some() {
+        if self.end.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventCandlestickData", len)?;
-        if let Some(v) = self.pair.as_ref() {
-            struct_ser.serialize_field("pair", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DirectedTradingPair", len)?;
+        if let Some(v) = self.start.as_ref() {
+            struct_ser.serialize_field("start", v)?;
         }
-        if let Some(v) = self.stick.as_ref() {
-            struct_ser.serialize_field("stick", v)?;
+        if let Some(v) = self.end.as_ref() {
+            struct_ser.serialize_field("end", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventCandlestickData {
+impl<'de> serde::Deserialize<'de> for DirectedTradingPair {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "pair",
-            "stick",
+            "start",
+            "end",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Pair,
-            Stick,
+            Start,
+            End,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2286,8 +2457,8 @@ impl<'de> serde::Deserialize<'de> for EventCandlestickData {
                         E: serde::de::Error,
                     {
                         match value {
-                            "pair" => Ok(GeneratedField::Pair),
-                            "stick" => Ok(GeneratedField::Stick),
+                            "start" => Ok(GeneratedField::Start),
+                            "end" => Ok(GeneratedField::End),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2297,47 +2468,47 @@ impl<'de> serde::Deserialize<'de> for EventCandlestickData {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventCandlestickData;
+            type Value = DirectedTradingPair;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventCandlestickData")
+                formatter.write_str("struct penumbra.core.component.dex.v1.DirectedTradingPair")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventCandlestickData, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DirectedTradingPair, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut pair__ = None;
-                let mut stick__ = None;
+                let mut start__ = None;
+                let mut end__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Pair => {
-                            if pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("pair"));
+                        GeneratedField::Start => {
+                            if start__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("start"));
                             }
-                            pair__ = map_.next_value()?;
+                            start__ = map_.next_value()?;
                         }
-                        GeneratedField::Stick => {
-                            if stick__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("stick"));
+                        GeneratedField::End => {
+                            if end__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("end"));
                             }
-                            stick__ = map_.next_value()?;
+                            end__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventCandlestickData {
-                    pair: pair__,
-                    stick: stick__,
+                Ok(DirectedTradingPair {
+                    start: start__,
+                    end: end__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventCandlestickData", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DirectedTradingPair", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventPositionClose {
+impl serde::Serialize for EventArbExecution {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2345,30 +2516,39 @@ impl serde::Serialize for EventPositionClose {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionClose", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        if self.swap_execution.is_some() {
+            len += 1;
         }
-        struct_ser.end()
-    }
-}
-impl<'de> serde::Deserialize<'de> for EventPositionClose {
-    #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventArbExecution", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.swap_execution.as_ref() {
+            struct_ser.serialize_field("swapExecution", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventArbExecution {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
+            "height",
+            "swap_execution",
+            "swapExecution",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
+            Height,
+            SwapExecution,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2391,7 +2571,8 @@ impl<'de> serde::Deserialize<'de> for EventPositionClose {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "height" => Ok(GeneratedField::Height),
+                            "swapExecution" | "swap_execution" => Ok(GeneratedField::SwapExecution),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2401,39 +2582,49 @@ impl<'de> serde::Deserialize<'de> for EventPositionClose {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventPositionClose;
+            type Value = EventArbExecution;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionClose")
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventArbExecution")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionClose, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventArbExecution, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut height__ = None;
+                let mut swap_execution__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            position_id__ = map_.next_value()?;
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SwapExecution => {
+                            if swap_execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution"));
+                            }
+                            swap_execution__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventPositionClose {
-                    position_id: position_id__,
+                Ok(EventArbExecution {
+                    height: height__.unwrap_or_default(),
+                    swap_execution: swap_execution__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionClose", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventArbExecution", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventPositionExecution {
+impl serde::Serialize for EventBatchSwap {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2441,83 +2632,48 @@ impl serde::Serialize for EventPositionExecution {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.reserves_1.is_some() {
-            len += 1;
-        }
-        if self.reserves_2.is_some() {
-            len += 1;
-        }
-        if self.prev_reserves_1.is_some() {
+        if self.batch_swap_output_data.is_some() {
             len += 1;
         }
-        if self.prev_reserves_2.is_some() {
+        if self.swap_execution_1_for_2.is_some() {
             len += 1;
         }
-        if self.context.is_some() {
+        if self.swap_execution_2_for_1.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionExecution", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if let Some(v) = self.reserves_1.as_ref() {
-            struct_ser.serialize_field("reserves1", v)?;
-        }
-        if let Some(v) = self.reserves_2.as_ref() {
-            struct_ser.serialize_field("reserves2", v)?;
-        }
-        if let Some(v) = self.prev_reserves_1.as_ref() {
-            struct_ser.serialize_field("prevReserves1", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventBatchSwap", len)?;
+        if let Some(v) = self.batch_swap_output_data.as_ref() {
+            struct_ser.serialize_field("batchSwapOutputData", v)?;
         }
-        if let Some(v) = self.prev_reserves_2.as_ref() {
-            struct_ser.serialize_field("prevReserves2", v)?;
+        if let Some(v) = self.swap_execution_1_for_2.as_ref() {
+            struct_ser.serialize_field("swapExecution1For2", v)?;
         }
-        if let Some(v) = self.context.as_ref() {
-            struct_ser.serialize_field("context", v)?;
+        if let Some(v) = self.swap_execution_2_for_1.as_ref() {
+            struct_ser.serialize_field("swapExecution2For1", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventPositionExecution {
+impl<'de> serde::Deserialize<'de> for EventBatchSwap {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
-            "trading_pair",
-            "tradingPair",
-            "reserves_1",
-            "reserves1",
-            "reserves_2",
-            "reserves2",
-            "prev_reserves_1",
-            "prevReserves1",
-            "prev_reserves_2",
-            "prevReserves2",
-            "context",
+            "batch_swap_output_data",
+            "batchSwapOutputData",
+            "swap_execution_1_for_2",
+            "swapExecution1For2",
+            "swap_execution_2_for_1",
+            "swapExecution2For1",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
-            TradingPair,
-            Reserves1,
-            Reserves2,
-            PrevReserves1,
-            PrevReserves2,
-            Context,
+            BatchSwapOutputData,
+            SwapExecution1For2,
+            SwapExecution2For1,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2540,13 +2696,9 @@ impl<'de> serde::Deserialize<'de> for EventPositionExecution {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
-                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
-                            "prevReserves1" | "prev_reserves_1" => Ok(GeneratedField::PrevReserves1),
-                            "prevReserves2" | "prev_reserves_2" => Ok(GeneratedField::PrevReserves2),
-                            "context" => Ok(GeneratedField::Context),
+                            "batchSwapOutputData" | "batch_swap_output_data" => Ok(GeneratedField::BatchSwapOutputData),
+                            "swapExecution1For2" | "swap_execution_1_for_2" => Ok(GeneratedField::SwapExecution1For2),
+                            "swapExecution2For1" | "swap_execution_2_for_1" => Ok(GeneratedField::SwapExecution2For1),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2556,87 +2708,55 @@ impl<'de> serde::Deserialize<'de> for EventPositionExecution {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventPositionExecution;
+            type Value = EventBatchSwap;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionExecution")
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventBatchSwap")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionExecution, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventBatchSwap, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                let mut trading_pair__ = None;
-                let mut reserves_1__ = None;
-                let mut reserves_2__ = None;
-                let mut prev_reserves_1__ = None;
-                let mut prev_reserves_2__ = None;
-                let mut context__ = None;
+                let mut batch_swap_output_data__ = None;
+                let mut swap_execution_1_for_2__ = None;
+                let mut swap_execution_2_for_1__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves1 => {
-                            if reserves_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves1"));
-                            }
-                            reserves_1__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves2 => {
-                            if reserves_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves2"));
-                            }
-                            reserves_2__ = map_.next_value()?;
-                        }
-                        GeneratedField::PrevReserves1 => {
-                            if prev_reserves_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("prevReserves1"));
+                        GeneratedField::BatchSwapOutputData => {
+                            if batch_swap_output_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batchSwapOutputData"));
                             }
-                            prev_reserves_1__ = map_.next_value()?;
+                            batch_swap_output_data__ = map_.next_value()?;
                         }
-                        GeneratedField::PrevReserves2 => {
-                            if prev_reserves_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("prevReserves2"));
+                        GeneratedField::SwapExecution1For2 => {
+                            if swap_execution_1_for_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution1For2"));
                             }
-                            prev_reserves_2__ = map_.next_value()?;
+                            swap_execution_1_for_2__ = map_.next_value()?;
                         }
-                        GeneratedField::Context => {
-                            if context__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("context"));
+                        GeneratedField::SwapExecution2For1 => {
+                            if swap_execution_2_for_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution2For1"));
                             }
-                            context__ = map_.next_value()?;
+                            swap_execution_2_for_1__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventPositionExecution {
-                    position_id: position_id__,
-                    trading_pair: trading_pair__,
-                    reserves_1: reserves_1__,
-                    reserves_2: reserves_2__,
-                    prev_reserves_1: prev_reserves_1__,
-                    prev_reserves_2: prev_reserves_2__,
-                    context: context__,
+                Ok(EventBatchSwap {
+                    batch_swap_output_data: batch_swap_output_data__,
+                    swap_execution_1_for_2: swap_execution_1_for_2__,
+                    swap_execution_2_for_1: swap_execution_2_for_1__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionExecution", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventBatchSwap", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventPositionOpen {
+impl serde::Serialize for EventCandlestickData {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2644,47 +2764,261 @@ impl serde::Serialize for EventPositionOpen {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.reserves_1.is_some() {
-            len += 1;
-        }
-        if self.reserves_2.is_some() {
-            len += 1;
-        }
-        if self.trading_fee != 0 {
+        if self.pair.is_some() {
             len += 1;
         }
-        if self.position.is_some() {
+        if self.stick.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionOpen", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventCandlestickData", len)?;
+        if let Some(v) = self.pair.as_ref() {
+            struct_ser.serialize_field("pair", v)?;
         }
-        if let Some(v) = self.reserves_1.as_ref() {
-            struct_ser.serialize_field("reserves1", v)?;
+        if let Some(v) = self.stick.as_ref() {
+            struct_ser.serialize_field("stick", v)?;
         }
-        if let Some(v) = self.reserves_2.as_ref() {
-            struct_ser.serialize_field("reserves2", v)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventCandlestickData {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "pair",
+            "stick",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Pair,
+            Stick,
+            __SkipField__,
         }
-        if self.trading_fee != 0 {
-            struct_ser.serialize_field("tradingFee", &self.trading_fee)?;
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pair" => Ok(GeneratedField::Pair),
+                            "stick" => Ok(GeneratedField::Stick),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
         }
-        if let Some(v) = self.position.as_ref() {
-            struct_ser.serialize_field("position", v)?;
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventCandlestickData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventCandlestickData")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventCandlestickData, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut pair__ = None;
+                let mut stick__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Pair => {
+                            if pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pair"));
+                            }
+                            pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Stick => {
+                            if stick__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stick"));
+                            }
+                            stick__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventCandlestickData {
+                    pair: pair__,
+                    stick: stick__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventCandlestickData", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventPositionClose {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionClose", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventPositionOpen {
+impl<'de> serde::Deserialize<'de> for EventPositionClose {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventPositionClose;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionClose")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionClose, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventPositionClose {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionClose", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventPositionExecution {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.reserves_1.is_some() {
+            len += 1;
+        }
+        if self.reserves_2.is_some() {
+            len += 1;
+        }
+        if self.prev_reserves_1.is_some() {
+            len += 1;
+        }
+        if self.prev_reserves_2.is_some() {
+            len += 1;
+        }
+        if self.context.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionExecution", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.reserves_1.as_ref() {
+            struct_ser.serialize_field("reserves1", v)?;
+        }
+        if let Some(v) = self.reserves_2.as_ref() {
+            struct_ser.serialize_field("reserves2", v)?;
+        }
+        if let Some(v) = self.prev_reserves_1.as_ref() {
+            struct_ser.serialize_field("prevReserves1", v)?;
+        }
+        if let Some(v) = self.prev_reserves_2.as_ref() {
+            struct_ser.serialize_field("prevReserves2", v)?;
+        }
+        if let Some(v) = self.context.as_ref() {
+            struct_ser.serialize_field("context", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventPositionExecution {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2699,9 +3033,11 @@ impl<'de> serde::Deserialize<'de> for EventPositionOpen {
             "reserves1",
             "reserves_2",
             "reserves2",
-            "trading_fee",
-            "tradingFee",
-            "position",
+            "prev_reserves_1",
+            "prevReserves1",
+            "prev_reserves_2",
+            "prevReserves2",
+            "context",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -2710,8 +3046,9 @@ impl<'de> serde::Deserialize<'de> for EventPositionOpen {
             TradingPair,
             Reserves1,
             Reserves2,
-            TradingFee,
-            Position,
+            PrevReserves1,
+            PrevReserves2,
+            Context,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2738,8 +3075,1286 @@ impl<'de> serde::Deserialize<'de> for EventPositionOpen {
                             "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
                             "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
-                            "tradingFee" | "trading_fee" => Ok(GeneratedField::TradingFee),
-                            "position" => Ok(GeneratedField::Position),
+                            "prevReserves1" | "prev_reserves_1" => Ok(GeneratedField::PrevReserves1),
+                            "prevReserves2" | "prev_reserves_2" => Ok(GeneratedField::PrevReserves2),
+                            "context" => Ok(GeneratedField::Context),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventPositionExecution;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionExecution")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionExecution, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut trading_pair__ = None;
+                let mut reserves_1__ = None;
+                let mut reserves_2__ = None;
+                let mut prev_reserves_1__ = None;
+                let mut prev_reserves_2__ = None;
+                let mut context__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves1 => {
+                            if reserves_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves1"));
+                            }
+                            reserves_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves2 => {
+                            if reserves_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves2"));
+                            }
+                            reserves_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::PrevReserves1 => {
+                            if prev_reserves_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevReserves1"));
+                            }
+                            prev_reserves_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::PrevReserves2 => {
+                            if prev_reserves_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevReserves2"));
+                            }
+                            prev_reserves_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Context => {
+                            if context__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("context"));
+                            }
+                            context__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventPositionExecution {
+                    position_id: position_id__,
+                    trading_pair: trading_pair__,
+                    reserves_1: reserves_1__,
+                    reserves_2: reserves_2__,
+                    prev_reserves_1: prev_reserves_1__,
+                    prev_reserves_2: prev_reserves_2__,
+                    context: context__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionExecution", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventPositionExpire {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.expiry_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionExpire", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if self.expiry_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expiryHeight", ToString::to_string(&self.expiry_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventPositionExpire {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "expiry_height",
+            "expiryHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            ExpiryHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "expiryHeight" | "expiry_height" => Ok(GeneratedField::ExpiryHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventPositionExpire;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionExpire")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionExpire, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut expiry_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::ExpiryHeight => {
+                            if expiry_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiryHeight"));
+                            }
+                            expiry_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventPositionExpire {
+                    position_id: position_id__,
+                    expiry_height: expiry_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionExpire", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventPositionOpen {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.reserves_1.is_some() {
+            len += 1;
+        }
+        if self.reserves_2.is_some() {
+            len += 1;
+        }
+        if self.trading_fee != 0 {
+            len += 1;
+        }
+        if self.position.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionOpen", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.reserves_1.as_ref() {
+            struct_ser.serialize_field("reserves1", v)?;
+        }
+        if let Some(v) = self.reserves_2.as_ref() {
+            struct_ser.serialize_field("reserves2", v)?;
+        }
+        if self.trading_fee != 0 {
+            struct_ser.serialize_field("tradingFee", &self.trading_fee)?;
+        }
+        if let Some(v) = self.position.as_ref() {
+            struct_ser.serialize_field("position", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventPositionOpen {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "trading_pair",
+            "tradingPair",
+            "reserves_1",
+            "reserves1",
+            "reserves_2",
+            "reserves2",
+            "trading_fee",
+            "tradingFee",
+            "position",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            TradingPair,
+            Reserves1,
+            Reserves2,
+            TradingFee,
+            Position,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
+                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
+                            "tradingFee" | "trading_fee" => Ok(GeneratedField::TradingFee),
+                            "position" => Ok(GeneratedField::Position),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventPositionOpen;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionOpen")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionOpen, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut trading_pair__ = None;
+                let mut reserves_1__ = None;
+                let mut reserves_2__ = None;
+                let mut trading_fee__ = None;
+                let mut position__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves1 => {
+                            if reserves_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves1"));
+                            }
+                            reserves_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves2 => {
+                            if reserves_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves2"));
+                            }
+                            reserves_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingFee => {
+                            if trading_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingFee"));
+                            }
+                            trading_fee__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventPositionOpen {
+                    position_id: position_id__,
+                    trading_pair: trading_pair__,
+                    reserves_1: reserves_1__,
+                    reserves_2: reserves_2__,
+                    trading_fee: trading_fee__.unwrap_or_default(),
+                    position: position__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionOpen", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventPositionWithdraw {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.reserves_1.is_some() {
+            len += 1;
+        }
+        if self.reserves_2.is_some() {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.reserves_1.as_ref() {
+            struct_ser.serialize_field("reserves1", v)?;
+        }
+        if let Some(v) = self.reserves_2.as_ref() {
+            struct_ser.serialize_field("reserves2", v)?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "trading_pair",
+            "tradingPair",
+            "reserves_1",
+            "reserves1",
+            "reserves_2",
+            "reserves2",
+            "sequence",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            TradingPair,
+            Reserves1,
+            Reserves2,
+            Sequence,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
+                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventPositionWithdraw;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionWithdraw")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionWithdraw, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut trading_pair__ = None;
+                let mut reserves_1__ = None;
+                let mut reserves_2__ = None;
+                let mut sequence__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves1 => {
+                            if reserves_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves1"));
+                            }
+                            reserves_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves2 => {
+                            if reserves_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves2"));
+                            }
+                            reserves_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventPositionWithdraw {
+                    position_id: position_id__,
+                    trading_pair: trading_pair__,
+                    reserves_1: reserves_1__,
+                    reserves_2: reserves_2__,
+                    sequence: sequence__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventQueuePositionClose {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventQueuePositionClose", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventQueuePositionClose {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventQueuePositionClose;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventQueuePositionClose")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventQueuePositionClose, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventQueuePositionClose {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventQueuePositionClose", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventSwap {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.delta_1_i.is_some() {
+            len += 1;
+        }
+        if self.delta_2_i.is_some() {
+            len += 1;
+        }
+        if self.swap_commitment.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventSwap", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.delta_1_i.as_ref() {
+            struct_ser.serialize_field("delta1I", v)?;
+        }
+        if let Some(v) = self.delta_2_i.as_ref() {
+            struct_ser.serialize_field("delta2I", v)?;
+        }
+        if let Some(v) = self.swap_commitment.as_ref() {
+            struct_ser.serialize_field("swapCommitment", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventSwap {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+            "delta_1_i",
+            "delta1I",
+            "delta_2_i",
+            "delta2I",
+            "swap_commitment",
+            "swapCommitment",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            Delta1I,
+            Delta2I,
+            SwapCommitment,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "delta1I" | "delta_1_i" => Ok(GeneratedField::Delta1I),
+                            "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
+                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventSwap;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventSwap")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSwap, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                let mut delta_1_i__ = None;
+                let mut delta_2_i__ = None;
+                let mut swap_commitment__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Delta1I => {
+                            if delta_1_i__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta1I"));
+                            }
+                            delta_1_i__ = map_.next_value()?;
+                        }
+                        GeneratedField::Delta2I => {
+                            if delta_2_i__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta2I"));
+                            }
+                            delta_2_i__ = map_.next_value()?;
+                        }
+                        GeneratedField::SwapCommitment => {
+                            if swap_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
+                            }
+                            swap_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventSwap {
+                    trading_pair: trading_pair__,
+                    delta_1_i: delta_1_i__,
+                    delta_2_i: delta_2_i__,
+                    swap_commitment: swap_commitment__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventSwap", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventSwapClaim {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.output_1_commitment.is_some() {
+            len += 1;
+        }
+        if self.output_2_commitment.is_some() {
+            len += 1;
+        }
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventSwapClaim", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.output_1_commitment.as_ref() {
+            struct_ser.serialize_field("output1Commitment", v)?;
+        }
+        if let Some(v) = self.output_2_commitment.as_ref() {
+            struct_ser.serialize_field("output2Commitment", v)?;
+        }
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventSwapClaim {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+            "output_1_commitment",
+            "output1Commitment",
+            "output_2_commitment",
+            "output2Commitment",
+            "nullifier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            Output1Commitment,
+            Output2Commitment,
+            Nullifier,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "output1Commitment" | "output_1_commitment" => Ok(GeneratedField::Output1Commitment),
+                            "output2Commitment" | "output_2_commitment" => Ok(GeneratedField::Output2Commitment),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventSwapClaim;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventSwapClaim")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSwapClaim, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                let mut output_1_commitment__ = None;
+                let mut output_2_commitment__ = None;
+                let mut nullifier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output1Commitment => {
+                            if output_1_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output1Commitment"));
+                            }
+                            output_1_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output2Commitment => {
+                            if output_2_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output2Commitment"));
+                            }
+                            output_2_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventSwapClaim {
+                    trading_pair: trading_pair__,
+                    output_1_commitment: output_1_commitment__,
+                    output_2_commitment: output_2_commitment__,
+                    nullifier: nullifier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventSwapClaim", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventValueCircuitBreakerCredit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.previous_balance.is_some() {
+            len += 1;
+        }
+        if self.new_balance.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerCredit", len)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.previous_balance.as_ref() {
+            struct_ser.serialize_field("previousBalance", v)?;
+        }
+        if let Some(v) = self.new_balance.as_ref() {
+            struct_ser.serialize_field("newBalance", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerCredit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset_id",
+            "assetId",
+            "previous_balance",
+            "previousBalance",
+            "new_balance",
+            "newBalance",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AssetId,
+            PreviousBalance,
+            NewBalance,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "previousBalance" | "previous_balance" => Ok(GeneratedField::PreviousBalance),
+                            "newBalance" | "new_balance" => Ok(GeneratedField::NewBalance),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventValueCircuitBreakerCredit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventValueCircuitBreakerCredit")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventValueCircuitBreakerCredit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset_id__ = None;
+                let mut previous_balance__ = None;
+                let mut new_balance__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::PreviousBalance => {
+                            if previous_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("previousBalance"));
+                            }
+                            previous_balance__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewBalance => {
+                            if new_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newBalance"));
+                            }
+                            new_balance__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventValueCircuitBreakerCredit {
+                    asset_id: asset_id__,
+                    previous_balance: previous_balance__,
+                    new_balance: new_balance__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerCredit", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventValueCircuitBreakerDebit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.previous_balance.is_some() {
+            len += 1;
+        }
+        if self.new_balance.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerDebit", len)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.previous_balance.as_ref() {
+            struct_ser.serialize_field("previousBalance", v)?;
+        }
+        if let Some(v) = self.new_balance.as_ref() {
+            struct_ser.serialize_field("newBalance", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerDebit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset_id",
+            "assetId",
+            "previous_balance",
+            "previousBalance",
+            "new_balance",
+            "newBalance",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AssetId,
+            PreviousBalance,
+            NewBalance,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "previousBalance" | "previous_balance" => Ok(GeneratedField::PreviousBalance),
+                            "newBalance" | "new_balance" => Ok(GeneratedField::NewBalance),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventValueCircuitBreakerDebit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventValueCircuitBreakerDebit")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventValueCircuitBreakerDebit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset_id__ = None;
+                let mut previous_balance__ = None;
+                let mut new_balance__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::PreviousBalance => {
+                            if previous_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("previousBalance"));
+                            }
+                            previous_balance__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewBalance => {
+                            if new_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newBalance"));
+                            }
+                            new_balance__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventValueCircuitBreakerDebit {
+                    asset_id: asset_id__,
+                    previous_balance: previous_balance__,
+                    new_balance: new_balance__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerDebit", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GenesisContent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.dex_params.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.GenesisContent", len)?;
+        if let Some(v) = self.dex_params.as_ref() {
+            struct_ser.serialize_field("dexParams", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GenesisContent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "dex_params",
+            "dexParams",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DexParams,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "dexParams" | "dex_params" => Ok(GeneratedField::DexParams),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2749,81 +4364,39 @@ impl<'de> serde::Deserialize<'de> for EventPositionOpen {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventPositionOpen;
+            type Value = GenesisContent;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionOpen")
+                formatter.write_str("struct penumbra.core.component.dex.v1.GenesisContent")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionOpen, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                let mut trading_pair__ = None;
-                let mut reserves_1__ = None;
-                let mut reserves_2__ = None;
-                let mut trading_fee__ = None;
-                let mut position__ = None;
+                let mut dex_params__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves1 => {
-                            if reserves_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves1"));
-                            }
-                            reserves_1__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves2 => {
-                            if reserves_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves2"));
-                            }
-                            reserves_2__ = map_.next_value()?;
-                        }
-                        GeneratedField::TradingFee => {
-                            if trading_fee__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingFee"));
-                            }
-                            trading_fee__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
+                        GeneratedField::DexParams => {
+                            if dex_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexParams"));
                             }
-                            position__ = map_.next_value()?;
+                            dex_params__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventPositionOpen {
-                    position_id: position_id__,
-                    trading_pair: trading_pair__,
-                    reserves_1: reserves_1__,
-                    reserves_2: reserves_2__,
-                    trading_fee: trading_fee__.unwrap_or_default(),
-                    position: position__,
+                Ok(GenesisContent {
+                    dex_params: dex_params__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionOpen", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.GenesisContent", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventPositionWithdraw {
+impl serde::Serialize for LiquidityPositionByIdRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2834,39 +4407,14 @@ impl serde::Serialize for EventPositionWithdraw {
         if self.position_id.is_some() {
             len += 1;
         }
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.reserves_1.is_some() {
-            len += 1;
-        }
-        if self.reserves_2.is_some() {
-            len += 1;
-        }
-        if self.sequence != 0 {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", len)?;
         if let Some(v) = self.position_id.as_ref() {
             struct_ser.serialize_field("positionId", v)?;
         }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if let Some(v) = self.reserves_1.as_ref() {
-            struct_ser.serialize_field("reserves1", v)?;
-        }
-        if let Some(v) = self.reserves_2.as_ref() {
-            struct_ser.serialize_field("reserves2", v)?;
-        }
-        if self.sequence != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
-        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2875,22 +4423,11 @@ impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
         const FIELDS: &[&str] = &[
             "position_id",
             "positionId",
-            "trading_pair",
-            "tradingPair",
-            "reserves_1",
-            "reserves1",
-            "reserves_2",
-            "reserves2",
-            "sequence",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PositionId,
-            TradingPair,
-            Reserves1,
-            Reserves2,
-            Sequence,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2914,10 +4451,6 @@ impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
                     {
                         match value {
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "reserves1" | "reserves_1" => Ok(GeneratedField::Reserves1),
-                            "reserves2" | "reserves_2" => Ok(GeneratedField::Reserves2),
-                            "sequence" => Ok(GeneratedField::Sequence),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2927,21 +4460,17 @@ impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventPositionWithdraw;
+            type Value = LiquidityPositionByIdRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionWithdraw")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionWithdraw, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut position_id__ = None;
-                let mut trading_pair__ = None;
-                let mut reserves_1__ = None;
-                let mut reserves_2__ = None;
-                let mut sequence__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PositionId => {
@@ -2950,50 +4479,20 @@ impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
                             }
                             position_id__ = map_.next_value()?;
                         }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves1 => {
-                            if reserves_1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves1"));
-                            }
-                            reserves_1__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves2 => {
-                            if reserves_2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves2"));
-                            }
-                            reserves_2__ = map_.next_value()?;
-                        }
-                        GeneratedField::Sequence => {
-                            if sequence__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequence"));
-                            }
-                            sequence__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventPositionWithdraw {
+                Ok(LiquidityPositionByIdRequest {
                     position_id: position_id__,
-                    trading_pair: trading_pair__,
-                    reserves_1: reserves_1__,
-                    reserves_2: reserves_2__,
-                    sequence: sequence__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventQueuePositionClose {
+impl serde::Serialize for LiquidityPositionByIdResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3001,30 +4500,29 @@ impl serde::Serialize for EventQueuePositionClose {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventQueuePositionClose", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventQueuePositionClose {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
+            "data",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
+            Data,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3047,7 +4545,7 @@ impl<'de> serde::Deserialize<'de> for EventQueuePositionClose {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "data" => Ok(GeneratedField::Data),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3057,39 +4555,39 @@ impl<'de> serde::Deserialize<'de> for EventQueuePositionClose {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventQueuePositionClose;
+            type Value = LiquidityPositionByIdResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventQueuePositionClose")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventQueuePositionClose, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut data__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
                             }
-                            position_id__ = map_.next_value()?;
+                            data__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventQueuePositionClose {
-                    position_id: position_id__,
+                Ok(LiquidityPositionByIdResponse {
+                    data: data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventQueuePositionClose", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventSwap {
+impl serde::Serialize for LiquidityPositionsByIdRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3097,57 +4595,30 @@ impl serde::Serialize for EventSwap {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.delta_1_i.is_some() {
-            len += 1;
-        }
-        if self.delta_2_i.is_some() {
-            len += 1;
-        }
-        if self.swap_commitment.is_some() {
+        if !self.position_id.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventSwap", len)?;
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if let Some(v) = self.delta_1_i.as_ref() {
-            struct_ser.serialize_field("delta1I", v)?;
-        }
-        if let Some(v) = self.delta_2_i.as_ref() {
-            struct_ser.serialize_field("delta2I", v)?;
-        }
-        if let Some(v) = self.swap_commitment.as_ref() {
-            struct_ser.serialize_field("swapCommitment", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", len)?;
+        if !self.position_id.is_empty() {
+            struct_ser.serialize_field("positionId", &self.position_id)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventSwap {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "trading_pair",
-            "tradingPair",
-            "delta_1_i",
-            "delta1I",
-            "delta_2_i",
-            "delta2I",
-            "swap_commitment",
-            "swapCommitment",
+            "position_id",
+            "positionId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TradingPair,
-            Delta1I,
-            Delta2I,
-            SwapCommitment,
+            PositionId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3170,10 +4641,7 @@ impl<'de> serde::Deserialize<'de> for EventSwap {
                         E: serde::de::Error,
                     {
                         match value {
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "delta1I" | "delta_1_i" => Ok(GeneratedField::Delta1I),
-                            "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
-                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3183,63 +4651,39 @@ impl<'de> serde::Deserialize<'de> for EventSwap {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventSwap;
+            type Value = LiquidityPositionsByIdRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventSwap")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSwap, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
-            {
-                let mut trading_pair__ = None;
-                let mut delta_1_i__ = None;
-                let mut delta_2_i__ = None;
-                let mut swap_commitment__ = None;
+            {
+                let mut position_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Delta1I => {
-                            if delta_1_i__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta1I"));
-                            }
-                            delta_1_i__ = map_.next_value()?;
-                        }
-                        GeneratedField::Delta2I => {
-                            if delta_2_i__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delta2I"));
-                            }
-                            delta_2_i__ = map_.next_value()?;
-                        }
-                        GeneratedField::SwapCommitment => {
-                            if swap_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            swap_commitment__ = map_.next_value()?;
+                            position_id__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventSwap {
-                    trading_pair: trading_pair__,
-                    delta_1_i: delta_1_i__,
-                    delta_2_i: delta_2_i__,
-                    swap_commitment: swap_commitment__,
+                Ok(LiquidityPositionsByIdRequest {
+                    position_id: position_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventSwap", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventSwapClaim {
+impl serde::Serialize for LiquidityPositionsByIdResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3247,56 +4691,29 @@ impl serde::Serialize for EventSwapClaim {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.trading_pair.is_some() {
-            len += 1;
-        }
-        if self.output_1_commitment.is_some() {
-            len += 1;
-        }
-        if self.output_2_commitment.is_some() {
-            len += 1;
-        }
-        if self.nullifier.is_some() {
+        if self.data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventSwapClaim", len)?;
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if let Some(v) = self.output_1_commitment.as_ref() {
-            struct_ser.serialize_field("output1Commitment", v)?;
-        }
-        if let Some(v) = self.output_2_commitment.as_ref() {
-            struct_ser.serialize_field("output2Commitment", v)?;
-        }
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventSwapClaim {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "trading_pair",
-            "tradingPair",
-            "output_1_commitment",
-            "output1Commitment",
-            "output_2_commitment",
-            "output2Commitment",
-            "nullifier",
+            "data",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TradingPair,
-            Output1Commitment,
-            Output2Commitment,
-            Nullifier,
+            Data,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3319,10 +4736,7 @@ impl<'de> serde::Deserialize<'de> for EventSwapClaim {
                         E: serde::de::Error,
                     {
                         match value {
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "output1Commitment" | "output_1_commitment" => Ok(GeneratedField::Output1Commitment),
-                            "output2Commitment" | "output_2_commitment" => Ok(GeneratedField::Output2Commitment),
-                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "data" => Ok(GeneratedField::Data),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3332,63 +4746,39 @@ impl<'de> serde::Deserialize<'de> for EventSwapClaim {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventSwapClaim;
+            type Value = LiquidityPositionsByIdResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventSwapClaim")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSwapClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut trading_pair__ = None;
-                let mut output_1_commitment__ = None;
-                let mut output_2_commitment__ = None;
-                let mut nullifier__ = None;
+                let mut data__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output1Commitment => {
-                            if output_1_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output1Commitment"));
-                            }
-                            output_1_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output2Commitment => {
-                            if output_2_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output2Commitment"));
-                            }
-                            output_2_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
                             }
-                            nullifier__ = map_.next_value()?;
+                            data__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventSwapClaim {
-                    trading_pair: trading_pair__,
-                    output_1_commitment: output_1_commitment__,
-                    output_2_commitment: output_2_commitment__,
-                    nullifier: nullifier__,
+                Ok(LiquidityPositionsByIdResponse {
+                    data: data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventSwapClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventValueCircuitBreakerCredit {
+impl serde::Serialize for LiquidityPositionsByPriceRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3396,48 +4786,39 @@ impl serde::Serialize for EventValueCircuitBreakerCredit {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.asset_id.is_some() {
-            len += 1;
-        }
-        if self.previous_balance.is_some() {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        if self.new_balance.is_some() {
+        if self.limit != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerCredit", len)?;
-        if let Some(v) = self.asset_id.as_ref() {
-            struct_ser.serialize_field("assetId", v)?;
-        }
-        if let Some(v) = self.previous_balance.as_ref() {
-            struct_ser.serialize_field("previousBalance", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
         }
-        if let Some(v) = self.new_balance.as_ref() {
-            struct_ser.serialize_field("newBalance", v)?;
+        if self.limit != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("limit", ToString::to_string(&self.limit).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerCredit {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "asset_id",
-            "assetId",
-            "previous_balance",
-            "previousBalance",
-            "new_balance",
-            "newBalance",
+            "trading_pair",
+            "tradingPair",
+            "limit",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AssetId,
-            PreviousBalance,
-            NewBalance,
+            TradingPair,
+            Limit,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3460,9 +4841,8 @@ impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerCredit {
                         E: serde::de::Error,
                     {
                         match value {
-                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
-                            "previousBalance" | "previous_balance" => Ok(GeneratedField::PreviousBalance),
-                            "newBalance" | "new_balance" => Ok(GeneratedField::NewBalance),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "limit" => Ok(GeneratedField::Limit),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3472,55 +4852,49 @@ impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerCredit {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventValueCircuitBreakerCredit;
+            type Value = LiquidityPositionsByPriceRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventValueCircuitBreakerCredit")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventValueCircuitBreakerCredit, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut asset_id__ = None;
-                let mut previous_balance__ = None;
-                let mut new_balance__ = None;
+                let mut trading_pair__ = None;
+                let mut limit__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AssetId => {
-                            if asset_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("assetId"));
-                            }
-                            asset_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::PreviousBalance => {
-                            if previous_balance__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("previousBalance"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            previous_balance__ = map_.next_value()?;
+                            trading_pair__ = map_.next_value()?;
                         }
-                        GeneratedField::NewBalance => {
-                            if new_balance__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("newBalance"));
+                        GeneratedField::Limit => {
+                            if limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limit"));
                             }
-                            new_balance__ = map_.next_value()?;
+                            limit__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventValueCircuitBreakerCredit {
-                    asset_id: asset_id__,
-                    previous_balance: previous_balance__,
-                    new_balance: new_balance__,
+                Ok(LiquidityPositionsByPriceRequest {
+                    trading_pair: trading_pair__,
+                    limit: limit__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerCredit", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventValueCircuitBreakerDebit {
+impl serde::Serialize for LiquidityPositionsByPriceResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3528,48 +4902,37 @@ impl serde::Serialize for EventValueCircuitBreakerDebit {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.asset_id.is_some() {
-            len += 1;
-        }
-        if self.previous_balance.is_some() {
+        if self.data.is_some() {
             len += 1;
         }
-        if self.new_balance.is_some() {
+        if self.id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerDebit", len)?;
-        if let Some(v) = self.asset_id.as_ref() {
-            struct_ser.serialize_field("assetId", v)?;
-        }
-        if let Some(v) = self.previous_balance.as_ref() {
-            struct_ser.serialize_field("previousBalance", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
         }
-        if let Some(v) = self.new_balance.as_ref() {
-            struct_ser.serialize_field("newBalance", v)?;
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerDebit {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "asset_id",
-            "assetId",
-            "previous_balance",
-            "previousBalance",
-            "new_balance",
-            "newBalance",
+            "data",
+            "id",
         ];
 
-        #[allow(clippy::enum_variant_names)]
-        enum GeneratedField {
-            AssetId,
-            PreviousBalance,
-            NewBalance,
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Data,
+            Id,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3592,9 +4955,8 @@ impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerDebit {
                         E: serde::de::Error,
                     {
                         match value {
-                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
-                            "previousBalance" | "previous_balance" => Ok(GeneratedField::PreviousBalance),
-                            "newBalance" | "new_balance" => Ok(GeneratedField::NewBalance),
+                            "data" => Ok(GeneratedField::Data),
+                            "id" => Ok(GeneratedField::Id),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3604,55 +4966,47 @@ impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerDebit {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventValueCircuitBreakerDebit;
+            type Value = LiquidityPositionsByPriceResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventValueCircuitBreakerDebit")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventValueCircuitBreakerDebit, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut asset_id__ = None;
-                let mut previous_balance__ = None;
-                let mut new_balance__ = None;
+                let mut data__ = None;
+                let mut id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AssetId => {
-                            if asset_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("assetId"));
-                            }
-                            asset_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::PreviousBalance => {
-                            if previous_balance__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("previousBalance"));
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
                             }
-                            previous_balance__ = map_.next_value()?;
+                            data__ = map_.next_value()?;
                         }
-                        GeneratedField::NewBalance => {
-                            if new_balance__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("newBalance"));
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
                             }
-                            new_balance__ = map_.next_value()?;
+                            id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventValueCircuitBreakerDebit {
-                    asset_id: asset_id__,
-                    previous_balance: previous_balance__,
-                    new_balance: new_balance__,
+                Ok(LiquidityPositionsByPriceResponse {
+                    data: data__,
+                    id: id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventValueCircuitBreakerDebit", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GenesisContent {
+impl serde::Serialize for LiquidityPositionsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3660,30 +5014,30 @@ impl serde::Serialize for GenesisContent {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.dex_params.is_some() {
+        if self.include_closed {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.GenesisContent", len)?;
-        if let Some(v) = self.dex_params.as_ref() {
-            struct_ser.serialize_field("dexParams", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", len)?;
+        if self.include_closed {
+            struct_ser.serialize_field("includeClosed", &self.include_closed)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GenesisContent {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "dex_params",
-            "dexParams",
+            "include_closed",
+            "includeClosed",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            DexParams,
+            IncludeClosed,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3706,7 +5060,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                         E: serde::de::Error,
                     {
                         match value {
-                            "dexParams" | "dex_params" => Ok(GeneratedField::DexParams),
+                            "includeClosed" | "include_closed" => Ok(GeneratedField::IncludeClosed),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3716,39 +5070,39 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GenesisContent;
+            type Value = LiquidityPositionsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.GenesisContent")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut dex_params__ = None;
+                let mut include_closed__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::DexParams => {
-                            if dex_params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("dexParams"));
+                        GeneratedField::IncludeClosed => {
+                            if include_closed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeClosed"));
                             }
-                            dex_params__ = map_.next_value()?;
+                            include_closed__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GenesisContent {
-                    dex_params: dex_params__,
+                Ok(LiquidityPositionsRequest {
+                    include_closed: include_closed__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.GenesisContent", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionByIdRequest {
+impl serde::Serialize for LiquidityPositionsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3756,30 +5110,29 @@ impl serde::Serialize for LiquidityPositionByIdRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
+            "data",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
+            Data,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3802,7 +5155,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "data" => Ok(GeneratedField::Data),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3812,39 +5165,39 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionByIdRequest;
+            type Value = LiquidityPositionsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut data__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
                             }
-                            position_id__ = map_.next_value()?;
+                            data__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionByIdRequest {
-                    position_id: position_id__,
+                Ok(LiquidityPositionsResponse {
+                    data: data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionByIdResponse {
+impl serde::Serialize for LpNft {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3852,29 +5205,38 @@ impl serde::Serialize for LiquidityPositionByIdResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        if self.state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LpNft", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
+impl<'de> serde::Deserialize<'de> for LpNft {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
+            "position_id",
+            "positionId",
+            "state",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
+            PositionId,
+            State,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3897,7 +5259,8 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "state" => Ok(GeneratedField::State),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3907,39 +5270,47 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionByIdResponse;
+            type Value = LpNft;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LpNft")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LpNft, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
+                let mut position_id__ = None;
+                let mut state__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            data__ = map_.next_value()?;
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionByIdResponse {
-                    data: data__,
+                Ok(LpNft {
+                    position_id: position_id__,
+                    state: state__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LpNft", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByIdRequest {
+impl serde::Serialize for Position {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3947,30 +5318,73 @@ impl serde::Serialize for LiquidityPositionsByIdRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.position_id.is_empty() {
+        if self.phi.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", len)?;
-        if !self.position_id.is_empty() {
-            struct_ser.serialize_field("positionId", &self.position_id)?;
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        if self.reserves.is_some() {
+            len += 1;
+        }
+        if self.close_on_fill {
+            len += 1;
+        }
+        if self.expiry_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Position", len)?;
+        if let Some(v) = self.phi.as_ref() {
+            struct_ser.serialize_field("phi", v)?;
+        }
+        if !self.nonce.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if self.close_on_fill {
+            struct_ser.serialize_field("closeOnFill", &self.close_on_fill)?;
+        }
+        if self.expiry_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expiryHeight", ToString::to_string(&self.expiry_height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
+impl<'de> serde::Deserialize<'de> for Position {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
+            "phi",
+            "nonce",
+            "state",
+            "reserves",
+            "close_on_fill",
+            "closeOnFill",
+            "expiry_height",
+            "expiryHeight",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
+            Phi,
+            Nonce,
+            State,
+            Reserves,
+            CloseOnFill,
+            ExpiryHeight,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3993,7 +5407,12 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "phi" => Ok(GeneratedField::Phi),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            "state" => Ok(GeneratedField::State),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "closeOnFill" | "close_on_fill" => Ok(GeneratedField::CloseOnFill),
+                            "expiryHeight" | "expiry_height" => Ok(GeneratedField::ExpiryHeight),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4003,39 +5422,83 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByIdRequest;
+            type Value = Position;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.Position")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Position, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut phi__ = None;
+                let mut nonce__ = None;
+                let mut state__ = None;
+                let mut reserves__ = None;
+                let mut close_on_fill__ = None;
+                let mut expiry_height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Phi => {
+                            if phi__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("phi"));
                             }
-                            position_id__ = Some(map_.next_value()?);
+                            phi__ = map_.next_value()?;
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::CloseOnFill => {
+                            if close_on_fill__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closeOnFill"));
+                            }
+                            close_on_fill__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ExpiryHeight => {
+                            if expiry_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiryHeight"));
+                            }
+                            expiry_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsByIdRequest {
-                    position_id: position_id__.unwrap_or_default(),
+                Ok(Position {
+                    phi: phi__,
+                    nonce: nonce__.unwrap_or_default(),
+                    state: state__,
+                    reserves: reserves__,
+                    close_on_fill: close_on_fill__.unwrap_or_default(),
+                    expiry_height: expiry_height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Position", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByIdResponse {
+impl serde::Serialize for PositionClose {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4043,29 +5506,30 @@ impl serde::Serialize for LiquidityPositionsByIdResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionClose", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
+impl<'de> serde::Deserialize<'de> for PositionClose {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
+            "position_id",
+            "positionId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
+            PositionId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4088,7 +5552,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4098,39 +5562,39 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByIdResponse;
+            type Value = PositionClose;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionClose")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionClose, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
+                let mut position_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            data__ = map_.next_value()?;
+                            position_id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsByIdResponse {
-                    data: data__,
+                Ok(PositionClose {
+                    position_id: position_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionClose", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByPriceRequest {
+impl serde::Serialize for PositionId {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4138,39 +5602,39 @@ impl serde::Serialize for LiquidityPositionsByPriceRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.trading_pair.is_some() {
+        if !self.inner.is_empty() {
             len += 1;
         }
-        if self.limit != 0 {
+        if !self.alt_bech32m.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", len)?;
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
-        }
-        if self.limit != 0 {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionId", len)?;
+        if !self.inner.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("limit", ToString::to_string(&self.limit).as_str())?;
+            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
+        }
+        if !self.alt_bech32m.is_empty() {
+            struct_ser.serialize_field("altBech32m", &self.alt_bech32m)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
+impl<'de> serde::Deserialize<'de> for PositionId {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "trading_pair",
-            "tradingPair",
-            "limit",
+            "inner",
+            "alt_bech32m",
+            "altBech32m",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TradingPair,
-            Limit,
+            Inner,
+            AltBech32m,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4193,8 +5657,8 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "limit" => Ok(GeneratedField::Limit),
+                            "inner" => Ok(GeneratedField::Inner),
+                            "altBech32m" | "alt_bech32m" => Ok(GeneratedField::AltBech32m),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4204,49 +5668,49 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByPriceRequest;
+            type Value = PositionId;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionId")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionId, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut trading_pair__ = None;
-                let mut limit__ = None;
+                let mut inner__ = None;
+                let mut alt_bech32m__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::Inner => {
+                            if inner__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inner"));
                             }
-                            trading_pair__ = map_.next_value()?;
+                            inner__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Limit => {
-                            if limit__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("limit"));
+                        GeneratedField::AltBech32m => {
+                            if alt_bech32m__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("altBech32m"));
                             }
-                            limit__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            alt_bech32m__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsByPriceRequest {
-                    trading_pair: trading_pair__,
-                    limit: limit__.unwrap_or_default(),
+                Ok(PositionId {
+                    inner: inner__.unwrap_or_default(),
+                    alt_bech32m: alt_bech32m__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionId", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByPriceResponse {
+impl serde::Serialize for PositionOpen {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4254,37 +5718,29 @@ impl serde::Serialize for LiquidityPositionsByPriceResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
-            len += 1;
-        }
-        if self.id.is_some() {
+        if self.position.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
-        }
-        if let Some(v) = self.id.as_ref() {
-            struct_ser.serialize_field("id", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionOpen", len)?;
+        if let Some(v) = self.position.as_ref() {
+            struct_ser.serialize_field("position", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceResponse {
+impl<'de> serde::Deserialize<'de> for PositionOpen {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
-            "id",
+            "position",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
-            Id,
+            Position,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4307,8 +5763,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
-                            "id" => Ok(GeneratedField::Id),
+                            "position" => Ok(GeneratedField::Position),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4318,47 +5773,39 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByPriceResponse;
+            type Value = PositionOpen;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionOpen")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionOpen, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
-                let mut id__ = None;
+                let mut position__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
-                            }
-                            data__ = map_.next_value()?;
-                        }
-                        GeneratedField::Id => {
-                            if id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("id"));
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
                             }
-                            id__ = map_.next_value()?;
+                            position__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsByPriceResponse {
-                    data: data__,
-                    id: id__,
+                Ok(PositionOpen {
+                    position: position__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionOpen", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsRequest {
+impl serde::Serialize for PositionRewardClaim {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4366,30 +5813,39 @@ impl serde::Serialize for LiquidityPositionsRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.include_closed {
+        if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", len)?;
-        if self.include_closed {
-            struct_ser.serialize_field("includeClosed", &self.include_closed)?;
+        if self.rewards_commitment.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardClaim", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.rewards_commitment.as_ref() {
+            struct_ser.serialize_field("rewardsCommitment", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
+impl<'de> serde::Deserialize<'de> for PositionRewardClaim {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "include_closed",
-            "includeClosed",
+            "position_id",
+            "positionId",
+            "rewards_commitment",
+            "rewardsCommitment",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            IncludeClosed,
+            PositionId,
+            RewardsCommitment,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4412,7 +5868,8 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "includeClosed" | "include_closed" => Ok(GeneratedField::IncludeClosed),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "rewardsCommitment" | "rewards_commitment" => Ok(GeneratedField::RewardsCommitment),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4422,39 +5879,47 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsRequest;
+            type Value = PositionRewardClaim;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardClaim")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardClaim, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut include_closed__ = None;
+                let mut position_id__ = None;
+                let mut rewards_commitment__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::IncludeClosed => {
-                            if include_closed__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("includeClosed"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            include_closed__ = Some(map_.next_value()?);
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::RewardsCommitment => {
+                            if rewards_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardsCommitment"));
+                            }
+                            rewards_commitment__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsRequest {
-                    include_closed: include_closed__.unwrap_or_default(),
+                Ok(PositionRewardClaim {
+                    position_id: position_id__,
+                    rewards_commitment: rewards_commitment__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardClaim", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsResponse {
+impl serde::Serialize for PositionRewardClaimPlan {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4462,29 +5927,29 @@ impl serde::Serialize for LiquidityPositionsResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if self.reserves.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardClaimPlan", len)?;
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
+impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
+            "reserves",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
+            Reserves,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4507,7 +5972,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
+                            "reserves" => Ok(GeneratedField::Reserves),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4517,39 +5982,39 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsResponse;
+            type Value = PositionRewardClaimPlan;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardClaimPlan")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardClaimPlan, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
+                let mut reserves__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
                             }
-                            data__ = map_.next_value()?;
+                            reserves__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsResponse {
-                    data: data__,
+                Ok(PositionRewardClaimPlan {
+                    reserves: reserves__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardClaimPlan", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LpNft {
+impl serde::Serialize for PositionState {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4557,38 +6022,40 @@ impl serde::Serialize for LpNft {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.state != 0 {
             len += 1;
         }
-        if self.state.is_some() {
+        if self.sequence != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LpNft", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionState", len)?;
+        if self.state != 0 {
+            let v = position_state::PositionStateEnum::try_from(self.state)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
+            struct_ser.serialize_field("state", &v)?;
         }
-        if let Some(v) = self.state.as_ref() {
-            struct_ser.serialize_field("state", v)?;
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LpNft {
+impl<'de> serde::Deserialize<'de> for PositionState {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
             "state",
+            "sequence",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
             State,
+            Sequence,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4611,8 +6078,8 @@ impl<'de> serde::Deserialize<'de> for LpNft {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
                             "state" => Ok(GeneratedField::State),
+                            "sequence" => Ok(GeneratedField::Sequence),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4622,214 +6089,129 @@ impl<'de> serde::Deserialize<'de> for LpNft {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LpNft;
+            type Value = PositionState;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LpNft")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionState")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LpNft, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionState, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
                 let mut state__ = None;
+                let mut sequence__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map_.next_value()?;
-                        }
                         GeneratedField::State => {
                             if state__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("state"));
                             }
-                            state__ = map_.next_value()?;
+                            state__ = Some(map_.next_value::<position_state::PositionStateEnum>()? as i32);
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LpNft {
-                    position_id: position_id__,
-                    state: state__,
+                Ok(PositionState {
+                    state: state__.unwrap_or_default(),
+                    sequence: sequence__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LpNft", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionState", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Position {
+impl serde::Serialize for position_state::PositionStateEnum {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.phi.is_some() {
-            len += 1;
-        }
-        if !self.nonce.is_empty() {
-            len += 1;
-        }
-        if self.state.is_some() {
-            len += 1;
-        }
-        if self.reserves.is_some() {
-            len += 1;
-        }
-        if self.close_on_fill {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Position", len)?;
-        if let Some(v) = self.phi.as_ref() {
-            struct_ser.serialize_field("phi", v)?;
-        }
-        if !self.nonce.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
-        }
-        if let Some(v) = self.state.as_ref() {
-            struct_ser.serialize_field("state", v)?;
-        }
-        if let Some(v) = self.reserves.as_ref() {
-            struct_ser.serialize_field("reserves", v)?;
-        }
-        if self.close_on_fill {
-            struct_ser.serialize_field("closeOnFill", &self.close_on_fill)?;
-        }
-        struct_ser.end()
+        let variant = match self {
+            Self::Unspecified => "POSITION_STATE_ENUM_UNSPECIFIED",
+            Self::Opened => "POSITION_STATE_ENUM_OPENED",
+            Self::Closed => "POSITION_STATE_ENUM_CLOSED",
+            Self::Withdrawn => "POSITION_STATE_ENUM_WITHDRAWN",
+            Self::Claimed => "POSITION_STATE_ENUM_CLAIMED",
+        };
+        serializer.serialize_str(variant)
     }
 }
-impl<'de> serde::Deserialize<'de> for Position {
+impl<'de> serde::Deserialize<'de> for position_state::PositionStateEnum {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "phi",
-            "nonce",
-            "state",
-            "reserves",
-            "close_on_fill",
-            "closeOnFill",
+            "POSITION_STATE_ENUM_UNSPECIFIED",
+            "POSITION_STATE_ENUM_OPENED",
+            "POSITION_STATE_ENUM_CLOSED",
+            "POSITION_STATE_ENUM_WITHDRAWN",
+            "POSITION_STATE_ENUM_CLAIMED",
         ];
 
-        #[allow(clippy::enum_variant_names)]
-        enum GeneratedField {
-            Phi,
-            Nonce,
-            State,
-            Reserves,
-            CloseOnFill,
-            __SkipField__,
-        }
-        impl<'de> serde::Deserialize<'de> for GeneratedField {
-            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
-            where
-                D: serde::Deserializer<'de>,
-            {
-                struct GeneratedVisitor;
+        struct GeneratedVisitor;
 
-                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-                    type Value = GeneratedField;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = position_state::PositionStateEnum;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        write!(formatter, "expected one of: {:?}", &FIELDS)
-                    }
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
 
-                    #[allow(unused_variables)]
-                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
-                    where
-                        E: serde::de::Error,
-                    {
-                        match value {
-                            "phi" => Ok(GeneratedField::Phi),
-                            "nonce" => Ok(GeneratedField::Nonce),
-                            "state" => Ok(GeneratedField::State),
-                            "reserves" => Ok(GeneratedField::Reserves),
-                            "closeOnFill" | "close_on_fill" => Ok(GeneratedField::CloseOnFill),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
-                    }
-                }
-                deserializer.deserialize_identifier(GeneratedVisitor)
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
             }
-        }
-        struct GeneratedVisitor;
-        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Position;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.Position")
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Position, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
             {
-                let mut phi__ = None;
-                let mut nonce__ = None;
-                let mut state__ = None;
-                let mut reserves__ = None;
-                let mut close_on_fill__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::Phi => {
-                            if phi__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("phi"));
-                            }
-                            phi__ = map_.next_value()?;
-                        }
-                        GeneratedField::Nonce => {
-                            if nonce__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nonce"));
-                            }
-                            nonce__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
-                            }
-                            state__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
-                            }
-                            reserves__ = map_.next_value()?;
-                        }
-                        GeneratedField::CloseOnFill => {
-                            if close_on_fill__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("closeOnFill"));
-                            }
-                            close_on_fill__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                match value {
+                    "POSITION_STATE_ENUM_UNSPECIFIED" => Ok(position_state::PositionStateEnum::Unspecified),
+                    "POSITION_STATE_ENUM_OPENED" => Ok(position_state::PositionStateEnum::Opened),
+                    "POSITION_STATE_ENUM_CLOSED" => Ok(position_state::PositionStateEnum::Closed),
+                    "POSITION_STATE_ENUM_WITHDRAWN" => Ok(position_state::PositionStateEnum::Withdrawn),
+                    "POSITION_STATE_ENUM_CLAIMED" => Ok(position_state::PositionStateEnum::Claimed),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
-                Ok(Position {
-                    phi: phi__,
-                    nonce: nonce__.unwrap_or_default(),
-                    state: state__,
-                    reserves: reserves__,
-                    close_on_fill: close_on_fill__.unwrap_or_default(),
-                })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Position", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_any(GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionClose {
+impl serde::Serialize for PositionWithdraw {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4840,14 +6222,27 @@ impl serde::Serialize for PositionClose {
         if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionClose", len)?;
+        if self.reserves_commitment.is_some() {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionWithdraw", len)?;
         if let Some(v) = self.position_id.as_ref() {
             struct_ser.serialize_field("positionId", v)?;
         }
+        if let Some(v) = self.reserves_commitment.as_ref() {
+            struct_ser.serialize_field("reservesCommitment", v)?;
+        }
+        if self.sequence != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionClose {
+impl<'de> serde::Deserialize<'de> for PositionWithdraw {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -4856,11 +6251,16 @@ impl<'de> serde::Deserialize<'de> for PositionClose {
         const FIELDS: &[&str] = &[
             "position_id",
             "positionId",
+            "reserves_commitment",
+            "reservesCommitment",
+            "sequence",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PositionId,
+            ReservesCommitment,
+            Sequence,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4884,6 +6284,8 @@ impl<'de> serde::Deserialize<'de> for PositionClose {
                     {
                         match value {
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "reservesCommitment" | "reserves_commitment" => Ok(GeneratedField::ReservesCommitment),
+                            "sequence" => Ok(GeneratedField::Sequence),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4893,17 +6295,19 @@ impl<'de> serde::Deserialize<'de> for PositionClose {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionClose;
+            type Value = PositionWithdraw;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionClose")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionWithdraw")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionClose, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionWithdraw, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut position_id__ = None;
+                let mut reserves_commitment__ = None;
+                let mut sequence__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PositionId => {
@@ -4912,20 +6316,36 @@ impl<'de> serde::Deserialize<'de> for PositionClose {
                             }
                             position_id__ = map_.next_value()?;
                         }
+                        GeneratedField::ReservesCommitment => {
+                            if reserves_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reservesCommitment"));
+                            }
+                            reserves_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionClose {
+                Ok(PositionWithdraw {
                     position_id: position_id__,
+                    reserves_commitment: reserves_commitment__,
+                    sequence: sequence__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionClose", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionWithdraw", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionId {
+impl serde::Serialize for PositionWithdrawPlan {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4933,39 +6353,63 @@ impl serde::Serialize for PositionId {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.inner.is_empty() {
+        if self.reserves.is_some() {
             len += 1;
         }
-        if !self.alt_bech32m.is_empty() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionId", len)?;
-        if !self.inner.is_empty() {
+        if self.pair.is_some() {
+            len += 1;
+        }
+        if self.sequence != 0 {
+            len += 1;
+        }
+        if !self.rewards.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionWithdrawPlan", len)?;
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.pair.as_ref() {
+            struct_ser.serialize_field("pair", v)?;
+        }
+        if self.sequence != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
+            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
         }
-        if !self.alt_bech32m.is_empty() {
-            struct_ser.serialize_field("altBech32m", &self.alt_bech32m)?;
+        if !self.rewards.is_empty() {
+            struct_ser.serialize_field("rewards", &self.rewards)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionId {
+impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "inner",
-            "alt_bech32m",
-            "altBech32m",
+            "reserves",
+            "position_id",
+            "positionId",
+            "pair",
+            "sequence",
+            "rewards",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Inner,
-            AltBech32m,
+            Reserves,
+            PositionId,
+            Pair,
+            Sequence,
+            Rewards,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4988,8 +6432,11 @@ impl<'de> serde::Deserialize<'de> for PositionId {
                         E: serde::de::Error,
                     {
                         match value {
-                            "inner" => Ok(GeneratedField::Inner),
-                            "altBech32m" | "alt_bech32m" => Ok(GeneratedField::AltBech32m),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "pair" => Ok(GeneratedField::Pair),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            "rewards" => Ok(GeneratedField::Rewards),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4999,49 +6446,73 @@ impl<'de> serde::Deserialize<'de> for PositionId {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionId;
+            type Value = PositionWithdrawPlan;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionId")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionWithdrawPlan")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionId, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionWithdrawPlan, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut inner__ = None;
-                let mut alt_bech32m__ = None;
+                let mut reserves__ = None;
+                let mut position_id__ = None;
+                let mut pair__ = None;
+                let mut sequence__ = None;
+                let mut rewards__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Inner => {
-                            if inner__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("inner"));
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
                             }
-                            inner__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Pair => {
+                            if pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pair"));
+                            }
+                            pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::AltBech32m => {
-                            if alt_bech32m__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("altBech32m"));
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
                             }
-                            alt_bech32m__ = Some(map_.next_value()?);
+                            rewards__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionId {
-                    inner: inner__.unwrap_or_default(),
-                    alt_bech32m: alt_bech32m__.unwrap_or_default(),
+                Ok(PositionWithdrawPlan {
+                    reserves: reserves__,
+                    position_id: position_id__,
+                    pair: pair__,
+                    sequence: sequence__.unwrap_or_default(),
+                    rewards: rewards__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionId", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionWithdrawPlan", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionOpen {
+impl serde::Serialize for PriceAccumulator {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5049,29 +6520,59 @@ impl serde::Serialize for PositionOpen {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position.is_some() {
+        if self.height != 0 {
+            len += 1;
+        }
+        if !self.cumulative_price.is_empty() {
+            len += 1;
+        }
+        if !self.price.is_empty() {
+            len += 1;
+        }
+        if !self.previous_price.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionOpen", len)?;
-        if let Some(v) = self.position.as_ref() {
-            struct_ser.serialize_field("position", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PriceAccumulator", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if !self.cumulative_price.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("cumulativePrice", pbjson::private::base64::encode(&self.cumulative_price).as_str())?;
+        }
+        if !self.price.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("price", pbjson::private::base64::encode(&self.price).as_str())?;
+        }
+        if !self.previous_price.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("previousPrice", pbjson::private::base64::encode(&self.previous_price).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionOpen {
+impl<'de> serde::Deserialize<'de> for PriceAccumulator {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position",
+            "height",
+            "cumulative_price",
+            "cumulativePrice",
+            "price",
+            "previous_price",
+            "previousPrice",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Position,
+            Height,
+            CumulativePrice,
+            Price,
+            PreviousPrice,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5094,7 +6595,10 @@ impl<'de> serde::Deserialize<'de> for PositionOpen {
                         E: serde::de::Error,
                     {
                         match value {
-                            "position" => Ok(GeneratedField::Position),
+                            "height" => Ok(GeneratedField::Height),
+                            "cumulativePrice" | "cumulative_price" => Ok(GeneratedField::CumulativePrice),
+                            "price" => Ok(GeneratedField::Price),
+                            "previousPrice" | "previous_price" => Ok(GeneratedField::PreviousPrice),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5104,39 +6608,71 @@ impl<'de> serde::Deserialize<'de> for PositionOpen {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionOpen;
+            type Value = PriceAccumulator;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionOpen")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PriceAccumulator")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionOpen, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PriceAccumulator, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position__ = None;
+                let mut height__ = None;
+                let mut cumulative_price__ = None;
+                let mut price__ = None;
+                let mut previous_price__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            position__ = map_.next_value()?;
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CumulativePrice => {
+                            if cumulative_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cumulativePrice"));
+                            }
+                            cumulative_price__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PreviousPrice => {
+                            if previous_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("previousPrice"));
+                            }
+                            previous_price__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionOpen {
-                    position: position__,
+                Ok(PriceAccumulator {
+                    height: height__.unwrap_or_default(),
+                    cumulative_price: cumulative_price__.unwrap_or_default(),
+                    price: price__.unwrap_or_default(),
+                    previous_price: previous_price__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionOpen", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PriceAccumulator", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionRewardClaim {
+impl serde::Serialize for Reserves {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5144,39 +6680,37 @@ impl serde::Serialize for PositionRewardClaim {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.r1.is_some() {
             len += 1;
         }
-        if self.rewards_commitment.is_some() {
+        if self.r2.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardClaim", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Reserves", len)?;
+        if let Some(v) = self.r1.as_ref() {
+            struct_ser.serialize_field("r1", v)?;
         }
-        if let Some(v) = self.rewards_commitment.as_ref() {
-            struct_ser.serialize_field("rewardsCommitment", v)?;
+        if let Some(v) = self.r2.as_ref() {
+            struct_ser.serialize_field("r2", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionRewardClaim {
+impl<'de> serde::Deserialize<'de> for Reserves {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
-            "rewards_commitment",
-            "rewardsCommitment",
+            "r1",
+            "r2",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
-            RewardsCommitment,
+            R1,
+            R2,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5199,8 +6733,8 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaim {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "rewardsCommitment" | "rewards_commitment" => Ok(GeneratedField::RewardsCommitment),
+                            "r1" => Ok(GeneratedField::R1),
+                            "r2" => Ok(GeneratedField::R2),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5210,47 +6744,47 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaim {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionRewardClaim;
+            type Value = Reserves;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardClaim")
+                formatter.write_str("struct penumbra.core.component.dex.v1.Reserves")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Reserves, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                let mut rewards_commitment__ = None;
+                let mut r1__ = None;
+                let mut r2__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::R1 => {
+                            if r1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("r1"));
                             }
-                            position_id__ = map_.next_value()?;
+                            r1__ = map_.next_value()?;
                         }
-                        GeneratedField::RewardsCommitment => {
-                            if rewards_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rewardsCommitment"));
+                        GeneratedField::R2 => {
+                            if r2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("r2"));
                             }
-                            rewards_commitment__ = map_.next_value()?;
+                            r2__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionRewardClaim {
-                    position_id: position_id__,
-                    rewards_commitment: rewards_commitment__,
+                Ok(Reserves {
+                    r1: r1__,
+                    r2: r2__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Reserves", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionRewardClaimPlan {
+impl serde::Serialize for SimulateBatchRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5258,29 +6792,29 @@ impl serde::Serialize for PositionRewardClaimPlan {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.reserves.is_some() {
+        if !self.swaps.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionRewardClaimPlan", len)?;
-        if let Some(v) = self.reserves.as_ref() {
-            struct_ser.serialize_field("reserves", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateBatchRequest", len)?;
+        if !self.swaps.is_empty() {
+            struct_ser.serialize_field("swaps", &self.swaps)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
+impl<'de> serde::Deserialize<'de> for SimulateBatchRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "reserves",
+            "swaps",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Reserves,
+            Swaps,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5303,7 +6837,7 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
                         E: serde::de::Error,
                     {
                         match value {
-                            "reserves" => Ok(GeneratedField::Reserves),
+                            "swaps" => Ok(GeneratedField::Swaps),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5313,39 +6847,39 @@ impl<'de> serde::Deserialize<'de> for PositionRewardClaimPlan {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionRewardClaimPlan;
+            type Value = SimulateBatchRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionRewardClaimPlan")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateBatchRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionRewardClaimPlan, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateBatchRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut reserves__ = None;
+                let mut swaps__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
+                        GeneratedField::Swaps => {
+                            if swaps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swaps"));
                             }
-                            reserves__ = map_.next_value()?;
+                            swaps__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionRewardClaimPlan {
-                    reserves: reserves__,
+                Ok(SimulateBatchRequest {
+                    swaps: swaps__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionRewardClaimPlan", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateBatchRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionState {
+impl serde::Serialize for simulate_batch_request::Swap {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5353,40 +6887,48 @@ impl serde::Serialize for PositionState {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.state != 0 {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        if self.sequence != 0 {
+        if self.delta_1.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionState", len)?;
-        if self.state != 0 {
-            let v = position_state::PositionStateEnum::try_from(self.state)
-                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.state)))?;
-            struct_ser.serialize_field("state", &v)?;
+        if self.delta_2.is_some() {
+            len += 1;
         }
-        if self.sequence != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateBatchRequest.Swap", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.delta_1.as_ref() {
+            struct_ser.serialize_field("delta1", v)?;
+        }
+        if let Some(v) = self.delta_2.as_ref() {
+            struct_ser.serialize_field("delta2", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionState {
+impl<'de> serde::Deserialize<'de> for simulate_batch_request::Swap {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "state",
-            "sequence",
+            "trading_pair",
+            "tradingPair",
+            "delta_1",
+            "delta1",
+            "delta_2",
+            "delta2",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            State,
-            Sequence,
+            TradingPair,
+            Delta1,
+            Delta2,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5400,149 +6942,76 @@ impl<'de> serde::Deserialize<'de> for PositionState {
                     type Value = GeneratedField;
 
                     fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        write!(formatter, "expected one of: {:?}", &FIELDS)
-                    }
-
-                    #[allow(unused_variables)]
-                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
-                    where
-                        E: serde::de::Error,
-                    {
-                        match value {
-                            "state" => Ok(GeneratedField::State),
-                            "sequence" => Ok(GeneratedField::Sequence),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
-                    }
-                }
-                deserializer.deserialize_identifier(GeneratedVisitor)
-            }
-        }
-        struct GeneratedVisitor;
-        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionState;
-
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionState")
-            }
-
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionState, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
-            {
-                let mut state__ = None;
-                let mut sequence__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
-                            }
-                            state__ = Some(map_.next_value::<position_state::PositionStateEnum>()? as i32);
-                        }
-                        GeneratedField::Sequence => {
-                            if sequence__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequence"));
-                            }
-                            sequence__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
-                }
-                Ok(PositionState {
-                    state: state__.unwrap_or_default(),
-                    sequence: sequence__.unwrap_or_default(),
-                })
-            }
-        }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionState", FIELDS, GeneratedVisitor)
-    }
-}
-impl serde::Serialize for position_state::PositionStateEnum {
-    #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        let variant = match self {
-            Self::Unspecified => "POSITION_STATE_ENUM_UNSPECIFIED",
-            Self::Opened => "POSITION_STATE_ENUM_OPENED",
-            Self::Closed => "POSITION_STATE_ENUM_CLOSED",
-            Self::Withdrawn => "POSITION_STATE_ENUM_WITHDRAWN",
-            Self::Claimed => "POSITION_STATE_ENUM_CLAIMED",
-        };
-        serializer.serialize_str(variant)
-    }
-}
-impl<'de> serde::Deserialize<'de> for position_state::PositionStateEnum {
-    #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        const FIELDS: &[&str] = &[
-            "POSITION_STATE_ENUM_UNSPECIFIED",
-            "POSITION_STATE_ENUM_OPENED",
-            "POSITION_STATE_ENUM_CLOSED",
-            "POSITION_STATE_ENUM_WITHDRAWN",
-            "POSITION_STATE_ENUM_CLAIMED",
-        ];
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
 
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "delta1" | "delta_1" => Ok(GeneratedField::Delta1),
+                            "delta2" | "delta_2" => Ok(GeneratedField::Delta2),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
         struct GeneratedVisitor;
-
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = position_state::PositionStateEnum;
+            type Value = simulate_batch_request::Swap;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(formatter, "expected one of: {:?}", &FIELDS)
-            }
-
-            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
-                    })
-            }
-
-            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
-                    })
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateBatchRequest.Swap")
             }
 
-            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<simulate_batch_request::Swap, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
             {
-                match value {
-                    "POSITION_STATE_ENUM_UNSPECIFIED" => Ok(position_state::PositionStateEnum::Unspecified),
-                    "POSITION_STATE_ENUM_OPENED" => Ok(position_state::PositionStateEnum::Opened),
-                    "POSITION_STATE_ENUM_CLOSED" => Ok(position_state::PositionStateEnum::Closed),
-                    "POSITION_STATE_ENUM_WITHDRAWN" => Ok(position_state::PositionStateEnum::Withdrawn),
-                    "POSITION_STATE_ENUM_CLAIMED" => Ok(position_state::PositionStateEnum::Claimed),
-                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                let mut trading_pair__ = None;
+                let mut delta_1__ = None;
+                let mut delta_2__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Delta1 => {
+                            if delta_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta1"));
+                            }
+                            delta_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Delta2 => {
+                            if delta_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delta2"));
+                            }
+                            delta_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
+                Ok(simulate_batch_request::Swap {
+                    trading_pair: trading_pair__,
+                    delta_1: delta_1__,
+                    delta_2: delta_2__,
+                })
             }
         }
-        deserializer.deserialize_any(GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateBatchRequest.Swap", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionWithdraw {
+impl serde::Serialize for SimulateBatchResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5550,48 +7019,46 @@ impl serde::Serialize for PositionWithdraw {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if !self.batches.is_empty() {
             len += 1;
         }
-        if self.reserves_commitment.is_some() {
+        if !self.outputs.is_empty() {
             len += 1;
         }
-        if self.sequence != 0 {
+        if !self.arb_executions.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionWithdraw", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateBatchResponse", len)?;
+        if !self.batches.is_empty() {
+            struct_ser.serialize_field("batches", &self.batches)?;
         }
-        if let Some(v) = self.reserves_commitment.as_ref() {
-            struct_ser.serialize_field("reservesCommitment", v)?;
+        if !self.outputs.is_empty() {
+            struct_ser.serialize_field("outputs", &self.outputs)?;
         }
-        if self.sequence != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        if !self.arb_executions.is_empty() {
+            struct_ser.serialize_field("arbExecutions", &self.arb_executions)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionWithdraw {
+impl<'de> serde::Deserialize<'de> for SimulateBatchResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
-            "reserves_commitment",
-            "reservesCommitment",
-            "sequence",
+            "batches",
+            "outputs",
+            "arb_executions",
+            "arbExecutions",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
-            ReservesCommitment,
-            Sequence,
+            Batches,
+            Outputs,
+            ArbExecutions,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5614,9 +7081,9 @@ impl<'de> serde::Deserialize<'de> for PositionWithdraw {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "reservesCommitment" | "reserves_commitment" => Ok(GeneratedField::ReservesCommitment),
-                            "sequence" => Ok(GeneratedField::Sequence),
+                            "batches" => Ok(GeneratedField::Batches),
+                            "outputs" => Ok(GeneratedField::Outputs),
+                            "arbExecutions" | "arb_executions" => Ok(GeneratedField::ArbExecutions),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5626,57 +7093,55 @@ impl<'de> serde::Deserialize<'de> for PositionWithdraw {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionWithdraw;
+            type Value = SimulateBatchResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionWithdraw")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateBatchResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionWithdraw, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateBatchResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                let mut reserves_commitment__ = None;
-                let mut sequence__ = None;
+                let mut batches__ = None;
+                let mut outputs__ = None;
+                let mut arb_executions__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Batches => {
+                            if batches__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batches"));
                             }
-                            position_id__ = map_.next_value()?;
+                            batches__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::ReservesCommitment => {
-                            if reserves_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reservesCommitment"));
+                        GeneratedField::Outputs => {
+                            if outputs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputs"));
                             }
-                            reserves_commitment__ = map_.next_value()?;
+                            outputs__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::Sequence => {
-                            if sequence__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequence"));
+                        GeneratedField::ArbExecutions => {
+                            if arb_executions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("arbExecutions"));
                             }
-                            sequence__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            arb_executions__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionWithdraw {
-                    position_id: position_id__,
-                    reserves_commitment: reserves_commitment__,
-                    sequence: sequence__.unwrap_or_default(),
+                Ok(SimulateBatchResponse {
+                    batches: batches__.unwrap_or_default(),
+                    outputs: outputs__.unwrap_or_default(),
+                    arb_executions: arb_executions__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionWithdraw", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateBatchResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for PositionWithdrawPlan {
+impl serde::Serialize for simulate_batch_response::Batch {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5684,63 +7149,48 @@ impl serde::Serialize for PositionWithdrawPlan {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.reserves.is_some() {
-            len += 1;
-        }
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        if self.pair.is_some() {
+        if self.output_data.is_some() {
             len += 1;
         }
-        if self.sequence != 0 {
+        if self.swap_execution_1_for_2.is_some() {
             len += 1;
         }
-        if !self.rewards.is_empty() {
+        if self.swap_execution_2_for_1.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionWithdrawPlan", len)?;
-        if let Some(v) = self.reserves.as_ref() {
-            struct_ser.serialize_field("reserves", v)?;
-        }
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
-        if let Some(v) = self.pair.as_ref() {
-            struct_ser.serialize_field("pair", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateBatchResponse.Batch", len)?;
+        if let Some(v) = self.output_data.as_ref() {
+            struct_ser.serialize_field("outputData", v)?;
         }
-        if self.sequence != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("sequence", ToString::to_string(&self.sequence).as_str())?;
+        if let Some(v) = self.swap_execution_1_for_2.as_ref() {
+            struct_ser.serialize_field("swapExecution1For2", v)?;
         }
-        if !self.rewards.is_empty() {
-            struct_ser.serialize_field("rewards", &self.rewards)?;
+        if let Some(v) = self.swap_execution_2_for_1.as_ref() {
+            struct_ser.serialize_field("swapExecution2For1", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
+impl<'de> serde::Deserialize<'de> for simulate_batch_response::Batch {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "reserves",
-            "position_id",
-            "positionId",
-            "pair",
-            "sequence",
-            "rewards",
+            "output_data",
+            "outputData",
+            "swap_execution_1_for_2",
+            "swapExecution1For2",
+            "swap_execution_2_for_1",
+            "swapExecution2For1",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Reserves,
-            PositionId,
-            Pair,
-            Sequence,
-            Rewards,
+            OutputData,
+            SwapExecution1For2,
+            SwapExecution2For1,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5763,11 +7213,9 @@ impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
                         E: serde::de::Error,
                     {
                         match value {
-                            "reserves" => Ok(GeneratedField::Reserves),
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "pair" => Ok(GeneratedField::Pair),
-                            "sequence" => Ok(GeneratedField::Sequence),
-                            "rewards" => Ok(GeneratedField::Rewards),
+                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
+                            "swapExecution1For2" | "swap_execution_1_for_2" => Ok(GeneratedField::SwapExecution1For2),
+                            "swapExecution2For1" | "swap_execution_2_for_1" => Ok(GeneratedField::SwapExecution2For1),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5777,73 +7225,55 @@ impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionWithdrawPlan;
+            type Value = simulate_batch_response::Batch;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionWithdrawPlan")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateBatchResponse.Batch")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionWithdrawPlan, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<simulate_batch_response::Batch, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut reserves__ = None;
-                let mut position_id__ = None;
-                let mut pair__ = None;
-                let mut sequence__ = None;
-                let mut rewards__ = None;
+                let mut output_data__ = None;
+                let mut swap_execution_1_for_2__ = None;
+                let mut swap_execution_2_for_1__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
-                            }
-                            reserves__ = map_.next_value()?;
-                        }
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::Pair => {
-                            if pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("pair"));
+                        GeneratedField::OutputData => {
+                            if output_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputData"));
                             }
-                            pair__ = map_.next_value()?;
+                            output_data__ = map_.next_value()?;
                         }
-                        GeneratedField::Sequence => {
-                            if sequence__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequence"));
+                        GeneratedField::SwapExecution1For2 => {
+                            if swap_execution_1_for_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution1For2"));
                             }
-                            sequence__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            swap_execution_1_for_2__ = map_.next_value()?;
                         }
-                        GeneratedField::Rewards => {
-                            if rewards__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rewards"));
+                        GeneratedField::SwapExecution2For1 => {
+                            if swap_execution_2_for_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapExecution2For1"));
                             }
-                            rewards__ = Some(map_.next_value()?);
+                            swap_execution_2_for_1__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
-                }
-                Ok(PositionWithdrawPlan {
-                    reserves: reserves__,
-                    position_id: position_id__,
-                    pair: pair__,
-                    sequence: sequence__.unwrap_or_default(),
-                    rewards: rewards__.unwrap_or_default(),
+                }
+                Ok(simulate_batch_response::Batch {
+                    output_data: output_data__,
+                    swap_execution_1_for_2: swap_execution_1_for_2__,
+                    swap_execution_2_for_1: swap_execution_2_for_1__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionWithdrawPlan", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateBatchResponse.Batch", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Reserves {
+impl serde::Serialize for simulate_batch_response::SwapOutput {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5851,37 +7281,39 @@ impl serde::Serialize for Reserves {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.r1.is_some() {
+        if self.lambda_1.is_some() {
             len += 1;
         }
-        if self.r2.is_some() {
+        if self.lambda_2.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Reserves", len)?;
-        if let Some(v) = self.r1.as_ref() {
-            struct_ser.serialize_field("r1", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateBatchResponse.SwapOutput", len)?;
+        if let Some(v) = self.lambda_1.as_ref() {
+            struct_ser.serialize_field("lambda1", v)?;
         }
-        if let Some(v) = self.r2.as_ref() {
-            struct_ser.serialize_field("r2", v)?;
+        if let Some(v) = self.lambda_2.as_ref() {
+            struct_ser.serialize_field("lambda2", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Reserves {
+impl<'de> serde::Deserialize<'de> for simulate_batch_response::SwapOutput {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "r1",
-            "r2",
+            "lambda_1",
+            "lambda1",
+            "lambda_2",
+            "lambda2",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            R1,
-            R2,
+            Lambda1,
+            Lambda2,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5904,8 +7336,8 @@ impl<'de> serde::Deserialize<'de> for Reserves {
                         E: serde::de::Error,
                     {
                         match value {
-                            "r1" => Ok(GeneratedField::R1),
-                            "r2" => Ok(GeneratedField::R2),
+                            "lambda1" | "lambda_1" => Ok(GeneratedField::Lambda1),
+                            "lambda2" | "lambda_2" => Ok(GeneratedField::Lambda2),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5915,44 +7347,44 @@ impl<'de> serde::Deserialize<'de> for Reserves {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Reserves;
+            type Value = simulate_batch_response::SwapOutput;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.Reserves")
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulateBatchResponse.SwapOutput")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Reserves, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<simulate_batch_response::SwapOutput, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut r1__ = None;
-                let mut r2__ = None;
+                let mut lambda_1__ = None;
+                let mut lambda_2__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::R1 => {
-                            if r1__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("r1"));
+                        GeneratedField::Lambda1 => {
+                            if lambda_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lambda1"));
                             }
-                            r1__ = map_.next_value()?;
+                            lambda_1__ = map_.next_value()?;
                         }
-                        GeneratedField::R2 => {
-                            if r2__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("r2"));
+                        GeneratedField::Lambda2 => {
+                            if lambda_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lambda2"));
                             }
-                            r2__ = map_.next_value()?;
+                            lambda_2__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Reserves {
-                    r1: r1__,
-                    r2: r2__,
+                Ok(simulate_batch_response::SwapOutput {
+                    lambda_1: lambda_1__,
+                    lambda_2: lambda_2__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Reserves", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateBatchResponse.SwapOutput", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for SimulateTradeRequest {
@@ -9754,6 +11186,240 @@ impl<'de> serde::Deserialize<'de> for TradingPair {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.TradingPair", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for TwapRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.blocks != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.TwapRequest", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if self.blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blocks", ToString::to_string(&self.blocks).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TwapRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+            "blocks",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            Blocks,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "blocks" => Ok(GeneratedField::Blocks),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TwapRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.TwapRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TwapRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                let mut blocks__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Blocks => {
+                            if blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blocks"));
+                            }
+                            blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TwapRequest {
+                    trading_pair: trading_pair__,
+                    blocks: blocks__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.TwapRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TwapResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.approx_price != 0. {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.TwapResponse", len)?;
+        if self.approx_price != 0. {
+            struct_ser.serialize_field("approxPrice", &self.approx_price)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TwapResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "approx_price",
+            "approxPrice",
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ApproxPrice,
+            Height,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "approxPrice" | "approx_price" => Ok(GeneratedField::ApproxPrice),
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TwapResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.TwapResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TwapResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut approx_price__ = None;
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ApproxPrice => {
+                            if approx_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approxPrice"));
+                            }
+                            approx_price__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TwapResponse {
+                    approx_price: approx_price__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.TwapResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ZkSwapClaimProof {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -359,6 +359,46 @@ impl ::prost::Name for TimestampByHeightResponse {
         ::prost::alloc::format!("penumbra.core.component.sct.v1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SctFrontierRequest {
+    /// The height of the block after which to return the frontier, or 0 for the
+    /// latest block.
+    ///
+    /// Only the latest block and a few recent ones are available.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+}
+impl ::prost::Name for SctFrontierRequest {
+    const NAME: &'static str = "SctFrontierRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.sct.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.sct.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SctFrontierResponse {
+    /// The height of the block after which the frontier was taken.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The root of the state commitment tree at that height.
+    #[prost(message, optional, tag = "2")]
+    pub anchor: ::core::option::Option<
+        super::super::super::super::crypto::tct::v1::MerkleRoot,
+    >,
+    /// The state commitment tree at that height, with every commitment forgotten,
+    /// serialized with bincode.
+    #[prost(bytes = "vec", tag = "3")]
+    pub compact_frontier: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for SctFrontierResponse {
+    const NAME: &'static str = "SctFrontierResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.sct.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.sct.v1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -536,6 +576,39 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Returns the frontier of the state commitment tree after a recent block,
+        /// from which a client can start scanning the chain without scanning the
+        /// blocks before it.
+        pub async fn sct_frontier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SctFrontierRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SctFrontierResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.sct.v1.QueryService/SctFrontier",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.sct.v1.QueryService",
+                        "SctFrontier",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -567,6 +640,16 @@ pub mod query_service_server {
             tonic::Response<super::TimestampByHeightResponse>,
             tonic::Status,
         >;
+        /// Returns the frontier of the state commitment tree after a recent block,
+        /// from which a client can start scanning the chain without scanning the
+        /// blocks before it.
+        async fn sct_frontier(
+            &self,
+            request: tonic::Request<super::SctFrontierRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SctFrontierResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the SCT component.
     #[derive(Debug)]
@@ -787,6 +870,52 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.sct.v1.QueryService/SctFrontier" => {
+                    #[allow(non_camel_case_types)]
+                    struct SctFrontierSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::SctFrontierRequest>
+                    for SctFrontierSvc<T> {
+                        type Response = super::SctFrontierResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SctFrontierRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::sct_frontier(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SctFrontierSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
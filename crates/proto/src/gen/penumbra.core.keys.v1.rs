@@ -80,6 +80,35 @@ impl ::prost::Name for AddressView {
         ::prost::alloc::format!("penumbra.core.keys.v1.{}", Self::NAME)
     }
 }
+/// A request for a payment to an address, which can be shared as a
+/// `penumbra:` URI.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaymentRequest {
+    /// The address to pay.
+    #[prost(message, optional, tag = "1")]
+    pub address: ::core::option::Option<Address>,
+    /// The values requested. If empty, the payer chooses what to send.
+    #[prost(message, repeated, tag = "2")]
+    pub values: ::prost::alloc::vec::Vec<super::super::asset::v1::Value>,
+    /// The memo text to attach to the payment, if any.
+    #[prost(string, tag = "3")]
+    pub memo: ::prost::alloc::string::String,
+    /// The time after which the request should no longer be paid, in seconds
+    /// since the UNIX epoch, or 0 if it doesn't expire.
+    #[prost(uint64, tag = "4")]
+    pub expiry: u64,
+    /// The identifier of the invoice the payment is for, if any.
+    #[prost(string, tag = "5")]
+    pub invoice_id: ::prost::alloc::string::String,
+}
+impl ::prost::Name for PaymentRequest {
+    const NAME: &'static str = "PaymentRequest";
+    const PACKAGE: &'static str = "penumbra.core.keys.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.keys.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PayloadKey {
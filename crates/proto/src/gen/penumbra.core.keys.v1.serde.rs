@@ -1157,6 +1157,173 @@ impl<'de> serde::Deserialize<'de> for PayloadKey {
         deserializer.deserialize_struct("penumbra.core.keys.v1.PayloadKey", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for PaymentRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.address.is_some() {
+            len += 1;
+        }
+        if !self.values.is_empty() {
+            len += 1;
+        }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
+        if self.expiry != 0 {
+            len += 1;
+        }
+        if !self.invoice_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.keys.v1.PaymentRequest", len)?;
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
+        }
+        if !self.values.is_empty() {
+            struct_ser.serialize_field("values", &self.values)?;
+        }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
+        if self.expiry != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expiry", ToString::to_string(&self.expiry).as_str())?;
+        }
+        if !self.invoice_id.is_empty() {
+            struct_ser.serialize_field("invoiceId", &self.invoice_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PaymentRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "address",
+            "values",
+            "memo",
+            "expiry",
+            "invoice_id",
+            "invoiceId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Address,
+            Values,
+            Memo,
+            Expiry,
+            InvoiceId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "address" => Ok(GeneratedField::Address),
+                            "values" => Ok(GeneratedField::Values),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "expiry" => Ok(GeneratedField::Expiry),
+                            "invoiceId" | "invoice_id" => Ok(GeneratedField::InvoiceId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PaymentRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.keys.v1.PaymentRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PaymentRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut address__ = None;
+                let mut values__ = None;
+                let mut memo__ = None;
+                let mut expiry__ = None;
+                let mut invoice_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = map_.next_value()?;
+                        }
+                        GeneratedField::Values => {
+                            if values__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("values"));
+                            }
+                            values__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Expiry => {
+                            if expiry__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiry"));
+                            }
+                            expiry__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::InvoiceId => {
+                            if invoice_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("invoiceId"));
+                            }
+                            invoice_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PaymentRequest {
+                    address: address__,
+                    values: values__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
+                    expiry: expiry__.unwrap_or_default(),
+                    invoice_id: invoice_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.keys.v1.PaymentRequest", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for SpendKey {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
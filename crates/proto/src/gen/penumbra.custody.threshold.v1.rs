@@ -208,6 +208,125 @@ impl ::prost::Name for FollowerRound2 {
         ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
     }
 }
+/// A message the coordinator sends after its first message, in robust signing.
+///
+/// Rather than a single second round, the coordinator keeps starting signing attempts
+/// with any threshold of signers that have sent it fresh commitments, until one attempt
+/// produces a signature.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RoastCoordinatorMessage {
+    #[prost(oneof = "roast_coordinator_message::Message", tags = "1, 2")]
+    pub message: ::core::option::Option<roast_coordinator_message::Message>,
+}
+/// Nested message and enum types in `RoastCoordinatorMessage`.
+pub mod roast_coordinator_message {
+    /// A new signing attempt.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Attempt {
+        /// The number of this attempt, counting from 1.
+        #[prost(uint64, tag = "1")]
+        pub attempt: u64,
+        /// The commitments of the signers taking part in this attempt.
+        #[prost(message, optional, tag = "2")]
+        pub commitments: ::core::option::Option<super::CoordinatorRound2>,
+    }
+    impl ::prost::Name for Attempt {
+        const NAME: &'static str = "Attempt";
+        const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.threshold.v1.RoastCoordinatorMessage.{}", Self::NAME
+            )
+        }
+    }
+    /// The end of signing, which tells the followers to stop waiting for attempts.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Finished {
+        /// Whether a signature was produced.
+        #[prost(bool, tag = "1")]
+        pub success: bool,
+        /// The verification keys of the signers who were found to misbehave.
+        #[prost(message, repeated, tag = "2")]
+        pub misbehaving: ::prost::alloc::vec::Vec<super::VerificationKey>,
+    }
+    impl ::prost::Name for Finished {
+        const NAME: &'static str = "Finished";
+        const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.threshold.v1.RoastCoordinatorMessage.{}", Self::NAME
+            )
+        }
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Message {
+        #[prost(message, tag = "1")]
+        Attempt(Attempt),
+        #[prost(message, tag = "2")]
+        Finished(Finished),
+    }
+}
+impl ::prost::Name for RoastCoordinatorMessage {
+    const NAME: &'static str = "RoastCoordinatorMessage";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+/// The message the followers send to the coordinator in robust signing.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RoastFollowerMessage {
+    #[prost(message, optional, tag = "1")]
+    pub inner: ::core::option::Option<roast_follower_message::Inner>,
+    /// The verification key identifying the sender.
+    #[prost(message, optional, tag = "2")]
+    pub pk: ::core::option::Option<VerificationKey>,
+    /// A signature over the proto-encoded bytes of inner.
+    #[prost(message, optional, tag = "3")]
+    pub sig: ::core::option::Option<Signature>,
+}
+/// Nested message and enum types in `RoastFollowerMessage`.
+pub mod roast_follower_message {
+    /// The inner message that will be signed by the follower.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Inner {
+        /// The attempt these shares are for, or 0 for the first message, which has no shares.
+        #[prost(uint64, tag = "1")]
+        pub attempt: u64,
+        /// One share for each signature requested by the plan, in order.
+        #[prost(message, repeated, tag = "2")]
+        pub shares: ::prost::alloc::vec::Vec<
+            super::super::super::super::crypto::decaf377_frost::v1::SignatureShare,
+        >,
+        /// One fresh signing commitment pair for each signature, for the next attempt.
+        #[prost(message, repeated, tag = "3")]
+        pub commitments: ::prost::alloc::vec::Vec<
+            super::super::super::super::crypto::decaf377_frost::v1::SigningCommitments,
+        >,
+    }
+    impl ::prost::Name for Inner {
+        const NAME: &'static str = "Inner";
+        const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.threshold.v1.RoastFollowerMessage.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for RoastFollowerMessage {
+    const NAME: &'static str = "RoastFollowerMessage";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
 /// The first message we broadcast in the DKG protocol.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -297,3 +416,697 @@ impl ::prost::Name for DkgRound2 {
         ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
     }
 }
+/// The first message we broadcast when resharing a threshold key.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReshareRound1 {
+    /// A verification key establishing an identity for the sender of this message.
+    #[prost(bytes = "vec", tag = "1")]
+    pub vk: ::prost::alloc::vec::Vec<u8>,
+    /// An encryption key for the second round, if the sender is receiving a new share.
+    #[prost(bytes = "vec", tag = "2")]
+    pub epk: ::prost::alloc::vec::Vec<u8>,
+    /// The threshold of the current key, if the sender is dealing out their current share.
+    ///
+    /// This is zero for participants who aren't dealing out a share.
+    #[prost(uint32, tag = "3")]
+    pub old_threshold: u32,
+}
+impl ::prost::Name for ReshareRound1 {
+    const NAME: &'static str = "ReshareRound1";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+/// The second message we broadcast when resharing a threshold key.
+///
+/// Only the participants dealing out their current share send this message.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReshareRound2 {
+    #[prost(message, optional, tag = "1")]
+    pub inner: ::core::option::Option<reshare_round2::Inner>,
+    /// The verification key identifying the sender.
+    #[prost(bytes = "vec", tag = "2")]
+    pub vk: ::prost::alloc::vec::Vec<u8>,
+    /// A signature over the proto-encoded inner message.
+    #[prost(bytes = "vec", tag = "3")]
+    pub sig: ::prost::alloc::vec::Vec<u8>,
+}
+/// Nested message and enum types in `ReshareRound2`.
+pub mod reshare_round2 {
+    /// A share of the dealer's share, encrypted, along with an identifier for the recipient.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TargetedPackage {
+        /// A verification key identifying the recipient.
+        #[prost(bytes = "vec", tag = "1")]
+        pub vk: ::prost::alloc::vec::Vec<u8>,
+        /// The ciphertext of an encrypted ResharePackage.
+        #[prost(bytes = "vec", tag = "2")]
+        pub encrypted_package: ::prost::alloc::vec::Vec<u8>,
+    }
+    impl ::prost::Name for TargetedPackage {
+        const NAME: &'static str = "TargetedPackage";
+        const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.threshold.v1.ReshareRound2.{}", Self::NAME
+            )
+        }
+    }
+    /// An inner message that will be signed.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Inner {
+        /// The verification keys of every participant dealing out their share, sorted.
+        #[prost(bytes = "vec", repeated, tag = "1")]
+        pub dealers: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+        /// The threshold of the new key.
+        #[prost(uint32, tag = "2")]
+        pub threshold: u32,
+        /// Commitments to the coefficients of the polynomial splitting the dealer's share.
+        #[prost(bytes = "vec", repeated, tag = "3")]
+        pub commitment: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+        /// Encrypted packages for each recipient.
+        #[prost(message, repeated, tag = "4")]
+        pub encrypted_packages: ::prost::alloc::vec::Vec<TargetedPackage>,
+    }
+    impl ::prost::Name for Inner {
+        const NAME: &'static str = "Inner";
+        const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.threshold.v1.ReshareRound2.{}", Self::NAME
+            )
+        }
+    }
+}
+impl ::prost::Name for ReshareRound2 {
+    const NAME: &'static str = "ReshareRound2";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+/// The package a dealer sends to each recipient when resharing a threshold key.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResharePackage {
+    /// The recipient's share of the dealer's share.
+    #[prost(bytes = "vec", tag = "1")]
+    pub share: ::prost::alloc::vec::Vec<u8>,
+    /// The full viewing key, which stays the same when resharing.
+    #[prost(message, optional, tag = "2")]
+    pub full_viewing_key: ::core::option::Option<
+        super::super::super::core::keys::v1::FullViewingKey,
+    >,
+}
+impl ::prost::Name for ResharePackage {
+    const NAME: &'static str = "ResharePackage";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateRequest {
+    /// An identifier for the session, derived from the session's shared key.
+    #[prost(bytes = "vec", tag = "1")]
+    pub session_id: ::prost::alloc::vec::Vec<u8>,
+    /// The ed25519 verification keys of the participants allowed to post to the session.
+    ///
+    /// If this is empty, anyone can post, as long as they sign their messages.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub participants: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The ed25519 verification key of the participant creating the session.
+    #[prost(bytes = "vec", tag = "3")]
+    pub creator: ::prost::alloc::vec::Vec<u8>,
+    /// The creator's signature over the session identifier and participants.
+    #[prost(bytes = "vec", tag = "4")]
+    pub sig: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for CreateRequest {
+    const NAME: &'static str = "CreateRequest";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateResponse {}
+impl ::prost::Name for CreateResponse {
+    const NAME: &'static str = "CreateResponse";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PostRequest {
+    /// An identifier for the session, derived from the session's shared key.
+    #[prost(bytes = "vec", tag = "1")]
+    pub session_id: ::prost::alloc::vec::Vec<u8>,
+    /// The ed25519 verification key of the participant posting the message.
+    #[prost(bytes = "vec", tag = "2")]
+    pub sender: ::prost::alloc::vec::Vec<u8>,
+    /// The encrypted message.
+    #[prost(bytes = "vec", tag = "3")]
+    pub ciphertext: ::prost::alloc::vec::Vec<u8>,
+    /// The sender's signature over the session identifier and ciphertext.
+    #[prost(bytes = "vec", tag = "4")]
+    pub sig: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for PostRequest {
+    const NAME: &'static str = "PostRequest";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PostResponse {}
+impl ::prost::Name for PostResponse {
+    const NAME: &'static str = "PostResponse";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeRequest {
+    /// An identifier for the session, derived from the session's shared key.
+    #[prost(bytes = "vec", tag = "1")]
+    pub session_id: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for SubscribeRequest {
+    const NAME: &'static str = "SubscribeRequest";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeResponse {
+    /// The ed25519 verification key of the participant that posted the message.
+    #[prost(bytes = "vec", tag = "1")]
+    pub sender: ::prost::alloc::vec::Vec<u8>,
+    /// The encrypted message.
+    #[prost(bytes = "vec", tag = "2")]
+    pub ciphertext: ::prost::alloc::vec::Vec<u8>,
+    /// The sender's signature over the session identifier and ciphertext.
+    #[prost(bytes = "vec", tag = "3")]
+    pub sig: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for SubscribeResponse {
+    const NAME: &'static str = "SubscribeResponse";
+    const PACKAGE: &'static str = "penumbra.custody.threshold.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.custody.threshold.v1.{}", Self::NAME)
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "rpc")]
+pub mod coordinator_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// A service relaying the messages of threshold signing and DKG sessions between participants.
+    ///
+    /// Participants encrypt every message they post under a key shared out-of-band when the session
+    /// is set up, so the coordinator only sees opaque ciphertexts, and cannot read them.  Each message
+    /// is also signed with its sender's identity key, so that neither the coordinator nor the other
+    /// participants can forge it.
+    #[derive(Debug, Clone)]
+    pub struct CoordinatorServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl CoordinatorServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> CoordinatorServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> CoordinatorServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            CoordinatorServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Create a session, before any participant can post or subscribe to it.
+        pub async fn create(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateRequest>,
+        ) -> std::result::Result<tonic::Response<super::CreateResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.threshold.v1.CoordinatorService/Create",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.custody.threshold.v1.CoordinatorService",
+                        "Create",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Post a message to every participant in a session.
+        pub async fn post(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PostRequest>,
+        ) -> std::result::Result<tonic::Response<super::PostResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.threshold.v1.CoordinatorService/Post",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.custody.threshold.v1.CoordinatorService",
+                        "Post",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Stream every message posted to a session, starting from the first one,
+        /// and waiting for new messages as they arrive.
+        pub async fn subscribe(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::SubscribeResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.custody.threshold.v1.CoordinatorService/Subscribe",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.custody.threshold.v1.CoordinatorService",
+                        "Subscribe",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "rpc")]
+pub mod coordinator_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with CoordinatorServiceServer.
+    #[async_trait]
+    pub trait CoordinatorService: Send + Sync + 'static {
+        /// Create a session, before any participant can post or subscribe to it.
+        async fn create(
+            &self,
+            request: tonic::Request<super::CreateRequest>,
+        ) -> std::result::Result<tonic::Response<super::CreateResponse>, tonic::Status>;
+        /// Post a message to every participant in a session.
+        async fn post(
+            &self,
+            request: tonic::Request<super::PostRequest>,
+        ) -> std::result::Result<tonic::Response<super::PostResponse>, tonic::Status>;
+        /// Server streaming response type for the Subscribe method.
+        type SubscribeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::SubscribeResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Stream every message posted to a session, starting from the first one,
+        /// and waiting for new messages as they arrive.
+        async fn subscribe(
+            &self,
+            request: tonic::Request<super::SubscribeRequest>,
+        ) -> std::result::Result<tonic::Response<Self::SubscribeStream>, tonic::Status>;
+    }
+    /// A service relaying the messages of threshold signing and DKG sessions between participants.
+    ///
+    /// Participants encrypt every message they post under a key shared out-of-band when the session
+    /// is set up, so the coordinator only sees opaque ciphertexts, and cannot read them.  Each message
+    /// is also signed with its sender's identity key, so that neither the coordinator nor the other
+    /// participants can forge it.
+    #[derive(Debug)]
+    pub struct CoordinatorServiceServer<T: CoordinatorService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: CoordinatorService> CoordinatorServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for CoordinatorServiceServer<T>
+    where
+        T: CoordinatorService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/penumbra.custody.threshold.v1.CoordinatorService/Create" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateSvc<T: CoordinatorService>(pub Arc<T>);
+                    impl<
+                        T: CoordinatorService,
+                    > tonic::server::UnaryService<super::CreateRequest>
+                    for CreateSvc<T> {
+                        type Response = super::CreateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoordinatorService>::create(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = CreateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.custody.threshold.v1.CoordinatorService/Post" => {
+                    #[allow(non_camel_case_types)]
+                    struct PostSvc<T: CoordinatorService>(pub Arc<T>);
+                    impl<
+                        T: CoordinatorService,
+                    > tonic::server::UnaryService<super::PostRequest> for PostSvc<T> {
+                        type Response = super::PostResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PostRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoordinatorService>::post(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PostSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.custody.threshold.v1.CoordinatorService/Subscribe" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeSvc<T: CoordinatorService>(pub Arc<T>);
+                    impl<
+                        T: CoordinatorService,
+                    > tonic::server::ServerStreamingService<super::SubscribeRequest>
+                    for SubscribeSvc<T> {
+                        type Response = super::SubscribeResponse;
+                        type ResponseStream = T::SubscribeStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubscribeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CoordinatorService>::subscribe(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SubscribeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: CoordinatorService> Clone for CoordinatorServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: CoordinatorService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: CoordinatorService> tonic::server::NamedService
+    for CoordinatorServiceServer<T> {
+        const NAME: &'static str = "penumbra.custody.threshold.v1.CoordinatorService";
+    }
+}
@@ -124,7 +124,7 @@ impl ::prost::Name for AuthorizeValidatorVoteResponse {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct PreAuthorization {
-    #[prost(oneof = "pre_authorization::PreAuthorization", tags = "1")]
+    #[prost(oneof = "pre_authorization::PreAuthorization", tags = "1, 2, 3")]
     pub pre_authorization: ::core::option::Option<pre_authorization::PreAuthorization>,
 }
 /// Nested message and enum types in `PreAuthorization`.
@@ -150,11 +150,59 @@ pub mod pre_authorization {
             )
         }
     }
+    /// An ECDSA-based preauthorization over the NIST P-256 curve, containing an
+    /// ECDSA/SHA-256 signature over the `TransactionPlan`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct EcdsaP256 {
+        /// The SEC1-encoded P-256 verification key used to verify the signature.
+        #[prost(bytes = "vec", tag = "1")]
+        pub vk: ::prost::alloc::vec::Vec<u8>,
+        /// The ECDSA signature over the `TransactionPlan`, either as 64 bytes of
+        /// fixed-width `r || s` or DER-encoded.
+        #[prost(bytes = "vec", tag = "2")]
+        pub sig: ::prost::alloc::vec::Vec<u8>,
+    }
+    impl ::prost::Name for EcdsaP256 {
+        const NAME: &'static str = "EcdsaP256";
+        const PACKAGE: &'static str = "penumbra.custody.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.v1.PreAuthorization.{}", Self::NAME
+            )
+        }
+    }
+    /// An ECDSA-based preauthorization over the secp256k1 curve, containing an
+    /// ECDSA/SHA-256 signature over the `TransactionPlan`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct EcdsaSecp256k1 {
+        /// The SEC1-encoded secp256k1 verification key used to verify the signature.
+        #[prost(bytes = "vec", tag = "1")]
+        pub vk: ::prost::alloc::vec::Vec<u8>,
+        /// The ECDSA signature over the `TransactionPlan`, either as 64 bytes of
+        /// fixed-width `r || s` or DER-encoded.
+        #[prost(bytes = "vec", tag = "2")]
+        pub sig: ::prost::alloc::vec::Vec<u8>,
+    }
+    impl ::prost::Name for EcdsaSecp256k1 {
+        const NAME: &'static str = "EcdsaSecp256k1";
+        const PACKAGE: &'static str = "penumbra.custody.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.custody.v1.PreAuthorization.{}", Self::NAME
+            )
+        }
+    }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum PreAuthorization {
         #[prost(message, tag = "1")]
         Ed25519(Ed25519),
+        #[prost(message, tag = "2")]
+        EcdsaP256(EcdsaP256),
+        #[prost(message, tag = "3")]
+        EcdsaSecp256k1(EcdsaSecp256k1),
     }
 }
 impl ::prost::Name for PreAuthorization {
@@ -1002,6 +1002,12 @@ impl serde::Serialize for PreAuthorization {
                 pre_authorization::PreAuthorization::Ed25519(v) => {
                     struct_ser.serialize_field("ed25519", v)?;
                 }
+                pre_authorization::PreAuthorization::EcdsaP256(v) => {
+                    struct_ser.serialize_field("ecdsaP256", v)?;
+                }
+                pre_authorization::PreAuthorization::EcdsaSecp256k1(v) => {
+                    struct_ser.serialize_field("ecdsaSecp256k1", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -1015,11 +1021,17 @@ impl<'de> serde::Deserialize<'de> for PreAuthorization {
     {
         const FIELDS: &[&str] = &[
             "ed25519",
+            "ecdsa_p256",
+            "ecdsaP256",
+            "ecdsa_secp256k1",
+            "ecdsaSecp256k1",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Ed25519,
+            EcdsaP256,
+            EcdsaSecp256k1,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1043,6 +1055,8 @@ impl<'de> serde::Deserialize<'de> for PreAuthorization {
                     {
                         match value {
                             "ed25519" => Ok(GeneratedField::Ed25519),
+                            "ecdsaP256" | "ecdsa_p256" => Ok(GeneratedField::EcdsaP256),
+                            "ecdsaSecp256k1" | "ecdsa_secp256k1" => Ok(GeneratedField::EcdsaSecp256k1),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1070,6 +1084,20 @@ impl<'de> serde::Deserialize<'de> for PreAuthorization {
                                 return Err(serde::de::Error::duplicate_field("ed25519"));
                             }
                             pre_authorization__ = map_.next_value::<::std::option::Option<_>>()?.map(pre_authorization::PreAuthorization::Ed25519)
+;
+                        }
+                        GeneratedField::EcdsaP256 => {
+                            if pre_authorization__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ecdsaP256"));
+                            }
+                            pre_authorization__ = map_.next_value::<::std::option::Option<_>>()?.map(pre_authorization::PreAuthorization::EcdsaP256)
+;
+                        }
+                        GeneratedField::EcdsaSecp256k1 => {
+                            if pre_authorization__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ecdsaSecp256k1"));
+                            }
+                            pre_authorization__ = map_.next_value::<::std::option::Option<_>>()?.map(pre_authorization::PreAuthorization::EcdsaSecp256k1)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -1085,6 +1113,242 @@ impl<'de> serde::Deserialize<'de> for PreAuthorization {
         deserializer.deserialize_struct("penumbra.custody.v1.PreAuthorization", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for pre_authorization::EcdsaP256 {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.vk.is_empty() {
+            len += 1;
+        }
+        if !self.sig.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.PreAuthorization.EcdsaP256", len)?;
+        if !self.vk.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("vk", pbjson::private::base64::encode(&self.vk).as_str())?;
+        }
+        if !self.sig.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sig", pbjson::private::base64::encode(&self.sig).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for pre_authorization::EcdsaP256 {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vk",
+            "sig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Vk,
+            Sig,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vk" => Ok(GeneratedField::Vk),
+                            "sig" => Ok(GeneratedField::Sig),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = pre_authorization::EcdsaP256;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.PreAuthorization.EcdsaP256")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<pre_authorization::EcdsaP256, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vk__ = None;
+                let mut sig__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Vk => {
+                            if vk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vk"));
+                            }
+                            vk__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sig => {
+                            if sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sig"));
+                            }
+                            sig__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(pre_authorization::EcdsaP256 {
+                    vk: vk__.unwrap_or_default(),
+                    sig: sig__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.PreAuthorization.EcdsaP256", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for pre_authorization::EcdsaSecp256k1 {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.vk.is_empty() {
+            len += 1;
+        }
+        if !self.sig.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.custody.v1.PreAuthorization.EcdsaSecp256k1", len)?;
+        if !self.vk.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("vk", pbjson::private::base64::encode(&self.vk).as_str())?;
+        }
+        if !self.sig.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("sig", pbjson::private::base64::encode(&self.sig).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for pre_authorization::EcdsaSecp256k1 {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vk",
+            "sig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Vk,
+            Sig,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vk" => Ok(GeneratedField::Vk),
+                            "sig" => Ok(GeneratedField::Sig),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = pre_authorization::EcdsaSecp256k1;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.custody.v1.PreAuthorization.EcdsaSecp256k1")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<pre_authorization::EcdsaSecp256k1, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vk__ = None;
+                let mut sig__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Vk => {
+                            if vk__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vk"));
+                            }
+                            vk__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sig => {
+                            if sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sig"));
+                            }
+                            sig__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(pre_authorization::EcdsaSecp256k1 {
+                    vk: vk__.unwrap_or_default(),
+                    sig: sig__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.custody.v1.PreAuthorization.EcdsaSecp256k1", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for pre_authorization::Ed25519 {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
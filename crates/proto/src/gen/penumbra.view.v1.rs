@@ -1,3 +1,170 @@
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateInvoiceRequest {
+    /// The account to be paid.
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<super::super::core::keys::v1::AddressIndex>,
+    /// The values requested. If empty, the payer chooses what to send.
+    #[prost(message, repeated, tag = "2")]
+    pub values: ::prost::alloc::vec::Vec<super::super::core::asset::v1::Value>,
+    /// The memo text to attach to the payment. If empty, the payment's memo is
+    /// the invoice ID.
+    #[prost(string, tag = "3")]
+    pub memo: ::prost::alloc::string::String,
+    /// The time after which the invoice should no longer be paid, in seconds
+    /// since the UNIX epoch, or 0 if it doesn't expire.
+    #[prost(uint64, tag = "4")]
+    pub expiry: u64,
+    /// The identifier of the invoice, which must be unique in the wallet. If
+    /// empty, a random one is chosen.
+    #[prost(string, tag = "5")]
+    pub invoice_id: ::prost::alloc::string::String,
+    /// If set, the invoice is paid to a one-time address of its own, rather than
+    /// matched by the memo of its payment.
+    ///
+    /// This is refused by a view service which synchronizes through a detection
+    /// service, since payments to one-time addresses wouldn't be detected.
+    #[prost(bool, tag = "6")]
+    pub dedicated_address: bool,
+}
+impl ::prost::Name for CreateInvoiceRequest {
+    const NAME: &'static str = "CreateInvoiceRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateInvoiceResponse {
+    /// The payment request to send to the payer.
+    #[prost(message, optional, tag = "1")]
+    pub payment_request: ::core::option::Option<
+        super::super::core::keys::v1::PaymentRequest,
+    >,
+}
+impl ::prost::Name for CreateInvoiceResponse {
+    const NAME: &'static str = "CreateInvoiceResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InvoicesRequest {
+    /// If set, include the invoices which have been paid in full.
+    #[prost(bool, tag = "1")]
+    pub include_paid: bool,
+}
+impl ::prost::Name for InvoicesRequest {
+    const NAME: &'static str = "InvoicesRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InvoicesResponse {
+    #[prost(message, optional, tag = "1")]
+    pub invoice: ::core::option::Option<Invoice>,
+}
+impl ::prost::Name for InvoicesResponse {
+    const NAME: &'static str = "InvoicesResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// An invoice created by the view service, with the payments matched to it.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Invoice {
+    #[prost(message, optional, tag = "1")]
+    pub payment_request: ::core::option::Option<
+        super::super::core::keys::v1::PaymentRequest,
+    >,
+    /// Whether the invoice is paid to a one-time address of its own.
+    #[prost(bool, tag = "2")]
+    pub dedicated_address: bool,
+    /// The notes received in payment of the invoice.
+    #[prost(message, repeated, tag = "3")]
+    pub payments: ::prost::alloc::vec::Vec<SpendableNoteRecord>,
+}
+impl ::prost::Name for Invoice {
+    const NAME: &'static str = "Invoice";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteInvoiceRequest {
+    #[prost(string, tag = "1")]
+    pub invoice_id: ::prost::alloc::string::String,
+}
+impl ::prost::Name for DeleteInvoiceRequest {
+    const NAME: &'static str = "DeleteInvoiceRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteInvoiceResponse {
+    /// Whether there was an invoice with the given ID.
+    #[prost(bool, tag = "1")]
+    pub deleted: bool,
+}
+impl ::prost::Name for DeleteInvoiceResponse {
+    const NAME: &'static str = "DeleteInvoiceResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FilteredCompactBlockRangeRequest {
+    /// The detection keys of the addresses to detect notes for, each 32 bytes.
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub detection_keys: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// The start height of the range.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+    /// The end height of the range, defaults to the latest block height.
+    #[prost(uint64, tag = "3")]
+    pub end_height: u64,
+    /// If set, keeps the connection alive past `end_height`,
+    /// streaming new compact blocks as they are created.
+    #[prost(bool, tag = "4")]
+    pub keep_alive: bool,
+}
+impl ::prost::Name for FilteredCompactBlockRangeRequest {
+    const NAME: &'static str = "FilteredCompactBlockRangeRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FilteredCompactBlockRangeResponse {
+    #[prost(message, optional, tag = "1")]
+    pub compact_block: ::core::option::Option<
+        super::super::core::component::compact_block::v1::CompactBlock,
+    >,
+}
+impl ::prost::Name for FilteredCompactBlockRangeResponse {
+    const NAME: &'static str = "FilteredCompactBlockRangeResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
 /// Filters in an `AuctionsRequest` will be combined using `AND` logic -- that
 /// is, the more filters you add, the fewer responses you're likely to get.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -304,6 +471,9 @@ pub struct TransactionPlannerRequest {
     /// The epoch of the transaction being planned.
     #[prost(message, optional, tag = "201")]
     pub epoch: ::core::option::Option<super::super::core::component::sct::v1::Epoch>,
+    /// The strategy used to select the notes spent to fund the transaction.
+    #[prost(enumeration = "transaction_planner_request::NoteSelection", tag = "202")]
+    pub note_selection: i32,
     /// Specifies either that the planner should compute fees automatically or that it should use a fixed fee amount.
     #[prost(oneof = "transaction_planner_request::FeeMode", tags = "100, 101")]
     pub fee_mode: ::core::option::Option<transaction_planner_request::FeeMode>,
@@ -635,6 +805,62 @@ pub mod transaction_planner_request {
             )
         }
     }
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum NoteSelection {
+        /// Spend notes sent to one-time addresses first, then the largest notes.
+        Unspecified = 0,
+        /// Spend as few notes as possible, by spending the largest notes first.
+        FewestSpends = 1,
+        /// Spend up to 16 dust notes first, smallest first, consolidating them into
+        /// change, and then the largest notes. A note is dust if it's worth less than
+        /// a tenth of the amount being spent.
+        ConsolidateDust = 2,
+        /// Spend notes sent to the same address index where possible, so that the
+        /// transaction doesn't link the wallet's addresses.
+        SingleAddress = 3,
+        /// Spend the oldest notes first, since they have the most notes created after
+        /// them to hide among.
+        LargestAnonymitySet = 4,
+    }
+    impl NoteSelection {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                NoteSelection::Unspecified => "NOTE_SELECTION_UNSPECIFIED",
+                NoteSelection::FewestSpends => "NOTE_SELECTION_FEWEST_SPENDS",
+                NoteSelection::ConsolidateDust => "NOTE_SELECTION_CONSOLIDATE_DUST",
+                NoteSelection::SingleAddress => "NOTE_SELECTION_SINGLE_ADDRESS",
+                NoteSelection::LargestAnonymitySet => {
+                    "NOTE_SELECTION_LARGEST_ANONYMITY_SET"
+                }
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "NOTE_SELECTION_UNSPECIFIED" => Some(Self::Unspecified),
+                "NOTE_SELECTION_FEWEST_SPENDS" => Some(Self::FewestSpends),
+                "NOTE_SELECTION_CONSOLIDATE_DUST" => Some(Self::ConsolidateDust),
+                "NOTE_SELECTION_SINGLE_ADDRESS" => Some(Self::SingleAddress),
+                "NOTE_SELECTION_LARGEST_ANONYMITY_SET" => Some(Self::LargestAnonymitySet),
+                _ => None,
+            }
+        }
+    }
     /// Specifies either that the planner should compute fees automatically or that it should use a fixed fee amount.
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
@@ -890,223 +1116,218 @@ impl ::prost::Name for StatusStreamResponse {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// A query for notes known by the view service.
-///
-/// This message uses the fact that all proto fields are optional
-/// to allow various filtering on the returned notes.
+/// Requests a stream of the changes made to the wallet by newly scanned blocks.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NotesRequest {
-    /// If set, return spent notes as well as unspent notes.
-    #[prost(bool, tag = "2")]
-    pub include_spent: bool,
-    /// If set, only return notes with the specified asset id.
-    #[prost(message, optional, tag = "3")]
-    pub asset_id: ::core::option::Option<super::super::core::asset::v1::AssetId>,
-    /// If set, only return notes with the specified address incore.component.dex.v1.
-    #[prost(message, optional, tag = "4")]
-    pub address_index: ::core::option::Option<
-        super::super::core::keys::v1::AddressIndex,
-    >,
-    /// If set, stop returning notes once the total exceeds this amount.
-    ///
-    /// Ignored if `asset_id` is unset or if `include_spent` is set.
-    #[prost(message, optional, tag = "6")]
-    pub amount_to_spend: ::core::option::Option<super::super::core::num::v1::Amount>,
-}
-impl ::prost::Name for NotesRequest {
-    const NAME: &'static str = "NotesRequest";
+pub struct WalletEventsRequest {}
+impl ::prost::Name for WalletEventsRequest {
+    const NAME: &'static str = "WalletEventsRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// A query for notes to be used for voting on a proposal.
+/// A change made to the wallet by scanning a block.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NotesForVotingRequest {
-    /// The starting height of the proposal.
+pub struct WalletEventsResponse {
+    /// The height of the block whose scan made the change.
     #[prost(uint64, tag = "1")]
-    pub votable_at_height: u64,
-    /// If set, only return notes with the specified asset id.
-    #[prost(message, optional, tag = "3")]
-    pub address_index: ::core::option::Option<
-        super::super::core::keys::v1::AddressIndex,
-    >,
+    pub height: u64,
+    #[prost(oneof = "wallet_events_response::Event", tags = "2, 3, 4, 5, 6, 7")]
+    pub event: ::core::option::Option<wallet_events_response::Event>,
 }
-impl ::prost::Name for NotesForVotingRequest {
-    const NAME: &'static str = "NotesForVotingRequest";
-    const PACKAGE: &'static str = "penumbra.view.v1";
-    fn full_name() -> ::prost::alloc::string::String {
-        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+/// Nested message and enum types in `WalletEventsResponse`.
+pub mod wallet_events_response {
+    /// A new note was sent to the wallet.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct NoteDetected {
+        #[prost(message, optional, tag = "1")]
+        pub note_record: ::core::option::Option<super::SpendableNoteRecord>,
     }
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct WitnessRequest {
-    /// The transaction plan to witness
-    #[prost(message, optional, tag = "3")]
-    pub transaction_plan: ::core::option::Option<
-        super::super::core::transaction::v1::TransactionPlan,
-    >,
-}
-impl ::prost::Name for WitnessRequest {
-    const NAME: &'static str = "WitnessRequest";
-    const PACKAGE: &'static str = "penumbra.view.v1";
-    fn full_name() -> ::prost::alloc::string::String {
-        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    impl ::prost::Name for NoteDetected {
+        const NAME: &'static str = "NoteDetected";
+        const PACKAGE: &'static str = "penumbra.view.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.view.v1.WalletEventsResponse.{}", Self::NAME
+            )
+        }
     }
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct WitnessResponse {
-    #[prost(message, optional, tag = "1")]
-    pub witness_data: ::core::option::Option<
-        super::super::core::transaction::v1::WitnessData,
-    >,
-}
-impl ::prost::Name for WitnessResponse {
-    const NAME: &'static str = "WitnessResponse";
-    const PACKAGE: &'static str = "penumbra.view.v1";
-    fn full_name() -> ::prost::alloc::string::String {
-        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    /// One of the wallet's notes was spent.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct NoteSpent {
+        /// The nullifier revealed by the spend.
+        #[prost(message, optional, tag = "1")]
+        pub nullifier: ::core::option::Option<
+            super::super::super::core::component::sct::v1::Nullifier,
+        >,
+        /// The spent note, if it was known to the view service.
+        #[prost(message, optional, tag = "2")]
+        pub note_record: ::core::option::Option<super::SpendableNoteRecord>,
     }
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct WitnessAndBuildRequest {
-    #[prost(message, optional, tag = "1")]
-    pub transaction_plan: ::core::option::Option<
-        super::super::core::transaction::v1::TransactionPlan,
-    >,
-    #[prost(message, optional, tag = "2")]
-    pub authorization_data: ::core::option::Option<
-        super::super::core::transaction::v1::AuthorizationData,
-    >,
-}
-impl ::prost::Name for WitnessAndBuildRequest {
-    const NAME: &'static str = "WitnessAndBuildRequest";
-    const PACKAGE: &'static str = "penumbra.view.v1";
-    fn full_name() -> ::prost::alloc::string::String {
-        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    impl ::prost::Name for NoteSpent {
+        const NAME: &'static str = "NoteSpent";
+        const PACKAGE: &'static str = "penumbra.view.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.view.v1.WalletEventsResponse.{}", Self::NAME
+            )
+        }
     }
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct WitnessAndBuildResponse {
-    #[prost(oneof = "witness_and_build_response::Status", tags = "1, 2")]
-    pub status: ::core::option::Option<witness_and_build_response::Status>,
-}
-/// Nested message and enum types in `WitnessAndBuildResponse`.
-pub mod witness_and_build_response {
-    /// Signals that building is in progress.
+    /// A new swap was made by the wallet.
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
-    pub struct BuildProgress {
-        /// An approximate progress of the build, from 0 to 1.
-        #[prost(float, tag = "1")]
-        pub progress: f32,
+    pub struct SwapDetected {
+        #[prost(message, optional, tag = "1")]
+        pub swap_record: ::core::option::Option<super::SwapRecord>,
     }
-    impl ::prost::Name for BuildProgress {
-        const NAME: &'static str = "BuildProgress";
+    impl ::prost::Name for SwapDetected {
+        const NAME: &'static str = "SwapDetected";
         const PACKAGE: &'static str = "penumbra.view.v1";
         fn full_name() -> ::prost::alloc::string::String {
             ::prost::alloc::format!(
-                "penumbra.view.v1.WitnessAndBuildResponse.{}", Self::NAME
+                "penumbra.view.v1.WalletEventsResponse.{}", Self::NAME
             )
         }
     }
-    /// Signals that the transaction is complete.
+    /// A transaction relevant to the wallet was recorded.
+    ///
+    /// Its full details can be fetched with `TransactionInfoByHash`.
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
-    pub struct Complete {
-        /// The finished transaction.
+    pub struct TransactionRecorded {
         #[prost(message, optional, tag = "1")]
+        pub id: ::core::option::Option<
+            super::super::super::core::txhash::v1::TransactionId,
+        >,
+        #[prost(message, optional, tag = "2")]
         pub transaction: ::core::option::Option<
             super::super::super::core::transaction::v1::Transaction,
         >,
     }
-    impl ::prost::Name for Complete {
-        const NAME: &'static str = "Complete";
+    impl ::prost::Name for TransactionRecorded {
+        const NAME: &'static str = "TransactionRecorded";
         const PACKAGE: &'static str = "penumbra.view.v1";
         fn full_name() -> ::prost::alloc::string::String {
             ::prost::alloc::format!(
-                "penumbra.view.v1.WitnessAndBuildResponse.{}", Self::NAME
+                "penumbra.view.v1.WalletEventsResponse.{}", Self::NAME
+            )
+        }
+    }
+    /// One of the wallet's liquidity positions changed state.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PositionUpdated {
+        #[prost(message, optional, tag = "1")]
+        pub position_id: ::core::option::Option<
+            super::super::super::core::component::dex::v1::PositionId,
+        >,
+        #[prost(message, optional, tag = "2")]
+        pub state: ::core::option::Option<
+            super::super::super::core::component::dex::v1::PositionState,
+        >,
+    }
+    impl ::prost::Name for PositionUpdated {
+        const NAME: &'static str = "PositionUpdated";
+        const PACKAGE: &'static str = "penumbra.view.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.view.v1.WalletEventsResponse.{}", Self::NAME
+            )
+        }
+    }
+    /// One of the wallet's auctions changed state.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct AuctionUpdated {
+        #[prost(message, optional, tag = "1")]
+        pub auction_id: ::core::option::Option<
+            super::super::super::core::component::auction::v1::AuctionId,
+        >,
+        /// The sequence number of the auction state, as in `AuctionsResponse`.
+        #[prost(uint64, tag = "2")]
+        pub seq: u64,
+    }
+    impl ::prost::Name for AuctionUpdated {
+        const NAME: &'static str = "AuctionUpdated";
+        const PACKAGE: &'static str = "penumbra.view.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.view.v1.WalletEventsResponse.{}", Self::NAME
             )
         }
     }
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Status {
-        #[prost(message, tag = "1")]
-        BuildProgress(BuildProgress),
+    pub enum Event {
         #[prost(message, tag = "2")]
-        Complete(Complete),
+        NoteDetected(NoteDetected),
+        #[prost(message, tag = "3")]
+        NoteSpent(NoteSpent),
+        #[prost(message, tag = "4")]
+        SwapDetected(SwapDetected),
+        #[prost(message, tag = "5")]
+        TransactionRecorded(TransactionRecorded),
+        #[prost(message, tag = "6")]
+        PositionUpdated(PositionUpdated),
+        #[prost(message, tag = "7")]
+        AuctionUpdated(AuctionUpdated),
     }
 }
-impl ::prost::Name for WitnessAndBuildResponse {
-    const NAME: &'static str = "WitnessAndBuildResponse";
+impl ::prost::Name for WalletEventsResponse {
+    const NAME: &'static str = "WalletEventsResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Requests all assets known to the view service.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AssetsRequest {
-    /// If set to false (default), returns all assets, regardless of whether the rest of the fields of
-    /// the request indicate a filter.
-    #[prost(bool, tag = "1")]
-    pub filtered: bool,
-    /// Include these specific denominations in the response.
-    #[prost(message, repeated, tag = "2")]
-    pub include_specific_denominations: ::prost::alloc::vec::Vec<
-        super::super::core::asset::v1::Denom,
-    >,
-    /// Include all delegation tokens, to any validator, in the response.
-    #[prost(bool, tag = "3")]
-    pub include_delegation_tokens: bool,
-    /// Include all unbonding tokens, from any validator, in the response.
-    #[prost(bool, tag = "4")]
-    pub include_unbonding_tokens: bool,
-    /// Include all LP NFTs in the response.
-    #[prost(bool, tag = "5")]
-    pub include_lp_nfts: bool,
-    /// Include all proposal NFTs in the response.
-    #[prost(bool, tag = "6")]
-    pub include_proposal_nfts: bool,
-    /// Include all voting receipt tokens in the response.
-    #[prost(bool, tag = "7")]
-    pub include_voting_receipt_tokens: bool,
+pub struct BatchSwapOutputsRequest {
+    /// If present, return only batch swaps at or after this height.
+    #[prost(uint64, tag = "1")]
+    pub start_height: u64,
+    /// If present, return only batch swaps at or before this height.
+    #[prost(uint64, tag = "2")]
+    pub end_height: u64,
 }
-impl ::prost::Name for AssetsRequest {
-    const NAME: &'static str = "AssetsRequest";
+impl ::prost::Name for BatchSwapOutputsRequest {
+    const NAME: &'static str = "BatchSwapOutputsRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Requests all assets known to the view service.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AssetsResponse {
-    #[prost(message, optional, tag = "2")]
-    pub denom_metadata: ::core::option::Option<super::super::core::asset::v1::Metadata>,
+pub struct BatchSwapOutputsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub data: ::core::option::Option<
+        super::super::core::component::dex::v1::BatchSwapOutputData,
+    >,
 }
-impl ::prost::Name for AssetsResponse {
-    const NAME: &'static str = "AssetsResponse";
+impl ::prost::Name for BatchSwapOutputsResponse {
+    const NAME: &'static str = "BatchSwapOutputsResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Requests the current app parameters from the view service.
+/// An address in the user's address book, with the label they gave it.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AppParametersRequest {}
-impl ::prost::Name for AppParametersRequest {
-    const NAME: &'static str = "AppParametersRequest";
+pub struct Contact {
+    #[prost(message, optional, tag = "1")]
+    pub address: ::core::option::Option<super::super::core::keys::v1::Address>,
+    /// The label of the contact, which must not be empty.
+    #[prost(string, tag = "2")]
+    pub label: ::prost::alloc::string::String,
+}
+impl ::prost::Name for Contact {
+    const NAME: &'static str = "Contact";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1114,23 +1335,22 @@ impl ::prost::Name for AppParametersRequest {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AppParametersResponse {
-    #[prost(message, optional, tag = "1")]
-    pub parameters: ::core::option::Option<super::super::core::app::v1::AppParameters>,
-}
-impl ::prost::Name for AppParametersResponse {
-    const NAME: &'static str = "AppParametersResponse";
+pub struct ContactsRequest {}
+impl ::prost::Name for ContactsRequest {
+    const NAME: &'static str = "ContactsRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Requests the current gas prices from the view service.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GasPricesRequest {}
-impl ::prost::Name for GasPricesRequest {
-    const NAME: &'static str = "GasPricesRequest";
+pub struct ContactsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub contact: ::core::option::Option<Contact>,
+}
+impl ::prost::Name for ContactsResponse {
+    const NAME: &'static str = "ContactsResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1138,31 +1358,22 @@ impl ::prost::Name for GasPricesRequest {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GasPricesResponse {
-    /// The current gas prices, in the preferred (native) token.
+pub struct SetContactRequest {
     #[prost(message, optional, tag = "1")]
-    pub gas_prices: ::core::option::Option<
-        super::super::core::component::fee::v1::GasPrices,
-    >,
-    /// Other gas prices for other accepted tokens.
-    #[prost(message, repeated, tag = "2")]
-    pub alt_gas_prices: ::prost::alloc::vec::Vec<
-        super::super::core::component::fee::v1::GasPrices,
-    >,
+    pub contact: ::core::option::Option<Contact>,
 }
-impl ::prost::Name for GasPricesResponse {
-    const NAME: &'static str = "GasPricesResponse";
+impl ::prost::Name for SetContactRequest {
+    const NAME: &'static str = "SetContactRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Requests the current FMD parameters from the view service.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct FmdParametersRequest {}
-impl ::prost::Name for FmdParametersRequest {
-    const NAME: &'static str = "FMDParametersRequest";
+pub struct SetContactResponse {}
+impl ::prost::Name for SetContactResponse {
+    const NAME: &'static str = "SetContactResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1170,14 +1381,12 @@ impl ::prost::Name for FmdParametersRequest {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct FmdParametersResponse {
+pub struct DeleteContactRequest {
     #[prost(message, optional, tag = "1")]
-    pub parameters: ::core::option::Option<
-        super::super::core::component::shielded_pool::v1::FmdParameters,
-    >,
+    pub address: ::core::option::Option<super::super::core::keys::v1::Address>,
 }
-impl ::prost::Name for FmdParametersResponse {
-    const NAME: &'static str = "FMDParametersResponse";
+impl ::prost::Name for DeleteContactRequest {
+    const NAME: &'static str = "DeleteContactRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1185,30 +1394,31 @@ impl ::prost::Name for FmdParametersResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NoteByCommitmentRequest {
-    #[prost(message, optional, tag = "2")]
-    pub note_commitment: ::core::option::Option<
-        super::super::crypto::tct::v1::StateCommitment,
-    >,
-    /// If set to true, waits to return until the requested note is detected.
-    #[prost(bool, tag = "3")]
-    pub await_detection: bool,
+pub struct DeleteContactResponse {
+    /// Whether the address was in the address book.
+    #[prost(bool, tag = "1")]
+    pub deleted: bool,
 }
-impl ::prost::Name for NoteByCommitmentRequest {
-    const NAME: &'static str = "NoteByCommitmentRequest";
+impl ::prost::Name for DeleteContactResponse {
+    const NAME: &'static str = "DeleteContactResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
+/// A label the user gave to one of the accounts of their wallet.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NoteByCommitmentResponse {
-    #[prost(message, optional, tag = "1")]
-    pub spendable_note: ::core::option::Option<SpendableNoteRecord>,
+pub struct AccountLabel {
+    /// The account number, as in an `AddressIndex`.
+    #[prost(uint32, tag = "1")]
+    pub account: u32,
+    /// The label of the account, which must not be empty.
+    #[prost(string, tag = "2")]
+    pub label: ::prost::alloc::string::String,
 }
-impl ::prost::Name for NoteByCommitmentResponse {
-    const NAME: &'static str = "NoteByCommitmentResponse";
+impl ::prost::Name for AccountLabel {
+    const NAME: &'static str = "AccountLabel";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1216,17 +1426,9 @@ impl ::prost::Name for NoteByCommitmentResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SwapByCommitmentRequest {
-    #[prost(message, optional, tag = "2")]
-    pub swap_commitment: ::core::option::Option<
-        super::super::crypto::tct::v1::StateCommitment,
-    >,
-    /// If set to true, waits to return until the requested swap is detected.
-    #[prost(bool, tag = "3")]
-    pub await_detection: bool,
-}
-impl ::prost::Name for SwapByCommitmentRequest {
-    const NAME: &'static str = "SwapByCommitmentRequest";
+pub struct AccountLabelsRequest {}
+impl ::prost::Name for AccountLabelsRequest {
+    const NAME: &'static str = "AccountLabelsRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1234,12 +1436,12 @@ impl ::prost::Name for SwapByCommitmentRequest {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SwapByCommitmentResponse {
+pub struct AccountLabelsResponse {
     #[prost(message, optional, tag = "1")]
-    pub swap: ::core::option::Option<SwapRecord>,
+    pub account_label: ::core::option::Option<AccountLabel>,
 }
-impl ::prost::Name for SwapByCommitmentResponse {
-    const NAME: &'static str = "SwapByCommitmentResponse";
+impl ::prost::Name for AccountLabelsResponse {
+    const NAME: &'static str = "AccountLabelsResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1247,9 +1449,12 @@ impl ::prost::Name for SwapByCommitmentResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UnclaimedSwapsRequest {}
-impl ::prost::Name for UnclaimedSwapsRequest {
-    const NAME: &'static str = "UnclaimedSwapsRequest";
+pub struct SetAccountLabelRequest {
+    #[prost(message, optional, tag = "1")]
+    pub account_label: ::core::option::Option<AccountLabel>,
+}
+impl ::prost::Name for SetAccountLabelRequest {
+    const NAME: &'static str = "SetAccountLabelRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1257,12 +1462,9 @@ impl ::prost::Name for UnclaimedSwapsRequest {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UnclaimedSwapsResponse {
-    #[prost(message, optional, tag = "1")]
-    pub swap: ::core::option::Option<SwapRecord>,
-}
-impl ::prost::Name for UnclaimedSwapsResponse {
-    const NAME: &'static str = "UnclaimedSwapsResponse";
+pub struct SetAccountLabelResponse {}
+impl ::prost::Name for SetAccountLabelResponse {
+    const NAME: &'static str = "SetAccountLabelResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1270,16 +1472,12 @@ impl ::prost::Name for UnclaimedSwapsResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NullifierStatusRequest {
-    #[prost(message, optional, tag = "2")]
-    pub nullifier: ::core::option::Option<
-        super::super::core::component::sct::v1::Nullifier,
-    >,
-    #[prost(bool, tag = "3")]
-    pub await_detection: bool,
+pub struct DeleteAccountLabelRequest {
+    #[prost(uint32, tag = "1")]
+    pub account: u32,
 }
-impl ::prost::Name for NullifierStatusRequest {
-    const NAME: &'static str = "NullifierStatusRequest";
+impl ::prost::Name for DeleteAccountLabelRequest {
+    const NAME: &'static str = "DeleteAccountLabelRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1287,43 +1485,64 @@ impl ::prost::Name for NullifierStatusRequest {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NullifierStatusResponse {
+pub struct DeleteAccountLabelResponse {
+    /// Whether the account had a label.
     #[prost(bool, tag = "1")]
-    pub spent: bool,
+    pub deleted: bool,
 }
-impl ::prost::Name for NullifierStatusResponse {
-    const NAME: &'static str = "NullifierStatusResponse";
+impl ::prost::Name for DeleteAccountLabelResponse {
+    const NAME: &'static str = "DeleteAccountLabelResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
+/// A query for notes known by the view service.
+///
+/// This message uses the fact that all proto fields are optional
+/// to allow various filtering on the returned notes.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TransactionInfoByHashRequest {
-    /// The transaction hash to query for.
-    #[prost(message, optional, tag = "2")]
-    pub id: ::core::option::Option<super::super::core::txhash::v1::TransactionId>,
+pub struct NotesRequest {
+    /// If set, return spent notes as well as unspent notes.
+    #[prost(bool, tag = "2")]
+    pub include_spent: bool,
+    /// If set, only return notes with the specified asset id.
+    #[prost(message, optional, tag = "3")]
+    pub asset_id: ::core::option::Option<super::super::core::asset::v1::AssetId>,
+    /// If set, only return notes with the specified address incore.component.dex.v1.
+    #[prost(message, optional, tag = "4")]
+    pub address_index: ::core::option::Option<
+        super::super::core::keys::v1::AddressIndex,
+    >,
+    /// If set, stop returning notes once the total exceeds this amount.
+    ///
+    /// Ignored if `asset_id` is unset or if `include_spent` is set.
+    #[prost(message, optional, tag = "6")]
+    pub amount_to_spend: ::core::option::Option<super::super::core::num::v1::Amount>,
 }
-impl ::prost::Name for TransactionInfoByHashRequest {
-    const NAME: &'static str = "TransactionInfoByHashRequest";
+impl ::prost::Name for NotesRequest {
+    const NAME: &'static str = "NotesRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
+/// A query for notes to be used for voting on a proposal.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TransactionInfoRequest {
-    /// If present, return only transactions after this height.
+pub struct NotesForVotingRequest {
+    /// The starting height of the proposal.
     #[prost(uint64, tag = "1")]
-    pub start_height: u64,
-    /// If present, return only transactions before this height.
-    #[prost(uint64, tag = "2")]
-    pub end_height: u64,
+    pub votable_at_height: u64,
+    /// If set, only return notes with the specified asset id.
+    #[prost(message, optional, tag = "3")]
+    pub address_index: ::core::option::Option<
+        super::super::core::keys::v1::AddressIndex,
+    >,
 }
-impl ::prost::Name for TransactionInfoRequest {
-    const NAME: &'static str = "TransactionInfoRequest";
+impl ::prost::Name for NotesForVotingRequest {
+    const NAME: &'static str = "NotesForVotingRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1331,31 +1550,15 @@ impl ::prost::Name for TransactionInfoRequest {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TransactionInfo {
-    /// The height the transaction was included in a block, if known.
-    #[prost(uint64, tag = "1")]
-    pub height: u64,
-    /// The hash of the transaction.
-    #[prost(message, optional, tag = "2")]
-    pub id: ::core::option::Option<super::super::core::txhash::v1::TransactionId>,
-    /// The transaction data itself.
+pub struct WitnessRequest {
+    /// The transaction plan to witness
     #[prost(message, optional, tag = "3")]
-    pub transaction: ::core::option::Option<
-        super::super::core::transaction::v1::Transaction,
-    >,
-    /// The transaction perspective, as seen by this view server.
-    #[prost(message, optional, tag = "4")]
-    pub perspective: ::core::option::Option<
-        super::super::core::transaction::v1::TransactionPerspective,
-    >,
-    /// A precomputed transaction view of `transaction` from `perspective`, included for convenience of clients that don't have support for viewing transactions on their own.
-    #[prost(message, optional, tag = "5")]
-    pub view: ::core::option::Option<
-        super::super::core::transaction::v1::TransactionView,
+    pub transaction_plan: ::core::option::Option<
+        super::super::core::transaction::v1::TransactionPlan,
     >,
 }
-impl ::prost::Name for TransactionInfo {
-    const NAME: &'static str = "TransactionInfo";
+impl ::prost::Name for WitnessRequest {
+    const NAME: &'static str = "WitnessRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1363,12 +1566,14 @@ impl ::prost::Name for TransactionInfo {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TransactionInfoResponse {
+pub struct WitnessResponse {
     #[prost(message, optional, tag = "1")]
-    pub tx_info: ::core::option::Option<TransactionInfo>,
+    pub witness_data: ::core::option::Option<
+        super::super::core::transaction::v1::WitnessData,
+    >,
 }
-impl ::prost::Name for TransactionInfoResponse {
-    const NAME: &'static str = "TransactionInfoResponse";
+impl ::prost::Name for WitnessResponse {
+    const NAME: &'static str = "WitnessResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1376,12 +1581,18 @@ impl ::prost::Name for TransactionInfoResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TransactionInfoByHashResponse {
+pub struct WitnessAndBuildRequest {
     #[prost(message, optional, tag = "1")]
-    pub tx_info: ::core::option::Option<TransactionInfo>,
+    pub transaction_plan: ::core::option::Option<
+        super::super::core::transaction::v1::TransactionPlan,
+    >,
+    #[prost(message, optional, tag = "2")]
+    pub authorization_data: ::core::option::Option<
+        super::super::core::transaction::v1::AuthorizationData,
+    >,
 }
-impl ::prost::Name for TransactionInfoByHashResponse {
-    const NAME: &'static str = "TransactionInfoByHashResponse";
+impl ::prost::Name for WitnessAndBuildRequest {
+    const NAME: &'static str = "WitnessAndBuildRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1389,113 +1600,120 @@ impl ::prost::Name for TransactionInfoByHashResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NotesResponse {
-    #[prost(message, optional, tag = "1")]
-    pub note_record: ::core::option::Option<SpendableNoteRecord>,
+pub struct WitnessAndBuildResponse {
+    #[prost(oneof = "witness_and_build_response::Status", tags = "1, 2")]
+    pub status: ::core::option::Option<witness_and_build_response::Status>,
 }
-impl ::prost::Name for NotesResponse {
-    const NAME: &'static str = "NotesResponse";
+/// Nested message and enum types in `WitnessAndBuildResponse`.
+pub mod witness_and_build_response {
+    /// Signals that building is in progress.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct BuildProgress {
+        /// An approximate progress of the build, from 0 to 1.
+        #[prost(float, tag = "1")]
+        pub progress: f32,
+    }
+    impl ::prost::Name for BuildProgress {
+        const NAME: &'static str = "BuildProgress";
+        const PACKAGE: &'static str = "penumbra.view.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.view.v1.WitnessAndBuildResponse.{}", Self::NAME
+            )
+        }
+    }
+    /// Signals that the transaction is complete.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Complete {
+        /// The finished transaction.
+        #[prost(message, optional, tag = "1")]
+        pub transaction: ::core::option::Option<
+            super::super::super::core::transaction::v1::Transaction,
+        >,
+    }
+    impl ::prost::Name for Complete {
+        const NAME: &'static str = "Complete";
+        const PACKAGE: &'static str = "penumbra.view.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            ::prost::alloc::format!(
+                "penumbra.view.v1.WitnessAndBuildResponse.{}", Self::NAME
+            )
+        }
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Status {
+        #[prost(message, tag = "1")]
+        BuildProgress(BuildProgress),
+        #[prost(message, tag = "2")]
+        Complete(Complete),
+    }
+}
+impl ::prost::Name for WitnessAndBuildResponse {
+    const NAME: &'static str = "WitnessAndBuildResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
+/// Requests all assets known to the view service.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct NotesForVotingResponse {
-    #[prost(message, optional, tag = "1")]
-    pub note_record: ::core::option::Option<SpendableNoteRecord>,
-    #[prost(message, optional, tag = "2")]
-    pub identity_key: ::core::option::Option<super::super::core::keys::v1::IdentityKey>,
+pub struct AssetsRequest {
+    /// If set to false (default), returns all assets, regardless of whether the rest of the fields of
+    /// the request indicate a filter.
+    #[prost(bool, tag = "1")]
+    pub filtered: bool,
+    /// Include these specific denominations in the response.
+    #[prost(message, repeated, tag = "2")]
+    pub include_specific_denominations: ::prost::alloc::vec::Vec<
+        super::super::core::asset::v1::Denom,
+    >,
+    /// Include all delegation tokens, to any validator, in the response.
+    #[prost(bool, tag = "3")]
+    pub include_delegation_tokens: bool,
+    /// Include all unbonding tokens, from any validator, in the response.
+    #[prost(bool, tag = "4")]
+    pub include_unbonding_tokens: bool,
+    /// Include all LP NFTs in the response.
+    #[prost(bool, tag = "5")]
+    pub include_lp_nfts: bool,
+    /// Include all proposal NFTs in the response.
+    #[prost(bool, tag = "6")]
+    pub include_proposal_nfts: bool,
+    /// Include all voting receipt tokens in the response.
+    #[prost(bool, tag = "7")]
+    pub include_voting_receipt_tokens: bool,
 }
-impl ::prost::Name for NotesForVotingResponse {
-    const NAME: &'static str = "NotesForVotingResponse";
+impl ::prost::Name for AssetsRequest {
+    const NAME: &'static str = "AssetsRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// A note plaintext with associated metadata about its status.
+/// Requests all assets known to the view service.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SpendableNoteRecord {
-    /// The note commitment, identifying the note.
-    #[prost(message, optional, tag = "1")]
-    pub note_commitment: ::core::option::Option<
-        super::super::crypto::tct::v1::StateCommitment,
-    >,
-    /// The note plaintext itself.
+pub struct AssetsResponse {
     #[prost(message, optional, tag = "2")]
-    pub note: ::core::option::Option<
-        super::super::core::component::shielded_pool::v1::Note,
-    >,
-    /// A precomputed decryption of the note's address incore.component.dex.v1.
-    #[prost(message, optional, tag = "3")]
-    pub address_index: ::core::option::Option<
-        super::super::core::keys::v1::AddressIndex,
-    >,
-    /// The note's nullifier.
-    #[prost(message, optional, tag = "4")]
-    pub nullifier: ::core::option::Option<
-        super::super::core::component::sct::v1::Nullifier,
-    >,
-    /// The height at which the note was created.
-    #[prost(uint64, tag = "5")]
-    pub height_created: u64,
-    /// Records whether the note was spent (and if so, at what height).
-    #[prost(uint64, tag = "6")]
-    pub height_spent: u64,
-    /// The note position.
-    #[prost(uint64, tag = "7")]
-    pub position: u64,
-    /// The source of the note
-    #[prost(message, optional, tag = "8")]
-    pub source: ::core::option::Option<
-        super::super::core::component::sct::v1::CommitmentSource,
-    >,
-    /// The sender's return address, if known.
-    #[prost(message, optional, tag = "9")]
-    pub return_address: ::core::option::Option<
-        super::super::core::keys::v1::AddressView,
-    >,
+    pub denom_metadata: ::core::option::Option<super::super::core::asset::v1::Metadata>,
 }
-impl ::prost::Name for SpendableNoteRecord {
-    const NAME: &'static str = "SpendableNoteRecord";
+impl ::prost::Name for AssetsResponse {
+    const NAME: &'static str = "AssetsResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
+/// Requests the current app parameters from the view service.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SwapRecord {
-    #[prost(message, optional, tag = "1")]
-    pub swap_commitment: ::core::option::Option<
-        super::super::crypto::tct::v1::StateCommitment,
-    >,
-    #[prost(message, optional, tag = "2")]
-    pub swap: ::core::option::Option<
-        super::super::core::component::dex::v1::SwapPlaintext,
-    >,
-    #[prost(uint64, tag = "3")]
-    pub position: u64,
-    #[prost(message, optional, tag = "4")]
-    pub nullifier: ::core::option::Option<
-        super::super::core::component::sct::v1::Nullifier,
-    >,
-    #[prost(message, optional, tag = "5")]
-    pub output_data: ::core::option::Option<
-        super::super::core::component::dex::v1::BatchSwapOutputData,
-    >,
-    #[prost(uint64, tag = "6")]
-    pub height_claimed: u64,
-    #[prost(message, optional, tag = "7")]
-    pub source: ::core::option::Option<
-        super::super::core::component::sct::v1::CommitmentSource,
-    >,
-}
-impl ::prost::Name for SwapRecord {
-    const NAME: &'static str = "SwapRecord";
+pub struct AppParametersRequest {}
+impl ::prost::Name for AppParametersRequest {
+    const NAME: &'static str = "AppParametersRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
@@ -1503,294 +1721,683 @@ impl ::prost::Name for SwapRecord {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct OwnedPositionIdsRequest {
-    /// If present, return only positions with this position state.
+pub struct AppParametersResponse {
     #[prost(message, optional, tag = "1")]
-    pub position_state: ::core::option::Option<
-        super::super::core::component::dex::v1::PositionState,
-    >,
-    /// If present, return only positions for this trading pair.
-    #[prost(message, optional, tag = "2")]
-    pub trading_pair: ::core::option::Option<
-        super::super::core::component::dex::v1::TradingPair,
-    >,
+    pub parameters: ::core::option::Option<super::super::core::app::v1::AppParameters>,
 }
-impl ::prost::Name for OwnedPositionIdsRequest {
-    const NAME: &'static str = "OwnedPositionIdsRequest";
+impl ::prost::Name for AppParametersResponse {
+    const NAME: &'static str = "AppParametersResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
+/// Requests the current gas prices from the view service.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct OwnedPositionIdsResponse {
-    #[prost(message, optional, tag = "1")]
-    pub position_id: ::core::option::Option<
-        super::super::core::component::dex::v1::PositionId,
-    >,
-}
-impl ::prost::Name for OwnedPositionIdsResponse {
-    const NAME: &'static str = "OwnedPositionIdsResponse";
+pub struct GasPricesRequest {}
+impl ::prost::Name for GasPricesRequest {
+    const NAME: &'static str = "GasPricesRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Requests information on an asset by asset id
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AssetMetadataByIdRequest {
-    /// The asset id to request information on.
-    #[prost(message, optional, tag = "2")]
-    pub asset_id: ::core::option::Option<super::super::core::asset::v1::AssetId>,
+pub struct GasPricesResponse {
+    /// The current gas prices, in the preferred (native) token.
+    #[prost(message, optional, tag = "1")]
+    pub gas_prices: ::core::option::Option<
+        super::super::core::component::fee::v1::GasPrices,
+    >,
+    /// Other gas prices for other accepted tokens.
+    #[prost(message, repeated, tag = "2")]
+    pub alt_gas_prices: ::prost::alloc::vec::Vec<
+        super::super::core::component::fee::v1::GasPrices,
+    >,
 }
-impl ::prost::Name for AssetMetadataByIdRequest {
-    const NAME: &'static str = "AssetMetadataByIdRequest";
+impl ::prost::Name for GasPricesResponse {
+    const NAME: &'static str = "GasPricesResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
+/// Requests the current FMD parameters from the view service.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AssetMetadataByIdResponse {
-    /// If present, information on the requested asset.
-    ///
-    /// If the requested asset was unknown, this field will not be present.
-    #[prost(message, optional, tag = "1")]
-    pub denom_metadata: ::core::option::Option<super::super::core::asset::v1::Metadata>,
-}
-impl ::prost::Name for AssetMetadataByIdResponse {
-    const NAME: &'static str = "AssetMetadataByIdResponse";
+pub struct FmdParametersRequest {}
+impl ::prost::Name for FmdParametersRequest {
+    const NAME: &'static str = "FMDParametersRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Requests `ValueView`s of delegation tokens for the given address index. The
-/// returned `ValueView`s will include the `ValidatorInfo` for the delegated
-/// validator in their `extended_metadata` fields.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DelegationsByAddressIndexRequest {
-    /// The address index to fetch delegation balances for.
+pub struct FmdParametersResponse {
     #[prost(message, optional, tag = "1")]
-    pub address_index: ::core::option::Option<
-        super::super::core::keys::v1::AddressIndex,
+    pub parameters: ::core::option::Option<
+        super::super::core::component::shielded_pool::v1::FmdParameters,
     >,
-    #[prost(enumeration = "delegations_by_address_index_request::Filter", tag = "2")]
-    pub filter: i32,
 }
-/// Nested message and enum types in `DelegationsByAddressIndexRequest`.
-pub mod delegations_by_address_index_request {
-    #[derive(
-        Clone,
-        Copy,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-        PartialOrd,
-        Ord,
-        ::prost::Enumeration
-    )]
-    #[repr(i32)]
-    pub enum Filter {
-        /// By default, returns delegations for all active validators. For validators
-        /// that the given address index has no delegation tokens for, a `ValueView`
-        /// with a balance of `0` will be returned.
-        Unspecified = 0,
-        /// Returns only delegations to active validators that the given address
-        /// index holds delegation tokens for.
-        AllActiveWithNonzeroBalances = 1,
-        /// Return delegations for all validators, whether active or not. For
-        /// validators that the given address index has no delegation tokens for, a
-        /// `ValueView` with a balance of `0` will be returned.
-        All = 2,
-    }
-    impl Filter {
-        /// String value of the enum field names used in the ProtoBuf definition.
-        ///
-        /// The values are not transformed in any way and thus are considered stable
-        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
-        pub fn as_str_name(&self) -> &'static str {
-            match self {
-                Filter::Unspecified => "FILTER_UNSPECIFIED",
-                Filter::AllActiveWithNonzeroBalances => {
-                    "FILTER_ALL_ACTIVE_WITH_NONZERO_BALANCES"
-                }
-                Filter::All => "FILTER_ALL",
-            }
-        }
-        /// Creates an enum from field names used in the ProtoBuf definition.
-        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
-            match value {
-                "FILTER_UNSPECIFIED" => Some(Self::Unspecified),
-                "FILTER_ALL_ACTIVE_WITH_NONZERO_BALANCES" => {
-                    Some(Self::AllActiveWithNonzeroBalances)
-                }
-                "FILTER_ALL" => Some(Self::All),
-                _ => None,
-            }
-        }
+impl ::prost::Name for FmdParametersResponse {
+    const NAME: &'static str = "FMDParametersResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-impl ::prost::Name for DelegationsByAddressIndexRequest {
-    const NAME: &'static str = "DelegationsByAddressIndexRequest";
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NoteByCommitmentRequest {
+    #[prost(message, optional, tag = "2")]
+    pub note_commitment: ::core::option::Option<
+        super::super::crypto::tct::v1::StateCommitment,
+    >,
+    /// If set to true, waits to return until the requested note is detected.
+    #[prost(bool, tag = "3")]
+    pub await_detection: bool,
+}
+impl ::prost::Name for NoteByCommitmentRequest {
+    const NAME: &'static str = "NoteByCommitmentRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Contains a `ValueView` of delegation tokens for the requested address index.
-/// The `ValueView` includes the `ValidatorInfo` for the delegated validator in
-/// cits `extended_metadata` field.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct DelegationsByAddressIndexResponse {
+pub struct NoteByCommitmentResponse {
     #[prost(message, optional, tag = "1")]
-    pub value_view: ::core::option::Option<super::super::core::asset::v1::ValueView>,
+    pub spendable_note: ::core::option::Option<SpendableNoteRecord>,
 }
-impl ::prost::Name for DelegationsByAddressIndexResponse {
-    const NAME: &'static str = "DelegationsByAddressIndexResponse";
+impl ::prost::Name for NoteByCommitmentResponse {
+    const NAME: &'static str = "NoteByCommitmentResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Requests unbonding tokens for a given address index, with optional filtering
-/// for whether the tokens are currently claimable.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UnbondingTokensByAddressIndexRequest {
-    #[prost(
-        enumeration = "unbonding_tokens_by_address_index_request::Filter",
-        tag = "1"
-    )]
-    pub filter: i32,
-    /// The address index to fetch unbonding tokens for.
+pub struct SwapByCommitmentRequest {
     #[prost(message, optional, tag = "2")]
-    pub address_index: ::core::option::Option<
-        super::super::core::keys::v1::AddressIndex,
+    pub swap_commitment: ::core::option::Option<
+        super::super::crypto::tct::v1::StateCommitment,
     >,
+    /// If set to true, waits to return until the requested swap is detected.
+    #[prost(bool, tag = "3")]
+    pub await_detection: bool,
 }
-/// Nested message and enum types in `UnbondingTokensByAddressIndexRequest`.
-pub mod unbonding_tokens_by_address_index_request {
-    #[derive(
-        Clone,
-        Copy,
-        Debug,
-        PartialEq,
-        Eq,
-        Hash,
-        PartialOrd,
-        Ord,
-        ::prost::Enumeration
-    )]
-    #[repr(i32)]
-    pub enum Filter {
-        /// Return all unbonding tokens, regardless of whether they're claimable
-        /// right now.
-        Unspecified = 0,
-        /// Return all unbonding tokens that are currently claimable. This includes:
-        ///
-        /// * tokens that have passed the `unbonding_delay` (from `StakeParameters`)
-        /// * tokens for unbonded validators
-        Claimable = 1,
-        /// Return all unbonding tokens that are not yet claimable, because they are
-        /// still in the `unbonding_delay` (from `StakeParameters`) period.
-        NotYetClaimable = 2,
+impl ::prost::Name for SwapByCommitmentRequest {
+    const NAME: &'static str = "SwapByCommitmentRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
-    impl Filter {
-        /// String value of the enum field names used in the ProtoBuf definition.
-        ///
-        /// The values are not transformed in any way and thus are considered stable
-        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
-        pub fn as_str_name(&self) -> &'static str {
-            match self {
-                Filter::Unspecified => "FILTER_UNSPECIFIED",
-                Filter::Claimable => "FILTER_CLAIMABLE",
-                Filter::NotYetClaimable => "FILTER_NOT_YET_CLAIMABLE",
-            }
-        }
-        /// Creates an enum from field names used in the ProtoBuf definition.
-        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
-            match value {
-                "FILTER_UNSPECIFIED" => Some(Self::Unspecified),
-                "FILTER_CLAIMABLE" => Some(Self::Claimable),
-                "FILTER_NOT_YET_CLAIMABLE" => Some(Self::NotYetClaimable),
-                _ => None,
-            }
-        }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapByCommitmentResponse {
+    #[prost(message, optional, tag = "1")]
+    pub swap: ::core::option::Option<SwapRecord>,
+}
+impl ::prost::Name for SwapByCommitmentResponse {
+    const NAME: &'static str = "SwapByCommitmentResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-impl ::prost::Name for UnbondingTokensByAddressIndexRequest {
-    const NAME: &'static str = "UnbondingTokensByAddressIndexRequest";
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnclaimedSwapsRequest {}
+impl ::prost::Name for UnclaimedSwapsRequest {
+    const NAME: &'static str = "UnclaimedSwapsRequest";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Returns unbonding tokens for the given address index, optionally filtered by
-/// whether the tokens are currently claimable.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct UnbondingTokensByAddressIndexResponse {
-    /// A `ValueView` representing the amount of the given unbonding token.
+pub struct UnclaimedSwapsResponse {
     #[prost(message, optional, tag = "1")]
-    pub value_view: ::core::option::Option<super::super::core::asset::v1::ValueView>,
-    /// Whether the unbonding token is currently claimable. This will only be
-    /// `true` if the `unbonding_delay` (from `StakeParameters`) has passed or the
-    /// validator has unbonded.
-    #[prost(bool, tag = "2")]
-    pub claimable: bool,
+    pub swap: ::core::option::Option<SwapRecord>,
 }
-impl ::prost::Name for UnbondingTokensByAddressIndexResponse {
-    const NAME: &'static str = "UnbondingTokensByAddressIndexResponse";
+impl ::prost::Name for UnclaimedSwapsResponse {
+    const NAME: &'static str = "UnclaimedSwapsResponse";
     const PACKAGE: &'static str = "penumbra.view.v1";
     fn full_name() -> ::prost::alloc::string::String {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Generated client implementations.
-#[cfg(feature = "rpc")]
-pub mod view_service_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    use tonic::codegen::http::Uri;
-    /// The view RPC is used by a view client, who wants to do some
-    /// transaction-related actions, to request data from a view service, which is
-    /// responsible for synchronizing and scanning the public chain state with one or
-    /// more full viewing keys.
-    #[derive(Debug, Clone)]
-    pub struct ViewServiceClient<T> {
-        inner: tonic::client::Grpc<T>,
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NullifierStatusRequest {
+    #[prost(message, optional, tag = "2")]
+    pub nullifier: ::core::option::Option<
+        super::super::core::component::sct::v1::Nullifier,
+    >,
+    #[prost(bool, tag = "3")]
+    pub await_detection: bool,
+}
+impl ::prost::Name for NullifierStatusRequest {
+    const NAME: &'static str = "NullifierStatusRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
-    impl ViewServiceClient<tonic::transport::Channel> {
-        /// Attempt to create a new client by connecting to a given endpoint.
-        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
-        where
-            D: TryInto<tonic::transport::Endpoint>,
-            D::Error: Into<StdError>,
-        {
-            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
-            Ok(Self::new(conn))
-        }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NullifierStatusResponse {
+    #[prost(bool, tag = "1")]
+    pub spent: bool,
+}
+impl ::prost::Name for NullifierStatusResponse {
+    const NAME: &'static str = "NullifierStatusResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
-    impl<T> ViewServiceClient<T>
-    where
-        T: tonic::client::GrpcService<tonic::body::BoxBody>,
-        T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
-    {
-        pub fn new(inner: T) -> Self {
-            let inner = tonic::client::Grpc::new(inner);
-            Self { inner }
-        }
-        pub fn with_origin(inner: T, origin: Uri) -> Self {
-            let inner = tonic::client::Grpc::with_origin(inner, origin);
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionInfoByHashRequest {
+    /// The transaction hash to query for.
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<super::super::core::txhash::v1::TransactionId>,
+}
+impl ::prost::Name for TransactionInfoByHashRequest {
+    const NAME: &'static str = "TransactionInfoByHashRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionInfoRequest {
+    /// If present, return only transactions after this height.
+    #[prost(uint64, tag = "1")]
+    pub start_height: u64,
+    /// If present, return only transactions before this height.
+    #[prost(uint64, tag = "2")]
+    pub end_height: u64,
+}
+impl ::prost::Name for TransactionInfoRequest {
+    const NAME: &'static str = "TransactionInfoRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionInfo {
+    /// The height the transaction was included in a block, if known.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The hash of the transaction.
+    #[prost(message, optional, tag = "2")]
+    pub id: ::core::option::Option<super::super::core::txhash::v1::TransactionId>,
+    /// The transaction data itself.
+    #[prost(message, optional, tag = "3")]
+    pub transaction: ::core::option::Option<
+        super::super::core::transaction::v1::Transaction,
+    >,
+    /// The transaction perspective, as seen by this view server.
+    #[prost(message, optional, tag = "4")]
+    pub perspective: ::core::option::Option<
+        super::super::core::transaction::v1::TransactionPerspective,
+    >,
+    /// A precomputed transaction view of `transaction` from `perspective`, included for convenience of clients that don't have support for viewing transactions on their own.
+    #[prost(message, optional, tag = "5")]
+    pub view: ::core::option::Option<
+        super::super::core::transaction::v1::TransactionView,
+    >,
+}
+impl ::prost::Name for TransactionInfo {
+    const NAME: &'static str = "TransactionInfo";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionInfoResponse {
+    #[prost(message, optional, tag = "1")]
+    pub tx_info: ::core::option::Option<TransactionInfo>,
+}
+impl ::prost::Name for TransactionInfoResponse {
+    const NAME: &'static str = "TransactionInfoResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionInfoByHashResponse {
+    #[prost(message, optional, tag = "1")]
+    pub tx_info: ::core::option::Option<TransactionInfo>,
+}
+impl ::prost::Name for TransactionInfoByHashResponse {
+    const NAME: &'static str = "TransactionInfoByHashResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NotesResponse {
+    #[prost(message, optional, tag = "1")]
+    pub note_record: ::core::option::Option<SpendableNoteRecord>,
+}
+impl ::prost::Name for NotesResponse {
+    const NAME: &'static str = "NotesResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NotesForVotingResponse {
+    #[prost(message, optional, tag = "1")]
+    pub note_record: ::core::option::Option<SpendableNoteRecord>,
+    #[prost(message, optional, tag = "2")]
+    pub identity_key: ::core::option::Option<super::super::core::keys::v1::IdentityKey>,
+}
+impl ::prost::Name for NotesForVotingResponse {
+    const NAME: &'static str = "NotesForVotingResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// A note plaintext with associated metadata about its status.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpendableNoteRecord {
+    /// The note commitment, identifying the note.
+    #[prost(message, optional, tag = "1")]
+    pub note_commitment: ::core::option::Option<
+        super::super::crypto::tct::v1::StateCommitment,
+    >,
+    /// The note plaintext itself.
+    #[prost(message, optional, tag = "2")]
+    pub note: ::core::option::Option<
+        super::super::core::component::shielded_pool::v1::Note,
+    >,
+    /// A precomputed decryption of the note's address incore.component.dex.v1.
+    #[prost(message, optional, tag = "3")]
+    pub address_index: ::core::option::Option<
+        super::super::core::keys::v1::AddressIndex,
+    >,
+    /// The note's nullifier.
+    #[prost(message, optional, tag = "4")]
+    pub nullifier: ::core::option::Option<
+        super::super::core::component::sct::v1::Nullifier,
+    >,
+    /// The height at which the note was created.
+    #[prost(uint64, tag = "5")]
+    pub height_created: u64,
+    /// Records whether the note was spent (and if so, at what height).
+    #[prost(uint64, tag = "6")]
+    pub height_spent: u64,
+    /// The note position.
+    #[prost(uint64, tag = "7")]
+    pub position: u64,
+    /// The source of the note
+    #[prost(message, optional, tag = "8")]
+    pub source: ::core::option::Option<
+        super::super::core::component::sct::v1::CommitmentSource,
+    >,
+    /// The sender's return address, if known.
+    #[prost(message, optional, tag = "9")]
+    pub return_address: ::core::option::Option<
+        super::super::core::keys::v1::AddressView,
+    >,
+}
+impl ::prost::Name for SpendableNoteRecord {
+    const NAME: &'static str = "SpendableNoteRecord";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SwapRecord {
+    #[prost(message, optional, tag = "1")]
+    pub swap_commitment: ::core::option::Option<
+        super::super::crypto::tct::v1::StateCommitment,
+    >,
+    #[prost(message, optional, tag = "2")]
+    pub swap: ::core::option::Option<
+        super::super::core::component::dex::v1::SwapPlaintext,
+    >,
+    #[prost(uint64, tag = "3")]
+    pub position: u64,
+    #[prost(message, optional, tag = "4")]
+    pub nullifier: ::core::option::Option<
+        super::super::core::component::sct::v1::Nullifier,
+    >,
+    #[prost(message, optional, tag = "5")]
+    pub output_data: ::core::option::Option<
+        super::super::core::component::dex::v1::BatchSwapOutputData,
+    >,
+    #[prost(uint64, tag = "6")]
+    pub height_claimed: u64,
+    #[prost(message, optional, tag = "7")]
+    pub source: ::core::option::Option<
+        super::super::core::component::sct::v1::CommitmentSource,
+    >,
+}
+impl ::prost::Name for SwapRecord {
+    const NAME: &'static str = "SwapRecord";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OwnedPositionIdsRequest {
+    /// If present, return only positions with this position state.
+    #[prost(message, optional, tag = "1")]
+    pub position_state: ::core::option::Option<
+        super::super::core::component::dex::v1::PositionState,
+    >,
+    /// If present, return only positions for this trading pair.
+    #[prost(message, optional, tag = "2")]
+    pub trading_pair: ::core::option::Option<
+        super::super::core::component::dex::v1::TradingPair,
+    >,
+}
+impl ::prost::Name for OwnedPositionIdsRequest {
+    const NAME: &'static str = "OwnedPositionIdsRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OwnedPositionIdsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<
+        super::super::core::component::dex::v1::PositionId,
+    >,
+}
+impl ::prost::Name for OwnedPositionIdsResponse {
+    const NAME: &'static str = "OwnedPositionIdsResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// Requests information on an asset by asset id
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetMetadataByIdRequest {
+    /// The asset id to request information on.
+    #[prost(message, optional, tag = "2")]
+    pub asset_id: ::core::option::Option<super::super::core::asset::v1::AssetId>,
+}
+impl ::prost::Name for AssetMetadataByIdRequest {
+    const NAME: &'static str = "AssetMetadataByIdRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetMetadataByIdResponse {
+    /// If present, information on the requested asset.
+    ///
+    /// If the requested asset was unknown, this field will not be present.
+    #[prost(message, optional, tag = "1")]
+    pub denom_metadata: ::core::option::Option<super::super::core::asset::v1::Metadata>,
+}
+impl ::prost::Name for AssetMetadataByIdResponse {
+    const NAME: &'static str = "AssetMetadataByIdResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// Requests `ValueView`s of delegation tokens for the given address index. The
+/// returned `ValueView`s will include the `ValidatorInfo` for the delegated
+/// validator in their `extended_metadata` fields.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationsByAddressIndexRequest {
+    /// The address index to fetch delegation balances for.
+    #[prost(message, optional, tag = "1")]
+    pub address_index: ::core::option::Option<
+        super::super::core::keys::v1::AddressIndex,
+    >,
+    #[prost(enumeration = "delegations_by_address_index_request::Filter", tag = "2")]
+    pub filter: i32,
+}
+/// Nested message and enum types in `DelegationsByAddressIndexRequest`.
+pub mod delegations_by_address_index_request {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Filter {
+        /// By default, returns delegations for all active validators. For validators
+        /// that the given address index has no delegation tokens for, a `ValueView`
+        /// with a balance of `0` will be returned.
+        Unspecified = 0,
+        /// Returns only delegations to active validators that the given address
+        /// index holds delegation tokens for.
+        AllActiveWithNonzeroBalances = 1,
+        /// Return delegations for all validators, whether active or not. For
+        /// validators that the given address index has no delegation tokens for, a
+        /// `ValueView` with a balance of `0` will be returned.
+        All = 2,
+    }
+    impl Filter {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Filter::Unspecified => "FILTER_UNSPECIFIED",
+                Filter::AllActiveWithNonzeroBalances => {
+                    "FILTER_ALL_ACTIVE_WITH_NONZERO_BALANCES"
+                }
+                Filter::All => "FILTER_ALL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "FILTER_UNSPECIFIED" => Some(Self::Unspecified),
+                "FILTER_ALL_ACTIVE_WITH_NONZERO_BALANCES" => {
+                    Some(Self::AllActiveWithNonzeroBalances)
+                }
+                "FILTER_ALL" => Some(Self::All),
+                _ => None,
+            }
+        }
+    }
+}
+impl ::prost::Name for DelegationsByAddressIndexRequest {
+    const NAME: &'static str = "DelegationsByAddressIndexRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// Contains a `ValueView` of delegation tokens for the requested address index.
+/// The `ValueView` includes the `ValidatorInfo` for the delegated validator in
+/// cits `extended_metadata` field.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DelegationsByAddressIndexResponse {
+    #[prost(message, optional, tag = "1")]
+    pub value_view: ::core::option::Option<super::super::core::asset::v1::ValueView>,
+}
+impl ::prost::Name for DelegationsByAddressIndexResponse {
+    const NAME: &'static str = "DelegationsByAddressIndexResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// Requests unbonding tokens for a given address index, with optional filtering
+/// for whether the tokens are currently claimable.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnbondingTokensByAddressIndexRequest {
+    #[prost(
+        enumeration = "unbonding_tokens_by_address_index_request::Filter",
+        tag = "1"
+    )]
+    pub filter: i32,
+    /// The address index to fetch unbonding tokens for.
+    #[prost(message, optional, tag = "2")]
+    pub address_index: ::core::option::Option<
+        super::super::core::keys::v1::AddressIndex,
+    >,
+}
+/// Nested message and enum types in `UnbondingTokensByAddressIndexRequest`.
+pub mod unbonding_tokens_by_address_index_request {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Filter {
+        /// Return all unbonding tokens, regardless of whether they're claimable
+        /// right now.
+        Unspecified = 0,
+        /// Return all unbonding tokens that are currently claimable. This includes:
+        ///
+        /// * tokens that have passed the `unbonding_delay` (from `StakeParameters`)
+        /// * tokens for unbonded validators
+        Claimable = 1,
+        /// Return all unbonding tokens that are not yet claimable, because they are
+        /// still in the `unbonding_delay` (from `StakeParameters`) period.
+        NotYetClaimable = 2,
+    }
+    impl Filter {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Filter::Unspecified => "FILTER_UNSPECIFIED",
+                Filter::Claimable => "FILTER_CLAIMABLE",
+                Filter::NotYetClaimable => "FILTER_NOT_YET_CLAIMABLE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "FILTER_UNSPECIFIED" => Some(Self::Unspecified),
+                "FILTER_CLAIMABLE" => Some(Self::Claimable),
+                "FILTER_NOT_YET_CLAIMABLE" => Some(Self::NotYetClaimable),
+                _ => None,
+            }
+        }
+    }
+}
+impl ::prost::Name for UnbondingTokensByAddressIndexRequest {
+    const NAME: &'static str = "UnbondingTokensByAddressIndexRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// Returns unbonding tokens for the given address index, optionally filtered by
+/// whether the tokens are currently claimable.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnbondingTokensByAddressIndexResponse {
+    /// A `ValueView` representing the amount of the given unbonding token.
+    #[prost(message, optional, tag = "1")]
+    pub value_view: ::core::option::Option<super::super::core::asset::v1::ValueView>,
+    /// Whether the unbonding token is currently claimable. This will only be
+    /// `true` if the `unbonding_delay` (from `StakeParameters`) has passed or the
+    /// validator has unbonded.
+    #[prost(bool, tag = "2")]
+    pub claimable: bool,
+}
+impl ::prost::Name for UnbondingTokensByAddressIndexResponse {
+    const NAME: &'static str = "UnbondingTokensByAddressIndexResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "rpc")]
+pub mod view_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// The view RPC is used by a view client, who wants to do some
+    /// transaction-related actions, to request data from a view service, which is
+    /// responsible for synchronizing and scanning the public chain state with one or
+    /// more full viewing keys.
+    #[derive(Debug, Clone)]
+    pub struct ViewServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ViewServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ViewServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
             Self { inner }
         }
         pub fn with_interceptor<F>(
@@ -1812,42 +2419,373 @@ pub mod view_service_client {
         {
             ViewServiceClient::new(InterceptedService::new(inner, interceptor))
         }
-        /// Compress requests with the given encoding.
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Get current status of chain sync
+        pub async fn status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StatusRequest>,
+        ) -> std::result::Result<tonic::Response<super::StatusResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/Status",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Status"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Stream sync status updates until the view service has caught up with the chain.
+        /// Returns a stream of `StatusStreamResponse`s.
+        pub async fn status_stream(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StatusStreamRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::StatusStreamResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/StatusStream",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "StatusStream"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Queries for notes that have been accepted by the chain.
+        /// Returns a stream of `NotesResponse`s.
+        pub async fn notes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::NotesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::NotesResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/Notes",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Notes"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Returns a stream of `NotesForVotingResponse`s.
+        pub async fn notes_for_voting(
+            &mut self,
+            request: impl tonic::IntoRequest<super::NotesForVotingRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::NotesForVotingResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/NotesForVoting",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "NotesForVoting"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Queries for metadata about known assets.
+        /// Returns a stream of `AssetsResponse`s.
+        pub async fn assets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AssetsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::AssetsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/Assets",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Assets"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Query for metadata about a specific asset, by asset ID.
         ///
-        /// This requires the server to support it otherwise it might respond with an
-        /// error.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.send_compressed(encoding);
-            self
+        /// This is the same as the method on the shielded pool's `QueryService`, but exposing it
+        /// here allows a view server to provide more specific or opinionated asset metadata -- like
+        /// using an asset registry to provide tickers, symbols, etc.
+        pub async fn asset_metadata_by_id(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AssetMetadataByIdRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AssetMetadataByIdResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/AssetMetadataById",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "AssetMetadataById"),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        /// Enable decompressing responses.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.accept_compressed(encoding);
-            self
+        /// Query for the current app parameters.
+        pub async fn app_parameters(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AppParametersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AppParametersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/AppParameters",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "AppParameters"),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_decoding_message_size(limit);
-            self
+        /// Query for the current gas prices.
+        pub async fn gas_prices(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GasPricesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GasPricesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/GasPrices",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "GasPrices"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_encoding_message_size(limit);
-            self
+        /// Query for the current FMD parameters.
+        pub async fn fmd_parameters(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FmdParametersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FmdParametersResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/FMDParameters",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "FMDParameters"),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        /// Get current status of chain sync
-        pub async fn status(
+        /// Query for an address given an address index
+        pub async fn address_by_index(
             &mut self,
-            request: impl tonic::IntoRequest<super::StatusRequest>,
-        ) -> std::result::Result<tonic::Response<super::StatusResponse>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::AddressByIndexRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddressByIndexResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/AddressByIndex",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "AddressByIndex"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Query for wallet id
+        pub async fn wallet_id(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WalletIdRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::WalletIdResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/WalletId",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "WalletId"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Query for an address given an address index
+        pub async fn index_by_address(
+            &mut self,
+            request: impl tonic::IntoRequest<super::IndexByAddressRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::IndexByAddressResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/IndexByAddress",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "IndexByAddress"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Query for an ephemeral address
+        pub async fn ephemeral_address(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EphemeralAddressRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EphemeralAddressResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -1859,20 +2797,22 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/Status",
+                "/penumbra.view.v1.ViewService/EphemeralAddress",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Status"));
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "EphemeralAddress"),
+                );
             self.inner.unary(req, path, codec).await
         }
-        /// Stream sync status updates until the view service has caught up with the chain.
-        /// Returns a stream of `StatusStreamResponse`s.
-        pub async fn status_stream(
+        /// Query for balance of a given address.
+        /// Returns a stream of `BalancesResponses`.
+        pub async fn balances(
             &mut self,
-            request: impl tonic::IntoRequest<super::StatusStreamRequest>,
+            request: impl tonic::IntoRequest<super::BalancesRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::StatusStreamResponse>>,
+            tonic::Response<tonic::codec::Streaming<super::BalancesResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -1886,20 +2826,19 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/StatusStream",
+                "/penumbra.view.v1.ViewService/Balances",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "StatusStream"));
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Balances"));
             self.inner.server_streaming(req, path, codec).await
         }
-        /// Queries for notes that have been accepted by the chain.
-        /// Returns a stream of `NotesResponse`s.
-        pub async fn notes(
+        /// Query for a note by its note commitment, optionally waiting until the note is detected.
+        pub async fn note_by_commitment(
             &mut self,
-            request: impl tonic::IntoRequest<super::NotesRequest>,
+            request: impl tonic::IntoRequest<super::NoteByCommitmentRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::NotesResponse>>,
+            tonic::Response<super::NoteByCommitmentResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1913,19 +2852,21 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/Notes",
+                "/penumbra.view.v1.ViewService/NoteByCommitment",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Notes"));
-            self.inner.server_streaming(req, path, codec).await
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "NoteByCommitment"),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        /// Returns a stream of `NotesForVotingResponse`s.
-        pub async fn notes_for_voting(
+        /// Query for a swap by its swap commitment, optionally waiting until the swap is detected.
+        pub async fn swap_by_commitment(
             &mut self,
-            request: impl tonic::IntoRequest<super::NotesForVotingRequest>,
+            request: impl tonic::IntoRequest<super::SwapByCommitmentRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::NotesForVotingResponse>>,
+            tonic::Response<super::SwapByCommitmentResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1939,22 +2880,21 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/NotesForVoting",
+                "/penumbra.view.v1.ViewService/SwapByCommitment",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "NotesForVoting"),
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "SwapByCommitment"),
                 );
-            self.inner.server_streaming(req, path, codec).await
+            self.inner.unary(req, path, codec).await
         }
-        /// Queries for metadata about known assets.
-        /// Returns a stream of `AssetsResponse`s.
-        pub async fn assets(
+        /// Query for all unclaimed swaps.
+        pub async fn unclaimed_swaps(
             &mut self,
-            request: impl tonic::IntoRequest<super::AssetsRequest>,
+            request: impl tonic::IntoRequest<super::UnclaimedSwapsRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::AssetsResponse>>,
+            tonic::Response<tonic::codec::Streaming<super::UnclaimedSwapsResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -1968,23 +2908,21 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/Assets",
+                "/penumbra.view.v1.ViewService/UnclaimedSwaps",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Assets"));
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "UnclaimedSwaps"),
+                );
             self.inner.server_streaming(req, path, codec).await
         }
-        /// Query for metadata about a specific asset, by asset ID.
-        ///
-        /// This is the same as the method on the shielded pool's `QueryService`, but exposing it
-        /// here allows a view server to provide more specific or opinionated asset metadata -- like
-        /// using an asset registry to provide tickers, symbols, etc.
-        pub async fn asset_metadata_by_id(
+        /// Query for whether a nullifier has been spent, optionally waiting until it is spent.
+        pub async fn nullifier_status(
             &mut self,
-            request: impl tonic::IntoRequest<super::AssetMetadataByIdRequest>,
+            request: impl tonic::IntoRequest<super::NullifierStatusRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::AssetMetadataByIdResponse>,
+            tonic::Response<super::NullifierStatusResponse>,
             tonic::Status,
         > {
             self.inner
@@ -1998,21 +2936,21 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/AssetMetadataById",
+                "/penumbra.view.v1.ViewService/NullifierStatus",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "AssetMetadataById"),
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "NullifierStatus"),
                 );
             self.inner.unary(req, path, codec).await
         }
-        /// Query for the current app parameters.
-        pub async fn app_parameters(
+        /// Query for a given transaction by its hash.
+        pub async fn transaction_info_by_hash(
             &mut self,
-            request: impl tonic::IntoRequest<super::AppParametersRequest>,
+            request: impl tonic::IntoRequest<super::TransactionInfoByHashRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::AppParametersResponse>,
+            tonic::Response<super::TransactionInfoByHashResponse>,
             tonic::Status,
         > {
             self.inner
@@ -2026,21 +2964,25 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/AppParameters",
+                "/penumbra.view.v1.ViewService/TransactionInfoByHash",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "AppParameters"),
+                    GrpcMethod::new(
+                        "penumbra.view.v1.ViewService",
+                        "TransactionInfoByHash",
+                    ),
                 );
             self.inner.unary(req, path, codec).await
         }
-        /// Query for the current gas prices.
-        pub async fn gas_prices(
+        /// Query for the full transactions in the given range of blocks.
+        /// Returns a stream of `TransactionInfoResponse`s.
+        pub async fn transaction_info(
             &mut self,
-            request: impl tonic::IntoRequest<super::GasPricesRequest>,
+            request: impl tonic::IntoRequest<super::TransactionInfoRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GasPricesResponse>,
+            tonic::Response<tonic::codec::Streaming<super::TransactionInfoResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -2054,19 +2996,21 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/GasPrices",
+                "/penumbra.view.v1.ViewService/TransactionInfo",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "GasPrices"));
-            self.inner.unary(req, path, codec).await
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "TransactionInfo"),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
-        /// Query for the current FMD parameters.
-        pub async fn fmd_parameters(
+        /// Query for owned position IDs for the given trading pair and in the given position state.
+        pub async fn owned_position_ids(
             &mut self,
-            request: impl tonic::IntoRequest<super::FmdParametersRequest>,
+            request: impl tonic::IntoRequest<super::OwnedPositionIdsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::FmdParametersResponse>,
+            tonic::Response<tonic::codec::Streaming<super::OwnedPositionIdsResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -2080,21 +3024,21 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/FMDParameters",
+                "/penumbra.view.v1.ViewService/OwnedPositionIds",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "FMDParameters"),
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "OwnedPositionIds"),
                 );
-            self.inner.unary(req, path, codec).await
+            self.inner.server_streaming(req, path, codec).await
         }
-        /// Query for an address given an address index
-        pub async fn address_by_index(
+        /// Translates a high-level intent ("send X funds to Y address") into a complete transaction plan.
+        pub async fn transaction_planner(
             &mut self,
-            request: impl tonic::IntoRequest<super::AddressByIndexRequest>,
+            request: impl tonic::IntoRequest<super::TransactionPlannerRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::AddressByIndexResponse>,
+            tonic::Response<super::TransactionPlannerResponse>,
             tonic::Status,
         > {
             self.inner
@@ -2108,21 +3052,26 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/AddressByIndex",
+                "/penumbra.view.v1.ViewService/TransactionPlanner",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "AddressByIndex"),
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "TransactionPlanner"),
                 );
             self.inner.unary(req, path, codec).await
         }
-        /// Query for wallet id
-        pub async fn wallet_id(
+        /// Returns authentication data for the given transaction plan.
+        ///
+        /// This method takes a complete transaction plan, so that the client can get a
+        /// consistent set of authentication paths to a common root for the entire
+        /// transaction.  (Otherwise, if a client made multiple requests, the wallet
+        /// service could have advanced the state commitment tree  between queries).
+        pub async fn witness(
             &mut self,
-            request: impl tonic::IntoRequest<super::WalletIdRequest>,
+            request: impl tonic::IntoRequest<super::WitnessRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::WalletIdResponse>,
+            tonic::Response<super::WitnessResponse>,
             tonic::Status,
         > {
             self.inner
@@ -2136,19 +3085,25 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/WalletId",
+                "/penumbra.view.v1.ViewService/Witness",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "WalletId"));
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Witness"));
             self.inner.unary(req, path, codec).await
         }
-        /// Query for an address given an address index
-        pub async fn index_by_address(
+        /// Like `Witness`, but immediately uses the witness data to build (prove) the transaction.
+        ///
+        /// This method is useful for clients that can't easily do proving themselves, either because
+        /// they're not written in Rust and can't easily import the proving code, or because they don't
+        /// have access to proving keys, or some other reason.
+        ///
+        /// This method streams status updates to the caller before finally returning the transaction.
+        pub async fn witness_and_build(
             &mut self,
-            request: impl tonic::IntoRequest<super::IndexByAddressRequest>,
+            request: impl tonic::IntoRequest<super::WitnessAndBuildRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::IndexByAddressResponse>,
+            tonic::Response<tonic::codec::Streaming<super::WitnessAndBuildResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -2162,21 +3117,31 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/IndexByAddress",
+                "/penumbra.view.v1.ViewService/WitnessAndBuild",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "IndexByAddress"),
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "WitnessAndBuild"),
                 );
-            self.inner.unary(req, path, codec).await
+            self.inner.server_streaming(req, path, codec).await
         }
-        /// Query for an ephemeral address
-        pub async fn ephemeral_address(
+        /// Authorize a transaction plan and build the transaction.
+        ///
+        /// This method is only supported on view servers that have access to a custody
+        /// service.  Otherwise, it will fail.
+        ///
+        /// Penumbra's transaction authorization mechanism is designed so transactions
+        /// can be signed and built (proved) concurrently. This allows implementations
+        /// to, e.g., start proving optimistically while presenting the user with an
+        /// approval dialog.
+        ///
+        /// This method streams status updates to the caller before finally returning the transaction.
+        pub async fn authorize_and_build(
             &mut self,
-            request: impl tonic::IntoRequest<super::EphemeralAddressRequest>,
+            request: impl tonic::IntoRequest<super::AuthorizeAndBuildRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::EphemeralAddressResponse>,
+            tonic::Response<tonic::codec::Streaming<super::AuthorizeAndBuildResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -2190,22 +3155,25 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/EphemeralAddress",
+                "/penumbra.view.v1.ViewService/AuthorizeAndBuild",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "EphemeralAddress"),
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "AuthorizeAndBuild"),
                 );
-            self.inner.unary(req, path, codec).await
+            self.inner.server_streaming(req, path, codec).await
         }
-        /// Query for balance of a given address.
-        /// Returns a stream of `BalancesResponses`.
-        pub async fn balances(
+        /// Broadcast a transaction to the network, optionally waiting for full confirmation.
+        ///
+        /// This method streams status updates to the caller before finally returning confirmation.
+        pub async fn broadcast_transaction(
             &mut self,
-            request: impl tonic::IntoRequest<super::BalancesRequest>,
+            request: impl tonic::IntoRequest<super::BroadcastTransactionRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::BalancesResponse>>,
+            tonic::Response<
+                tonic::codec::Streaming<super::BroadcastTransactionResponse>,
+            >,
             tonic::Status,
         > {
             self.inner
@@ -2219,19 +3187,29 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/Balances",
+                "/penumbra.view.v1.ViewService/BroadcastTransaction",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Balances"));
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1.ViewService",
+                        "BroadcastTransaction",
+                    ),
+                );
             self.inner.server_streaming(req, path, codec).await
         }
-        /// Query for a note by its note commitment, optionally waiting until the note is detected.
-        pub async fn note_by_commitment(
+        /// Get delegation tokens for a given address index. Each delegation token will
+        /// be represented by a `ValueView` with the given address index's balance of
+        /// that token. Each `ValueView`'s `extended_metadata` field will contain the
+        /// `ValidatorInfo` of the delegated validator.
+        pub async fn delegations_by_address_index(
             &mut self,
-            request: impl tonic::IntoRequest<super::NoteByCommitmentRequest>,
+            request: impl tonic::IntoRequest<super::DelegationsByAddressIndexRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::NoteByCommitmentResponse>,
+            tonic::Response<
+                tonic::codec::Streaming<super::DelegationsByAddressIndexResponse>,
+            >,
             tonic::Status,
         > {
             self.inner
@@ -2245,21 +3223,27 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/NoteByCommitment",
+                "/penumbra.view.v1.ViewService/DelegationsByAddressIndex",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "NoteByCommitment"),
+                    GrpcMethod::new(
+                        "penumbra.view.v1.ViewService",
+                        "DelegationsByAddressIndex",
+                    ),
                 );
-            self.inner.unary(req, path, codec).await
+            self.inner.server_streaming(req, path, codec).await
         }
-        /// Query for a swap by its swap commitment, optionally waiting until the swap is detected.
-        pub async fn swap_by_commitment(
+        /// Get unbonding tokens for the given address index, optionally filtered by
+        /// whether the tokens are currently claimable.
+        pub async fn unbonding_tokens_by_address_index(
             &mut self,
-            request: impl tonic::IntoRequest<super::SwapByCommitmentRequest>,
+            request: impl tonic::IntoRequest<super::UnbondingTokensByAddressIndexRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::SwapByCommitmentResponse>,
+            tonic::Response<
+                tonic::codec::Streaming<super::UnbondingTokensByAddressIndexResponse>,
+            >,
             tonic::Status,
         > {
             self.inner
@@ -2273,21 +3257,24 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/SwapByCommitment",
+                "/penumbra.view.v1.ViewService/UnbondingTokensByAddressIndex",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "SwapByCommitment"),
+                    GrpcMethod::new(
+                        "penumbra.view.v1.ViewService",
+                        "UnbondingTokensByAddressIndex",
+                    ),
                 );
-            self.inner.unary(req, path, codec).await
+            self.inner.server_streaming(req, path, codec).await
         }
-        /// Query for all unclaimed swaps.
-        pub async fn unclaimed_swaps(
+        /// Gets the auctions controlled by the user's wallet.
+        pub async fn auctions(
             &mut self,
-            request: impl tonic::IntoRequest<super::UnclaimedSwapsRequest>,
+            request: impl tonic::IntoRequest<super::AuctionsRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::UnclaimedSwapsResponse>>,
+            tonic::Response<tonic::codec::Streaming<super::AuctionsResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -2301,21 +3288,26 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/UnclaimedSwaps",
+                "/penumbra.view.v1.ViewService/Auctions",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "UnclaimedSwaps"),
-                );
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Auctions"));
             self.inner.server_streaming(req, path, codec).await
         }
-        /// Query for whether a nullifier has been spent, optionally waiting until it is spent.
-        pub async fn nullifier_status(
+        /// Stream changes to the user's wallet as the view service scans new blocks.
+        ///
+        /// An event is sent whenever scanning a block adds a new note or swap, spends
+        /// one of the wallet's notes, or records a transaction, position change or
+        /// auction change for the wallet. Only blocks scanned after the request is
+        /// made are reported, so clients should subscribe before querying the current
+        /// state, to avoid missing changes. The stream does not end on its own, but
+        /// fails if the client falls too far behind.
+        pub async fn wallet_events(
             &mut self,
-            request: impl tonic::IntoRequest<super::NullifierStatusRequest>,
+            request: impl tonic::IntoRequest<super::WalletEventsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::NullifierStatusResponse>,
+            tonic::Response<tonic::codec::Streaming<super::WalletEventsResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -2329,21 +3321,24 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/NullifierStatus",
+                "/penumbra.view.v1.ViewService/WalletEvents",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "NullifierStatus"),
-                );
-            self.inner.unary(req, path, codec).await
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "WalletEvents"));
+            self.inner.server_streaming(req, path, codec).await
         }
-        /// Query for a given transaction by its hash.
-        pub async fn transaction_info_by_hash(
+        /// Get the outputs of the batch swaps the view service has seen while
+        /// scanning blocks, in order of height.
+        ///
+        /// These are the clearing prices of the DEX at each height, which clients
+        /// can use to value the wallet's history, for instance to compute the cost
+        /// basis of its assets.
+        pub async fn batch_swap_outputs(
             &mut self,
-            request: impl tonic::IntoRequest<super::TransactionInfoByHashRequest>,
+            request: impl tonic::IntoRequest<super::BatchSwapOutputsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::TransactionInfoByHashResponse>,
+            tonic::Response<tonic::codec::Streaming<super::BatchSwapOutputsResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -2357,25 +3352,21 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/TransactionInfoByHash",
+                "/penumbra.view.v1.ViewService/BatchSwapOutputs",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new(
-                        "penumbra.view.v1.ViewService",
-                        "TransactionInfoByHash",
-                    ),
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "BatchSwapOutputs"),
                 );
-            self.inner.unary(req, path, codec).await
+            self.inner.server_streaming(req, path, codec).await
         }
-        /// Query for the full transactions in the given range of blocks.
-        /// Returns a stream of `TransactionInfoResponse`s.
-        pub async fn transaction_info(
+        /// Get the contacts in the user's address book, ordered by label.
+        pub async fn contacts(
             &mut self,
-            request: impl tonic::IntoRequest<super::TransactionInfoRequest>,
+            request: impl tonic::IntoRequest<super::ContactsRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::TransactionInfoResponse>>,
+            tonic::Response<tonic::codec::Streaming<super::ContactsResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -2389,21 +3380,20 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/TransactionInfo",
+                "/penumbra.view.v1.ViewService/Contacts",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "TransactionInfo"),
-                );
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Contacts"));
             self.inner.server_streaming(req, path, codec).await
         }
-        /// Query for owned position IDs for the given trading pair and in the given position state.
-        pub async fn owned_position_ids(
+        /// Add a contact to the user's address book, or change the label of an
+        /// existing one.
+        pub async fn set_contact(
             &mut self,
-            request: impl tonic::IntoRequest<super::OwnedPositionIdsRequest>,
+            request: impl tonic::IntoRequest<super::SetContactRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::OwnedPositionIdsResponse>>,
+            tonic::Response<super::SetContactResponse>,
             tonic::Status,
         > {
             self.inner
@@ -2417,21 +3407,19 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/OwnedPositionIds",
+                "/penumbra.view.v1.ViewService/SetContact",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "OwnedPositionIds"),
-                );
-            self.inner.server_streaming(req, path, codec).await
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "SetContact"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Translates a high-level intent ("send X funds to Y address") into a complete transaction plan.
-        pub async fn transaction_planner(
+        /// Remove a contact from the user's address book.
+        pub async fn delete_contact(
             &mut self,
-            request: impl tonic::IntoRequest<super::TransactionPlannerRequest>,
+            request: impl tonic::IntoRequest<super::DeleteContactRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::TransactionPlannerResponse>,
+            tonic::Response<super::DeleteContactResponse>,
             tonic::Status,
         > {
             self.inner
@@ -2445,26 +3433,21 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/TransactionPlanner",
+                "/penumbra.view.v1.ViewService/DeleteContact",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "TransactionPlanner"),
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "DeleteContact"),
                 );
             self.inner.unary(req, path, codec).await
         }
-        /// Returns authentication data for the given transaction plan.
-        ///
-        /// This method takes a complete transaction plan, so that the client can get a
-        /// consistent set of authentication paths to a common root for the entire
-        /// transaction.  (Otherwise, if a client made multiple requests, the wallet
-        /// service could have advanced the state commitment tree  between queries).
-        pub async fn witness(
+        /// Get the labels the user has given to the accounts of their wallet.
+        pub async fn account_labels(
             &mut self,
-            request: impl tonic::IntoRequest<super::WitnessRequest>,
+            request: impl tonic::IntoRequest<super::AccountLabelsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::WitnessResponse>,
+            tonic::Response<tonic::codec::Streaming<super::AccountLabelsResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -2478,25 +3461,22 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/Witness",
+                "/penumbra.view.v1.ViewService/AccountLabels",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Witness"));
-            self.inner.unary(req, path, codec).await
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "AccountLabels"),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
-        /// Like `Witness`, but immediately uses the witness data to build (prove) the transaction.
-        ///
-        /// This method is useful for clients that can't easily do proving themselves, either because
-        /// they're not written in Rust and can't easily import the proving code, or because they don't
-        /// have access to proving keys, or some other reason.
-        ///
-        /// This method streams status updates to the caller before finally returning the transaction.
-        pub async fn witness_and_build(
+        /// Label one of the accounts of the user's wallet, replacing any existing
+        /// label.
+        pub async fn set_account_label(
             &mut self,
-            request: impl tonic::IntoRequest<super::WitnessAndBuildRequest>,
+            request: impl tonic::IntoRequest<super::SetAccountLabelRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::WitnessAndBuildResponse>>,
+            tonic::Response<super::SetAccountLabelResponse>,
             tonic::Status,
         > {
             self.inner
@@ -2510,31 +3490,21 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/WitnessAndBuild",
+                "/penumbra.view.v1.ViewService/SetAccountLabel",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "WitnessAndBuild"),
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "SetAccountLabel"),
                 );
-            self.inner.server_streaming(req, path, codec).await
+            self.inner.unary(req, path, codec).await
         }
-        /// Authorize a transaction plan and build the transaction.
-        ///
-        /// This method is only supported on view servers that have access to a custody
-        /// service.  Otherwise, it will fail.
-        ///
-        /// Penumbra's transaction authorization mechanism is designed so transactions
-        /// can be signed and built (proved) concurrently. This allows implementations
-        /// to, e.g., start proving optimistically while presenting the user with an
-        /// approval dialog.
-        ///
-        /// This method streams status updates to the caller before finally returning the transaction.
-        pub async fn authorize_and_build(
+        /// Remove the label from one of the accounts of the user's wallet.
+        pub async fn delete_account_label(
             &mut self,
-            request: impl tonic::IntoRequest<super::AuthorizeAndBuildRequest>,
+            request: impl tonic::IntoRequest<super::DeleteAccountLabelRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::AuthorizeAndBuildResponse>>,
+            tonic::Response<super::DeleteAccountLabelResponse>,
             tonic::Status,
         > {
             self.inner
@@ -2548,25 +3518,26 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/AuthorizeAndBuild",
+                "/penumbra.view.v1.ViewService/DeleteAccountLabel",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new("penumbra.view.v1.ViewService", "AuthorizeAndBuild"),
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "DeleteAccountLabel"),
                 );
-            self.inner.server_streaming(req, path, codec).await
+            self.inner.unary(req, path, codec).await
         }
-        /// Broadcast a transaction to the network, optionally waiting for full confirmation.
+        /// Create an invoice: a payment request to one of the accounts of the user's
+        /// wallet, which the view service matches incoming notes against.
         ///
-        /// This method streams status updates to the caller before finally returning confirmation.
-        pub async fn broadcast_transaction(
+        /// A payment is matched to an invoice either by its dedicated address, if it
+        /// was given a one-time address of its own, or else by the memo of the
+        /// transaction which paid it.
+        pub async fn create_invoice(
             &mut self,
-            request: impl tonic::IntoRequest<super::BroadcastTransactionRequest>,
+            request: impl tonic::IntoRequest<super::CreateInvoiceRequest>,
         ) -> std::result::Result<
-            tonic::Response<
-                tonic::codec::Streaming<super::BroadcastTransactionResponse>,
-            >,
+            tonic::Response<super::CreateInvoiceResponse>,
             tonic::Status,
         > {
             self.inner
@@ -2580,29 +3551,21 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/BroadcastTransaction",
+                "/penumbra.view.v1.ViewService/CreateInvoice",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new(
-                        "penumbra.view.v1.ViewService",
-                        "BroadcastTransaction",
-                    ),
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "CreateInvoice"),
                 );
-            self.inner.server_streaming(req, path, codec).await
+            self.inner.unary(req, path, codec).await
         }
-        /// Get delegation tokens for a given address index. Each delegation token will
-        /// be represented by a `ValueView` with the given address index's balance of
-        /// that token. Each `ValueView`'s `extended_metadata` field will contain the
-        /// `ValidatorInfo` of the delegated validator.
-        pub async fn delegations_by_address_index(
+        /// Get the user's invoices, with the notes received in payment of each.
+        pub async fn invoices(
             &mut self,
-            request: impl tonic::IntoRequest<super::DelegationsByAddressIndexRequest>,
+            request: impl tonic::IntoRequest<super::InvoicesRequest>,
         ) -> std::result::Result<
-            tonic::Response<
-                tonic::codec::Streaming<super::DelegationsByAddressIndexResponse>,
-            >,
+            tonic::Response<tonic::codec::Streaming<super::InvoicesResponse>>,
             tonic::Status,
         > {
             self.inner
@@ -2616,27 +3579,19 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/DelegationsByAddressIndex",
+                "/penumbra.view.v1.ViewService/Invoices",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(
-                    GrpcMethod::new(
-                        "penumbra.view.v1.ViewService",
-                        "DelegationsByAddressIndex",
-                    ),
-                );
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Invoices"));
             self.inner.server_streaming(req, path, codec).await
         }
-        /// Get unbonding tokens for the given address index, optionally filtered by
-        /// whether the tokens are currently claimable.
-        pub async fn unbonding_tokens_by_address_index(
+        /// Remove an invoice, so that payments are no longer matched to it.
+        pub async fn delete_invoice(
             &mut self,
-            request: impl tonic::IntoRequest<super::UnbondingTokensByAddressIndexRequest>,
+            request: impl tonic::IntoRequest<super::DeleteInvoiceRequest>,
         ) -> std::result::Result<
-            tonic::Response<
-                tonic::codec::Streaming<super::UnbondingTokensByAddressIndexResponse>,
-            >,
+            tonic::Response<super::DeleteInvoiceResponse>,
             tonic::Status,
         > {
             self.inner
@@ -2650,24 +3605,128 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/UnbondingTokensByAddressIndex",
+                "/penumbra.view.v1.ViewService/DeleteInvoice",
             );
             let mut req = request.into_request();
             req.extensions_mut()
                 .insert(
-                    GrpcMethod::new(
-                        "penumbra.view.v1.ViewService",
-                        "UnbondingTokensByAddressIndex",
-                    ),
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "DeleteInvoice"),
                 );
-            self.inner.server_streaming(req, path, codec).await
+            self.inner.unary(req, path, codec).await
         }
-        /// Gets the auctions controlled by the user's wallet.
-        pub async fn auctions(
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "rpc")]
+pub mod detection_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// A service which detects the notes sent to a user, using the fuzzy message
+    /// detection keys of their addresses, so that the user's view service only
+    /// needs to trial-decrypt the notes which might be theirs.
+    ///
+    /// Detection is probabilistic: as well as every note sent to the registered
+    /// addresses, the service detects a fraction of other notes set by the chain's
+    /// FMD parameters, so it can't tell exactly which notes are the user's.
+    #[derive(Debug, Clone)]
+    pub struct DetectionServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl DetectionServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> DetectionServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> DetectionServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            DetectionServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Stream the compact blocks in a range, with the notes whose clues match
+        /// none of the given detection keys rolled up into their commitments.
+        ///
+        /// Blocks where none of the state payloads were detected are streamed without
+        /// them, since their block root is enough for the view service to advance its
+        /// state commitment tree. Their nullifiers are kept, since spends can't be
+        /// detected.
+        ///
+        /// Only notes created by transactions carry clues, so every other payload is
+        /// left as it is. Notes sent to one-time addresses can't be detected, since
+        /// each one-time address has its own detection key.
+        pub async fn filtered_compact_block_range(
             &mut self,
-            request: impl tonic::IntoRequest<super::AuctionsRequest>,
+            request: impl tonic::IntoRequest<super::FilteredCompactBlockRangeRequest>,
         ) -> std::result::Result<
-            tonic::Response<tonic::codec::Streaming<super::AuctionsResponse>>,
+            tonic::Response<
+                tonic::codec::Streaming<super::FilteredCompactBlockRangeResponse>,
+            >,
             tonic::Status,
         > {
             self.inner
@@ -2681,11 +3740,16 @@ pub mod view_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/penumbra.view.v1.ViewService/Auctions",
+                "/penumbra.view.v1.DetectionService/FilteredCompactBlockRange",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Auctions"));
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1.DetectionService",
+                        "FilteredCompactBlockRange",
+                    ),
+                );
             self.inner.server_streaming(req, path, codec).await
         }
     }
@@ -3047,6 +4111,140 @@ pub mod view_service_server {
             &self,
             request: tonic::Request<super::AuctionsRequest>,
         ) -> std::result::Result<tonic::Response<Self::AuctionsStream>, tonic::Status>;
+        /// Server streaming response type for the WalletEvents method.
+        type WalletEventsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::WalletEventsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Stream changes to the user's wallet as the view service scans new blocks.
+        ///
+        /// An event is sent whenever scanning a block adds a new note or swap, spends
+        /// one of the wallet's notes, or records a transaction, position change or
+        /// auction change for the wallet. Only blocks scanned after the request is
+        /// made are reported, so clients should subscribe before querying the current
+        /// state, to avoid missing changes. The stream does not end on its own, but
+        /// fails if the client falls too far behind.
+        async fn wallet_events(
+            &self,
+            request: tonic::Request<super::WalletEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::WalletEventsStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the BatchSwapOutputs method.
+        type BatchSwapOutputsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::BatchSwapOutputsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Get the outputs of the batch swaps the view service has seen while
+        /// scanning blocks, in order of height.
+        ///
+        /// These are the clearing prices of the DEX at each height, which clients
+        /// can use to value the wallet's history, for instance to compute the cost
+        /// basis of its assets.
+        async fn batch_swap_outputs(
+            &self,
+            request: tonic::Request<super::BatchSwapOutputsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::BatchSwapOutputsStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the Contacts method.
+        type ContactsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ContactsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Get the contacts in the user's address book, ordered by label.
+        async fn contacts(
+            &self,
+            request: tonic::Request<super::ContactsRequest>,
+        ) -> std::result::Result<tonic::Response<Self::ContactsStream>, tonic::Status>;
+        /// Add a contact to the user's address book, or change the label of an
+        /// existing one.
+        async fn set_contact(
+            &self,
+            request: tonic::Request<super::SetContactRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetContactResponse>,
+            tonic::Status,
+        >;
+        /// Remove a contact from the user's address book.
+        async fn delete_contact(
+            &self,
+            request: tonic::Request<super::DeleteContactRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteContactResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the AccountLabels method.
+        type AccountLabelsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::AccountLabelsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Get the labels the user has given to the accounts of their wallet.
+        async fn account_labels(
+            &self,
+            request: tonic::Request<super::AccountLabelsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::AccountLabelsStream>,
+            tonic::Status,
+        >;
+        /// Label one of the accounts of the user's wallet, replacing any existing
+        /// label.
+        async fn set_account_label(
+            &self,
+            request: tonic::Request<super::SetAccountLabelRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetAccountLabelResponse>,
+            tonic::Status,
+        >;
+        /// Remove the label from one of the accounts of the user's wallet.
+        async fn delete_account_label(
+            &self,
+            request: tonic::Request<super::DeleteAccountLabelRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteAccountLabelResponse>,
+            tonic::Status,
+        >;
+        /// Create an invoice: a payment request to one of the accounts of the user's
+        /// wallet, which the view service matches incoming notes against.
+        ///
+        /// A payment is matched to an invoice either by its dedicated address, if it
+        /// was given a one-time address of its own, or else by the memo of the
+        /// transaction which paid it.
+        async fn create_invoice(
+            &self,
+            request: tonic::Request<super::CreateInvoiceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateInvoiceResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the Invoices method.
+        type InvoicesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::InvoicesResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Get the user's invoices, with the notes received in payment of each.
+        async fn invoices(
+            &self,
+            request: tonic::Request<super::InvoicesRequest>,
+        ) -> std::result::Result<tonic::Response<Self::InvoicesStream>, tonic::Status>;
+        /// Remove an invoice, so that payments are no longer matched to it.
+        async fn delete_invoice(
+            &self,
+            request: tonic::Request<super::DeleteInvoiceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteInvoiceResponse>,
+            tonic::Status,
+        >;
     }
     /// The view RPC is used by a view client, who wants to do some
     /// transaction-related actions, to request data from a view service, which is
@@ -3133,23 +4331,488 @@ pub mod view_service_server {
             match req.uri().path() {
                 "/penumbra.view.v1.ViewService/Status" => {
                     #[allow(non_camel_case_types)]
-                    struct StatusSvc<T: ViewService>(pub Arc<T>);
+                    struct StatusSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::StatusRequest>
+                    for StatusSvc<T> {
+                        type Response = super::StatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::status(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = StatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/StatusStream" => {
+                    #[allow(non_camel_case_types)]
+                    struct StatusStreamSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<super::StatusStreamRequest>
+                    for StatusStreamSvc<T> {
+                        type Response = super::StatusStreamResponse;
+                        type ResponseStream = T::StatusStreamStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StatusStreamRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::status_stream(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = StatusStreamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/Notes" => {
+                    #[allow(non_camel_case_types)]
+                    struct NotesSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<super::NotesRequest>
+                    for NotesSvc<T> {
+                        type Response = super::NotesResponse;
+                        type ResponseStream = T::NotesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::NotesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::notes(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NotesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/NotesForVoting" => {
+                    #[allow(non_camel_case_types)]
+                    struct NotesForVotingSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<super::NotesForVotingRequest>
+                    for NotesForVotingSvc<T> {
+                        type Response = super::NotesForVotingResponse;
+                        type ResponseStream = T::NotesForVotingStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::NotesForVotingRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::notes_for_voting(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NotesForVotingSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/Assets" => {
+                    #[allow(non_camel_case_types)]
+                    struct AssetsSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<super::AssetsRequest>
+                    for AssetsSvc<T> {
+                        type Response = super::AssetsResponse;
+                        type ResponseStream = T::AssetsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AssetsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::assets(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AssetsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/AssetMetadataById" => {
+                    #[allow(non_camel_case_types)]
+                    struct AssetMetadataByIdSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::AssetMetadataByIdRequest>
+                    for AssetMetadataByIdSvc<T> {
+                        type Response = super::AssetMetadataByIdResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AssetMetadataByIdRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::asset_metadata_by_id(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AssetMetadataByIdSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/AppParameters" => {
+                    #[allow(non_camel_case_types)]
+                    struct AppParametersSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::AppParametersRequest>
+                    for AppParametersSvc<T> {
+                        type Response = super::AppParametersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AppParametersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::app_parameters(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AppParametersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/GasPrices" => {
+                    #[allow(non_camel_case_types)]
+                    struct GasPricesSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::GasPricesRequest>
+                    for GasPricesSvc<T> {
+                        type Response = super::GasPricesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GasPricesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::gas_prices(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GasPricesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/FMDParameters" => {
+                    #[allow(non_camel_case_types)]
+                    struct FMDParametersSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::FmdParametersRequest>
+                    for FMDParametersSvc<T> {
+                        type Response = super::FmdParametersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FmdParametersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::fmd_parameters(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = FMDParametersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/AddressByIndex" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddressByIndexSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::AddressByIndexRequest>
+                    for AddressByIndexSvc<T> {
+                        type Response = super::AddressByIndexResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddressByIndexRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::address_by_index(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AddressByIndexSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/WalletId" => {
+                    #[allow(non_camel_case_types)]
+                    struct WalletIdSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::StatusRequest>
-                    for StatusSvc<T> {
-                        type Response = super::StatusResponse;
+                    > tonic::server::UnaryService<super::WalletIdRequest>
+                    for WalletIdSvc<T> {
+                        type Response = super::WalletIdResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::StatusRequest>,
+                            request: tonic::Request<super::WalletIdRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::status(&inner, request).await
+                                <T as ViewService>::wallet_id(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3161,7 +4824,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = StatusSvc(inner);
+                        let method = WalletIdSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3177,26 +4840,25 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/StatusStream" => {
+                "/penumbra.view.v1.ViewService/IndexByAddress" => {
                     #[allow(non_camel_case_types)]
-                    struct StatusStreamSvc<T: ViewService>(pub Arc<T>);
+                    struct IndexByAddressSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<super::StatusStreamRequest>
-                    for StatusStreamSvc<T> {
-                        type Response = super::StatusStreamResponse;
-                        type ResponseStream = T::StatusStreamStream;
+                    > tonic::server::UnaryService<super::IndexByAddressRequest>
+                    for IndexByAddressSvc<T> {
+                        type Response = super::IndexByAddressResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::StatusStreamRequest>,
+                            request: tonic::Request<super::IndexByAddressRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::status_stream(&inner, request).await
+                                <T as ViewService>::index_by_address(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3208,7 +4870,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = StatusStreamSvc(inner);
+                        let method = IndexByAddressSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3219,31 +4881,30 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/Notes" => {
+                "/penumbra.view.v1.ViewService/EphemeralAddress" => {
                     #[allow(non_camel_case_types)]
-                    struct NotesSvc<T: ViewService>(pub Arc<T>);
+                    struct EphemeralAddressSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<super::NotesRequest>
-                    for NotesSvc<T> {
-                        type Response = super::NotesResponse;
-                        type ResponseStream = T::NotesStream;
+                    > tonic::server::UnaryService<super::EphemeralAddressRequest>
+                    for EphemeralAddressSvc<T> {
+                        type Response = super::EphemeralAddressResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::NotesRequest>,
+                            request: tonic::Request<super::EphemeralAddressRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::notes(&inner, request).await
+                                <T as ViewService>::ephemeral_address(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3255,7 +4916,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = NotesSvc(inner);
+                        let method = EphemeralAddressSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3266,31 +4927,31 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/NotesForVoting" => {
+                "/penumbra.view.v1.ViewService/Balances" => {
                     #[allow(non_camel_case_types)]
-                    struct NotesForVotingSvc<T: ViewService>(pub Arc<T>);
+                    struct BalancesSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<super::NotesForVotingRequest>
-                    for NotesForVotingSvc<T> {
-                        type Response = super::NotesForVotingResponse;
-                        type ResponseStream = T::NotesForVotingStream;
+                    > tonic::server::ServerStreamingService<super::BalancesRequest>
+                    for BalancesSvc<T> {
+                        type Response = super::BalancesResponse;
+                        type ResponseStream = T::BalancesStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::NotesForVotingRequest>,
+                            request: tonic::Request<super::BalancesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::notes_for_voting(&inner, request).await
+                                <T as ViewService>::balances(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3302,7 +4963,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = NotesForVotingSvc(inner);
+                        let method = BalancesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3318,26 +4979,120 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/Assets" => {
+                "/penumbra.view.v1.ViewService/NoteByCommitment" => {
                     #[allow(non_camel_case_types)]
-                    struct AssetsSvc<T: ViewService>(pub Arc<T>);
+                    struct NoteByCommitmentSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<super::AssetsRequest>
-                    for AssetsSvc<T> {
-                        type Response = super::AssetsResponse;
-                        type ResponseStream = T::AssetsStream;
+                    > tonic::server::UnaryService<super::NoteByCommitmentRequest>
+                    for NoteByCommitmentSvc<T> {
+                        type Response = super::NoteByCommitmentResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::NoteByCommitmentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::note_by_commitment(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NoteByCommitmentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/SwapByCommitment" => {
+                    #[allow(non_camel_case_types)]
+                    struct SwapByCommitmentSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::SwapByCommitmentRequest>
+                    for SwapByCommitmentSvc<T> {
+                        type Response = super::SwapByCommitmentResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SwapByCommitmentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::swap_by_commitment(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SwapByCommitmentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/UnclaimedSwaps" => {
+                    #[allow(non_camel_case_types)]
+                    struct UnclaimedSwapsSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<super::UnclaimedSwapsRequest>
+                    for UnclaimedSwapsSvc<T> {
+                        type Response = super::UnclaimedSwapsResponse;
+                        type ResponseStream = T::UnclaimedSwapsStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AssetsRequest>,
+                            request: tonic::Request<super::UnclaimedSwapsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::assets(&inner, request).await
+                                <T as ViewService>::unclaimed_swaps(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3349,7 +5104,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AssetsSvc(inner);
+                        let method = UnclaimedSwapsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3365,26 +5120,25 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/AssetMetadataById" => {
+                "/penumbra.view.v1.ViewService/NullifierStatus" => {
                     #[allow(non_camel_case_types)]
-                    struct AssetMetadataByIdSvc<T: ViewService>(pub Arc<T>);
+                    struct NullifierStatusSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::AssetMetadataByIdRequest>
-                    for AssetMetadataByIdSvc<T> {
-                        type Response = super::AssetMetadataByIdResponse;
+                    > tonic::server::UnaryService<super::NullifierStatusRequest>
+                    for NullifierStatusSvc<T> {
+                        type Response = super::NullifierStatusResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AssetMetadataByIdRequest>,
+                            request: tonic::Request<super::NullifierStatusRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::asset_metadata_by_id(&inner, request)
-                                    .await
+                                <T as ViewService>::nullifier_status(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3396,7 +5150,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AssetMetadataByIdSvc(inner);
+                        let method = NullifierStatusSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3412,25 +5166,29 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/AppParameters" => {
+                "/penumbra.view.v1.ViewService/TransactionInfoByHash" => {
                     #[allow(non_camel_case_types)]
-                    struct AppParametersSvc<T: ViewService>(pub Arc<T>);
+                    struct TransactionInfoByHashSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::AppParametersRequest>
-                    for AppParametersSvc<T> {
-                        type Response = super::AppParametersResponse;
+                    > tonic::server::UnaryService<super::TransactionInfoByHashRequest>
+                    for TransactionInfoByHashSvc<T> {
+                        type Response = super::TransactionInfoByHashResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AppParametersRequest>,
+                            request: tonic::Request<super::TransactionInfoByHashRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::app_parameters(&inner, request).await
+                                <T as ViewService>::transaction_info_by_hash(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -3442,7 +5200,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AppParametersSvc(inner);
+                        let method = TransactionInfoByHashSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3458,25 +5216,27 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/GasPrices" => {
+                "/penumbra.view.v1.ViewService/TransactionInfo" => {
                     #[allow(non_camel_case_types)]
-                    struct GasPricesSvc<T: ViewService>(pub Arc<T>);
+                    struct TransactionInfoSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::GasPricesRequest>
-                    for GasPricesSvc<T> {
-                        type Response = super::GasPricesResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::TransactionInfoRequest,
+                    > for TransactionInfoSvc<T> {
+                        type Response = super::TransactionInfoResponse;
+                        type ResponseStream = T::TransactionInfoStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GasPricesRequest>,
+                            request: tonic::Request<super::TransactionInfoRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::gas_prices(&inner, request).await
+                                <T as ViewService>::transaction_info(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3488,7 +5248,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = GasPricesSvc(inner);
+                        let method = TransactionInfoSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3499,30 +5259,33 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/FMDParameters" => {
+                "/penumbra.view.v1.ViewService/OwnedPositionIds" => {
                     #[allow(non_camel_case_types)]
-                    struct FMDParametersSvc<T: ViewService>(pub Arc<T>);
+                    struct OwnedPositionIdsSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::FmdParametersRequest>
-                    for FMDParametersSvc<T> {
-                        type Response = super::FmdParametersResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::OwnedPositionIdsRequest,
+                    > for OwnedPositionIdsSvc<T> {
+                        type Response = super::OwnedPositionIdsResponse;
+                        type ResponseStream = T::OwnedPositionIdsStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::FmdParametersRequest>,
+                            request: tonic::Request<super::OwnedPositionIdsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::fmd_parameters(&inner, request).await
+                                <T as ViewService>::owned_position_ids(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -3534,7 +5297,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = FMDParametersSvc(inner);
+                        let method = OwnedPositionIdsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3545,30 +5308,31 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/AddressByIndex" => {
+                "/penumbra.view.v1.ViewService/TransactionPlanner" => {
                     #[allow(non_camel_case_types)]
-                    struct AddressByIndexSvc<T: ViewService>(pub Arc<T>);
+                    struct TransactionPlannerSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::AddressByIndexRequest>
-                    for AddressByIndexSvc<T> {
-                        type Response = super::AddressByIndexResponse;
+                    > tonic::server::UnaryService<super::TransactionPlannerRequest>
+                    for TransactionPlannerSvc<T> {
+                        type Response = super::TransactionPlannerResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AddressByIndexRequest>,
+                            request: tonic::Request<super::TransactionPlannerRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::address_by_index(&inner, request).await
+                                <T as ViewService>::transaction_planner(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -3580,7 +5344,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AddressByIndexSvc(inner);
+                        let method = TransactionPlannerSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3596,25 +5360,25 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/WalletId" => {
+                "/penumbra.view.v1.ViewService/Witness" => {
                     #[allow(non_camel_case_types)]
-                    struct WalletIdSvc<T: ViewService>(pub Arc<T>);
+                    struct WitnessSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::WalletIdRequest>
-                    for WalletIdSvc<T> {
-                        type Response = super::WalletIdResponse;
+                    > tonic::server::UnaryService<super::WitnessRequest>
+                    for WitnessSvc<T> {
+                        type Response = super::WitnessResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::WalletIdRequest>,
+                            request: tonic::Request<super::WitnessRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::wallet_id(&inner, request).await
+                                <T as ViewService>::witness(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3626,7 +5390,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = WalletIdSvc(inner);
+                        let method = WitnessSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3642,25 +5406,27 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/IndexByAddress" => {
+                "/penumbra.view.v1.ViewService/WitnessAndBuild" => {
                     #[allow(non_camel_case_types)]
-                    struct IndexByAddressSvc<T: ViewService>(pub Arc<T>);
+                    struct WitnessAndBuildSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::IndexByAddressRequest>
-                    for IndexByAddressSvc<T> {
-                        type Response = super::IndexByAddressResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::WitnessAndBuildRequest,
+                    > for WitnessAndBuildSvc<T> {
+                        type Response = super::WitnessAndBuildResponse;
+                        type ResponseStream = T::WitnessAndBuildStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::IndexByAddressRequest>,
+                            request: tonic::Request<super::WitnessAndBuildRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::index_by_address(&inner, request).await
+                                <T as ViewService>::witness_and_build(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3672,7 +5438,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = IndexByAddressSvc(inner);
+                        let method = WitnessAndBuildSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3683,30 +5449,33 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/EphemeralAddress" => {
+                "/penumbra.view.v1.ViewService/AuthorizeAndBuild" => {
                     #[allow(non_camel_case_types)]
-                    struct EphemeralAddressSvc<T: ViewService>(pub Arc<T>);
+                    struct AuthorizeAndBuildSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::EphemeralAddressRequest>
-                    for EphemeralAddressSvc<T> {
-                        type Response = super::EphemeralAddressResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::AuthorizeAndBuildRequest,
+                    > for AuthorizeAndBuildSvc<T> {
+                        type Response = super::AuthorizeAndBuildResponse;
+                        type ResponseStream = T::AuthorizeAndBuildStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::EphemeralAddressRequest>,
+                            request: tonic::Request<super::AuthorizeAndBuildRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::ephemeral_address(&inner, request).await
+                                <T as ViewService>::authorize_and_build(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -3718,7 +5487,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = EphemeralAddressSvc(inner);
+                        let method = AuthorizeAndBuildSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3729,31 +5498,33 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/Balances" => {
+                "/penumbra.view.v1.ViewService/BroadcastTransaction" => {
                     #[allow(non_camel_case_types)]
-                    struct BalancesSvc<T: ViewService>(pub Arc<T>);
+                    struct BroadcastTransactionSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<super::BalancesRequest>
-                    for BalancesSvc<T> {
-                        type Response = super::BalancesResponse;
-                        type ResponseStream = T::BalancesStream;
+                    > tonic::server::ServerStreamingService<
+                        super::BroadcastTransactionRequest,
+                    > for BroadcastTransactionSvc<T> {
+                        type Response = super::BroadcastTransactionResponse;
+                        type ResponseStream = T::BroadcastTransactionStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BalancesRequest>,
+                            request: tonic::Request<super::BroadcastTransactionRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::balances(&inner, request).await
+                                <T as ViewService>::broadcast_transaction(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -3765,7 +5536,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = BalancesSvc(inner);
+                        let method = BroadcastTransactionSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3781,25 +5552,32 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/NoteByCommitment" => {
+                "/penumbra.view.v1.ViewService/DelegationsByAddressIndex" => {
                     #[allow(non_camel_case_types)]
-                    struct NoteByCommitmentSvc<T: ViewService>(pub Arc<T>);
+                    struct DelegationsByAddressIndexSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::NoteByCommitmentRequest>
-                    for NoteByCommitmentSvc<T> {
-                        type Response = super::NoteByCommitmentResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::DelegationsByAddressIndexRequest,
+                    > for DelegationsByAddressIndexSvc<T> {
+                        type Response = super::DelegationsByAddressIndexResponse;
+                        type ResponseStream = T::DelegationsByAddressIndexStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::NoteByCommitmentRequest>,
+                            request: tonic::Request<
+                                super::DelegationsByAddressIndexRequest,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::note_by_commitment(&inner, request)
+                                <T as ViewService>::delegations_by_address_index(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -3812,7 +5590,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = NoteByCommitmentSvc(inner);
+                        let method = DelegationsByAddressIndexSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3823,30 +5601,37 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/SwapByCommitment" => {
+                "/penumbra.view.v1.ViewService/UnbondingTokensByAddressIndex" => {
                     #[allow(non_camel_case_types)]
-                    struct SwapByCommitmentSvc<T: ViewService>(pub Arc<T>);
+                    struct UnbondingTokensByAddressIndexSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::SwapByCommitmentRequest>
-                    for SwapByCommitmentSvc<T> {
-                        type Response = super::SwapByCommitmentResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::UnbondingTokensByAddressIndexRequest,
+                    > for UnbondingTokensByAddressIndexSvc<T> {
+                        type Response = super::UnbondingTokensByAddressIndexResponse;
+                        type ResponseStream = T::UnbondingTokensByAddressIndexStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SwapByCommitmentRequest>,
+                            request: tonic::Request<
+                                super::UnbondingTokensByAddressIndexRequest,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::swap_by_commitment(&inner, request)
+                                <T as ViewService>::unbonding_tokens_by_address_index(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -3859,7 +5644,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = SwapByCommitmentSvc(inner);
+                        let method = UnbondingTokensByAddressIndexSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3870,31 +5655,31 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/UnclaimedSwaps" => {
+                "/penumbra.view.v1.ViewService/Auctions" => {
                     #[allow(non_camel_case_types)]
-                    struct UnclaimedSwapsSvc<T: ViewService>(pub Arc<T>);
+                    struct AuctionsSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<super::UnclaimedSwapsRequest>
-                    for UnclaimedSwapsSvc<T> {
-                        type Response = super::UnclaimedSwapsResponse;
-                        type ResponseStream = T::UnclaimedSwapsStream;
+                    > tonic::server::ServerStreamingService<super::AuctionsRequest>
+                    for AuctionsSvc<T> {
+                        type Response = super::AuctionsResponse;
+                        type ResponseStream = T::AuctionsStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UnclaimedSwapsRequest>,
+                            request: tonic::Request<super::AuctionsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::unclaimed_swaps(&inner, request).await
+                                <T as ViewService>::auctions(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3906,7 +5691,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = UnclaimedSwapsSvc(inner);
+                        let method = AuctionsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3922,25 +5707,26 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/NullifierStatus" => {
+                "/penumbra.view.v1.ViewService/WalletEvents" => {
                     #[allow(non_camel_case_types)]
-                    struct NullifierStatusSvc<T: ViewService>(pub Arc<T>);
+                    struct WalletEventsSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::NullifierStatusRequest>
-                    for NullifierStatusSvc<T> {
-                        type Response = super::NullifierStatusResponse;
+                    > tonic::server::ServerStreamingService<super::WalletEventsRequest>
+                    for WalletEventsSvc<T> {
+                        type Response = super::WalletEventsResponse;
+                        type ResponseStream = T::WalletEventsStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::NullifierStatusRequest>,
+                            request: tonic::Request<super::WalletEventsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::nullifier_status(&inner, request).await
+                                <T as ViewService>::wallet_events(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -3952,7 +5738,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = NullifierStatusSvc(inner);
+                        let method = WalletEventsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3963,33 +5749,32 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/TransactionInfoByHash" => {
+                "/penumbra.view.v1.ViewService/BatchSwapOutputs" => {
                     #[allow(non_camel_case_types)]
-                    struct TransactionInfoByHashSvc<T: ViewService>(pub Arc<T>);
+                    struct BatchSwapOutputsSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::TransactionInfoByHashRequest>
-                    for TransactionInfoByHashSvc<T> {
-                        type Response = super::TransactionInfoByHashResponse;
+                    > tonic::server::ServerStreamingService<
+                        super::BatchSwapOutputsRequest,
+                    > for BatchSwapOutputsSvc<T> {
+                        type Response = super::BatchSwapOutputsResponse;
+                        type ResponseStream = T::BatchSwapOutputsStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::TransactionInfoByHashRequest>,
+                            request: tonic::Request<super::BatchSwapOutputsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::transaction_info_by_hash(
-                                        &inner,
-                                        request,
-                                    )
+                                <T as ViewService>::batch_swap_outputs(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -4002,7 +5787,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = TransactionInfoByHashSvc(inner);
+                        let method = BatchSwapOutputsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4013,32 +5798,31 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/TransactionInfo" => {
+                "/penumbra.view.v1.ViewService/Contacts" => {
                     #[allow(non_camel_case_types)]
-                    struct TransactionInfoSvc<T: ViewService>(pub Arc<T>);
+                    struct ContactsSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<
-                        super::TransactionInfoRequest,
-                    > for TransactionInfoSvc<T> {
-                        type Response = super::TransactionInfoResponse;
-                        type ResponseStream = T::TransactionInfoStream;
+                    > tonic::server::ServerStreamingService<super::ContactsRequest>
+                    for ContactsSvc<T> {
+                        type Response = super::ContactsResponse;
+                        type ResponseStream = T::ContactsStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::TransactionInfoRequest>,
+                            request: tonic::Request<super::ContactsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::transaction_info(&inner, request).await
+                                <T as ViewService>::contacts(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -4050,7 +5834,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = TransactionInfoSvc(inner);
+                        let method = ContactsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4066,28 +5850,25 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/OwnedPositionIds" => {
+                "/penumbra.view.v1.ViewService/SetContact" => {
                     #[allow(non_camel_case_types)]
-                    struct OwnedPositionIdsSvc<T: ViewService>(pub Arc<T>);
+                    struct SetContactSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<
-                        super::OwnedPositionIdsRequest,
-                    > for OwnedPositionIdsSvc<T> {
-                        type Response = super::OwnedPositionIdsResponse;
-                        type ResponseStream = T::OwnedPositionIdsStream;
+                    > tonic::server::UnaryService<super::SetContactRequest>
+                    for SetContactSvc<T> {
+                        type Response = super::SetContactResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::OwnedPositionIdsRequest>,
+                            request: tonic::Request<super::SetContactRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::owned_position_ids(&inner, request)
-                                    .await
+                                <T as ViewService>::set_contact(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -4099,7 +5880,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = OwnedPositionIdsSvc(inner);
+                        let method = SetContactSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4110,31 +5891,30 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/TransactionPlanner" => {
+                "/penumbra.view.v1.ViewService/DeleteContact" => {
                     #[allow(non_camel_case_types)]
-                    struct TransactionPlannerSvc<T: ViewService>(pub Arc<T>);
+                    struct DeleteContactSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::TransactionPlannerRequest>
-                    for TransactionPlannerSvc<T> {
-                        type Response = super::TransactionPlannerResponse;
+                    > tonic::server::UnaryService<super::DeleteContactRequest>
+                    for DeleteContactSvc<T> {
+                        type Response = super::DeleteContactResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::TransactionPlannerRequest>,
+                            request: tonic::Request<super::DeleteContactRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::transaction_planner(&inner, request)
-                                    .await
+                                <T as ViewService>::delete_contact(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -4146,7 +5926,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = TransactionPlannerSvc(inner);
+                        let method = DeleteContactSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4162,25 +5942,26 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/Witness" => {
+                "/penumbra.view.v1.ViewService/AccountLabels" => {
                     #[allow(non_camel_case_types)]
-                    struct WitnessSvc<T: ViewService>(pub Arc<T>);
+                    struct AccountLabelsSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::UnaryService<super::WitnessRequest>
-                    for WitnessSvc<T> {
-                        type Response = super::WitnessResponse;
+                    > tonic::server::ServerStreamingService<super::AccountLabelsRequest>
+                    for AccountLabelsSvc<T> {
+                        type Response = super::AccountLabelsResponse;
+                        type ResponseStream = T::AccountLabelsStream;
                         type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
+                            tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::WitnessRequest>,
+                            request: tonic::Request<super::AccountLabelsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::witness(&inner, request).await
+                                <T as ViewService>::account_labels(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -4192,7 +5973,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = WitnessSvc(inner);
+                        let method = AccountLabelsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4203,32 +5984,30 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.unary(method, req).await;
+                        let res = grpc.server_streaming(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/WitnessAndBuild" => {
+                "/penumbra.view.v1.ViewService/SetAccountLabel" => {
                     #[allow(non_camel_case_types)]
-                    struct WitnessAndBuildSvc<T: ViewService>(pub Arc<T>);
+                    struct SetAccountLabelSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<
-                        super::WitnessAndBuildRequest,
-                    > for WitnessAndBuildSvc<T> {
-                        type Response = super::WitnessAndBuildResponse;
-                        type ResponseStream = T::WitnessAndBuildStream;
+                    > tonic::server::UnaryService<super::SetAccountLabelRequest>
+                    for SetAccountLabelSvc<T> {
+                        type Response = super::SetAccountLabelResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::WitnessAndBuildRequest>,
+                            request: tonic::Request<super::SetAccountLabelRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::witness_and_build(&inner, request).await
+                                <T as ViewService>::set_account_label(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -4240,7 +6019,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = WitnessAndBuildSvc(inner);
+                        let method = SetAccountLabelSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4251,32 +6030,30 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/AuthorizeAndBuild" => {
+                "/penumbra.view.v1.ViewService/DeleteAccountLabel" => {
                     #[allow(non_camel_case_types)]
-                    struct AuthorizeAndBuildSvc<T: ViewService>(pub Arc<T>);
+                    struct DeleteAccountLabelSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<
-                        super::AuthorizeAndBuildRequest,
-                    > for AuthorizeAndBuildSvc<T> {
-                        type Response = super::AuthorizeAndBuildResponse;
-                        type ResponseStream = T::AuthorizeAndBuildStream;
+                    > tonic::server::UnaryService<super::DeleteAccountLabelRequest>
+                    for DeleteAccountLabelSvc<T> {
+                        type Response = super::DeleteAccountLabelResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AuthorizeAndBuildRequest>,
+                            request: tonic::Request<super::DeleteAccountLabelRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::authorize_and_build(&inner, request)
+                                <T as ViewService>::delete_account_label(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -4289,7 +6066,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AuthorizeAndBuildSvc(inner);
+                        let method = DeleteAccountLabelSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4300,33 +6077,30 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/BroadcastTransaction" => {
+                "/penumbra.view.v1.ViewService/CreateInvoice" => {
                     #[allow(non_camel_case_types)]
-                    struct BroadcastTransactionSvc<T: ViewService>(pub Arc<T>);
+                    struct CreateInvoiceSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<
-                        super::BroadcastTransactionRequest,
-                    > for BroadcastTransactionSvc<T> {
-                        type Response = super::BroadcastTransactionResponse;
-                        type ResponseStream = T::BroadcastTransactionStream;
+                    > tonic::server::UnaryService<super::CreateInvoiceRequest>
+                    for CreateInvoiceSvc<T> {
+                        type Response = super::CreateInvoiceResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::BroadcastTransactionRequest>,
+                            request: tonic::Request<super::CreateInvoiceRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::broadcast_transaction(&inner, request)
-                                    .await
+                                <T as ViewService>::create_invoice(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -4338,7 +6112,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = BroadcastTransactionSvc(inner);
+                        let method = CreateInvoiceSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4349,38 +6123,31 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/DelegationsByAddressIndex" => {
+                "/penumbra.view.v1.ViewService/Invoices" => {
                     #[allow(non_camel_case_types)]
-                    struct DelegationsByAddressIndexSvc<T: ViewService>(pub Arc<T>);
+                    struct InvoicesSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<
-                        super::DelegationsByAddressIndexRequest,
-                    > for DelegationsByAddressIndexSvc<T> {
-                        type Response = super::DelegationsByAddressIndexResponse;
-                        type ResponseStream = T::DelegationsByAddressIndexStream;
+                    > tonic::server::ServerStreamingService<super::InvoicesRequest>
+                    for InvoicesSvc<T> {
+                        type Response = super::InvoicesResponse;
+                        type ResponseStream = T::InvoicesStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                super::DelegationsByAddressIndexRequest,
-                            >,
+                            request: tonic::Request<super::InvoicesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::delegations_by_address_index(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
+                                <T as ViewService>::invoices(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -4392,7 +6159,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = DelegationsByAddressIndexSvc(inner);
+                        let method = InvoicesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4408,33 +6175,25 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/UnbondingTokensByAddressIndex" => {
+                "/penumbra.view.v1.ViewService/DeleteInvoice" => {
                     #[allow(non_camel_case_types)]
-                    struct UnbondingTokensByAddressIndexSvc<T: ViewService>(pub Arc<T>);
+                    struct DeleteInvoiceSvc<T: ViewService>(pub Arc<T>);
                     impl<
                         T: ViewService,
-                    > tonic::server::ServerStreamingService<
-                        super::UnbondingTokensByAddressIndexRequest,
-                    > for UnbondingTokensByAddressIndexSvc<T> {
-                        type Response = super::UnbondingTokensByAddressIndexResponse;
-                        type ResponseStream = T::UnbondingTokensByAddressIndexStream;
+                    > tonic::server::UnaryService<super::DeleteInvoiceRequest>
+                    for DeleteInvoiceSvc<T> {
+                        type Response = super::DeleteInvoiceResponse;
                         type Future = BoxFuture<
-                            tonic::Response<Self::ResponseStream>,
+                            tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<
-                                super::UnbondingTokensByAddressIndexRequest,
-                            >,
+                            request: tonic::Request<super::DeleteInvoiceRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::unbonding_tokens_by_address_index(
-                                        &inner,
-                                        request,
-                                    )
-                                    .await
+                                <T as ViewService>::delete_invoice(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -4446,7 +6205,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = UnbondingTokensByAddressIndexSvc(inner);
+                        let method = DeleteInvoiceSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4457,31 +6216,201 @@ pub mod view_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
                 }
-                "/penumbra.view.v1.ViewService/Auctions" => {
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: ViewService> Clone for ViewServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: ViewService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: ViewService> tonic::server::NamedService for ViewServiceServer<T> {
+        const NAME: &'static str = "penumbra.view.v1.ViewService";
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "rpc")]
+pub mod detection_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with DetectionServiceServer.
+    #[async_trait]
+    pub trait DetectionService: Send + Sync + 'static {
+        /// Server streaming response type for the FilteredCompactBlockRange method.
+        type FilteredCompactBlockRangeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::FilteredCompactBlockRangeResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Stream the compact blocks in a range, with the notes whose clues match
+        /// none of the given detection keys rolled up into their commitments.
+        ///
+        /// Blocks where none of the state payloads were detected are streamed without
+        /// them, since their block root is enough for the view service to advance its
+        /// state commitment tree. Their nullifiers are kept, since spends can't be
+        /// detected.
+        ///
+        /// Only notes created by transactions carry clues, so every other payload is
+        /// left as it is. Notes sent to one-time addresses can't be detected, since
+        /// each one-time address has its own detection key.
+        async fn filtered_compact_block_range(
+            &self,
+            request: tonic::Request<super::FilteredCompactBlockRangeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::FilteredCompactBlockRangeStream>,
+            tonic::Status,
+        >;
+    }
+    /// A service which detects the notes sent to a user, using the fuzzy message
+    /// detection keys of their addresses, so that the user's view service only
+    /// needs to trial-decrypt the notes which might be theirs.
+    ///
+    /// Detection is probabilistic: as well as every note sent to the registered
+    /// addresses, the service detects a fraction of other notes set by the chain's
+    /// FMD parameters, so it can't tell exactly which notes are the user's.
+    #[derive(Debug)]
+    pub struct DetectionServiceServer<T: DetectionService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: DetectionService> DetectionServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for DetectionServiceServer<T>
+    where
+        T: DetectionService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/penumbra.view.v1.DetectionService/FilteredCompactBlockRange" => {
                     #[allow(non_camel_case_types)]
-                    struct AuctionsSvc<T: ViewService>(pub Arc<T>);
+                    struct FilteredCompactBlockRangeSvc<T: DetectionService>(pub Arc<T>);
                     impl<
-                        T: ViewService,
-                    > tonic::server::ServerStreamingService<super::AuctionsRequest>
-                    for AuctionsSvc<T> {
-                        type Response = super::AuctionsResponse;
-                        type ResponseStream = T::AuctionsStream;
+                        T: DetectionService,
+                    > tonic::server::ServerStreamingService<
+                        super::FilteredCompactBlockRangeRequest,
+                    > for FilteredCompactBlockRangeSvc<T> {
+                        type Response = super::FilteredCompactBlockRangeResponse;
+                        type ResponseStream = T::FilteredCompactBlockRangeStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AuctionsRequest>,
+                            request: tonic::Request<
+                                super::FilteredCompactBlockRangeRequest,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ViewService>::auctions(&inner, request).await
+                                <T as DetectionService>::filtered_compact_block_range(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -4493,7 +6422,7 @@ pub mod view_service_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = AuctionsSvc(inner);
+                        let method = FilteredCompactBlockRangeSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -4524,7 +6453,7 @@ pub mod view_service_server {
             }
         }
     }
-    impl<T: ViewService> Clone for ViewServiceServer<T> {
+    impl<T: DetectionService> Clone for DetectionServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -4536,7 +6465,7 @@ pub mod view_service_server {
             }
         }
     }
-    impl<T: ViewService> Clone for _Inner<T> {
+    impl<T: DetectionService> Clone for _Inner<T> {
         fn clone(&self) -> Self {
             Self(Arc::clone(&self.0))
         }
@@ -4546,7 +6475,7 @@ pub mod view_service_server {
             write!(f, "{:?}", self.0)
         }
     }
-    impl<T: ViewService> tonic::server::NamedService for ViewServiceServer<T> {
-        const NAME: &'static str = "penumbra.view.v1.ViewService";
+    impl<T: DetectionService> tonic::server::NamedService for DetectionServiceServer<T> {
+        const NAME: &'static str = "penumbra.view.v1.DetectionService";
     }
 }
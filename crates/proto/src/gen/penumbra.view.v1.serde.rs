@@ -1,3 +1,285 @@
+impl serde::Serialize for AccountLabel {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.account != 0 {
+            len += 1;
+        }
+        if !self.label.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.AccountLabel", len)?;
+        if self.account != 0 {
+            struct_ser.serialize_field("account", &self.account)?;
+        }
+        if !self.label.is_empty() {
+            struct_ser.serialize_field("label", &self.label)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AccountLabel {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "account",
+            "label",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Account,
+            Label,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "account" => Ok(GeneratedField::Account),
+                            "label" => Ok(GeneratedField::Label),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AccountLabel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.AccountLabel")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AccountLabel, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut account__ = None;
+                let mut label__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Account => {
+                            if account__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("account"));
+                            }
+                            account__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Label => {
+                            if label__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("label"));
+                            }
+                            label__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AccountLabel {
+                    account: account__.unwrap_or_default(),
+                    label: label__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.AccountLabel", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AccountLabelsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.AccountLabelsRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AccountLabelsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AccountLabelsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.AccountLabelsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AccountLabelsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(AccountLabelsRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.AccountLabelsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AccountLabelsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.account_label.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.AccountLabelsResponse", len)?;
+        if let Some(v) = self.account_label.as_ref() {
+            struct_ser.serialize_field("accountLabel", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AccountLabelsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "account_label",
+            "accountLabel",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AccountLabel,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "accountLabel" | "account_label" => Ok(GeneratedField::AccountLabel),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AccountLabelsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.AccountLabelsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AccountLabelsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut account_label__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AccountLabel => {
+                            if account_label__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountLabel"));
+                            }
+                            account_label__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AccountLabelsResponse {
+                    account_label: account_label__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.AccountLabelsResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for AddressByIndexRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1829,7 +2111,7 @@ impl<'de> serde::Deserialize<'de> for BalancesResponse {
         deserializer.deserialize_struct("penumbra.view.v1.BalancesResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for BroadcastTransactionRequest {
+impl serde::Serialize for BatchSwapOutputsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1837,38 +2119,253 @@ impl serde::Serialize for BroadcastTransactionRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.transaction.is_some() {
+        if self.start_height != 0 {
             len += 1;
         }
-        if self.await_detection {
+        if self.end_height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BroadcastTransactionRequest", len)?;
-        if let Some(v) = self.transaction.as_ref() {
-            struct_ser.serialize_field("transaction", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BatchSwapOutputsRequest", len)?;
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
         }
-        if self.await_detection {
-            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for BroadcastTransactionRequest {
+impl<'de> serde::Deserialize<'de> for BatchSwapOutputsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "transaction",
-            "await_detection",
-            "awaitDetection",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Transaction,
-            AwaitDetection,
+            StartHeight,
+            EndHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchSwapOutputsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.BatchSwapOutputsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchSwapOutputsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BatchSwapOutputsRequest {
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.BatchSwapOutputsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BatchSwapOutputsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BatchSwapOutputsResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BatchSwapOutputsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Data,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "data" => Ok(GeneratedField::Data),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BatchSwapOutputsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.BatchSwapOutputsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BatchSwapOutputsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BatchSwapOutputsResponse {
+                    data: data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.BatchSwapOutputsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BroadcastTransactionRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.transaction.is_some() {
+            len += 1;
+        }
+        if self.await_detection {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BroadcastTransactionRequest", len)?;
+        if let Some(v) = self.transaction.as_ref() {
+            struct_ser.serialize_field("transaction", v)?;
+        }
+        if self.await_detection {
+            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BroadcastTransactionRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "transaction",
+            "await_detection",
+            "awaitDetection",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Transaction,
+            AwaitDetection,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2267,7 +2764,7 @@ impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::Confirmed
         deserializer.deserialize_struct("penumbra.view.v1.BroadcastTransactionResponse.Confirmed", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DelegationsByAddressIndexRequest {
+impl serde::Serialize for Contact {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2275,40 +2772,37 @@ impl serde::Serialize for DelegationsByAddressIndexRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address_index.is_some() {
+        if self.address.is_some() {
             len += 1;
         }
-        if self.filter != 0 {
+        if !self.label.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DelegationsByAddressIndexRequest", len)?;
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.Contact", len)?;
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
         }
-        if self.filter != 0 {
-            let v = delegations_by_address_index_request::Filter::try_from(self.filter)
-                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.filter)))?;
-            struct_ser.serialize_field("filter", &v)?;
+        if !self.label.is_empty() {
+            struct_ser.serialize_field("label", &self.label)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for DelegationsByAddressIndexRequest {
+impl<'de> serde::Deserialize<'de> for Contact {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address_index",
-            "addressIndex",
-            "filter",
+            "address",
+            "label",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AddressIndex,
-            Filter,
+            Address,
+            Label,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2331,8 +2825,8 @@ impl<'de> serde::Deserialize<'de> for DelegationsByAddressIndexRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
-                            "filter" => Ok(GeneratedField::Filter),
+                            "address" => Ok(GeneratedField::Address),
+                            "label" => Ok(GeneratedField::Label),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2342,152 +2836,69 @@ impl<'de> serde::Deserialize<'de> for DelegationsByAddressIndexRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DelegationsByAddressIndexRequest;
+            type Value = Contact;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.DelegationsByAddressIndexRequest")
+                formatter.write_str("struct penumbra.view.v1.Contact")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegationsByAddressIndexRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Contact, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address_index__ = None;
-                let mut filter__ = None;
+                let mut address__ = None;
+                let mut label__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
                             }
-                            address_index__ = map_.next_value()?;
+                            address__ = map_.next_value()?;
                         }
-                        GeneratedField::Filter => {
-                            if filter__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("filter"));
+                        GeneratedField::Label => {
+                            if label__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("label"));
                             }
-                            filter__ = Some(map_.next_value::<delegations_by_address_index_request::Filter>()? as i32);
+                            label__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(DelegationsByAddressIndexRequest {
-                    address_index: address_index__,
-                    filter: filter__.unwrap_or_default(),
+                Ok(Contact {
+                    address: address__,
+                    label: label__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.DelegationsByAddressIndexRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.Contact", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for delegations_by_address_index_request::Filter {
+impl serde::Serialize for ContactsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let variant = match self {
-            Self::Unspecified => "FILTER_UNSPECIFIED",
-            Self::AllActiveWithNonzeroBalances => "FILTER_ALL_ACTIVE_WITH_NONZERO_BALANCES",
-            Self::All => "FILTER_ALL",
-        };
-        serializer.serialize_str(variant)
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.ContactsRequest", len)?;
+        struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for delegations_by_address_index_request::Filter {
+impl<'de> serde::Deserialize<'de> for ContactsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "FILTER_UNSPECIFIED",
-            "FILTER_ALL_ACTIVE_WITH_NONZERO_BALANCES",
-            "FILTER_ALL",
-        ];
-
-        struct GeneratedVisitor;
-
-        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = delegations_by_address_index_request::Filter;
-
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(formatter, "expected one of: {:?}", &FIELDS)
-            }
-
-            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
-                    })
-            }
-
-            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
-                    })
-            }
-
-            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                match value {
-                    "FILTER_UNSPECIFIED" => Ok(delegations_by_address_index_request::Filter::Unspecified),
-                    "FILTER_ALL_ACTIVE_WITH_NONZERO_BALANCES" => Ok(delegations_by_address_index_request::Filter::AllActiveWithNonzeroBalances),
-                    "FILTER_ALL" => Ok(delegations_by_address_index_request::Filter::All),
-                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
-                }
-            }
-        }
-        deserializer.deserialize_any(GeneratedVisitor)
-    }
-}
-impl serde::Serialize for DelegationsByAddressIndexResponse {
-    #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.value_view.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DelegationsByAddressIndexResponse", len)?;
-        if let Some(v) = self.value_view.as_ref() {
-            struct_ser.serialize_field("valueView", v)?;
-        }
-        struct_ser.end()
-    }
-}
-impl<'de> serde::Deserialize<'de> for DelegationsByAddressIndexResponse {
-    #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        const FIELDS: &[&str] = &[
-            "value_view",
-            "valueView",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ValueView,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2509,10 +2920,7 @@ impl<'de> serde::Deserialize<'de> for DelegationsByAddressIndexResponse {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "valueView" | "value_view" => Ok(GeneratedField::ValueView),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -2520,39 +2928,27 @@ impl<'de> serde::Deserialize<'de> for DelegationsByAddressIndexResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DelegationsByAddressIndexResponse;
+            type Value = ContactsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.DelegationsByAddressIndexResponse")
+                formatter.write_str("struct penumbra.view.v1.ContactsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegationsByAddressIndexResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ContactsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value_view__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::ValueView => {
-                            if value_view__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("valueView"));
-                            }
-                            value_view__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(DelegationsByAddressIndexResponse {
-                    value_view: value_view__,
+                Ok(ContactsRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.DelegationsByAddressIndexResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.ContactsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EphemeralAddressRequest {
+impl serde::Serialize for ContactsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2560,30 +2956,29 @@ impl serde::Serialize for EphemeralAddressRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address_index.is_some() {
+        if self.contact.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.EphemeralAddressRequest", len)?;
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.ContactsResponse", len)?;
+        if let Some(v) = self.contact.as_ref() {
+            struct_ser.serialize_field("contact", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
+impl<'de> serde::Deserialize<'de> for ContactsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address_index",
-            "addressIndex",
+            "contact",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AddressIndex,
+            Contact,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2606,7 +3001,7 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "contact" => Ok(GeneratedField::Contact),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2616,39 +3011,39 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EphemeralAddressRequest;
+            type Value = ContactsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.EphemeralAddressRequest")
+                formatter.write_str("struct penumbra.view.v1.ContactsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EphemeralAddressRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ContactsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address_index__ = None;
+                let mut contact__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                        GeneratedField::Contact => {
+                            if contact__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("contact"));
                             }
-                            address_index__ = map_.next_value()?;
+                            contact__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EphemeralAddressRequest {
-                    address_index: address_index__,
+                Ok(ContactsResponse {
+                    contact: contact__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.EphemeralAddressRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.ContactsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EphemeralAddressResponse {
+impl serde::Serialize for CreateInvoiceRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2656,29 +3051,72 @@ impl serde::Serialize for EphemeralAddressResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address.is_some() {
+        if self.account.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.EphemeralAddressResponse", len)?;
-        if let Some(v) = self.address.as_ref() {
-            struct_ser.serialize_field("address", v)?;
+        if !self.values.is_empty() {
+            len += 1;
+        }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
+        if self.expiry != 0 {
+            len += 1;
+        }
+        if !self.invoice_id.is_empty() {
+            len += 1;
+        }
+        if self.dedicated_address {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.CreateInvoiceRequest", len)?;
+        if let Some(v) = self.account.as_ref() {
+            struct_ser.serialize_field("account", v)?;
+        }
+        if !self.values.is_empty() {
+            struct_ser.serialize_field("values", &self.values)?;
+        }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
+        if self.expiry != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expiry", ToString::to_string(&self.expiry).as_str())?;
+        }
+        if !self.invoice_id.is_empty() {
+            struct_ser.serialize_field("invoiceId", &self.invoice_id)?;
+        }
+        if self.dedicated_address {
+            struct_ser.serialize_field("dedicatedAddress", &self.dedicated_address)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EphemeralAddressResponse {
+impl<'de> serde::Deserialize<'de> for CreateInvoiceRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address",
+            "account",
+            "values",
+            "memo",
+            "expiry",
+            "invoice_id",
+            "invoiceId",
+            "dedicated_address",
+            "dedicatedAddress",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Address,
+            Account,
+            Values,
+            Memo,
+            Expiry,
+            InvoiceId,
+            DedicatedAddress,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2701,7 +3139,12 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "address" => Ok(GeneratedField::Address),
+                            "account" => Ok(GeneratedField::Account),
+                            "values" => Ok(GeneratedField::Values),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "expiry" => Ok(GeneratedField::Expiry),
+                            "invoiceId" | "invoice_id" => Ok(GeneratedField::InvoiceId),
+                            "dedicatedAddress" | "dedicated_address" => Ok(GeneratedField::DedicatedAddress),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2711,61 +3154,112 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EphemeralAddressResponse;
+            type Value = CreateInvoiceRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.EphemeralAddressResponse")
+                formatter.write_str("struct penumbra.view.v1.CreateInvoiceRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EphemeralAddressResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CreateInvoiceRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address__ = None;
+                let mut account__ = None;
+                let mut values__ = None;
+                let mut memo__ = None;
+                let mut expiry__ = None;
+                let mut invoice_id__ = None;
+                let mut dedicated_address__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Address => {
-                            if address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("address"));
+                        GeneratedField::Account => {
+                            if account__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("account"));
                             }
-                            address__ = map_.next_value()?;
+                            account__ = map_.next_value()?;
+                        }
+                        GeneratedField::Values => {
+                            if values__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("values"));
+                            }
+                            values__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Expiry => {
+                            if expiry__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiry"));
+                            }
+                            expiry__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::InvoiceId => {
+                            if invoice_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("invoiceId"));
+                            }
+                            invoice_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DedicatedAddress => {
+                            if dedicated_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dedicatedAddress"));
+                            }
+                            dedicated_address__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EphemeralAddressResponse {
-                    address: address__,
+                Ok(CreateInvoiceRequest {
+                    account: account__,
+                    values: values__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
+                    expiry: expiry__.unwrap_or_default(),
+                    invoice_id: invoice_id__.unwrap_or_default(),
+                    dedicated_address: dedicated_address__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.EphemeralAddressResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.CreateInvoiceRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for FmdParametersRequest {
+impl serde::Serialize for CreateInvoiceResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1.FMDParametersRequest", len)?;
+        let mut len = 0;
+        if self.payment_request.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.CreateInvoiceResponse", len)?;
+        if let Some(v) = self.payment_request.as_ref() {
+            struct_ser.serialize_field("paymentRequest", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for FmdParametersRequest {
+impl<'de> serde::Deserialize<'de> for CreateInvoiceResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "payment_request",
+            "paymentRequest",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            PaymentRequest,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2787,7 +3281,10 @@ impl<'de> serde::Deserialize<'de> for FmdParametersRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "paymentRequest" | "payment_request" => Ok(GeneratedField::PaymentRequest),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -2795,27 +3292,39 @@ impl<'de> serde::Deserialize<'de> for FmdParametersRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = FmdParametersRequest;
+            type Value = CreateInvoiceResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.FMDParametersRequest")
+                formatter.write_str("struct penumbra.view.v1.CreateInvoiceResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FmdParametersRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CreateInvoiceResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut payment_request__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PaymentRequest => {
+                            if payment_request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("paymentRequest"));
+                            }
+                            payment_request__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(FmdParametersRequest {
+                Ok(CreateInvoiceResponse {
+                    payment_request: payment_request__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.FMDParametersRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.CreateInvoiceResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for FmdParametersResponse {
+impl serde::Serialize for DelegationsByAddressIndexRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2823,29 +3332,40 @@ impl serde::Serialize for FmdParametersResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.parameters.is_some() {
+        if self.address_index.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.FMDParametersResponse", len)?;
-        if let Some(v) = self.parameters.as_ref() {
-            struct_ser.serialize_field("parameters", v)?;
+        if self.filter != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DelegationsByAddressIndexRequest", len)?;
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if self.filter != 0 {
+            let v = delegations_by_address_index_request::Filter::try_from(self.filter)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.filter)))?;
+            struct_ser.serialize_field("filter", &v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for FmdParametersResponse {
+impl<'de> serde::Deserialize<'de> for DelegationsByAddressIndexRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "parameters",
+            "address_index",
+            "addressIndex",
+            "filter",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Parameters,
+            AddressIndex,
+            Filter,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2868,7 +3388,8 @@ impl<'de> serde::Deserialize<'de> for FmdParametersResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "parameters" => Ok(GeneratedField::Parameters),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "filter" => Ok(GeneratedField::Filter),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2878,61 +3399,152 @@ impl<'de> serde::Deserialize<'de> for FmdParametersResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = FmdParametersResponse;
+            type Value = DelegationsByAddressIndexRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.FMDParametersResponse")
+                formatter.write_str("struct penumbra.view.v1.DelegationsByAddressIndexRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FmdParametersResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegationsByAddressIndexRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut parameters__ = None;
+                let mut address_index__ = None;
+                let mut filter__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Parameters => {
-                            if parameters__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("parameters"));
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
                             }
-                            parameters__ = map_.next_value()?;
+                            address_index__ = map_.next_value()?;
+                        }
+                        GeneratedField::Filter => {
+                            if filter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("filter"));
+                            }
+                            filter__ = Some(map_.next_value::<delegations_by_address_index_request::Filter>()? as i32);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(FmdParametersResponse {
-                    parameters: parameters__,
+                Ok(DelegationsByAddressIndexRequest {
+                    address_index: address_index__,
+                    filter: filter__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.FMDParametersResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.DelegationsByAddressIndexRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GasPricesRequest {
+impl serde::Serialize for delegations_by_address_index_request::Filter {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "FILTER_UNSPECIFIED",
+            Self::AllActiveWithNonzeroBalances => "FILTER_ALL_ACTIVE_WITH_NONZERO_BALANCES",
+            Self::All => "FILTER_ALL",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for delegations_by_address_index_request::Filter {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "FILTER_UNSPECIFIED",
+            "FILTER_ALL_ACTIVE_WITH_NONZERO_BALANCES",
+            "FILTER_ALL",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = delegations_by_address_index_request::Filter;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "FILTER_UNSPECIFIED" => Ok(delegations_by_address_index_request::Filter::Unspecified),
+                    "FILTER_ALL_ACTIVE_WITH_NONZERO_BALANCES" => Ok(delegations_by_address_index_request::Filter::AllActiveWithNonzeroBalances),
+                    "FILTER_ALL" => Ok(delegations_by_address_index_request::Filter::All),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DelegationsByAddressIndexResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1.GasPricesRequest", len)?;
+        let mut len = 0;
+        if self.value_view.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DelegationsByAddressIndexResponse", len)?;
+        if let Some(v) = self.value_view.as_ref() {
+            struct_ser.serialize_field("valueView", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GasPricesRequest {
+impl<'de> serde::Deserialize<'de> for DelegationsByAddressIndexResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "value_view",
+            "valueView",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            ValueView,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2954,7 +3566,10 @@ impl<'de> serde::Deserialize<'de> for GasPricesRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "valueView" | "value_view" => Ok(GeneratedField::ValueView),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -2962,27 +3577,39 @@ impl<'de> serde::Deserialize<'de> for GasPricesRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GasPricesRequest;
+            type Value = DelegationsByAddressIndexResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.GasPricesRequest")
+                formatter.write_str("struct penumbra.view.v1.DelegationsByAddressIndexResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GasPricesRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DelegationsByAddressIndexResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut value_view__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ValueView => {
+                            if value_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("valueView"));
+                            }
+                            value_view__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(GasPricesRequest {
+                Ok(DelegationsByAddressIndexResponse {
+                    value_view: value_view__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.GasPricesRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.DelegationsByAddressIndexResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GasPricesResponse {
+impl serde::Serialize for DeleteAccountLabelRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2990,39 +3617,29 @@ impl serde::Serialize for GasPricesResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.gas_prices.is_some() {
-            len += 1;
-        }
-        if !self.alt_gas_prices.is_empty() {
+        if self.account != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.GasPricesResponse", len)?;
-        if let Some(v) = self.gas_prices.as_ref() {
-            struct_ser.serialize_field("gasPrices", v)?;
-        }
-        if !self.alt_gas_prices.is_empty() {
-            struct_ser.serialize_field("altGasPrices", &self.alt_gas_prices)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DeleteAccountLabelRequest", len)?;
+        if self.account != 0 {
+            struct_ser.serialize_field("account", &self.account)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GasPricesResponse {
+impl<'de> serde::Deserialize<'de> for DeleteAccountLabelRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "gas_prices",
-            "gasPrices",
-            "alt_gas_prices",
-            "altGasPrices",
+            "account",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            GasPrices,
-            AltGasPrices,
+            Account,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3045,8 +3662,7 @@ impl<'de> serde::Deserialize<'de> for GasPricesResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
-                            "altGasPrices" | "alt_gas_prices" => Ok(GeneratedField::AltGasPrices),
+                            "account" => Ok(GeneratedField::Account),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3056,47 +3672,41 @@ impl<'de> serde::Deserialize<'de> for GasPricesResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GasPricesResponse;
+            type Value = DeleteAccountLabelRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.GasPricesResponse")
+                formatter.write_str("struct penumbra.view.v1.DeleteAccountLabelRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GasPricesResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DeleteAccountLabelRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut gas_prices__ = None;
-                let mut alt_gas_prices__ = None;
+                let mut account__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::GasPrices => {
-                            if gas_prices__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("gasPrices"));
-                            }
-                            gas_prices__ = map_.next_value()?;
-                        }
-                        GeneratedField::AltGasPrices => {
-                            if alt_gas_prices__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("altGasPrices"));
+                        GeneratedField::Account => {
+                            if account__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("account"));
                             }
-                            alt_gas_prices__ = Some(map_.next_value()?);
+                            account__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GasPricesResponse {
-                    gas_prices: gas_prices__,
-                    alt_gas_prices: alt_gas_prices__.unwrap_or_default(),
+                Ok(DeleteAccountLabelRequest {
+                    account: account__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.GasPricesResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.DeleteAccountLabelRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IndexByAddressRequest {
+impl serde::Serialize for DeleteAccountLabelResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3104,29 +3714,29 @@ impl serde::Serialize for IndexByAddressRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address.is_some() {
+        if self.deleted {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.IndexByAddressRequest", len)?;
-        if let Some(v) = self.address.as_ref() {
-            struct_ser.serialize_field("address", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DeleteAccountLabelResponse", len)?;
+        if self.deleted {
+            struct_ser.serialize_field("deleted", &self.deleted)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
+impl<'de> serde::Deserialize<'de> for DeleteAccountLabelResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address",
+            "deleted",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Address,
+            Deleted,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3149,7 +3759,7 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "address" => Ok(GeneratedField::Address),
+                            "deleted" => Ok(GeneratedField::Deleted),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3159,39 +3769,39 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IndexByAddressRequest;
+            type Value = DeleteAccountLabelResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.IndexByAddressRequest")
+                formatter.write_str("struct penumbra.view.v1.DeleteAccountLabelResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IndexByAddressRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DeleteAccountLabelResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address__ = None;
+                let mut deleted__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Address => {
-                            if address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("address"));
+                        GeneratedField::Deleted => {
+                            if deleted__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deleted"));
                             }
-                            address__ = map_.next_value()?;
+                            deleted__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(IndexByAddressRequest {
-                    address: address__,
+                Ok(DeleteAccountLabelResponse {
+                    deleted: deleted__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.IndexByAddressRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.DeleteAccountLabelResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IndexByAddressResponse {
+impl serde::Serialize for DeleteContactRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3199,30 +3809,29 @@ impl serde::Serialize for IndexByAddressResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address_index.is_some() {
+        if self.address.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.IndexByAddressResponse", len)?;
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DeleteContactRequest", len)?;
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
+impl<'de> serde::Deserialize<'de> for DeleteContactRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address_index",
-            "addressIndex",
+            "address",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AddressIndex,
+            Address,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3245,7 +3854,7 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "address" => Ok(GeneratedField::Address),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3255,39 +3864,39 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IndexByAddressResponse;
+            type Value = DeleteContactRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.IndexByAddressResponse")
+                formatter.write_str("struct penumbra.view.v1.DeleteContactRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IndexByAddressResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DeleteContactRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address_index__ = None;
+                let mut address__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
                             }
-                            address_index__ = map_.next_value()?;
+                            address__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(IndexByAddressResponse {
-                    address_index: address_index__,
+                Ok(DeleteContactRequest {
+                    address: address__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.IndexByAddressResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.DeleteContactRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NoteByCommitmentRequest {
+impl serde::Serialize for DeleteContactResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3295,39 +3904,29 @@ impl serde::Serialize for NoteByCommitmentRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.note_commitment.is_some() {
+        if self.deleted {
             len += 1;
         }
-        if self.await_detection {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NoteByCommitmentRequest", len)?;
-        if let Some(v) = self.note_commitment.as_ref() {
-            struct_ser.serialize_field("noteCommitment", v)?;
-        }
-        if self.await_detection {
-            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DeleteContactResponse", len)?;
+        if self.deleted {
+            struct_ser.serialize_field("deleted", &self.deleted)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
+impl<'de> serde::Deserialize<'de> for DeleteContactResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_commitment",
-            "noteCommitment",
-            "await_detection",
-            "awaitDetection",
+            "deleted",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteCommitment,
-            AwaitDetection,
+            Deleted,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3350,8 +3949,7 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
-                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "deleted" => Ok(GeneratedField::Deleted),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3361,47 +3959,39 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NoteByCommitmentRequest;
+            type Value = DeleteContactResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NoteByCommitmentRequest")
+                formatter.write_str("struct penumbra.view.v1.DeleteContactResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DeleteContactResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_commitment__ = None;
-                let mut await_detection__ = None;
+                let mut deleted__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::NoteCommitment => {
-                            if note_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
-                            }
-                            note_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::AwaitDetection => {
-                            if await_detection__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                        GeneratedField::Deleted => {
+                            if deleted__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deleted"));
                             }
-                            await_detection__ = Some(map_.next_value()?);
+                            deleted__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NoteByCommitmentRequest {
-                    note_commitment: note_commitment__,
-                    await_detection: await_detection__.unwrap_or_default(),
+                Ok(DeleteContactResponse {
+                    deleted: deleted__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NoteByCommitmentRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.DeleteContactResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NoteByCommitmentResponse {
+impl serde::Serialize for DeleteInvoiceRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3409,30 +3999,30 @@ impl serde::Serialize for NoteByCommitmentResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.spendable_note.is_some() {
+        if !self.invoice_id.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NoteByCommitmentResponse", len)?;
-        if let Some(v) = self.spendable_note.as_ref() {
-            struct_ser.serialize_field("spendableNote", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DeleteInvoiceRequest", len)?;
+        if !self.invoice_id.is_empty() {
+            struct_ser.serialize_field("invoiceId", &self.invoice_id)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
+impl<'de> serde::Deserialize<'de> for DeleteInvoiceRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "spendable_note",
-            "spendableNote",
+            "invoice_id",
+            "invoiceId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SpendableNote,
+            InvoiceId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3455,7 +4045,7 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "spendableNote" | "spendable_note" => Ok(GeneratedField::SpendableNote),
+                            "invoiceId" | "invoice_id" => Ok(GeneratedField::InvoiceId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3465,39 +4055,39 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NoteByCommitmentResponse;
+            type Value = DeleteInvoiceRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NoteByCommitmentResponse")
+                formatter.write_str("struct penumbra.view.v1.DeleteInvoiceRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DeleteInvoiceRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut spendable_note__ = None;
+                let mut invoice_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SpendableNote => {
-                            if spendable_note__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("spendableNote"));
+                        GeneratedField::InvoiceId => {
+                            if invoice_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("invoiceId"));
                             }
-                            spendable_note__ = map_.next_value()?;
+                            invoice_id__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NoteByCommitmentResponse {
-                    spendable_note: spendable_note__,
+                Ok(DeleteInvoiceRequest {
+                    invoice_id: invoice_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NoteByCommitmentResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.DeleteInvoiceRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesForVotingRequest {
+impl serde::Serialize for DeleteInvoiceResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3505,40 +4095,29 @@ impl serde::Serialize for NotesForVotingRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.votable_at_height != 0 {
+        if self.deleted {
             len += 1;
         }
-        if self.address_index.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesForVotingRequest", len)?;
-        if self.votable_at_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("votableAtHeight", ToString::to_string(&self.votable_at_height).as_str())?;
-        }
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DeleteInvoiceResponse", len)?;
+        if self.deleted {
+            struct_ser.serialize_field("deleted", &self.deleted)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
+impl<'de> serde::Deserialize<'de> for DeleteInvoiceResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "votable_at_height",
-            "votableAtHeight",
-            "address_index",
-            "addressIndex",
+            "deleted",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            VotableAtHeight,
-            AddressIndex,
+            Deleted,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3561,8 +4140,7 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "votableAtHeight" | "votable_at_height" => Ok(GeneratedField::VotableAtHeight),
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "deleted" => Ok(GeneratedField::Deleted),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3572,49 +4150,39 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesForVotingRequest;
+            type Value = DeleteInvoiceResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NotesForVotingRequest")
+                formatter.write_str("struct penumbra.view.v1.DeleteInvoiceResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesForVotingRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DeleteInvoiceResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut votable_at_height__ = None;
-                let mut address_index__ = None;
+                let mut deleted__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::VotableAtHeight => {
-                            if votable_at_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("votableAtHeight"));
-                            }
-                            votable_at_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                        GeneratedField::Deleted => {
+                            if deleted__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deleted"));
                             }
-                            address_index__ = map_.next_value()?;
+                            deleted__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NotesForVotingRequest {
-                    votable_at_height: votable_at_height__.unwrap_or_default(),
-                    address_index: address_index__,
+                Ok(DeleteInvoiceResponse {
+                    deleted: deleted__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NotesForVotingRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.DeleteInvoiceResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesForVotingResponse {
+impl serde::Serialize for EphemeralAddressRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3622,39 +4190,30 @@ impl serde::Serialize for NotesForVotingResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.note_record.is_some() {
-            len += 1;
-        }
-        if self.identity_key.is_some() {
+        if self.address_index.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesForVotingResponse", len)?;
-        if let Some(v) = self.note_record.as_ref() {
-            struct_ser.serialize_field("noteRecord", v)?;
-        }
-        if let Some(v) = self.identity_key.as_ref() {
-            struct_ser.serialize_field("identityKey", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.EphemeralAddressRequest", len)?;
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
+impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_record",
-            "noteRecord",
-            "identity_key",
-            "identityKey",
+            "address_index",
+            "addressIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteRecord,
-            IdentityKey,
+            AddressIndex,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3677,8 +4236,7 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
-                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3688,47 +4246,39 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesForVotingResponse;
+            type Value = EphemeralAddressRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NotesForVotingResponse")
+                formatter.write_str("struct penumbra.view.v1.EphemeralAddressRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesForVotingResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EphemeralAddressRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_record__ = None;
-                let mut identity_key__ = None;
+                let mut address_index__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::NoteRecord => {
-                            if note_record__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteRecord"));
-                            }
-                            note_record__ = map_.next_value()?;
-                        }
-                        GeneratedField::IdentityKey => {
-                            if identity_key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
                             }
-                            identity_key__ = map_.next_value()?;
+                            address_index__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NotesForVotingResponse {
-                    note_record: note_record__,
-                    identity_key: identity_key__,
+                Ok(EphemeralAddressRequest {
+                    address_index: address_index__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NotesForVotingResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.EphemeralAddressRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesRequest {
+impl serde::Serialize for EphemeralAddressResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3736,57 +4286,29 @@ impl serde::Serialize for NotesRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.include_spent {
-            len += 1;
-        }
-        if self.asset_id.is_some() {
-            len += 1;
-        }
-        if self.address_index.is_some() {
-            len += 1;
-        }
-        if self.amount_to_spend.is_some() {
+        if self.address.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesRequest", len)?;
-        if self.include_spent {
-            struct_ser.serialize_field("includeSpent", &self.include_spent)?;
-        }
-        if let Some(v) = self.asset_id.as_ref() {
-            struct_ser.serialize_field("assetId", v)?;
-        }
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
-        }
-        if let Some(v) = self.amount_to_spend.as_ref() {
-            struct_ser.serialize_field("amountToSpend", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.EphemeralAddressResponse", len)?;
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NotesRequest {
+impl<'de> serde::Deserialize<'de> for EphemeralAddressResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "include_spent",
-            "includeSpent",
-            "asset_id",
-            "assetId",
-            "address_index",
-            "addressIndex",
-            "amount_to_spend",
-            "amountToSpend",
+            "address",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            IncludeSpent,
-            AssetId,
-            AddressIndex,
-            AmountToSpend,
+            Address,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3809,10 +4331,7 @@ impl<'de> serde::Deserialize<'de> for NotesRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "includeSpent" | "include_spent" => Ok(GeneratedField::IncludeSpent),
-                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
-                            "amountToSpend" | "amount_to_spend" => Ok(GeneratedField::AmountToSpend),
+                            "address" => Ok(GeneratedField::Address),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3822,94 +4341,61 @@ impl<'de> serde::Deserialize<'de> for NotesRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesRequest;
+            type Value = EphemeralAddressResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NotesRequest")
+                formatter.write_str("struct penumbra.view.v1.EphemeralAddressResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EphemeralAddressResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut include_spent__ = None;
-                let mut asset_id__ = None;
-                let mut address_index__ = None;
-                let mut amount_to_spend__ = None;
+                let mut address__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::IncludeSpent => {
-                            if include_spent__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("includeSpent"));
-                            }
-                            include_spent__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::AssetId => {
-                            if asset_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("assetId"));
-                            }
-                            asset_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
-                            }
-                            address_index__ = map_.next_value()?;
-                        }
-                        GeneratedField::AmountToSpend => {
-                            if amount_to_spend__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("amountToSpend"));
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
                             }
-                            amount_to_spend__ = map_.next_value()?;
+                            address__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NotesRequest {
-                    include_spent: include_spent__.unwrap_or_default(),
-                    asset_id: asset_id__,
-                    address_index: address_index__,
-                    amount_to_spend: amount_to_spend__,
+                Ok(EphemeralAddressResponse {
+                    address: address__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NotesRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.EphemeralAddressResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesResponse {
+impl serde::Serialize for FmdParametersRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.note_record.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesResponse", len)?;
-        if let Some(v) = self.note_record.as_ref() {
-            struct_ser.serialize_field("noteRecord", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.FMDParametersRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NotesResponse {
+impl<'de> serde::Deserialize<'de> for FmdParametersRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_record",
-            "noteRecord",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteRecord,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3931,10 +4417,7 @@ impl<'de> serde::Deserialize<'de> for NotesResponse {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -3942,39 +4425,27 @@ impl<'de> serde::Deserialize<'de> for NotesResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesResponse;
+            type Value = FmdParametersRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NotesResponse")
+                formatter.write_str("struct penumbra.view.v1.FMDParametersRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FmdParametersRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_record__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::NoteRecord => {
-                            if note_record__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteRecord"));
-                            }
-                            note_record__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(NotesResponse {
-                    note_record: note_record__,
+                Ok(FmdParametersRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NotesResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.FMDParametersRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NullifierStatusRequest {
+impl serde::Serialize for FmdParametersResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3982,38 +4453,29 @@ impl serde::Serialize for NullifierStatusRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.nullifier.is_some() {
-            len += 1;
-        }
-        if self.await_detection {
+        if self.parameters.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NullifierStatusRequest", len)?;
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
-        }
-        if self.await_detection {
-            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.FMDParametersResponse", len)?;
+        if let Some(v) = self.parameters.as_ref() {
+            struct_ser.serialize_field("parameters", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
+impl<'de> serde::Deserialize<'de> for FmdParametersResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "nullifier",
-            "await_detection",
-            "awaitDetection",
+            "parameters",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Nullifier,
-            AwaitDetection,
+            Parameters,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4036,8 +4498,7 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "parameters" => Ok(GeneratedField::Parameters),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4047,47 +4508,39 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NullifierStatusRequest;
+            type Value = FmdParametersResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NullifierStatusRequest")
+                formatter.write_str("struct penumbra.view.v1.FMDParametersResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FmdParametersResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut nullifier__ = None;
-                let mut await_detection__ = None;
+                let mut parameters__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
-                            }
-                            nullifier__ = map_.next_value()?;
-                        }
-                        GeneratedField::AwaitDetection => {
-                            if await_detection__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                        GeneratedField::Parameters => {
+                            if parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parameters"));
                             }
-                            await_detection__ = Some(map_.next_value()?);
+                            parameters__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NullifierStatusRequest {
-                    nullifier: nullifier__,
-                    await_detection: await_detection__.unwrap_or_default(),
+                Ok(FmdParametersResponse {
+                    parameters: parameters__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NullifierStatusRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.FMDParametersResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NullifierStatusResponse {
+impl serde::Serialize for FilteredCompactBlockRangeRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4095,29 +4548,59 @@ impl serde::Serialize for NullifierStatusResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.spent {
+        if !self.detection_keys.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NullifierStatusResponse", len)?;
-        if self.spent {
-            struct_ser.serialize_field("spent", &self.spent)?;
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        if self.keep_alive {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.FilteredCompactBlockRangeRequest", len)?;
+        if !self.detection_keys.is_empty() {
+            struct_ser.serialize_field("detectionKeys", &self.detection_keys.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+        }
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        if self.keep_alive {
+            struct_ser.serialize_field("keepAlive", &self.keep_alive)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
+impl<'de> serde::Deserialize<'de> for FilteredCompactBlockRangeRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "spent",
+            "detection_keys",
+            "detectionKeys",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+            "keep_alive",
+            "keepAlive",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Spent,
+            DetectionKeys,
+            StartHeight,
+            EndHeight,
+            KeepAlive,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4140,7 +4623,10 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "spent" => Ok(GeneratedField::Spent),
+                            "detectionKeys" | "detection_keys" => Ok(GeneratedField::DetectionKeys),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "keepAlive" | "keep_alive" => Ok(GeneratedField::KeepAlive),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4150,39 +4636,70 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NullifierStatusResponse;
+            type Value = FilteredCompactBlockRangeRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NullifierStatusResponse")
+                formatter.write_str("struct penumbra.view.v1.FilteredCompactBlockRangeRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FilteredCompactBlockRangeRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut spent__ = None;
+                let mut detection_keys__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                let mut keep_alive__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Spent => {
-                            if spent__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("spent"));
+                        GeneratedField::DetectionKeys => {
+                            if detection_keys__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("detectionKeys"));
                             }
-                            spent__ = Some(map_.next_value()?);
+                            detection_keys__ = 
+                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter().map(|x| x.0).collect())
+                            ;
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::KeepAlive => {
+                            if keep_alive__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("keepAlive"));
+                            }
+                            keep_alive__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NullifierStatusResponse {
-                    spent: spent__.unwrap_or_default(),
+                Ok(FilteredCompactBlockRangeRequest {
+                    detection_keys: detection_keys__.unwrap_or_default(),
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                    keep_alive: keep_alive__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NullifierStatusResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.FilteredCompactBlockRangeRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for OwnedPositionIdsRequest {
+impl serde::Serialize for FilteredCompactBlockRangeResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4190,39 +4707,30 @@ impl serde::Serialize for OwnedPositionIdsRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_state.is_some() {
-            len += 1;
-        }
-        if self.trading_pair.is_some() {
+        if self.compact_block.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.OwnedPositionIdsRequest", len)?;
-        if let Some(v) = self.position_state.as_ref() {
-            struct_ser.serialize_field("positionState", v)?;
-        }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.FilteredCompactBlockRangeResponse", len)?;
+        if let Some(v) = self.compact_block.as_ref() {
+            struct_ser.serialize_field("compactBlock", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
+impl<'de> serde::Deserialize<'de> for FilteredCompactBlockRangeResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_state",
-            "positionState",
-            "trading_pair",
-            "tradingPair",
+            "compact_block",
+            "compactBlock",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionState,
-            TradingPair,
+            CompactBlock,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4245,8 +4753,7 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionState" | "position_state" => Ok(GeneratedField::PositionState),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "compactBlock" | "compact_block" => Ok(GeneratedField::CompactBlock),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4256,78 +4763,61 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = OwnedPositionIdsRequest;
+            type Value = FilteredCompactBlockRangeResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.OwnedPositionIdsRequest")
+                formatter.write_str("struct penumbra.view.v1.FilteredCompactBlockRangeResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FilteredCompactBlockRangeResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_state__ = None;
-                let mut trading_pair__ = None;
+                let mut compact_block__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionState => {
-                            if position_state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionState"));
-                            }
-                            position_state__ = map_.next_value()?;
-                        }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::CompactBlock => {
+                            if compact_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("compactBlock"));
                             }
-                            trading_pair__ = map_.next_value()?;
+                            compact_block__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(OwnedPositionIdsRequest {
-                    position_state: position_state__,
-                    trading_pair: trading_pair__,
+                Ok(FilteredCompactBlockRangeResponse {
+                    compact_block: compact_block__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.OwnedPositionIdsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.FilteredCompactBlockRangeResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for OwnedPositionIdsResponse {
+impl serde::Serialize for GasPricesRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.OwnedPositionIdsResponse", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.GasPricesRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
+impl<'de> serde::Deserialize<'de> for GasPricesRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4349,10 +4839,7 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -4360,39 +4847,27 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = OwnedPositionIdsResponse;
+            type Value = GasPricesRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.OwnedPositionIdsResponse")
+                formatter.write_str("struct penumbra.view.v1.GasPricesRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GasPricesRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(OwnedPositionIdsResponse {
-                    position_id: position_id__,
+                Ok(GasPricesRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.GasPricesRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SpendableNoteRecord {
+impl serde::Serialize for GasPricesResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4400,101 +4875,39 @@ impl serde::Serialize for SpendableNoteRecord {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.note_commitment.is_some() {
+        if self.gas_prices.is_some() {
             len += 1;
         }
-        if self.note.is_some() {
+        if !self.alt_gas_prices.is_empty() {
             len += 1;
         }
-        if self.address_index.is_some() {
-            len += 1;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.GasPricesResponse", len)?;
+        if let Some(v) = self.gas_prices.as_ref() {
+            struct_ser.serialize_field("gasPrices", v)?;
         }
-        if self.nullifier.is_some() {
-            len += 1;
-        }
-        if self.height_created != 0 {
-            len += 1;
-        }
-        if self.height_spent != 0 {
-            len += 1;
-        }
-        if self.position != 0 {
-            len += 1;
-        }
-        if self.source.is_some() {
-            len += 1;
-        }
-        if self.return_address.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SpendableNoteRecord", len)?;
-        if let Some(v) = self.note_commitment.as_ref() {
-            struct_ser.serialize_field("noteCommitment", v)?;
-        }
-        if let Some(v) = self.note.as_ref() {
-            struct_ser.serialize_field("note", v)?;
-        }
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
-        }
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
-        }
-        if self.height_created != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("heightCreated", ToString::to_string(&self.height_created).as_str())?;
-        }
-        if self.height_spent != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("heightSpent", ToString::to_string(&self.height_spent).as_str())?;
-        }
-        if self.position != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
-        }
-        if let Some(v) = self.source.as_ref() {
-            struct_ser.serialize_field("source", v)?;
-        }
-        if let Some(v) = self.return_address.as_ref() {
-            struct_ser.serialize_field("returnAddress", v)?;
+        if !self.alt_gas_prices.is_empty() {
+            struct_ser.serialize_field("altGasPrices", &self.alt_gas_prices)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
+impl<'de> serde::Deserialize<'de> for GasPricesResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_commitment",
-            "noteCommitment",
-            "note",
-            "address_index",
-            "addressIndex",
-            "nullifier",
-            "height_created",
-            "heightCreated",
-            "height_spent",
-            "heightSpent",
-            "position",
-            "source",
-            "return_address",
-            "returnAddress",
+            "gas_prices",
+            "gasPrices",
+            "alt_gas_prices",
+            "altGasPrices",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteCommitment,
-            Note,
-            AddressIndex,
-            Nullifier,
-            HeightCreated,
-            HeightSpent,
-            Position,
-            Source,
-            ReturnAddress,
+            GasPrices,
+            AltGasPrices,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4517,15 +4930,8 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
                         E: serde::de::Error,
                     {
                         match value {
-                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
-                            "note" => Ok(GeneratedField::Note),
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "heightCreated" | "height_created" => Ok(GeneratedField::HeightCreated),
-                            "heightSpent" | "height_spent" => Ok(GeneratedField::HeightSpent),
-                            "position" => Ok(GeneratedField::Position),
-                            "source" => Ok(GeneratedField::Source),
-                            "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
+                            "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
+                            "altGasPrices" | "alt_gas_prices" => Ok(GeneratedField::AltGasPrices),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4535,131 +4941,77 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SpendableNoteRecord;
+            type Value = GasPricesResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.SpendableNoteRecord")
+                formatter.write_str("struct penumbra.view.v1.GasPricesResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpendableNoteRecord, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GasPricesResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_commitment__ = None;
-                let mut note__ = None;
-                let mut address_index__ = None;
-                let mut nullifier__ = None;
-                let mut height_created__ = None;
-                let mut height_spent__ = None;
-                let mut position__ = None;
-                let mut source__ = None;
-                let mut return_address__ = None;
+                let mut gas_prices__ = None;
+                let mut alt_gas_prices__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::NoteCommitment => {
-                            if note_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
-                            }
-                            note_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Note => {
-                            if note__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("note"));
-                            }
-                            note__ = map_.next_value()?;
-                        }
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
-                            }
-                            address_index__ = map_.next_value()?;
-                        }
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
-                            }
-                            nullifier__ = map_.next_value()?;
-                        }
-                        GeneratedField::HeightCreated => {
-                            if height_created__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("heightCreated"));
-                            }
-                            height_created__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::HeightSpent => {
-                            if height_spent__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("heightSpent"));
-                            }
-                            height_spent__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
-                            }
-                            position__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Source => {
-                            if source__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("source"));
+                        GeneratedField::GasPrices => {
+                            if gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasPrices"));
                             }
-                            source__ = map_.next_value()?;
+                            gas_prices__ = map_.next_value()?;
                         }
-                        GeneratedField::ReturnAddress => {
-                            if return_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("returnAddress"));
+                        GeneratedField::AltGasPrices => {
+                            if alt_gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("altGasPrices"));
                             }
-                            return_address__ = map_.next_value()?;
+                            alt_gas_prices__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SpendableNoteRecord {
-                    note_commitment: note_commitment__,
-                    note: note__,
-                    address_index: address_index__,
-                    nullifier: nullifier__,
-                    height_created: height_created__.unwrap_or_default(),
-                    height_spent: height_spent__.unwrap_or_default(),
-                    position: position__.unwrap_or_default(),
-                    source: source__,
-                    return_address: return_address__,
+                Ok(GasPricesResponse {
+                    gas_prices: gas_prices__,
+                    alt_gas_prices: alt_gas_prices__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.SpendableNoteRecord", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.GasPricesResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StatusRequest {
+impl serde::Serialize for IndexByAddressRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusRequest", len)?;
+        let mut len = 0;
+        if self.address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.IndexByAddressRequest", len)?;
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StatusRequest {
+impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "address",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Address,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4681,7 +5033,10 @@ impl<'de> serde::Deserialize<'de> for StatusRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "address" => Ok(GeneratedField::Address),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -4689,27 +5044,39 @@ impl<'de> serde::Deserialize<'de> for StatusRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StatusRequest;
+            type Value = IndexByAddressRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.StatusRequest")
+                formatter.write_str("struct penumbra.view.v1.IndexByAddressRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IndexByAddressRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(StatusRequest {
+                Ok(IndexByAddressRequest {
+                    address: address__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.StatusRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.IndexByAddressRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StatusResponse {
+impl serde::Serialize for IndexByAddressResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4717,50 +5084,30 @@ impl serde::Serialize for StatusResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.full_sync_height != 0 {
-            len += 1;
-        }
-        if self.partial_sync_height != 0 {
-            len += 1;
-        }
-        if self.catching_up {
+        if self.address_index.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusResponse", len)?;
-        if self.full_sync_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("fullSyncHeight", ToString::to_string(&self.full_sync_height).as_str())?;
-        }
-        if self.partial_sync_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("partialSyncHeight", ToString::to_string(&self.partial_sync_height).as_str())?;
-        }
-        if self.catching_up {
-            struct_ser.serialize_field("catchingUp", &self.catching_up)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.IndexByAddressResponse", len)?;
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StatusResponse {
+impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "full_sync_height",
-            "fullSyncHeight",
-            "partial_sync_height",
-            "partialSyncHeight",
-            "catching_up",
-            "catchingUp",
+            "address_index",
+            "addressIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            FullSyncHeight,
-            PartialSyncHeight,
-            CatchingUp,
+            AddressIndex,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4783,9 +5130,7 @@ impl<'de> serde::Deserialize<'de> for StatusResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "fullSyncHeight" | "full_sync_height" => Ok(GeneratedField::FullSyncHeight),
-                            "partialSyncHeight" | "partial_sync_height" => Ok(GeneratedField::PartialSyncHeight),
-                            "catchingUp" | "catching_up" => Ok(GeneratedField::CatchingUp),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4795,81 +5140,87 @@ impl<'de> serde::Deserialize<'de> for StatusResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StatusResponse;
+            type Value = IndexByAddressResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.StatusResponse")
+                formatter.write_str("struct penumbra.view.v1.IndexByAddressResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IndexByAddressResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut full_sync_height__ = None;
-                let mut partial_sync_height__ = None;
-                let mut catching_up__ = None;
+                let mut address_index__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::FullSyncHeight => {
-                            if full_sync_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fullSyncHeight"));
-                            }
-                            full_sync_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::PartialSyncHeight => {
-                            if partial_sync_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("partialSyncHeight"));
-                            }
-                            partial_sync_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::CatchingUp => {
-                            if catching_up__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("catchingUp"));
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
                             }
-                            catching_up__ = Some(map_.next_value()?);
+                            address_index__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(StatusResponse {
-                    full_sync_height: full_sync_height__.unwrap_or_default(),
-                    partial_sync_height: partial_sync_height__.unwrap_or_default(),
-                    catching_up: catching_up__.unwrap_or_default(),
+                Ok(IndexByAddressResponse {
+                    address_index: address_index__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.StatusResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.IndexByAddressResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StatusStreamRequest {
+impl serde::Serialize for Invoice {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusStreamRequest", len)?;
+        let mut len = 0;
+        if self.payment_request.is_some() {
+            len += 1;
+        }
+        if self.dedicated_address {
+            len += 1;
+        }
+        if !self.payments.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.Invoice", len)?;
+        if let Some(v) = self.payment_request.as_ref() {
+            struct_ser.serialize_field("paymentRequest", v)?;
+        }
+        if self.dedicated_address {
+            struct_ser.serialize_field("dedicatedAddress", &self.dedicated_address)?;
+        }
+        if !self.payments.is_empty() {
+            struct_ser.serialize_field("payments", &self.payments)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StatusStreamRequest {
+impl<'de> serde::Deserialize<'de> for Invoice {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "payment_request",
+            "paymentRequest",
+            "dedicated_address",
+            "dedicatedAddress",
+            "payments",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            PaymentRequest,
+            DedicatedAddress,
+            Payments,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4891,7 +5242,12 @@ impl<'de> serde::Deserialize<'de> for StatusStreamRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "paymentRequest" | "payment_request" => Ok(GeneratedField::PaymentRequest),
+                            "dedicatedAddress" | "dedicated_address" => Ok(GeneratedField::DedicatedAddress),
+                            "payments" => Ok(GeneratedField::Payments),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -4899,27 +5255,55 @@ impl<'de> serde::Deserialize<'de> for StatusStreamRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StatusStreamRequest;
+            type Value = Invoice;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.StatusStreamRequest")
+                formatter.write_str("struct penumbra.view.v1.Invoice")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusStreamRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Invoice, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut payment_request__ = None;
+                let mut dedicated_address__ = None;
+                let mut payments__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PaymentRequest => {
+                            if payment_request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("paymentRequest"));
+                            }
+                            payment_request__ = map_.next_value()?;
+                        }
+                        GeneratedField::DedicatedAddress => {
+                            if dedicated_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dedicatedAddress"));
+                            }
+                            dedicated_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Payments => {
+                            if payments__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("payments"));
+                            }
+                            payments__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(StatusStreamRequest {
+                Ok(Invoice {
+                    payment_request: payment_request__,
+                    dedicated_address: dedicated_address__.unwrap_or_default(),
+                    payments: payments__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.StatusStreamRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.Invoice", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StatusStreamResponse {
+impl serde::Serialize for InvoicesRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4927,51 +5311,30 @@ impl serde::Serialize for StatusStreamResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.latest_known_block_height != 0 {
-            len += 1;
-        }
-        if self.full_sync_height != 0 {
+        if self.include_paid {
             len += 1;
         }
-        if self.partial_sync_height != 0 {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusStreamResponse", len)?;
-        if self.latest_known_block_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("latestKnownBlockHeight", ToString::to_string(&self.latest_known_block_height).as_str())?;
-        }
-        if self.full_sync_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("fullSyncHeight", ToString::to_string(&self.full_sync_height).as_str())?;
-        }
-        if self.partial_sync_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("partialSyncHeight", ToString::to_string(&self.partial_sync_height).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.InvoicesRequest", len)?;
+        if self.include_paid {
+            struct_ser.serialize_field("includePaid", &self.include_paid)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StatusStreamResponse {
+impl<'de> serde::Deserialize<'de> for InvoicesRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "latest_known_block_height",
-            "latestKnownBlockHeight",
-            "full_sync_height",
-            "fullSyncHeight",
-            "partial_sync_height",
-            "partialSyncHeight",
+            "include_paid",
+            "includePaid",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            LatestKnownBlockHeight,
-            FullSyncHeight,
-            PartialSyncHeight,
+            IncludePaid,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4994,9 +5357,7 @@ impl<'de> serde::Deserialize<'de> for StatusStreamResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "latestKnownBlockHeight" | "latest_known_block_height" => Ok(GeneratedField::LatestKnownBlockHeight),
-                            "fullSyncHeight" | "full_sync_height" => Ok(GeneratedField::FullSyncHeight),
-                            "partialSyncHeight" | "partial_sync_height" => Ok(GeneratedField::PartialSyncHeight),
+                            "includePaid" | "include_paid" => Ok(GeneratedField::IncludePaid),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5006,61 +5367,39 @@ impl<'de> serde::Deserialize<'de> for StatusStreamResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StatusStreamResponse;
+            type Value = InvoicesRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.StatusStreamResponse")
+                formatter.write_str("struct penumbra.view.v1.InvoicesRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusStreamResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InvoicesRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut latest_known_block_height__ = None;
-                let mut full_sync_height__ = None;
-                let mut partial_sync_height__ = None;
+                let mut include_paid__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::LatestKnownBlockHeight => {
-                            if latest_known_block_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("latestKnownBlockHeight"));
-                            }
-                            latest_known_block_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::FullSyncHeight => {
-                            if full_sync_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fullSyncHeight"));
-                            }
-                            full_sync_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::PartialSyncHeight => {
-                            if partial_sync_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("partialSyncHeight"));
+                        GeneratedField::IncludePaid => {
+                            if include_paid__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includePaid"));
                             }
-                            partial_sync_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            include_paid__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(StatusStreamResponse {
-                    latest_known_block_height: latest_known_block_height__.unwrap_or_default(),
-                    full_sync_height: full_sync_height__.unwrap_or_default(),
-                    partial_sync_height: partial_sync_height__.unwrap_or_default(),
+                Ok(InvoicesRequest {
+                    include_paid: include_paid__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.StatusStreamResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.InvoicesRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapByCommitmentRequest {
+impl serde::Serialize for InvoicesResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5068,39 +5407,29 @@ impl serde::Serialize for SwapByCommitmentRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_commitment.is_some() {
-            len += 1;
-        }
-        if self.await_detection {
+        if self.invoice.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SwapByCommitmentRequest", len)?;
-        if let Some(v) = self.swap_commitment.as_ref() {
-            struct_ser.serialize_field("swapCommitment", v)?;
-        }
-        if self.await_detection {
-            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.InvoicesResponse", len)?;
+        if let Some(v) = self.invoice.as_ref() {
+            struct_ser.serialize_field("invoice", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapByCommitmentRequest {
+impl<'de> serde::Deserialize<'de> for InvoicesResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_commitment",
-            "swapCommitment",
-            "await_detection",
-            "awaitDetection",
+            "invoice",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapCommitment,
-            AwaitDetection,
+            Invoice,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5123,8 +5452,7 @@ impl<'de> serde::Deserialize<'de> for SwapByCommitmentRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
-                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "invoice" => Ok(GeneratedField::Invoice),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5134,47 +5462,39 @@ impl<'de> serde::Deserialize<'de> for SwapByCommitmentRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapByCommitmentRequest;
+            type Value = InvoicesResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.SwapByCommitmentRequest")
+                formatter.write_str("struct penumbra.view.v1.InvoicesResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapByCommitmentRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InvoicesResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_commitment__ = None;
-                let mut await_detection__ = None;
+                let mut invoice__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapCommitment => {
-                            if swap_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
-                            }
-                            swap_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::AwaitDetection => {
-                            if await_detection__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                        GeneratedField::Invoice => {
+                            if invoice__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("invoice"));
                             }
-                            await_detection__ = Some(map_.next_value()?);
+                            invoice__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapByCommitmentRequest {
-                    swap_commitment: swap_commitment__,
-                    await_detection: await_detection__.unwrap_or_default(),
+                Ok(InvoicesResponse {
+                    invoice: invoice__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.SwapByCommitmentRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.InvoicesResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapByCommitmentResponse {
+impl serde::Serialize for NoteByCommitmentRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5182,29 +5502,39 @@ impl serde::Serialize for SwapByCommitmentResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap.is_some() {
+        if self.note_commitment.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SwapByCommitmentResponse", len)?;
-        if let Some(v) = self.swap.as_ref() {
-            struct_ser.serialize_field("swap", v)?;
+        if self.await_detection {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NoteByCommitmentRequest", len)?;
+        if let Some(v) = self.note_commitment.as_ref() {
+            struct_ser.serialize_field("noteCommitment", v)?;
+        }
+        if self.await_detection {
+            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapByCommitmentResponse {
+impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap",
+            "note_commitment",
+            "noteCommitment",
+            "await_detection",
+            "awaitDetection",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Swap,
+            NoteCommitment,
+            AwaitDetection,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5227,7 +5557,8 @@ impl<'de> serde::Deserialize<'de> for SwapByCommitmentResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swap" => Ok(GeneratedField::Swap),
+                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
+                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5237,39 +5568,47 @@ impl<'de> serde::Deserialize<'de> for SwapByCommitmentResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapByCommitmentResponse;
+            type Value = NoteByCommitmentRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.SwapByCommitmentResponse")
+                formatter.write_str("struct penumbra.view.v1.NoteByCommitmentRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapByCommitmentResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap__ = None;
+                let mut note_commitment__ = None;
+                let mut await_detection__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Swap => {
-                            if swap__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swap"));
+                        GeneratedField::NoteCommitment => {
+                            if note_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
                             }
-                            swap__ = map_.next_value()?;
+                            note_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::AwaitDetection => {
+                            if await_detection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                            }
+                            await_detection__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapByCommitmentResponse {
-                    swap: swap__,
+                Ok(NoteByCommitmentRequest {
+                    note_commitment: note_commitment__,
+                    await_detection: await_detection__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.SwapByCommitmentResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.NoteByCommitmentRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapRecord {
+impl serde::Serialize for NoteByCommitmentResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5277,82 +5616,30 @@ impl serde::Serialize for SwapRecord {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_commitment.is_some() {
-            len += 1;
-        }
-        if self.swap.is_some() {
-            len += 1;
-        }
-        if self.position != 0 {
-            len += 1;
-        }
-        if self.nullifier.is_some() {
-            len += 1;
-        }
-        if self.output_data.is_some() {
-            len += 1;
-        }
-        if self.height_claimed != 0 {
-            len += 1;
-        }
-        if self.source.is_some() {
+        if self.spendable_note.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SwapRecord", len)?;
-        if let Some(v) = self.swap_commitment.as_ref() {
-            struct_ser.serialize_field("swapCommitment", v)?;
-        }
-        if let Some(v) = self.swap.as_ref() {
-            struct_ser.serialize_field("swap", v)?;
-        }
-        if self.position != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
-        }
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
-        }
-        if let Some(v) = self.output_data.as_ref() {
-            struct_ser.serialize_field("outputData", v)?;
-        }
-        if self.height_claimed != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("heightClaimed", ToString::to_string(&self.height_claimed).as_str())?;
-        }
-        if let Some(v) = self.source.as_ref() {
-            struct_ser.serialize_field("source", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NoteByCommitmentResponse", len)?;
+        if let Some(v) = self.spendable_note.as_ref() {
+            struct_ser.serialize_field("spendableNote", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapRecord {
+impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_commitment",
-            "swapCommitment",
-            "swap",
-            "position",
-            "nullifier",
-            "output_data",
-            "outputData",
-            "height_claimed",
-            "heightClaimed",
-            "source",
+            "spendable_note",
+            "spendableNote",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapCommitment,
-            Swap,
-            Position,
-            Nullifier,
-            OutputData,
-            HeightClaimed,
-            Source,
+            SpendableNote,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5375,13 +5662,7 @@ impl<'de> serde::Deserialize<'de> for SwapRecord {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
-                            "swap" => Ok(GeneratedField::Swap),
-                            "position" => Ok(GeneratedField::Position),
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
-                            "heightClaimed" | "height_claimed" => Ok(GeneratedField::HeightClaimed),
-                            "source" => Ok(GeneratedField::Source),
+                            "spendableNote" | "spendable_note" => Ok(GeneratedField::SpendableNote),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5391,91 +5672,39 @@ impl<'de> serde::Deserialize<'de> for SwapRecord {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapRecord;
+            type Value = NoteByCommitmentResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.SwapRecord")
+                formatter.write_str("struct penumbra.view.v1.NoteByCommitmentResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapRecord, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_commitment__ = None;
-                let mut swap__ = None;
-                let mut position__ = None;
-                let mut nullifier__ = None;
-                let mut output_data__ = None;
-                let mut height_claimed__ = None;
-                let mut source__ = None;
+                let mut spendable_note__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapCommitment => {
-                            if swap_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
-                            }
-                            swap_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Swap => {
-                            if swap__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swap"));
-                            }
-                            swap__ = map_.next_value()?;
-                        }
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
-                            }
-                            position__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
-                            }
-                            nullifier__ = map_.next_value()?;
-                        }
-                        GeneratedField::OutputData => {
-                            if output_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outputData"));
-                            }
-                            output_data__ = map_.next_value()?;
-                        }
-                        GeneratedField::HeightClaimed => {
-                            if height_claimed__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("heightClaimed"));
-                            }
-                            height_claimed__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Source => {
-                            if source__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("source"));
+                        GeneratedField::SpendableNote => {
+                            if spendable_note__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spendableNote"));
                             }
-                            source__ = map_.next_value()?;
+                            spendable_note__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapRecord {
-                    swap_commitment: swap_commitment__,
-                    swap: swap__,
-                    position: position__.unwrap_or_default(),
-                    nullifier: nullifier__,
-                    output_data: output_data__,
-                    height_claimed: height_claimed__.unwrap_or_default(),
-                    source: source__,
+                Ok(NoteByCommitmentResponse {
+                    spendable_note: spendable_note__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.SwapRecord", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.NoteByCommitmentResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionInfo {
+impl serde::Serialize for NotesForVotingRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5483,62 +5712,40 @@ impl serde::Serialize for TransactionInfo {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.height != 0 {
-            len += 1;
-        }
-        if self.id.is_some() {
-            len += 1;
-        }
-        if self.transaction.is_some() {
-            len += 1;
-        }
-        if self.perspective.is_some() {
+        if self.votable_at_height != 0 {
             len += 1;
         }
-        if self.view.is_some() {
+        if self.address_index.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfo", len)?;
-        if self.height != 0 {
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesForVotingRequest", len)?;
+        if self.votable_at_height != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
-        }
-        if let Some(v) = self.id.as_ref() {
-            struct_ser.serialize_field("id", v)?;
-        }
-        if let Some(v) = self.transaction.as_ref() {
-            struct_ser.serialize_field("transaction", v)?;
-        }
-        if let Some(v) = self.perspective.as_ref() {
-            struct_ser.serialize_field("perspective", v)?;
+            struct_ser.serialize_field("votableAtHeight", ToString::to_string(&self.votable_at_height).as_str())?;
         }
-        if let Some(v) = self.view.as_ref() {
-            struct_ser.serialize_field("view", v)?;
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionInfo {
+impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "height",
-            "id",
-            "transaction",
-            "perspective",
-            "view",
+            "votable_at_height",
+            "votableAtHeight",
+            "address_index",
+            "addressIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Height,
-            Id,
-            Transaction,
-            Perspective,
-            View,
+            VotableAtHeight,
+            AddressIndex,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5561,11 +5768,8 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
                         E: serde::de::Error,
                     {
                         match value {
-                            "height" => Ok(GeneratedField::Height),
-                            "id" => Ok(GeneratedField::Id),
-                            "transaction" => Ok(GeneratedField::Transaction),
-                            "perspective" => Ok(GeneratedField::Perspective),
-                            "view" => Ok(GeneratedField::View),
+                            "votableAtHeight" | "votable_at_height" => Ok(GeneratedField::VotableAtHeight),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5575,73 +5779,49 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionInfo;
+            type Value = NotesForVotingRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionInfo")
+                formatter.write_str("struct penumbra.view.v1.NotesForVotingRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfo, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesForVotingRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut height__ = None;
-                let mut id__ = None;
-                let mut transaction__ = None;
-                let mut perspective__ = None;
-                let mut view__ = None;
+                let mut votable_at_height__ = None;
+                let mut address_index__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Height => {
-                            if height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("height"));
+                        GeneratedField::VotableAtHeight => {
+                            if votable_at_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votableAtHeight"));
                             }
-                            height__ = 
+                            votable_at_height__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::Id => {
-                            if id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("id"));
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
                             }
-                            id__ = map_.next_value()?;
+                            address_index__ = map_.next_value()?;
                         }
-                        GeneratedField::Transaction => {
-                            if transaction__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("transaction"));
-                            }
-                            transaction__ = map_.next_value()?;
-                        }
-                        GeneratedField::Perspective => {
-                            if perspective__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("perspective"));
-                            }
-                            perspective__ = map_.next_value()?;
-                        }
-                        GeneratedField::View => {
-                            if view__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("view"));
-                            }
-                            view__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(TransactionInfo {
-                    height: height__.unwrap_or_default(),
-                    id: id__,
-                    transaction: transaction__,
-                    perspective: perspective__,
-                    view: view__,
+                Ok(NotesForVotingRequest {
+                    votable_at_height: votable_at_height__.unwrap_or_default(),
+                    address_index: address_index__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfo", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.NotesForVotingRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionInfoByHashRequest {
+impl serde::Serialize for NotesForVotingResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5649,29 +5829,39 @@ impl serde::Serialize for TransactionInfoByHashRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.id.is_some() {
+        if self.note_record.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoByHashRequest", len)?;
-        if let Some(v) = self.id.as_ref() {
-            struct_ser.serialize_field("id", v)?;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesForVotingResponse", len)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
+        }
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
+impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "id",
+            "note_record",
+            "noteRecord",
+            "identity_key",
+            "identityKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Id,
+            NoteRecord,
+            IdentityKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5694,7 +5884,8 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "id" => Ok(GeneratedField::Id),
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5704,39 +5895,47 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionInfoByHashRequest;
+            type Value = NotesForVotingResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionInfoByHashRequest")
+                formatter.write_str("struct penumbra.view.v1.NotesForVotingResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoByHashRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesForVotingResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut id__ = None;
+                let mut note_record__ = None;
+                let mut identity_key__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Id => {
-                            if id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("id"));
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
                             }
-                            id__ = map_.next_value()?;
+                            note_record__ = map_.next_value()?;
+                        }
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(TransactionInfoByHashRequest {
-                    id: id__,
+                Ok(NotesForVotingResponse {
+                    note_record: note_record__,
+                    identity_key: identity_key__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoByHashRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.NotesForVotingResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionInfoByHashResponse {
+impl serde::Serialize for NotesRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5744,30 +5943,57 @@ impl serde::Serialize for TransactionInfoByHashResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.tx_info.is_some() {
+        if self.include_spent {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoByHashResponse", len)?;
-        if let Some(v) = self.tx_info.as_ref() {
-            struct_ser.serialize_field("txInfo", v)?;
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if self.amount_to_spend.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesRequest", len)?;
+        if self.include_spent {
+            struct_ser.serialize_field("includeSpent", &self.include_spent)?;
+        }
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if let Some(v) = self.amount_to_spend.as_ref() {
+            struct_ser.serialize_field("amountToSpend", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionInfoByHashResponse {
+impl<'de> serde::Deserialize<'de> for NotesRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "tx_info",
-            "txInfo",
+            "include_spent",
+            "includeSpent",
+            "asset_id",
+            "assetId",
+            "address_index",
+            "addressIndex",
+            "amount_to_spend",
+            "amountToSpend",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TxInfo,
+            IncludeSpent,
+            AssetId,
+            AddressIndex,
+            AmountToSpend,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5790,7 +6016,10 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "txInfo" | "tx_info" => Ok(GeneratedField::TxInfo),
+                            "includeSpent" | "include_spent" => Ok(GeneratedField::IncludeSpent),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "amountToSpend" | "amount_to_spend" => Ok(GeneratedField::AmountToSpend),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5800,39 +6029,63 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionInfoByHashResponse;
+            type Value = NotesRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionInfoByHashResponse")
+                formatter.write_str("struct penumbra.view.v1.NotesRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoByHashResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut tx_info__ = None;
+                let mut include_spent__ = None;
+                let mut asset_id__ = None;
+                let mut address_index__ = None;
+                let mut amount_to_spend__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TxInfo => {
-                            if tx_info__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("txInfo"));
+                        GeneratedField::IncludeSpent => {
+                            if include_spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeSpent"));
                             }
-                            tx_info__ = map_.next_value()?;
+                            include_spent__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map_.next_value()?;
+                        }
+                        GeneratedField::AmountToSpend => {
+                            if amount_to_spend__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amountToSpend"));
+                            }
+                            amount_to_spend__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(TransactionInfoByHashResponse {
-                    tx_info: tx_info__,
+                Ok(NotesRequest {
+                    include_spent: include_spent__.unwrap_or_default(),
+                    asset_id: asset_id__,
+                    address_index: address_index__,
+                    amount_to_spend: amount_to_spend__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoByHashResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.NotesRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionInfoRequest {
+impl serde::Serialize for NotesResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5840,41 +6093,30 @@ impl serde::Serialize for TransactionInfoRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.start_height != 0 {
-            len += 1;
-        }
-        if self.end_height != 0 {
+        if self.note_record.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoRequest", len)?;
-        if self.start_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
-        }
-        if self.end_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesResponse", len)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
+impl<'de> serde::Deserialize<'de> for NotesResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "start_height",
-            "startHeight",
-            "end_height",
-            "endHeight",
+            "note_record",
+            "noteRecord",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            StartHeight,
-            EndHeight,
+            NoteRecord,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5897,8 +6139,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
-                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5908,51 +6149,39 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionInfoRequest;
+            type Value = NotesResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionInfoRequest")
+                formatter.write_str("struct penumbra.view.v1.NotesResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut start_height__ = None;
-                let mut end_height__ = None;
+                let mut note_record__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::StartHeight => {
-                            if start_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startHeight"));
-                            }
-                            start_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::EndHeight => {
-                            if end_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
                             }
-                            end_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            note_record__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(TransactionInfoRequest {
-                    start_height: start_height__.unwrap_or_default(),
-                    end_height: end_height__.unwrap_or_default(),
+                Ok(NotesResponse {
+                    note_record: note_record__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.NotesResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionInfoResponse {
+impl serde::Serialize for NullifierStatusRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5960,30 +6189,38 @@ impl serde::Serialize for TransactionInfoResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.tx_info.is_some() {
+        if self.nullifier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoResponse", len)?;
-        if let Some(v) = self.tx_info.as_ref() {
-            struct_ser.serialize_field("txInfo", v)?;
+        if self.await_detection {
+            len += 1;
         }
-        struct_ser.end()
-    }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NullifierStatusRequest", len)?;
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        if self.await_detection {
+            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        }
+        struct_ser.end()
+    }
 }
-impl<'de> serde::Deserialize<'de> for TransactionInfoResponse {
+impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "tx_info",
-            "txInfo",
+            "nullifier",
+            "await_detection",
+            "awaitDetection",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TxInfo,
+            Nullifier,
+            AwaitDetection,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6006,7 +6243,8 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "txInfo" | "tx_info" => Ok(GeneratedField::TxInfo),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6016,39 +6254,47 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionInfoResponse;
+            type Value = NullifierStatusRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionInfoResponse")
+                formatter.write_str("struct penumbra.view.v1.NullifierStatusRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut tx_info__ = None;
+                let mut nullifier__ = None;
+                let mut await_detection__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TxInfo => {
-                            if tx_info__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("txInfo"));
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
                             }
-                            tx_info__ = map_.next_value()?;
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::AwaitDetection => {
+                            if await_detection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                            }
+                            await_detection__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(TransactionInfoResponse {
-                    tx_info: tx_info__,
+                Ok(NullifierStatusRequest {
+                    nullifier: nullifier__,
+                    await_detection: await_detection__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.NullifierStatusRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionPlannerRequest {
+impl serde::Serialize for NullifierStatusResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6056,223 +6302,29 @@ impl serde::Serialize for TransactionPlannerRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.expiry_height != 0 {
-            len += 1;
-        }
-        if self.memo.is_some() {
-            len += 1;
-        }
-        if self.source.is_some() {
-            len += 1;
-        }
-        if !self.outputs.is_empty() {
-            len += 1;
-        }
-        if !self.spends.is_empty() {
-            len += 1;
-        }
-        if !self.swaps.is_empty() {
-            len += 1;
-        }
-        if !self.swap_claims.is_empty() {
-            len += 1;
-        }
-        if !self.delegations.is_empty() {
-            len += 1;
-        }
-        if !self.undelegations.is_empty() {
-            len += 1;
-        }
-        if !self.undelegation_claims.is_empty() {
-            len += 1;
-        }
-        if !self.ibc_relay_actions.is_empty() {
-            len += 1;
-        }
-        if !self.ics20_withdrawals.is_empty() {
-            len += 1;
-        }
-        if !self.position_opens.is_empty() {
-            len += 1;
-        }
-        if !self.position_closes.is_empty() {
-            len += 1;
-        }
-        if !self.position_withdraws.is_empty() {
-            len += 1;
-        }
-        if !self.dutch_auction_schedule_actions.is_empty() {
-            len += 1;
-        }
-        if !self.dutch_auction_end_actions.is_empty() {
-            len += 1;
-        }
-        if !self.dutch_auction_withdraw_actions.is_empty() {
-            len += 1;
-        }
-        if !self.delegator_votes.is_empty() {
-            len += 1;
-        }
-        if self.epoch_index != 0 {
-            len += 1;
-        }
-        if self.epoch.is_some() {
-            len += 1;
-        }
-        if self.fee_mode.is_some() {
+        if self.spent {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest", len)?;
-        if self.expiry_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("expiryHeight", ToString::to_string(&self.expiry_height).as_str())?;
-        }
-        if let Some(v) = self.memo.as_ref() {
-            struct_ser.serialize_field("memo", v)?;
-        }
-        if let Some(v) = self.source.as_ref() {
-            struct_ser.serialize_field("source", v)?;
-        }
-        if !self.outputs.is_empty() {
-            struct_ser.serialize_field("outputs", &self.outputs)?;
-        }
-        if !self.spends.is_empty() {
-            struct_ser.serialize_field("spends", &self.spends)?;
-        }
-        if !self.swaps.is_empty() {
-            struct_ser.serialize_field("swaps", &self.swaps)?;
-        }
-        if !self.swap_claims.is_empty() {
-            struct_ser.serialize_field("swapClaims", &self.swap_claims)?;
-        }
-        if !self.delegations.is_empty() {
-            struct_ser.serialize_field("delegations", &self.delegations)?;
-        }
-        if !self.undelegations.is_empty() {
-            struct_ser.serialize_field("undelegations", &self.undelegations)?;
-        }
-        if !self.undelegation_claims.is_empty() {
-            struct_ser.serialize_field("undelegationClaims", &self.undelegation_claims)?;
-        }
-        if !self.ibc_relay_actions.is_empty() {
-            struct_ser.serialize_field("ibcRelayActions", &self.ibc_relay_actions)?;
-        }
-        if !self.ics20_withdrawals.is_empty() {
-            struct_ser.serialize_field("ics20Withdrawals", &self.ics20_withdrawals)?;
-        }
-        if !self.position_opens.is_empty() {
-            struct_ser.serialize_field("positionOpens", &self.position_opens)?;
-        }
-        if !self.position_closes.is_empty() {
-            struct_ser.serialize_field("positionCloses", &self.position_closes)?;
-        }
-        if !self.position_withdraws.is_empty() {
-            struct_ser.serialize_field("positionWithdraws", &self.position_withdraws)?;
-        }
-        if !self.dutch_auction_schedule_actions.is_empty() {
-            struct_ser.serialize_field("dutchAuctionScheduleActions", &self.dutch_auction_schedule_actions)?;
-        }
-        if !self.dutch_auction_end_actions.is_empty() {
-            struct_ser.serialize_field("dutchAuctionEndActions", &self.dutch_auction_end_actions)?;
-        }
-        if !self.dutch_auction_withdraw_actions.is_empty() {
-            struct_ser.serialize_field("dutchAuctionWithdrawActions", &self.dutch_auction_withdraw_actions)?;
-        }
-        if !self.delegator_votes.is_empty() {
-            struct_ser.serialize_field("delegatorVotes", &self.delegator_votes)?;
-        }
-        if self.epoch_index != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
-        }
-        if let Some(v) = self.epoch.as_ref() {
-            struct_ser.serialize_field("epoch", v)?;
-        }
-        if let Some(v) = self.fee_mode.as_ref() {
-            match v {
-                transaction_planner_request::FeeMode::AutoFee(v) => {
-                    struct_ser.serialize_field("autoFee", v)?;
-                }
-                transaction_planner_request::FeeMode::ManualFee(v) => {
-                    struct_ser.serialize_field("manualFee", v)?;
-                }
-            }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NullifierStatusResponse", len)?;
+        if self.spent {
+            struct_ser.serialize_field("spent", &self.spent)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
+impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "expiry_height",
-            "expiryHeight",
-            "memo",
-            "source",
-            "outputs",
-            "spends",
-            "swaps",
-            "swap_claims",
-            "swapClaims",
-            "delegations",
-            "undelegations",
-            "undelegation_claims",
-            "undelegationClaims",
-            "ibc_relay_actions",
-            "ibcRelayActions",
-            "ics20_withdrawals",
-            "ics20Withdrawals",
-            "position_opens",
-            "positionOpens",
-            "position_closes",
-            "positionCloses",
-            "position_withdraws",
-            "positionWithdraws",
-            "dutch_auction_schedule_actions",
-            "dutchAuctionScheduleActions",
-            "dutch_auction_end_actions",
-            "dutchAuctionEndActions",
-            "dutch_auction_withdraw_actions",
-            "dutchAuctionWithdrawActions",
-            "delegator_votes",
-            "delegatorVotes",
-            "epoch_index",
-            "epochIndex",
-            "epoch",
-            "auto_fee",
-            "autoFee",
-            "manual_fee",
-            "manualFee",
+            "spent",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ExpiryHeight,
-            Memo,
-            Source,
-            Outputs,
-            Spends,
-            Swaps,
-            SwapClaims,
-            Delegations,
-            Undelegations,
-            UndelegationClaims,
-            IbcRelayActions,
-            Ics20Withdrawals,
-            PositionOpens,
-            PositionCloses,
-            PositionWithdraws,
-            DutchAuctionScheduleActions,
-            DutchAuctionEndActions,
-            DutchAuctionWithdrawActions,
-            DelegatorVotes,
-            EpochIndex,
-            Epoch,
-            AutoFee,
-            ManualFee,
+            Spent,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6295,29 +6347,7 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "expiryHeight" | "expiry_height" => Ok(GeneratedField::ExpiryHeight),
-                            "memo" => Ok(GeneratedField::Memo),
-                            "source" => Ok(GeneratedField::Source),
-                            "outputs" => Ok(GeneratedField::Outputs),
-                            "spends" => Ok(GeneratedField::Spends),
-                            "swaps" => Ok(GeneratedField::Swaps),
-                            "swapClaims" | "swap_claims" => Ok(GeneratedField::SwapClaims),
-                            "delegations" => Ok(GeneratedField::Delegations),
-                            "undelegations" => Ok(GeneratedField::Undelegations),
-                            "undelegationClaims" | "undelegation_claims" => Ok(GeneratedField::UndelegationClaims),
-                            "ibcRelayActions" | "ibc_relay_actions" => Ok(GeneratedField::IbcRelayActions),
-                            "ics20Withdrawals" | "ics20_withdrawals" => Ok(GeneratedField::Ics20Withdrawals),
-                            "positionOpens" | "position_opens" => Ok(GeneratedField::PositionOpens),
-                            "positionCloses" | "position_closes" => Ok(GeneratedField::PositionCloses),
-                            "positionWithdraws" | "position_withdraws" => Ok(GeneratedField::PositionWithdraws),
-                            "dutchAuctionScheduleActions" | "dutch_auction_schedule_actions" => Ok(GeneratedField::DutchAuctionScheduleActions),
-                            "dutchAuctionEndActions" | "dutch_auction_end_actions" => Ok(GeneratedField::DutchAuctionEndActions),
-                            "dutchAuctionWithdrawActions" | "dutch_auction_withdraw_actions" => Ok(GeneratedField::DutchAuctionWithdrawActions),
-                            "delegatorVotes" | "delegator_votes" => Ok(GeneratedField::DelegatorVotes),
-                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
-                            "epoch" => Ok(GeneratedField::Epoch),
-                            "autoFee" | "auto_fee" => Ok(GeneratedField::AutoFee),
-                            "manualFee" | "manual_fee" => Ok(GeneratedField::ManualFee),
+                            "spent" => Ok(GeneratedField::Spent),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6327,219 +6357,3729 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionPlannerRequest;
+            type Value = NullifierStatusResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest")
+                formatter.write_str("struct penumbra.view.v1.NullifierStatusResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionPlannerRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut expiry_height__ = None;
-                let mut memo__ = None;
-                let mut source__ = None;
-                let mut outputs__ = None;
-                let mut spends__ = None;
-                let mut swaps__ = None;
-                let mut swap_claims__ = None;
-                let mut delegations__ = None;
-                let mut undelegations__ = None;
-                let mut undelegation_claims__ = None;
-                let mut ibc_relay_actions__ = None;
-                let mut ics20_withdrawals__ = None;
-                let mut position_opens__ = None;
-                let mut position_closes__ = None;
-                let mut position_withdraws__ = None;
-                let mut dutch_auction_schedule_actions__ = None;
-                let mut dutch_auction_end_actions__ = None;
-                let mut dutch_auction_withdraw_actions__ = None;
-                let mut delegator_votes__ = None;
-                let mut epoch_index__ = None;
-                let mut epoch__ = None;
-                let mut fee_mode__ = None;
+                let mut spent__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ExpiryHeight => {
-                            if expiry_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("expiryHeight"));
-                            }
-                            expiry_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Memo => {
-                            if memo__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("memo"));
-                            }
-                            memo__ = map_.next_value()?;
-                        }
-                        GeneratedField::Source => {
-                            if source__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("source"));
-                            }
-                            source__ = map_.next_value()?;
-                        }
-                        GeneratedField::Outputs => {
-                            if outputs__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outputs"));
-                            }
-                            outputs__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Spends => {
-                            if spends__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("spends"));
-                            }
-                            spends__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Swaps => {
-                            if swaps__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swaps"));
-                            }
-                            swaps__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::SwapClaims => {
-                            if swap_claims__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapClaims"));
-                            }
-                            swap_claims__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Delegations => {
-                            if delegations__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delegations"));
-                            }
-                            delegations__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Undelegations => {
-                            if undelegations__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("undelegations"));
-                            }
-                            undelegations__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::UndelegationClaims => {
-                            if undelegation_claims__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("undelegationClaims"));
+                        GeneratedField::Spent => {
+                            if spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spent"));
                             }
-                            undelegation_claims__ = Some(map_.next_value()?);
+                            spent__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::IbcRelayActions => {
-                            if ibc_relay_actions__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("ibcRelayActions"));
-                            }
-                            ibc_relay_actions__ = Some(map_.next_value()?);
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
-                        GeneratedField::Ics20Withdrawals => {
-                            if ics20_withdrawals__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("ics20Withdrawals"));
-                            }
-                            ics20_withdrawals__ = Some(map_.next_value()?);
+                    }
+                }
+                Ok(NullifierStatusResponse {
+                    spent: spent__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.NullifierStatusResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OwnedPositionIdsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_state.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.OwnedPositionIdsRequest", len)?;
+        if let Some(v) = self.position_state.as_ref() {
+            struct_ser.serialize_field("positionState", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_state",
+            "positionState",
+            "trading_pair",
+            "tradingPair",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionState,
+            TradingPair,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionState" | "position_state" => Ok(GeneratedField::PositionState),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            _ => Ok(GeneratedField::__SkipField__),
                         }
-                        GeneratedField::PositionOpens => {
-                            if position_opens__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionOpens"));
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OwnedPositionIdsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.OwnedPositionIdsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_state__ = None;
+                let mut trading_pair__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionState => {
+                            if position_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionState"));
                             }
-                            position_opens__ = Some(map_.next_value()?);
+                            position_state__ = map_.next_value()?;
                         }
-                        GeneratedField::PositionCloses => {
-                            if position_closes__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionCloses"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            position_closes__ = Some(map_.next_value()?);
+                            trading_pair__ = map_.next_value()?;
                         }
-                        GeneratedField::PositionWithdraws => {
-                            if position_withdraws__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionWithdraws"));
-                            }
-                            position_withdraws__ = Some(map_.next_value()?);
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
-                        GeneratedField::DutchAuctionScheduleActions => {
+                    }
+                }
+                Ok(OwnedPositionIdsRequest {
+                    position_state: position_state__,
+                    trading_pair: trading_pair__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.OwnedPositionIdsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OwnedPositionIdsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.OwnedPositionIdsResponse", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OwnedPositionIdsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.OwnedPositionIdsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(OwnedPositionIdsResponse {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SetAccountLabelRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.account_label.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SetAccountLabelRequest", len)?;
+        if let Some(v) = self.account_label.as_ref() {
+            struct_ser.serialize_field("accountLabel", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SetAccountLabelRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "account_label",
+            "accountLabel",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AccountLabel,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "accountLabel" | "account_label" => Ok(GeneratedField::AccountLabel),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SetAccountLabelRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.SetAccountLabelRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SetAccountLabelRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut account_label__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AccountLabel => {
+                            if account_label__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountLabel"));
+                            }
+                            account_label__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SetAccountLabelRequest {
+                    account_label: account_label__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.SetAccountLabelRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SetAccountLabelResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.SetAccountLabelResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SetAccountLabelResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SetAccountLabelResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.SetAccountLabelResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SetAccountLabelResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(SetAccountLabelResponse {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.SetAccountLabelResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SetContactRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.contact.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SetContactRequest", len)?;
+        if let Some(v) = self.contact.as_ref() {
+            struct_ser.serialize_field("contact", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SetContactRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "contact",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Contact,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "contact" => Ok(GeneratedField::Contact),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SetContactRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.SetContactRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SetContactRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut contact__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Contact => {
+                            if contact__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("contact"));
+                            }
+                            contact__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SetContactRequest {
+                    contact: contact__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.SetContactRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SetContactResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.SetContactResponse", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SetContactResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SetContactResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.SetContactResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SetContactResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(SetContactResponse {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.SetContactResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SpendableNoteRecord {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.note_commitment.is_some() {
+            len += 1;
+        }
+        if self.note.is_some() {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        if self.height_created != 0 {
+            len += 1;
+        }
+        if self.height_spent != 0 {
+            len += 1;
+        }
+        if self.position != 0 {
+            len += 1;
+        }
+        if self.source.is_some() {
+            len += 1;
+        }
+        if self.return_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SpendableNoteRecord", len)?;
+        if let Some(v) = self.note_commitment.as_ref() {
+            struct_ser.serialize_field("noteCommitment", v)?;
+        }
+        if let Some(v) = self.note.as_ref() {
+            struct_ser.serialize_field("note", v)?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        if self.height_created != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightCreated", ToString::to_string(&self.height_created).as_str())?;
+        }
+        if self.height_spent != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightSpent", ToString::to_string(&self.height_spent).as_str())?;
+        }
+        if self.position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
+        }
+        if let Some(v) = self.source.as_ref() {
+            struct_ser.serialize_field("source", v)?;
+        }
+        if let Some(v) = self.return_address.as_ref() {
+            struct_ser.serialize_field("returnAddress", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "note_commitment",
+            "noteCommitment",
+            "note",
+            "address_index",
+            "addressIndex",
+            "nullifier",
+            "height_created",
+            "heightCreated",
+            "height_spent",
+            "heightSpent",
+            "position",
+            "source",
+            "return_address",
+            "returnAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NoteCommitment,
+            Note,
+            AddressIndex,
+            Nullifier,
+            HeightCreated,
+            HeightSpent,
+            Position,
+            Source,
+            ReturnAddress,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
+                            "note" => Ok(GeneratedField::Note),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "heightCreated" | "height_created" => Ok(GeneratedField::HeightCreated),
+                            "heightSpent" | "height_spent" => Ok(GeneratedField::HeightSpent),
+                            "position" => Ok(GeneratedField::Position),
+                            "source" => Ok(GeneratedField::Source),
+                            "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SpendableNoteRecord;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.SpendableNoteRecord")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpendableNoteRecord, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut note_commitment__ = None;
+                let mut note__ = None;
+                let mut address_index__ = None;
+                let mut nullifier__ = None;
+                let mut height_created__ = None;
+                let mut height_spent__ = None;
+                let mut position__ = None;
+                let mut source__ = None;
+                let mut return_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NoteCommitment => {
+                            if note_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
+                            }
+                            note_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Note => {
+                            if note__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("note"));
+                            }
+                            note__ = map_.next_value()?;
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map_.next_value()?;
+                        }
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeightCreated => {
+                            if height_created__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightCreated"));
+                            }
+                            height_created__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::HeightSpent => {
+                            if height_spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightSpent"));
+                            }
+                            height_spent__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Source => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("source"));
+                            }
+                            source__ = map_.next_value()?;
+                        }
+                        GeneratedField::ReturnAddress => {
+                            if return_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("returnAddress"));
+                            }
+                            return_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SpendableNoteRecord {
+                    note_commitment: note_commitment__,
+                    note: note__,
+                    address_index: address_index__,
+                    nullifier: nullifier__,
+                    height_created: height_created__.unwrap_or_default(),
+                    height_spent: height_spent__.unwrap_or_default(),
+                    position: position__.unwrap_or_default(),
+                    source: source__,
+                    return_address: return_address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.SpendableNoteRecord", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.StatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(StatusRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.StatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.full_sync_height != 0 {
+            len += 1;
+        }
+        if self.partial_sync_height != 0 {
+            len += 1;
+        }
+        if self.catching_up {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusResponse", len)?;
+        if self.full_sync_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fullSyncHeight", ToString::to_string(&self.full_sync_height).as_str())?;
+        }
+        if self.partial_sync_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("partialSyncHeight", ToString::to_string(&self.partial_sync_height).as_str())?;
+        }
+        if self.catching_up {
+            struct_ser.serialize_field("catchingUp", &self.catching_up)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "full_sync_height",
+            "fullSyncHeight",
+            "partial_sync_height",
+            "partialSyncHeight",
+            "catching_up",
+            "catchingUp",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FullSyncHeight,
+            PartialSyncHeight,
+            CatchingUp,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fullSyncHeight" | "full_sync_height" => Ok(GeneratedField::FullSyncHeight),
+                            "partialSyncHeight" | "partial_sync_height" => Ok(GeneratedField::PartialSyncHeight),
+                            "catchingUp" | "catching_up" => Ok(GeneratedField::CatchingUp),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StatusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.StatusResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut full_sync_height__ = None;
+                let mut partial_sync_height__ = None;
+                let mut catching_up__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FullSyncHeight => {
+                            if full_sync_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fullSyncHeight"));
+                            }
+                            full_sync_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PartialSyncHeight => {
+                            if partial_sync_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partialSyncHeight"));
+                            }
+                            partial_sync_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CatchingUp => {
+                            if catching_up__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("catchingUp"));
+                            }
+                            catching_up__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(StatusResponse {
+                    full_sync_height: full_sync_height__.unwrap_or_default(),
+                    partial_sync_height: partial_sync_height__.unwrap_or_default(),
+                    catching_up: catching_up__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.StatusResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatusStreamRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusStreamRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatusStreamRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StatusStreamRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.StatusStreamRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusStreamRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(StatusStreamRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.StatusStreamRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatusStreamResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.latest_known_block_height != 0 {
+            len += 1;
+        }
+        if self.full_sync_height != 0 {
+            len += 1;
+        }
+        if self.partial_sync_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusStreamResponse", len)?;
+        if self.latest_known_block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("latestKnownBlockHeight", ToString::to_string(&self.latest_known_block_height).as_str())?;
+        }
+        if self.full_sync_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fullSyncHeight", ToString::to_string(&self.full_sync_height).as_str())?;
+        }
+        if self.partial_sync_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("partialSyncHeight", ToString::to_string(&self.partial_sync_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatusStreamResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "latest_known_block_height",
+            "latestKnownBlockHeight",
+            "full_sync_height",
+            "fullSyncHeight",
+            "partial_sync_height",
+            "partialSyncHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            LatestKnownBlockHeight,
+            FullSyncHeight,
+            PartialSyncHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "latestKnownBlockHeight" | "latest_known_block_height" => Ok(GeneratedField::LatestKnownBlockHeight),
+                            "fullSyncHeight" | "full_sync_height" => Ok(GeneratedField::FullSyncHeight),
+                            "partialSyncHeight" | "partial_sync_height" => Ok(GeneratedField::PartialSyncHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StatusStreamResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.StatusStreamResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusStreamResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut latest_known_block_height__ = None;
+                let mut full_sync_height__ = None;
+                let mut partial_sync_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::LatestKnownBlockHeight => {
+                            if latest_known_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("latestKnownBlockHeight"));
+                            }
+                            latest_known_block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FullSyncHeight => {
+                            if full_sync_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fullSyncHeight"));
+                            }
+                            full_sync_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PartialSyncHeight => {
+                            if partial_sync_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partialSyncHeight"));
+                            }
+                            partial_sync_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(StatusStreamResponse {
+                    latest_known_block_height: latest_known_block_height__.unwrap_or_default(),
+                    full_sync_height: full_sync_height__.unwrap_or_default(),
+                    partial_sync_height: partial_sync_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.StatusStreamResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SwapByCommitmentRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.swap_commitment.is_some() {
+            len += 1;
+        }
+        if self.await_detection {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SwapByCommitmentRequest", len)?;
+        if let Some(v) = self.swap_commitment.as_ref() {
+            struct_ser.serialize_field("swapCommitment", v)?;
+        }
+        if self.await_detection {
+            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SwapByCommitmentRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "swap_commitment",
+            "swapCommitment",
+            "await_detection",
+            "awaitDetection",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SwapCommitment,
+            AwaitDetection,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
+                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SwapByCommitmentRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.SwapByCommitmentRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapByCommitmentRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut swap_commitment__ = None;
+                let mut await_detection__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SwapCommitment => {
+                            if swap_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
+                            }
+                            swap_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::AwaitDetection => {
+                            if await_detection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                            }
+                            await_detection__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SwapByCommitmentRequest {
+                    swap_commitment: swap_commitment__,
+                    await_detection: await_detection__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.SwapByCommitmentRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SwapByCommitmentResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.swap.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SwapByCommitmentResponse", len)?;
+        if let Some(v) = self.swap.as_ref() {
+            struct_ser.serialize_field("swap", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SwapByCommitmentResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "swap",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Swap,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "swap" => Ok(GeneratedField::Swap),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SwapByCommitmentResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.SwapByCommitmentResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapByCommitmentResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut swap__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Swap => {
+                            if swap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swap"));
+                            }
+                            swap__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SwapByCommitmentResponse {
+                    swap: swap__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.SwapByCommitmentResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SwapRecord {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.swap_commitment.is_some() {
+            len += 1;
+        }
+        if self.swap.is_some() {
+            len += 1;
+        }
+        if self.position != 0 {
+            len += 1;
+        }
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        if self.output_data.is_some() {
+            len += 1;
+        }
+        if self.height_claimed != 0 {
+            len += 1;
+        }
+        if self.source.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SwapRecord", len)?;
+        if let Some(v) = self.swap_commitment.as_ref() {
+            struct_ser.serialize_field("swapCommitment", v)?;
+        }
+        if let Some(v) = self.swap.as_ref() {
+            struct_ser.serialize_field("swap", v)?;
+        }
+        if self.position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
+        }
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        if let Some(v) = self.output_data.as_ref() {
+            struct_ser.serialize_field("outputData", v)?;
+        }
+        if self.height_claimed != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightClaimed", ToString::to_string(&self.height_claimed).as_str())?;
+        }
+        if let Some(v) = self.source.as_ref() {
+            struct_ser.serialize_field("source", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SwapRecord {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "swap_commitment",
+            "swapCommitment",
+            "swap",
+            "position",
+            "nullifier",
+            "output_data",
+            "outputData",
+            "height_claimed",
+            "heightClaimed",
+            "source",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SwapCommitment,
+            Swap,
+            Position,
+            Nullifier,
+            OutputData,
+            HeightClaimed,
+            Source,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
+                            "swap" => Ok(GeneratedField::Swap),
+                            "position" => Ok(GeneratedField::Position),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
+                            "heightClaimed" | "height_claimed" => Ok(GeneratedField::HeightClaimed),
+                            "source" => Ok(GeneratedField::Source),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SwapRecord;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.SwapRecord")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapRecord, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut swap_commitment__ = None;
+                let mut swap__ = None;
+                let mut position__ = None;
+                let mut nullifier__ = None;
+                let mut output_data__ = None;
+                let mut height_claimed__ = None;
+                let mut source__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SwapCommitment => {
+                            if swap_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
+                            }
+                            swap_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Swap => {
+                            if swap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swap"));
+                            }
+                            swap__ = map_.next_value()?;
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::OutputData => {
+                            if output_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputData"));
+                            }
+                            output_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeightClaimed => {
+                            if height_claimed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightClaimed"));
+                            }
+                            height_claimed__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Source => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("source"));
+                            }
+                            source__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SwapRecord {
+                    swap_commitment: swap_commitment__,
+                    swap: swap__,
+                    position: position__.unwrap_or_default(),
+                    nullifier: nullifier__,
+                    output_data: output_data__,
+                    height_claimed: height_claimed__.unwrap_or_default(),
+                    source: source__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.SwapRecord", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.id.is_some() {
+            len += 1;
+        }
+        if self.transaction.is_some() {
+            len += 1;
+        }
+        if self.perspective.is_some() {
+            len += 1;
+        }
+        if self.view.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfo", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
+        }
+        if let Some(v) = self.transaction.as_ref() {
+            struct_ser.serialize_field("transaction", v)?;
+        }
+        if let Some(v) = self.perspective.as_ref() {
+            struct_ser.serialize_field("perspective", v)?;
+        }
+        if let Some(v) = self.view.as_ref() {
+            struct_ser.serialize_field("view", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "id",
+            "transaction",
+            "perspective",
+            "view",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            Id,
+            Transaction,
+            Perspective,
+            View,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "id" => Ok(GeneratedField::Id),
+                            "transaction" => Ok(GeneratedField::Transaction),
+                            "perspective" => Ok(GeneratedField::Perspective),
+                            "view" => Ok(GeneratedField::View),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut id__ = None;
+                let mut transaction__ = None;
+                let mut perspective__ = None;
+                let mut view__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Transaction => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transaction"));
+                            }
+                            transaction__ = map_.next_value()?;
+                        }
+                        GeneratedField::Perspective => {
+                            if perspective__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("perspective"));
+                            }
+                            perspective__ = map_.next_value()?;
+                        }
+                        GeneratedField::View => {
+                            if view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("view"));
+                            }
+                            view__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionInfo {
+                    height: height__.unwrap_or_default(),
+                    id: id__,
+                    transaction: transaction__,
+                    perspective: perspective__,
+                    view: view__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionInfoByHashRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoByHashRequest", len)?;
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionInfoByHashRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionInfoByHashRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoByHashRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionInfoByHashRequest {
+                    id: id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoByHashRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionInfoByHashResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.tx_info.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoByHashResponse", len)?;
+        if let Some(v) = self.tx_info.as_ref() {
+            struct_ser.serialize_field("txInfo", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionInfoByHashResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "tx_info",
+            "txInfo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TxInfo,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "txInfo" | "tx_info" => Ok(GeneratedField::TxInfo),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionInfoByHashResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionInfoByHashResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoByHashResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut tx_info__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TxInfo => {
+                            if tx_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txInfo"));
+                            }
+                            tx_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionInfoByHashResponse {
+                    tx_info: tx_info__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoByHashResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionInfoRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoRequest", len)?;
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartHeight,
+            EndHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionInfoRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionInfoRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionInfoRequest {
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionInfoResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.tx_info.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoResponse", len)?;
+        if let Some(v) = self.tx_info.as_ref() {
+            struct_ser.serialize_field("txInfo", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionInfoResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "tx_info",
+            "txInfo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TxInfo,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "txInfo" | "tx_info" => Ok(GeneratedField::TxInfo),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionInfoResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionInfoResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut tx_info__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TxInfo => {
+                            if tx_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txInfo"));
+                            }
+                            tx_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionInfoResponse {
+                    tx_info: tx_info__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionPlannerRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.expiry_height != 0 {
+            len += 1;
+        }
+        if self.memo.is_some() {
+            len += 1;
+        }
+        if self.source.is_some() {
+            len += 1;
+        }
+        if !self.outputs.is_empty() {
+            len += 1;
+        }
+        if !self.spends.is_empty() {
+            len += 1;
+        }
+        if !self.swaps.is_empty() {
+            len += 1;
+        }
+        if !self.swap_claims.is_empty() {
+            len += 1;
+        }
+        if !self.delegations.is_empty() {
+            len += 1;
+        }
+        if !self.undelegations.is_empty() {
+            len += 1;
+        }
+        if !self.undelegation_claims.is_empty() {
+            len += 1;
+        }
+        if !self.ibc_relay_actions.is_empty() {
+            len += 1;
+        }
+        if !self.ics20_withdrawals.is_empty() {
+            len += 1;
+        }
+        if !self.position_opens.is_empty() {
+            len += 1;
+        }
+        if !self.position_closes.is_empty() {
+            len += 1;
+        }
+        if !self.position_withdraws.is_empty() {
+            len += 1;
+        }
+        if !self.dutch_auction_schedule_actions.is_empty() {
+            len += 1;
+        }
+        if !self.dutch_auction_end_actions.is_empty() {
+            len += 1;
+        }
+        if !self.dutch_auction_withdraw_actions.is_empty() {
+            len += 1;
+        }
+        if !self.delegator_votes.is_empty() {
+            len += 1;
+        }
+        if self.epoch_index != 0 {
+            len += 1;
+        }
+        if self.epoch.is_some() {
+            len += 1;
+        }
+        if self.note_selection != 0 {
+            len += 1;
+        }
+        if self.fee_mode.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest", len)?;
+        if self.expiry_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expiryHeight", ToString::to_string(&self.expiry_height).as_str())?;
+        }
+        if let Some(v) = self.memo.as_ref() {
+            struct_ser.serialize_field("memo", v)?;
+        }
+        if let Some(v) = self.source.as_ref() {
+            struct_ser.serialize_field("source", v)?;
+        }
+        if !self.outputs.is_empty() {
+            struct_ser.serialize_field("outputs", &self.outputs)?;
+        }
+        if !self.spends.is_empty() {
+            struct_ser.serialize_field("spends", &self.spends)?;
+        }
+        if !self.swaps.is_empty() {
+            struct_ser.serialize_field("swaps", &self.swaps)?;
+        }
+        if !self.swap_claims.is_empty() {
+            struct_ser.serialize_field("swapClaims", &self.swap_claims)?;
+        }
+        if !self.delegations.is_empty() {
+            struct_ser.serialize_field("delegations", &self.delegations)?;
+        }
+        if !self.undelegations.is_empty() {
+            struct_ser.serialize_field("undelegations", &self.undelegations)?;
+        }
+        if !self.undelegation_claims.is_empty() {
+            struct_ser.serialize_field("undelegationClaims", &self.undelegation_claims)?;
+        }
+        if !self.ibc_relay_actions.is_empty() {
+            struct_ser.serialize_field("ibcRelayActions", &self.ibc_relay_actions)?;
+        }
+        if !self.ics20_withdrawals.is_empty() {
+            struct_ser.serialize_field("ics20Withdrawals", &self.ics20_withdrawals)?;
+        }
+        if !self.position_opens.is_empty() {
+            struct_ser.serialize_field("positionOpens", &self.position_opens)?;
+        }
+        if !self.position_closes.is_empty() {
+            struct_ser.serialize_field("positionCloses", &self.position_closes)?;
+        }
+        if !self.position_withdraws.is_empty() {
+            struct_ser.serialize_field("positionWithdraws", &self.position_withdraws)?;
+        }
+        if !self.dutch_auction_schedule_actions.is_empty() {
+            struct_ser.serialize_field("dutchAuctionScheduleActions", &self.dutch_auction_schedule_actions)?;
+        }
+        if !self.dutch_auction_end_actions.is_empty() {
+            struct_ser.serialize_field("dutchAuctionEndActions", &self.dutch_auction_end_actions)?;
+        }
+        if !self.dutch_auction_withdraw_actions.is_empty() {
+            struct_ser.serialize_field("dutchAuctionWithdrawActions", &self.dutch_auction_withdraw_actions)?;
+        }
+        if !self.delegator_votes.is_empty() {
+            struct_ser.serialize_field("delegatorVotes", &self.delegator_votes)?;
+        }
+        if self.epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+        }
+        if let Some(v) = self.epoch.as_ref() {
+            struct_ser.serialize_field("epoch", v)?;
+        }
+        if self.note_selection != 0 {
+            let v = transaction_planner_request::NoteSelection::try_from(self.note_selection)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.note_selection)))?;
+            struct_ser.serialize_field("noteSelection", &v)?;
+        }
+        if let Some(v) = self.fee_mode.as_ref() {
+            match v {
+                transaction_planner_request::FeeMode::AutoFee(v) => {
+                    struct_ser.serialize_field("autoFee", v)?;
+                }
+                transaction_planner_request::FeeMode::ManualFee(v) => {
+                    struct_ser.serialize_field("manualFee", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "expiry_height",
+            "expiryHeight",
+            "memo",
+            "source",
+            "outputs",
+            "spends",
+            "swaps",
+            "swap_claims",
+            "swapClaims",
+            "delegations",
+            "undelegations",
+            "undelegation_claims",
+            "undelegationClaims",
+            "ibc_relay_actions",
+            "ibcRelayActions",
+            "ics20_withdrawals",
+            "ics20Withdrawals",
+            "position_opens",
+            "positionOpens",
+            "position_closes",
+            "positionCloses",
+            "position_withdraws",
+            "positionWithdraws",
+            "dutch_auction_schedule_actions",
+            "dutchAuctionScheduleActions",
+            "dutch_auction_end_actions",
+            "dutchAuctionEndActions",
+            "dutch_auction_withdraw_actions",
+            "dutchAuctionWithdrawActions",
+            "delegator_votes",
+            "delegatorVotes",
+            "epoch_index",
+            "epochIndex",
+            "epoch",
+            "note_selection",
+            "noteSelection",
+            "auto_fee",
+            "autoFee",
+            "manual_fee",
+            "manualFee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ExpiryHeight,
+            Memo,
+            Source,
+            Outputs,
+            Spends,
+            Swaps,
+            SwapClaims,
+            Delegations,
+            Undelegations,
+            UndelegationClaims,
+            IbcRelayActions,
+            Ics20Withdrawals,
+            PositionOpens,
+            PositionCloses,
+            PositionWithdraws,
+            DutchAuctionScheduleActions,
+            DutchAuctionEndActions,
+            DutchAuctionWithdrawActions,
+            DelegatorVotes,
+            EpochIndex,
+            Epoch,
+            NoteSelection,
+            AutoFee,
+            ManualFee,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "expiryHeight" | "expiry_height" => Ok(GeneratedField::ExpiryHeight),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "source" => Ok(GeneratedField::Source),
+                            "outputs" => Ok(GeneratedField::Outputs),
+                            "spends" => Ok(GeneratedField::Spends),
+                            "swaps" => Ok(GeneratedField::Swaps),
+                            "swapClaims" | "swap_claims" => Ok(GeneratedField::SwapClaims),
+                            "delegations" => Ok(GeneratedField::Delegations),
+                            "undelegations" => Ok(GeneratedField::Undelegations),
+                            "undelegationClaims" | "undelegation_claims" => Ok(GeneratedField::UndelegationClaims),
+                            "ibcRelayActions" | "ibc_relay_actions" => Ok(GeneratedField::IbcRelayActions),
+                            "ics20Withdrawals" | "ics20_withdrawals" => Ok(GeneratedField::Ics20Withdrawals),
+                            "positionOpens" | "position_opens" => Ok(GeneratedField::PositionOpens),
+                            "positionCloses" | "position_closes" => Ok(GeneratedField::PositionCloses),
+                            "positionWithdraws" | "position_withdraws" => Ok(GeneratedField::PositionWithdraws),
+                            "dutchAuctionScheduleActions" | "dutch_auction_schedule_actions" => Ok(GeneratedField::DutchAuctionScheduleActions),
+                            "dutchAuctionEndActions" | "dutch_auction_end_actions" => Ok(GeneratedField::DutchAuctionEndActions),
+                            "dutchAuctionWithdrawActions" | "dutch_auction_withdraw_actions" => Ok(GeneratedField::DutchAuctionWithdrawActions),
+                            "delegatorVotes" | "delegator_votes" => Ok(GeneratedField::DelegatorVotes),
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "epoch" => Ok(GeneratedField::Epoch),
+                            "noteSelection" | "note_selection" => Ok(GeneratedField::NoteSelection),
+                            "autoFee" | "auto_fee" => Ok(GeneratedField::AutoFee),
+                            "manualFee" | "manual_fee" => Ok(GeneratedField::ManualFee),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionPlannerRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionPlannerRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut expiry_height__ = None;
+                let mut memo__ = None;
+                let mut source__ = None;
+                let mut outputs__ = None;
+                let mut spends__ = None;
+                let mut swaps__ = None;
+                let mut swap_claims__ = None;
+                let mut delegations__ = None;
+                let mut undelegations__ = None;
+                let mut undelegation_claims__ = None;
+                let mut ibc_relay_actions__ = None;
+                let mut ics20_withdrawals__ = None;
+                let mut position_opens__ = None;
+                let mut position_closes__ = None;
+                let mut position_withdraws__ = None;
+                let mut dutch_auction_schedule_actions__ = None;
+                let mut dutch_auction_end_actions__ = None;
+                let mut dutch_auction_withdraw_actions__ = None;
+                let mut delegator_votes__ = None;
+                let mut epoch_index__ = None;
+                let mut epoch__ = None;
+                let mut note_selection__ = None;
+                let mut fee_mode__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ExpiryHeight => {
+                            if expiry_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiryHeight"));
+                            }
+                            expiry_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = map_.next_value()?;
+                        }
+                        GeneratedField::Source => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("source"));
+                            }
+                            source__ = map_.next_value()?;
+                        }
+                        GeneratedField::Outputs => {
+                            if outputs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputs"));
+                            }
+                            outputs__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Spends => {
+                            if spends__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spends"));
+                            }
+                            spends__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Swaps => {
+                            if swaps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swaps"));
+                            }
+                            swaps__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SwapClaims => {
+                            if swap_claims__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapClaims"));
+                            }
+                            swap_claims__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Delegations => {
+                            if delegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegations"));
+                            }
+                            delegations__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Undelegations => {
+                            if undelegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("undelegations"));
+                            }
+                            undelegations__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::UndelegationClaims => {
+                            if undelegation_claims__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("undelegationClaims"));
+                            }
+                            undelegation_claims__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::IbcRelayActions => {
+                            if ibc_relay_actions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcRelayActions"));
+                            }
+                            ibc_relay_actions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Ics20Withdrawals => {
+                            if ics20_withdrawals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20Withdrawals"));
+                            }
+                            ics20_withdrawals__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PositionOpens => {
+                            if position_opens__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionOpens"));
+                            }
+                            position_opens__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PositionCloses => {
+                            if position_closes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionCloses"));
+                            }
+                            position_closes__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PositionWithdraws => {
+                            if position_withdraws__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionWithdraws"));
+                            }
+                            position_withdraws__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DutchAuctionScheduleActions => {
                             if dutch_auction_schedule_actions__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("dutchAuctionScheduleActions"));
                             }
-                            dutch_auction_schedule_actions__ = Some(map_.next_value()?);
+                            dutch_auction_schedule_actions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DutchAuctionEndActions => {
+                            if dutch_auction_end_actions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dutchAuctionEndActions"));
+                            }
+                            dutch_auction_end_actions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DutchAuctionWithdrawActions => {
+                            if dutch_auction_withdraw_actions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dutchAuctionWithdrawActions"));
+                            }
+                            dutch_auction_withdraw_actions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DelegatorVotes => {
+                            if delegator_votes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegatorVotes"));
+                            }
+                            delegator_votes__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                            }
+                            epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Epoch => {
+                            if epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epoch"));
+                            }
+                            epoch__ = map_.next_value()?;
+                        }
+                        GeneratedField::NoteSelection => {
+                            if note_selection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteSelection"));
+                            }
+                            note_selection__ = Some(map_.next_value::<transaction_planner_request::NoteSelection>()? as i32);
+                        }
+                        GeneratedField::AutoFee => {
+                            if fee_mode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("autoFee"));
+                            }
+                            fee_mode__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_planner_request::FeeMode::AutoFee)
+;
+                        }
+                        GeneratedField::ManualFee => {
+                            if fee_mode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("manualFee"));
+                            }
+                            fee_mode__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_planner_request::FeeMode::ManualFee)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionPlannerRequest {
+                    expiry_height: expiry_height__.unwrap_or_default(),
+                    memo: memo__,
+                    source: source__,
+                    outputs: outputs__.unwrap_or_default(),
+                    spends: spends__.unwrap_or_default(),
+                    swaps: swaps__.unwrap_or_default(),
+                    swap_claims: swap_claims__.unwrap_or_default(),
+                    delegations: delegations__.unwrap_or_default(),
+                    undelegations: undelegations__.unwrap_or_default(),
+                    undelegation_claims: undelegation_claims__.unwrap_or_default(),
+                    ibc_relay_actions: ibc_relay_actions__.unwrap_or_default(),
+                    ics20_withdrawals: ics20_withdrawals__.unwrap_or_default(),
+                    position_opens: position_opens__.unwrap_or_default(),
+                    position_closes: position_closes__.unwrap_or_default(),
+                    position_withdraws: position_withdraws__.unwrap_or_default(),
+                    dutch_auction_schedule_actions: dutch_auction_schedule_actions__.unwrap_or_default(),
+                    dutch_auction_end_actions: dutch_auction_end_actions__.unwrap_or_default(),
+                    dutch_auction_withdraw_actions: dutch_auction_withdraw_actions__.unwrap_or_default(),
+                    delegator_votes: delegator_votes__.unwrap_or_default(),
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    epoch: epoch__,
+                    note_selection: note_selection__.unwrap_or_default(),
+                    fee_mode: fee_mode__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_planner_request::ActionDutchAuctionEnd {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionEnd", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::ActionDutchAuctionEnd {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction_id",
+            "auctionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AuctionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::ActionDutchAuctionEnd;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionEnd")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::ActionDutchAuctionEnd, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(transaction_planner_request::ActionDutchAuctionEnd {
+                    auction_id: auction_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionEnd", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_planner_request::ActionDutchAuctionSchedule {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.description.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionSchedule", len)?;
+        if let Some(v) = self.description.as_ref() {
+            struct_ser.serialize_field("description", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::ActionDutchAuctionSchedule {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "description",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Description,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "description" => Ok(GeneratedField::Description),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::ActionDutchAuctionSchedule;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionSchedule")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::ActionDutchAuctionSchedule, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut description__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(transaction_planner_request::ActionDutchAuctionSchedule {
+                    description: description__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionSchedule", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_planner_request::ActionDutchAuctionWithdraw {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        if self.seq != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionWithdraw", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if self.seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::ActionDutchAuctionWithdraw {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction_id",
+            "auctionId",
+            "seq",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AuctionId,
+            Seq,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "seq" => Ok(GeneratedField::Seq),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::ActionDutchAuctionWithdraw;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionWithdraw")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::ActionDutchAuctionWithdraw, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction_id__ = None;
+                let mut seq__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Seq => {
+                            if seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seq"));
+                            }
+                            seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(transaction_planner_request::ActionDutchAuctionWithdraw {
+                    auction_id: auction_id__,
+                    seq: seq__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionWithdraw", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_planner_request::Delegate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if self.rate_data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Delegate", len)?;
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if let Some(v) = self.rate_data.as_ref() {
+            struct_ser.serialize_field("rateData", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::Delegate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "amount",
+            "rate_data",
+            "rateData",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Amount,
+            RateData,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "amount" => Ok(GeneratedField::Amount),
+                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::Delegate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Delegate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Delegate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut amount__ = None;
+                let mut rate_data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::RateData => {
+                            if rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateData"));
+                            }
+                            rate_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(transaction_planner_request::Delegate {
+                    amount: amount__,
+                    rate_data: rate_data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Delegate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_planner_request::DelegatorVote {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal != 0 {
+            len += 1;
+        }
+        if self.vote.is_some() {
+            len += 1;
+        }
+        if self.start_block_height != 0 {
+            len += 1;
+        }
+        if self.start_position != 0 {
+            len += 1;
+        }
+        if !self.rate_data.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.DelegatorVote", len)?;
+        if self.proposal != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
+        }
+        if let Some(v) = self.vote.as_ref() {
+            struct_ser.serialize_field("vote", v)?;
+        }
+        if self.start_block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startBlockHeight", ToString::to_string(&self.start_block_height).as_str())?;
+        }
+        if self.start_position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startPosition", ToString::to_string(&self.start_position).as_str())?;
+        }
+        if !self.rate_data.is_empty() {
+            struct_ser.serialize_field("rateData", &self.rate_data)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::DelegatorVote {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal",
+            "vote",
+            "start_block_height",
+            "startBlockHeight",
+            "start_position",
+            "startPosition",
+            "rate_data",
+            "rateData",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Proposal,
+            Vote,
+            StartBlockHeight,
+            StartPosition,
+            RateData,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposal" => Ok(GeneratedField::Proposal),
+                            "vote" => Ok(GeneratedField::Vote),
+                            "startBlockHeight" | "start_block_height" => Ok(GeneratedField::StartBlockHeight),
+                            "startPosition" | "start_position" => Ok(GeneratedField::StartPosition),
+                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::DelegatorVote;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.DelegatorVote")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::DelegatorVote, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal__ = None;
+                let mut vote__ = None;
+                let mut start_block_height__ = None;
+                let mut start_position__ = None;
+                let mut rate_data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Proposal => {
+                            if proposal__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposal"));
+                            }
+                            proposal__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Vote => {
+                            if vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vote"));
+                            }
+                            vote__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartBlockHeight => {
+                            if start_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startBlockHeight"));
+                            }
+                            start_block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartPosition => {
+                            if start_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startPosition"));
+                            }
+                            start_position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RateData => {
+                            if rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateData"));
+                            }
+                            rate_data__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(transaction_planner_request::DelegatorVote {
+                    proposal: proposal__.unwrap_or_default(),
+                    vote: vote__,
+                    start_block_height: start_block_height__.unwrap_or_default(),
+                    start_position: start_position__.unwrap_or_default(),
+                    rate_data: rate_data__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.DelegatorVote", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_planner_request::NoteSelection {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "NOTE_SELECTION_UNSPECIFIED",
+            Self::FewestSpends => "NOTE_SELECTION_FEWEST_SPENDS",
+            Self::ConsolidateDust => "NOTE_SELECTION_CONSOLIDATE_DUST",
+            Self::SingleAddress => "NOTE_SELECTION_SINGLE_ADDRESS",
+            Self::LargestAnonymitySet => "NOTE_SELECTION_LARGEST_ANONYMITY_SET",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::NoteSelection {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "NOTE_SELECTION_UNSPECIFIED",
+            "NOTE_SELECTION_FEWEST_SPENDS",
+            "NOTE_SELECTION_CONSOLIDATE_DUST",
+            "NOTE_SELECTION_SINGLE_ADDRESS",
+            "NOTE_SELECTION_LARGEST_ANONYMITY_SET",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::NoteSelection;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "NOTE_SELECTION_UNSPECIFIED" => Ok(transaction_planner_request::NoteSelection::Unspecified),
+                    "NOTE_SELECTION_FEWEST_SPENDS" => Ok(transaction_planner_request::NoteSelection::FewestSpends),
+                    "NOTE_SELECTION_CONSOLIDATE_DUST" => Ok(transaction_planner_request::NoteSelection::ConsolidateDust),
+                    "NOTE_SELECTION_SINGLE_ADDRESS" => Ok(transaction_planner_request::NoteSelection::SingleAddress),
+                    "NOTE_SELECTION_LARGEST_ANONYMITY_SET" => Ok(transaction_planner_request::NoteSelection::LargestAnonymitySet),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_planner_request::Output {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        if self.address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Output", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::Output {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "value",
+            "address",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Value,
+            Address,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "value" => Ok(GeneratedField::Value),
+                            "address" => Ok(GeneratedField::Address),
+                            _ => Ok(GeneratedField::__SkipField__),
                         }
-                        GeneratedField::DutchAuctionEndActions => {
-                            if dutch_auction_end_actions__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("dutchAuctionEndActions"));
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::Output;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Output")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Output, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                let mut address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
                             }
-                            dutch_auction_end_actions__ = Some(map_.next_value()?);
+                            value__ = map_.next_value()?;
                         }
-                        GeneratedField::DutchAuctionWithdrawActions => {
-                            if dutch_auction_withdraw_actions__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("dutchAuctionWithdrawActions"));
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
                             }
-                            dutch_auction_withdraw_actions__ = Some(map_.next_value()?);
+                            address__ = map_.next_value()?;
                         }
-                        GeneratedField::DelegatorVotes => {
-                            if delegator_votes__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delegatorVotes"));
-                            }
-                            delegator_votes__ = Some(map_.next_value()?);
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
-                        GeneratedField::EpochIndex => {
-                            if epoch_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("epochIndex"));
-                            }
-                            epoch_index__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                    }
+                }
+                Ok(transaction_planner_request::Output {
+                    value: value__,
+                    address: address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Output", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_planner_request::PositionClose {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionClose", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionClose {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
                         }
-                        GeneratedField::Epoch => {
-                            if epoch__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("epoch"));
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::PositionClose;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.PositionClose")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::PositionClose, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            epoch__ = map_.next_value()?;
+                            position_id__ = map_.next_value()?;
                         }
-                        GeneratedField::AutoFee => {
-                            if fee_mode__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("autoFee"));
-                            }
-                            fee_mode__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_planner_request::FeeMode::AutoFee)
-;
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(transaction_planner_request::PositionClose {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionClose", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_planner_request::PositionOpen {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionOpen", len)?;
+        if let Some(v) = self.position.as_ref() {
+            struct_ser.serialize_field("position", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionOpen {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Position,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "position" => Ok(GeneratedField::Position),
+                            _ => Ok(GeneratedField::__SkipField__),
                         }
-                        GeneratedField::ManualFee => {
-                            if fee_mode__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("manualFee"));
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::PositionOpen;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.PositionOpen")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::PositionOpen, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
                             }
-                            fee_mode__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_planner_request::FeeMode::ManualFee)
-;
+                            position__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(TransactionPlannerRequest {
-                    expiry_height: expiry_height__.unwrap_or_default(),
-                    memo: memo__,
-                    source: source__,
-                    outputs: outputs__.unwrap_or_default(),
-                    spends: spends__.unwrap_or_default(),
-                    swaps: swaps__.unwrap_or_default(),
-                    swap_claims: swap_claims__.unwrap_or_default(),
-                    delegations: delegations__.unwrap_or_default(),
-                    undelegations: undelegations__.unwrap_or_default(),
-                    undelegation_claims: undelegation_claims__.unwrap_or_default(),
-                    ibc_relay_actions: ibc_relay_actions__.unwrap_or_default(),
-                    ics20_withdrawals: ics20_withdrawals__.unwrap_or_default(),
-                    position_opens: position_opens__.unwrap_or_default(),
-                    position_closes: position_closes__.unwrap_or_default(),
-                    position_withdraws: position_withdraws__.unwrap_or_default(),
-                    dutch_auction_schedule_actions: dutch_auction_schedule_actions__.unwrap_or_default(),
-                    dutch_auction_end_actions: dutch_auction_end_actions__.unwrap_or_default(),
-                    dutch_auction_withdraw_actions: dutch_auction_withdraw_actions__.unwrap_or_default(),
-                    delegator_votes: delegator_votes__.unwrap_or_default(),
-                    epoch_index: epoch_index__.unwrap_or_default(),
-                    epoch: epoch__,
-                    fee_mode: fee_mode__,
+                Ok(transaction_planner_request::PositionOpen {
+                    position: position__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionOpen", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::ActionDutchAuctionEnd {
+impl serde::Serialize for transaction_planner_request::PositionWithdraw {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6547,30 +10087,47 @@ impl serde::Serialize for transaction_planner_request::ActionDutchAuctionEnd {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.auction_id.is_some() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionEnd", len)?;
-        if let Some(v) = self.auction_id.as_ref() {
-            struct_ser.serialize_field("auctionId", v)?;
+        if self.reserves.is_some() {
+            len += 1;
+        }
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionWithdraw", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::ActionDutchAuctionEnd {
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithdraw {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "auction_id",
-            "auctionId",
+            "position_id",
+            "positionId",
+            "reserves",
+            "trading_pair",
+            "tradingPair",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AuctionId,
+            PositionId,
+            Reserves,
+            TradingPair,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6593,7 +10150,9 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::ActionDutchAu
                         E: serde::de::Error,
                     {
                         match value {
-                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6603,39 +10162,55 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::ActionDutchAu
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::ActionDutchAuctionEnd;
+            type Value = transaction_planner_request::PositionWithdraw;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionEnd")
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.PositionWithdraw")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::ActionDutchAuctionEnd, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::PositionWithdraw, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut auction_id__ = None;
+                let mut position_id__ = None;
+                let mut reserves__ = None;
+                let mut trading_pair__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AuctionId => {
-                            if auction_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            auction_id__ = map_.next_value()?;
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::ActionDutchAuctionEnd {
-                    auction_id: auction_id__,
+                Ok(transaction_planner_request::PositionWithdraw {
+                    position_id: position_id__,
+                    reserves: reserves__,
+                    trading_pair: trading_pair__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionEnd", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionWithdraw", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::ActionDutchAuctionSchedule {
+impl serde::Serialize for transaction_planner_request::Spend {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6643,29 +10218,37 @@ impl serde::Serialize for transaction_planner_request::ActionDutchAuctionSchedul
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.description.is_some() {
+        if self.value.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionSchedule", len)?;
-        if let Some(v) = self.description.as_ref() {
-            struct_ser.serialize_field("description", v)?;
+        if self.address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Spend", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::ActionDutchAuctionSchedule {
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::Spend {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "description",
+            "value",
+            "address",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Description,
+            Value,
+            Address,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6688,7 +10271,8 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::ActionDutchAu
                         E: serde::de::Error,
                     {
                         match value {
-                            "description" => Ok(GeneratedField::Description),
+                            "value" => Ok(GeneratedField::Value),
+                            "address" => Ok(GeneratedField::Address),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6698,39 +10282,47 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::ActionDutchAu
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::ActionDutchAuctionSchedule;
+            type Value = transaction_planner_request::Spend;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionSchedule")
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Spend")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::ActionDutchAuctionSchedule, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Spend, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut description__ = None;
+                let mut value__ = None;
+                let mut address__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Description => {
-                            if description__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("description"));
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
                             }
-                            description__ = map_.next_value()?;
+                            value__ = map_.next_value()?;
+                        }
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::ActionDutchAuctionSchedule {
-                    description: description__,
+                Ok(transaction_planner_request::Spend {
+                    value: value__,
+                    address: address__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionSchedule", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Spend", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::ActionDutchAuctionWithdraw {
+impl serde::Serialize for transaction_planner_request::Swap {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6738,39 +10330,55 @@ impl serde::Serialize for transaction_planner_request::ActionDutchAuctionWithdra
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.auction_id.is_some() {
+        if self.value.is_some() {
             len += 1;
         }
-        if self.seq != 0 {
+        if self.target_asset.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionWithdraw", len)?;
-        if let Some(v) = self.auction_id.as_ref() {
-            struct_ser.serialize_field("auctionId", v)?;
+        if self.fee.is_some() {
+            len += 1;
         }
-        if self.seq != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
+        if self.claim_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Swap", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if let Some(v) = self.target_asset.as_ref() {
+            struct_ser.serialize_field("targetAsset", v)?;
+        }
+        if let Some(v) = self.fee.as_ref() {
+            struct_ser.serialize_field("fee", v)?;
+        }
+        if let Some(v) = self.claim_address.as_ref() {
+            struct_ser.serialize_field("claimAddress", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::ActionDutchAuctionWithdraw {
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "auction_id",
-            "auctionId",
-            "seq",
+            "value",
+            "target_asset",
+            "targetAsset",
+            "fee",
+            "claim_address",
+            "claimAddress",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AuctionId,
-            Seq,
+            Value,
+            TargetAsset,
+            Fee,
+            ClaimAddress,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6793,8 +10401,10 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::ActionDutchAu
                         E: serde::de::Error,
                     {
                         match value {
-                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
-                            "seq" => Ok(GeneratedField::Seq),
+                            "value" => Ok(GeneratedField::Value),
+                            "targetAsset" | "target_asset" => Ok(GeneratedField::TargetAsset),
+                            "fee" => Ok(GeneratedField::Fee),
+                            "claimAddress" | "claim_address" => Ok(GeneratedField::ClaimAddress),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6804,49 +10414,63 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::ActionDutchAu
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::ActionDutchAuctionWithdraw;
+            type Value = transaction_planner_request::Swap;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionWithdraw")
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Swap")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::ActionDutchAuctionWithdraw, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Swap, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut auction_id__ = None;
-                let mut seq__ = None;
+                let mut value__ = None;
+                let mut target_asset__ = None;
+                let mut fee__ = None;
+                let mut claim_address__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AuctionId => {
-                            if auction_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
                             }
-                            auction_id__ = map_.next_value()?;
+                            value__ = map_.next_value()?;
                         }
-                        GeneratedField::Seq => {
-                            if seq__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("seq"));
+                        GeneratedField::TargetAsset => {
+                            if target_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("targetAsset"));
                             }
-                            seq__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            target_asset__ = map_.next_value()?;
+                        }
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
+                            }
+                            fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::ClaimAddress => {
+                            if claim_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimAddress"));
+                            }
+                            claim_address__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::ActionDutchAuctionWithdraw {
-                    auction_id: auction_id__,
-                    seq: seq__.unwrap_or_default(),
+                Ok(transaction_planner_request::Swap {
+                    value: value__,
+                    target_asset: target_asset__,
+                    fee: fee__,
+                    claim_address: claim_address__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.ActionDutchAuctionWithdraw", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Swap", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::Delegate {
+impl serde::Serialize for transaction_planner_request::SwapClaim {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6854,38 +10478,30 @@ impl serde::Serialize for transaction_planner_request::Delegate {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.amount.is_some() {
-            len += 1;
-        }
-        if self.rate_data.is_some() {
+        if self.swap_commitment.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Delegate", len)?;
-        if let Some(v) = self.amount.as_ref() {
-            struct_ser.serialize_field("amount", v)?;
-        }
-        if let Some(v) = self.rate_data.as_ref() {
-            struct_ser.serialize_field("rateData", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.SwapClaim", len)?;
+        if let Some(v) = self.swap_commitment.as_ref() {
+            struct_ser.serialize_field("swapCommitment", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::Delegate {
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::SwapClaim {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "amount",
-            "rate_data",
-            "rateData",
+            "swap_commitment",
+            "swapCommitment",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Amount,
-            RateData,
+            SwapCommitment,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6908,8 +10524,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Delegate {
                         E: serde::de::Error,
                     {
                         match value {
-                            "amount" => Ok(GeneratedField::Amount),
-                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6919,47 +10534,39 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Delegate {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::Delegate;
+            type Value = transaction_planner_request::SwapClaim;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Delegate")
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.SwapClaim")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Delegate, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::SwapClaim, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut amount__ = None;
-                let mut rate_data__ = None;
+                let mut swap_commitment__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Amount => {
-                            if amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("amount"));
-                            }
-                            amount__ = map_.next_value()?;
-                        }
-                        GeneratedField::RateData => {
-                            if rate_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rateData"));
+                        GeneratedField::SwapCommitment => {
+                            if swap_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
                             }
-                            rate_data__ = map_.next_value()?;
+                            swap_commitment__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::Delegate {
-                    amount: amount__,
-                    rate_data: rate_data__,
+                Ok(transaction_planner_request::SwapClaim {
+                    swap_commitment: swap_commitment__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Delegate", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.SwapClaim", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::DelegatorVote {
+impl serde::Serialize for transaction_planner_request::Undelegate {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6967,66 +10574,37 @@ impl serde::Serialize for transaction_planner_request::DelegatorVote {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.proposal != 0 {
-            len += 1;
-        }
-        if self.vote.is_some() {
-            len += 1;
-        }
-        if self.start_block_height != 0 {
-            len += 1;
-        }
-        if self.start_position != 0 {
+        if self.value.is_some() {
             len += 1;
         }
-        if !self.rate_data.is_empty() {
+        if self.rate_data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.DelegatorVote", len)?;
-        if self.proposal != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proposal", ToString::to_string(&self.proposal).as_str())?;
-        }
-        if let Some(v) = self.vote.as_ref() {
-            struct_ser.serialize_field("vote", v)?;
-        }
-        if self.start_block_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startBlockHeight", ToString::to_string(&self.start_block_height).as_str())?;
-        }
-        if self.start_position != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startPosition", ToString::to_string(&self.start_position).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Undelegate", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
         }
-        if !self.rate_data.is_empty() {
-            struct_ser.serialize_field("rateData", &self.rate_data)?;
+        if let Some(v) = self.rate_data.as_ref() {
+            struct_ser.serialize_field("rateData", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::DelegatorVote {
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::Undelegate {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "proposal",
-            "vote",
-            "start_block_height",
-            "startBlockHeight",
-            "start_position",
-            "startPosition",
+            "value",
             "rate_data",
             "rateData",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Proposal,
-            Vote,
-            StartBlockHeight,
-            StartPosition,
+            Value,
             RateData,
             __SkipField__,
         }
@@ -7050,10 +10628,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::DelegatorVote
                         E: serde::de::Error,
                     {
                         match value {
-                            "proposal" => Ok(GeneratedField::Proposal),
-                            "vote" => Ok(GeneratedField::Vote),
-                            "startBlockHeight" | "start_block_height" => Ok(GeneratedField::StartBlockHeight),
-                            "startPosition" | "start_position" => Ok(GeneratedField::StartPosition),
+                            "value" => Ok(GeneratedField::Value),
                             "rateData" | "rate_data" => Ok(GeneratedField::RateData),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
@@ -7064,77 +10639,47 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::DelegatorVote
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::DelegatorVote;
+            type Value = transaction_planner_request::Undelegate;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.DelegatorVote")
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Undelegate")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::DelegatorVote, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Undelegate, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut proposal__ = None;
-                let mut vote__ = None;
-                let mut start_block_height__ = None;
-                let mut start_position__ = None;
+                let mut value__ = None;
                 let mut rate_data__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Proposal => {
-                            if proposal__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("proposal"));
-                            }
-                            proposal__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Vote => {
-                            if vote__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("vote"));
-                            }
-                            vote__ = map_.next_value()?;
-                        }
-                        GeneratedField::StartBlockHeight => {
-                            if start_block_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startBlockHeight"));
-                            }
-                            start_block_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::StartPosition => {
-                            if start_position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startPosition"));
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
                             }
-                            start_position__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            value__ = map_.next_value()?;
                         }
                         GeneratedField::RateData => {
                             if rate_data__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("rateData"));
                             }
-                            rate_data__ = Some(map_.next_value()?);
+                            rate_data__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::DelegatorVote {
-                    proposal: proposal__.unwrap_or_default(),
-                    vote: vote__,
-                    start_block_height: start_block_height__.unwrap_or_default(),
-                    start_position: start_position__.unwrap_or_default(),
-                    rate_data: rate_data__.unwrap_or_default(),
+                Ok(transaction_planner_request::Undelegate {
+                    value: value__,
+                    rate_data: rate_data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.DelegatorVote", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Undelegate", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::Output {
+impl serde::Serialize for transaction_planner_request::UndelegateClaim {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7142,37 +10687,67 @@ impl serde::Serialize for transaction_planner_request::Output {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.value.is_some() {
+        if self.validator_identity.is_some() {
             len += 1;
         }
-        if self.address.is_some() {
+        if self.start_epoch_index != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Output", len)?;
-        if let Some(v) = self.value.as_ref() {
-            struct_ser.serialize_field("value", v)?;
+        if self.penalty.is_some() {
+            len += 1;
         }
-        if let Some(v) = self.address.as_ref() {
-            struct_ser.serialize_field("address", v)?;
+        if self.unbonding_amount.is_some() {
+            len += 1;
+        }
+        if self.unbonding_start_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.UndelegateClaim", len)?;
+        if let Some(v) = self.validator_identity.as_ref() {
+            struct_ser.serialize_field("validatorIdentity", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
+        }
+        if let Some(v) = self.unbonding_amount.as_ref() {
+            struct_ser.serialize_field("unbondingAmount", v)?;
+        }
+        if self.unbonding_start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("unbondingStartHeight", ToString::to_string(&self.unbonding_start_height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::Output {
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::UndelegateClaim {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "value",
-            "address",
+            "validator_identity",
+            "validatorIdentity",
+            "start_epoch_index",
+            "startEpochIndex",
+            "penalty",
+            "unbonding_amount",
+            "unbondingAmount",
+            "unbonding_start_height",
+            "unbondingStartHeight",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Value,
-            Address,
+            ValidatorIdentity,
+            StartEpochIndex,
+            Penalty,
+            UnbondingAmount,
+            UnbondingStartHeight,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7195,8 +10770,11 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Output {
                         E: serde::de::Error,
                     {
                         match value {
-                            "value" => Ok(GeneratedField::Value),
-                            "address" => Ok(GeneratedField::Address),
+                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            "unbondingAmount" | "unbonding_amount" => Ok(GeneratedField::UnbondingAmount),
+                            "unbondingStartHeight" | "unbonding_start_height" => Ok(GeneratedField::UnbondingStartHeight),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7206,47 +10784,75 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Output {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::Output;
+            type Value = transaction_planner_request::UndelegateClaim;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Output")
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.UndelegateClaim")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Output, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::UndelegateClaim, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
-                let mut address__ = None;
+                let mut validator_identity__ = None;
+                let mut start_epoch_index__ = None;
+                let mut penalty__ = None;
+                let mut unbonding_amount__ = None;
+                let mut unbonding_start_height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
+                        GeneratedField::ValidatorIdentity => {
+                            if validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
                             }
-                            value__ = map_.next_value()?;
+                            validator_identity__ = map_.next_value()?;
                         }
-                        GeneratedField::Address => {
-                            if address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("address"));
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
                             }
-                            address__ = map_.next_value()?;
+                            start_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
+                            }
+                            penalty__ = map_.next_value()?;
+                        }
+                        GeneratedField::UnbondingAmount => {
+                            if unbonding_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondingAmount"));
+                            }
+                            unbonding_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::UnbondingStartHeight => {
+                            if unbonding_start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondingStartHeight"));
+                            }
+                            unbonding_start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::Output {
-                    value: value__,
-                    address: address__,
+                Ok(transaction_planner_request::UndelegateClaim {
+                    validator_identity: validator_identity__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    penalty: penalty__,
+                    unbonding_amount: unbonding_amount__,
+                    unbonding_start_height: unbonding_start_height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Output", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.UndelegateClaim", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::PositionClose {
+impl serde::Serialize for TransactionPlannerResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7254,30 +10860,29 @@ impl serde::Serialize for transaction_planner_request::PositionClose {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.plan.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionClose", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerResponse", len)?;
+        if let Some(v) = self.plan.as_ref() {
+            struct_ser.serialize_field("plan", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionClose {
+impl<'de> serde::Deserialize<'de> for TransactionPlannerResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
+            "plan",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
+            Plan,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7300,7 +10905,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionClose
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "plan" => Ok(GeneratedField::Plan),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7310,39 +10915,39 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionClose
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::PositionClose;
+            type Value = TransactionPlannerResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.PositionClose")
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::PositionClose, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionPlannerResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut plan__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Plan => {
+                            if plan__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("plan"));
                             }
-                            position_id__ = map_.next_value()?;
+                            plan__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::PositionClose {
-                    position_id: position_id__,
+                Ok(TransactionPlannerResponse {
+                    plan: plan__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionClose", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::PositionOpen {
+impl serde::Serialize for UnbondingTokensByAddressIndexRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7350,29 +10955,40 @@ impl serde::Serialize for transaction_planner_request::PositionOpen {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position.is_some() {
+        if self.filter != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionOpen", len)?;
-        if let Some(v) = self.position.as_ref() {
-            struct_ser.serialize_field("position", v)?;
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.UnbondingTokensByAddressIndexRequest", len)?;
+        if self.filter != 0 {
+            let v = unbonding_tokens_by_address_index_request::Filter::try_from(self.filter)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.filter)))?;
+            struct_ser.serialize_field("filter", &v)?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionOpen {
+impl<'de> serde::Deserialize<'de> for UnbondingTokensByAddressIndexRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position",
+            "filter",
+            "address_index",
+            "addressIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Position,
+            Filter,
+            AddressIndex,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7395,7 +11011,8 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionOpen
                         E: serde::de::Error,
                     {
                         match value {
-                            "position" => Ok(GeneratedField::Position),
+                            "filter" => Ok(GeneratedField::Filter),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7405,39 +11022,121 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionOpen
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::PositionOpen;
+            type Value = UnbondingTokensByAddressIndexRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.PositionOpen")
+                formatter.write_str("struct penumbra.view.v1.UnbondingTokensByAddressIndexRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::PositionOpen, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnbondingTokensByAddressIndexRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position__ = None;
+                let mut filter__ = None;
+                let mut address_index__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
+                        GeneratedField::Filter => {
+                            if filter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("filter"));
                             }
-                            position__ = map_.next_value()?;
+                            filter__ = Some(map_.next_value::<unbonding_tokens_by_address_index_request::Filter>()? as i32);
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::PositionOpen {
-                    position: position__,
+                Ok(UnbondingTokensByAddressIndexRequest {
+                    filter: filter__.unwrap_or_default(),
+                    address_index: address_index__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionOpen", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.UnbondingTokensByAddressIndexRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for unbonding_tokens_by_address_index_request::Filter {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "FILTER_UNSPECIFIED",
+            Self::Claimable => "FILTER_CLAIMABLE",
+            Self::NotYetClaimable => "FILTER_NOT_YET_CLAIMABLE",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for unbonding_tokens_by_address_index_request::Filter {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "FILTER_UNSPECIFIED",
+            "FILTER_CLAIMABLE",
+            "FILTER_NOT_YET_CLAIMABLE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = unbonding_tokens_by_address_index_request::Filter;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "FILTER_UNSPECIFIED" => Ok(unbonding_tokens_by_address_index_request::Filter::Unspecified),
+                    "FILTER_CLAIMABLE" => Ok(unbonding_tokens_by_address_index_request::Filter::Claimable),
+                    "FILTER_NOT_YET_CLAIMABLE" => Ok(unbonding_tokens_by_address_index_request::Filter::NotYetClaimable),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::PositionWithdraw {
+impl serde::Serialize for UnbondingTokensByAddressIndexResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7445,47 +11144,38 @@ impl serde::Serialize for transaction_planner_request::PositionWithdraw {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        if self.reserves.is_some() {
+        if self.value_view.is_some() {
             len += 1;
         }
-        if self.trading_pair.is_some() {
+        if self.claimable {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionWithdraw", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
-        if let Some(v) = self.reserves.as_ref() {
-            struct_ser.serialize_field("reserves", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.UnbondingTokensByAddressIndexResponse", len)?;
+        if let Some(v) = self.value_view.as_ref() {
+            struct_ser.serialize_field("valueView", v)?;
         }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
+        if self.claimable {
+            struct_ser.serialize_field("claimable", &self.claimable)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithdraw {
+impl<'de> serde::Deserialize<'de> for UnbondingTokensByAddressIndexResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
-            "reserves",
-            "trading_pair",
-            "tradingPair",
+            "value_view",
+            "valueView",
+            "claimable",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
-            Reserves,
-            TradingPair,
+            ValueView,
+            Claimable,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7508,9 +11198,8 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithd
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "reserves" => Ok(GeneratedField::Reserves),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "valueView" | "value_view" => Ok(GeneratedField::ValueView),
+                            "claimable" => Ok(GeneratedField::Claimable),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7520,93 +11209,69 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithd
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::PositionWithdraw;
+            type Value = UnbondingTokensByAddressIndexResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.PositionWithdraw")
+                formatter.write_str("struct penumbra.view.v1.UnbondingTokensByAddressIndexResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::PositionWithdraw, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnbondingTokensByAddressIndexResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                let mut reserves__ = None;
-                let mut trading_pair__ = None;
+                let mut value_view__ = None;
+                let mut claimable__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
+                        GeneratedField::ValueView => {
+                            if value_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("valueView"));
                             }
-                            reserves__ = map_.next_value()?;
+                            value_view__ = map_.next_value()?;
                         }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::Claimable => {
+                            if claimable__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimable"));
                             }
-                            trading_pair__ = map_.next_value()?;
+                            claimable__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::PositionWithdraw {
-                    position_id: position_id__,
-                    reserves: reserves__,
-                    trading_pair: trading_pair__,
+                Ok(UnbondingTokensByAddressIndexResponse {
+                    value_view: value_view__,
+                    claimable: claimable__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionWithdraw", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.UnbondingTokensByAddressIndexResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::Spend {
+impl serde::Serialize for UnclaimedSwapsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.value.is_some() {
-            len += 1;
-        }
-        if self.address.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Spend", len)?;
-        if let Some(v) = self.value.as_ref() {
-            struct_ser.serialize_field("value", v)?;
-        }
-        if let Some(v) = self.address.as_ref() {
-            struct_ser.serialize_field("address", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.UnclaimedSwapsRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::Spend {
+impl<'de> serde::Deserialize<'de> for UnclaimedSwapsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "value",
-            "address",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Value,
-            Address,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7628,11 +11293,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Spend {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "value" => Ok(GeneratedField::Value),
-                            "address" => Ok(GeneratedField::Address),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -7640,47 +11301,27 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Spend {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::Spend;
+            type Value = UnclaimedSwapsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Spend")
+                formatter.write_str("struct penumbra.view.v1.UnclaimedSwapsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Spend, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnclaimedSwapsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
-                let mut address__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
-                            }
-                            value__ = map_.next_value()?;
-                        }
-                        GeneratedField::Address => {
-                            if address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("address"));
-                            }
-                            address__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(transaction_planner_request::Spend {
-                    value: value__,
-                    address: address__,
+                Ok(UnclaimedSwapsRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Spend", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.UnclaimedSwapsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::Swap {
+impl serde::Serialize for UnclaimedSwapsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7688,55 +11329,29 @@ impl serde::Serialize for transaction_planner_request::Swap {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.value.is_some() {
-            len += 1;
-        }
-        if self.target_asset.is_some() {
-            len += 1;
-        }
-        if self.fee.is_some() {
-            len += 1;
-        }
-        if self.claim_address.is_some() {
+        if self.swap.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Swap", len)?;
-        if let Some(v) = self.value.as_ref() {
-            struct_ser.serialize_field("value", v)?;
-        }
-        if let Some(v) = self.target_asset.as_ref() {
-            struct_ser.serialize_field("targetAsset", v)?;
-        }
-        if let Some(v) = self.fee.as_ref() {
-            struct_ser.serialize_field("fee", v)?;
-        }
-        if let Some(v) = self.claim_address.as_ref() {
-            struct_ser.serialize_field("claimAddress", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.UnclaimedSwapsResponse", len)?;
+        if let Some(v) = self.swap.as_ref() {
+            struct_ser.serialize_field("swap", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
+impl<'de> serde::Deserialize<'de> for UnclaimedSwapsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "value",
-            "target_asset",
-            "targetAsset",
-            "fee",
-            "claim_address",
-            "claimAddress",
+            "swap",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Value,
-            TargetAsset,
-            Fee,
-            ClaimAddress,
+            Swap,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7759,10 +11374,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
                         E: serde::de::Error,
                     {
                         match value {
-                            "value" => Ok(GeneratedField::Value),
-                            "targetAsset" | "target_asset" => Ok(GeneratedField::TargetAsset),
-                            "fee" => Ok(GeneratedField::Fee),
-                            "claimAddress" | "claim_address" => Ok(GeneratedField::ClaimAddress),
+                            "swap" => Ok(GeneratedField::Swap),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7772,94 +11384,61 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::Swap;
+            type Value = UnclaimedSwapsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Swap")
+                formatter.write_str("struct penumbra.view.v1.UnclaimedSwapsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Swap, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnclaimedSwapsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
-                let mut target_asset__ = None;
-                let mut fee__ = None;
-                let mut claim_address__ = None;
+                let mut swap__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
-                            }
-                            value__ = map_.next_value()?;
-                        }
-                        GeneratedField::TargetAsset => {
-                            if target_asset__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("targetAsset"));
-                            }
-                            target_asset__ = map_.next_value()?;
-                        }
-                        GeneratedField::Fee => {
-                            if fee__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fee"));
-                            }
-                            fee__ = map_.next_value()?;
-                        }
-                        GeneratedField::ClaimAddress => {
-                            if claim_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("claimAddress"));
+                        GeneratedField::Swap => {
+                            if swap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swap"));
                             }
-                            claim_address__ = map_.next_value()?;
+                            swap__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::Swap {
-                    value: value__,
-                    target_asset: target_asset__,
-                    fee: fee__,
-                    claim_address: claim_address__,
+                Ok(UnclaimedSwapsResponse {
+                    swap: swap__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Swap", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.UnclaimedSwapsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::SwapClaim {
+impl serde::Serialize for WalletEventsRequest {
     #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.swap_commitment.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.SwapClaim", len)?;
-        if let Some(v) = self.swap_commitment.as_ref() {
-            struct_ser.serialize_field("swapCommitment", v)?;
-        }
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.WalletEventsRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::SwapClaim {
+impl<'de> serde::Deserialize<'de> for WalletEventsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_commitment",
-            "swapCommitment",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapCommitment,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7881,10 +11460,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::SwapClaim {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -7892,39 +11468,27 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::SwapClaim {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::SwapClaim;
+            type Value = WalletEventsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.SwapClaim")
+                formatter.write_str("struct penumbra.view.v1.WalletEventsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::SwapClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WalletEventsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_commitment__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::SwapCommitment => {
-                            if swap_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
-                            }
-                            swap_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(transaction_planner_request::SwapClaim {
-                    swap_commitment: swap_commitment__,
+                Ok(WalletEventsRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.SwapClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.WalletEventsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::Undelegate {
+impl serde::Serialize for WalletEventsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7932,38 +11496,73 @@ impl serde::Serialize for transaction_planner_request::Undelegate {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.value.is_some() {
+        if self.height != 0 {
             len += 1;
         }
-        if self.rate_data.is_some() {
+        if self.event.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Undelegate", len)?;
-        if let Some(v) = self.value.as_ref() {
-            struct_ser.serialize_field("value", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.WalletEventsResponse", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
-        if let Some(v) = self.rate_data.as_ref() {
-            struct_ser.serialize_field("rateData", v)?;
+        if let Some(v) = self.event.as_ref() {
+            match v {
+                wallet_events_response::Event::NoteDetected(v) => {
+                    struct_ser.serialize_field("noteDetected", v)?;
+                }
+                wallet_events_response::Event::NoteSpent(v) => {
+                    struct_ser.serialize_field("noteSpent", v)?;
+                }
+                wallet_events_response::Event::SwapDetected(v) => {
+                    struct_ser.serialize_field("swapDetected", v)?;
+                }
+                wallet_events_response::Event::TransactionRecorded(v) => {
+                    struct_ser.serialize_field("transactionRecorded", v)?;
+                }
+                wallet_events_response::Event::PositionUpdated(v) => {
+                    struct_ser.serialize_field("positionUpdated", v)?;
+                }
+                wallet_events_response::Event::AuctionUpdated(v) => {
+                    struct_ser.serialize_field("auctionUpdated", v)?;
+                }
+            }
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::Undelegate {
+impl<'de> serde::Deserialize<'de> for WalletEventsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "value",
-            "rate_data",
-            "rateData",
+            "height",
+            "note_detected",
+            "noteDetected",
+            "note_spent",
+            "noteSpent",
+            "swap_detected",
+            "swapDetected",
+            "transaction_recorded",
+            "transactionRecorded",
+            "position_updated",
+            "positionUpdated",
+            "auction_updated",
+            "auctionUpdated",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Value,
-            RateData,
+            Height,
+            NoteDetected,
+            NoteSpent,
+            SwapDetected,
+            TransactionRecorded,
+            PositionUpdated,
+            AuctionUpdated,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7986,8 +11585,13 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Undelegate {
                         E: serde::de::Error,
                     {
                         match value {
-                            "value" => Ok(GeneratedField::Value),
-                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            "height" => Ok(GeneratedField::Height),
+                            "noteDetected" | "note_detected" => Ok(GeneratedField::NoteDetected),
+                            "noteSpent" | "note_spent" => Ok(GeneratedField::NoteSpent),
+                            "swapDetected" | "swap_detected" => Ok(GeneratedField::SwapDetected),
+                            "transactionRecorded" | "transaction_recorded" => Ok(GeneratedField::TransactionRecorded),
+                            "positionUpdated" | "position_updated" => Ok(GeneratedField::PositionUpdated),
+                            "auctionUpdated" | "auction_updated" => Ok(GeneratedField::AuctionUpdated),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7997,47 +11601,85 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Undelegate {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::Undelegate;
+            type Value = WalletEventsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Undelegate")
+                formatter.write_str("struct penumbra.view.v1.WalletEventsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Undelegate, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WalletEventsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
-                let mut rate_data__ = None;
+                let mut height__ = None;
+                let mut event__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            value__ = map_.next_value()?;
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::RateData => {
-                            if rate_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rateData"));
+                        GeneratedField::NoteDetected => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteDetected"));
                             }
-                            rate_data__ = map_.next_value()?;
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(wallet_events_response::Event::NoteDetected)
+;
+                        }
+                        GeneratedField::NoteSpent => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteSpent"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(wallet_events_response::Event::NoteSpent)
+;
+                        }
+                        GeneratedField::SwapDetected => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapDetected"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(wallet_events_response::Event::SwapDetected)
+;
+                        }
+                        GeneratedField::TransactionRecorded => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionRecorded"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(wallet_events_response::Event::TransactionRecorded)
+;
+                        }
+                        GeneratedField::PositionUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionUpdated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(wallet_events_response::Event::PositionUpdated)
+;
+                        }
+                        GeneratedField::AuctionUpdated => {
+                            if event__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionUpdated"));
+                            }
+                            event__ = map_.next_value::<::std::option::Option<_>>()?.map(wallet_events_response::Event::AuctionUpdated)
+;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::Undelegate {
-                    value: value__,
-                    rate_data: rate_data__,
+                Ok(WalletEventsResponse {
+                    height: height__.unwrap_or_default(),
+                    event: event__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Undelegate", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.WalletEventsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::UndelegateClaim {
+impl serde::Serialize for wallet_events_response::AuctionUpdated {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -8045,67 +11687,39 @@ impl serde::Serialize for transaction_planner_request::UndelegateClaim {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.validator_identity.is_some() {
-            len += 1;
-        }
-        if self.start_epoch_index != 0 {
-            len += 1;
-        }
-        if self.penalty.is_some() {
-            len += 1;
-        }
-        if self.unbonding_amount.is_some() {
+        if self.auction_id.is_some() {
             len += 1;
         }
-        if self.unbonding_start_height != 0 {
+        if self.seq != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.UndelegateClaim", len)?;
-        if let Some(v) = self.validator_identity.as_ref() {
-            struct_ser.serialize_field("validatorIdentity", v)?;
-        }
-        if self.start_epoch_index != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
-        }
-        if let Some(v) = self.penalty.as_ref() {
-            struct_ser.serialize_field("penalty", v)?;
-        }
-        if let Some(v) = self.unbonding_amount.as_ref() {
-            struct_ser.serialize_field("unbondingAmount", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.WalletEventsResponse.AuctionUpdated", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
         }
-        if self.unbonding_start_height != 0 {
+        if self.seq != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("unbondingStartHeight", ToString::to_string(&self.unbonding_start_height).as_str())?;
+            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::UndelegateClaim {
+impl<'de> serde::Deserialize<'de> for wallet_events_response::AuctionUpdated {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "validator_identity",
-            "validatorIdentity",
-            "start_epoch_index",
-            "startEpochIndex",
-            "penalty",
-            "unbonding_amount",
-            "unbondingAmount",
-            "unbonding_start_height",
-            "unbondingStartHeight",
+            "auction_id",
+            "auctionId",
+            "seq",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ValidatorIdentity,
-            StartEpochIndex,
-            Penalty,
-            UnbondingAmount,
-            UnbondingStartHeight,
+            AuctionId,
+            Seq,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8128,11 +11742,8 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::UndelegateCla
                         E: serde::de::Error,
                     {
                         match value {
-                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
-                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
-                            "penalty" => Ok(GeneratedField::Penalty),
-                            "unbondingAmount" | "unbonding_amount" => Ok(GeneratedField::UnbondingAmount),
-                            "unbondingStartHeight" | "unbonding_start_height" => Ok(GeneratedField::UnbondingStartHeight),
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "seq" => Ok(GeneratedField::Seq),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -8142,54 +11753,31 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::UndelegateCla
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::UndelegateClaim;
+            type Value = wallet_events_response::AuctionUpdated;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.UndelegateClaim")
+                formatter.write_str("struct penumbra.view.v1.WalletEventsResponse.AuctionUpdated")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::UndelegateClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<wallet_events_response::AuctionUpdated, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut validator_identity__ = None;
-                let mut start_epoch_index__ = None;
-                let mut penalty__ = None;
-                let mut unbonding_amount__ = None;
-                let mut unbonding_start_height__ = None;
+                let mut auction_id__ = None;
+                let mut seq__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ValidatorIdentity => {
-                            if validator_identity__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
-                            }
-                            validator_identity__ = map_.next_value()?;
-                        }
-                        GeneratedField::StartEpochIndex => {
-                            if start_epoch_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
-                            }
-                            start_epoch_index__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Penalty => {
-                            if penalty__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("penalty"));
-                            }
-                            penalty__ = map_.next_value()?;
-                        }
-                        GeneratedField::UnbondingAmount => {
-                            if unbonding_amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("unbondingAmount"));
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
                             }
-                            unbonding_amount__ = map_.next_value()?;
+                            auction_id__ = map_.next_value()?;
                         }
-                        GeneratedField::UnbondingStartHeight => {
-                            if unbonding_start_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("unbondingStartHeight"));
+                        GeneratedField::Seq => {
+                            if seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seq"));
                             }
-                            unbonding_start_height__ = 
+                            seq__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
@@ -8198,19 +11786,16 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::UndelegateCla
                         }
                     }
                 }
-                Ok(transaction_planner_request::UndelegateClaim {
-                    validator_identity: validator_identity__,
-                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
-                    penalty: penalty__,
-                    unbonding_amount: unbonding_amount__,
-                    unbonding_start_height: unbonding_start_height__.unwrap_or_default(),
+                Ok(wallet_events_response::AuctionUpdated {
+                    auction_id: auction_id__,
+                    seq: seq__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.UndelegateClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.WalletEventsResponse.AuctionUpdated", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionPlannerResponse {
+impl serde::Serialize for wallet_events_response::NoteDetected {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -8218,29 +11803,30 @@ impl serde::Serialize for TransactionPlannerResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.plan.is_some() {
+        if self.note_record.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerResponse", len)?;
-        if let Some(v) = self.plan.as_ref() {
-            struct_ser.serialize_field("plan", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.WalletEventsResponse.NoteDetected", len)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionPlannerResponse {
+impl<'de> serde::Deserialize<'de> for wallet_events_response::NoteDetected {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "plan",
+            "note_record",
+            "noteRecord",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Plan,
+            NoteRecord,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8263,7 +11849,7 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "plan" => Ok(GeneratedField::Plan),
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -8273,39 +11859,39 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionPlannerResponse;
+            type Value = wallet_events_response::NoteDetected;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerResponse")
+                formatter.write_str("struct penumbra.view.v1.WalletEventsResponse.NoteDetected")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionPlannerResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<wallet_events_response::NoteDetected, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut plan__ = None;
+                let mut note_record__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Plan => {
-                            if plan__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("plan"));
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
                             }
-                            plan__ = map_.next_value()?;
+                            note_record__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(TransactionPlannerResponse {
-                    plan: plan__,
+                Ok(wallet_events_response::NoteDetected {
+                    note_record: note_record__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.WalletEventsResponse.NoteDetected", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for UnbondingTokensByAddressIndexRequest {
+impl serde::Serialize for wallet_events_response::NoteSpent {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -8313,40 +11899,38 @@ impl serde::Serialize for UnbondingTokensByAddressIndexRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.filter != 0 {
+        if self.nullifier.is_some() {
             len += 1;
         }
-        if self.address_index.is_some() {
+        if self.note_record.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.UnbondingTokensByAddressIndexRequest", len)?;
-        if self.filter != 0 {
-            let v = unbonding_tokens_by_address_index_request::Filter::try_from(self.filter)
-                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.filter)))?;
-            struct_ser.serialize_field("filter", &v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.WalletEventsResponse.NoteSpent", len)?;
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
         }
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for UnbondingTokensByAddressIndexRequest {
+impl<'de> serde::Deserialize<'de> for wallet_events_response::NoteSpent {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "filter",
-            "address_index",
-            "addressIndex",
+            "nullifier",
+            "note_record",
+            "noteRecord",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Filter,
-            AddressIndex,
+            Nullifier,
+            NoteRecord,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8369,8 +11953,8 @@ impl<'de> serde::Deserialize<'de> for UnbondingTokensByAddressIndexRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "filter" => Ok(GeneratedField::Filter),
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -8380,121 +11964,47 @@ impl<'de> serde::Deserialize<'de> for UnbondingTokensByAddressIndexRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = UnbondingTokensByAddressIndexRequest;
+            type Value = wallet_events_response::NoteSpent;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.UnbondingTokensByAddressIndexRequest")
+                formatter.write_str("struct penumbra.view.v1.WalletEventsResponse.NoteSpent")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnbondingTokensByAddressIndexRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<wallet_events_response::NoteSpent, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut filter__ = None;
-                let mut address_index__ = None;
+                let mut nullifier__ = None;
+                let mut note_record__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Filter => {
-                            if filter__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("filter"));
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
                             }
-                            filter__ = Some(map_.next_value::<unbonding_tokens_by_address_index_request::Filter>()? as i32);
+                            nullifier__ = map_.next_value()?;
                         }
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
                             }
-                            address_index__ = map_.next_value()?;
+                            note_record__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(UnbondingTokensByAddressIndexRequest {
-                    filter: filter__.unwrap_or_default(),
-                    address_index: address_index__,
+                Ok(wallet_events_response::NoteSpent {
+                    nullifier: nullifier__,
+                    note_record: note_record__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.UnbondingTokensByAddressIndexRequest", FIELDS, GeneratedVisitor)
-    }
-}
-impl serde::Serialize for unbonding_tokens_by_address_index_request::Filter {
-    #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        let variant = match self {
-            Self::Unspecified => "FILTER_UNSPECIFIED",
-            Self::Claimable => "FILTER_CLAIMABLE",
-            Self::NotYetClaimable => "FILTER_NOT_YET_CLAIMABLE",
-        };
-        serializer.serialize_str(variant)
-    }
-}
-impl<'de> serde::Deserialize<'de> for unbonding_tokens_by_address_index_request::Filter {
-    #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        const FIELDS: &[&str] = &[
-            "FILTER_UNSPECIFIED",
-            "FILTER_CLAIMABLE",
-            "FILTER_NOT_YET_CLAIMABLE",
-        ];
-
-        struct GeneratedVisitor;
-
-        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = unbonding_tokens_by_address_index_request::Filter;
-
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(formatter, "expected one of: {:?}", &FIELDS)
-            }
-
-            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
-                    })
-            }
-
-            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
-                    })
-            }
-
-            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                match value {
-                    "FILTER_UNSPECIFIED" => Ok(unbonding_tokens_by_address_index_request::Filter::Unspecified),
-                    "FILTER_CLAIMABLE" => Ok(unbonding_tokens_by_address_index_request::Filter::Claimable),
-                    "FILTER_NOT_YET_CLAIMABLE" => Ok(unbonding_tokens_by_address_index_request::Filter::NotYetClaimable),
-                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
-                }
-            }
-        }
-        deserializer.deserialize_any(GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.WalletEventsResponse.NoteSpent", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for UnbondingTokensByAddressIndexResponse {
+impl serde::Serialize for wallet_events_response::PositionUpdated {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -8502,38 +12012,38 @@ impl serde::Serialize for UnbondingTokensByAddressIndexResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.value_view.is_some() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        if self.claimable {
+        if self.state.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.UnbondingTokensByAddressIndexResponse", len)?;
-        if let Some(v) = self.value_view.as_ref() {
-            struct_ser.serialize_field("valueView", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.WalletEventsResponse.PositionUpdated", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
         }
-        if self.claimable {
-            struct_ser.serialize_field("claimable", &self.claimable)?;
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for UnbondingTokensByAddressIndexResponse {
+impl<'de> serde::Deserialize<'de> for wallet_events_response::PositionUpdated {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "value_view",
-            "valueView",
-            "claimable",
+            "position_id",
+            "positionId",
+            "state",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ValueView,
-            Claimable,
+            PositionId,
+            State,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8556,8 +12066,8 @@ impl<'de> serde::Deserialize<'de> for UnbondingTokensByAddressIndexResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "valueView" | "value_view" => Ok(GeneratedField::ValueView),
-                            "claimable" => Ok(GeneratedField::Claimable),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "state" => Ok(GeneratedField::State),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -8567,69 +12077,78 @@ impl<'de> serde::Deserialize<'de> for UnbondingTokensByAddressIndexResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = UnbondingTokensByAddressIndexResponse;
+            type Value = wallet_events_response::PositionUpdated;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.UnbondingTokensByAddressIndexResponse")
+                formatter.write_str("struct penumbra.view.v1.WalletEventsResponse.PositionUpdated")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnbondingTokensByAddressIndexResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<wallet_events_response::PositionUpdated, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value_view__ = None;
-                let mut claimable__ = None;
+                let mut position_id__ = None;
+                let mut state__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ValueView => {
-                            if value_view__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("valueView"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            value_view__ = map_.next_value()?;
+                            position_id__ = map_.next_value()?;
                         }
-                        GeneratedField::Claimable => {
-                            if claimable__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("claimable"));
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
                             }
-                            claimable__ = Some(map_.next_value()?);
+                            state__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(UnbondingTokensByAddressIndexResponse {
-                    value_view: value_view__,
-                    claimable: claimable__.unwrap_or_default(),
+                Ok(wallet_events_response::PositionUpdated {
+                    position_id: position_id__,
+                    state: state__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.UnbondingTokensByAddressIndexResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.WalletEventsResponse.PositionUpdated", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for UnclaimedSwapsRequest {
+impl serde::Serialize for wallet_events_response::SwapDetected {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1.UnclaimedSwapsRequest", len)?;
+        let mut len = 0;
+        if self.swap_record.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.WalletEventsResponse.SwapDetected", len)?;
+        if let Some(v) = self.swap_record.as_ref() {
+            struct_ser.serialize_field("swapRecord", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for UnclaimedSwapsRequest {
+impl<'de> serde::Deserialize<'de> for wallet_events_response::SwapDetected {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "swap_record",
+            "swapRecord",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            SwapRecord,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8651,7 +12170,10 @@ impl<'de> serde::Deserialize<'de> for UnclaimedSwapsRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "swapRecord" | "swap_record" => Ok(GeneratedField::SwapRecord),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -8659,27 +12181,39 @@ impl<'de> serde::Deserialize<'de> for UnclaimedSwapsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = UnclaimedSwapsRequest;
+            type Value = wallet_events_response::SwapDetected;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.UnclaimedSwapsRequest")
+                formatter.write_str("struct penumbra.view.v1.WalletEventsResponse.SwapDetected")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnclaimedSwapsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<wallet_events_response::SwapDetected, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut swap_record__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SwapRecord => {
+                            if swap_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapRecord"));
+                            }
+                            swap_record__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(UnclaimedSwapsRequest {
+                Ok(wallet_events_response::SwapDetected {
+                    swap_record: swap_record__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.UnclaimedSwapsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.WalletEventsResponse.SwapDetected", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for UnclaimedSwapsResponse {
+impl serde::Serialize for wallet_events_response::TransactionRecorded {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -8687,29 +12221,37 @@ impl serde::Serialize for UnclaimedSwapsResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap.is_some() {
+        if self.id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.UnclaimedSwapsResponse", len)?;
-        if let Some(v) = self.swap.as_ref() {
-            struct_ser.serialize_field("swap", v)?;
+        if self.transaction.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.WalletEventsResponse.TransactionRecorded", len)?;
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
+        }
+        if let Some(v) = self.transaction.as_ref() {
+            struct_ser.serialize_field("transaction", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for UnclaimedSwapsResponse {
+impl<'de> serde::Deserialize<'de> for wallet_events_response::TransactionRecorded {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap",
+            "id",
+            "transaction",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Swap,
+            Id,
+            Transaction,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -8732,7 +12274,8 @@ impl<'de> serde::Deserialize<'de> for UnclaimedSwapsResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swap" => Ok(GeneratedField::Swap),
+                            "id" => Ok(GeneratedField::Id),
+                            "transaction" => Ok(GeneratedField::Transaction),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -8742,36 +12285,44 @@ impl<'de> serde::Deserialize<'de> for UnclaimedSwapsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = UnclaimedSwapsResponse;
+            type Value = wallet_events_response::TransactionRecorded;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.UnclaimedSwapsResponse")
+                formatter.write_str("struct penumbra.view.v1.WalletEventsResponse.TransactionRecorded")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnclaimedSwapsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<wallet_events_response::TransactionRecorded, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap__ = None;
+                let mut id__ = None;
+                let mut transaction__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Swap => {
-                            if swap__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swap"));
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
                             }
-                            swap__ = map_.next_value()?;
+                            id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Transaction => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transaction"));
+                            }
+                            transaction__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(UnclaimedSwapsResponse {
-                    swap: swap__,
+                Ok(wallet_events_response::TransactionRecorded {
+                    id: id__,
+                    transaction: transaction__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.UnclaimedSwapsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.WalletEventsResponse.TransactionRecorded", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for WalletIdRequest {
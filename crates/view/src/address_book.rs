@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+
+use penumbra_keys::{Address, AddressView};
+
+/// The labels a user has given to addresses: the addresses of their contacts, and the accounts of
+/// their own wallet.
+///
+/// The address book is kept in the view database, but isn't derived from the chain, so it
+/// survives rescanning the wallet.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AddressBook {
+    /// The label of each contact, by address.
+    pub contacts: BTreeMap<Address, String>,
+    /// The label of each of the wallet's accounts which has one, by account number.
+    pub accounts: BTreeMap<u32, String>,
+}
+
+impl AddressBook {
+    /// The label for an address: the label of its account, if it belongs to the wallet, or the
+    /// label of the contact it belongs to.
+    pub fn label(&self, address: &AddressView) -> Option<&str> {
+        match address {
+            AddressView::Decoded { index, .. } => self.accounts.get(&index.account),
+            AddressView::Opaque { address } => self.contacts.get(address),
+        }
+        .map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_keys::{
+        keys::{AddressIndex, Bip44Path, SpendKey},
+        test_keys,
+    };
+
+    use super::*;
+
+    #[test]
+    fn labels_accounts_and_contacts() {
+        let fvk = &*test_keys::FULL_VIEWING_KEY;
+        let (own, _) = fvk.payment_address(AddressIndex::new(1));
+        let (ephemeral, _) = fvk.ephemeral_address(rand_core::OsRng, AddressIndex::new(1));
+        let other = SpendKey::from_seed_phrase_bip44(
+            test_keys::SEED_PHRASE
+                .parse()
+                .expect("test seed phrase is valid"),
+            &Bip44Path::new(1),
+        );
+        let (contact, _) = other
+            .full_viewing_key()
+            .payment_address(AddressIndex::new(0));
+
+        let book = AddressBook {
+            contacts: [(contact.clone(), "alice".to_string())].into(),
+            accounts: [(1, "savings".to_string())].into(),
+        };
+
+        // One-time addresses of a labeled account have its label too.
+        assert_eq!(book.label(&fvk.view_address(own)), Some("savings"));
+        assert_eq!(book.label(&fvk.view_address(ephemeral)), Some("savings"));
+        assert_eq!(
+            book.label(&AddressView::Opaque { address: contact }),
+            Some("alice")
+        );
+        assert_eq!(
+            book.label(&fvk.view_address(fvk.payment_address(AddressIndex::new(0)).0)),
+            None
+        );
+    }
+}
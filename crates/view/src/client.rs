@@ -14,10 +14,10 @@ use penumbra_asset::{
 };
 use penumbra_dex::{
     lp::position::{self, Position},
-    TradingPair,
+    BatchSwapOutputData, TradingPair,
 };
 use penumbra_fee::GasPrices;
-use penumbra_keys::{keys::AddressIndex, Address};
+use penumbra_keys::{keys::AddressIndex, Address, PaymentRequest};
 use penumbra_num::Amount;
 use penumbra_proto::view::v1::{
     self as pb, view_service_client::ViewServiceClient, BalancesResponse,
@@ -30,7 +30,10 @@ use penumbra_transaction::{
     txhash::TransactionId, AuthorizationData, Transaction, TransactionPlan, WitnessData,
 };
 
-use crate::{SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionInfo};
+use crate::{
+    AddressBook, Invoice, SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionInfo,
+    WalletEvent,
+};
 
 pub(crate) type BroadcastStatusStream = Pin<
     Box<dyn Future<Output = Result<Streaming<BroadcastTransactionResponse>, anyhow::Error>> + Send>,
@@ -92,6 +95,22 @@ pub trait ViewClient {
         >,
     >;
 
+    /// Stream the changes made to the wallet by the blocks scanned from now on.
+    ///
+    /// The stream does not end on its own.
+    fn wallet_events(
+        &mut self,
+    ) -> Pin<
+        Box<
+            dyn Future<
+                    Output = Result<
+                        Pin<Box<dyn Stream<Item = Result<WalletEvent>> + Send + 'static>>,
+                    >,
+                > + Send
+                + 'static,
+        >,
+    >;
+
     /// Get a copy of the app parameters.
     fn app_params(
         &mut self,
@@ -200,6 +219,64 @@ pub trait ViewClient {
         end_height: Option<u64>,
     ) -> Pin<Box<dyn Future<Output = Result<Vec<TransactionInfo>>> + Send + 'static>>;
 
+    /// Queries for the outputs of the batch swaps seen by the view service in a range of block
+    /// heights, ordered by height.
+    fn batch_swap_outputs(
+        &mut self,
+        start_height: Option<u64>,
+        end_height: Option<u64>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<BatchSwapOutputData>>> + Send + 'static>>;
+
+    /// Get the wallet's address book, with the labels of its contacts and of its own accounts.
+    fn address_book(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<AddressBook>> + Send + 'static>>;
+
+    /// Add a contact to the address book, or relabel an existing one.
+    fn set_contact(
+        &mut self,
+        address: Address,
+        label: String,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>>;
+
+    /// Remove a contact from the address book, returning whether it was there.
+    fn delete_contact(
+        &mut self,
+        address: Address,
+    ) -> Pin<Box<dyn Future<Output = Result<bool>> + Send + 'static>>;
+
+    /// Label one of the wallet's accounts, replacing any existing label.
+    fn set_account_label(
+        &mut self,
+        account: u32,
+        label: String,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>>;
+
+    /// Remove the label of one of the wallet's accounts, returning whether it had one.
+    fn delete_account_label(
+        &mut self,
+        account: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<bool>> + Send + 'static>>;
+
+    /// Create an invoice, returning the payment request to send to the payer.
+    fn create_invoice(
+        &mut self,
+        request: pb::CreateInvoiceRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<PaymentRequest>> + Send + 'static>>;
+
+    /// Get the wallet's invoices, with the payments received for each, optionally including
+    /// those which have been paid in full.
+    fn invoices(
+        &mut self,
+        include_paid: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Invoice>>> + Send + 'static>>;
+
+    /// Remove one of the wallet's invoices, returning whether it was there.
+    fn delete_invoice(
+        &mut self,
+        invoice_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<bool>> + Send + 'static>>;
+
     fn broadcast_transaction(
         &mut self,
         transaction: Transaction,
@@ -391,6 +468,31 @@ where
         .boxed()
     }
 
+    fn wallet_events(
+        &mut self,
+    ) -> Pin<
+        Box<
+            dyn Future<
+                    Output = Result<
+                        Pin<Box<dyn Stream<Item = Result<WalletEvent>> + Send + 'static>>,
+                    >,
+                > + Send
+                + 'static,
+        >,
+    > {
+        let mut self2 = self.clone();
+        async move {
+            let stream = self2.wallet_events(tonic::Request::new(pb::WalletEventsRequest {}));
+            let stream = stream.await?.into_inner();
+
+            Ok(stream
+                .map_err(|e| anyhow::anyhow!("view service error: {}", e))
+                .and_then(|msg| async move { WalletEvent::try_from(msg) })
+                .boxed())
+        }
+        .boxed()
+    }
+
     fn app_params(
         &mut self,
     ) -> Pin<Box<dyn Future<Output = Result<AppParameters>> + Send + 'static>> {
@@ -834,6 +936,204 @@ where
         .boxed()
     }
 
+    fn batch_swap_outputs(
+        &mut self,
+        start_height: Option<u64>,
+        end_height: Option<u64>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<BatchSwapOutputData>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = self2.batch_swap_outputs(tonic::Request::new(pb::BatchSwapOutputsRequest {
+                start_height: start_height.unwrap_or_default(),
+                end_height: end_height.unwrap_or_default(),
+            }));
+            let pb_outputs: Vec<_> = rsp.await?.into_inner().try_collect().await?;
+
+            pb_outputs
+                .into_iter()
+                .map(|rsp| {
+                    rsp.data
+                        .ok_or_else(|| anyhow::anyhow!("empty BatchSwapOutputsResponse message"))?
+                        .try_into()
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
+    fn address_book(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<AddressBook>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let contacts: Vec<_> = self2
+                .contacts(tonic::Request::new(pb::ContactsRequest {}))
+                .await?
+                .into_inner()
+                .try_collect()
+                .await?;
+            let account_labels: Vec<_> = self2
+                .account_labels(tonic::Request::new(pb::AccountLabelsRequest {}))
+                .await?
+                .into_inner()
+                .try_collect()
+                .await?;
+
+            let mut address_book = AddressBook::default();
+            for rsp in contacts {
+                let contact = rsp
+                    .contact
+                    .ok_or_else(|| anyhow::anyhow!("empty ContactsResponse message"))?;
+                let address = contact
+                    .address
+                    .ok_or_else(|| anyhow::anyhow!("missing contact address"))?
+                    .try_into()?;
+                address_book.contacts.insert(address, contact.label);
+            }
+            for rsp in account_labels {
+                let account_label = rsp
+                    .account_label
+                    .ok_or_else(|| anyhow::anyhow!("empty AccountLabelsResponse message"))?;
+                address_book
+                    .accounts
+                    .insert(account_label.account, account_label.label);
+            }
+
+            Ok(address_book)
+        }
+        .boxed()
+    }
+
+    fn set_contact(
+        &mut self,
+        address: Address,
+        label: String,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            // We have to manually invoke the method on the type, because it has the
+            // same name as the one we're implementing.
+            ViewServiceClient::set_contact(
+                &mut self2,
+                tonic::Request::new(pb::SetContactRequest {
+                    contact: Some(pb::Contact {
+                        address: Some(address.into()),
+                        label,
+                    }),
+                }),
+            )
+            .await?;
+            Ok(())
+        }
+        .boxed()
+    }
+
+    fn delete_contact(
+        &mut self,
+        address: Address,
+    ) -> Pin<Box<dyn Future<Output = Result<bool>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::delete_contact(
+                &mut self2,
+                tonic::Request::new(pb::DeleteContactRequest {
+                    address: Some(address.into()),
+                }),
+            );
+            Ok(rsp.await?.into_inner().deleted)
+        }
+        .boxed()
+    }
+
+    fn set_account_label(
+        &mut self,
+        account: u32,
+        label: String,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            ViewServiceClient::set_account_label(
+                &mut self2,
+                tonic::Request::new(pb::SetAccountLabelRequest {
+                    account_label: Some(pb::AccountLabel { account, label }),
+                }),
+            )
+            .await?;
+            Ok(())
+        }
+        .boxed()
+    }
+
+    fn delete_account_label(
+        &mut self,
+        account: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<bool>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::delete_account_label(
+                &mut self2,
+                tonic::Request::new(pb::DeleteAccountLabelRequest { account }),
+            );
+            Ok(rsp.await?.into_inner().deleted)
+        }
+        .boxed()
+    }
+
+    fn create_invoice(
+        &mut self,
+        request: pb::CreateInvoiceRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<PaymentRequest>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::create_invoice(&mut self2, tonic::Request::new(request));
+            rsp.await?
+                .into_inner()
+                .payment_request
+                .ok_or_else(|| anyhow::anyhow!("empty CreateInvoiceResponse message"))?
+                .try_into()
+        }
+        .boxed()
+    }
+
+    fn invoices(
+        &mut self,
+        include_paid: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Invoice>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::invoices(
+                &mut self2,
+                tonic::Request::new(pb::InvoicesRequest { include_paid }),
+            );
+            let invoices: Vec<_> = rsp.await?.into_inner().try_collect().await?;
+
+            invoices
+                .into_iter()
+                .map(|rsp| {
+                    rsp.invoice
+                        .ok_or_else(|| anyhow::anyhow!("empty InvoicesResponse message"))?
+                        .try_into()
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
+    fn delete_invoice(
+        &mut self,
+        invoice_id: String,
+    ) -> Pin<Box<dyn Future<Output = Result<bool>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::delete_invoice(
+                &mut self2,
+                tonic::Request::new(pb::DeleteInvoiceRequest { invoice_id }),
+            );
+            Ok(rsp.await?.into_inner().deleted)
+        }
+        .boxed()
+    }
+
     fn broadcast_transaction(
         &mut self,
         transaction: Transaction,
@@ -0,0 +1,158 @@
+use penumbra_auction::auction::AuctionId;
+use penumbra_dex::lp::position;
+use penumbra_proto::{view::v1 as pb, DomainType};
+use penumbra_sct::Nullifier;
+use penumbra_transaction::{txhash::TransactionId, Transaction};
+
+use pb::wallet_events_response as pbe;
+
+use crate::{SpendableNoteRecord, SwapRecord};
+
+/// A change made to a wallet by scanning a block, as sent to subscribers of the view service.
+#[derive(Clone, Debug)]
+pub struct WalletEvent {
+    /// The height of the block whose scan made the change.
+    pub height: u64,
+    pub kind: WalletEventKind,
+}
+
+#[derive(Clone, Debug)]
+pub enum WalletEventKind {
+    /// A new note was sent to the wallet.
+    NoteDetected(SpendableNoteRecord),
+    /// One of the wallet's notes was spent, revealing `nullifier`.
+    NoteSpent {
+        nullifier: Nullifier,
+        /// The spent note, if it was known to the view service.
+        note_record: Option<SpendableNoteRecord>,
+    },
+    /// A new swap was made by the wallet.
+    SwapDetected(SwapRecord),
+    /// A transaction relevant to the wallet was recorded.
+    TransactionRecorded(Box<Transaction>),
+    /// One of the wallet's liquidity positions changed state.
+    PositionUpdated {
+        position_id: position::Id,
+        state: position::State,
+    },
+    /// One of the wallet's auctions changed state.
+    AuctionUpdated { auction_id: AuctionId, seq: u64 },
+}
+
+impl DomainType for WalletEvent {
+    type Proto = pb::WalletEventsResponse;
+}
+
+impl From<WalletEvent> for pb::WalletEventsResponse {
+    fn from(msg: WalletEvent) -> Self {
+        let event = match msg.kind {
+            WalletEventKind::NoteDetected(note_record) => {
+                pbe::Event::NoteDetected(pbe::NoteDetected {
+                    note_record: Some(note_record.into()),
+                })
+            }
+            WalletEventKind::NoteSpent {
+                nullifier,
+                note_record,
+            } => pbe::Event::NoteSpent(pbe::NoteSpent {
+                nullifier: Some(nullifier.into()),
+                note_record: note_record.map(Into::into),
+            }),
+            WalletEventKind::SwapDetected(swap_record) => {
+                pbe::Event::SwapDetected(pbe::SwapDetected {
+                    swap_record: Some(swap_record.into()),
+                })
+            }
+            WalletEventKind::TransactionRecorded(transaction) => {
+                pbe::Event::TransactionRecorded(pbe::TransactionRecorded {
+                    id: Some(transaction.id().into()),
+                    transaction: Some((*transaction).into()),
+                })
+            }
+            WalletEventKind::PositionUpdated { position_id, state } => {
+                pbe::Event::PositionUpdated(pbe::PositionUpdated {
+                    position_id: Some(position_id.into()),
+                    state: Some(state.into()),
+                })
+            }
+            WalletEventKind::AuctionUpdated { auction_id, seq } => {
+                pbe::Event::AuctionUpdated(pbe::AuctionUpdated {
+                    auction_id: Some(auction_id.into()),
+                    seq,
+                })
+            }
+        };
+
+        pb::WalletEventsResponse {
+            height: msg.height,
+            event: Some(event),
+        }
+    }
+}
+
+impl TryFrom<pb::WalletEventsResponse> for WalletEvent {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::WalletEventsResponse) -> Result<Self, Self::Error> {
+        let kind = match value
+            .event
+            .ok_or_else(|| anyhow::anyhow!("missing event"))?
+        {
+            pbe::Event::NoteDetected(event) => WalletEventKind::NoteDetected(
+                event
+                    .note_record
+                    .ok_or_else(|| anyhow::anyhow!("missing note_record"))?
+                    .try_into()?,
+            ),
+            pbe::Event::NoteSpent(event) => WalletEventKind::NoteSpent {
+                nullifier: event
+                    .nullifier
+                    .ok_or_else(|| anyhow::anyhow!("missing nullifier"))?
+                    .try_into()?,
+                note_record: event.note_record.map(TryInto::try_into).transpose()?,
+            },
+            pbe::Event::SwapDetected(event) => WalletEventKind::SwapDetected(
+                event
+                    .swap_record
+                    .ok_or_else(|| anyhow::anyhow!("missing swap_record"))?
+                    .try_into()?,
+            ),
+            pbe::Event::TransactionRecorded(event) => {
+                let transaction: Transaction = event
+                    .transaction
+                    .ok_or_else(|| anyhow::anyhow!("missing transaction"))?
+                    .try_into()?;
+                if let Some(id) = event.id {
+                    let id = TransactionId::try_from(id)?;
+                    anyhow::ensure!(
+                        id == transaction.id(),
+                        "transaction id does not match the transaction"
+                    );
+                }
+                WalletEventKind::TransactionRecorded(Box::new(transaction))
+            }
+            pbe::Event::PositionUpdated(event) => WalletEventKind::PositionUpdated {
+                position_id: event
+                    .position_id
+                    .ok_or_else(|| anyhow::anyhow!("missing position_id"))?
+                    .try_into()?,
+                state: event
+                    .state
+                    .ok_or_else(|| anyhow::anyhow!("missing state"))?
+                    .try_into()?,
+            },
+            pbe::Event::AuctionUpdated(event) => WalletEventKind::AuctionUpdated {
+                auction_id: event
+                    .auction_id
+                    .ok_or_else(|| anyhow::anyhow!("missing auction_id"))?
+                    .try_into()?,
+                seq: event.seq,
+            },
+        };
+
+        Ok(Self {
+            height: value.height,
+            kind,
+        })
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::BTreeMap;
+
+use penumbra_asset::{asset, Value};
+use penumbra_keys::PaymentRequest;
+use penumbra_num::Amount;
+use penumbra_proto::{view::v1 as pb, DomainType};
+
+use crate::SpendableNoteRecord;
+
+/// An invoice: a payment request to one of the wallet's accounts, with the notes received in
+/// payment of it.
+///
+/// A note pays an invoice with a dedicated address if it is sent to that address. Otherwise, it
+/// pays the invoice if it is sent to the invoice's account by a transaction whose memo is the
+/// request's [`PaymentRequest::payment_memo`].
+#[derive(Clone, Debug)]
+pub struct Invoice {
+    /// The payment request sent to the payer.
+    pub request: PaymentRequest,
+    /// Whether the request is paid to a one-time address of its own.
+    pub dedicated_address: bool,
+    /// The notes received in payment of the invoice.
+    pub payments: Vec<SpendableNoteRecord>,
+}
+
+impl Invoice {
+    /// The total amount of each asset received in payment of the invoice.
+    pub fn received(&self) -> BTreeMap<asset::Id, Amount> {
+        let mut received = BTreeMap::<_, Amount>::new();
+        for payment in &self.payments {
+            *received.entry(payment.note.asset_id()).or_default() += payment.note.amount();
+        }
+        received
+    }
+
+    /// Whether the invoice has been paid in full: every value it requests has been received, or,
+    /// if it requests none, anything has.
+    pub fn is_paid(&self) -> bool {
+        covers(&self.received(), &self.request.values)
+    }
+}
+
+/// Whether the amounts received cover the values requested.
+fn covers(received: &BTreeMap<asset::Id, Amount>, requested: &[Value]) -> bool {
+    if requested.is_empty() {
+        return !received.is_empty();
+    }
+    let mut requested_amounts = BTreeMap::<_, Amount>::new();
+    for value in requested {
+        *requested_amounts.entry(value.asset_id).or_default() += value.amount;
+    }
+    requested_amounts
+        .into_iter()
+        .all(|(asset_id, amount)| received.get(&asset_id).copied().unwrap_or_default() >= amount)
+}
+
+impl DomainType for Invoice {
+    type Proto = pb::Invoice;
+}
+
+impl From<Invoice> for pb::Invoice {
+    fn from(invoice: Invoice) -> Self {
+        Self {
+            payment_request: Some(invoice.request.into()),
+            dedicated_address: invoice.dedicated_address,
+            payments: invoice.payments.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::Invoice> for Invoice {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::Invoice) -> Result<Self, Self::Error> {
+        Ok(Self {
+            request: proto
+                .payment_request
+                .ok_or_else(|| anyhow::anyhow!("missing payment request"))?
+                .try_into()?,
+            dedicated_address: proto.dedicated_address,
+            payments: proto
+                .payments
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+
+    use super::*;
+
+    #[test]
+    fn payments_cover_requested_values() {
+        let penumbra = *STAKING_TOKEN_ASSET_ID;
+        let other = asset::Id(decaf377::Fq::from(1u64));
+        let value = |asset_id, amount: u64| Value {
+            amount: amount.into(),
+            asset_id,
+        };
+        let received = |amounts: &[(asset::Id, u64)]| -> BTreeMap<asset::Id, Amount> {
+            amounts
+                .iter()
+                .map(|&(asset_id, amount)| (asset_id, amount.into()))
+                .collect()
+        };
+
+        // Values of the same asset are requested in total.
+        let requested = [value(penumbra, 5), value(penumbra, 5), value(other, 1)];
+        assert!(covers(&received(&[(penumbra, 10), (other, 1)]), &requested));
+        assert!(covers(&received(&[(penumbra, 11), (other, 2)]), &requested));
+        assert!(!covers(&received(&[(penumbra, 9), (other, 1)]), &requested));
+        assert!(!covers(&received(&[(penumbra, 10)]), &requested));
+
+        // A request without values is paid by anything.
+        assert!(covers(&received(&[(other, 1)]), &[]));
+        assert!(!covers(&received(&[]), &[]));
+    }
+}
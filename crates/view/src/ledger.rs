@@ -0,0 +1,656 @@
+//! An accounting ledger of a wallet's history, with the cost basis of the assets it disposes of.
+//!
+//! Each transaction recorded by the view service becomes a [`LedgerEntry`], listing the amount of
+//! each asset the wallet received or sent, and the fee it paid. The amounts are valued in a
+//! numeraire asset using the clearing prices of the DEX batch swaps at the height of the
+//! transaction, and the amounts sent are matched against the lots of the asset the wallet
+//! received earlier, first in first out or last in first out, to find their cost basis.
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use penumbra_asset::{
+    asset::{self, Metadata},
+    Value,
+};
+use penumbra_dex::{swap_claim::SwapClaimView, BatchSwapOutputData};
+use penumbra_keys::AddressView;
+use penumbra_num::{fixpoint::U128x128, Amount};
+use penumbra_shielded_pool::{OutputView, SpendView};
+use penumbra_transaction::{txhash::TransactionId, ActionView};
+use serde::Serialize;
+
+use crate::{TransactionInfo, ViewClient};
+
+/// How the amounts of an asset the wallet sends are matched to the lots it received.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CostBasisMethod {
+    /// The oldest lots are disposed of first.
+    #[default]
+    Fifo,
+    /// The newest lots are disposed of first.
+    Lifo,
+}
+
+/// What a transaction did for the wallet, judging by its first action of interest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryKind {
+    /// Funds were sent away from the wallet.
+    Send,
+    /// Funds were sent to the wallet by someone else.
+    Receive,
+    /// The wallet only moved funds between its own addresses.
+    Internal,
+    Swap,
+    /// The outputs of a swap were claimed.
+    SwapClaim,
+    PositionOpen,
+    PositionClose,
+    /// The reserves of a closed liquidity position were withdrawn.
+    PositionWithdraw,
+    AuctionSchedule,
+    AuctionEnd,
+    /// The proceeds of an auction were withdrawn.
+    AuctionWithdraw,
+    Delegate,
+    Undelegate,
+    UndelegateClaim,
+    IbcWithdrawal,
+}
+
+/// Which way an amount moved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    /// The amount was received by the wallet.
+    In,
+    /// The amount was sent away by the wallet.
+    Out,
+    /// The amount was paid by the wallet as a transaction fee.
+    Fee,
+}
+
+/// A change to the wallet's holdings of one asset made by a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Posting {
+    pub direction: Direction,
+    pub value: Value,
+    /// The value of the amount in the numeraire, if the asset had a price at the height of the
+    /// transaction.
+    pub numeraire_value: Option<Amount>,
+    /// The cost of the lots the amount was matched to, in the numeraire, for amounts which left
+    /// the wallet, if each of them had a price when it was received.
+    pub cost_basis: Option<Amount>,
+}
+
+impl Posting {
+    /// The gain realized by disposing of the amount, which is negative for a loss.
+    pub fn gain(&self) -> Option<i128> {
+        i128::from(self.numeraire_value?).checked_sub(i128::from(self.cost_basis?))
+    }
+}
+
+/// The changes made to the wallet's holdings by one transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LedgerEntry {
+    pub height: u64,
+    pub transaction_id: TransactionId,
+    pub kind: EntryKind,
+    pub postings: Vec<Posting>,
+}
+
+/// The wallet's history as a list of ledger entries, in order of height.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ledger {
+    pub entries: Vec<LedgerEntry>,
+}
+
+impl Ledger {
+    /// Build the ledger of the given transactions, which should be the wallet's whole history, so
+    /// that the lots sent in later transactions were received in earlier ones.
+    pub fn new(
+        transactions: impl IntoIterator<Item = TransactionInfo>,
+        prices: &Prices,
+        method: CostBasisMethod,
+    ) -> Self {
+        let mut transactions = transactions.into_iter().collect::<Vec<_>>();
+        transactions.sort_by_key(|info| info.height);
+
+        let mut book = Book::new(method);
+        let entries = transactions
+            .iter()
+            .map(|info| {
+                let (kind, flows) = flows(info);
+                book.record(info.height, info.id, kind, flows, prices)
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Build the ledger of the wallet's whole history from its view service, valued in the
+    /// numeraire at the prices of the batch swaps the view service has seen.
+    pub async fn fetch(
+        view: &mut impl ViewClient,
+        numeraire: asset::Id,
+        method: CostBasisMethod,
+    ) -> anyhow::Result<Self> {
+        let transactions = view.transaction_info(None, None).await?;
+        let prices = Prices::new(numeraire, view.batch_swap_outputs(None, None).await?);
+        Ok(Self::new(transactions, &prices, method))
+    }
+
+    /// The postings of the ledger as flat rows, with amounts formatted in the display units of
+    /// their assets, and values in the display units of the numeraire.
+    pub fn rows(&self, assets: &asset::Cache, numeraire: &Metadata) -> Vec<LedgerRow> {
+        let numeraire = &numeraire.default_unit();
+        let format_numeraire = |amount: Option<Amount>| amount.map(|a| numeraire.format_value(a));
+
+        self.entries
+            .iter()
+            .flat_map(|entry| {
+                entry.postings.iter().map(move |posting| {
+                    let (asset, amount) = match assets.get(&posting.value.asset_id) {
+                        Some(metadata) => {
+                            let unit = metadata.default_unit();
+                            (unit.to_string(), unit.format_value(posting.value.amount))
+                        }
+                        None => (
+                            posting.value.asset_id.to_string(),
+                            posting.value.amount.to_string(),
+                        ),
+                    };
+                    let gain = posting.gain().map(|gain| {
+                        let magnitude = numeraire.format_value(gain.unsigned_abs().into());
+                        if gain < 0 {
+                            format!("-{magnitude}")
+                        } else {
+                            magnitude
+                        }
+                    });
+
+                    LedgerRow {
+                        height: entry.height,
+                        transaction_id: entry.transaction_id.to_string(),
+                        kind: entry.kind,
+                        direction: posting.direction,
+                        asset,
+                        amount,
+                        value: format_numeraire(posting.numeraire_value),
+                        cost_basis: format_numeraire(posting.cost_basis),
+                        gain,
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+/// One posting of a [`Ledger`], formatted for export.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct LedgerRow {
+    pub height: u64,
+    pub transaction_id: String,
+    pub kind: EntryKind,
+    pub direction: Direction,
+    pub asset: String,
+    pub amount: String,
+    pub value: Option<String>,
+    pub cost_basis: Option<String>,
+    pub gain: Option<String>,
+}
+
+impl LedgerRow {
+    const CSV_HEADER: &'static str =
+        "height,transaction_id,kind,direction,asset,amount,value,cost_basis,gain";
+
+    /// Write the rows as CSV, with a header line.
+    pub fn write_csv(rows: &[LedgerRow], mut writer: impl std::io::Write) -> std::io::Result<()> {
+        writeln!(writer, "{}", Self::CSV_HEADER)?;
+        for row in rows {
+            let kind = serde_json::to_value(row.kind)?;
+            let direction = serde_json::to_value(row.direction)?;
+            let fields = [
+                row.height.to_string(),
+                row.transaction_id.clone(),
+                kind.as_str().unwrap_or_default().to_string(),
+                direction.as_str().unwrap_or_default().to_string(),
+                row.asset.clone(),
+                row.amount.clone(),
+                row.value.clone().unwrap_or_default(),
+                row.cost_basis.clone().unwrap_or_default(),
+                row.gain.clone().unwrap_or_default(),
+            ];
+            let fields = fields
+                .iter()
+                .map(String::as_str)
+                .map(csv_field)
+                .collect::<Vec<_>>();
+            writeln!(writer, "{}", fields.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+/// Quote a CSV field if it contains a separator, quote, or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// The prices of assets in a numeraire at past heights, from the outputs of batch swaps between
+/// them and the numeraire.
+#[derive(Clone, Debug)]
+pub struct Prices {
+    numeraire: asset::Id,
+    /// For each asset, the number of base units of the numeraire one base unit of the asset
+    /// cleared at, at each height it was traded against the numeraire.
+    by_asset: BTreeMap<asset::Id, BTreeMap<u64, U128x128>>,
+}
+
+impl Prices {
+    pub fn new(
+        numeraire: asset::Id,
+        batch_swap_outputs: impl IntoIterator<Item = BatchSwapOutputData>,
+    ) -> Self {
+        let mut by_asset = BTreeMap::<_, BTreeMap<_, _>>::new();
+        for output_data in batch_swap_outputs {
+            let pair = output_data.trading_pair;
+            // The amounts of each asset which changed hands in the batch, in either direction.
+            let traded_1 = output_data
+                .delta_1
+                .saturating_sub(&output_data.unfilled_1)
+                .saturating_add(&output_data.lambda_1);
+            let traded_2 = output_data
+                .delta_2
+                .saturating_sub(&output_data.unfilled_2)
+                .saturating_add(&output_data.lambda_2);
+
+            let (asset_id, price) = if pair.asset_2() == numeraire {
+                (pair.asset_1(), U128x128::ratio(traded_2, traded_1))
+            } else if pair.asset_1() == numeraire {
+                (pair.asset_2(), U128x128::ratio(traded_1, traded_2))
+            } else {
+                continue;
+            };
+            // If nothing was traded, the batch says nothing about the price.
+            if let Ok(price) = price {
+                if price != U128x128::default() {
+                    by_asset
+                        .entry(asset_id)
+                        .or_default()
+                        .insert(output_data.height, price);
+                }
+            }
+        }
+
+        Self {
+            numeraire,
+            by_asset,
+        }
+    }
+
+    pub fn numeraire(&self) -> asset::Id {
+        self.numeraire
+    }
+
+    /// The price of one base unit of the asset in base units of the numeraire, from the last
+    /// batch swap against the numeraire at or before `height`.
+    pub fn price(&self, asset_id: asset::Id, height: u64) -> Option<U128x128> {
+        if asset_id == self.numeraire {
+            return Some(1u64.into());
+        }
+        self.by_asset
+            .get(&asset_id)?
+            .range(..=height)
+            .next_back()
+            .map(|(_, price)| *price)
+    }
+}
+
+/// The amounts of each asset the transaction moved in and out of the wallet, along with the fee
+/// it paid, and what kind of transaction it was.
+fn flows(info: &TransactionInfo) -> (EntryKind, Vec<(Direction, Value)>) {
+    let mut received = BTreeMap::<asset::Id, Amount>::new();
+    let mut sent = BTreeMap::<asset::Id, Amount>::new();
+    let mut kind = None;
+    // Only the wallet's own spends are visible to it, so if any are, the wallet paid the fee.
+    let mut funded = false;
+
+    let mut receive = |value: Value| {
+        *received.entry(value.asset_id).or_default() += value.amount;
+    };
+    for action in info.view.action_views() {
+        match action {
+            ActionView::Spend(SpendView::Visible { note, .. }) => {
+                funded = true;
+                let value = note.value.value();
+                *sent.entry(value.asset_id).or_default() += value.amount;
+            }
+            ActionView::Output(OutputView::Visible { note, .. }) => {
+                if matches!(note.address, AddressView::Decoded { .. }) {
+                    receive(note.value.value());
+                }
+            }
+            ActionView::SwapClaim(SwapClaimView::Visible {
+                output_1, output_2, ..
+            }) => {
+                receive(output_1.value.value());
+                receive(output_2.value.value());
+            }
+            _ => {}
+        }
+
+        kind = kind.or(match action {
+            ActionView::Swap(_) => Some(EntryKind::Swap),
+            ActionView::SwapClaim(_) => Some(EntryKind::SwapClaim),
+            ActionView::PositionOpen(_) => Some(EntryKind::PositionOpen),
+            ActionView::PositionClose(_) => Some(EntryKind::PositionClose),
+            ActionView::PositionWithdraw(_) => Some(EntryKind::PositionWithdraw),
+            ActionView::ActionDutchAuctionSchedule(_) => Some(EntryKind::AuctionSchedule),
+            ActionView::ActionDutchAuctionEnd(_) => Some(EntryKind::AuctionEnd),
+            ActionView::ActionDutchAuctionWithdraw(_) => Some(EntryKind::AuctionWithdraw),
+            ActionView::Delegate(_) => Some(EntryKind::Delegate),
+            ActionView::Undelegate(_) => Some(EntryKind::Undelegate),
+            ActionView::UndelegateClaim(_) => Some(EntryKind::UndelegateClaim),
+            ActionView::Ics20Withdrawal(_) => Some(EntryKind::IbcWithdrawal),
+            _ => None,
+        });
+    }
+
+    let mut flows = Vec::new();
+    if funded {
+        let fee = info.view.body_view.transaction_parameters.fee.0;
+        if fee.amount > Amount::zero() {
+            let fee_sent = sent.entry(fee.asset_id).or_default();
+            *fee_sent = fee_sent.saturating_sub(&fee.amount);
+            flows.push((Direction::Fee, fee));
+        }
+    }
+
+    // Only the net change to each asset matters, since change comes back to the wallet.
+    let asset_ids = received.keys().chain(sent.keys()).copied();
+    for asset_id in asset_ids.collect::<BTreeSet<_>>() {
+        let received = received.get(&asset_id).copied().unwrap_or_default();
+        let sent = sent.get(&asset_id).copied().unwrap_or_default();
+        if received > sent {
+            flows.push((
+                Direction::In,
+                Value {
+                    amount: received - sent,
+                    asset_id,
+                },
+            ));
+        } else if sent > received {
+            flows.push((
+                Direction::Out,
+                Value {
+                    amount: sent - received,
+                    asset_id,
+                },
+            ));
+        }
+    }
+
+    let kind = kind.unwrap_or_else(|| {
+        let moved = |direction| flows.iter().any(|(d, _)| *d == direction);
+        if moved(Direction::Out) {
+            EntryKind::Send
+        } else if moved(Direction::In) && !funded {
+            EntryKind::Receive
+        } else {
+            EntryKind::Internal
+        }
+    });
+
+    (kind, flows)
+}
+
+/// A quantity of an asset the wallet received at once.
+#[derive(Clone, Debug)]
+struct Lot {
+    amount: Amount,
+    /// The price of one base unit when the lot was received, if it had one.
+    unit_cost: Option<U128x128>,
+}
+
+/// The lots of each asset held by the wallet.
+#[derive(Clone, Debug)]
+struct Book {
+    method: CostBasisMethod,
+    lots: BTreeMap<asset::Id, VecDeque<Lot>>,
+}
+
+impl Book {
+    fn new(method: CostBasisMethod) -> Self {
+        Self {
+            method,
+            lots: BTreeMap::new(),
+        }
+    }
+
+    /// Value the flows of a transaction, adding the amounts received as new lots and taking the
+    /// amounts sent out of the lots already held.
+    fn record(
+        &mut self,
+        height: u64,
+        transaction_id: TransactionId,
+        kind: EntryKind,
+        flows: Vec<(Direction, Value)>,
+        prices: &Prices,
+    ) -> LedgerEntry {
+        let postings = flows
+            .into_iter()
+            .map(|(direction, value)| {
+                let price = prices.price(value.asset_id, height);
+                let numeraire_value =
+                    price.and_then(|price| price.apply_to_amount(&value.amount).ok());
+                let cost_basis = match direction {
+                    Direction::In => {
+                        self.acquire(value, price);
+                        None
+                    }
+                    Direction::Out | Direction::Fee => self.dispose(value),
+                };
+                Posting {
+                    direction,
+                    value,
+                    numeraire_value,
+                    cost_basis,
+                }
+            })
+            .collect();
+
+        LedgerEntry {
+            height,
+            transaction_id,
+            kind,
+            postings,
+        }
+    }
+
+    fn acquire(&mut self, value: Value, unit_cost: Option<U128x128>) {
+        self.lots.entry(value.asset_id).or_default().push_back(Lot {
+            amount: value.amount,
+            unit_cost,
+        });
+    }
+
+    /// Take the value out of the lots of its asset, returning their total cost, or `None` if any
+    /// of them had no price, or if there weren't enough lots to cover it.
+    fn dispose(&mut self, value: Value) -> Option<Amount> {
+        let lots = self.lots.entry(value.asset_id).or_default();
+        let mut remaining = value.amount;
+        let mut cost = Some(Amount::zero());
+
+        while remaining > Amount::zero() {
+            let lot = match self.method {
+                CostBasisMethod::Fifo => lots.front_mut(),
+                CostBasisMethod::Lifo => lots.back_mut(),
+            };
+            let Some(lot) = lot else {
+                // The wallet is sending more than it is known to have received, such as funds
+                // from before its birthday, whose cost is unknown.
+                return None;
+            };
+
+            let taken = remaining.min(lot.amount);
+            cost = cost.zip(lot.unit_cost).and_then(|(cost, unit_cost)| {
+                let lot_cost = unit_cost.apply_to_amount(&taken).ok()?;
+                cost.checked_add(&lot_cost)
+            });
+            lot.amount = lot.amount - taken;
+            remaining = remaining - taken;
+
+            if lot.amount == Amount::zero() {
+                match self.method {
+                    CostBasisMethod::Fifo => lots.pop_front(),
+                    CostBasisMethod::Lifo => lots.pop_back(),
+                };
+            }
+        }
+
+        cost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_dex::TradingPair;
+
+    use super::*;
+
+    fn other_asset() -> asset::Id {
+        asset::Id(2u64.into())
+    }
+
+    fn value(amount: u64, asset_id: asset::Id) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id,
+        }
+    }
+
+    /// A batch swap at `height` in which `sold` of the other asset was sold for `bought` of the
+    /// staking token.
+    fn batch_swap(height: u64, sold: u64, bought: u64) -> BatchSwapOutputData {
+        let trading_pair = TradingPair::new(*STAKING_TOKEN_ASSET_ID, other_asset());
+        let (delta_1, delta_2, lambda_1, lambda_2) = if trading_pair.asset_1() == other_asset() {
+            (sold, 0, 0, bought)
+        } else {
+            (0, sold, bought, 0)
+        };
+        BatchSwapOutputData {
+            delta_1: delta_1.into(),
+            delta_2: delta_2.into(),
+            lambda_1: lambda_1.into(),
+            lambda_2: lambda_2.into(),
+            unfilled_1: 0u64.into(),
+            unfilled_2: 0u64.into(),
+            height,
+            trading_pair,
+            sct_position_prefix: 0u64.into(),
+        }
+    }
+
+    fn prices() -> Prices {
+        Prices::new(
+            *STAKING_TOKEN_ASSET_ID,
+            [
+                batch_swap(10, 100, 200),
+                batch_swap(20, 100, 300),
+                batch_swap(30, 100, 600),
+            ],
+        )
+    }
+
+    #[test]
+    fn prices_come_from_the_last_batch_swap() {
+        let prices = prices();
+        assert_eq!(prices.price(other_asset(), 5), None);
+        assert_eq!(prices.price(other_asset(), 10), Some(2u64.into()));
+        assert_eq!(prices.price(other_asset(), 25), Some(3u64.into()));
+        assert_eq!(prices.price(*STAKING_TOKEN_ASSET_ID, 0), Some(1u64.into()));
+    }
+
+    /// Receive 10 of the other asset at each of heights 10 and 20, then send 15 at height 30.
+    fn sale(method: CostBasisMethod) -> LedgerEntry {
+        let prices = prices();
+        let mut book = Book::new(method);
+        for height in [10, 20] {
+            book.record(
+                height,
+                TransactionId([0; 32]),
+                EntryKind::Receive,
+                vec![(Direction::In, value(10, other_asset()))],
+                &prices,
+            );
+        }
+        book.record(
+            30,
+            TransactionId([1; 32]),
+            EntryKind::Send,
+            vec![
+                (Direction::Fee, value(1, *STAKING_TOKEN_ASSET_ID)),
+                (Direction::Out, value(15, other_asset())),
+            ],
+            &prices,
+        )
+    }
+
+    #[test]
+    fn fifo_disposes_of_the_oldest_lots() {
+        let entry = sale(CostBasisMethod::Fifo);
+        let sent = &entry.postings[1];
+        assert_eq!(sent.numeraire_value, Some(90u64.into()));
+        // 10 at a price of 2, and 5 at a price of 3.
+        assert_eq!(sent.cost_basis, Some(35u64.into()));
+        assert_eq!(sent.gain(), Some(55));
+    }
+
+    #[test]
+    fn lifo_disposes_of_the_newest_lots() {
+        let entry = sale(CostBasisMethod::Lifo);
+        let sent = &entry.postings[1];
+        // 10 at a price of 3, and 5 at a price of 2.
+        assert_eq!(sent.cost_basis, Some(40u64.into()));
+        assert_eq!(sent.gain(), Some(50));
+    }
+
+    #[test]
+    fn unknown_lots_have_no_cost_basis() {
+        // The fee is paid with staking tokens the wallet isn't known to have received.
+        let entry = sale(CostBasisMethod::Fifo);
+        let fee = &entry.postings[0];
+        assert_eq!(fee.direction, Direction::Fee);
+        assert_eq!(fee.numeraire_value, Some(1u64.into()));
+        assert_eq!(fee.cost_basis, None);
+        assert_eq!(fee.gain(), None);
+    }
+
+    #[test]
+    fn csv_fields_are_quoted() {
+        let row = LedgerRow {
+            height: 1,
+            transaction_id: "ab".to_string(),
+            kind: EntryKind::SwapClaim,
+            direction: Direction::In,
+            asset: "a,\"b\"".to_string(),
+            amount: "1.5".to_string(),
+            value: None,
+            cost_basis: None,
+            gain: Some("-0.5".to_string()),
+        };
+        let mut csv = Vec::new();
+        LedgerRow::write_csv(&[row], &mut csv).expect("can write to a vec");
+        assert_eq!(
+            String::from_utf8(csv).expect("csv is utf-8"),
+            "height,transaction_id,kind,direction,asset,amount,value,cost_basis,gain\n\
+             1,ab,swap_claim,in,\"a,\"\"b\"\"\",1.5,,,-0.5\n"
+        );
+    }
+}
@@ -2,18 +2,25 @@
 //!
 //! This crate provides a [`ViewClient`] and a [`ViewServer`]. These form a client-server pair to
 //! synchronize and interact with public chain state using one or more full viewing keys. See the
-//! documentation of [`ViewClient`] and a [`ViewServer`] for more information.
+//! documentation of [`ViewClient`] and a [`ViewServer`] for more information. Many wallets can
+//! be served from one database by a [`MultiViewServer`].
 //!
 //! This crate also provides a [`Planner`]. This is a planner for
 //! [`TransactionPlan`][penumbra_transaction::TransactionPlan].
 //!
-//! Finally, this crate provides a [`Storage`] type for managing persistent sqlite storage.
+//! Finally, this crate provides a [`Storage`] type for managing persistent storage, kept in sqlite
+//! by a [`SqliteBackend`] or in Postgres by a [`PostgresBackend`], behind the [`StorageBackend`]
+//! trait.
 
 #![deny(clippy::unwrap_used)]
 #![recursion_limit = "512"]
 // Requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+mod address_book;
 mod client;
+mod event;
+mod invoice;
+mod ledger;
 mod metrics;
 mod note_record;
 mod planner;
@@ -25,12 +32,21 @@ mod sync;
 mod transaction_info;
 mod worker;
 
+pub use crate::address_book::AddressBook;
 pub use crate::client::ViewClient;
+pub use crate::event::{WalletEvent, WalletEventKind};
+pub use crate::invoice::Invoice;
+pub use crate::ledger::{
+    CostBasisMethod, Direction, EntryKind, Ledger, LedgerEntry, LedgerRow, Posting, Prices,
+};
 pub use crate::metrics::register_metrics;
 pub use crate::note_record::SpendableNoteRecord;
-pub use crate::planner::Planner;
-pub use crate::service::ViewServer;
+pub use crate::planner::{NoteSelection, PlanSequence, Planner, DEFAULT_MAX_SPENDS};
+pub use crate::service::{MultiViewServer, ViewServer, WALLET_ID_HEADER};
 pub use crate::status::StatusStreamResponse;
-pub use crate::storage::Storage;
+pub use crate::storage::{
+    Birthday, DatabaseKey, PostgresBackend, SqliteBackend, Storage, StorageBackend,
+};
 pub use crate::swap_record::SwapRecord;
 pub use crate::transaction_info::TransactionInfo;
+pub use crate::worker::{check_detected, DETECTED_ACCOUNTS};
@@ -11,20 +11,40 @@
 //! This trick is probably good to avoid in general, because it could be
 //! confusing, but in this limited case, it seems like a clean option.
 
-#[allow(unused_imports)]
 pub use metrics::*;
 
 /// Registers all metrics used by this crate.
 pub fn register_metrics() {
-    /*
-    // Sample code for reference -- delete when adding the first metric
-    register_counter!(MEMPOOL_CHECKTX_TOTAL);
     describe_counter!(
-        MEMPOOL_CHECKTX_TOTAL,
-        "The total number of checktx requests made to the mempool"
+        VIEW_SYNC_BLOCKS_TOTAL,
+        Unit::Count,
+        "The total number of compact blocks processed by the view worker"
+    );
+    describe_gauge!(
+        VIEW_SYNC_HEIGHT,
+        Unit::Count,
+        "The height of the latest compact block processed by the view worker"
+    );
+    describe_gauge!(
+        VIEW_SYNC_BLOCKS_PER_SECOND,
+        Unit::CountPerSecond,
+        "The rate at which the view worker processed compact blocks over the last report interval"
+    );
+    describe_histogram!(
+        VIEW_SYNC_TRIAL_DECRYPT_DURATION,
+        Unit::Seconds,
+        "The time spent trial-decrypting a compact block for every wallet scanning it"
+    );
+    describe_histogram!(
+        VIEW_SYNC_COMMIT_DURATION,
+        Unit::Seconds,
+        "The time spent scanning a trial-decrypted compact block and committing it to storage"
     );
-     */
 }
 
-// Sample code for reference -- delete when adding the first metric
-// pub const MEMPOOL_CHECKTX_TOTAL: &str = "penumbra_pd_mempool_checktx_total";
+pub const VIEW_SYNC_BLOCKS_TOTAL: &str = "penumbra_view_sync_blocks_total";
+pub const VIEW_SYNC_HEIGHT: &str = "penumbra_view_sync_height";
+pub const VIEW_SYNC_BLOCKS_PER_SECOND: &str = "penumbra_view_sync_blocks_per_second";
+pub const VIEW_SYNC_TRIAL_DECRYPT_DURATION: &str =
+    "penumbra_view_sync_trial_decrypt_duration_seconds";
+pub const VIEW_SYNC_COMMIT_DURATION: &str = "penumbra_view_sync_commit_duration_seconds";
@@ -7,6 +7,7 @@ use penumbra_tct::Position;
 
 use r2d2_sqlite::rusqlite::Row;
 use serde::{Deserialize, Serialize};
+use sqlx::{postgres::PgRow, Row as _};
 
 /// Corresponds to the SpendableNoteRecord proto
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -115,3 +116,45 @@ impl TryFrom<&Row<'_>> for SpendableNoteRecord {
         })
     }
 }
+
+impl TryFrom<&PgRow> for SpendableNoteRecord {
+    type Error = anyhow::Error;
+
+    fn try_from(row: &PgRow) -> Result<Self, Self::Error> {
+        let return_address_bytes = row
+            .try_get::<Option<Vec<u8>>, _>("return_address")
+            // If there's no return_address column, fill in None
+            .ok()
+            .flatten();
+        let return_address = return_address_bytes
+            .map(|b| {
+                // Address is not proto-encoded
+                Address::try_from(b)
+            })
+            .transpose()?
+            .map(|a| AddressView::Opaque { address: a });
+        Ok(SpendableNoteRecord {
+            address_index: row.try_get::<Vec<u8>, _>("address_index")?[..].try_into()?,
+            nullifier: row.try_get::<Vec<u8>, _>("nullifier")?[..].try_into()?,
+            height_created: row.try_get::<i64, _>("height_created")? as u64,
+            height_spent: row
+                .try_get::<Option<i64>, _>("height_spent")?
+                .map(|height| height as u64),
+            position: (row.try_get::<i64, _>("position")? as u64).into(),
+            note_commitment: row.try_get::<Vec<u8>, _>("note_commitment")?[..].try_into()?,
+            note: Note::from_parts(
+                row.try_get::<Vec<u8>, _>("address")?[..].try_into()?,
+                Value {
+                    amount: u128::from_be_bytes(
+                        row.try_get::<Vec<u8>, _>("amount")?[..].try_into()?,
+                    )
+                    .into(),
+                    asset_id: row.try_get::<Vec<u8>, _>("asset_id")?[..].try_into()?,
+                },
+                Rseed(row.try_get::<Vec<u8>, _>("rseed")?[..].try_into()?),
+            )?,
+            source: CommitmentSource::decode(&row.try_get::<Vec<u8>, _>("source")?[..])?,
+            return_address,
+        })
+    }
+}
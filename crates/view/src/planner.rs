@@ -1,7 +1,7 @@
 use std::{
     collections::BTreeMap,
     fmt::{self, Debug, Formatter},
-    mem,
+    mem, vec,
 };
 
 use anyhow::{Context, Result};
@@ -48,6 +48,15 @@ use penumbra_transaction::{
     ActionList, TransactionParameters,
 };
 
+mod note_selection;
+mod sequence;
+
+pub use note_selection::NoteSelection;
+pub use sequence::{PlanSequence, DEFAULT_MAX_SPENDS};
+
+/// The most spends the planner adds to a single transaction before giving up.
+const MAX_PLANNING_ITERATIONS: usize = 100;
+
 /// A planner for a [`TransactionPlan`] that can fill in the required spends and change outputs upon
 /// finalization to make a transaction balance.
 pub struct Planner<R: RngCore + CryptoRng> {
@@ -65,6 +74,8 @@ pub struct Planner<R: RngCore + CryptoRng> {
     memo_text: Option<String>,
     /// A user-specified memo return address, if any.
     memo_return_address: Option<Address>,
+    /// The strategy used to select the notes spent to fund the transaction.
+    note_selection: NoteSelection,
 }
 
 impl<R: RngCore + CryptoRng> Debug for Planner<R> {
@@ -77,6 +88,7 @@ impl<R: RngCore + CryptoRng> Debug for Planner<R> {
             .field("change_address", &self.change_address)
             .field("memo_text", &self.memo_text)
             .field("memo_return_address", &self.memo_return_address)
+            .field("note_selection", &self.note_selection)
             .finish()
     }
 }
@@ -93,6 +105,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             change_address: None,
             memo_text: None,
             memo_return_address: None,
+            note_selection: Default::default(),
         }
     }
 
@@ -154,6 +167,13 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Set the strategy used to select the notes spent to fund the transaction.
+    #[instrument(skip(self))]
+    pub fn note_selection(&mut self, note_selection: NoteSelection) -> &mut Self {
+        self.note_selection = note_selection;
+        self
+    }
+
     /// Spend a specific positioned note in the transaction.
     #[instrument(skip(self))]
     pub fn spend(&mut self, note: Note, position: tct::Position) -> &mut Self {
@@ -479,44 +499,25 @@ impl<R: RngCore + CryptoRng> Planner<R> {
 
     /// Prioritize notes to spend to release value of a specific transaction.
     ///
-    /// Various logic is possible for note selection. Currently, this method
-    /// prioritizes notes sent to a one-time address, then notes with the largest
-    /// value:
-    ///
-    /// - Prioritizing notes sent to one-time addresses optimizes for a future in
-    /// which we implement DAGSync keyed by fuzzy message detection (which will not
-    /// be able to detect notes sent to one-time addresses). Spending these notes
-    /// immediately converts them into change notes, sent to the default address for
-    /// the users' account, which are detectable.
-    ///
-    /// - Prioritizing notes with the largest value optimizes for gas used by the
-    /// transaction.
-    ///
-    /// We may want to make note prioritization configurable in the future. For
-    /// instance, a user might prefer a note prioritization strategy that harvested
-    /// capital losses when possible, using cost basis information retained by the
-    /// view server.
+    /// The notes are returned in the order they should be spent to fund `amount_to_spend`, as
+    /// determined by the [`NoteSelection`] strategy of the planner, which defaults to spending
+    /// notes sent to one-time addresses first, then the largest notes. Notes without value are
+    /// filtered out.
     pub fn prioritize_and_filter_spendable_notes(
         &mut self,
         records: Vec<SpendableNoteRecord>,
+        amount_to_spend: Amount,
     ) -> Vec<SpendableNoteRecord> {
-        let mut filtered = records
-            .into_iter()
-            .filter(|record| record.note.amount() > Amount::zero())
-            .collect::<Vec<_>>();
-        filtered.sort_by(|a, b| {
-            // Sort by whether the note was sent to an ephemeral address...
-            match (
-                a.address_index.is_ephemeral(),
-                b.address_index.is_ephemeral(),
-            ) {
-                (true, false) => std::cmp::Ordering::Less,
-                (false, true) => std::cmp::Ordering::Greater,
-                // ... then by largest amount.
-                _ => b.note.amount().cmp(&a.note.amount()),
-            }
-        });
-        filtered
+        self.note_selection.prioritize(records, amount_to_spend)
+    }
+
+    /// Plan the transaction as a [`PlanSequence`], which splits it into several transactions if
+    /// funding it takes more than `max_spends` spends.
+    ///
+    /// `max_spends` is raised to at least 3, so that every transaction of the sequence makes
+    /// progress.
+    pub fn into_sequence(self, source: AddressIndex, max_spends: usize) -> PlanSequence<R> {
+        PlanSequence::new(self, source, max_spends)
     }
 
     /// Add spends and change outputs as required to balance the transaction, using the view service
@@ -531,6 +532,79 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         // 2. Using one-time addresses for change addresses is undesirable.
         source.randomizer = [0u8; 12];
 
+        let (change_address, notes_by_asset_id) = self.notes_to_spend(view, source).await?;
+        let asset_cache = view.assets().await?;
+        self.spend_until_balanced(
+            notes_by_asset_id,
+            &change_address,
+            &asset_cache,
+            MAX_PLANNING_ITERATIONS,
+        )?;
+
+        // Construct the memo plan for the transaction, using user-specified data if it
+        // was provided.
+        let memo_plan = if self.action_list.requires_memo() {
+            let return_address = if let Some(ref address) = self.memo_return_address {
+                // Check that this address is actually controlled by the user.
+                // We don't have an FVK, so we have to ask the view service.
+                anyhow::ensure!(
+                    view.index_by_address(address.clone()).await?.is_some(),
+                    "return address for memo is not controlled by the user",
+                );
+                address.clone()
+            } else {
+                view.address_by_index(source).await?.clone()
+            };
+
+            Some(MemoPlan::new(
+                &mut self.rng,
+                MemoPlaintext::new(return_address, self.memo_text.take().unwrap_or_default())
+                    .context("could not create memo plaintext")?,
+            ))
+        } else {
+            None
+        };
+
+        // Configure the transaction parameters with the chain ID.
+        let app_params = view.app_params().await?;
+        let chain_id = app_params.chain_id.clone();
+        self.transaction_parameters.chain_id = chain_id.clone();
+
+        // Fetch the FMD parameters that will be used to plan the transaction.
+        // (This really should have been considered witness data. Oh well.)
+        let fmd_params = view.fmd_parameters().await?;
+
+        let plan = mem::take(&mut self.action_list).into_plan(
+            &mut self.rng,
+            &fmd_params,
+            self.transaction_parameters.clone(),
+            memo_plan,
+        )?;
+
+        // Reset the planner in case it were reused. We don't want people to do that
+        // but otherwise we can't do builder method chaining with &mut self, and forcing
+        // the builder to move between calls is annoying for callers who are building up
+        // actions programmatically. Except we can't do a normal std::mem::replace here because
+        // the generic RNG mucks everything up. So it's just awful.
+        self.action_list = Default::default();
+        self.gas_prices = Default::default();
+        self.fee_tier = Default::default();
+        self.transaction_parameters = Default::default();
+        self.change_address = None;
+        self.memo_text = None;
+        self.memo_return_address = None;
+        self.note_selection = Default::default();
+
+        Ok(plan)
+    }
+
+    /// Compute the change address for the transaction, and find the notes of each asset it
+    /// requires in the source account, in the order they should be spent.
+    async fn notes_to_spend<V: ViewClient>(
+        &mut self,
+        view: &mut V,
+        source: AddressIndex,
+    ) -> anyhow::Result<(Address, BTreeMap<asset::Id, Vec<SpendableNoteRecord>>)> {
         // Compute the change address for this transaction.
         let change_address = if let Some(ref address) = self.change_address {
             address.clone()
@@ -571,12 +645,30 @@ impl<R: RngCore + CryptoRng> Planner<R> {
                 .await?;
             notes_by_asset_id.insert(
                 required.asset_id,
-                self.prioritize_and_filter_spendable_notes(records),
+                self.prioritize_and_filter_spendable_notes(records, required.amount),
             );
         }
 
-        let mut iterations = 0usize;
-        let asset_cache = view.assets().await?;
+        Ok((change_address, notes_by_asset_id))
+    }
+
+    /// Add spends of the given notes, in order, until the transaction balances, returning the
+    /// notes spent.
+    ///
+    /// The notes of each required asset must be in the order they should be spent. Planning fails
+    /// if more than `max_spends` spends are needed.
+    fn spend_until_balanced(
+        &mut self,
+        notes_by_asset_id: BTreeMap<asset::Id, Vec<SpendableNoteRecord>>,
+        change_address: &Address,
+        asset_cache: &asset::Cache,
+        max_spends: usize,
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
+        let mut notes_by_asset_id = notes_by_asset_id
+            .into_iter()
+            .map(|(asset_id, notes)| (asset_id, notes.into_iter()))
+            .collect::<BTreeMap<asset::Id, vec::IntoIter<SpendableNoteRecord>>>();
+        let mut spent = Vec::new();
 
         // Now iterate over the action list's imbalances to balance the transaction.
         while let Some(required) = self.action_list.balance_with_fee().required().next() {
@@ -584,17 +676,18 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             let note = notes_by_asset_id
                 .get_mut(&required.asset_id)
                 .expect("we already made a notes request for each required asset")
-                .pop()
+                .next()
                 .ok_or_else(|| {
                     anyhow!(
                         "ran out of notes to spend while planning transaction, need {}",
-                        required.format(&asset_cache)
+                        required.format(asset_cache)
                     )
                 })?;
 
             // Add a spend for that note to the action list.
             self.action_list
-                .push(SpendPlan::new(&mut OsRng, note.note, note.position));
+                .push(SpendPlan::new(&mut OsRng, note.note.clone(), note.position));
+            spent.push(note);
 
             // Refresh the fee estimate and change outputs.
             self.action_list.refresh_fee_and_change(
@@ -603,68 +696,236 @@ impl<R: RngCore + CryptoRng> Planner<R> {
                     .gas_prices
                     .context("planner instances must call set_gas_prices prior to planning")?,
                 &self.fee_tier,
-                &change_address,
+                change_address,
             );
 
-            iterations = iterations + 1;
-            if iterations > 100 {
-                return Err(anyhow!("failed to plan transaction after 100 iterations"));
+            if spent.len() > max_spends {
+                return Err(anyhow!(
+                    "failed to plan transaction after {max_spends} iterations"
+                ));
             }
         }
 
-        // Construct the memo plan for the transaction, using user-specified data if it
-        // was provided.
-        let memo_plan = if self.action_list.requires_memo() {
-            let return_address = if let Some(ref address) = self.memo_return_address {
-                // Check that this address is actually controlled by the user.
-                // We don't have an FVK, so we have to ask the view service.
-                anyhow::ensure!(
-                    view.index_by_address(address.clone()).await?.is_some(),
-                    "return address for memo is not controlled by the user",
-                );
-                address.clone()
-            } else {
-                view.address_by_index(source).await?.clone()
-            };
-
-            Some(MemoPlan::new(
-                &mut self.rng,
-                MemoPlaintext::new(return_address, self.memo_text.take().unwrap_or_default())
-                    .context("could not create memo plaintext")?,
-            ))
-        } else {
-            None
-        };
+        Ok(spent)
+    }
+}
 
-        // Configure the transaction parameters with the chain ID.
-        let app_params = view.app_params().await?;
-        let chain_id = app_params.chain_id.clone();
-        self.transaction_parameters.chain_id = chain_id.clone();
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::STAKING_TOKEN_ASSET_ID;
+    use penumbra_keys::test_keys;
+    use penumbra_sct::{CommitmentSource, Nullifier};
+
+    use super::{note_selection::MAX_DUST_SPENDS, *};
+
+    /// A note of the staking token sent to the test wallet.
+    fn record(amount: u64, address_index: AddressIndex, position: u64) -> SpendableNoteRecord {
+        let fvk = &*test_keys::FULL_VIEWING_KEY;
+        let note = Note::generate(
+            &mut OsRng,
+            &fvk.payment_address(address_index).0,
+            Value {
+                amount: amount.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+        );
+        let note_commitment = note.commit();
+        let position = tct::Position::from(position);
+        SpendableNoteRecord {
+            note_commitment,
+            nullifier: Nullifier::derive(fvk.nullifier_key(), position, &note_commitment),
+            note,
+            address_index,
+            height_created: 0,
+            height_spent: None,
+            position,
+            source: CommitmentSource::Genesis,
+            return_address: None,
+        }
+    }
 
-        // Fetch the FMD parameters that will be used to plan the transaction.
-        // (This really should have been considered witness data. Oh well.)
-        let fmd_params = view.fmd_parameters().await?;
+    fn ephemeral(account: u32) -> AddressIndex {
+        AddressIndex {
+            account,
+            randomizer: [1; 12],
+        }
+    }
 
-        let plan = mem::take(&mut self.action_list).into_plan(
-            &mut self.rng,
-            &fmd_params,
-            self.transaction_parameters.clone(),
-            memo_plan,
+    /// The result of funding an output with some notes.
+    #[derive(Debug, PartialEq)]
+    struct Planned {
+        /// The amounts of the notes spent, in the order they were spent.
+        spent: Vec<u128>,
+        /// The address indices the spent notes were sent to, in the order they were spent.
+        spent_from: Vec<AddressIndex>,
+        /// The number of outputs, including change.
+        outputs: usize,
+    }
+
+    /// Fund an output of `amount` of the staking token with `notes`, selected by `strategy`.
+    fn plan(
+        strategy: NoteSelection,
+        amount: u64,
+        notes: Vec<SpendableNoteRecord>,
+    ) -> anyhow::Result<Planned> {
+        let mut planner = Planner::new(OsRng);
+        planner
+            .set_gas_prices(GasPrices::zero())
+            .note_selection(strategy)
+            .output(
+                Value {
+                    amount: amount.into(),
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                },
+                test_keys::ADDRESS_1.clone(),
+            );
+        let by_address = notes
+            .iter()
+            .map(|record| (record.note_commitment, record.address_index))
+            .collect::<BTreeMap<_, _>>();
+        let notes = planner.prioritize_and_filter_spendable_notes(notes, amount.into());
+        planner.spend_until_balanced(
+            BTreeMap::from([(*STAKING_TOKEN_ASSET_ID, notes)]),
+            &test_keys::ADDRESS_0,
+            &asset::Cache::with_known_assets(),
+            MAX_PLANNING_ITERATIONS,
         )?;
 
-        // Reset the planner in case it were reused. We don't want people to do that
-        // but otherwise we can't do builder method chaining with &mut self, and forcing
-        // the builder to move between calls is annoying for callers who are building up
-        // actions programmatically. Except we can't do a normal std::mem::replace here because
-        // the generic RNG mucks everything up. So it's just awful.
-        self.action_list = Default::default();
-        self.gas_prices = Default::default();
-        self.fee_tier = Default::default();
-        self.transaction_parameters = Default::default();
-        self.change_address = None;
-        self.memo_text = None;
-        self.memo_return_address = None;
-
-        Ok(plan)
+        let spends = planner
+            .action_list
+            .actions()
+            .iter()
+            .filter_map(|action| match action {
+                ActionPlan::Spend(spend) => Some(&spend.note),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let outputs = planner
+            .action_list
+            .actions()
+            .iter()
+            .filter(|action| matches!(action, ActionPlan::Output(_)))
+            .count()
+            + planner.action_list.change_outputs().len();
+
+        Ok(Planned {
+            spent: spends.iter().map(|note| note.amount().value()).collect(),
+            spent_from: spends
+                .iter()
+                .map(|note| by_address[&note.commit()])
+                .collect(),
+            outputs,
+        })
+    }
+
+    #[test]
+    fn default_selection_spends_one_time_addresses_first() -> anyhow::Result<()> {
+        let notes = vec![
+            record(100, 0.into(), 0),
+            record(10, ephemeral(0), 1),
+            record(60, 0.into(), 2),
+        ];
+        let planned = plan(NoteSelection::default(), 100, notes)?;
+        assert_eq!(planned.spent, vec![10, 100]);
+        assert_eq!(planned.spent_from, vec![ephemeral(0), 0.into()]);
+        // The output, and change of 10.
+        assert_eq!(planned.outputs, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn fewest_spends_spends_largest_notes_first() -> anyhow::Result<()> {
+        let notes = vec![
+            record(30, 0.into(), 0),
+            record(100, 0.into(), 1),
+            record(5, ephemeral(0), 2),
+            record(60, 0.into(), 3),
+        ];
+        let planned = plan(NoteSelection::FewestSpends, 150, notes)?;
+        assert_eq!(planned.spent, vec![100, 60]);
+        assert_eq!(planned.outputs, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn consolidate_dust_spends_smallest_notes_first() -> anyhow::Result<()> {
+        let notes = vec![
+            record(100, 0.into(), 0),
+            record(3, 0.into(), 1),
+            record(1, 0.into(), 2),
+            record(40, 0.into(), 3),
+            record(2, 0.into(), 4),
+        ];
+        let planned = plan(NoteSelection::ConsolidateDust, 50, notes)?;
+        // Only the notes worth less than a tenth of the payment are dust, so they're spent
+        // smallest first, and then the largest note, rather than the note of 40 as well.
+        assert_eq!(planned.spent, vec![1, 2, 3, 100]);
+        assert_eq!(planned.outputs, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn consolidate_dust_is_bounded() -> anyhow::Result<()> {
+        let mut notes = (0..2 * MAX_DUST_SPENDS as u64)
+            .map(|position| record(1, 0.into(), position))
+            .collect::<Vec<_>>();
+        notes.push(record(1000, 0.into(), 100));
+        let planned = plan(NoteSelection::ConsolidateDust, 100, notes)?;
+        // Once the most dust has been spent, the largest note is spent next.
+        let mut expected = vec![1; MAX_DUST_SPENDS];
+        expected.push(1000);
+        assert_eq!(planned.spent, expected);
+        assert_eq!(planned.outputs, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn single_address_avoids_mixing_address_indices() -> anyhow::Result<()> {
+        let notes = vec![
+            record(60, 0.into(), 0),
+            record(70, ephemeral(0), 1),
+            record(10, 0.into(), 2),
+            record(60, 0.into(), 3),
+        ];
+
+        // Spending the largest notes first would link the two addresses...
+        let planned = plan(NoteSelection::FewestSpends, 100, notes.clone())?;
+        assert_eq!(planned.spent_from, vec![ephemeral(0), 0.into()]);
+
+        // ... which spending from the address holding the most doesn't.
+        let planned = plan(NoteSelection::SingleAddress, 100, notes.clone())?;
+        assert_eq!(planned.spent, vec![60, 60]);
+        assert_eq!(planned.spent_from, vec![0.into(), 0.into()]);
+        assert_eq!(planned.outputs, 2);
+
+        // The other addresses are only spent from when it's not enough.
+        let planned = plan(NoteSelection::SingleAddress, 150, notes)?;
+        assert_eq!(planned.spent, vec![60, 60, 10, 70]);
+        assert_eq!(planned.outputs, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn largest_anonymity_set_spends_oldest_notes_first() -> anyhow::Result<()> {
+        let notes = vec![
+            record(100, 0.into(), 5),
+            record(20, 0.into(), 1),
+            record(50, 0.into(), 3),
+        ];
+        let planned = plan(NoteSelection::LargestAnonymitySet, 60, notes)?;
+        assert_eq!(planned.spent, vec![20, 50]);
+        assert_eq!(planned.outputs, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn notes_without_value_are_never_spent() -> anyhow::Result<()> {
+        let notes = vec![record(0, 0.into(), 0), record(10, 0.into(), 1)];
+        let planned = plan(NoteSelection::ConsolidateDust, 10, notes.clone())?;
+        assert_eq!(planned.spent, vec![10]);
+        // Spending exactly the amount of the output leaves no change.
+        assert_eq!(planned.outputs, 1);
+        assert!(plan(NoteSelection::ConsolidateDust, 20, notes).is_err());
+        Ok(())
     }
 }
@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+
+use penumbra_keys::keys::AddressIndex;
+use penumbra_num::Amount;
+use penumbra_proto::view::v1::transaction_planner_request as pb;
+
+use crate::SpendableNoteRecord;
+
+/// The most notes the [`NoteSelection::ConsolidateDust`] strategy spends ahead of larger ones,
+/// which keeps the transaction from growing without bound when a wallet holds a lot of dust.
+const MAX_DUST_SPENDS: usize = 16;
+
+/// The [`NoteSelection::ConsolidateDust`] strategy counts a note as dust if it's worth less than
+/// this fraction of the amount being spent.
+const DUST_FRACTION: u128 = 10;
+
+/// A strategy for selecting the notes the [`Planner`](super::Planner) spends to fund a transaction.
+///
+/// Each strategy orders the spendable notes of one asset, and the planner spends them in that
+/// order until the transaction balances.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NoteSelection {
+    /// Spend notes sent to one-time addresses first, then the largest notes.
+    ///
+    /// Notes sent to one-time addresses won't be detectable by a future DAGSync keyed by fuzzy
+    /// message detection, so spending them first converts them into change notes sent to the
+    /// account's default address, which are.
+    #[default]
+    OneTimeAddressesFirst,
+    /// Spend as few notes as possible, by spending the largest notes first, which minimizes the
+    /// gas used by the transaction.
+    FewestSpends,
+    /// Spend up to 16 dust notes first, smallest first, consolidating them into change, and then
+    /// the largest notes.
+    ///
+    /// A note is dust if it's worth less than a tenth of the amount being spent, so that larger
+    /// notes aren't spent when they aren't needed.
+    ConsolidateDust,
+    /// Spend notes sent to the same address index where possible, so that the transaction doesn't
+    /// link the wallet's addresses to each other.
+    ///
+    /// The address index holding the most of the asset is spent from first, largest notes
+    /// first; the others are only spent from if it runs out.
+    SingleAddress,
+    /// Spend the oldest notes first, since the most notes have been created after them to hide
+    /// among.
+    LargestAnonymitySet,
+}
+
+impl NoteSelection {
+    /// Order the notes of a single asset in the order they should be spent to fund
+    /// `amount_to_spend`, leaving out any notes without value.
+    pub fn prioritize(
+        &self,
+        records: Vec<SpendableNoteRecord>,
+        amount_to_spend: Amount,
+    ) -> Vec<SpendableNoteRecord> {
+        let mut records = records
+            .into_iter()
+            .filter(|record| record.note.amount() > Amount::zero())
+            .collect::<Vec<_>>();
+
+        match self {
+            NoteSelection::OneTimeAddressesFirst => {
+                records.sort_by(|a, b| {
+                    // Sort by whether the note was sent to an ephemeral address...
+                    b.address_index
+                        .is_ephemeral()
+                        .cmp(&a.address_index.is_ephemeral())
+                        // ... then by largest amount.
+                        .then_with(|| b.note.amount().cmp(&a.note.amount()))
+                });
+            }
+            NoteSelection::FewestSpends => {
+                records.sort_by_key(|record| std::cmp::Reverse(record.note.amount()));
+            }
+            NoteSelection::ConsolidateDust => {
+                records.sort_by_key(|record| record.note.amount());
+                let dust = records
+                    .iter()
+                    .take(MAX_DUST_SPENDS)
+                    .take_while(|record| {
+                        u128::from(record.note.amount()).saturating_mul(DUST_FRACTION)
+                            < u128::from(amount_to_spend)
+                    })
+                    .count();
+                records[dust..].reverse();
+            }
+            NoteSelection::SingleAddress => {
+                let mut by_address = BTreeMap::<AddressIndex, Vec<SpendableNoteRecord>>::new();
+                for record in records.drain(..) {
+                    by_address
+                        .entry(record.address_index)
+                        .or_default()
+                        .push(record);
+                }
+                let mut groups = by_address.into_values().collect::<Vec<_>>();
+                for group in groups.iter_mut() {
+                    group.sort_by_key(|record| std::cmp::Reverse(record.note.amount()));
+                }
+                groups.sort_by_key(|group| {
+                    std::cmp::Reverse(group.iter().fold(0u128, |total, record| {
+                        total.saturating_add(record.note.amount().into())
+                    }))
+                });
+                records = groups.into_iter().flatten().collect();
+            }
+            NoteSelection::LargestAnonymitySet => {
+                records.sort_by_key(|record| record.position);
+            }
+        }
+
+        records
+    }
+}
+
+impl From<pb::NoteSelection> for NoteSelection {
+    fn from(value: pb::NoteSelection) -> Self {
+        match value {
+            pb::NoteSelection::Unspecified => NoteSelection::OneTimeAddressesFirst,
+            pb::NoteSelection::FewestSpends => NoteSelection::FewestSpends,
+            pb::NoteSelection::ConsolidateDust => NoteSelection::ConsolidateDust,
+            pb::NoteSelection::SingleAddress => NoteSelection::SingleAddress,
+            pb::NoteSelection::LargestAnonymitySet => NoteSelection::LargestAnonymitySet,
+        }
+    }
+}
+
+impl From<NoteSelection> for pb::NoteSelection {
+    fn from(value: NoteSelection) -> Self {
+        match value {
+            NoteSelection::OneTimeAddressesFirst => pb::NoteSelection::Unspecified,
+            NoteSelection::FewestSpends => pb::NoteSelection::FewestSpends,
+            NoteSelection::ConsolidateDust => pb::NoteSelection::ConsolidateDust,
+            NoteSelection::SingleAddress => pb::NoteSelection::SingleAddress,
+            NoteSelection::LargestAnonymitySet => pb::NoteSelection::LargestAnonymitySet,
+        }
+    }
+}
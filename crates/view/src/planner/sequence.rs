@@ -0,0 +1,495 @@
+use std::collections::BTreeMap;
+
+use penumbra_asset::asset;
+use penumbra_keys::keys::AddressIndex;
+use penumbra_transaction::TransactionPlan;
+use rand::{CryptoRng, RngCore};
+
+use super::Planner;
+use crate::{SpendableNoteRecord, ViewClient};
+
+/// The most notes spent by each transaction of a [`PlanSequence`] by default, which keeps each
+/// transaction well under the chain's maximum transaction size.
+pub const DEFAULT_MAX_SPENDS: usize = 64;
+
+/// A sequence of transactions which together carry out the actions of a [`Planner`], when funding
+/// them takes more spends than fit in one transaction.
+///
+/// Each transaction but the last consolidates notes that the final transaction would otherwise
+/// spend into a single change note, which the transactions after it spend in their place. Since a
+/// note can only be spent once the transaction creating it is confirmed, each transaction is only
+/// planned once the ones before it have been detected by the view service.
+pub struct PlanSequence<R: RngCore + CryptoRng> {
+    planner: Planner<R>,
+    source: AddressIndex,
+    max_spends: usize,
+    finished: bool,
+}
+
+impl<R: RngCore + CryptoRng> PlanSequence<R> {
+    pub(super) fn new(planner: Planner<R>, mut source: AddressIndex, max_spends: usize) -> Self {
+        // Change is always sent to the account's default address, as in `Planner::plan`.
+        source.randomizer = [0u8; 12];
+        Self {
+            planner,
+            source,
+            // A consolidation spends a note for its fee as well as the notes it consolidates, so
+            // with fewer spends than this it may not reduce the number of notes.
+            max_spends: max_spends.max(3),
+            finished: false,
+        }
+    }
+
+    /// Whether the final transaction of the sequence, carrying out the planned actions, has been
+    /// planned.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Plan the next transaction of the sequence, returning `None` once the final transaction has
+    /// been planned.
+    ///
+    /// The transactions planned before this one must have been confirmed, and detected by `view`,
+    /// since it spends their change.
+    pub async fn next_plan<V: ViewClient>(
+        &mut self,
+        view: &mut V,
+    ) -> anyhow::Result<Option<TransactionPlan>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        // Find the notes the final transaction would spend, without planning it yet.
+        let action_list = self.planner.action_list.clone();
+        let (change_address, notes_by_asset_id) =
+            self.planner.notes_to_spend(view, self.source).await?;
+        let asset_cache = view.assets().await?;
+        let spent = self.planner.spend_until_balanced(
+            notes_by_asset_id,
+            &change_address,
+            &asset_cache,
+            usize::MAX,
+        );
+        self.planner.action_list = action_list;
+        let spent = spent?;
+
+        if spent.len() <= self.max_spends {
+            let plan = self.planner.plan(view, self.source).await?;
+            self.finished = true;
+            return Ok(Some(plan));
+        }
+
+        // Otherwise, consolidate as many notes of the asset needing the most spends as fit in one
+        // transaction, leaving room for a spend to pay its fee.
+        let mut spent_by_asset_id = BTreeMap::<asset::Id, Vec<SpendableNoteRecord>>::new();
+        for record in spent {
+            spent_by_asset_id
+                .entry(record.note.asset_id())
+                .or_default()
+                .push(record);
+        }
+        let notes = spent_by_asset_id
+            .into_values()
+            .max_by_key(Vec::len)
+            .unwrap_or_default();
+        tracing::debug!(
+            total = notes.len(),
+            max_spends = self.max_spends,
+            "consolidating notes before planning the final transaction"
+        );
+
+        let mut consolidation = Planner::new(&mut self.planner.rng);
+        consolidation
+            .set_fee_tier(self.planner.fee_tier)
+            .note_selection(self.planner.note_selection)
+            .expiry_height(self.planner.transaction_parameters.expiry_height);
+        if let Some(gas_prices) = self.planner.gas_prices {
+            consolidation.set_gas_prices(gas_prices);
+        }
+        for record in notes.into_iter().take(self.max_spends - 1) {
+            consolidation.spend(record.note, record.position);
+        }
+
+        Ok(Some(consolidation.plan(view, self.source).await?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{future::Future, pin::Pin};
+
+    use anyhow::{anyhow, Result};
+    use futures::{FutureExt, Stream};
+    use pbjson_types::Any;
+    use penumbra_app::params::AppParameters;
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_auction::auction::AuctionId;
+    use penumbra_dex::{
+        lp::position::{self, Position},
+        BatchSwapOutputData, TradingPair,
+    };
+    use penumbra_fee::GasPrices;
+    use penumbra_keys::{test_keys, Address, PaymentRequest};
+    use penumbra_num::Amount;
+    use penumbra_proto::view::v1 as pb;
+    use penumbra_sct::{CommitmentSource, Nullifier};
+    use penumbra_shielded_pool::{fmd, note, Note};
+    use penumbra_stake::IdentityKey;
+    use penumbra_tct as tct;
+    use penumbra_transaction::{
+        txhash::TransactionId, AuthorizationData, Transaction, WitnessData,
+    };
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{
+        client::BroadcastStatusStream, AddressBook, Invoice, StatusStreamResponse, SwapRecord,
+        TransactionInfo, WalletEvent,
+    };
+
+    const MAX_SPENDS: usize = 4;
+
+    type Response<T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'static>>;
+    type ResponseStream<T> = Response<Pin<Box<dyn Stream<Item = Result<T>> + Send + 'static>>>;
+
+    fn not_supported<T: Send + 'static>() -> Response<T> {
+        async { Err(anyhow!("not supported")) }.boxed()
+    }
+
+    /// A view client for the test wallet, [`test_keys::FULL_VIEWING_KEY`], holding a fixed set of
+    /// notes.
+    ///
+    /// Only the queries made while planning are answered; any other query fails.
+    #[derive(Clone, Default)]
+    struct PlanningViewClient {
+        notes: Vec<SpendableNoteRecord>,
+    }
+
+    impl ViewClient for PlanningViewClient {
+        fn app_params(&mut self) -> Response<AppParameters> {
+            async { Ok(AppParameters::default()) }.boxed()
+        }
+
+        fn fmd_parameters(&mut self) -> Response<fmd::Parameters> {
+            async { Ok(fmd::Parameters::default()) }.boxed()
+        }
+
+        fn notes(&mut self, request: pb::NotesRequest) -> Response<Vec<SpendableNoteRecord>> {
+            let notes = self.notes.clone();
+            async move {
+                let asset_id = request.asset_id.map(asset::Id::try_from).transpose()?;
+                let address_index = request
+                    .address_index
+                    .map(AddressIndex::try_from)
+                    .transpose()?;
+                Ok(notes
+                    .into_iter()
+                    .filter(|record| {
+                        (request.include_spent || record.height_spent.is_none())
+                            && (asset_id.is_none() || asset_id == Some(record.note.asset_id()))
+                            && (address_index.is_none()
+                                || address_index.map(|index| index.account)
+                                    == Some(record.address_index.account))
+                    })
+                    .collect())
+            }
+            .boxed()
+        }
+
+        fn assets(&mut self) -> Response<asset::Cache> {
+            async { Ok(asset::Cache::with_known_assets()) }.boxed()
+        }
+
+        fn address_by_index(&mut self, address_index: AddressIndex) -> Response<Address> {
+            let address = test_keys::FULL_VIEWING_KEY.payment_address(address_index).0;
+            async move { Ok(address) }.boxed()
+        }
+
+        fn auctions(
+            &mut self,
+            _account_filter: Option<AddressIndex>,
+            _include_inactive: bool,
+            _query_latest_state: bool,
+        ) -> Response<
+            Vec<(
+                AuctionId,
+                SpendableNoteRecord,
+                u64,
+                Option<Any>,
+                Vec<Position>,
+            )>,
+        > {
+            not_supported()
+        }
+
+        fn status(&mut self) -> Response<pb::StatusResponse> {
+            not_supported()
+        }
+
+        fn status_stream(&mut self) -> ResponseStream<StatusStreamResponse> {
+            not_supported()
+        }
+
+        fn wallet_events(&mut self) -> ResponseStream<WalletEvent> {
+            not_supported()
+        }
+
+        fn gas_prices(&mut self) -> Response<GasPrices> {
+            not_supported()
+        }
+
+        fn notes_for_voting(
+            &mut self,
+            _request: pb::NotesForVotingRequest,
+        ) -> Response<Vec<(SpendableNoteRecord, IdentityKey)>> {
+            not_supported()
+        }
+
+        fn balances(
+            &mut self,
+            _address_index: AddressIndex,
+            _asset_id: Option<asset::Id>,
+        ) -> Response<Vec<(asset::Id, Amount)>> {
+            not_supported()
+        }
+
+        fn note_by_commitment(
+            &mut self,
+            _note_commitment: note::StateCommitment,
+        ) -> Response<SpendableNoteRecord> {
+            not_supported()
+        }
+
+        fn swap_by_commitment(
+            &mut self,
+            _swap_commitment: tct::StateCommitment,
+        ) -> Response<SwapRecord> {
+            not_supported()
+        }
+
+        fn nullifier_status(&mut self, _nullifier: Nullifier) -> Response<bool> {
+            not_supported()
+        }
+
+        fn await_nullifier(&mut self, _nullifier: Nullifier) -> Response<()> {
+            not_supported()
+        }
+
+        fn await_note_by_commitment(
+            &mut self,
+            _note_commitment: note::StateCommitment,
+        ) -> Response<SpendableNoteRecord> {
+            not_supported()
+        }
+
+        fn witness(&mut self, _plan: &TransactionPlan) -> Response<WitnessData> {
+            not_supported()
+        }
+
+        fn witness_and_build(
+            &mut self,
+            _plan: TransactionPlan,
+            _auth_data: AuthorizationData,
+        ) -> Response<Transaction> {
+            not_supported()
+        }
+
+        fn owned_position_ids(
+            &mut self,
+            _position_state: Option<position::State>,
+            _trading_pair: Option<TradingPair>,
+        ) -> Response<Vec<position::Id>> {
+            not_supported()
+        }
+
+        fn transaction_info_by_hash(&mut self, _id: TransactionId) -> Response<TransactionInfo> {
+            not_supported()
+        }
+
+        fn transaction_info(
+            &mut self,
+            _start_height: Option<u64>,
+            _end_height: Option<u64>,
+        ) -> Response<Vec<TransactionInfo>> {
+            not_supported()
+        }
+
+        fn batch_swap_outputs(
+            &mut self,
+            _start_height: Option<u64>,
+            _end_height: Option<u64>,
+        ) -> Response<Vec<BatchSwapOutputData>> {
+            not_supported()
+        }
+
+        fn address_book(&mut self) -> Response<AddressBook> {
+            not_supported()
+        }
+
+        fn set_contact(&mut self, _address: Address, _label: String) -> Response<()> {
+            not_supported()
+        }
+
+        fn delete_contact(&mut self, _address: Address) -> Response<bool> {
+            not_supported()
+        }
+
+        fn set_account_label(&mut self, _account: u32, _label: String) -> Response<()> {
+            not_supported()
+        }
+
+        fn delete_account_label(&mut self, _account: u32) -> Response<bool> {
+            not_supported()
+        }
+
+        fn create_invoice(
+            &mut self,
+            _request: pb::CreateInvoiceRequest,
+        ) -> Response<PaymentRequest> {
+            not_supported()
+        }
+
+        fn invoices(&mut self, _include_paid: bool) -> Response<Vec<Invoice>> {
+            not_supported()
+        }
+
+        fn delete_invoice(&mut self, _invoice_id: String) -> Response<bool> {
+            not_supported()
+        }
+
+        fn broadcast_transaction(
+            &mut self,
+            _transaction: Transaction,
+            _await_detection: bool,
+        ) -> BroadcastStatusStream {
+            not_supported()
+        }
+
+        fn index_by_address(&mut self, _address: Address) -> Response<Option<AddressIndex>> {
+            not_supported()
+        }
+
+        fn unclaimed_swaps(&mut self) -> Response<Vec<SwapRecord>> {
+            not_supported()
+        }
+    }
+
+    fn staking(amount: u64) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+    }
+
+    /// Record a note sent to the test wallet.
+    fn receive(view: &mut PlanningViewClient, note: Note) {
+        let fvk = &*test_keys::FULL_VIEWING_KEY;
+        let note_commitment = note.commit();
+        let position = tct::Position::from(view.notes.len() as u64);
+        view.notes.push(SpendableNoteRecord {
+            note_commitment,
+            nullifier: Nullifier::derive(fvk.nullifier_key(), position, &note_commitment),
+            address_index: fvk
+                .address_index(&note.address())
+                .expect("note is sent to the test wallet"),
+            note,
+            height_created: 0,
+            height_spent: None,
+            position,
+            source: CommitmentSource::Genesis,
+            return_address: None,
+        });
+    }
+
+    /// Update the view as if the planned transaction had been confirmed.
+    fn confirm(view: &mut PlanningViewClient, plan: &TransactionPlan) {
+        for spend in plan.spend_plans() {
+            let record = view
+                .notes
+                .iter_mut()
+                .find(|record| record.note_commitment == spend.note.commit())
+                .expect("plan spends a note in the wallet");
+            assert!(record.height_spent.is_none(), "note is spent twice");
+            record.height_spent = Some(1);
+        }
+        for output in plan.output_plans() {
+            if test_keys::FULL_VIEWING_KEY
+                .address_index(&output.dest_address)
+                .is_some()
+            {
+                receive(view, output.output_note());
+            }
+        }
+    }
+
+    /// A view holding `count` notes of `amount` each.
+    fn wallet(count: usize, amount: u64) -> PlanningViewClient {
+        let mut view = PlanningViewClient::default();
+        for _ in 0..count {
+            receive(
+                &mut view,
+                Note::generate(&mut OsRng, &test_keys::ADDRESS_0, staking(amount)),
+            );
+        }
+        view
+    }
+
+    fn send(amount: u64) -> PlanSequence<OsRng> {
+        let mut planner = Planner::new(OsRng);
+        planner
+            .set_gas_prices(GasPrices::zero())
+            .output(staking(amount), test_keys::ADDRESS_1.clone());
+        planner.into_sequence(AddressIndex::new(0), MAX_SPENDS)
+    }
+
+    fn pays(plan: &TransactionPlan, amount: u64) -> bool {
+        plan.output_plans().any(|output| {
+            output.dest_address == *test_keys::ADDRESS_1 && output.value == staking(amount)
+        })
+    }
+
+    #[tokio::test]
+    async fn sends_within_max_spends_are_planned_at_once() -> anyhow::Result<()> {
+        let mut view = wallet(MAX_SPENDS, 10);
+        let mut sequence = send(35);
+
+        let plan = sequence
+            .next_plan(&mut view)
+            .await?
+            .expect("the send is planned");
+        assert!(sequence.is_finished());
+        assert!(pays(&plan, 35));
+        assert_eq!(plan.spend_plans().count(), MAX_SPENDS);
+        assert!(sequence.next_plan(&mut view).await?.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sends_needing_more_spends_consolidate_first() -> anyhow::Result<()> {
+        let mut view = wallet(10, 10);
+        let mut sequence = send(95);
+
+        let mut consolidations = 0;
+        let plan = loop {
+            let plan = sequence
+                .next_plan(&mut view)
+                .await?
+                .expect("the sequence ends with the send");
+            assert!(plan.spend_plans().count() <= MAX_SPENDS);
+            confirm(&mut view, &plan);
+            if sequence.is_finished() {
+                break plan;
+            }
+            // Consolidations only send change back to the wallet.
+            assert!(plan
+                .output_plans()
+                .all(|output| output.dest_address == *test_keys::ADDRESS_0));
+            consolidations += 1;
+            assert!(consolidations < 10, "the sequence should terminate");
+        };
+        assert!(consolidations > 0);
+        assert!(pays(&plan, 95));
+        assert!(sequence.next_plan(&mut view).await?.is_none());
+        Ok(())
+    }
+}
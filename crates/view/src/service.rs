@@ -15,7 +15,7 @@ use penumbra_auction::auction::dutch::actions::view::{
 use rand::Rng;
 use rand_core::OsRng;
 use tap::{Tap, TapFallible};
-use tokio::sync::{watch, RwLock};
+use tokio::sync::{broadcast::error::RecvError, watch, RwLock};
 use tokio_stream::wrappers::WatchStream;
 use tonic::{async_trait, transport::Channel, Request, Response, Status};
 use tracing::{instrument, Instrument};
@@ -34,7 +34,7 @@ use penumbra_fee::Fee;
 use penumbra_keys::{
     keys::WalletId,
     keys::{AddressIndex, FullViewingKey},
-    Address, AddressView,
+    Address, AddressView, PaymentRequest,
 };
 use penumbra_num::Amount;
 use penumbra_proto::{
@@ -60,7 +60,14 @@ use penumbra_transaction::{
     AuthorizationData, Transaction, TransactionPerspective, TransactionPlan, WitnessData,
 };
 
-use crate::{worker::Worker, Planner, Storage};
+use crate::{
+    worker::{WalletSync, Worker},
+    DatabaseKey, Planner, Storage,
+};
+
+mod multi;
+
+pub use multi::{MultiViewServer, WALLET_ID_HEADER};
 
 /// A [`futures::Stream`] of broadcast transaction responses.
 ///
@@ -89,17 +96,23 @@ pub struct ViewServer {
     node: Url,
     /// Used to watch for changes to the sync height.
     sync_height_rx: watch::Receiver<u64>,
+    /// Whether the worker synchronizes through a detection service, which limits the addresses
+    /// that can be handed out.
+    detection: bool,
 }
 
 impl ViewServer {
-    /// Convenience method that calls [`Storage::load_or_initialize`] and then [`Self::new`].
+    /// Convenience method that calls [`Storage::load_or_initialize`] and then [`Self::new`], or
+    /// [`Self::with_detection_service`] if a `detection` service is given.
     pub async fn load_or_initialize(
         storage_path: Option<impl AsRef<Utf8Path>>,
+        key: Option<DatabaseKey>,
         registry_path: Option<impl AsRef<Utf8Path>>,
         fvk: &FullViewingKey,
         node: Url,
+        detection: Option<Url>,
     ) -> anyhow::Result<Self> {
-        let storage = Storage::load_or_initialize(storage_path, fvk, node.clone())
+        let storage = Storage::load_or_initialize(storage_path, key, fvk, node.clone())
             .tap(|_| tracing::trace!("loading or initializing storage"))
             .await?
             .tap(|_| tracing::debug!("storage is ready"));
@@ -108,10 +121,12 @@ impl ViewServer {
             storage.load_asset_metadata(registry_path).await?;
         }
 
-        Self::new(storage, node)
-            .tap(|_| tracing::trace!("constructing view server"))
-            .await
-            .tap(|_| tracing::debug!("constructed view server"))
+        tracing::trace!("constructing view server");
+        match detection {
+            Some(detection) => Self::with_detection_service(storage, node, detection).await,
+            None => Self::new(storage, node).await,
+        }
+        .tap(|_| tracing::debug!("constructed view server"))
     }
 
     /// Constructs a new [`ViewService`], spawning a sync task internally.
@@ -122,6 +137,39 @@ impl ViewServer {
     /// by this method, rather than calling it multiple times.  That way, each clone
     /// will be backed by the same scanning task, rather than each spawning its own.
     pub async fn new(storage: Storage, node: Url) -> anyhow::Result<Self> {
+        Self::spawn(vec![storage], node, None)
+            .await?
+            .pop()
+            .ok_or_else(|| anyhow!("no view server was constructed"))
+    }
+
+    /// Constructs a new [`ViewService`] like [`Self::new`], whose sync task fetches compact blocks
+    /// from the `detection` service rather than the node.
+    ///
+    /// The detection service only sends the payloads of the notes it detects as possibly belonging
+    /// to the wallet, and just the roots and nullifiers of blocks where it detects none, so the
+    /// sync task fetches and trial-decrypts far fewer notes. Notes sent to one-time addresses, or to accounts after the first
+    /// [`DETECTED_ACCOUNTS`](crate::DETECTED_ACCOUNTS), are missed, so the server refuses to
+    /// hand out those addresses, and fails to start if the wallet already holds notes sent to
+    /// them.
+    pub async fn with_detection_service(
+        storage: Storage,
+        node: Url,
+        detection: Url,
+    ) -> anyhow::Result<Self> {
+        Self::spawn(vec![storage], node, Some(detection))
+            .await?
+            .pop()
+            .ok_or_else(|| anyhow!("no view server was constructed"))
+    }
+
+    /// Constructs a [`ViewServer`] for each of the wallets in `storages`, spawning a single sync
+    /// task which synchronizes all of them.
+    async fn spawn(
+        storages: Vec<Storage>,
+        node: Url,
+        detection: Option<Url>,
+    ) -> anyhow::Result<Vec<Self>> {
         let span = tracing::error_span!(parent: None, "view");
         let channel = Channel::from_shared(node.to_string())
             .with_context(|| "could not parse node URI")?
@@ -130,24 +178,48 @@ impl ViewServer {
             .await
             .with_context(|| "could not connect to grpc server")
             .tap_err(|error| tracing::error!(?error, "could not connect to grpc server"))?;
+        let has_detection = detection.is_some();
+        let detection = match detection {
+            Some(detection) => Some(
+                Channel::from_shared(detection.to_string())
+                    .with_context(|| "could not parse detection service URI")?
+                    .connect()
+                    .instrument(span.clone())
+                    .await
+                    .with_context(|| "could not connect to detection service")?,
+            ),
+            None => None,
+        };
 
-        let (worker, state_commitment_tree, error_slot, sync_height_rx) =
-            Worker::new(storage.clone(), channel)
-                .instrument(span.clone())
-                .tap(|_| tracing::trace!("constructing view server worker"))
-                .await?
-                .tap(|_| tracing::debug!("constructed view server worker"));
+        let (worker, syncs, error_slot) = Worker::new(storages.clone(), channel, detection)
+            .instrument(span.clone())
+            .tap(|_| tracing::trace!("constructing view server worker"))
+            .await?
+            .tap(|_| tracing::debug!("constructed view server worker"));
 
         tokio::spawn(worker.run().instrument(span))
             .tap(|_| tracing::debug!("spawned view server worker"));
 
-        Ok(Self {
-            storage,
-            error_slot,
-            sync_height_rx,
-            state_commitment_tree,
-            node,
-        })
+        Ok(storages
+            .into_iter()
+            .zip(syncs)
+            .map(
+                |(
+                    storage,
+                    WalletSync {
+                        sct: state_commitment_tree,
+                        sync_height_rx,
+                    },
+                )| Self {
+                    storage,
+                    error_slot: error_slot.clone(),
+                    sync_height_rx,
+                    state_commitment_tree,
+                    node: node.clone(),
+                    detection: has_detection,
+                },
+            )
+            .collect())
     }
 
     /// Checks if the view server worker has encountered an error.
@@ -180,6 +252,16 @@ impl ViewServer {
         Ok(()).tap(|_| tracing::trace!("view server worker is healthy"))
     }
 
+    /// Checks that the notes sent to an address of the given account will be found, which they
+    /// might not be if the worker synchronizes through a detection service.
+    fn check_detected(&self, account: u32, ephemeral: bool) -> Result<(), tonic::Status> {
+        if self.detection {
+            crate::check_detected(account, ephemeral)
+                .map_err(|e| tonic::Status::failed_precondition(format!("{e:#}")))?;
+        }
+        Ok(())
+    }
+
     #[instrument(skip(self, transaction), fields(id = %transaction.id()))]
     fn broadcast_transaction(
         &self,
@@ -403,6 +485,19 @@ impl ViewService for ViewServer {
     >;
     type AuctionsStream =
         Pin<Box<dyn futures::Stream<Item = Result<pb::AuctionsResponse, tonic::Status>> + Send>>;
+    type WalletEventsStream = Pin<
+        Box<dyn futures::Stream<Item = Result<pb::WalletEventsResponse, tonic::Status>> + Send>,
+    >;
+    type BatchSwapOutputsStream = Pin<
+        Box<dyn futures::Stream<Item = Result<pb::BatchSwapOutputsResponse, tonic::Status>> + Send>,
+    >;
+    type ContactsStream =
+        Pin<Box<dyn futures::Stream<Item = Result<pb::ContactsResponse, tonic::Status>> + Send>>;
+    type AccountLabelsStream = Pin<
+        Box<dyn futures::Stream<Item = Result<pb::AccountLabelsResponse, tonic::Status>> + Send>,
+    >;
+    type InvoicesStream =
+        Pin<Box<dyn futures::Stream<Item = Result<pb::InvoicesResponse, tonic::Status>> + Send>>;
 
     #[instrument(skip_all, level = "trace")]
     async fn auctions(
@@ -519,6 +614,7 @@ impl ViewService for ViewServer {
         let mut planner = Planner::new(OsRng);
         planner.set_gas_prices(gas_prices);
         planner.expiry_height(prq.expiry_height);
+        planner.note_selection(prq.note_selection().into());
 
         for output in prq.outputs {
             let address: Address = output
@@ -778,7 +874,7 @@ impl ViewService for ViewServer {
                 tonic::Status::failed_precondition("Error retrieving full viewing key")
             })?;
 
-        let address_index = request
+        let address_index: AddressIndex = request
             .into_inner()
             .address_index
             .ok_or_else(|| tonic::Status::invalid_argument("Missing address index"))?
@@ -786,6 +882,7 @@ impl ViewService for ViewServer {
             .map_err(|e| {
                 tonic::Status::invalid_argument(format!("Could not parse address index: {e:#}"))
             })?;
+        self.check_detected(address_index.account, address_index.is_ephemeral())?;
 
         Ok(tonic::Response::new(pb::AddressByIndexResponse {
             address: Some(fvk.payment_address(address_index).0.into()),
@@ -826,7 +923,7 @@ impl ViewService for ViewServer {
                 tonic::Status::failed_precondition("Error retrieving full viewing key")
             })?;
 
-        let address_index = request
+        let address_index: AddressIndex = request
             .into_inner()
             .address_index
             .ok_or_else(|| tonic::Status::invalid_argument("Missing address index"))?
@@ -834,6 +931,7 @@ impl ViewService for ViewServer {
             .map_err(|e| {
                 tonic::Status::invalid_argument(format!("Could not parse address index: {e:#}"))
             })?;
+        self.check_detected(address_index.account, true)?;
 
         Ok(tonic::Response::new(pb::EphemeralAddressResponse {
             address: Some(fvk.ephemeral_address(OsRng, address_index).0.into()),
@@ -1272,6 +1370,299 @@ impl ViewService for ViewServer {
         Ok(tonic::Response::new(stream.boxed()))
     }
 
+    #[instrument(skip_all, level = "trace")]
+    async fn wallet_events(
+        &self,
+        _: tonic::Request<pb::WalletEventsRequest>,
+    ) -> Result<tonic::Response<Self::WalletEventsStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        // Forward the wallet's events from the worker until the client goes away. If the client
+        // falls too far behind, the events it missed can't be recovered, so end the stream with
+        // an error rather than skip them silently.
+        let mut events = self.storage.subscribe_events();
+        let stream = try_stream! {
+            loop {
+                match events.recv().await {
+                    Ok(event) => yield pb::WalletEventsResponse::from(event),
+                    Err(RecvError::Lagged(missed)) => Err(tonic::Status::data_loss(format!(
+                        "client fell behind and missed {missed} wallet events"
+                    )))?,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(stream.boxed()))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn batch_swap_outputs(
+        &self,
+        request: tonic::Request<pb::BatchSwapOutputsRequest>,
+    ) -> Result<tonic::Response<Self::BatchSwapOutputsStream>, tonic::Status> {
+        self.check_worker().await?;
+        // Unpack optional start/end heights.
+        let start_height = if request.get_ref().start_height == 0 {
+            None
+        } else {
+            Some(request.get_ref().start_height)
+        };
+        let end_height = if request.get_ref().end_height == 0 {
+            None
+        } else {
+            Some(request.get_ref().end_height)
+        };
+
+        let outputs = self
+            .storage
+            .batch_swap_outputs(start_height, end_height)
+            .await
+            .map_err(|e| {
+                tonic::Status::unavailable(format!("error fetching batch swap outputs: {e}"))
+            })?;
+
+        let stream = try_stream! {
+            for output_data in outputs {
+                yield pb::BatchSwapOutputsResponse {
+                    data: Some(output_data.into()),
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting batch swap outputs: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn contacts(
+        &self,
+        _: tonic::Request<pb::ContactsRequest>,
+    ) -> Result<tonic::Response<Self::ContactsStream>, tonic::Status> {
+        let contacts = self
+            .storage
+            .address_book()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error fetching contacts: {e}")))?
+            .contacts;
+
+        let stream = stream::iter(contacts.into_iter().map(|(address, label)| {
+            Ok(pb::ContactsResponse {
+                contact: Some(pb::Contact {
+                    address: Some(address.into()),
+                    label,
+                }),
+            })
+        }));
+
+        Ok(tonic::Response::new(stream.boxed()))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn set_contact(
+        &self,
+        request: tonic::Request<pb::SetContactRequest>,
+    ) -> Result<tonic::Response<pb::SetContactResponse>, tonic::Status> {
+        let contact = request
+            .into_inner()
+            .contact
+            .ok_or_else(|| tonic::Status::invalid_argument("missing contact"))?;
+        let address: Address = contact
+            .address
+            .ok_or_else(|| tonic::Status::invalid_argument("missing contact address"))?
+            .try_into()
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid address: {e:#}")))?;
+
+        self.storage
+            .set_contact(&address, contact.label)
+            .await
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("error setting contact: {e:#}"))
+            })?;
+
+        Ok(tonic::Response::new(pb::SetContactResponse {}))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn delete_contact(
+        &self,
+        request: tonic::Request<pb::DeleteContactRequest>,
+    ) -> Result<tonic::Response<pb::DeleteContactResponse>, tonic::Status> {
+        let address: Address = request
+            .into_inner()
+            .address
+            .ok_or_else(|| tonic::Status::invalid_argument("missing address"))?
+            .try_into()
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid address: {e:#}")))?;
+
+        let deleted =
+            self.storage.delete_contact(&address).await.map_err(|e| {
+                tonic::Status::unavailable(format!("error deleting contact: {e:#}"))
+            })?;
+
+        Ok(tonic::Response::new(pb::DeleteContactResponse { deleted }))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn account_labels(
+        &self,
+        _: tonic::Request<pb::AccountLabelsRequest>,
+    ) -> Result<tonic::Response<Self::AccountLabelsStream>, tonic::Status> {
+        let accounts = self
+            .storage
+            .address_book()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error fetching account labels: {e}")))?
+            .accounts;
+
+        let stream = stream::iter(accounts.into_iter().map(|(account, label)| {
+            Ok(pb::AccountLabelsResponse {
+                account_label: Some(pb::AccountLabel { account, label }),
+            })
+        }));
+
+        Ok(tonic::Response::new(stream.boxed()))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn set_account_label(
+        &self,
+        request: tonic::Request<pb::SetAccountLabelRequest>,
+    ) -> Result<tonic::Response<pb::SetAccountLabelResponse>, tonic::Status> {
+        let account_label = request
+            .into_inner()
+            .account_label
+            .ok_or_else(|| tonic::Status::invalid_argument("missing account label"))?;
+
+        self.storage
+            .set_account_label(account_label.account, account_label.label)
+            .await
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("error setting account label: {e:#}"))
+            })?;
+
+        Ok(tonic::Response::new(pb::SetAccountLabelResponse {}))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn delete_account_label(
+        &self,
+        request: tonic::Request<pb::DeleteAccountLabelRequest>,
+    ) -> Result<tonic::Response<pb::DeleteAccountLabelResponse>, tonic::Status> {
+        let deleted = self
+            .storage
+            .delete_account_label(request.into_inner().account)
+            .await
+            .map_err(|e| {
+                tonic::Status::unavailable(format!("error deleting account label: {e:#}"))
+            })?;
+
+        Ok(tonic::Response::new(pb::DeleteAccountLabelResponse {
+            deleted,
+        }))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn create_invoice(
+        &self,
+        request: tonic::Request<pb::CreateInvoiceRequest>,
+    ) -> Result<tonic::Response<pb::CreateInvoiceResponse>, tonic::Status> {
+        let request = request.into_inner();
+        let fvk =
+            self.storage.full_viewing_key().await.map_err(|_| {
+                tonic::Status::failed_precondition("Error retrieving full viewing key")
+            })?;
+
+        let account: AddressIndex = request
+            .account
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid account: {e:#}")))?
+            .unwrap_or_default();
+        let account = AddressIndex::from(account.account);
+        // A dedicated address is a one-time address, whose payments a detection service misses.
+        self.check_detected(account.account, request.dedicated_address)?;
+        let address = if request.dedicated_address {
+            fvk.ephemeral_address(OsRng, account).0
+        } else {
+            fvk.payment_address(account).0
+        };
+
+        let values = request
+            .values
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<anyhow::Result<_>>()
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid value: {e:#}")))?;
+        let invoice_id = if request.invoice_id.is_empty() {
+            hex::encode(OsRng.gen::<[u8; 8]>())
+        } else {
+            request.invoice_id
+        };
+        let payment_request = PaymentRequest {
+            address,
+            values,
+            memo: Some(request.memo).filter(|memo| !memo.is_empty()),
+            expiry: Some(request.expiry).filter(|&expiry| expiry != 0),
+            invoice_id: Some(invoice_id),
+        };
+
+        self.storage
+            .record_invoice(&payment_request, request.dedicated_address)
+            .await
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("error creating invoice: {e:#}"))
+            })?;
+
+        Ok(tonic::Response::new(pb::CreateInvoiceResponse {
+            payment_request: Some(payment_request.into()),
+        }))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn invoices(
+        &self,
+        request: tonic::Request<pb::InvoicesRequest>,
+    ) -> Result<tonic::Response<Self::InvoicesStream>, tonic::Status> {
+        let include_paid = request.into_inner().include_paid;
+        let invoices =
+            self.storage.invoices().await.map_err(|e| {
+                tonic::Status::unavailable(format!("error fetching invoices: {e:#}"))
+            })?;
+
+        let stream = stream::iter(
+            invoices
+                .into_iter()
+                .filter(move |invoice| include_paid || !invoice.is_paid())
+                .map(|invoice| {
+                    Ok(pb::InvoicesResponse {
+                        invoice: Some(invoice.into()),
+                    })
+                }),
+        );
+
+        Ok(tonic::Response::new(stream.boxed()))
+    }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn delete_invoice(
+        &self,
+        request: tonic::Request<pb::DeleteInvoiceRequest>,
+    ) -> Result<tonic::Response<pb::DeleteInvoiceResponse>, tonic::Status> {
+        let deleted = self
+            .storage
+            .delete_invoice(&request.into_inner().invoice_id)
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error deleting invoice: {e:#}")))?;
+
+        Ok(tonic::Response::new(pb::DeleteInvoiceResponse { deleted }))
+    }
+
     #[instrument(skip_all, level = "trace")]
     async fn notes(
         &self,
@@ -0,0 +1,408 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use camino::Utf8Path;
+use tap::Tap;
+use tonic::{async_trait, metadata::MetadataValue, Request, Response, Status};
+use url::Url;
+
+use penumbra_keys::{keys::WalletId, FullViewingKey};
+use penumbra_proto::view::v1::{self as pb, view_service_server::ViewService};
+
+use super::ViewServer;
+use crate::{DatabaseKey, Storage};
+
+/// The gRPC metadata header which selects the wallet that a request to a [`MultiViewServer`] is
+/// for, holding the bech32m-encoded [`WalletId`] of the wallet.
+pub const WALLET_ID_HEADER: &str = "penumbra-wallet-id";
+
+/// A view service for many wallets, whose state is kept in one database.
+///
+/// All of the wallets are synchronized by a single task, which fetches and trial-decrypts each
+/// compact block once for all of them. Each request is answered by the [`ViewServer`] for the
+/// wallet named in its [`WALLET_ID_HEADER`] metadata header; a client can add the header to all
+/// of its requests with [`MultiViewServer::wallet_interceptor`].
+#[derive(Clone)]
+pub struct MultiViewServer {
+    wallets: Arc<BTreeMap<WalletId, ViewServer>>,
+}
+
+impl MultiViewServer {
+    /// Convenience method that calls [`Storage::load_or_initialize_wallets`] and then [`Self::new`].
+    pub async fn load_or_initialize(
+        storage_path: Option<impl AsRef<Utf8Path>>,
+        key: Option<DatabaseKey>,
+        registry_path: Option<impl AsRef<Utf8Path>>,
+        fvks: &[FullViewingKey],
+        node: Url,
+    ) -> anyhow::Result<Self> {
+        let storages = Storage::load_or_initialize_wallets(storage_path, key, fvks, node.clone())
+            .tap(|_| tracing::trace!("loading or initializing storage"))
+            .await?
+            .tap(|storages| tracing::debug!(wallets = storages.len(), "storage is ready"));
+
+        if let (Some(registry_path), Some(storage)) = (registry_path, storages.first()) {
+            storage.load_asset_metadata(registry_path).await?;
+        }
+
+        Self::new(storages, node).await
+    }
+
+    /// Constructs a new [`MultiViewServer`] for the wallets in `storages`, which should all be
+    /// handles to the same database, spawning a sync task internally.
+    pub async fn new(storages: Vec<Storage>, node: Url) -> anyhow::Result<Self> {
+        let wallets = ViewServer::spawn(storages, node, None)
+            .await?
+            .into_iter()
+            .map(|server| (server.storage.wallet_id(), server))
+            .collect();
+
+        Ok(Self {
+            wallets: Arc::new(wallets),
+        })
+    }
+
+    /// The wallets served by this view service.
+    pub fn wallet_ids(&self) -> impl Iterator<Item = &WalletId> {
+        self.wallets.keys()
+    }
+
+    /// The view service for one of the wallets, if it is served by this view service.
+    pub fn wallet(&self, wallet_id: &WalletId) -> Option<&ViewServer> {
+        self.wallets.get(wallet_id)
+    }
+
+    /// An interceptor adding the [`WALLET_ID_HEADER`] for `wallet_id` to every request, for use
+    /// with a [`ViewServiceClient`](pb::view_service_client::ViewServiceClient).
+    pub fn wallet_interceptor(
+        wallet_id: WalletId,
+    ) -> impl FnMut(Request<()>) -> Result<Request<()>, Status> + Clone {
+        move |mut request: Request<()>| {
+            let value = MetadataValue::try_from(wallet_id.to_string().as_str())
+                .map_err(|_| Status::internal("wallet id is not a valid header value"))?;
+            request.metadata_mut().insert(WALLET_ID_HEADER, value);
+            Ok(request)
+        }
+    }
+
+    /// The view service for the wallet named in a request's [`WALLET_ID_HEADER`].
+    fn route<T>(&self, request: &Request<T>) -> Result<&ViewServer, Status> {
+        let wallet_id = request
+            .metadata()
+            .get(WALLET_ID_HEADER)
+            .ok_or_else(|| {
+                Status::invalid_argument(format!("missing {WALLET_ID_HEADER} request header"))
+            })?
+            .to_str()
+            .map_err(|_| Status::invalid_argument(format!("invalid {WALLET_ID_HEADER} header")))?
+            .parse::<WalletId>()
+            .map_err(|e| Status::invalid_argument(format!("invalid wallet id: {e:#}")))?;
+
+        self.wallet(&wallet_id)
+            .ok_or_else(|| Status::not_found(format!("wallet {wallet_id} is not served here")))
+    }
+}
+
+#[async_trait]
+impl ViewService for MultiViewServer {
+    type NotesStream = <ViewServer as ViewService>::NotesStream;
+    type NotesForVotingStream = <ViewServer as ViewService>::NotesForVotingStream;
+    type AssetsStream = <ViewServer as ViewService>::AssetsStream;
+    type StatusStreamStream = <ViewServer as ViewService>::StatusStreamStream;
+    type TransactionInfoStream = <ViewServer as ViewService>::TransactionInfoStream;
+    type BalancesStream = <ViewServer as ViewService>::BalancesStream;
+    type OwnedPositionIdsStream = <ViewServer as ViewService>::OwnedPositionIdsStream;
+    type UnclaimedSwapsStream = <ViewServer as ViewService>::UnclaimedSwapsStream;
+    type BroadcastTransactionStream = <ViewServer as ViewService>::BroadcastTransactionStream;
+    type WitnessAndBuildStream = <ViewServer as ViewService>::WitnessAndBuildStream;
+    type AuthorizeAndBuildStream = <ViewServer as ViewService>::AuthorizeAndBuildStream;
+    type DelegationsByAddressIndexStream =
+        <ViewServer as ViewService>::DelegationsByAddressIndexStream;
+    type UnbondingTokensByAddressIndexStream =
+        <ViewServer as ViewService>::UnbondingTokensByAddressIndexStream;
+    type AuctionsStream = <ViewServer as ViewService>::AuctionsStream;
+    type WalletEventsStream = <ViewServer as ViewService>::WalletEventsStream;
+    type BatchSwapOutputsStream = <ViewServer as ViewService>::BatchSwapOutputsStream;
+    type ContactsStream = <ViewServer as ViewService>::ContactsStream;
+    type AccountLabelsStream = <ViewServer as ViewService>::AccountLabelsStream;
+    type InvoicesStream = <ViewServer as ViewService>::InvoicesStream;
+
+    async fn auctions(
+        &self,
+        request: Request<pb::AuctionsRequest>,
+    ) -> Result<Response<Self::AuctionsStream>, Status> {
+        ViewService::auctions(self.route(&request)?, request).await
+    }
+
+    async fn broadcast_transaction(
+        &self,
+        request: Request<pb::BroadcastTransactionRequest>,
+    ) -> Result<Response<Self::BroadcastTransactionStream>, Status> {
+        ViewService::broadcast_transaction(self.route(&request)?, request).await
+    }
+
+    async fn transaction_planner(
+        &self,
+        request: Request<pb::TransactionPlannerRequest>,
+    ) -> Result<Response<pb::TransactionPlannerResponse>, Status> {
+        ViewService::transaction_planner(self.route(&request)?, request).await
+    }
+
+    async fn address_by_index(
+        &self,
+        request: Request<pb::AddressByIndexRequest>,
+    ) -> Result<Response<pb::AddressByIndexResponse>, Status> {
+        ViewService::address_by_index(self.route(&request)?, request).await
+    }
+
+    async fn index_by_address(
+        &self,
+        request: Request<pb::IndexByAddressRequest>,
+    ) -> Result<Response<pb::IndexByAddressResponse>, Status> {
+        ViewService::index_by_address(self.route(&request)?, request).await
+    }
+
+    async fn ephemeral_address(
+        &self,
+        request: Request<pb::EphemeralAddressRequest>,
+    ) -> Result<Response<pb::EphemeralAddressResponse>, Status> {
+        ViewService::ephemeral_address(self.route(&request)?, request).await
+    }
+
+    async fn transaction_info_by_hash(
+        &self,
+        request: Request<pb::TransactionInfoByHashRequest>,
+    ) -> Result<Response<pb::TransactionInfoByHashResponse>, Status> {
+        ViewService::transaction_info_by_hash(self.route(&request)?, request).await
+    }
+
+    async fn swap_by_commitment(
+        &self,
+        request: Request<pb::SwapByCommitmentRequest>,
+    ) -> Result<Response<pb::SwapByCommitmentResponse>, Status> {
+        ViewService::swap_by_commitment(self.route(&request)?, request).await
+    }
+
+    async fn balances(
+        &self,
+        request: Request<pb::BalancesRequest>,
+    ) -> Result<Response<Self::BalancesStream>, Status> {
+        ViewService::balances(self.route(&request)?, request).await
+    }
+
+    async fn note_by_commitment(
+        &self,
+        request: Request<pb::NoteByCommitmentRequest>,
+    ) -> Result<Response<pb::NoteByCommitmentResponse>, Status> {
+        ViewService::note_by_commitment(self.route(&request)?, request).await
+    }
+
+    async fn nullifier_status(
+        &self,
+        request: Request<pb::NullifierStatusRequest>,
+    ) -> Result<Response<pb::NullifierStatusResponse>, Status> {
+        ViewService::nullifier_status(self.route(&request)?, request).await
+    }
+
+    async fn status(
+        &self,
+        request: Request<pb::StatusRequest>,
+    ) -> Result<Response<pb::StatusResponse>, Status> {
+        ViewService::status(self.route(&request)?, request).await
+    }
+
+    async fn status_stream(
+        &self,
+        request: Request<pb::StatusStreamRequest>,
+    ) -> Result<Response<Self::StatusStreamStream>, Status> {
+        ViewService::status_stream(self.route(&request)?, request).await
+    }
+
+    async fn wallet_events(
+        &self,
+        request: Request<pb::WalletEventsRequest>,
+    ) -> Result<Response<Self::WalletEventsStream>, Status> {
+        ViewService::wallet_events(self.route(&request)?, request).await
+    }
+
+    async fn batch_swap_outputs(
+        &self,
+        request: Request<pb::BatchSwapOutputsRequest>,
+    ) -> Result<Response<Self::BatchSwapOutputsStream>, Status> {
+        ViewService::batch_swap_outputs(self.route(&request)?, request).await
+    }
+
+    async fn contacts(
+        &self,
+        request: Request<pb::ContactsRequest>,
+    ) -> Result<Response<Self::ContactsStream>, Status> {
+        ViewService::contacts(self.route(&request)?, request).await
+    }
+
+    async fn set_contact(
+        &self,
+        request: Request<pb::SetContactRequest>,
+    ) -> Result<Response<pb::SetContactResponse>, Status> {
+        ViewService::set_contact(self.route(&request)?, request).await
+    }
+
+    async fn delete_contact(
+        &self,
+        request: Request<pb::DeleteContactRequest>,
+    ) -> Result<Response<pb::DeleteContactResponse>, Status> {
+        ViewService::delete_contact(self.route(&request)?, request).await
+    }
+
+    async fn account_labels(
+        &self,
+        request: Request<pb::AccountLabelsRequest>,
+    ) -> Result<Response<Self::AccountLabelsStream>, Status> {
+        ViewService::account_labels(self.route(&request)?, request).await
+    }
+
+    async fn set_account_label(
+        &self,
+        request: Request<pb::SetAccountLabelRequest>,
+    ) -> Result<Response<pb::SetAccountLabelResponse>, Status> {
+        ViewService::set_account_label(self.route(&request)?, request).await
+    }
+
+    async fn delete_account_label(
+        &self,
+        request: Request<pb::DeleteAccountLabelRequest>,
+    ) -> Result<Response<pb::DeleteAccountLabelResponse>, Status> {
+        ViewService::delete_account_label(self.route(&request)?, request).await
+    }
+
+    async fn create_invoice(
+        &self,
+        request: Request<pb::CreateInvoiceRequest>,
+    ) -> Result<Response<pb::CreateInvoiceResponse>, Status> {
+        ViewService::create_invoice(self.route(&request)?, request).await
+    }
+
+    async fn invoices(
+        &self,
+        request: Request<pb::InvoicesRequest>,
+    ) -> Result<Response<Self::InvoicesStream>, Status> {
+        ViewService::invoices(self.route(&request)?, request).await
+    }
+
+    async fn delete_invoice(
+        &self,
+        request: Request<pb::DeleteInvoiceRequest>,
+    ) -> Result<Response<pb::DeleteInvoiceResponse>, Status> {
+        ViewService::delete_invoice(self.route(&request)?, request).await
+    }
+
+    async fn notes(
+        &self,
+        request: Request<pb::NotesRequest>,
+    ) -> Result<Response<Self::NotesStream>, Status> {
+        ViewService::notes(self.route(&request)?, request).await
+    }
+
+    async fn notes_for_voting(
+        &self,
+        request: Request<pb::NotesForVotingRequest>,
+    ) -> Result<Response<Self::NotesForVotingStream>, Status> {
+        ViewService::notes_for_voting(self.route(&request)?, request).await
+    }
+
+    async fn assets(
+        &self,
+        request: Request<pb::AssetsRequest>,
+    ) -> Result<Response<Self::AssetsStream>, Status> {
+        ViewService::assets(self.route(&request)?, request).await
+    }
+
+    async fn transaction_info(
+        &self,
+        request: Request<pb::TransactionInfoRequest>,
+    ) -> Result<Response<Self::TransactionInfoStream>, Status> {
+        ViewService::transaction_info(self.route(&request)?, request).await
+    }
+
+    async fn witness(
+        &self,
+        request: Request<pb::WitnessRequest>,
+    ) -> Result<Response<pb::WitnessResponse>, Status> {
+        ViewService::witness(self.route(&request)?, request).await
+    }
+
+    async fn witness_and_build(
+        &self,
+        request: Request<pb::WitnessAndBuildRequest>,
+    ) -> Result<Response<Self::WitnessAndBuildStream>, Status> {
+        ViewService::witness_and_build(self.route(&request)?, request).await
+    }
+
+    async fn app_parameters(
+        &self,
+        request: Request<pb::AppParametersRequest>,
+    ) -> Result<Response<pb::AppParametersResponse>, Status> {
+        ViewService::app_parameters(self.route(&request)?, request).await
+    }
+
+    async fn gas_prices(
+        &self,
+        request: Request<pb::GasPricesRequest>,
+    ) -> Result<Response<pb::GasPricesResponse>, Status> {
+        ViewService::gas_prices(self.route(&request)?, request).await
+    }
+
+    async fn fmd_parameters(
+        &self,
+        request: Request<pb::FmdParametersRequest>,
+    ) -> Result<Response<pb::FmdParametersResponse>, Status> {
+        ViewService::fmd_parameters(self.route(&request)?, request).await
+    }
+
+    async fn owned_position_ids(
+        &self,
+        request: Request<pb::OwnedPositionIdsRequest>,
+    ) -> Result<Response<Self::OwnedPositionIdsStream>, Status> {
+        ViewService::owned_position_ids(self.route(&request)?, request).await
+    }
+
+    async fn authorize_and_build(
+        &self,
+        request: Request<pb::AuthorizeAndBuildRequest>,
+    ) -> Result<Response<Self::AuthorizeAndBuildStream>, Status> {
+        ViewService::authorize_and_build(self.route(&request)?, request).await
+    }
+
+    async fn unclaimed_swaps(
+        &self,
+        request: Request<pb::UnclaimedSwapsRequest>,
+    ) -> Result<Response<Self::UnclaimedSwapsStream>, Status> {
+        ViewService::unclaimed_swaps(self.route(&request)?, request).await
+    }
+
+    async fn wallet_id(
+        &self,
+        request: Request<pb::WalletIdRequest>,
+    ) -> Result<Response<pb::WalletIdResponse>, Status> {
+        ViewService::wallet_id(self.route(&request)?, request).await
+    }
+
+    async fn asset_metadata_by_id(
+        &self,
+        request: Request<pb::AssetMetadataByIdRequest>,
+    ) -> Result<Response<pb::AssetMetadataByIdResponse>, Status> {
+        ViewService::asset_metadata_by_id(self.route(&request)?, request).await
+    }
+
+    async fn delegations_by_address_index(
+        &self,
+        request: Request<pb::DelegationsByAddressIndexRequest>,
+    ) -> Result<Response<Self::DelegationsByAddressIndexStream>, Status> {
+        ViewService::delegations_by_address_index(self.route(&request)?, request).await
+    }
+
+    async fn unbonding_tokens_by_address_index(
+        &self,
+        request: Request<pb::UnbondingTokensByAddressIndexRequest>,
+    ) -> Result<Response<Self::UnbondingTokensByAddressIndexStream>, Status> {
+        ViewService::unbonding_tokens_by_address_index(self.route(&request)?, request).await
+    }
+}
@@ -1,50 +1,62 @@
-use std::{collections::BTreeMap, num::NonZeroU64, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    num::NonZeroU64,
+    sync::Arc,
+};
 
 use anyhow::{anyhow, Context};
+use async_trait::async_trait;
 use camino::Utf8Path;
-use decaf377::Fq;
-use once_cell::sync::Lazy;
 use parking_lot::Mutex;
 use penumbra_auction::auction::AuctionId;
-use r2d2_sqlite::{
-    rusqlite::{OpenFlags, OptionalExtension},
-    SqliteConnectionManager,
-};
-use sha2::{Digest, Sha256};
 use tap::{Tap, TapFallible};
-use tokio::{
-    sync::broadcast::{self, error::RecvError},
-    task::spawn_blocking,
-};
+use tokio::sync::broadcast::{self, error::RecvError};
 use tracing::{error_span, Instrument};
 use url::Url;
 
 use penumbra_app::params::AppParameters;
-use penumbra_asset::{asset, asset::Id, asset::Metadata, Value};
+use penumbra_asset::{asset, asset::Id, asset::Metadata};
 use penumbra_dex::{
     lp::position::{self, Position, State},
-    TradingPair,
+    BatchSwapOutputData, TradingPair,
 };
 use penumbra_fee::GasPrices;
-use penumbra_keys::{keys::AddressIndex, Address, FullViewingKey};
+use penumbra_keys::{
+    keys::{AddressIndex, WalletId},
+    Address, FullViewingKey, PaymentRequest,
+};
 use penumbra_num::Amount;
-use penumbra_proto::{
-    core::app::v1::{
+use penumbra_proto::core::{
+    app::v1::{
         query_service_client::QueryServiceClient as AppQueryServiceClient, AppParametersRequest,
     },
-    DomainType,
+    component::sct::v1::{
+        query_service_client::QueryServiceClient as SctQueryServiceClient, AnchorByHeightRequest,
+        SctFrontierRequest,
+    },
 };
-use penumbra_sct::{CommitmentSource, Nullifier};
-use penumbra_shielded_pool::{fmd, note, Note, Rseed};
+use penumbra_sct::Nullifier;
+use penumbra_shielded_pool::{fmd, note, Note};
 use penumbra_stake::{DelegationToken, IdentityKey};
 use penumbra_tct as tct;
 use penumbra_transaction::Transaction;
-use sct::TreeStore;
 use tct::StateCommitment;
 
-use crate::{sync::FilteredBlock, SpendableNoteRecord, SwapRecord};
+use crate::{
+    address_book::AddressBook,
+    event::{WalletEvent, WalletEventKind},
+    invoice::Invoice,
+    sync::FilteredBlock,
+    SpendableNoteRecord, SwapRecord,
+};
+
+#[cfg(test)]
+mod conformance;
+mod postgres;
+mod sqlite;
 
-mod sct;
+pub use postgres::PostgresBackend;
+pub use sqlite::{DatabaseKey, SqliteBackend};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct BalanceEntry {
@@ -53,13 +65,312 @@ pub struct BalanceEntry {
     pub address_index: AddressIndex,
 }
 
-/// The hash of the schema for the database.
-static SCHEMA_HASH: Lazy<String> =
-    Lazy::new(|| hex::encode(Sha256::digest(include_str!("storage/schema.sql"))));
+/// The height at which a wallet starts scanning the chain.
+#[derive(Clone, Debug, Default)]
+pub struct Birthday {
+    /// The height of the first block scanned for the wallet.
+    pub height: u64,
+    /// The state commitment tree at the end of the block before `height`, as fetched from a node.
+    ///
+    /// If this is `None`, the blocks before `height` are replayed from genesis to build the tree,
+    /// without being scanned.
+    pub frontier: Option<tct::Tree>,
+}
+
+/// A database holding the private state of one or more wallets.
+///
+/// The methods which read or write the state of a wallet take the [`WalletId`] of its full viewing
+/// key; the assets, note plaintexts, and chain parameters in the database are shared between all
+/// of its wallets. A [`Storage`] handle wraps a backend, adding the bookkeeping which doesn't
+/// depend on how the data is stored, such as waiting for notes to be detected.
+#[async_trait]
+pub trait StorageBackend: Send + Sync + 'static {
+    /// The wallets in the database, ordered by [`WalletId`].
+    async fn wallets(&self) -> anyhow::Result<Vec<WalletId>>;
+
+    /// Add a wallet to the database, unless it is already there.
+    async fn add_wallet(&self, fvk: &FullViewingKey) -> anyhow::Result<WalletId>;
+
+    async fn full_viewing_key(&self, wallet: WalletId) -> anyhow::Result<FullViewingKey>;
+
+    /// The last block height recorded for the wallet, or `None` before genesis.
+    async fn sync_height(&self, wallet: WalletId) -> anyhow::Result<Option<u64>>;
+
+    async fn birthday(&self, wallet: WalletId) -> anyhow::Result<Birthday>;
+
+    /// The height below which blocks are replayed for the wallet to rebuild its state commitment
+    /// tree, rather than scanned.
+    async fn replay_height(&self, wallet: WalletId) -> anyhow::Result<u64>;
+
+    /// Set the birthday of the wallet, discarding all of its records and restarting its sync from
+    /// the birthday.
+    async fn set_birthday(&self, wallet: WalletId, birthday: &Birthday) -> anyhow::Result<()>;
+
+    /// Discard the records of the wallet from the blocks at or after `height`, and restart its
+    /// sync from its birthday, so that the blocks before `height` are replayed and the rest are
+    /// scanned again.
+    async fn rewind(&self, wallet: WalletId, height: u64) -> anyhow::Result<()>;
+
+    async fn app_params(&self) -> anyhow::Result<Option<AppParameters>>;
+
+    async fn record_app_params(&self, params: &AppParameters) -> anyhow::Result<()>;
+
+    async fn gas_prices(&self) -> anyhow::Result<Option<GasPrices>>;
+
+    async fn fmd_parameters(&self) -> anyhow::Result<Option<fmd::Parameters>>;
+
+    async fn state_commitment_tree(&self, wallet: WalletId) -> anyhow::Result<tct::Tree>;
+
+    /// One entry for each unspent note of the wallet.
+    async fn unspent_balances(&self, wallet: WalletId) -> anyhow::Result<Vec<BalanceEntry>>;
+
+    async fn note_by_commitment(
+        &self,
+        wallet: WalletId,
+        note_commitment: StateCommitment,
+    ) -> anyhow::Result<Option<SpendableNoteRecord>>;
+
+    async fn note_by_nullifier(
+        &self,
+        wallet: WalletId,
+        nullifier: Nullifier,
+    ) -> anyhow::Result<Option<SpendableNoteRecord>>;
+
+    /// The notes of the wallet, optionally including spent notes or restricted to one asset.
+    async fn notes(
+        &self,
+        wallet: WalletId,
+        include_spent: bool,
+        asset_id: Option<asset::Id>,
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>>;
+
+    /// The delegation notes of the wallet which can be used to vote at the given height.
+    async fn notes_for_voting(
+        &self,
+        wallet: WalletId,
+        address_index: Option<AddressIndex>,
+        votable_at_height: u64,
+    ) -> anyhow::Result<Vec<(SpendableNoteRecord, IdentityKey)>>;
+
+    async fn notes_by_sender(
+        &self,
+        wallet: WalletId,
+        return_address: &Address,
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>>;
+
+    /// The wallet's notes which were sent to `address`.
+    async fn notes_by_address(
+        &self,
+        wallet: WalletId,
+        address: &Address,
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>>;
+
+    /// The wallet's notes created by transactions whose memo text is exactly `memo_text`, with
+    /// the return addresses of those transactions.
+    async fn notes_by_memo(
+        &self,
+        wallet: WalletId,
+        memo_text: &str,
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>>;
+
+    async fn swap_by_commitment(
+        &self,
+        wallet: WalletId,
+        swap_commitment: StateCommitment,
+    ) -> anyhow::Result<Option<SwapRecord>>;
+
+    async fn unclaimed_swaps(&self, wallet: WalletId) -> anyhow::Result<Vec<SwapRecord>>;
+
+    /// Whether the note with the nullifier has been spent, or `None` if there is no such note.
+    async fn nullifier_status(
+        &self,
+        wallet: WalletId,
+        nullifier: Nullifier,
+    ) -> anyhow::Result<Option<bool>>;
+
+    /// Filters for nullifiers of the wallet's notes and swaps, or of its transactions' spends.
+    async fn filter_nullifiers(
+        &self,
+        wallet: WalletId,
+        nullifiers: Vec<Nullifier>,
+    ) -> anyhow::Result<Vec<Nullifier>>;
+
+    async fn transaction_hashes(
+        &self,
+        wallet: WalletId,
+        start_height: u64,
+        end_height: u64,
+    ) -> anyhow::Result<Vec<(u64, Vec<u8>)>>;
+
+    async fn transactions(
+        &self,
+        wallet: WalletId,
+        start_height: u64,
+        end_height: u64,
+    ) -> anyhow::Result<Vec<(u64, Vec<u8>, Transaction)>>;
+
+    async fn transaction_by_hash(
+        &self,
+        wallet: WalletId,
+        tx_hash: &[u8],
+    ) -> anyhow::Result<Option<(u64, Transaction)>>;
+
+    /// The transactions with memo text matching the SQL `LIKE` pattern, where `\` is the escape
+    /// character.
+    async fn transactions_matching_memo(
+        &self,
+        wallet: WalletId,
+        pattern: String,
+    ) -> anyhow::Result<Vec<(u64, Vec<u8>, Transaction, String)>>;
+
+    async fn all_assets(&self) -> anyhow::Result<Vec<Metadata>>;
+
+    async fn asset_by_id(&self, id: &Id) -> anyhow::Result<Option<Metadata>>;
+
+    /// The assets whose denoms match the SQL `LIKE` pattern, where `\` is the escape character.
+    async fn assets_matching(&self, pattern: String) -> anyhow::Result<Vec<Metadata>>;
+
+    async fn record_asset(&self, asset: Metadata) -> anyhow::Result<()>;
+
+    /// The outputs of the batch swaps recorded at heights from `start_height` to `end_height`
+    /// inclusive, ordered by height.
+    async fn batch_swap_outputs(
+        &self,
+        start_height: u64,
+        end_height: u64,
+    ) -> anyhow::Result<Vec<BatchSwapOutputData>>;
+
+    /// Record the state of an auction at `height`, so that it can be reverted by a rewind.
+    async fn record_auction_with_state(
+        &self,
+        wallet: WalletId,
+        auction_id: AuctionId,
+        auction_state: u64,
+        height: u64,
+    ) -> anyhow::Result<()>;
+
+    async fn update_auction_with_note_commitment(
+        &self,
+        wallet: WalletId,
+        auction_id: AuctionId,
+        note_commitment: StateCommitment,
+    ) -> anyhow::Result<()>;
+
+    async fn fetch_auctions_by_account(
+        &self,
+        wallet: WalletId,
+        account_filter: Option<AddressIndex>,
+        include_inactive: bool,
+    ) -> anyhow::Result<Vec<(AuctionId, SpendableNoteRecord, u64)>>;
+
+    /// Record a position opened at `height`, so that it can be forgotten by a rewind.
+    async fn record_position(
+        &self,
+        wallet: WalletId,
+        position: Position,
+        height: u64,
+    ) -> anyhow::Result<()>;
 
+    /// Update the state of a position at `height`, so that it can be reverted by a rewind.
+    async fn update_position(
+        &self,
+        wallet: WalletId,
+        position_id: position::Id,
+        position_state: position::State,
+        height: u64,
+    ) -> anyhow::Result<()>;
+
+    async fn owned_position_ids(
+        &self,
+        wallet: WalletId,
+        position_state: Option<State>,
+        trading_pair: Option<TradingPair>,
+    ) -> anyhow::Result<Vec<position::Id>>;
+
+    /// The contacts in the wallet's address book, ordered by label.
+    async fn contacts(&self, wallet: WalletId) -> anyhow::Result<Vec<(Address, String)>>;
+
+    /// Add a contact to the wallet's address book, or change the label of an existing one.
+    async fn set_contact(
+        &self,
+        wallet: WalletId,
+        address: &Address,
+        label: String,
+    ) -> anyhow::Result<()>;
+
+    /// Remove a contact from the wallet's address book, returning whether it was there.
+    async fn delete_contact(&self, wallet: WalletId, address: &Address) -> anyhow::Result<bool>;
+
+    /// The labels of the wallet's accounts, by account number.
+    async fn account_labels(&self, wallet: WalletId) -> anyhow::Result<BTreeMap<u32, String>>;
+
+    async fn set_account_label(
+        &self,
+        wallet: WalletId,
+        account: u32,
+        label: String,
+    ) -> anyhow::Result<()>;
+
+    /// Remove the label of one of the wallet's accounts, returning whether it had one.
+    async fn delete_account_label(&self, wallet: WalletId, account: u32) -> anyhow::Result<bool>;
+
+    /// The wallet's invoices, ordered by ID, with whether each has a dedicated address.
+    async fn invoices(&self, wallet: WalletId) -> anyhow::Result<Vec<(PaymentRequest, bool)>>;
+
+    /// Record a new invoice, failing if the wallet already has one with the same ID.
+    async fn record_invoice(
+        &self,
+        wallet: WalletId,
+        invoice_id: &str,
+        request: &PaymentRequest,
+        dedicated_address: bool,
+    ) -> anyhow::Result<()>;
+
+    /// Remove one of the wallet's invoices, returning whether it was there.
+    async fn delete_invoice(&self, wallet: WalletId, invoice_id: &str) -> anyhow::Result<bool>;
+
+    /// Record the plaintext of a note, so that it can be given as advice during scanning.
+    async fn give_advice(&self, note: Note) -> anyhow::Result<()>;
+
+    /// The recorded notes among the note commitments which the wallet hasn't yet scanned.
+    ///
+    /// Since note plaintexts are shared between wallets, the advice can include notes for other
+    /// wallets in the database, which the caller must filter out.
+    async fn scan_advice(
+        &self,
+        wallet: WalletId,
+        note_commitments: Vec<note::StateCommitment>,
+    ) -> anyhow::Result<BTreeMap<note::StateCommitment, Note>>;
+
+    /// Record a scanned block for the wallet, along with its updated state commitment tree, in
+    /// one database transaction, returning the tree with any spent commitments forgotten.
+    ///
+    /// The block height is checked by the caller.
+    async fn record_block(
+        &self,
+        wallet: WalletId,
+        fvk: &FullViewingKey,
+        filtered_block: &FilteredBlock,
+        transactions: Vec<Transaction>,
+        app_parameters: Option<AppParameters>,
+        sct: tct::Tree,
+    ) -> anyhow::Result<tct::Tree>;
+}
+
+/// Persistent storage for the private state of a wallet.
+///
+/// One database can hold the state of many wallets, each identified by the [`WalletId`] of its
+/// full viewing key. A [`Storage`] reads and writes the state of one of them, sharing the
+/// database connection with the handles for the others.
+///
+/// The database is sqlite by default, or any other [`StorageBackend`], such as a
+/// [`PostgresBackend`] shared by several services.
 #[derive(Clone)]
 pub struct Storage {
-    pool: r2d2::Pool<SqliteConnectionManager>,
+    backend: Arc<dyn StorageBackend>,
+
+    /// The wallet whose state this handle reads and writes.
+    wallet: WalletId,
 
     /// This allows an optimization where we only commit to the database after
     /// scanning a nonempty block.
@@ -73,10 +384,76 @@ pub struct Storage {
     scanned_notes_tx: tokio::sync::broadcast::Sender<SpendableNoteRecord>,
     scanned_nullifiers_tx: tokio::sync::broadcast::Sender<Nullifier>,
     scanned_swaps_tx: tokio::sync::broadcast::Sender<SwapRecord>,
+    events_tx: tokio::sync::broadcast::Sender<WalletEvent>,
+}
+
+/// Fetch the current app parameters, to initialize a new database with.
+async fn fetch_app_params(node: Url) -> anyhow::Result<AppParameters> {
+    let mut client = AppQueryServiceClient::connect(node.to_string())
+        .instrument(error_span!("connecting_to_endpoint"))
+        .await
+        .tap_err(|error| {
+            tracing::error!(?error, "failed to connect to app query service endpoint")
+        })?
+        .tap(|_| tracing::debug!("connected to app query service endpoint"));
+    client
+        .app_parameters(tonic::Request::new(AppParametersRequest {}))
+        .instrument(error_span!("getting_app_parameters"))
+        .await?
+        .into_inner()
+        .try_into()
+}
+
+/// Fetch the frontier of the state commitment tree at the end of the block at `height`, checking
+/// it against the anchor for that height, or `None` if the node no longer has it.
+async fn fetch_sct_frontier(node: Url, height: u64) -> anyhow::Result<Option<tct::Tree>> {
+    let mut client = SctQueryServiceClient::connect(node.to_string())
+        .instrument(error_span!("connecting_to_endpoint"))
+        .await?;
+    let response = match client
+        .sct_frontier(tonic::Request::new(SctFrontierRequest { height }))
+        .await
+    {
+        Ok(response) => response.into_inner(),
+        Err(status)
+            if matches!(
+                status.code(),
+                tonic::Code::NotFound | tonic::Code::Unimplemented
+            ) =>
+        {
+            tracing::debug!(?status, "node could not provide the sct frontier");
+            return Ok(None);
+        }
+        Err(status) => return Err(status.into()),
+    };
+
+    anyhow::ensure!(
+        response.height == height,
+        "node returned the sct frontier at height {} rather than {}",
+        response.height,
+        height
+    );
+    let frontier: tct::Tree = bincode::deserialize(&response.compact_frontier)
+        .context("could not deserialize the sct frontier")?;
+    let anchor: tct::Root = client
+        .anchor_by_height(tonic::Request::new(AnchorByHeightRequest { height }))
+        .await?
+        .into_inner()
+        .anchor
+        .ok_or_else(|| anyhow!("missing anchor for height {height}"))?
+        .try_into()?;
+    anyhow::ensure!(
+        frontier.root() == anchor,
+        "the sct frontier at height {height} does not match the anchor for that height"
+    );
+
+    Ok(Some(frontier))
 }
 
 impl Storage {
     /// If the database at `storage_path` exists, [`Self::load`] it, otherwise, [`Self::initialize`] it.
+    ///
+    /// If there is a `key`, the database is encrypted with it.
     #[tracing::instrument(
         skip_all,
         fields(
@@ -86,173 +463,177 @@ impl Storage {
     )]
     pub async fn load_or_initialize(
         storage_path: Option<impl AsRef<Utf8Path>>,
+        key: Option<DatabaseKey>,
         fvk: &FullViewingKey,
         node: Url,
     ) -> anyhow::Result<Self> {
         if let Some(path) = storage_path.as_ref().map(AsRef::as_ref) {
             if path.exists() {
                 tracing::debug!(?path, "database exists");
-                return Self::load(path).await;
+                return Self::load(path, key).await;
             } else {
                 tracing::debug!(?path, "database does not exist");
             }
         };
 
-        let mut client = AppQueryServiceClient::connect(node.to_string())
-            .instrument(error_span!("connecting_to_endpoint"))
-            .await
-            .tap_err(|error| {
-                tracing::error!(?error, "failed to connect to app query service endpoint")
-            })?
-            .tap(|_| tracing::debug!("connected to app query service endpoint"));
-        let params = client
-            .app_parameters(tonic::Request::new(AppParametersRequest {}))
-            .instrument(error_span!("getting_app_parameters"))
-            .await?
-            .into_inner()
-            .try_into()?;
+        let params = fetch_app_params(node).await?;
 
-        Self::initialize(storage_path, fvk.clone(), params).await
+        Self::initialize(storage_path, key, fvk.clone(), params).await
     }
 
-    fn connect(
-        path: Option<impl AsRef<Utf8Path>>,
-    ) -> anyhow::Result<r2d2::Pool<SqliteConnectionManager>> {
-        if let Some(path) = path {
-            let manager = SqliteConnectionManager::file(path.as_ref())
-                .with_flags(
-                    // Don't allow opening URIs, because they can change the behavior of the database; we
-                    // just want to open normal filepaths.
-                    OpenFlags::default() & !OpenFlags::SQLITE_OPEN_URI,
-                )
-                .with_init(|conn| {
-                    // "NORMAL" will be consistent, but maybe not durable -- this is fine,
-                    // since all our data is being synced from the chain, so if we lose a dbtx,
-                    // it's like we're resuming sync from a previous height.
-                    conn.execute_batch("PRAGMA journal_mode=WAL; PRAGMA synchronous=NORMAL;")?;
-                    // We use `prepare_cached` a fair amount: this is an overestimate of the number
-                    // of cached prepared statements likely to be used.
-                    conn.set_prepared_statement_cache_capacity(32);
-                    Ok(())
-                });
-            Ok(r2d2::Pool::builder()
-                // We set max_size=1 to avoid "database is locked" sqlite errors,
-                // when accessing across multiple threads.
-                .max_size(1)
-                .build(manager)?)
-        } else {
-            let manager = SqliteConnectionManager::memory();
-            // Max size needs to be set to 1, otherwise a new in-memory database is created for each
-            // connection to the pool, which results in very confusing errors.
-            //
-            // Lifetimes and timeouts are likewise configured to their maximum values, since
-            // the in-memory database will disappear on connection close.
-            Ok(r2d2::Pool::builder()
-                .max_size(1)
-                .min_idle(Some(1))
-                .max_lifetime(Some(Duration::MAX))
-                .idle_timeout(Some(Duration::MAX))
-                .build(manager)?)
+    /// Like [`Self::load_or_initialize`], but for a database holding many wallets.
+    ///
+    /// Each of the `fvks` is added to the database if it isn't there already, and a handle is
+    /// returned for every wallet in the database, ordered by [`WalletId`].
+    pub async fn load_or_initialize_wallets(
+        storage_path: Option<impl AsRef<Utf8Path>>,
+        key: Option<DatabaseKey>,
+        fvks: &[FullViewingKey],
+        node: Url,
+    ) -> anyhow::Result<Vec<Self>> {
+        let existing = match storage_path {
+            Some(path) if path.as_ref().exists() => Self::load_wallets(path, key).await?,
+            _ => {
+                let fvk = fvks
+                    .first()
+                    .ok_or_else(|| anyhow!("no wallets to initialize the view database with"))?;
+                vec![Self::load_or_initialize(storage_path, key, fvk, node).await?]
+            }
+        };
+
+        let mut wallets = existing
+            .into_iter()
+            .map(|storage| (storage.wallet, storage))
+            .collect::<BTreeMap<_, _>>();
+        let handle = wallets
+            .values()
+            .next()
+            .cloned()
+            .ok_or_else(|| anyhow!("the view database holds no wallets"))?;
+        for fvk in fvks {
+            if !wallets.contains_key(&fvk.wallet_id()) {
+                wallets.insert(fvk.wallet_id(), handle.add_wallet(fvk).await?);
+            }
         }
+
+        Ok(wallets.into_values().collect())
     }
 
-    pub async fn load(path: impl AsRef<Utf8Path>) -> anyhow::Result<Self> {
-        let storage = Self {
-            pool: Self::connect(Some(path))?,
+    /// Connect to the Postgres database at `url`, creating the schema if the database is empty.
+    ///
+    /// Each of the `fvks` is added to the database if it isn't there already, and a handle is
+    /// returned for every wallet in the database, ordered by [`WalletId`].
+    #[tracing::instrument(skip_all, fields(url = %node))]
+    pub async fn load_or_initialize_postgres(
+        database_url: &str,
+        fvks: &[FullViewingKey],
+        node: Url,
+    ) -> anyhow::Result<Vec<Self>> {
+        let backend = PostgresBackend::connect(database_url).await?;
+        if backend.app_params().await?.is_none() {
+            tracing::debug!("initializing app parameters");
+            backend
+                .record_app_params(&fetch_app_params(node).await?)
+                .await?;
+        }
+        for fvk in fvks {
+            backend.add_wallet(fvk).await?;
+        }
+        Self::from_backend(backend).await
+    }
+
+    /// Load the database at `path`, which must hold exactly one wallet.
+    ///
+    /// If the database is encrypted, the `key` it was created with is needed to open it.
+    pub async fn load(
+        path: impl AsRef<Utf8Path>,
+        key: Option<DatabaseKey>,
+    ) -> anyhow::Result<Self> {
+        let mut wallets = Self::load_wallets(path, key).await?;
+        if wallets.len() != 1 {
+            anyhow::bail!(
+                "expected the view database to hold one wallet, but it holds {}",
+                wallets.len()
+            );
+        }
+        Ok(wallets.remove(0))
+    }
+
+    /// Load the database at `path`, returning a handle for each wallet it holds.
+    pub async fn load_wallets(
+        path: impl AsRef<Utf8Path>,
+        key: Option<DatabaseKey>,
+    ) -> anyhow::Result<Vec<Self>> {
+        Self::from_backend(SqliteBackend::load(path, key).await?).await
+    }
+
+    /// Return a handle for each wallet in the database of the backend, ordered by [`WalletId`].
+    pub async fn from_backend(backend: impl StorageBackend) -> anyhow::Result<Vec<Self>> {
+        let backend: Arc<dyn StorageBackend> = Arc::new(backend);
+        Ok(backend
+            .wallets()
+            .await?
+            .into_iter()
+            .map(|wallet| Self::for_wallet(backend.clone(), wallet))
+            .collect())
+    }
+
+    /// Make a new handle for a wallet in the database, with its own change notifications.
+    fn for_wallet(backend: Arc<dyn StorageBackend>, wallet: WalletId) -> Self {
+        Self {
+            backend,
+            wallet,
             uncommitted_height: Arc::new(Mutex::new(None)),
             scanned_notes_tx: broadcast::channel(128).0,
             scanned_nullifiers_tx: broadcast::channel(512).0,
             scanned_swaps_tx: broadcast::channel(128).0,
-        };
+            events_tx: broadcast::channel(1024).0,
+        }
+    }
 
-        spawn_blocking(move || {
-            // Check the version of the software used when first initializing this database.
-            // If it doesn't match the current version, we should report the error to the user.
-            let actual_schema_hash: String = storage
-                .pool
-                .get()?
-                .query_row("SELECT schema_hash FROM schema_hash", (), |row| {
-                    row.get("schema_hash")
-                })
-                .context("failed to query database schema version: the database was probably created by an old client version, and needs to be reset and resynchronized")?;
-
-            if actual_schema_hash != *SCHEMA_HASH {
-                let database_client_version: String = storage
-                    .pool
-                    .get()?
-                    .query_row("SELECT client_version FROM client_version", (), |row| {
-                        row.get("client_version")
-                    })
-                    .context("failed to query client version: the database was probably created by an old client version, and needs to be reset and resynchronized")?;
+    /// Add a wallet to this database, returning a handle for it.
+    ///
+    /// If the wallet is already in the database, its state is kept. A newly added wallet starts
+    /// scanning from genesis, independently of the other wallets in the database.
+    ///
+    /// Each handle has its own notifications of changes to the wallet, so only one handle
+    /// should be made for each wallet, and cloned to share it.
+    pub async fn add_wallet(&self, fvk: &FullViewingKey) -> anyhow::Result<Self> {
+        let wallet = self.backend.add_wallet(fvk).await?;
+        Ok(Self::for_wallet(self.backend.clone(), wallet))
+    }
 
-                anyhow::bail!(
-                    "can't load view database created by client version {} using client version {}: they have different schemata, so you need to reset your view database and resynchronize by running pcli view reset",
-                    database_client_version,
-                    env!("CARGO_PKG_VERSION"),
-                );
-            }
+    /// The wallet whose state this handle reads and writes.
+    pub fn wallet_id(&self) -> WalletId {
+        self.wallet
+    }
 
-            Ok(storage)
-        })
-            .await?
+    /// Subscribe to the changes made to the wallet by the blocks scanned from now on.
+    pub fn subscribe_events(&self) -> broadcast::Receiver<WalletEvent> {
+        self.events_tx.subscribe()
+    }
+
+    /// Notify subscribers of a change made to the wallet by scanning the block at `height`.
+    ///
+    /// This should only be called after the change is committed to the database.
+    pub(crate) fn notify(&self, height: u64, kind: WalletEventKind) {
+        // This will fail to be broadcast if there is no active receiver, which is fine.
+        let _ = self.events_tx.send(WalletEvent { height, kind });
     }
 
     pub async fn initialize(
         storage_path: Option<impl AsRef<Utf8Path>>,
+        key: Option<DatabaseKey>,
         fvk: FullViewingKey,
         params: AppParameters,
     ) -> anyhow::Result<Self> {
-        tracing::debug!(storage_path = ?storage_path.as_ref().map(AsRef::as_ref), ?fvk, ?params);
-
-        // Connect to the database (or create it)
-        let pool = Self::connect(storage_path)?;
-
-        spawn_blocking(move || {
-            // In one database transaction, populate everything
-            let mut conn = pool.get()?;
-            let tx = conn.transaction()?;
-
-            // Create the tables
-            tx.execute_batch(include_str!("storage/schema.sql"))?;
-
-            let params_bytes = params.encode_to_vec();
-            tx.execute(
-                "INSERT INTO kv (k, v) VALUES ('app_params', ?1)",
-                [&params_bytes[..]],
-            )?;
-
-            let fvk_bytes = fvk.encode_to_vec();
-            tx.execute("INSERT INTO kv (k, v) VALUES ('fvk', ?1)", [&fvk_bytes[..]])?;
-
-            // Insert -1 as a signaling value for pre-genesis.
-            // We just have to be careful to treat negative values as None
-            // in last_sync_height.
-            tx.execute("INSERT INTO sync_height (height) VALUES (-1)", ())?;
-
-            // Insert the schema hash into the database
-            tx.execute(
-                "INSERT INTO schema_hash (schema_hash) VALUES (?1)",
-                [&*SCHEMA_HASH],
-            )?;
-
-            // Insert the client version into the database
-            tx.execute(
-                "INSERT INTO client_version (client_version) VALUES (?1)",
-                [env!("CARGO_PKG_VERSION")],
-            )?;
-
-            tx.commit()?;
-            drop(conn);
-
-            Ok(Storage {
-                pool,
-                uncommitted_height: Arc::new(Mutex::new(None)),
-                scanned_notes_tx: broadcast::channel(128).0,
-                scanned_nullifiers_tx: broadcast::channel(512).0,
-                scanned_swaps_tx: broadcast::channel(128).0,
-            })
-        })
-        .await?
+        tracing::debug!(storage_path = ?storage_path.as_ref().map(AsRef::as_ref), encrypted = key.is_some(), ?fvk, ?params);
+
+        let backend: Arc<dyn StorageBackend> =
+            Arc::new(SqliteBackend::initialize(storage_path, key, params).await?);
+        let wallet = backend.add_wallet(&fvk).await?;
+
+        Ok(Self::for_wallet(backend, wallet))
     }
 
     /// Loads asset metadata from a JSON file and use to update the database.
@@ -291,72 +672,42 @@ impl Storage {
         address_index: Option<AddressIndex>,
         asset_id: Option<asset::Id>,
     ) -> anyhow::Result<Vec<BalanceEntry>> {
-        let pool = self.pool.clone();
-
-        spawn_blocking(move || {
-            let query = "SELECT notes.asset_id, notes.amount, spendable_notes.address_index
-                FROM    notes
-                JOIN    spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
-                WHERE   spendable_notes.height_spent IS NULL";
-
-            tracing::debug!(?query);
-
-            // Combine notes of the same asset/address index together
-            let mut balances: BTreeMap<AddressIndex, BTreeMap<asset::Id, Amount>> = BTreeMap::new();
-
-            for result in pool.get()?.prepare_cached(query)?.query_map([], |row| {
-                let asset_id = row.get::<&str, Vec<u8>>("asset_id")?;
-                let amount = row.get::<&str, Vec<u8>>("amount")?;
-                let address_index = row.get::<&str, Vec<u8>>("address_index")?;
-
-                Ok((asset_id, amount, address_index))
-            })? {
-                let (id, amount, index) = result?;
-
-                let id = Id::try_from(id.as_slice())?;
-
-                let amount: Amount = Amount::from_be_bytes(
-                    amount
-                        .as_slice()
-                        .try_into()
-                        .expect("amount slice of incorrect length"),
-                );
-
-                let index = AddressIndex::try_from(index.as_slice())?;
-
-                // Skip this entry if not captured by address index filter
-                if let Some(address_index) = address_index {
-                    if address_index != index {
-                        continue;
-                    }
+        // Combine notes of the same asset/address index together
+        let mut balances: BTreeMap<AddressIndex, BTreeMap<asset::Id, Amount>> = BTreeMap::new();
+
+        for entry in self.backend.unspent_balances(self.wallet).await? {
+            // Skip this entry if not captured by address index filter
+            if let Some(address_index) = address_index {
+                if address_index != entry.address_index {
+                    continue;
                 }
-                if let Some(asset_id) = asset_id {
-                    if asset_id != id {
-                        continue;
-                    }
+            }
+            if let Some(asset_id) = asset_id {
+                if asset_id != entry.id {
+                    continue;
                 }
-
-                balances
-                    .entry(index)
-                    .or_insert_with(BTreeMap::new)
-                    .entry(id)
-                    .and_modify(|e| *e += amount)
-                    .or_insert(amount);
             }
 
-            let entries = balances
-                .into_iter()
-                .flat_map(|(index, assets)| {
-                    assets.into_iter().map(move |(id, amount)| BalanceEntry {
-                        id,
-                        amount: amount.into(),
-                        address_index: index,
-                    })
+            let amount = Amount::from(entry.amount);
+            balances
+                .entry(entry.address_index)
+                .or_insert_with(BTreeMap::new)
+                .entry(entry.id)
+                .and_modify(|e| *e += amount)
+                .or_insert(amount);
+        }
+
+        let entries = balances
+            .into_iter()
+            .flat_map(|(index, assets)| {
+                assets.into_iter().map(move |(id, amount)| BalanceEntry {
+                    id,
+                    amount: amount.into(),
+                    address_index: index,
                 })
-                .collect::<Vec<_>>();
-            Ok(entries)
-        })
-        .await?
+            })
+            .collect::<Vec<_>>();
+        Ok(entries)
     }
 
     /// Query for a note by its note commitment, optionally waiting until the note is detected.
@@ -369,36 +720,11 @@ impl Storage {
         // have the record, so that we can't miss it if we race a write.
         let mut rx = self.scanned_notes_tx.subscribe();
 
-        let pool = self.pool.clone();
-
-        if let Some(record) = spawn_blocking(move || {
-            // Check if we already have the record
-            pool.get()?
-                .prepare(&format!(
-                    "SELECT
-                        notes.note_commitment,
-                        spendable_notes.height_created,
-                        notes.address,
-                        notes.amount,
-                        notes.asset_id,
-                        notes.rseed,
-                        spendable_notes.address_index,
-                        spendable_notes.source,
-                        spendable_notes.height_spent,
-                        spendable_notes.nullifier,
-                        spendable_notes.position,
-                        tx.return_address
-                    FROM notes
-                    JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
-                    LEFT JOIN tx ON spendable_notes.tx_hash = tx.tx_hash
-                    WHERE notes.note_commitment = x'{}'",
-                    hex::encode(note_commitment.0.to_bytes())
-                ))?
-                .query_and_then((), |record| record.try_into())?
-                .next()
-                .transpose()
-        })
-        .await??
+        // Check if we already have the record
+        if let Some(record) = self
+            .backend
+            .note_by_commitment(self.wallet, note_commitment)
+            .await?
         {
             return Ok(record);
         }
@@ -445,20 +771,11 @@ impl Storage {
         // have the record, so that we can't miss it if we race a write.
         let mut rx = self.scanned_swaps_tx.subscribe();
 
-        let pool = self.pool.clone();
-
-        if let Some(record) = spawn_blocking(move || {
-            // Check if we already have the swap record
-            pool.get()?
-                .prepare(&format!(
-                    "SELECT * FROM swaps WHERE swaps.swap_commitment = x'{}'",
-                    hex::encode(swap_commitment.0.to_bytes())
-                ))?
-                .query_and_then((), |record| record.try_into())?
-                .next()
-                .transpose()
-        })
-        .await??
+        // Check if we already have the swap record
+        if let Some(record) = self
+            .backend
+            .swap_by_commitment(self.wallet, swap_commitment)
+            .await?
         {
             return Ok(record);
         }
@@ -497,18 +814,7 @@ impl Storage {
 
     /// Query for all unclaimed swaps.
     pub async fn unclaimed_swaps(&self) -> anyhow::Result<Vec<SwapRecord>> {
-        let pool = self.pool.clone();
-
-        let records = spawn_blocking(move || {
-            // Check if we already have the swap record
-            pool.get()?
-                .prepare("SELECT * FROM swaps WHERE swaps.height_claimed is NULL")?
-                .query_and_then((), |record| record.try_into())?
-                .collect::<anyhow::Result<Vec<_>>>()
-        })
-        .await??;
-
-        Ok(records)
+        self.backend.unclaimed_swaps(self.wallet).await
     }
 
     /// Query for a nullifier's status, optionally waiting until the nullifier is detected.
@@ -521,26 +827,12 @@ impl Storage {
         // can't miss it if we race a write.
         let mut rx = self.scanned_nullifiers_tx.subscribe();
 
-        // Clone the pool handle so that the returned future is 'static
-        let pool = self.pool.clone();
-
-        let nullifier_bytes = nullifier.0.to_bytes().to_vec();
-
         // Check if we already have the nullifier in the set of spent notes
-        if let Some(height_spent) = spawn_blocking(move || {
-            pool.get()?
-                .prepare_cached("SELECT height_spent FROM spendable_notes WHERE nullifier = ?1")?
-                .query_and_then([nullifier_bytes], |row| {
-                    let height_spent: Option<u64> = row.get("height_spent")?;
-                    anyhow::Ok(height_spent)
-                })?
-                .next()
-                .transpose()
-        })
-        .await??
+        if let Some(spent) = self
+            .backend
+            .nullifier_status(self.wallet, nullifier)
+            .await?
         {
-            let spent = height_spent.is_some();
-
             // If we're awaiting detection and the nullifier isn't yet spent, don't return just yet
             if !await_detection || spent {
                 return Ok(spent);
@@ -571,85 +863,123 @@ impl Storage {
             return Ok(Some(height.get()));
         }
 
-        let pool = self.pool.clone();
+        self.backend.sync_height(self.wallet).await
+    }
 
-        spawn_blocking(move || {
-            let height: Option<i64> = pool
-                .get()?
-                .prepare_cached("SELECT height FROM sync_height ORDER BY height DESC LIMIT 1")?
-                .query_row([], |row| row.get::<_, Option<i64>>(0))?;
+    /// The height of the first block scanned for the wallet.
+    pub async fn birthday(&self) -> anyhow::Result<u64> {
+        Ok(self.backend.birthday(self.wallet).await?.height)
+    }
 
-            anyhow::Ok(u64::try_from(height.ok_or_else(|| anyhow!("missing sync height"))?).ok())
-        })
-        .await?
+    /// The height below which blocks are replayed to rebuild the state commitment tree, rather
+    /// than scanned for the wallet.
+    pub async fn replay_height(&self) -> anyhow::Result<u64> {
+        self.backend.replay_height(self.wallet).await
     }
 
-    pub async fn app_params(&self) -> anyhow::Result<AppParameters> {
-        let pool = self.pool.clone();
+    /// Set the birthday of the wallet to `height`, discarding all of its records so that it is
+    /// synchronized again starting from that height.
+    ///
+    /// If `node` still has the state commitment tree as of the block before the birthday, sync
+    /// starts from there. Otherwise the blocks before the birthday are replayed from genesis to
+    /// build the tree, which is much faster than scanning them.
+    ///
+    /// This must not be called while a worker is synchronizing the wallet.
+    pub async fn set_birthday(&self, height: u64, node: Url) -> anyhow::Result<()> {
+        // A frontier at height 0 can't be requested, since that asks for the latest one; there's
+        // only one block to replay in that case anyway.
+        let frontier = if height > 1 {
+            fetch_sct_frontier(node, height - 1).await?
+        } else {
+            None
+        };
+        if frontier.is_none() && height > 1 {
+            tracing::info!(
+                height,
+                "node no longer has the sct frontier for the birthday, so blocks before it will be replayed"
+            );
+        }
 
-        spawn_blocking(move || {
-            let params_bytes = pool
-                .get()?
-                .prepare_cached("SELECT v FROM kv WHERE k IS 'app_params' LIMIT 1")?
-                .query_row([], |row| row.get::<_, Option<Vec<u8>>>("v"))?
-                .ok_or_else(|| anyhow!("missing app_params in kv table"))?;
+        self.backend
+            .set_birthday(self.wallet, &Birthday { height, frontier })
+            .await?;
+        self.uncommitted_height.lock().take();
 
-            AppParameters::decode(params_bytes.as_slice())
-        })
-        .await?
+        Ok(())
     }
 
-    pub async fn gas_prices(&self) -> anyhow::Result<GasPrices> {
-        let pool = self.pool.clone();
+    /// Roll back the records of the wallet from the block at `height` onwards, so that those
+    /// blocks are scanned again.
+    ///
+    /// The state commitment tree can't be rolled back, so it's rebuilt by replaying the blocks
+    /// from the wallet's birthday up to `height`, which doesn't require scanning them again.
+    ///
+    /// This must not be called while a worker is synchronizing the wallet.
+    pub async fn rescan_from(&self, height: u64) -> anyhow::Result<()> {
+        let next_height = self.last_sync_height().await?.map_or(0, |h| h + 1);
+        anyhow::ensure!(
+            height <= next_height,
+            "can't rescan from height {height}, since the next block to scan is {next_height}"
+        );
 
-        spawn_blocking(move || {
-            let bytes = pool
-                .get()?
-                .prepare_cached("SELECT v FROM kv WHERE k IS 'gas_prices' LIMIT 1")?
-                .query_row([], |row| row.get::<_, Option<Vec<u8>>>("v"))?
-                .ok_or_else(|| anyhow!("missing gas_prices in kv table"))?;
+        self.backend.rewind(self.wallet, height).await?;
+        self.uncommitted_height.lock().take();
 
-            GasPrices::decode(bytes.as_slice())
-        })
-        .await?
+        Ok(())
     }
 
-    pub async fn fmd_parameters(&self) -> anyhow::Result<fmd::Parameters> {
-        let pool = self.pool.clone();
+    /// The commitments of the wallet which are witnessed in its state commitment tree: those of
+    /// its unspent notes and unclaimed swaps, and of its spent delegation tokens, which are kept
+    /// for voting.
+    pub(crate) async fn witnessed_commitments(&self) -> anyhow::Result<BTreeSet<StateCommitment>> {
+        let mut commitments = BTreeSet::new();
+
+        for record in self.backend.notes(self.wallet, true, None).await? {
+            let witnessed = match record.height_spent {
+                None => true,
+                Some(_) => match self.backend.asset_by_id(&record.note.asset_id()).await? {
+                    Some(metadata) => DelegationToken::try_from(metadata).is_ok(),
+                    None => false,
+                },
+            };
+            if witnessed {
+                commitments.insert(record.note_commitment);
+            }
+        }
+        for swap in self.backend.unclaimed_swaps(self.wallet).await? {
+            commitments.insert(swap.swap_commitment);
+        }
 
-        spawn_blocking(move || {
-            let bytes = pool
-                .get()?
-                .prepare_cached("SELECT v FROM kv WHERE k IS 'fmd_params' LIMIT 1")?
-                .query_row([], |row| row.get::<_, Option<Vec<u8>>>("v"))?
-                .ok_or_else(|| anyhow!("missing fmd_params in kv table"))?;
+        Ok(commitments)
+    }
 
-            fmd::Parameters::decode(bytes.as_slice())
-        })
-        .await?
+    pub async fn app_params(&self) -> anyhow::Result<AppParameters> {
+        self.backend
+            .app_params()
+            .await?
+            .ok_or_else(|| anyhow!("missing app_params in kv table"))
     }
 
-    pub async fn full_viewing_key(&self) -> anyhow::Result<FullViewingKey> {
-        let pool = self.pool.clone();
+    pub async fn gas_prices(&self) -> anyhow::Result<GasPrices> {
+        self.backend
+            .gas_prices()
+            .await?
+            .ok_or_else(|| anyhow!("missing gas_prices in kv table"))
+    }
 
-        spawn_blocking(move || {
-            let bytes = pool
-                .get()?
-                .prepare_cached("SELECT v FROM kv WHERE k is 'fvk' LIMIT 1")?
-                .query_row([], |row| row.get::<_, Option<Vec<u8>>>("v"))?
-                .ok_or_else(|| anyhow!("missing fvk in kv table"))?;
+    pub async fn fmd_parameters(&self) -> anyhow::Result<fmd::Parameters> {
+        self.backend
+            .fmd_parameters()
+            .await?
+            .ok_or_else(|| anyhow!("missing fmd_params in kv table"))
+    }
 
-            FullViewingKey::decode(bytes.as_slice())
-        })
-        .await?
+    pub async fn full_viewing_key(&self) -> anyhow::Result<FullViewingKey> {
+        self.backend.full_viewing_key(self.wallet).await
     }
 
     pub async fn state_commitment_tree(&self) -> anyhow::Result<tct::Tree> {
-        let pool = self.pool.clone();
-        spawn_blocking(move || {
-            tct::Tree::from_reader(&mut TreeStore(&mut pool.get()?.transaction()?))
-        })
-        .await?
+        self.backend.state_commitment_tree(self.wallet).await
     }
 
     /// Returns a tuple of (block height, transaction hash) for all transactions in a given range of block heights.
@@ -658,26 +988,12 @@ impl Storage {
         start_height: Option<u64>,
         end_height: Option<u64>,
     ) -> anyhow::Result<Vec<(u64, Vec<u8>)>> {
-        let starting_block = start_height.unwrap_or(0) as i64;
-        let ending_block = end_height.unwrap_or(self.last_sync_height().await?.unwrap_or(0)) as i64;
-
-        let pool = self.pool.clone();
-
-        spawn_blocking(move || {
-            pool.get()?
-                .prepare_cached(
-                    "SELECT block_height, tx_hash
-                    FROM tx
-                    WHERE block_height BETWEEN ?1 AND ?2",
-                )?
-                .query_and_then([starting_block, ending_block], |row| {
-                    let block_height: u64 = row.get("block_height")?;
-                    let tx_hash: Vec<u8> = row.get("tx_hash")?;
-                    anyhow::Ok((block_height, tx_hash))
-                })?
-                .collect()
-        })
-        .await?
+        let starting_block = start_height.unwrap_or(0);
+        let ending_block = end_height.unwrap_or(self.last_sync_height().await?.unwrap_or(0));
+
+        self.backend
+            .transaction_hashes(self.wallet, starting_block, ending_block)
+            .await
     }
 
     /// Returns a tuple of (block height, transaction hash, transaction) for all transactions in a given range of block heights.
@@ -686,55 +1002,19 @@ impl Storage {
         start_height: Option<u64>,
         end_height: Option<u64>,
     ) -> anyhow::Result<Vec<(u64, Vec<u8>, Transaction)>> {
-        let starting_block = start_height.unwrap_or(0) as i64;
-        let ending_block = end_height.unwrap_or(self.last_sync_height().await?.unwrap_or(0)) as i64;
-
-        let pool = self.pool.clone();
-
-        spawn_blocking(move || {
-            pool.get()?
-                .prepare_cached(
-                    "SELECT block_height, tx_hash, tx_bytes
-                    FROM tx
-                    WHERE block_height BETWEEN ?1 AND ?2",
-                )?
-                .query_and_then([starting_block, ending_block], |row| {
-                    let block_height: u64 = row.get("block_height")?;
-                    let tx_hash: Vec<u8> = row.get("tx_hash")?;
-                    let tx_bytes: Vec<u8> = row.get("tx_bytes")?;
-                    let tx = Transaction::decode(tx_bytes.as_slice())?;
-                    anyhow::Ok((block_height, tx_hash, tx))
-                })?
-                .collect()
-        })
-        .await?
+        let starting_block = start_height.unwrap_or(0);
+        let ending_block = end_height.unwrap_or(self.last_sync_height().await?.unwrap_or(0));
+
+        self.backend
+            .transactions(self.wallet, starting_block, ending_block)
+            .await
     }
 
     pub async fn transaction_by_hash(
         &self,
         tx_hash: &[u8],
     ) -> anyhow::Result<Option<(u64, Transaction)>> {
-        let pool = self.pool.clone();
-        let tx_hash = tx_hash.to_vec();
-
-        spawn_blocking(move || {
-            if let Some((block_height, tx_bytes)) = pool
-                .get()?
-                .prepare_cached("SELECT block_height, tx_bytes FROM tx WHERE tx_hash = ?1")?
-                .query_row([tx_hash], |row| {
-                    let block_height: u64 = row.get("block_height")?;
-                    let tx_bytes: Vec<u8> = row.get("tx_bytes")?;
-                    Ok((block_height, tx_bytes))
-                })
-                .optional()?
-            {
-                let tx = Transaction::decode(tx_bytes.as_slice())?;
-                Ok(Some((block_height, tx)))
-            } else {
-                Ok(None)
-            }
-        })
-        .await?
+        self.backend.transaction_by_hash(self.wallet, tx_hash).await
     }
 
     // Query for a note by its note commitment, optionally waiting until the note is detected.
@@ -747,41 +1027,10 @@ impl Storage {
         // have the record, so that we can't miss it if we race a write.
         let mut rx = self.scanned_notes_tx.subscribe();
 
-        // Clone the pool handle so that the returned future is 'static
-        let pool = self.pool.clone();
-
-        let nullifier_bytes = nullifier.to_bytes().to_vec();
-
-        if let Some(record) = spawn_blocking(move || {
-            let record = pool
-                .get()?
-                .prepare(&format!(
-                    "SELECT
-                        notes.note_commitment,
-                        spendable_notes.height_created,
-                        notes.address,
-                        notes.amount,
-                        notes.asset_id,
-                        notes.rseed,
-                        spendable_notes.address_index,
-                        spendable_notes.source,
-                        spendable_notes.height_spent,
-                        spendable_notes.nullifier,
-                        spendable_notes.position,
-                        tx.return_address
-                    FROM notes
-                    JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
-                    LEFT JOIN tx ON spendable_notes.tx_hash = tx.tx_hash
-                    WHERE hex(spendable_notes.nullifier) = \"{}\"",
-                    hex::encode_upper(nullifier_bytes)
-                ))?
-                .query_and_then((), |row| SpendableNoteRecord::try_from(row))?
-                .next()
-                .transpose()?;
-
-            anyhow::Ok(record)
-        })
-        .await??
+        if let Some(record) = self
+            .backend
+            .note_by_nullifier(self.wallet, nullifier)
+            .await?
         {
             return Ok(record);
         }
@@ -819,59 +1068,17 @@ impl Storage {
     }
 
     pub async fn all_assets(&self) -> anyhow::Result<Vec<Metadata>> {
-        let pool = self.pool.clone();
-
-        spawn_blocking(move || {
-            pool.get()?
-                .prepare_cached("SELECT metadata FROM assets")?
-                .query_and_then([], |row| {
-                    let metadata_json = row.get::<_, String>("metadata")?;
-                    let denom_metadata = serde_json::from_str(&metadata_json)?;
-
-                    anyhow::Ok(denom_metadata)
-                })?
-                .collect()
-        })
-        .await?
+        self.backend.all_assets().await
     }
 
     pub async fn asset_by_id(&self, id: &Id) -> anyhow::Result<Option<Metadata>> {
-        let id = id.to_bytes().to_vec();
-
-        let pool = self.pool.clone();
-
-        spawn_blocking(move || {
-            pool.get()?
-                .prepare_cached("SELECT metadata FROM assets WHERE asset_id = ?1")?
-                .query_and_then([id], |row| {
-                    let metadata_json = row.get::<_, String>("metadata")?;
-                    let denom_metadata = serde_json::from_str(&metadata_json)?;
-                    anyhow::Ok(denom_metadata)
-                })?
-                .next()
-                .transpose()
-        })
-        .await?
+        self.backend.asset_by_id(id).await
     }
 
     // Get assets whose denoms match the given SQL LIKE pattern, with the `_` and `%` wildcards,
     // where `\` is the escape character.
     pub async fn assets_matching(&self, pattern: String) -> anyhow::Result<Vec<Metadata>> {
-        let pattern = pattern.to_owned();
-
-        let pool = self.pool.clone();
-
-        spawn_blocking(move || {
-            pool.get()?
-                .prepare_cached("SELECT metadata FROM assets WHERE denom LIKE ?1 ESCAPE '\\'")?
-                .query_and_then([pattern], |row| {
-                    let metadata_json = row.get::<_, String>("metadata")?;
-                    let denom_metadata = serde_json::from_str(&metadata_json)?;
-                    anyhow::Ok(denom_metadata)
-                })?
-                .collect()
-        })
-        .await?
+        self.backend.assets_matching(pattern).await
     }
 
     pub async fn notes(
@@ -881,31 +1088,6 @@ impl Storage {
         address_index: Option<penumbra_keys::keys::AddressIndex>,
         amount_to_spend: Option<Amount>,
     ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
-        // If set, return spent notes as well as unspent notes.
-        // bool include_spent = 2;
-        let spent_clause = match include_spent {
-            false => "NULL",
-            true => "height_spent",
-        };
-
-        // If set, only return notes with the specified asset id.
-        // core.crypto.v1.AssetId asset_id = 3;
-        let asset_clause = asset_id
-            .map(|id| format!("x'{}'", hex::encode(id.to_bytes())))
-            .unwrap_or_else(|| "asset_id".to_string());
-
-        // If set, only return notes with the specified address index.
-        // crypto.AddressIndex address_index = 4;
-        // This isn't what we want any more, we need to be indexing notes
-        // by *account*, not just by address index.
-        // For now, just do filtering in software.
-        /*
-        let address_clause = address_index
-            .map(|d| format!("x'{}'", hex::encode(d.to_bytes())))
-            .unwrap_or_else(|| "address_index".to_string());
-         */
-        let address_clause = "address_index".to_string();
-
         // If set, stop returning notes once the total exceeds this amount.
         //
         // Ignored if `asset_id` is unset or if `include_spent` is set.
@@ -914,74 +1096,48 @@ impl Storage {
         let amount_cutoff = (amount_to_spend.is_some()) && !(include_spent || asset_id.is_none());
         let mut amount_total = Amount::zero();
 
-        let pool = self.pool.clone();
-
-        spawn_blocking(move || {
-            let mut output: Vec<SpendableNoteRecord> = Vec::new();
-
-            for result in pool
-                .get()?
-                .prepare(&format!(
-                    "SELECT notes.note_commitment,
-                        spendable_notes.height_created,
-                        notes.address,
-                        notes.amount,
-                        notes.asset_id,
-                        notes.rseed,
-                        spendable_notes.address_index,
-                        spendable_notes.source,
-                        spendable_notes.height_spent,
-                        spendable_notes.nullifier,
-                        spendable_notes.position,
-                        tx.return_address
-                FROM notes
-                JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
-                LEFT JOIN tx ON spendable_notes.tx_hash = tx.tx_hash
-                WHERE spendable_notes.height_spent IS {spent_clause}
-                AND notes.asset_id IS {asset_clause}
-                AND spendable_notes.address_index IS {address_clause}"
-                ))?
-                .query_and_then((), |row| SpendableNoteRecord::try_from(row))?
-            {
-                let record = result?;
-
-                // Skip notes that don't match the account, since we're
-                // not doing account filtering in SQL as a temporary hack (see above)
-                if let Some(address_index) = address_index {
-                    if record.address_index.account != address_index.account {
-                        continue;
-                    }
+        let mut output: Vec<SpendableNoteRecord> = Vec::new();
+
+        for record in self
+            .backend
+            .notes(self.wallet, include_spent, asset_id)
+            .await?
+        {
+            // Skip notes that don't match the account, since we're
+            // not doing account filtering in SQL as a temporary hack
+            if let Some(address_index) = address_index {
+                if record.address_index.account != address_index.account {
+                    continue;
                 }
-                let amount = record.note.amount();
+            }
+            let amount = record.note.amount();
 
-                // Only display notes of value > 0
+            // Only display notes of value > 0
 
-                if amount.value() > 0 {
-                    output.push(record);
-                }
+            if amount.value() > 0 {
+                output.push(record);
+            }
 
-                // If we're tracking amounts, accumulate the value of the note
-                // and check if we should break out of the loop.
-                if amount_cutoff {
-                    // We know all the notes are of the same type, so adding raw quantities makes sense.
-                    amount_total += amount;
-                    if amount_total >= amount_to_spend.unwrap_or_default() {
-                        break;
-                    }
+            // If we're tracking amounts, accumulate the value of the note
+            // and check if we should break out of the loop.
+            if amount_cutoff {
+                // We know all the notes are of the same type, so adding raw quantities makes sense.
+                amount_total += amount;
+                if amount_total >= amount_to_spend.unwrap_or_default() {
+                    break;
                 }
             }
+        }
 
-            if amount_total < amount_to_spend.unwrap_or_default() {
-                anyhow::bail!(
-                    "requested amount of {} exceeds total of {}",
-                    amount_to_spend.unwrap_or_default(),
-                    amount_total
-                );
-            }
+        if amount_total < amount_to_spend.unwrap_or_default() {
+            anyhow::bail!(
+                "requested amount of {} exceeds total of {}",
+                amount_to_spend.unwrap_or_default(),
+                amount_total
+            );
+        }
 
-            anyhow::Ok(output)
-        })
-        .await?
+        Ok(output)
     }
 
     pub async fn notes_for_voting(
@@ -989,118 +1145,41 @@ impl Storage {
         address_index: Option<penumbra_keys::keys::AddressIndex>,
         votable_at_height: u64,
     ) -> anyhow::Result<Vec<(SpendableNoteRecord, IdentityKey)>> {
-        // If set, only return notes with the specified address index.
-        // crypto.AddressIndex address_index = 3;
-        let address_clause = address_index
-            .map(|d| format!("x'{}'", hex::encode(d.to_bytes())))
-            .unwrap_or_else(|| "address_index".to_string());
-
-        let pool = self.pool.clone();
-
-        spawn_blocking(move || {
-            let mut lock = pool.get()?;
-            let dbtx = lock.transaction()?;
-
-            let spendable_note_records: Vec<SpendableNoteRecord> = dbtx
-                .prepare(&format!(
-                    "SELECT notes.note_commitment,
-                        spendable_notes.height_created,
-                        notes.address,
-                        notes.amount,
-                        notes.asset_id,
-                        notes.rseed,
-                        spendable_notes.address_index,
-                        spendable_notes.source,
-                        spendable_notes.height_spent,
-                        spendable_notes.nullifier,
-                        spendable_notes.position
-                    FROM
-                        notes JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
-                    WHERE
-                        spendable_notes.address_index IS {address_clause}
-                        AND notes.asset_id IN (
-                            SELECT asset_id FROM assets WHERE denom LIKE '_delegation\\_%' ESCAPE '\\'
-                        )
-                        AND ((spendable_notes.height_spent IS NULL) OR (spendable_notes.height_spent > {votable_at_height}))
-                        AND (spendable_notes.height_created < {votable_at_height})
-                    ",
-                ))?
-                .query_and_then((), |row| row.try_into())?
-                .collect::<anyhow::Result<Vec<_>>>()?;
-
-            // TODO: this could be internalized into the SQL query in principle, but it's easier to
-            // do it this way; if it becomes slow, we can do it better
-            let mut results = Vec::new();
-            for record in spendable_note_records {
-                let asset_id = record.note.asset_id().to_bytes().to_vec();
-                let denom: String = dbtx.query_row_and_then(
-                    "SELECT denom FROM assets WHERE asset_id = ?1",
-                    [asset_id],
-                    |row| row.get("denom"),
-                )?;
-
-                let identity_key = DelegationToken::from_str(&denom)
-                    .context("invalid delegation token denom")?
-                    .validator();
-
-                results.push((record, identity_key));
-            }
-
-            Ok(results)
-        }).await?
+        self.backend
+            .notes_for_voting(self.wallet, address_index, votable_at_height)
+            .await
     }
 
     #[tracing::instrument(skip(self))]
-    pub async fn record_asset(&self, asset: Metadata) -> anyhow::Result<()> {
-        tracing::debug!(?asset);
-
-        let asset_id = asset.id().to_bytes().to_vec();
-        let denom = asset.base_denom().denom;
-        let metadata_json = serde_json::to_string(&asset)?;
-
-        let pool = self.pool.clone();
+    /// The outputs of the batch swaps seen while scanning blocks in the given range of heights,
+    /// which defaults to every block synced so far, ordered by height.
+    pub async fn batch_swap_outputs(
+        &self,
+        start_height: Option<u64>,
+        end_height: Option<u64>,
+    ) -> anyhow::Result<Vec<BatchSwapOutputData>> {
+        let starting_block = start_height.unwrap_or(0);
+        let ending_block = end_height.unwrap_or(self.last_sync_height().await?.unwrap_or(0));
 
-        spawn_blocking(move || {
-            pool.get()?
-                .execute(
-                    "INSERT OR REPLACE INTO assets (asset_id, denom, metadata) VALUES (?1, ?2, ?3)",
-                    (asset_id, denom, metadata_json),
-                )
-                .map_err(anyhow::Error::from)
-        })
-        .await??;
+        self.backend
+            .batch_swap_outputs(starting_block, ending_block)
+            .await
+    }
 
-        Ok(())
+    pub async fn record_asset(&self, asset: Metadata) -> anyhow::Result<()> {
+        tracing::debug!(?asset);
+        self.backend.record_asset(asset).await
     }
 
     pub async fn record_auction_with_state(
         &self,
         auction_id: AuctionId,
         auction_state: u64,
+        height: u64,
     ) -> anyhow::Result<()> {
-        let auction_id = auction_id.0.to_vec();
-
-        let pool = self.pool.clone();
-
-        spawn_blocking(move || {
-            let mut lock = pool.get()?;
-            let tx = lock.transaction()?;
-            tx.execute(
-                "INSERT OR IGNORE INTO auctions (auction_id, auction_state, note_commitment) VALUES (?1, ?2, NULL)",
-                (auction_id.clone(), auction_state),
-            )?;
-            tx.execute(
-                "UPDATE auctions SET auction_state = ?2 WHERE auction_id = ?1",
-                (auction_id, auction_state),
-            )
-                .map_err(anyhow::Error::from)?;
-
-            tx.commit()?;
-            Ok::<(), anyhow::Error>(())
-            })
-            .await??;
-
-        Ok(())
+        self.backend
+            .record_auction_with_state(self.wallet, auction_id, auction_state, height)
+            .await
     }
 
     pub async fn update_auction_with_note_commitment(
@@ -1108,22 +1187,9 @@ impl Storage {
         auction_id: AuctionId,
         note_commitment: StateCommitment,
     ) -> anyhow::Result<()> {
-        let auction_id = auction_id.0.to_vec();
-        let blob_nc = note_commitment.0.to_bytes().to_vec();
-
-        let pool = self.pool.clone();
-
-        spawn_blocking(move || {
-            pool.get()?
-                .execute(
-                    "UPDATE auctions SET (note_commitment) = ?1 WHERE auction_id = ?2",
-                    (blob_nc, auction_id),
-                )
-                .map_err(anyhow::Error::from)
-        })
-        .await??;
-
-        Ok(())
+        self.backend
+            .update_auction_with_note_commitment(self.wallet, auction_id, note_commitment)
+            .await
     }
 
     pub async fn fetch_auctions_by_account(
@@ -1131,100 +1197,26 @@ impl Storage {
         account_filter: Option<AddressIndex>,
         include_inactive: bool,
     ) -> anyhow::Result<Vec<(AuctionId, SpendableNoteRecord, u64 /* local seqnum */)>> {
-        let account_clause = account_filter
-            .map(|idx| {
-                format!(
-                    "AND spendable_notes.address_index = x'{}'",
-                    hex::encode(idx.to_bytes())
-                )
-            })
-            .unwrap_or_else(|| "".to_string());
-
-        let active_clause = if !include_inactive {
-            "AND auctions.auction_state = 0"
-        } else {
-            ""
-        };
-
-        let query = format!(
-            "SELECT auctions.auction_id, spendable_notes.*, notes.*, auctions.auction_state
-                 FROM auctions
-                 JOIN spendable_notes ON auctions.note_commitment = spendable_notes.note_commitment
-                 JOIN notes ON auctions.note_commitment = notes.note_commitment
-                 WHERE 1 = 1
-                 {account_clause}
-                 {active_clause}",
-            account_clause = account_clause,
-            active_clause = active_clause,
-        );
-
-        let pool = self.pool.clone();
-
-        spawn_blocking(move || {
-            let mut conn = pool.get()?;
-            let tx = conn.transaction()?;
-
-            let spendable_note_records: Vec<(AuctionId, SpendableNoteRecord, u64)> = tx
-                .prepare(&query)?
-                .query_and_then((), |row| {
-                    let raw_auction_id: Vec<u8> = row.get("auction_id")?;
-                    let array_auction_id: [u8; 32] = raw_auction_id
-                        .try_into()
-                        .map_err(|_| anyhow!("auction id must be 32 bytes"))?;
-                    let auction_id = AuctionId(array_auction_id);
-                    let spendable_note_record: SpendableNoteRecord = row.try_into()?;
-                    let local_seq: u64 = row.get("auction_state")?;
-                    Ok((auction_id, spendable_note_record, local_seq))
-                })?
-                .collect::<anyhow::Result<Vec<_>>>()?;
-
-            Ok(spendable_note_records)
-        })
-        .await?
+        self.backend
+            .fetch_auctions_by_account(self.wallet, account_filter, include_inactive)
+            .await
     }
 
-    pub async fn record_position(&self, position: Position) -> anyhow::Result<()> {
-        let position_id = position.id().0.to_vec();
-
-        let position_state = position.state.to_string();
-        let trading_pair = position.phi.pair.to_string();
-
-        let pool = self.pool.clone();
-
-        spawn_blocking(move || {
-            pool.get()?
-                .execute(
-                    "INSERT OR REPLACE INTO positions (position_id, position_state, trading_pair) VALUES (?1, ?2, ?3)",
-                    (position_id, position_state, trading_pair),
-                )
-                .map_err(anyhow::Error::from)
-        })
-            .await??;
-
-        Ok(())
+    pub async fn record_position(&self, position: Position, height: u64) -> anyhow::Result<()> {
+        self.backend
+            .record_position(self.wallet, position, height)
+            .await
     }
 
     pub async fn update_position(
         &self,
         position_id: position::Id,
         position_state: position::State,
+        height: u64,
     ) -> anyhow::Result<()> {
-        let position_id = position_id.0.to_vec();
-        let position_state = position_state.to_string();
-
-        let pool = self.pool.clone();
-
-        spawn_blocking(move || {
-            pool.get()?
-                .execute(
-                    "UPDATE positions SET (position_state) = ?1 WHERE position_id = ?2",
-                    (position_state, position_id),
-                )
-                .map_err(anyhow::Error::from)
-        })
-        .await??;
-
-        Ok(())
+        self.backend
+            .update_position(self.wallet, position_id, position_state, height)
+            .await
     }
 
     pub async fn record_empty_block(&self, height: u64) -> anyhow::Result<()> {
@@ -1245,41 +1237,8 @@ impl Storage {
         Ok(())
     }
 
-    fn record_note_inner(
-        dbtx: &r2d2_sqlite::rusqlite::Transaction<'_>,
-        note: &Note,
-    ) -> anyhow::Result<()> {
-        let note_commitment = note.commit().0.to_bytes().to_vec();
-        let address = note.address().to_vec();
-        let amount = u128::from(note.amount()).to_be_bytes().to_vec();
-        let asset_id = note.asset_id().to_bytes().to_vec();
-        let rseed = note.rseed().to_bytes().to_vec();
-
-        dbtx.execute(
-            "INSERT INTO notes (note_commitment, address, amount, asset_id, rseed)
-                VALUES (?1, ?2, ?3, ?4, ?5)
-                ON CONFLICT (note_commitment)
-                DO UPDATE SET
-                address = excluded.address,
-                amount = excluded.amount,
-                asset_id = excluded.asset_id,
-                rseed = excluded.rseed",
-            (note_commitment, address, amount, asset_id, rseed),
-        )?;
-
-        Ok(())
-    }
-
     pub async fn give_advice(&self, note: Note) -> anyhow::Result<()> {
-        let pool = self.pool.clone();
-        let mut lock = pool.get()?;
-        let dbtx = lock.transaction()?;
-
-        Storage::record_note_inner(&dbtx, &note)?;
-
-        dbtx.commit()?;
-
-        Ok(())
+        self.backend.give_advice(note).await
     }
 
     /// Return advice about note contents for use in scanning.
@@ -1295,46 +1254,9 @@ impl Storage {
             return Ok(BTreeMap::new());
         }
 
-        let pool = self.pool.clone();
-
-        // This query gives advice about notes which are known but which have not already been recorded as spendable,
-        // in part to avoid revealing information about which notes have been spent.
-
-        spawn_blocking(move || {
-            pool.get()?
-                .prepare(&format!(
-                    "SELECT notes.note_commitment,
-                        notes.address,
-                        notes.amount,
-                        notes.asset_id,
-                        notes.rseed
-                    FROM notes
-                    LEFT OUTER JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
-                    WHERE (spendable_notes.note_commitment IS NULL) AND (notes.note_commitment IN ({}))",
-                    note_commitments
-                        .iter()
-                        .map(|cm| format!("x'{}'", hex::encode(cm.0.to_bytes())))
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                ))?
-                .query_and_then((), |row| {
-                    let address = Address::try_from(row.get::<_, Vec<u8>>("address")?)?;
-                    let amount = row.get::<_, [u8; 16]>("amount")?;
-                    let amount_u128: u128 = u128::from_be_bytes(amount);
-                    let asset_id = asset::Id(Fq::from_bytes_checked(&row.get::<_, [u8; 32]>("asset_id")?).expect("asset id malformed"));
-                    let rseed = Rseed(row.get::<_, [u8; 32]>("rseed")?);
-                    let note = Note::from_parts(
-                        address,
-                        Value {
-                            amount: amount_u128.into(),
-                            asset_id,
-                        },
-                        rseed,
-                    )?;
-                    anyhow::Ok((note.commit(), note))
-                })?
-                .collect::<anyhow::Result<BTreeMap<_, _>>>()
-        }).await?
+        self.backend
+            .scan_advice(self.wallet, note_commitments)
+            .await
     }
 
     /// Filters for nullifiers whose notes we control
@@ -1346,25 +1268,9 @@ impl Storage {
             return Ok(Vec::new());
         }
 
-        let pool = self.pool.clone();
-
-        spawn_blocking(move || {
-            pool.get()?
-                .prepare(&format!(
-                    "SELECT nullifier FROM (SELECT nullifier FROM spendable_notes UNION SELECT nullifier FROM swaps UNION SELECT nullifier FROM tx_by_nullifier) WHERE nullifier IN ({})",
-                    nullifiers
-                        .iter()
-                        .map(|x| format!("x'{}'", hex::encode(x.0.to_bytes())))
-                        .collect::<Vec<String>>()
-                        .join(",")
-                ))?
-                .query_and_then((), |row| {
-                    let nullifier: Vec<u8> = row.get("nullifier")?;
-                    nullifier.as_slice().try_into()
-                })?
-                .collect()
-        })
-            .await?
+        self.backend
+            .filter_nullifiers(self.wallet, nullifiers)
+            .await
     }
 
     pub async fn record_block(
@@ -1392,12 +1298,6 @@ impl Storage {
             );
         }
 
-        let pool = self.pool.clone();
-        let uncommitted_height = self.uncommitted_height.clone();
-        let scanned_notes_tx = self.scanned_notes_tx.clone();
-        let scanned_nullifiers_tx = self.scanned_nullifiers_tx.clone();
-        let scanned_swaps_tx = self.scanned_swaps_tx.clone();
-
         let fvk = self.full_viewing_key().await?;
 
         // If the app parameters have changed, update them.
@@ -1415,268 +1315,103 @@ impl Storage {
             None
         };
 
-        // Cloning the SCT is cheap because it's a copy-on-write structure, so we move an owned copy
-        // into the spawned thread. This means that if for any reason the thread panics or throws an
-        // error, the changes to the SCT will be discarded, just like any changes to the database,
-        // so the two stay transactionally in sync, even in the case of errors. This would not be
-        // the case if we `std::mem::take` the SCT and move it into the spawned thread, because then
-        // an error would mean the updated version would never be put back, and the outcome would be
-        // a cleared SCT but a non-empty database.
-        let mut new_sct = sct.clone();
-
-        *sct = spawn_blocking(move || {
-            let mut lock = pool.get()?;
-            let mut dbtx = lock.transaction()?;
-
-            if let Some(params) = new_app_parameters {
-                let params_bytes = params.encode_to_vec();
-                // We expect app_params to be present already but may as well use an upsert
-                dbtx.execute(
-                    "INSERT INTO kv (k, v) VALUES ('app_params', ?1)
-                    ON CONFLICT(k) DO UPDATE SET v = excluded.v",
-                    [&params_bytes[..]],
-                )?;
-            }
-
-            // Insert new note records into storage
-            for note_record in filtered_block.new_notes.values() {
-                let note_commitment = note_record.note_commitment.0.to_bytes().to_vec();
-                let height_created = filtered_block.height as i64;
-                let address_index = note_record.address_index.to_bytes().to_vec();
-                let nullifier = note_record.nullifier.to_bytes().to_vec();
-                let position = (u64::from(note_record.position)) as i64;
-                let source = note_record.source.encode_to_vec();
-                // Check if the note is from a transaction, if so, include the tx hash (id)
-                let tx_hash = match note_record.source {
-                    CommitmentSource::Transaction { id } => id,
-                    _ => None,
-                };
-
-                // Record the inner note data in the notes table
-                Storage::record_note_inner(&dbtx, &note_record.note)?;
-
-                dbtx.execute(
-                    "INSERT INTO spendable_notes
-                    (note_commitment, nullifier, position, height_created, address_index, source, height_spent, tx_hash)
-                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, NULL, ?7)
-                    ON CONFLICT (note_commitment)
-                    DO UPDATE SET nullifier = excluded.nullifier,
-                    position = excluded.position,
-                    height_created = excluded.height_created,
-                    address_index = excluded.address_index,
-                    source = excluded.source,
-                    height_spent = excluded.height_spent,
-                    tx_hash = excluded.tx_hash",
-                    (
-                        &note_commitment,
-                        &nullifier,
-                        &position,
-                        &height_created,
-                        &address_index,
-                        &source,
-                        // height_spent is NULL because the note is newly discovered
-                        &tx_hash,
-                    ),
-                )?;
-            }
-
-            // Insert new swap records into storage
-            for swap in filtered_block.new_swaps.values() {
-                let swap_commitment = swap.swap_commitment.0.to_bytes().to_vec();
-                let swap_bytes = swap.swap.encode_to_vec();
-                let position = (u64::from(swap.position)) as i64;
-                let nullifier = swap.nullifier.to_bytes().to_vec();
-                let source = swap.source.encode_to_vec();
-                let output_data = swap.output_data.encode_to_vec();
-
-                dbtx.execute(
-                    "INSERT INTO swaps (swap_commitment, swap, position, nullifier, output_data, height_claimed, source)
-                    VALUES (?1, ?2, ?3, ?4, ?5, NULL, ?6)
-                    ON CONFLICT (swap_commitment)
-                    DO UPDATE SET swap = excluded.swap,
-                    position = excluded.position,
-                    nullifier = excluded.nullifier,
-                    output_data = excluded.output_data,
-                    height_claimed = excluded.height_claimed,
-                    source = excluded.source",
-                    (
-                        &swap_commitment,
-                        &swap_bytes,
-                        &position,
-                        &nullifier,
-                        &output_data,
-                        // height_claimed is NULL because the swap is newly discovered
-                        &source,
-                    ),
-                )?;
-            }
-
-            // Update any rows of the table with matching nullifiers to have height_spent
+        // If anyone is subscribed to the wallet's events, look up the notes being spent before
+        // they're marked as spent, and keep a copy of the transactions to send them.
+        let (spent_notes, recorded_transactions) = if self.events_tx.receiver_count() > 0 {
+            let mut spent_notes = Vec::new();
             for nullifier in &filtered_block.spent_nullifiers {
-                let height_spent = filtered_block.height as i64;
-                let nullifier_bytes = nullifier.to_bytes().to_vec();
-
-                let spent_commitment: Option<StateCommitment> = dbtx.prepare_cached(
-                    "UPDATE spendable_notes SET height_spent = ?1 WHERE nullifier = ?2 RETURNING note_commitment"
-                )?
-                    .query_and_then(
-                        (height_spent, &nullifier_bytes),
-                        |row| {
-                            let bytes: Vec<u8> = row.get("note_commitment")?;
-                            StateCommitment::try_from(&bytes[..]).context("invalid commitment bytes")
-                        },
-                    )?
-                    .next()
-                    .transpose()?;
-
-                let swap_commitment: Option<StateCommitment> = dbtx.prepare_cached(
-                    "UPDATE swaps SET height_claimed = ?1 WHERE nullifier = ?2 RETURNING swap_commitment"
-                )?
-                    .query_and_then(
-                        (height_spent, &nullifier_bytes),
-                        |row| {
-                            let bytes: Vec<u8> = row.get("swap_commitment")?;
-                            StateCommitment::try_from(&bytes[..]).context("invalid commitment bytes")
-                        },
-                    )?
-                    .next()
-                    .transpose()?;
-
-                // Check denom type
-                let spent_denom: String
-                    = dbtx.prepare_cached(
-                    "SELECT denom FROM assets
-                        WHERE asset_id ==
-                            (SELECT asset_id FROM notes
-                             WHERE note_commitment ==
-                                (SELECT note_commitment FROM spendable_notes WHERE nullifier = ?1))"
-                )?
-                    .query_and_then(
-                        [&nullifier_bytes],
-                        |row| row.get("denom"),
-                    )?
-                    .next()
-                    .transpose()?
-                    .unwrap_or("unknown".to_string());
-
-                // Mark spent notes as spent
-                if let Some(spent_commitment) = spent_commitment {
-                    tracing::debug!(?nullifier, ?spent_commitment, ?spent_denom, "detected spent note commitment");
-                    // Forget spent note commitments from the SCT unless they are delegation tokens,
-                    // which must be saved to allow voting on proposals that might or might not be
-                    // open presently
-
-                    if DelegationToken::from_str(&spent_denom).is_err() {
-                        tracing::debug!(?nullifier, ?spent_commitment, ?spent_denom, "forgetting spent note commitment");
-                        new_sct.forget(spent_commitment);
-                    }
-                };
-
-                // Mark spent swaps as spent
-                if let Some(spent_swap_commitment) = swap_commitment {
-                    tracing::debug!(?nullifier, ?spent_swap_commitment, "detected and forgetting spent swap commitment");
-                    new_sct.forget(spent_swap_commitment);
-                };
-            }
-
-            // Update SCT table with current SCT state
-            new_sct.to_writer(&mut TreeStore(&mut dbtx))?;
-
-            // Record all transactions
-            for transaction in transactions {
-                let tx_bytes = transaction.encode_to_vec();
-                // We have to create an explicit temporary borrow, because the sqlx api is bad (see above)
-                let tx_hash_owned = sha2::Sha256::digest(&tx_bytes);
-                let tx_hash = tx_hash_owned.as_slice();
-                let tx_block_height = filtered_block.height as i64;
-                let decrypted_memo = transaction.decrypt_memo(&fvk).ok();
-                let memo_text = decrypted_memo.clone().map_or(None,|x| Some(x.text().to_string()));
-                let return_address = decrypted_memo.map_or(None, |x| Some(x.return_address().to_vec()));
-
-                tracing::debug!(tx_hash = ?hex::encode(tx_hash), "recording extended transaction");
-
-                dbtx.execute(
-                    "INSERT OR IGNORE INTO tx (tx_hash, tx_bytes, block_height, return_address, memo_text) VALUES (?1, ?2, ?3, ?4, ?5)",
-                    (&tx_hash, &tx_bytes, tx_block_height, return_address, memo_text),
-                )?;
-
-                // Associate all of the spent nullifiers with the transaction by hash.
-                for nf in transaction.spent_nullifiers() {
-                    let nf_bytes = nf.0.to_bytes().to_vec();
-                    dbtx.execute(
-                        "INSERT OR IGNORE INTO tx_by_nullifier (nullifier, tx_hash) VALUES (?1, ?2)",
-                        (&nf_bytes, &tx_hash),
-                    )?;
-                }
-            }
-
-            // Update FMD parameters if they've changed.
-            if filtered_block.fmd_parameters.is_some() {
-                let fmd_parameters_bytes =
-                    &fmd::Parameters::encode_to_vec(&filtered_block.fmd_parameters.ok_or_else(|| anyhow::anyhow!("missing fmd parameters in filtered block"))?)[..];
-
-                dbtx.execute(
-                    "INSERT INTO kv (k, v) VALUES ('fmd_params', ?1)
-                    ON CONFLICT(k) DO UPDATE SET v = excluded.v",
-                    [&fmd_parameters_bytes],
-                )?;
+                spent_notes.push(
+                    self.backend
+                        .note_by_nullifier(self.wallet, *nullifier)
+                        .await?,
+                );
             }
+            (spent_notes, transactions.clone())
+        } else {
+            (Vec::new(), Vec::new())
+        };
 
-            // Update gas prices if they've changed.
-            if filtered_block.gas_prices.is_some() {
-                let gas_prices_bytes =
-                    &GasPrices::encode_to_vec(&filtered_block.gas_prices.ok_or_else(|| anyhow::anyhow!("missing gas prices in filtered block"))?)[..];
+        // Cloning the SCT is cheap because it's a copy-on-write structure, so we hand an owned
+        // copy to the backend. This means that if for any reason the backend panics or throws an
+        // error, the changes to the SCT will be discarded, just like any changes to the database,
+        // so the two stay transactionally in sync, even in the case of errors. This would not be
+        // the case if we `std::mem::take` the SCT and handed it over, because then an error would
+        // mean the updated version would never be put back, and the outcome would be a cleared
+        // SCT but a non-empty database.
+        *sct = self
+            .backend
+            .record_block(
+                self.wallet,
+                &fvk,
+                &filtered_block,
+                transactions,
+                new_app_parameters,
+                sct.clone(),
+            )
+            .await?;
 
-                dbtx.execute(
-                    "INSERT INTO kv (k, v) VALUES ('gas_prices', ?1)
-                    ON CONFLICT(k) DO UPDATE SET v = excluded.v",
-                    [&gas_prices_bytes],
-                )?;
-            }
+        // IMPORTANT: NO PANICS OR ERRORS PAST THIS POINT
+        // If there is a panic or error past this point, the database will be left in out of
+        // sync with the in-memory copy of the SCT, which means that it will become corrupted as
+        // synchronization continues.
 
-            // Record block height as latest synced height
-            let latest_sync_height = filtered_block.height as i64;
-            dbtx.execute("UPDATE sync_height SET height = ?1", [latest_sync_height])?;
+        // It's critical to reset the uncommitted height here, since we've just
+        // invalidated it by committing.
+        self.uncommitted_height.lock().take();
 
-            // Commit the changes to the database
-            dbtx.commit()?;
+        // Broadcast all committed note records to channel
+        // Done following the commit to avoid notifying of a new SpendableNoteRecord before it is actually committed to the database
 
-            // IMPORTANT: NO PANICS OR ERRORS PAST THIS POINT
-            // If there is a panic or error past this point, the database will be left in out of
-            // sync with the in-memory copy of the SCT, which means that it will become corrupted as
-            // synchronization continues.
+        for note_record in filtered_block.new_notes.values() {
+            // This will fail to be broadcast if there is no active receiver (such as on initial
+            // sync) The error is ignored, as this isn't a problem, because if there is no
+            // active receiver there is nothing to do
+            let _ = self.scanned_notes_tx.send(note_record.clone());
+        }
 
-            // It's critical to reset the uncommitted height here, since we've just
-            // invalidated it by committing.
-            uncommitted_height.lock().take();
+        for nullifier in filtered_block.spent_nullifiers.iter() {
+            // This will fail to be broadcast if there is no active receiver (such as on initial
+            // sync) The error is ignored, as this isn't a problem, because if there is no
+            // active receiver there is nothing to do
+            let _ = self.scanned_nullifiers_tx.send(*nullifier);
+        }
 
-            // Broadcast all committed note records to channel
-            // Done following tx.commit() to avoid notifying of a new SpendableNoteRecord before it is actually committed to the database
+        for swap_record in filtered_block.new_swaps.values() {
+            // This will fail to be broadcast if there is no active receiver (such as on initial
+            // sync) The error is ignored, as this isn't a problem, because if there is no
+            // active receiver there is nothing to do
+            let _ = self.scanned_swaps_tx.send(swap_record.clone());
+        }
 
-            for note_record in filtered_block.new_notes.values() {
-                // This will fail to be broadcast if there is no active receiver (such as on initial
-                // sync) The error is ignored, as this isn't a problem, because if there is no
-                // active receiver there is nothing to do
-                let _ = scanned_notes_tx.send(note_record.clone());
+        // Notify subscribers of the wallet's events, now that the changes are committed.
+        if self.events_tx.receiver_count() > 0 {
+            let height = filtered_block.height;
+            for note_record in filtered_block.new_notes.into_values() {
+                self.notify(height, WalletEventKind::NoteDetected(note_record));
             }
-
-            for nullifier in filtered_block.spent_nullifiers.iter() {
-                // This will fail to be broadcast if there is no active receiver (such as on initial
-                // sync) The error is ignored, as this isn't a problem, because if there is no
-                // active receiver there is nothing to do
-                let _ = scanned_nullifiers_tx.send(*nullifier);
+            for (i, nullifier) in filtered_block.spent_nullifiers.into_iter().enumerate() {
+                let note_record = spent_notes.get(i).cloned().flatten().map(|mut record| {
+                    record.height_spent = Some(height);
+                    record
+                });
+                self.notify(
+                    height,
+                    WalletEventKind::NoteSpent {
+                        nullifier,
+                        note_record,
+                    },
+                );
             }
-
-            for swap_record in filtered_block.new_swaps.values() {
-                // This will fail to be broadcast if there is no active rece∑iver (such as on initial
-                // sync) The error is ignored, as this isn't a problem, because if there is no
-                // active receiver there is nothing to do
-                let _ = scanned_swaps_tx.send(swap_record.clone());
+            for swap_record in filtered_block.new_swaps.into_values() {
+                self.notify(height, WalletEventKind::SwapDetected(swap_record));
             }
-
-            anyhow::Ok(new_sct)
-        })
-            .await??;
+            for transaction in recorded_transactions {
+                self.notify(
+                    height,
+                    WalletEventKind::TransactionRecorded(Box::new(transaction)),
+                );
+            }
+        }
 
         Ok(())
     }
@@ -1686,77 +1421,144 @@ impl Storage {
         position_state: Option<State>,
         trading_pair: Option<TradingPair>,
     ) -> anyhow::Result<Vec<position::Id>> {
-        let pool = self.pool.clone();
+        self.backend
+            .owned_position_ids(self.wallet, position_state, trading_pair)
+            .await
+    }
 
-        let state_clause = match position_state {
-            Some(state) => format!("position_state = \"{}\"", state),
-            None => "".to_string(),
-        };
+    /// The wallet's address book, with the labels of its contacts and of its own accounts.
+    pub async fn address_book(&self) -> anyhow::Result<AddressBook> {
+        Ok(AddressBook {
+            contacts: self
+                .backend
+                .contacts(self.wallet)
+                .await?
+                .into_iter()
+                .collect(),
+            accounts: self.backend.account_labels(self.wallet).await?,
+        })
+    }
 
-        let pair_clause = match trading_pair {
-            Some(pair) => format!("trading_pair = \"{}\"", pair),
-            None => "".to_string(),
-        };
+    /// Add a contact to the address book, or relabel an existing one.
+    pub async fn set_contact(&self, address: &Address, label: String) -> anyhow::Result<()> {
+        anyhow::ensure!(!label.trim().is_empty(), "contact labels must not be empty");
+        self.backend.set_contact(self.wallet, address, label).await
+    }
 
-        spawn_blocking(move || {
-            let mut q = "SELECT position_id FROM positions".to_string();
-            match (position_state.is_some(), trading_pair.is_some()) {
-                (true, true) => {
-                    q = q + " WHERE " + &state_clause + " AND " + &pair_clause;
-                }
-                (true, false) => {
-                    q = q + " WHERE " + &state_clause;
-                }
-                (false, true) => {
-                    q = q + " WHERE " + &pair_clause;
-                }
-                (false, false) => (),
-            };
+    /// Remove a contact from the address book, returning whether it was there.
+    pub async fn delete_contact(&self, address: &Address) -> anyhow::Result<bool> {
+        self.backend.delete_contact(self.wallet, address).await
+    }
 
-            pool.get()?
-                .prepare_cached(&q)?
-                .query_and_then([], |row| {
-                    let position_id: Vec<u8> = row.get("position_id")?;
-                    Ok(position::Id(position_id.as_slice().try_into()?))
-                })?
-                .collect()
-        })
-        .await?
+    /// Label one of the wallet's accounts, replacing any existing label.
+    pub async fn set_account_label(&self, account: u32, label: String) -> anyhow::Result<()> {
+        anyhow::ensure!(!label.trim().is_empty(), "account labels must not be empty");
+        self.backend
+            .set_account_label(self.wallet, account, label)
+            .await
+    }
+
+    /// Remove the label of one of the wallet's accounts, returning whether it had one.
+    pub async fn delete_account_label(&self, account: u32) -> anyhow::Result<bool> {
+        self.backend
+            .delete_account_label(self.wallet, account)
+            .await
     }
 
     pub async fn notes_by_sender(
         &self,
         return_address: &Address,
     ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
-        let pool = self.pool.clone();
-
-        let query = "SELECT notes.note_commitment,
-            spendable_notes.height_created,
-            notes.address,
-            notes.amount,
-            notes.asset_id,
-            notes.rseed,
-            spendable_notes.address_index,
-            spendable_notes.source,
-            spendable_notes.height_spent,
-            spendable_notes.nullifier,
-            spendable_notes.position
-            FROM notes
-            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
-            JOIN tx ON spendable_notes.tx_hash = tx.tx_hash
-            WHERE tx.return_address = ?1";
-
-        let return_address = return_address.to_vec();
-
-        let records = spawn_blocking(move || {
-            pool.get()?
-                .prepare(query)?
-                .query_and_then([return_address], |record| record.try_into())?
-                .collect::<anyhow::Result<Vec<_>>>()
-        })
-        .await??;
+        self.backend
+            .notes_by_sender(self.wallet, return_address)
+            .await
+    }
 
-        Ok(records)
+    /// The wallet's invoices, ordered by ID, with the notes received in payment of each.
+    pub async fn invoices(&self) -> anyhow::Result<Vec<Invoice>> {
+        let fvk = self.full_viewing_key().await?;
+        let mut invoices = Vec::new();
+        for (request, dedicated_address) in self.backend.invoices(self.wallet).await? {
+            let payments = if dedicated_address {
+                self.backend
+                    .notes_by_address(self.wallet, &request.address)
+                    .await?
+            } else if let Some(memo) = request.payment_memo() {
+                let account = fvk
+                    .address_index(&request.address)
+                    .context("invoice address does not belong to the wallet")?
+                    .account;
+                self.backend
+                    .notes_by_memo(self.wallet, memo)
+                    .await?
+                    .into_iter()
+                    // Change sent back to the wallet by its own transactions pays nothing.
+                    .filter(|note| {
+                        note.address_index.account == account
+                            && !note.return_address.as_ref().is_some_and(|sender| {
+                                fvk.address_index(&sender.address()).is_some()
+                            })
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            invoices.push(Invoice {
+                request,
+                dedicated_address,
+                payments,
+            });
+        }
+        Ok(invoices)
+    }
+
+    /// Record a new invoice for a payment request to one of the wallet's addresses.
+    ///
+    /// Payments are matched to an invoice with a dedicated address by the address alone, so it
+    /// must be a one-time address. Otherwise, they're matched by memo, so the request must have
+    /// a payment memo which no other such invoice has.
+    pub async fn record_invoice(
+        &self,
+        request: &PaymentRequest,
+        dedicated_address: bool,
+    ) -> anyhow::Result<()> {
+        let invoice_id = request
+            .invoice_id
+            .as_deref()
+            .filter(|id| !id.trim().is_empty())
+            .context("invoices must have an ID")?;
+        let index = self
+            .full_viewing_key()
+            .await?
+            .address_index(&request.address)
+            .context("invoices must be paid to an address of the wallet")?;
+
+        if dedicated_address {
+            anyhow::ensure!(
+                index.is_ephemeral(),
+                "dedicated invoice addresses must be one-time addresses"
+            );
+        } else {
+            let memo = request
+                .payment_memo()
+                .context("invoices without a dedicated address must have a memo")?;
+            for (other, dedicated) in self.backend.invoices(self.wallet).await? {
+                anyhow::ensure!(
+                    dedicated || other.payment_memo() != Some(memo),
+                    "another invoice is already paid with the memo {memo:?}"
+                );
+            }
+        }
+
+        self.backend
+            .record_invoice(self.wallet, invoice_id, request, dedicated_address)
+            .await
+            .with_context(|| format!("could not record invoice {invoice_id}"))
+    }
+
+    /// Remove an invoice, returning whether it was there.
+    pub async fn delete_invoice(&self, invoice_id: &str) -> anyhow::Result<bool> {
+        self.backend.delete_invoice(self.wallet, invoice_id).await
     }
 
     /// Get all transactions with a matching memo text. The `pattern` argument
@@ -1766,23 +1568,9 @@ impl Storage {
         &self,
         pattern: String,
     ) -> anyhow::Result<Vec<(u64, Vec<u8>, Transaction, String)>> {
-        let pattern = pattern.to_owned();
         tracing::trace!(?pattern, "searching for memos matching");
-        let pool = self.pool.clone();
-
-        spawn_blocking(move || {
-            pool.get()?
-                .prepare_cached("SELECT block_height, tx_hash, tx_bytes, memo_text FROM tx WHERE memo_text LIKE ?1 ESCAPE '\\'")?
-                .query_and_then([pattern], |row| {
-                    let block_height: u64 = row.get("block_height")?;
-                    let tx_hash: Vec<u8> = row.get("tx_hash")?;
-                    let tx_bytes: Vec<u8> = row.get("tx_bytes")?;
-                    let tx = Transaction::decode(tx_bytes.as_slice())?;
-                    let memo_text: String = row.get("memo_text")?;
-                    anyhow::Ok((block_height, tx_hash, tx, memo_text))
-                })?
-                .collect()
-        })
-        .await?
+        self.backend
+            .transactions_matching_memo(self.wallet, pattern)
+            .await
     }
 }
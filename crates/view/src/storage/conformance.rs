@@ -0,0 +1,573 @@
+//! Tests which every [`StorageBackend`] must pass, run by the tests of each backend.
+
+use std::{future::Future, sync::Arc};
+
+use anyhow::Context;
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID, STAKING_TOKEN_DENOM};
+use penumbra_dex::{lp::Reserves, DirectedTradingPair};
+use penumbra_keys::{
+    keys::{Bip44Path, SpendKey},
+    test_keys,
+};
+use penumbra_sct::CommitmentSource;
+use penumbra_tct::Witness;
+use rand_core::OsRng;
+
+use super::*;
+
+/// Run every test against a new, empty database made by `new_backend`.
+pub async fn run<B, F, Fut>(mut new_backend: F) -> anyhow::Result<()>
+where
+    B: StorageBackend,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = anyhow::Result<B>>,
+{
+    wallets_have_separate_state(new_backend().await?)
+        .await
+        .context("wallets_have_separate_state")?;
+    state_commitment_tree_persists(new_backend().await?)
+        .await
+        .context("state_commitment_tree_persists")?;
+    notes_are_recorded_and_spent(new_backend().await?)
+        .await
+        .context("notes_are_recorded_and_spent")?;
+    assets_are_shared_between_wallets(new_backend().await?)
+        .await
+        .context("assets_are_shared_between_wallets")?;
+    wallets_can_be_rewound(new_backend().await?)
+        .await
+        .context("wallets_can_be_rewound")?;
+    positions_are_rewound(new_backend().await?)
+        .await
+        .context("positions_are_rewound")?;
+    batch_swap_outputs_are_shared_between_wallets(new_backend().await?)
+        .await
+        .context("batch_swap_outputs_are_shared_between_wallets")?;
+    address_book_survives_rescan(new_backend().await?)
+        .await
+        .context("address_book_survives_rescan")?;
+    invoices_are_matched_to_payments(new_backend().await?)
+        .await
+        .context("invoices_are_matched_to_payments")?;
+    Ok(())
+}
+
+fn empty_block(height: u64) -> FilteredBlock {
+    FilteredBlock {
+        new_notes: BTreeMap::new(),
+        new_swaps: BTreeMap::new(),
+        spent_nullifiers: Vec::new(),
+        height,
+        fmd_parameters: None,
+        app_parameters_updated: false,
+        gas_prices: None,
+        batch_swap_outputs: Vec::new(),
+    }
+}
+
+/// A channel which is never used, since no block in these tests updates the app parameters.
+fn channel() -> tonic::transport::Channel {
+    tonic::transport::Channel::from_static("http://127.0.0.1:8080").connect_lazy()
+}
+
+fn other_fvk() -> anyhow::Result<FullViewingKey> {
+    Ok(
+        SpendKey::from_seed_phrase_bip44(test_keys::SEED_PHRASE.parse()?, &Bip44Path::new(1))
+            .full_viewing_key()
+            .clone(),
+    )
+}
+
+/// Add the test wallet to the database of the backend, returning a handle for it.
+async fn storage(backend: impl StorageBackend) -> anyhow::Result<Storage> {
+    let backend: Arc<dyn StorageBackend> = Arc::new(backend);
+    let wallet = backend.add_wallet(&test_keys::FULL_VIEWING_KEY).await?;
+    Ok(Storage::for_wallet(backend, wallet))
+}
+
+async fn wallets_have_separate_state(backend: impl StorageBackend) -> anyhow::Result<()> {
+    let other_fvk = other_fvk()?;
+    let storage = storage(backend).await?;
+    let other = storage.add_wallet(&other_fvk).await?;
+    assert_eq!(storage.wallet_id(), *test_keys::WALLET_ID);
+    assert_eq!(other.wallet_id(), other_fvk.wallet_id());
+    assert_eq!(other.full_viewing_key().await?, other_fvk);
+
+    // Scanning a block for one wallet leaves the other where it was.
+    let mut sct = tct::Tree::new();
+    sct.end_block()?;
+    storage
+        .record_block(empty_block(0), Vec::new(), &mut sct, channel())
+        .await?;
+    assert_eq!(storage.last_sync_height().await?, Some(0));
+    assert_eq!(other.last_sync_height().await?, None);
+    assert_eq!(storage.state_commitment_tree().await?, sct);
+    assert_eq!(other.state_commitment_tree().await?, tct::Tree::new());
+
+    // Adding a wallet which is already there keeps its state.
+    let again = other.add_wallet(&test_keys::FULL_VIEWING_KEY).await?;
+    assert_eq!(again.last_sync_height().await?, Some(0));
+
+    Ok(())
+}
+
+async fn state_commitment_tree_persists(backend: impl StorageBackend) -> anyhow::Result<()> {
+    let storage = storage(backend).await?;
+    let other = storage.add_wallet(&other_fvk()?).await?;
+    assert_eq!(storage.state_commitment_tree().await?, tct::Tree::new());
+
+    // Make some kind of tree:
+    let mut sct = tct::Tree::new();
+    sct.insert(Witness::Keep, StateCommitment::try_from([0; 32])?)?;
+    sct.end_block()?;
+    sct.insert(Witness::Forget, StateCommitment::try_from([1; 32])?)?;
+    sct.end_epoch()?;
+    sct.insert(Witness::Keep, StateCommitment::try_from([2; 32])?)?;
+    sct.end_block()?;
+    storage
+        .record_block(empty_block(0), Vec::new(), &mut sct, channel())
+        .await?;
+    assert_eq!(storage.state_commitment_tree().await?, sct);
+
+    // Changes to the tree are written incrementally, including forgetting commitments:
+    sct.insert(Witness::Keep, StateCommitment::try_from([3; 32])?)?;
+    sct.forget(StateCommitment::try_from([0; 32])?);
+    sct.end_block()?;
+    storage
+        .record_block(empty_block(1), Vec::new(), &mut sct, channel())
+        .await?;
+    assert_eq!(storage.state_commitment_tree().await?, sct);
+
+    // The tree of another wallet in the same database is unaffected:
+    assert_eq!(other.state_commitment_tree().await?, tct::Tree::new());
+
+    Ok(())
+}
+
+async fn notes_are_recorded_and_spent(backend: impl StorageBackend) -> anyhow::Result<()> {
+    let fvk = &*test_keys::FULL_VIEWING_KEY;
+    let storage = storage(backend).await?;
+    let other = storage.add_wallet(&other_fvk()?).await?;
+    storage.record_asset(STAKING_TOKEN_DENOM.clone()).await?;
+
+    let address_index = AddressIndex::from(0u32);
+    let note = Note::generate(
+        &mut OsRng,
+        &fvk.payment_address(address_index).0,
+        Value {
+            amount: 100u64.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        },
+    );
+    let note_commitment = note.commit();
+    let mut sct = tct::Tree::new();
+    let position = sct.insert(Witness::Keep, note_commitment)?;
+    let nullifier = Nullifier::derive(fvk.nullifier_key(), position, &note_commitment);
+    sct.end_block()?;
+
+    let mut block = empty_block(0);
+    block.new_notes.insert(
+        note_commitment,
+        SpendableNoteRecord {
+            note_commitment,
+            note: note.clone(),
+            address_index,
+            nullifier,
+            height_created: 0,
+            height_spent: None,
+            position,
+            source: CommitmentSource::Genesis,
+            return_address: None,
+        },
+    );
+    storage
+        .record_block(block, Vec::new(), &mut sct, channel())
+        .await?;
+
+    assert_eq!(
+        storage.balances(None, None).await?,
+        vec![BalanceEntry {
+            id: *STAKING_TOKEN_ASSET_ID,
+            amount: 100,
+            address_index,
+        }]
+    );
+    let record = storage.note_by_commitment(note_commitment, false).await?;
+    assert_eq!(record.nullifier, nullifier);
+    assert_eq!(record.position, position);
+    let record = storage.note_by_nullifier(nullifier, false).await?;
+    assert_eq!(record.note_commitment, note_commitment);
+    assert_eq!(storage.notes(false, None, None, None).await?.len(), 1);
+    assert!(!storage.nullifier_status(nullifier, false).await?);
+    let unknown = Nullifier::derive(fvk.nullifier_key(), 1u64.into(), &note_commitment);
+    assert_eq!(
+        storage.filter_nullifiers(vec![nullifier, unknown]).await?,
+        vec![nullifier]
+    );
+
+    // The note is known to the other wallet only as advice, since it can't spend it.
+    assert!(other.balances(None, None).await?.is_empty());
+    assert!(other
+        .note_by_commitment(note_commitment, false)
+        .await
+        .is_err());
+    assert!(storage.scan_advice(vec![note_commitment]).await?.is_empty());
+    assert_eq!(
+        other.scan_advice(vec![note_commitment]).await?,
+        BTreeMap::from([(note_commitment, note)])
+    );
+
+    // Spending the note marks it as spent, and forgets its commitment.
+    let mut block = empty_block(1);
+    block.spent_nullifiers.push(nullifier);
+    storage
+        .record_block(block, Vec::new(), &mut sct, channel())
+        .await?;
+
+    assert!(storage.nullifier_status(nullifier, false).await?);
+    assert!(storage.balances(None, None).await?.is_empty());
+    assert!(storage.notes(false, None, None, None).await?.is_empty());
+    let spent = storage.notes(true, None, None, None).await?;
+    assert_eq!(spent.len(), 1);
+    assert_eq!(spent[0].height_spent, Some(1));
+    assert!(sct.witness(note_commitment).is_none());
+    assert_eq!(storage.state_commitment_tree().await?, sct);
+
+    Ok(())
+}
+
+async fn assets_are_shared_between_wallets(backend: impl StorageBackend) -> anyhow::Result<()> {
+    let storage = storage(backend).await?;
+    let other = storage.add_wallet(&other_fvk()?).await?;
+    storage.record_asset(STAKING_TOKEN_DENOM.clone()).await?;
+    // Recording an asset again replaces it.
+    storage.record_asset(STAKING_TOKEN_DENOM.clone()).await?;
+
+    assert_eq!(
+        other.asset_by_id(&STAKING_TOKEN_ASSET_ID).await?,
+        Some(STAKING_TOKEN_DENOM.clone())
+    );
+    assert_eq!(
+        other.assets_matching("%penumbra".to_string()).await?,
+        vec![STAKING_TOKEN_DENOM.clone()]
+    );
+    assert!(other
+        .assets_matching("%delegation%".to_string())
+        .await?
+        .is_empty());
+    assert_eq!(other.all_assets().await?, vec![STAKING_TOKEN_DENOM.clone()]);
+
+    Ok(())
+}
+
+async fn wallets_can_be_rewound(backend: impl StorageBackend) -> anyhow::Result<()> {
+    let fvk = &*test_keys::FULL_VIEWING_KEY;
+    let storage = storage(backend).await?;
+    storage.record_asset(STAKING_TOKEN_DENOM.clone()).await?;
+    let address_index = AddressIndex::from(0u32);
+    let mut sct = tct::Tree::new();
+
+    // Receive a note in each of the first two blocks, spending the first in the second.
+    let mut nullifiers = Vec::new();
+    for height in 0..2 {
+        let note = Note::generate(
+            &mut OsRng,
+            &fvk.payment_address(address_index).0,
+            Value {
+                amount: 100u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+        );
+        let note_commitment = note.commit();
+        let position = sct.insert(Witness::Keep, note_commitment)?;
+        let nullifier = Nullifier::derive(fvk.nullifier_key(), position, &note_commitment);
+        sct.end_block()?;
+
+        let mut block = empty_block(height);
+        block.spent_nullifiers.extend(nullifiers.last().copied());
+        block.new_notes.insert(
+            note_commitment,
+            SpendableNoteRecord {
+                note_commitment,
+                note,
+                address_index,
+                nullifier,
+                height_created: height,
+                height_spent: None,
+                position,
+                source: CommitmentSource::Genesis,
+                return_address: None,
+            },
+        );
+        storage
+            .record_block(block, Vec::new(), &mut sct, channel())
+            .await?;
+        nullifiers.push(nullifier);
+    }
+    assert_eq!(storage.notes(true, None, None, None).await?.len(), 1);
+
+    // A rescan can't skip blocks which haven't been scanned yet.
+    assert!(storage.rescan_from(3).await.is_err());
+
+    // Rescanning the second block forgets what it did, and restarts sync from the birthday.
+    storage.rescan_from(1).await?;
+    let notes = storage.notes(true, None, None, None).await?;
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0].nullifier, nullifiers[0]);
+    assert_eq!(notes[0].height_spent, None);
+    assert!(storage
+        .note_by_nullifier(nullifiers[1], false)
+        .await
+        .is_err());
+    assert_eq!(storage.last_sync_height().await?, None);
+    assert_eq!(storage.replay_height().await?, 1);
+    assert_eq!(storage.state_commitment_tree().await?, tct::Tree::new());
+
+    // Setting a birthday with a frontier discards every record, and starts from the frontier.
+    let birthday = Birthday {
+        height: 5,
+        frontier: Some(sct.clone()),
+    };
+    storage
+        .backend
+        .set_birthday(storage.wallet, &birthday)
+        .await?;
+    assert_eq!(storage.birthday().await?, 5);
+    assert_eq!(storage.replay_height().await?, 5);
+    assert_eq!(storage.last_sync_height().await?, Some(4));
+    assert_eq!(storage.state_commitment_tree().await?, sct);
+    assert!(storage.notes(true, None, None, None).await?.is_empty());
+
+    // Rescanning after the birthday replays from the frontier, rather than from genesis.
+    storage.rescan_from(5).await?;
+    assert_eq!(storage.last_sync_height().await?, Some(4));
+    assert_eq!(storage.replay_height().await?, 5);
+
+    Ok(())
+}
+
+async fn positions_are_rewound(backend: impl StorageBackend) -> anyhow::Result<()> {
+    let storage = storage(backend).await?;
+    let mut sct = tct::Tree::new();
+    for height in 0..4 {
+        sct.end_block()?;
+        storage
+            .record_block(empty_block(height), Vec::new(), &mut sct, channel())
+            .await?;
+    }
+
+    let pair = DirectedTradingPair::new(*STAKING_TOKEN_ASSET_ID, asset::Id::try_from([1; 32])?);
+    let position = || {
+        Position::new(
+            OsRng,
+            pair,
+            30,
+            1u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: 100u64.into(),
+                r2: 0u64.into(),
+            },
+        )
+    };
+
+    // Open a position before the rewind height and close it after, and open another after.
+    let before = position();
+    let after = position();
+    storage.record_position(before.clone(), 1).await?;
+    storage
+        .update_position(before.id(), State::Closed, 3)
+        .await?;
+    storage.record_position(after.clone(), 3).await?;
+    assert_eq!(storage.owned_position_ids(None, None).await?.len(), 2);
+
+    // Rescanning forgets the position opened after the rewind height, and reopens the other.
+    storage.rescan_from(2).await?;
+    assert_eq!(
+        storage.owned_position_ids(None, None).await?,
+        vec![before.id()]
+    );
+    assert_eq!(
+        storage
+            .owned_position_ids(Some(State::Opened), None)
+            .await?,
+        vec![before.id()]
+    );
+
+    Ok(())
+}
+
+async fn batch_swap_outputs_are_shared_between_wallets(
+    backend: impl StorageBackend,
+) -> anyhow::Result<()> {
+    let storage = storage(backend).await?;
+    let other = storage.add_wallet(&other_fvk()?).await?;
+    let output_data = BatchSwapOutputData {
+        delta_1: 100u64.into(),
+        delta_2: 0u64.into(),
+        lambda_1: 0u64.into(),
+        lambda_2: 250u64.into(),
+        unfilled_1: 0u64.into(),
+        unfilled_2: 0u64.into(),
+        height: 0,
+        trading_pair: TradingPair::new(*STAKING_TOKEN_ASSET_ID, asset::Id(1u64.into())),
+        sct_position_prefix: 0u64.into(),
+    };
+
+    // Both wallets scan the block with the batch swap, but it's only recorded once.
+    for storage in [&storage, &other] {
+        let mut block = empty_block(0);
+        block.batch_swap_outputs.push(output_data);
+        storage
+            .record_block(block, Vec::new(), &mut tct::Tree::new(), channel())
+            .await?;
+    }
+
+    assert_eq!(
+        other.batch_swap_outputs(None, None).await?,
+        vec![output_data]
+    );
+    assert!(storage.batch_swap_outputs(Some(1), None).await?.is_empty());
+
+    Ok(())
+}
+
+async fn address_book_survives_rescan(backend: impl StorageBackend) -> anyhow::Result<()> {
+    let storage = storage(backend).await?;
+    let other = storage.add_wallet(&other_fvk()?).await?;
+    let (contact, _) = other_fvk()?.payment_address(0u32.into());
+
+    storage.set_contact(&contact, "alice".to_string()).await?;
+    storage.set_contact(&contact, "bob".to_string()).await?;
+    storage.set_account_label(1, "savings".to_string()).await?;
+    assert!(storage
+        .set_contact(&contact, " ".to_string())
+        .await
+        .is_err());
+    assert!(storage.set_account_label(2, String::new()).await.is_err());
+
+    let expected = AddressBook {
+        contacts: [(contact.clone(), "bob".to_string())].into(),
+        accounts: [(1, "savings".to_string())].into(),
+    };
+    assert_eq!(storage.address_book().await?, expected);
+    assert_eq!(other.address_book().await?, AddressBook::default());
+
+    // The address book isn't derived from the chain, so it's kept when the wallet is reset.
+    storage
+        .backend
+        .set_birthday(
+            storage.wallet,
+            &Birthday {
+                height: 0,
+                frontier: None,
+            },
+        )
+        .await?;
+    assert_eq!(storage.address_book().await?, expected);
+
+    assert!(storage.delete_contact(&contact).await?);
+    assert!(!storage.delete_contact(&contact).await?);
+    assert!(storage.delete_account_label(1).await?);
+    assert!(!storage.delete_account_label(1).await?);
+    assert_eq!(storage.address_book().await?, AddressBook::default());
+
+    Ok(())
+}
+
+async fn invoices_are_matched_to_payments(backend: impl StorageBackend) -> anyhow::Result<()> {
+    let fvk = &*test_keys::FULL_VIEWING_KEY;
+    let storage = storage(backend).await?;
+    let other = storage.add_wallet(&other_fvk()?).await?;
+
+    let (address, _) = fvk.ephemeral_address(OsRng, AddressIndex::from(0u32));
+    let requested = Value {
+        amount: 100u64.into(),
+        asset_id: *STAKING_TOKEN_ASSET_ID,
+    };
+    let dedicated = PaymentRequest {
+        values: vec![requested],
+        invoice_id: Some("dedicated".to_string()),
+        ..PaymentRequest::new(address.clone())
+    };
+    let by_memo = PaymentRequest {
+        memo: Some("order 1".to_string()),
+        invoice_id: Some("by-memo".to_string()),
+        ..PaymentRequest::new(fvk.payment_address(0u32.into()).0)
+    };
+    storage.record_invoice(&dedicated, true).await?;
+    storage.record_invoice(&by_memo, false).await?;
+
+    // Invoices need a unique ID, an address of the wallet, and a way to tell their payments apart.
+    assert!(storage.record_invoice(&dedicated, true).await.is_err());
+    assert!(other.record_invoice(&by_memo, false).await.is_err());
+    let same_memo = PaymentRequest {
+        invoice_id: Some("same-memo".to_string()),
+        ..by_memo.clone()
+    };
+    assert!(storage.record_invoice(&same_memo, false).await.is_err());
+    assert!(storage.record_invoice(&same_memo, true).await.is_err());
+    assert!(storage
+        .record_invoice(&PaymentRequest::new(address.clone()), true)
+        .await
+        .is_err());
+
+    // A note sent to the dedicated address pays its invoice.
+    let note = Note::generate(&mut OsRng, &address, requested);
+    let note_commitment = note.commit();
+    let mut sct = tct::Tree::new();
+    let position = sct.insert(Witness::Keep, note_commitment)?;
+    sct.end_block()?;
+    let mut block = empty_block(0);
+    block.new_notes.insert(
+        note_commitment,
+        SpendableNoteRecord {
+            note_commitment,
+            note,
+            address_index: fvk
+                .address_index(&address)
+                .context("address is the wallet's")?,
+            nullifier: Nullifier::derive(fvk.nullifier_key(), position, &note_commitment),
+            height_created: 0,
+            height_spent: None,
+            position,
+            source: CommitmentSource::Genesis,
+            return_address: None,
+        },
+    );
+    storage
+        .record_block(block, Vec::new(), &mut sct, channel())
+        .await?;
+
+    let invoices = storage.invoices().await?;
+    assert_eq!(invoices.len(), 2);
+    assert_eq!(invoices[0].request, by_memo);
+    assert!(invoices[0].payments.is_empty());
+    assert!(!invoices[0].is_paid());
+    assert_eq!(invoices[1].request, dedicated);
+    assert!(invoices[1].dedicated_address);
+    assert_eq!(invoices[1].payments.len(), 1);
+    assert!(invoices[1].is_paid());
+    assert!(other.invoices().await?.is_empty());
+
+    // Invoices aren't derived from the chain, so they're kept when the wallet is reset.
+    storage
+        .backend
+        .set_birthday(
+            storage.wallet,
+            &Birthday {
+                height: 0,
+                frontier: None,
+            },
+        )
+        .await?;
+    let invoices = storage.invoices().await?;
+    assert_eq!(invoices.len(), 2);
+    assert!(invoices[1].payments.is_empty());
+
+    assert!(storage.delete_invoice("dedicated").await?);
+    assert!(!storage.delete_invoice("dedicated").await?);
+    assert_eq!(storage.invoices().await?.len(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,1535 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use penumbra_auction::auction::AuctionId;
+use sha2::{Digest, Sha256};
+use sqlx::{
+    postgres::{PgConnectOptions, PgPoolOptions, PgRow},
+    PgPool, Postgres, Row, Transaction as DbTransaction,
+};
+
+use penumbra_app::params::AppParameters;
+use penumbra_asset::{asset, asset::Id, asset::Metadata, Value};
+use penumbra_dex::{
+    lp::position::{self, Position, State},
+    BatchSwapOutputData, TradingPair,
+};
+use penumbra_fee::GasPrices;
+use penumbra_keys::{
+    keys::{AddressIndex, WalletId},
+    Address, FullViewingKey, PaymentRequest,
+};
+use penumbra_proto::DomainType;
+use penumbra_sct::{CommitmentSource, Nullifier};
+use penumbra_shielded_pool::{fmd, note, Note, Rseed};
+use penumbra_stake::{DelegationToken, IdentityKey};
+use penumbra_tct as tct;
+use penumbra_transaction::Transaction;
+use sct::TreeStore;
+use tct::StateCommitment;
+
+use super::{BalanceEntry, Birthday, StorageBackend};
+use crate::{sync::FilteredBlock, SpendableNoteRecord, SwapRecord};
+
+mod sct;
+
+/// The hash of the schema for the database.
+static SCHEMA_HASH: Lazy<String> =
+    Lazy::new(|| hex::encode(Sha256::digest(include_str!("postgres/schema.sql"))));
+
+/// An arbitrary key for the advisory lock held while creating the schema, so that services
+/// connecting to a new database at the same time don't race to create it.
+const SCHEMA_LOCK: i64 = 0x7065_6e75_6d62_7261;
+
+/// The columns of a [`SpendableNoteRecord`], for queries joining `notes` and `spendable_notes`.
+const NOTE_RECORD_COLUMNS: &str = "notes.note_commitment,
+    spendable_notes.height_created,
+    notes.address,
+    notes.amount,
+    notes.asset_id,
+    notes.rseed,
+    spendable_notes.address_index,
+    spendable_notes.source,
+    spendable_notes.height_spent,
+    spendable_notes.nullifier,
+    spendable_notes.position";
+
+/// A [`StorageBackend`] keeping the view database in Postgres, so that it can be shared by
+/// several services querying it concurrently.
+#[derive(Clone)]
+pub struct PostgresBackend {
+    pool: PgPool,
+}
+
+impl PostgresBackend {
+    /// Connect to the database at `url`, creating the schema if the database is empty.
+    pub async fn connect(url: &str) -> anyhow::Result<Self> {
+        Self::connect_with(url.parse()?).await
+    }
+
+    /// Like [`Self::connect`], with the options for the connections to the database.
+    pub async fn connect_with(options: PgConnectOptions) -> anyhow::Result<Self> {
+        let pool = PgPoolOptions::new().connect_with(options).await?;
+
+        let mut dbtx = pool.begin().await?;
+        sqlx::query("SELECT pg_advisory_xact_lock($1)")
+            .bind(SCHEMA_LOCK)
+            .execute(dbtx.as_mut())
+            .await?;
+
+        let initialized: bool = sqlx::query_scalar(
+            "SELECT EXISTS (
+                SELECT FROM information_schema.tables
+                WHERE table_schema = current_schema() AND table_name = 'schema_hash'
+            )",
+        )
+        .fetch_one(dbtx.as_mut())
+        .await?;
+
+        if initialized {
+            // Check the version of the software used when first initializing this database.
+            // If it doesn't match the current version, we should report the error to the user.
+            let actual_schema_hash: String =
+                sqlx::query_scalar("SELECT schema_hash FROM schema_hash")
+                    .fetch_one(dbtx.as_mut())
+                    .await
+                    .context("failed to query database schema version")?;
+
+            if actual_schema_hash != *SCHEMA_HASH {
+                let database_client_version: String =
+                    sqlx::query_scalar("SELECT client_version FROM client_version")
+                        .fetch_one(dbtx.as_mut())
+                        .await
+                        .context("failed to query client version")?;
+
+                anyhow::bail!(
+                    "can't load view database created by client version {} using client version {}: they have different schemata, so the database needs to be reset and resynchronized",
+                    database_client_version,
+                    env!("CARGO_PKG_VERSION"),
+                );
+            }
+        } else {
+            tracing::debug!("creating view database schema");
+            sqlx::raw_sql(include_str!("postgres/schema.sql"))
+                .execute(dbtx.as_mut())
+                .await?;
+            sqlx::query("INSERT INTO schema_hash (schema_hash) VALUES ($1)")
+                .bind(&*SCHEMA_HASH)
+                .execute(dbtx.as_mut())
+                .await?;
+            sqlx::query("INSERT INTO client_version (client_version) VALUES ($1)")
+                .bind(env!("CARGO_PKG_VERSION"))
+                .execute(dbtx.as_mut())
+                .await?;
+        }
+
+        dbtx.commit().await?;
+
+        Ok(Self { pool })
+    }
+
+    async fn record_note_inner(
+        dbtx: &mut DbTransaction<'_, Postgres>,
+        note: &Note,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "INSERT INTO notes (note_commitment, address, amount, asset_id, rseed)
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (note_commitment)
+                DO UPDATE SET
+                address = excluded.address,
+                amount = excluded.amount,
+                asset_id = excluded.asset_id,
+                rseed = excluded.rseed",
+        )
+        .bind(note.commit().0.to_bytes().to_vec())
+        .bind(note.address().to_vec())
+        .bind(u128::from(note.amount()).to_be_bytes().to_vec())
+        .bind(note.asset_id().to_bytes().to_vec())
+        .bind(note.rseed().to_bytes().to_vec())
+        .execute(dbtx.as_mut())
+        .await?;
+
+        Ok(())
+    }
+
+    /// Reset the state commitment tree and sync height of a wallet to its birthday.
+    async fn restart_from_birthday(
+        dbtx: &mut DbTransaction<'_, Postgres>,
+        wallet: WalletId,
+    ) -> anyhow::Result<()> {
+        let row =
+            sqlx::query("SELECT birthday, birthday_frontier FROM wallets WHERE wallet_id = $1")
+                .bind(&wallet.0[..])
+                .fetch_one(dbtx.as_mut())
+                .await?;
+        let birthday: i64 = row.try_get("birthday")?;
+        let frontier: Option<Vec<u8>> = row.try_get("birthday_frontier")?;
+
+        for table in [
+            "sct_position",
+            "sct_forgotten",
+            "sct_hashes",
+            "sct_commitments",
+        ] {
+            sqlx::query(&format!("DELETE FROM {table} WHERE wallet_id = $1"))
+                .bind(&wallet.0[..])
+                .execute(dbtx.as_mut())
+                .await?;
+        }
+        sqlx::query("INSERT INTO sct_position (wallet_id, position) VALUES ($1, 0)")
+            .bind(&wallet.0[..])
+            .execute(dbtx.as_mut())
+            .await?;
+        sqlx::query("INSERT INTO sct_forgotten (wallet_id, forgotten) VALUES ($1, 0)")
+            .bind(&wallet.0[..])
+            .execute(dbtx.as_mut())
+            .await?;
+
+        // Without a frontier, the wallet starts again from genesis.
+        let sync_height = match frontier {
+            Some(bytes) => {
+                let frontier: tct::Tree = bincode::deserialize(&bytes)?;
+                frontier
+                    .to_async_writer(&mut TreeStore(&mut *dbtx, wallet))
+                    .await?;
+                birthday - 1
+            }
+            None => -1,
+        };
+        sqlx::query("UPDATE wallets SET sync_height = $1 WHERE wallet_id = $2")
+            .bind(sync_height)
+            .bind(&wallet.0[..])
+            .execute(dbtx.as_mut())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_kv(
+        dbtx: &mut DbTransaction<'_, Postgres>,
+        key: &str,
+        value: Vec<u8>,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "INSERT INTO kv (k, v) VALUES ($1, $2)
+            ON CONFLICT (k) DO UPDATE SET v = excluded.v",
+        )
+        .bind(key)
+        .bind(value)
+        .execute(dbtx.as_mut())
+        .await?;
+
+        Ok(())
+    }
+
+    /// Read one of the protobuf-encoded values in the kv table.
+    async fn kv<T: DomainType>(&self, key: &str) -> anyhow::Result<Option<T>>
+    where
+        anyhow::Error: From<<T as TryFrom<T::Proto>>::Error>,
+    {
+        let bytes: Option<Vec<u8>> = sqlx::query_scalar("SELECT v FROM kv WHERE k = $1")
+            .bind(key)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        bytes.map(|bytes| T::decode(bytes.as_slice())).transpose()
+    }
+}
+
+fn note_from_row(row: &PgRow) -> anyhow::Result<Note> {
+    let address: Vec<u8> = row.try_get("address")?;
+    let amount: Vec<u8> = row.try_get("amount")?;
+    let asset_id: Vec<u8> = row.try_get("asset_id")?;
+    let rseed: Vec<u8> = row.try_get("rseed")?;
+    Ok(Note::from_parts(
+        Address::try_from(address)?,
+        Value {
+            amount: u128::from_be_bytes(amount[..].try_into()?).into(),
+            asset_id: asset_id[..].try_into()?,
+        },
+        Rseed(rseed[..].try_into()?),
+    )?)
+}
+
+fn metadata_from_row(row: PgRow) -> anyhow::Result<Metadata> {
+    let metadata_json: String = row.try_get("metadata")?;
+    Ok(serde_json::from_str(&metadata_json)?)
+}
+
+fn transaction_from_row(row: &PgRow) -> anyhow::Result<(u64, Vec<u8>, Transaction)> {
+    let block_height: i64 = row.try_get("block_height")?;
+    let tx_hash: Vec<u8> = row.try_get("tx_hash")?;
+    let tx_bytes: Vec<u8> = row.try_get("tx_bytes")?;
+    let tx = Transaction::decode(tx_bytes.as_slice())?;
+    Ok((block_height as u64, tx_hash, tx))
+}
+
+#[async_trait]
+impl StorageBackend for PostgresBackend {
+    async fn wallets(&self) -> anyhow::Result<Vec<WalletId>> {
+        sqlx::query_scalar::<_, Vec<u8>>("SELECT wallet_id FROM wallets ORDER BY wallet_id")
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(|bytes| {
+                Ok(WalletId(
+                    bytes
+                        .try_into()
+                        .map_err(|_| anyhow!("wallet id must be 32 bytes"))?,
+                ))
+            })
+            .collect()
+    }
+
+    async fn add_wallet(&self, fvk: &FullViewingKey) -> anyhow::Result<WalletId> {
+        let wallet = fvk.wallet_id();
+        let mut dbtx = self.pool.begin().await?;
+
+        // Insert -1 as a signaling value for pre-genesis.
+        let inserted = sqlx::query(
+            "INSERT INTO wallets (wallet_id, fvk, sync_height) VALUES ($1, $2, -1)
+            ON CONFLICT DO NOTHING",
+        )
+        .bind(&wallet.0[..])
+        .bind(fvk.encode_to_vec())
+        .execute(dbtx.as_mut())
+        .await?
+        .rows_affected();
+
+        if inserted > 0 {
+            sqlx::query("INSERT INTO sct_position (wallet_id, position) VALUES ($1, 0)")
+                .bind(&wallet.0[..])
+                .execute(dbtx.as_mut())
+                .await?;
+            sqlx::query("INSERT INTO sct_forgotten (wallet_id, forgotten) VALUES ($1, 0)")
+                .bind(&wallet.0[..])
+                .execute(dbtx.as_mut())
+                .await?;
+        }
+
+        dbtx.commit().await?;
+        Ok(wallet)
+    }
+
+    async fn full_viewing_key(&self, wallet: WalletId) -> anyhow::Result<FullViewingKey> {
+        let bytes: Vec<u8> = sqlx::query_scalar("SELECT fvk FROM wallets WHERE wallet_id = $1")
+            .bind(&wallet.0[..])
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| anyhow!("missing fvk in wallets table"))?;
+
+        FullViewingKey::decode(bytes.as_slice())
+    }
+
+    async fn sync_height(&self, wallet: WalletId) -> anyhow::Result<Option<u64>> {
+        let height: i64 =
+            sqlx::query_scalar("SELECT sync_height FROM wallets WHERE wallet_id = $1")
+                .bind(&wallet.0[..])
+                .fetch_optional(&self.pool)
+                .await?
+                .ok_or_else(|| anyhow!("missing sync height"))?;
+
+        Ok(u64::try_from(height).ok())
+    }
+
+    async fn birthday(&self, wallet: WalletId) -> anyhow::Result<Birthday> {
+        let row =
+            sqlx::query("SELECT birthday, birthday_frontier FROM wallets WHERE wallet_id = $1")
+                .bind(&wallet.0[..])
+                .fetch_one(&self.pool)
+                .await?;
+        let height: i64 = row.try_get("birthday")?;
+        let frontier: Option<Vec<u8>> = row.try_get("birthday_frontier")?;
+
+        Ok(Birthday {
+            height: height as u64,
+            frontier: frontier
+                .map(|bytes| bincode::deserialize(&bytes))
+                .transpose()?,
+        })
+    }
+
+    async fn replay_height(&self, wallet: WalletId) -> anyhow::Result<u64> {
+        let height: i64 =
+            sqlx::query_scalar("SELECT replay_height FROM wallets WHERE wallet_id = $1")
+                .bind(&wallet.0[..])
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok(height as u64)
+    }
+
+    async fn set_birthday(&self, wallet: WalletId, birthday: &Birthday) -> anyhow::Result<()> {
+        let frontier = birthday
+            .frontier
+            .as_ref()
+            .map(bincode::serialize)
+            .transpose()?;
+        let mut dbtx = self.pool.begin().await?;
+
+        for table in [
+            "spendable_notes",
+            "swaps",
+            "tx",
+            "tx_by_nullifier",
+            "positions",
+            "position_updates",
+            "auctions",
+            "auction_updates",
+        ] {
+            sqlx::query(&format!("DELETE FROM {table} WHERE wallet_id = $1"))
+                .bind(&wallet.0[..])
+                .execute(dbtx.as_mut())
+                .await?;
+        }
+        sqlx::query(
+            "UPDATE wallets SET birthday = $1, birthday_frontier = $2, replay_height = $1
+            WHERE wallet_id = $3",
+        )
+        .bind(birthday.height as i64)
+        .bind(frontier)
+        .bind(&wallet.0[..])
+        .execute(dbtx.as_mut())
+        .await?;
+        Self::restart_from_birthday(&mut dbtx, wallet).await?;
+
+        dbtx.commit().await?;
+        Ok(())
+    }
+
+    async fn rewind(&self, wallet: WalletId, height: u64) -> anyhow::Result<()> {
+        let height = height as i64;
+        let mut dbtx = self.pool.begin().await?;
+
+        for statement in [
+            "DELETE FROM spendable_notes WHERE wallet_id = $1 AND height_created >= $2",
+            "UPDATE spendable_notes SET height_spent = NULL
+            WHERE wallet_id = $1 AND height_spent >= $2",
+            "DELETE FROM swaps WHERE wallet_id = $1 AND height_created >= $2",
+            "UPDATE swaps SET height_claimed = NULL WHERE wallet_id = $1 AND height_claimed >= $2",
+            "DELETE FROM tx_by_nullifier WHERE wallet_id = $1 AND tx_hash IN
+            (SELECT tx_hash FROM tx WHERE wallet_id = $1 AND block_height >= $2)",
+            "DELETE FROM tx WHERE wallet_id = $1 AND block_height >= $2",
+            "DELETE FROM position_updates WHERE wallet_id = $1 AND height >= $2",
+            "DELETE FROM auction_updates WHERE wallet_id = $1 AND height >= $2",
+            // The blocks before the birthday were never scanned, so they are still replayed.
+            "UPDATE wallets SET replay_height = GREATEST(birthday, $2) WHERE wallet_id = $1",
+        ] {
+            sqlx::query(statement)
+                .bind(&wallet.0[..])
+                .bind(height)
+                .execute(dbtx.as_mut())
+                .await?;
+        }
+        // Positions and auctions go back to their latest remaining state, and are forgotten if
+        // they have none, while auctions forget the notes which were rewound.
+        for statement in [
+            "DELETE FROM positions WHERE wallet_id = $1 AND position_id NOT IN
+            (SELECT position_id FROM position_updates WHERE wallet_id = $1)",
+            "UPDATE positions SET position_state =
+            (SELECT position_state FROM position_updates
+            WHERE position_updates.wallet_id = positions.wallet_id
+            AND position_updates.position_id = positions.position_id
+            ORDER BY height DESC, seq DESC LIMIT 1)
+            WHERE wallet_id = $1",
+            "DELETE FROM auctions WHERE wallet_id = $1 AND auction_id NOT IN
+            (SELECT auction_id FROM auction_updates WHERE wallet_id = $1)",
+            "UPDATE auctions SET auction_state =
+            (SELECT auction_state FROM auction_updates
+            WHERE auction_updates.wallet_id = auctions.wallet_id
+            AND auction_updates.auction_id = auctions.auction_id
+            ORDER BY height DESC, seq DESC LIMIT 1)
+            WHERE wallet_id = $1",
+            "UPDATE auctions SET note_commitment = NULL
+            WHERE wallet_id = $1 AND note_commitment NOT IN
+            (SELECT note_commitment FROM spendable_notes WHERE wallet_id = $1)",
+        ] {
+            sqlx::query(statement)
+                .bind(&wallet.0[..])
+                .execute(dbtx.as_mut())
+                .await?;
+        }
+        Self::restart_from_birthday(&mut dbtx, wallet).await?;
+
+        dbtx.commit().await?;
+        Ok(())
+    }
+
+    async fn app_params(&self) -> anyhow::Result<Option<AppParameters>> {
+        self.kv("app_params").await
+    }
+
+    async fn record_app_params(&self, params: &AppParameters) -> anyhow::Result<()> {
+        let mut dbtx = self.pool.begin().await?;
+        Self::set_kv(&mut dbtx, "app_params", params.encode_to_vec()).await?;
+        dbtx.commit().await?;
+        Ok(())
+    }
+
+    async fn gas_prices(&self) -> anyhow::Result<Option<GasPrices>> {
+        self.kv("gas_prices").await
+    }
+
+    async fn fmd_parameters(&self) -> anyhow::Result<Option<fmd::Parameters>> {
+        self.kv("fmd_params").await
+    }
+
+    async fn state_commitment_tree(&self, wallet: WalletId) -> anyhow::Result<tct::Tree> {
+        let mut dbtx = self.pool.begin().await?;
+        let tree = tct::Tree::from_async_reader(&mut TreeStore(&mut dbtx, wallet)).await?;
+        dbtx.commit().await?;
+        Ok(tree)
+    }
+
+    async fn unspent_balances(&self, wallet: WalletId) -> anyhow::Result<Vec<BalanceEntry>> {
+        sqlx::query(
+            "SELECT notes.asset_id, notes.amount, spendable_notes.address_index
+            FROM    notes
+            JOIN    spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            WHERE   spendable_notes.wallet_id = $1
+            AND     spendable_notes.height_spent IS NULL",
+        )
+        .bind(&wallet.0[..])
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|row| {
+            let asset_id: Vec<u8> = row.try_get("asset_id")?;
+            let amount: Vec<u8> = row.try_get("amount")?;
+            let address_index: Vec<u8> = row.try_get("address_index")?;
+
+            Ok(BalanceEntry {
+                id: Id::try_from(asset_id.as_slice())?,
+                amount: u128::from_be_bytes(
+                    amount
+                        .as_slice()
+                        .try_into()
+                        .context("amount slice of incorrect length")?,
+                ),
+                address_index: AddressIndex::try_from(address_index.as_slice())?,
+            })
+        })
+        .collect()
+    }
+
+    async fn note_by_commitment(
+        &self,
+        wallet: WalletId,
+        note_commitment: StateCommitment,
+    ) -> anyhow::Result<Option<SpendableNoteRecord>> {
+        sqlx::query(&format!(
+            "SELECT {NOTE_RECORD_COLUMNS}, tx.return_address
+            FROM notes
+            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            LEFT JOIN tx ON spendable_notes.wallet_id = tx.wallet_id
+                AND spendable_notes.tx_hash = tx.tx_hash
+            WHERE spendable_notes.wallet_id = $1
+            AND notes.note_commitment = $2"
+        ))
+        .bind(&wallet.0[..])
+        .bind(note_commitment.0.to_bytes().to_vec())
+        .fetch_optional(&self.pool)
+        .await?
+        .as_ref()
+        .map(SpendableNoteRecord::try_from)
+        .transpose()
+    }
+
+    async fn note_by_nullifier(
+        &self,
+        wallet: WalletId,
+        nullifier: Nullifier,
+    ) -> anyhow::Result<Option<SpendableNoteRecord>> {
+        sqlx::query(&format!(
+            "SELECT {NOTE_RECORD_COLUMNS}, tx.return_address
+            FROM notes
+            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            LEFT JOIN tx ON spendable_notes.wallet_id = tx.wallet_id
+                AND spendable_notes.tx_hash = tx.tx_hash
+            WHERE spendable_notes.wallet_id = $1
+            AND spendable_notes.nullifier = $2"
+        ))
+        .bind(&wallet.0[..])
+        .bind(nullifier.to_bytes().to_vec())
+        .fetch_optional(&self.pool)
+        .await?
+        .as_ref()
+        .map(SpendableNoteRecord::try_from)
+        .transpose()
+    }
+
+    async fn notes(
+        &self,
+        wallet: WalletId,
+        include_spent: bool,
+        asset_id: Option<asset::Id>,
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
+        sqlx::query(&format!(
+            "SELECT {NOTE_RECORD_COLUMNS}, tx.return_address
+            FROM notes
+            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            LEFT JOIN tx ON spendable_notes.wallet_id = tx.wallet_id
+                AND spendable_notes.tx_hash = tx.tx_hash
+            WHERE spendable_notes.wallet_id = $1
+            AND ($2 OR spendable_notes.height_spent IS NULL)
+            AND ($3::BYTEA IS NULL OR notes.asset_id = $3)"
+        ))
+        .bind(&wallet.0[..])
+        .bind(include_spent)
+        .bind(asset_id.map(|id| id.to_bytes().to_vec()))
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(SpendableNoteRecord::try_from)
+        .collect()
+    }
+
+    async fn notes_for_voting(
+        &self,
+        wallet: WalletId,
+        address_index: Option<AddressIndex>,
+        votable_at_height: u64,
+    ) -> anyhow::Result<Vec<(SpendableNoteRecord, IdentityKey)>> {
+        sqlx::query(&format!(
+            "SELECT {NOTE_RECORD_COLUMNS}, assets.denom
+            FROM notes
+            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            JOIN assets ON notes.asset_id = assets.asset_id
+            WHERE spendable_notes.wallet_id = $1
+            AND ($2::BYTEA IS NULL OR spendable_notes.address_index = $2)
+            AND assets.denom LIKE '_delegation\\_%' ESCAPE '\\'
+            AND (spendable_notes.height_spent IS NULL OR spendable_notes.height_spent > $3)
+            AND spendable_notes.height_created < $3"
+        ))
+        .bind(&wallet.0[..])
+        .bind(address_index.map(|index| index.to_bytes().to_vec()))
+        .bind(votable_at_height as i64)
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|row| {
+            let denom: String = row.try_get("denom")?;
+            let identity_key = DelegationToken::from_str(&denom)
+                .context("invalid delegation token denom")?
+                .validator();
+            Ok((SpendableNoteRecord::try_from(row)?, identity_key))
+        })
+        .collect()
+    }
+
+    async fn notes_by_sender(
+        &self,
+        wallet: WalletId,
+        return_address: &Address,
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
+        sqlx::query(&format!(
+            "SELECT {NOTE_RECORD_COLUMNS}
+            FROM notes
+            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            JOIN tx ON spendable_notes.wallet_id = tx.wallet_id
+                AND spendable_notes.tx_hash = tx.tx_hash
+            WHERE spendable_notes.wallet_id = $1 AND tx.return_address = $2"
+        ))
+        .bind(&wallet.0[..])
+        .bind(return_address.to_vec())
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(SpendableNoteRecord::try_from)
+        .collect()
+    }
+
+    async fn notes_by_address(
+        &self,
+        wallet: WalletId,
+        address: &Address,
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
+        sqlx::query(&format!(
+            "SELECT {NOTE_RECORD_COLUMNS}
+            FROM notes
+            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            WHERE spendable_notes.wallet_id = $1 AND notes.address = $2"
+        ))
+        .bind(&wallet.0[..])
+        .bind(address.to_vec())
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(SpendableNoteRecord::try_from)
+        .collect()
+    }
+
+    async fn notes_by_memo(
+        &self,
+        wallet: WalletId,
+        memo_text: &str,
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
+        sqlx::query(&format!(
+            "SELECT {NOTE_RECORD_COLUMNS}, tx.return_address
+            FROM notes
+            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            JOIN tx ON spendable_notes.wallet_id = tx.wallet_id
+                AND spendable_notes.tx_hash = tx.tx_hash
+            WHERE spendable_notes.wallet_id = $1 AND tx.memo_text = $2"
+        ))
+        .bind(&wallet.0[..])
+        .bind(memo_text)
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(SpendableNoteRecord::try_from)
+        .collect()
+    }
+
+    async fn swap_by_commitment(
+        &self,
+        wallet: WalletId,
+        swap_commitment: StateCommitment,
+    ) -> anyhow::Result<Option<SwapRecord>> {
+        sqlx::query("SELECT * FROM swaps WHERE wallet_id = $1 AND swap_commitment = $2")
+            .bind(&wallet.0[..])
+            .bind(swap_commitment.0.to_bytes().to_vec())
+            .fetch_optional(&self.pool)
+            .await?
+            .as_ref()
+            .map(SwapRecord::try_from)
+            .transpose()
+    }
+
+    async fn unclaimed_swaps(&self, wallet: WalletId) -> anyhow::Result<Vec<SwapRecord>> {
+        sqlx::query("SELECT * FROM swaps WHERE wallet_id = $1 AND height_claimed IS NULL")
+            .bind(&wallet.0[..])
+            .fetch_all(&self.pool)
+            .await?
+            .iter()
+            .map(SwapRecord::try_from)
+            .collect()
+    }
+
+    async fn nullifier_status(
+        &self,
+        wallet: WalletId,
+        nullifier: Nullifier,
+    ) -> anyhow::Result<Option<bool>> {
+        let height_spent: Option<Option<i64>> = sqlx::query_scalar(
+            "SELECT height_spent FROM spendable_notes WHERE wallet_id = $1 AND nullifier = $2",
+        )
+        .bind(&wallet.0[..])
+        .bind(nullifier.to_bytes().to_vec())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(height_spent.map(|height_spent| height_spent.is_some()))
+    }
+
+    async fn filter_nullifiers(
+        &self,
+        wallet: WalletId,
+        nullifiers: Vec<Nullifier>,
+    ) -> anyhow::Result<Vec<Nullifier>> {
+        sqlx::query_scalar::<_, Vec<u8>>(
+            "SELECT nullifier FROM (
+                SELECT nullifier FROM spendable_notes WHERE wallet_id = $1
+                UNION SELECT nullifier FROM swaps WHERE wallet_id = $1
+                UNION SELECT nullifier FROM tx_by_nullifier WHERE wallet_id = $1
+            ) AS owned WHERE nullifier = ANY($2)",
+        )
+        .bind(&wallet.0[..])
+        .bind(
+            nullifiers
+                .iter()
+                .map(|nullifier| nullifier.to_bytes().to_vec())
+                .collect::<Vec<_>>(),
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(|nullifier| nullifier.as_slice().try_into())
+        .collect()
+    }
+
+    async fn transaction_hashes(
+        &self,
+        wallet: WalletId,
+        start_height: u64,
+        end_height: u64,
+    ) -> anyhow::Result<Vec<(u64, Vec<u8>)>> {
+        sqlx::query(
+            "SELECT block_height, tx_hash
+            FROM tx
+            WHERE wallet_id = $1 AND block_height BETWEEN $2 AND $3",
+        )
+        .bind(&wallet.0[..])
+        .bind(start_height as i64)
+        .bind(end_height as i64)
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|row| {
+            let block_height: i64 = row.try_get("block_height")?;
+            let tx_hash: Vec<u8> = row.try_get("tx_hash")?;
+            Ok((block_height as u64, tx_hash))
+        })
+        .collect()
+    }
+
+    async fn transactions(
+        &self,
+        wallet: WalletId,
+        start_height: u64,
+        end_height: u64,
+    ) -> anyhow::Result<Vec<(u64, Vec<u8>, Transaction)>> {
+        sqlx::query(
+            "SELECT block_height, tx_hash, tx_bytes
+            FROM tx
+            WHERE wallet_id = $1 AND block_height BETWEEN $2 AND $3",
+        )
+        .bind(&wallet.0[..])
+        .bind(start_height as i64)
+        .bind(end_height as i64)
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(transaction_from_row)
+        .collect()
+    }
+
+    async fn transaction_by_hash(
+        &self,
+        wallet: WalletId,
+        tx_hash: &[u8],
+    ) -> anyhow::Result<Option<(u64, Transaction)>> {
+        sqlx::query(
+            "SELECT block_height, tx_hash, tx_bytes FROM tx WHERE wallet_id = $1 AND tx_hash = $2",
+        )
+        .bind(&wallet.0[..])
+        .bind(tx_hash)
+        .fetch_optional(&self.pool)
+        .await?
+        .as_ref()
+        .map(|row| {
+            let (block_height, _, tx) = transaction_from_row(row)?;
+            Ok((block_height, tx))
+        })
+        .transpose()
+    }
+
+    async fn transactions_matching_memo(
+        &self,
+        wallet: WalletId,
+        pattern: String,
+    ) -> anyhow::Result<Vec<(u64, Vec<u8>, Transaction, String)>> {
+        sqlx::query(
+            "SELECT block_height, tx_hash, tx_bytes, memo_text
+            FROM tx
+            WHERE wallet_id = $1 AND memo_text LIKE $2 ESCAPE '\\'",
+        )
+        .bind(&wallet.0[..])
+        .bind(pattern)
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|row| {
+            let (block_height, tx_hash, tx) = transaction_from_row(row)?;
+            let memo_text: String = row.try_get("memo_text")?;
+            Ok((block_height, tx_hash, tx, memo_text))
+        })
+        .collect()
+    }
+
+    async fn all_assets(&self) -> anyhow::Result<Vec<Metadata>> {
+        sqlx::query("SELECT metadata FROM assets")
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(metadata_from_row)
+            .collect()
+    }
+
+    async fn asset_by_id(&self, id: &Id) -> anyhow::Result<Option<Metadata>> {
+        sqlx::query("SELECT metadata FROM assets WHERE asset_id = $1")
+            .bind(id.to_bytes().to_vec())
+            .fetch_optional(&self.pool)
+            .await?
+            .map(metadata_from_row)
+            .transpose()
+    }
+
+    async fn assets_matching(&self, pattern: String) -> anyhow::Result<Vec<Metadata>> {
+        sqlx::query("SELECT metadata FROM assets WHERE denom LIKE $1 ESCAPE '\\'")
+            .bind(pattern)
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(metadata_from_row)
+            .collect()
+    }
+
+    async fn record_asset(&self, asset: Metadata) -> anyhow::Result<()> {
+        sqlx::query(
+            "INSERT INTO assets (asset_id, denom, metadata) VALUES ($1, $2, $3)
+            ON CONFLICT (asset_id) DO UPDATE SET
+            denom = excluded.denom,
+            metadata = excluded.metadata",
+        )
+        .bind(asset.id().to_bytes().to_vec())
+        .bind(asset.base_denom().denom)
+        .bind(serde_json::to_string(&asset)?)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn batch_swap_outputs(
+        &self,
+        start_height: u64,
+        end_height: u64,
+    ) -> anyhow::Result<Vec<BatchSwapOutputData>> {
+        sqlx::query_scalar::<_, Vec<u8>>(
+            "SELECT output_data FROM batch_swap_outputs
+            WHERE height BETWEEN $1 AND $2
+            ORDER BY height",
+        )
+        .bind(start_height as i64)
+        .bind(end_height as i64)
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|output_data| BatchSwapOutputData::decode(output_data.as_slice()))
+        .collect()
+    }
+
+    async fn record_auction_with_state(
+        &self,
+        wallet: WalletId,
+        auction_id: AuctionId,
+        auction_state: u64,
+        height: u64,
+    ) -> anyhow::Result<()> {
+        let mut dbtx = self.pool.begin().await?;
+        sqlx::query(
+            "INSERT INTO auctions (wallet_id, auction_id, auction_state, note_commitment)
+            VALUES ($1, $2, $3, NULL)
+            ON CONFLICT (wallet_id, auction_id) DO UPDATE SET auction_state = excluded.auction_state",
+        )
+        .bind(&wallet.0[..])
+        .bind(&auction_id.0[..])
+        .bind(auction_state as i64)
+        .execute(dbtx.as_mut())
+        .await?;
+        sqlx::query(
+            "INSERT INTO auction_updates (wallet_id, auction_id, height, auction_state)
+            VALUES ($1, $2, $3, $4)",
+        )
+        .bind(&wallet.0[..])
+        .bind(&auction_id.0[..])
+        .bind(height as i64)
+        .bind(auction_state as i64)
+        .execute(dbtx.as_mut())
+        .await?;
+
+        dbtx.commit().await?;
+        Ok(())
+    }
+
+    async fn update_auction_with_note_commitment(
+        &self,
+        wallet: WalletId,
+        auction_id: AuctionId,
+        note_commitment: StateCommitment,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "UPDATE auctions SET note_commitment = $1 WHERE wallet_id = $2 AND auction_id = $3",
+        )
+        .bind(note_commitment.0.to_bytes().to_vec())
+        .bind(&wallet.0[..])
+        .bind(&auction_id.0[..])
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn fetch_auctions_by_account(
+        &self,
+        wallet: WalletId,
+        account_filter: Option<AddressIndex>,
+        include_inactive: bool,
+    ) -> anyhow::Result<Vec<(AuctionId, SpendableNoteRecord, u64)>> {
+        sqlx::query(&format!(
+            "SELECT auctions.auction_id, {NOTE_RECORD_COLUMNS}, auctions.auction_state
+            FROM auctions
+            JOIN spendable_notes ON auctions.wallet_id = spendable_notes.wallet_id
+                AND auctions.note_commitment = spendable_notes.note_commitment
+            JOIN notes ON auctions.note_commitment = notes.note_commitment
+            WHERE auctions.wallet_id = $1
+            AND ($2::BYTEA IS NULL OR spendable_notes.address_index = $2)
+            AND ($3 OR auctions.auction_state = 0)"
+        ))
+        .bind(&wallet.0[..])
+        .bind(account_filter.map(|index| index.to_bytes().to_vec()))
+        .bind(include_inactive)
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|row| {
+            let raw_auction_id: Vec<u8> = row.try_get("auction_id")?;
+            let auction_id = AuctionId(
+                raw_auction_id
+                    .try_into()
+                    .map_err(|_| anyhow!("auction id must be 32 bytes"))?,
+            );
+            let local_seq: i64 = row.try_get("auction_state")?;
+            Ok((
+                auction_id,
+                SpendableNoteRecord::try_from(row)?,
+                local_seq as u64,
+            ))
+        })
+        .collect()
+    }
+
+    async fn record_position(
+        &self,
+        wallet: WalletId,
+        position: Position,
+        height: u64,
+    ) -> anyhow::Result<()> {
+        let mut dbtx = self.pool.begin().await?;
+        sqlx::query(
+            "INSERT INTO positions (wallet_id, position_id, position_state, trading_pair)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (wallet_id, position_id) DO UPDATE SET
+            position_state = excluded.position_state,
+            trading_pair = excluded.trading_pair",
+        )
+        .bind(&wallet.0[..])
+        .bind(position.id().0.to_vec())
+        .bind(position.state.to_string())
+        .bind(position.phi.pair.to_string())
+        .execute(dbtx.as_mut())
+        .await?;
+        sqlx::query(
+            "INSERT INTO position_updates (wallet_id, position_id, height, position_state)
+            VALUES ($1, $2, $3, $4)",
+        )
+        .bind(&wallet.0[..])
+        .bind(position.id().0.to_vec())
+        .bind(height as i64)
+        .bind(position.state.to_string())
+        .execute(dbtx.as_mut())
+        .await?;
+
+        dbtx.commit().await?;
+        Ok(())
+    }
+
+    async fn update_position(
+        &self,
+        wallet: WalletId,
+        position_id: position::Id,
+        position_state: position::State,
+        height: u64,
+    ) -> anyhow::Result<()> {
+        // Only the positions of the wallet are tracked, so neither is their history.
+        sqlx::query(
+            "WITH updated AS (
+                UPDATE positions SET position_state = $1
+                WHERE wallet_id = $2 AND position_id = $3
+                RETURNING wallet_id, position_id, position_state
+            )
+            INSERT INTO position_updates (wallet_id, position_id, height, position_state)
+            SELECT wallet_id, position_id, $4, position_state FROM updated",
+        )
+        .bind(position_state.to_string())
+        .bind(&wallet.0[..])
+        .bind(position_id.0.to_vec())
+        .bind(height as i64)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn owned_position_ids(
+        &self,
+        wallet: WalletId,
+        position_state: Option<State>,
+        trading_pair: Option<TradingPair>,
+    ) -> anyhow::Result<Vec<position::Id>> {
+        sqlx::query_scalar::<_, Vec<u8>>(
+            "SELECT position_id FROM positions
+            WHERE wallet_id = $1
+            AND ($2::TEXT IS NULL OR position_state = $2)
+            AND ($3::TEXT IS NULL OR trading_pair = $3)",
+        )
+        .bind(&wallet.0[..])
+        .bind(position_state.map(|state| state.to_string()))
+        .bind(trading_pair.map(|pair| pair.to_string()))
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(|position_id| Ok(position::Id(position_id.as_slice().try_into()?)))
+        .collect()
+    }
+
+    async fn contacts(&self, wallet: WalletId) -> anyhow::Result<Vec<(Address, String)>> {
+        sqlx::query_as::<_, (Vec<u8>, String)>(
+            "SELECT address, label FROM contacts WHERE wallet_id = $1 ORDER BY label",
+        )
+        .bind(&wallet.0[..])
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(|(address, label)| anyhow::Ok((Address::try_from(address.as_slice())?, label)))
+        .collect()
+    }
+
+    async fn set_contact(
+        &self,
+        wallet: WalletId,
+        address: &Address,
+        label: String,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "INSERT INTO contacts (wallet_id, address, label) VALUES ($1, $2, $3)
+            ON CONFLICT (wallet_id, address) DO UPDATE SET label = excluded.label",
+        )
+        .bind(&wallet.0[..])
+        .bind(address.to_vec())
+        .bind(label)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete_contact(&self, wallet: WalletId, address: &Address) -> anyhow::Result<bool> {
+        let result = sqlx::query("DELETE FROM contacts WHERE wallet_id = $1 AND address = $2")
+            .bind(&wallet.0[..])
+            .bind(address.to_vec())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn account_labels(&self, wallet: WalletId) -> anyhow::Result<BTreeMap<u32, String>> {
+        sqlx::query_as::<_, (i64, String)>(
+            "SELECT account, label FROM account_labels WHERE wallet_id = $1",
+        )
+        .bind(&wallet.0[..])
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(|(account, label)| anyhow::Ok((u32::try_from(account)?, label)))
+        .collect()
+    }
+
+    async fn set_account_label(
+        &self,
+        wallet: WalletId,
+        account: u32,
+        label: String,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "INSERT INTO account_labels (wallet_id, account, label) VALUES ($1, $2, $3)
+            ON CONFLICT (wallet_id, account) DO UPDATE SET label = excluded.label",
+        )
+        .bind(&wallet.0[..])
+        .bind(i64::from(account))
+        .bind(label)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete_account_label(&self, wallet: WalletId, account: u32) -> anyhow::Result<bool> {
+        let result =
+            sqlx::query("DELETE FROM account_labels WHERE wallet_id = $1 AND account = $2")
+                .bind(&wallet.0[..])
+                .bind(i64::from(account))
+                .execute(&self.pool)
+                .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn invoices(&self, wallet: WalletId) -> anyhow::Result<Vec<(PaymentRequest, bool)>> {
+        sqlx::query_as::<_, (Vec<u8>, bool)>(
+            "SELECT payment_request, dedicated_address FROM invoices
+            WHERE wallet_id = $1 ORDER BY invoice_id",
+        )
+        .bind(&wallet.0[..])
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(|(request, dedicated_address)| {
+            anyhow::Ok((
+                PaymentRequest::decode(request.as_slice())?,
+                dedicated_address,
+            ))
+        })
+        .collect()
+    }
+
+    async fn record_invoice(
+        &self,
+        wallet: WalletId,
+        invoice_id: &str,
+        request: &PaymentRequest,
+        dedicated_address: bool,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            "INSERT INTO invoices (wallet_id, invoice_id, payment_request, dedicated_address)
+            VALUES ($1, $2, $3, $4)",
+        )
+        .bind(&wallet.0[..])
+        .bind(invoice_id)
+        .bind(request.encode_to_vec())
+        .bind(dedicated_address)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete_invoice(&self, wallet: WalletId, invoice_id: &str) -> anyhow::Result<bool> {
+        let result = sqlx::query("DELETE FROM invoices WHERE wallet_id = $1 AND invoice_id = $2")
+            .bind(&wallet.0[..])
+            .bind(invoice_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn give_advice(&self, note: Note) -> anyhow::Result<()> {
+        let mut dbtx = self.pool.begin().await?;
+        Self::record_note_inner(&mut dbtx, &note).await?;
+        dbtx.commit().await?;
+        Ok(())
+    }
+
+    async fn scan_advice(
+        &self,
+        wallet: WalletId,
+        note_commitments: Vec<note::StateCommitment>,
+    ) -> anyhow::Result<BTreeMap<note::StateCommitment, Note>> {
+        // This query gives advice about notes which are known but which have not already been
+        // recorded as spendable, in part to avoid revealing information about which notes have
+        // been spent.
+        sqlx::query(
+            "SELECT notes.note_commitment,
+                notes.address,
+                notes.amount,
+                notes.asset_id,
+                notes.rseed
+            FROM notes
+            LEFT OUTER JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+                AND spendable_notes.wallet_id = $1
+            WHERE spendable_notes.note_commitment IS NULL AND notes.note_commitment = ANY($2)",
+        )
+        .bind(&wallet.0[..])
+        .bind(
+            note_commitments
+                .iter()
+                .map(|cm| cm.0.to_bytes().to_vec())
+                .collect::<Vec<_>>(),
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|row| {
+            let note = note_from_row(row)?;
+            Ok((note.commit(), note))
+        })
+        .collect()
+    }
+
+    async fn record_block(
+        &self,
+        wallet: WalletId,
+        fvk: &FullViewingKey,
+        filtered_block: &FilteredBlock,
+        transactions: Vec<Transaction>,
+        app_parameters: Option<AppParameters>,
+        mut new_sct: tct::Tree,
+    ) -> anyhow::Result<tct::Tree> {
+        let mut dbtx = self.pool.begin().await?;
+        let height = filtered_block.height as i64;
+
+        if let Some(params) = app_parameters {
+            Self::set_kv(&mut dbtx, "app_params", params.encode_to_vec()).await?;
+        }
+
+        // Insert new note records into storage
+        for note_record in filtered_block.new_notes.values() {
+            // Check if the note is from a transaction, if so, include the tx hash (id)
+            let tx_hash = match note_record.source {
+                CommitmentSource::Transaction { id } => id.map(|id| id.to_vec()),
+                _ => None,
+            };
+
+            // Record the inner note data in the notes table
+            Self::record_note_inner(&mut dbtx, &note_record.note).await?;
+
+            sqlx::query(
+                "INSERT INTO spendable_notes
+                (wallet_id, note_commitment, nullifier, position, height_created, address_index, source, height_spent, tx_hash)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, NULL, $8)
+                ON CONFLICT (wallet_id, note_commitment)
+                DO UPDATE SET nullifier = excluded.nullifier,
+                position = excluded.position,
+                height_created = excluded.height_created,
+                address_index = excluded.address_index,
+                source = excluded.source,
+                height_spent = excluded.height_spent,
+                tx_hash = excluded.tx_hash",
+            )
+            .bind(&wallet.0[..])
+            .bind(note_record.note_commitment.0.to_bytes().to_vec())
+            .bind(note_record.nullifier.to_bytes().to_vec())
+            .bind(u64::from(note_record.position) as i64)
+            .bind(height)
+            .bind(note_record.address_index.to_bytes().to_vec())
+            .bind(note_record.source.encode_to_vec())
+            .bind(tx_hash)
+            .execute(dbtx.as_mut())
+            .await?;
+        }
+
+        // Insert new swap records into storage
+        for swap in filtered_block.new_swaps.values() {
+            sqlx::query(
+                "INSERT INTO swaps (wallet_id, swap_commitment, swap, position, nullifier, output_data, height_created, height_claimed, source)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, NULL, $8)
+                ON CONFLICT (wallet_id, swap_commitment)
+                DO UPDATE SET swap = excluded.swap,
+                position = excluded.position,
+                nullifier = excluded.nullifier,
+                output_data = excluded.output_data,
+                height_created = excluded.height_created,
+                height_claimed = excluded.height_claimed,
+                source = excluded.source",
+            )
+            .bind(&wallet.0[..])
+            .bind(swap.swap_commitment.0.to_bytes().to_vec())
+            .bind(swap.swap.encode_to_vec())
+            .bind(u64::from(swap.position) as i64)
+            .bind(swap.nullifier.to_bytes().to_vec())
+            .bind(swap.output_data.encode_to_vec())
+            .bind(height)
+            .bind(swap.source.encode_to_vec())
+            .execute(dbtx.as_mut())
+            .await?;
+        }
+
+        // The batch swap outputs are the same for every wallet scanning the block, so they may
+        // already be recorded.
+        for output_data in &filtered_block.batch_swap_outputs {
+            sqlx::query(
+                "INSERT INTO batch_swap_outputs (height, trading_pair, output_data)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (height, trading_pair) DO NOTHING",
+            )
+            .bind(output_data.height as i64)
+            .bind(output_data.trading_pair.encode_to_vec())
+            .bind(output_data.encode_to_vec())
+            .execute(dbtx.as_mut())
+            .await?;
+        }
+
+        // Update any rows of the table with matching nullifiers to have height_spent
+        for nullifier in &filtered_block.spent_nullifiers {
+            let nullifier_bytes = nullifier.to_bytes().to_vec();
+
+            let spent_commitment: Option<Vec<u8>> = sqlx::query_scalar(
+                "UPDATE spendable_notes SET height_spent = $1
+                WHERE wallet_id = $2 AND nullifier = $3
+                RETURNING note_commitment",
+            )
+            .bind(height)
+            .bind(&wallet.0[..])
+            .bind(&nullifier_bytes)
+            .fetch_optional(dbtx.as_mut())
+            .await?;
+
+            let swap_commitment: Option<Vec<u8>> = sqlx::query_scalar(
+                "UPDATE swaps SET height_claimed = $1
+                WHERE wallet_id = $2 AND nullifier = $3
+                RETURNING swap_commitment",
+            )
+            .bind(height)
+            .bind(&wallet.0[..])
+            .bind(&nullifier_bytes)
+            .fetch_optional(dbtx.as_mut())
+            .await?;
+
+            // Check denom type
+            let spent_denom: String = sqlx::query_scalar(
+                "SELECT assets.denom
+                FROM spendable_notes
+                JOIN notes ON spendable_notes.note_commitment = notes.note_commitment
+                JOIN assets ON notes.asset_id = assets.asset_id
+                WHERE spendable_notes.wallet_id = $1 AND spendable_notes.nullifier = $2",
+            )
+            .bind(&wallet.0[..])
+            .bind(&nullifier_bytes)
+            .fetch_optional(dbtx.as_mut())
+            .await?
+            .unwrap_or("unknown".to_string());
+
+            // Mark spent notes as spent
+            if let Some(spent_commitment) = spent_commitment {
+                let spent_commitment = StateCommitment::try_from(&spent_commitment[..])
+                    .context("invalid commitment bytes")?;
+                tracing::debug!(
+                    ?nullifier,
+                    ?spent_commitment,
+                    ?spent_denom,
+                    "detected spent note commitment"
+                );
+                // Forget spent note commitments from the SCT unless they are delegation tokens,
+                // which must be saved to allow voting on proposals that might or might not be
+                // open presently
+                if DelegationToken::from_str(&spent_denom).is_err() {
+                    tracing::debug!(
+                        ?nullifier,
+                        ?spent_commitment,
+                        ?spent_denom,
+                        "forgetting spent note commitment"
+                    );
+                    new_sct.forget(spent_commitment);
+                }
+            };
+
+            // Mark spent swaps as spent
+            if let Some(spent_swap_commitment) = swap_commitment {
+                let spent_swap_commitment = StateCommitment::try_from(&spent_swap_commitment[..])
+                    .context("invalid commitment bytes")?;
+                tracing::debug!(
+                    ?nullifier,
+                    ?spent_swap_commitment,
+                    "detected and forgetting spent swap commitment"
+                );
+                new_sct.forget(spent_swap_commitment);
+            };
+        }
+
+        // Update SCT table with current SCT state
+        new_sct
+            .to_async_writer(&mut TreeStore(&mut dbtx, wallet))
+            .await?;
+
+        // Record all transactions
+        for transaction in transactions {
+            let tx_bytes = transaction.encode_to_vec();
+            let tx_hash = Sha256::digest(&tx_bytes).to_vec();
+            let decrypted_memo = transaction.decrypt_memo(fvk).ok();
+            let memo_text = decrypted_memo.as_ref().map(|x| x.text().to_string());
+            let return_address = decrypted_memo.map(|x| x.return_address().to_vec());
+
+            tracing::debug!(tx_hash = ?hex::encode(&tx_hash), "recording extended transaction");
+
+            sqlx::query(
+                "INSERT INTO tx (wallet_id, tx_hash, tx_bytes, block_height, return_address, memo_text)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT DO NOTHING",
+            )
+            .bind(&wallet.0[..])
+            .bind(&tx_hash)
+            .bind(&tx_bytes)
+            .bind(height)
+            .bind(return_address)
+            .bind(memo_text)
+            .execute(dbtx.as_mut())
+            .await?;
+
+            // Associate all of the spent nullifiers with the transaction by hash.
+            for nf in transaction.spent_nullifiers() {
+                sqlx::query(
+                    "INSERT INTO tx_by_nullifier (wallet_id, nullifier, tx_hash) VALUES ($1, $2, $3)
+                    ON CONFLICT DO NOTHING",
+                )
+                .bind(&wallet.0[..])
+                .bind(nf.0.to_bytes().to_vec())
+                .bind(&tx_hash)
+                .execute(dbtx.as_mut())
+                .await?;
+            }
+        }
+
+        // Update FMD parameters if they've changed.
+        if let Some(fmd_parameters) = &filtered_block.fmd_parameters {
+            Self::set_kv(&mut dbtx, "fmd_params", fmd_parameters.encode_to_vec()).await?;
+        }
+
+        // Update gas prices if they've changed.
+        if let Some(gas_prices) = &filtered_block.gas_prices {
+            Self::set_kv(&mut dbtx, "gas_prices", gas_prices.encode_to_vec()).await?;
+        }
+
+        // Record block height as latest synced height
+        sqlx::query("UPDATE wallets SET sync_height = $1 WHERE wallet_id = $2")
+            .bind(height)
+            .bind(&wallet.0[..])
+            .execute(dbtx.as_mut())
+            .await?;
+
+        // Commit the changes to the database
+        dbtx.commit().await?;
+
+        Ok(new_sct)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{OsRng, RngCore};
+
+    use super::*;
+    use crate::storage::conformance;
+
+    /// The database to run the conformance tests against.
+    const TEST_DATABASE_URL: &str = "PENUMBRA_VIEW_TEST_POSTGRES_URL";
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database, whose URL is given by PENUMBRA_VIEW_TEST_POSTGRES_URL"]
+    async fn conformance() -> anyhow::Result<()> {
+        let url = std::env::var(TEST_DATABASE_URL)
+            .with_context(|| format!("{TEST_DATABASE_URL} must be set to run this test"))?;
+        let admin = PgPool::connect(&url).await?;
+        let schemata = std::sync::Mutex::new(Vec::new());
+
+        let result = conformance::run(|| {
+            let (admin, url, schemata) = (&admin, &url, &schemata);
+            async move {
+                // Run each test in a new schema, so that it starts from an empty database.
+                let schema = format!("view_conformance_{:016x}", OsRng.next_u64());
+                sqlx::query(&format!("CREATE SCHEMA {schema}"))
+                    .execute(admin)
+                    .await?;
+                schemata
+                    .lock()
+                    .expect("lock is not poisoned")
+                    .push(schema.clone());
+                let options = PgConnectOptions::from_str(url)?.options([("search_path", schema)]);
+                PostgresBackend::connect_with(options).await
+            }
+        })
+        .await;
+
+        for schema in schemata.into_inner().expect("lock is not poisoned") {
+            sqlx::query(&format!("DROP SCHEMA {schema} CASCADE"))
+                .execute(&admin)
+                .await?;
+        }
+        result
+    }
+}
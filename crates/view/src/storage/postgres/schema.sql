@@ -0,0 +1,208 @@
+-- The Postgres version of the schema in `../sqlite/schema.sql`, which documents each table.
+
+-- The hash of this schema file
+CREATE TABLE schema_hash (schema_hash TEXT NOT NULL);
+
+-- The client version that created this database
+CREATE TABLE client_version (client_version TEXT NOT NULL);
+
+-- General-purpose blob storage
+CREATE TABLE kv (
+    k                       TEXT PRIMARY KEY NOT NULL,
+    v                       BYTEA NOT NULL
+);
+
+CREATE TABLE wallets (
+    wallet_id               BYTEA PRIMARY KEY NOT NULL,
+    fvk                     BYTEA NOT NULL,
+    -- the last height scanned for this wallet, or -1 before genesis
+    sync_height             BIGINT NOT NULL,
+    -- the height of the first block scanned for this wallet
+    birthday                BIGINT NOT NULL DEFAULT 0,
+    -- the bincode-encoded SCT at the end of the block before the birthday, if it was fetched
+    -- from a node rather than built by replaying the blocks from genesis
+    birthday_frontier       BYTEA,
+    -- blocks below this height are replayed to rebuild the SCT, rather than scanned
+    replay_height           BIGINT NOT NULL DEFAULT 0
+);
+
+CREATE TABLE assets (
+    asset_id                BYTEA PRIMARY KEY NOT NULL,
+    denom                   TEXT NOT NULL,
+    metadata                TEXT NOT NULL
+);
+
+-- the outputs of the batch swaps in the blocks scanned for any wallet, which price the assets
+-- traded on the dex at each height
+CREATE TABLE batch_swap_outputs (
+    height                  BIGINT NOT NULL,
+    trading_pair            BYTEA NOT NULL,
+    output_data             BYTEA NOT NULL,
+    PRIMARY KEY (height, trading_pair)
+);
+
+CREATE TABLE sct_position (
+    wallet_id BYTEA PRIMARY KEY NOT NULL,
+    position BIGINT
+);
+
+CREATE TABLE sct_forgotten (
+    wallet_id BYTEA PRIMARY KEY NOT NULL,
+    forgotten BIGINT NOT NULL
+);
+
+CREATE TABLE sct_hashes (
+    wallet_id BYTEA NOT NULL,
+    position BIGINT NOT NULL,
+    height   SMALLINT NOT NULL,
+    hash     BYTEA NOT NULL,
+    PRIMARY KEY (wallet_id, position, height)
+);
+
+CREATE TABLE sct_commitments (
+    wallet_id BYTEA NOT NULL,
+    position BIGINT NOT NULL,
+    commitment BYTEA NOT NULL,
+    PRIMARY KEY (wallet_id, position)
+);
+
+CREATE TABLE tx_by_nullifier (
+    wallet_id               BYTEA NOT NULL,
+    nullifier               BYTEA NOT NULL,
+    tx_hash                 BYTEA NOT NULL,
+    PRIMARY KEY (wallet_id, nullifier)
+);
+
+CREATE TABLE tx (
+    wallet_id               BYTEA NOT NULL,
+    tx_hash                 BYTEA NOT NULL,
+    tx_bytes                BYTEA NOT NULL,
+    block_height            BIGINT NOT NULL,
+    return_address          BYTEA,
+    memo_text               TEXT,
+    PRIMARY KEY (wallet_id, tx_hash)
+);
+
+CREATE TABLE notes (
+    note_commitment         BYTEA PRIMARY KEY NOT NULL,
+    address                 BYTEA NOT NULL,
+    amount                  BYTEA NOT NULL,
+    asset_id                BYTEA NOT NULL,
+    rseed                   BYTEA NOT NULL
+);
+
+CREATE INDEX notes_idx ON notes (
+    address,
+    asset_id,
+    amount
+);
+
+CREATE TABLE spendable_notes (
+    wallet_id               BYTEA NOT NULL,
+    note_commitment         BYTEA NOT NULL,
+    nullifier               BYTEA NOT NULL,
+    position                BIGINT NOT NULL,
+    height_created          BIGINT NOT NULL,
+    address_index           BYTEA NOT NULL,
+    source                  BYTEA NOT NULL,
+    height_spent            BIGINT,
+    tx_hash                 BYTEA,
+    PRIMARY KEY (wallet_id, note_commitment)
+);
+
+CREATE INDEX spendable_notes_by_nullifier_idx ON spendable_notes (
+    wallet_id,
+    nullifier
+);
+
+CREATE INDEX spendable_notes_by_source_idx ON spendable_notes (
+    source
+);
+
+CREATE INDEX spendable_notes_idx ON spendable_notes (
+    wallet_id,
+    address_index,
+    height_created,
+    height_spent
+);
+
+CREATE TABLE swaps (
+    wallet_id               BYTEA NOT NULL,
+    swap_commitment         BYTEA NOT NULL,
+    swap                    BYTEA NOT NULL,
+    position                BIGINT NOT NULL,
+    nullifier               BYTEA NOT NULL,
+    output_data             BYTEA NOT NULL,
+    height_created          BIGINT NOT NULL,
+    height_claimed          BIGINT,
+    source                  BYTEA NOT NULL,
+    PRIMARY KEY (wallet_id, swap_commitment)
+);
+
+CREATE INDEX swaps_nullifier_idx ON swaps (wallet_id, nullifier);
+
+CREATE TABLE positions (
+     wallet_id              BYTEA NOT NULL,
+     position_id            BYTEA NOT NULL,
+     position_state         TEXT NOT NULL,
+     trading_pair           TEXT NOT NULL,
+     PRIMARY KEY (wallet_id, position_id)
+);
+
+CREATE TABLE auctions (
+     wallet_id              BYTEA NOT NULL,
+     auction_id             BYTEA NOT NULL,
+     auction_state          BIGINT NOT NULL,
+     note_commitment        BYTEA,
+     PRIMARY KEY (wallet_id, auction_id)
+);
+
+-- The height of every change to the state of a position or an auction, so that a rewind can put
+-- them back to their state before the height it rewinds to.
+CREATE TABLE position_updates (
+     seq                    BIGSERIAL PRIMARY KEY,
+     wallet_id              BYTEA NOT NULL,
+     position_id            BYTEA NOT NULL,
+     height                 BIGINT NOT NULL,
+     position_state         TEXT NOT NULL
+);
+
+CREATE INDEX position_updates_idx ON position_updates (wallet_id, position_id, height);
+
+CREATE TABLE auction_updates (
+     seq                    BIGSERIAL PRIMARY KEY,
+     wallet_id              BYTEA NOT NULL,
+     auction_id             BYTEA NOT NULL,
+     height                 BIGINT NOT NULL,
+     auction_state          BIGINT NOT NULL
+);
+
+CREATE INDEX auction_updates_idx ON auction_updates (wallet_id, auction_id, height);
+
+-- The user's address book, with a label for each of the addresses they've saved. Like the account
+-- labels, it isn't derived from the chain, so it is kept when a wallet is rescanned.
+CREATE TABLE contacts (
+     wallet_id              BYTEA NOT NULL,
+     address                BYTEA NOT NULL,
+     label                  TEXT NOT NULL,
+     PRIMARY KEY (wallet_id, address)
+);
+
+-- The labels the user has given to the accounts of each wallet.
+CREATE TABLE account_labels (
+     wallet_id              BYTEA NOT NULL,
+     account                BIGINT NOT NULL,
+     label                  TEXT NOT NULL,
+     PRIMARY KEY (wallet_id, account)
+);
+
+-- The invoices the user has created, with the payment request of each, encoded as a proto, which
+-- incoming notes are matched against. Like the address book, they aren't derived from the chain,
+-- so they are kept when a wallet is rescanned.
+CREATE TABLE invoices (
+     wallet_id              BYTEA NOT NULL,
+     invoice_id             TEXT NOT NULL,
+     payment_request        BYTEA NOT NULL,
+     dedicated_address      BOOLEAN NOT NULL,
+     PRIMARY KEY (wallet_id, invoice_id)
+);
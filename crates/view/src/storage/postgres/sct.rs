@@ -0,0 +1,214 @@
+use std::{ops::Range, pin::Pin};
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use sqlx::{Postgres, Row, Transaction};
+
+use penumbra_keys::keys::WalletId;
+use penumbra_tct::{
+    storage::{AsyncRead, AsyncWrite, StoredPosition},
+    structure::Hash,
+    Forgotten, Position, StateCommitment,
+};
+
+/// The state commitment tree of one wallet, stored in the database.
+#[derive(Debug)]
+pub struct TreeStore<'a, 'c: 'a>(pub &'a mut Transaction<'c, Postgres>, pub WalletId);
+
+fn hash_from_bytes(bytes: Vec<u8>) -> anyhow::Result<Hash> {
+    let array =
+        <[u8; 32]>::try_from(bytes).map_err(|_| anyhow::anyhow!("hash was of incorrect length"))?;
+    Hash::from_bytes(array).map_err(|e| anyhow::anyhow!("Error converting hash: {}", e))
+}
+
+fn commitment_from_bytes(bytes: Vec<u8>) -> anyhow::Result<StateCommitment> {
+    let array = <[u8; 32]>::try_from(bytes)
+        .map_err(|_| anyhow::anyhow!("commitment was of incorrect length"))?;
+    StateCommitment::try_from(array).map_err(Into::into)
+}
+
+#[async_trait]
+impl AsyncRead for TreeStore<'_, '_> {
+    type Error = anyhow::Error;
+
+    type HashesStream<'a> =
+        Pin<Box<dyn Stream<Item = Result<(Position, u8, Hash), Self::Error>> + Send + 'a>>
+    where
+        Self: 'a;
+
+    type CommitmentsStream<'a> =
+        Pin<Box<dyn Stream<Item = Result<(Position, StateCommitment), Self::Error>> + Send + 'a>>
+    where
+        Self: 'a;
+
+    async fn position(&mut self) -> Result<StoredPosition, Self::Error> {
+        let position: Option<i64> =
+            sqlx::query_scalar("SELECT position FROM sct_position WHERE wallet_id = $1 LIMIT 1")
+                .bind(&self.1 .0[..])
+                .fetch_one(self.0.as_mut())
+                .await
+                .context("failed to query position")?;
+        Ok(position.map(|p| Position::from(p as u64)).into())
+    }
+
+    async fn forgotten(&mut self) -> Result<Forgotten, Self::Error> {
+        let forgotten: i64 =
+            sqlx::query_scalar("SELECT forgotten FROM sct_forgotten WHERE wallet_id = $1 LIMIT 1")
+                .bind(&self.1 .0[..])
+                .fetch_one(self.0.as_mut())
+                .await
+                .context("failed to query forgotten")?;
+        Ok((forgotten as u64).into())
+    }
+
+    async fn hash(&mut self, position: Position, height: u8) -> Result<Option<Hash>, Self::Error> {
+        let bytes: Option<Vec<u8>> = sqlx::query_scalar(
+            "SELECT hash FROM sct_hashes
+            WHERE wallet_id = $1 AND position = $2 AND height = $3 LIMIT 1",
+        )
+        .bind(&self.1 .0[..])
+        .bind(u64::from(position) as i64)
+        .bind(height as i16)
+        .fetch_optional(self.0.as_mut())
+        .await
+        .context("failed to query hash")?;
+
+        bytes.map(hash_from_bytes).transpose()
+    }
+
+    fn hashes(&mut self) -> Self::HashesStream<'_> {
+        Box::pin(
+            sqlx::query("SELECT position, height, hash FROM sct_hashes WHERE wallet_id = $1")
+                .bind(self.1 .0.to_vec())
+                .fetch(self.0.as_mut())
+                .map(|row| {
+                    let row = row.context("couldn't query database")?;
+                    let position: i64 = row.try_get("position")?;
+                    let height: i16 = row.try_get("height")?;
+                    let hash = hash_from_bytes(row.try_get("hash")?)?;
+                    anyhow::Ok((Position::from(position as u64), height as u8, hash))
+                }),
+        )
+    }
+
+    async fn commitment(
+        &mut self,
+        position: Position,
+    ) -> Result<Option<StateCommitment>, Self::Error> {
+        let bytes: Option<Vec<u8>> = sqlx::query_scalar(
+            "SELECT commitment FROM sct_commitments WHERE wallet_id = $1 AND position = $2 LIMIT 1",
+        )
+        .bind(&self.1 .0[..])
+        .bind(u64::from(position) as i64)
+        .fetch_optional(self.0.as_mut())
+        .await
+        .context("failed to query commitment")?;
+
+        bytes.map(commitment_from_bytes).transpose()
+    }
+
+    fn commitments(&mut self) -> Self::CommitmentsStream<'_> {
+        Box::pin(
+            sqlx::query("SELECT position, commitment FROM sct_commitments WHERE wallet_id = $1")
+                .bind(self.1 .0.to_vec())
+                .fetch(self.0.as_mut())
+                .map(|row| {
+                    let row = row.context("couldn't query database")?;
+                    let position: i64 = row.try_get("position")?;
+                    let commitment = commitment_from_bytes(row.try_get("commitment")?)?;
+                    anyhow::Ok((Position::from(position as u64), commitment))
+                }),
+        )
+    }
+}
+
+#[async_trait]
+impl AsyncWrite for TreeStore<'_, '_> {
+    async fn set_position(&mut self, position: StoredPosition) -> Result<(), Self::Error> {
+        let position = Option::from(position).map(|p: Position| u64::from(p) as i64);
+
+        sqlx::query("UPDATE sct_position SET position = $1 WHERE wallet_id = $2")
+            .bind(position)
+            .bind(&self.1 .0[..])
+            .execute(self.0.as_mut())
+            .await
+            .context("failed to update position")?;
+
+        Ok(())
+    }
+
+    async fn set_forgotten(&mut self, forgotten: Forgotten) -> Result<(), Self::Error> {
+        let forgotten = u64::from(forgotten) as i64;
+
+        sqlx::query("UPDATE sct_forgotten SET forgotten = $1 WHERE wallet_id = $2")
+            .bind(forgotten)
+            .bind(&self.1 .0[..])
+            .execute(self.0.as_mut())
+            .await
+            .context("failed to update forgotten")?;
+
+        Ok(())
+    }
+
+    async fn add_hash(
+        &mut self,
+        position: Position,
+        height: u8,
+        hash: Hash,
+        _essential: bool,
+    ) -> Result<(), Self::Error> {
+        sqlx::query(
+            "INSERT INTO sct_hashes (wallet_id, position, height, hash) VALUES ($1, $2, $3, $4)
+            ON CONFLICT DO NOTHING",
+        )
+        .bind(&self.1 .0[..])
+        .bind(u64::from(position) as i64)
+        .bind(height as i16)
+        .bind(&hash.to_bytes()[..])
+        .execute(self.0.as_mut())
+        .await
+        .context("failed to insert hash")?;
+
+        Ok(())
+    }
+
+    async fn add_commitment(
+        &mut self,
+        position: Position,
+        commitment: StateCommitment,
+    ) -> Result<(), Self::Error> {
+        sqlx::query(
+            "INSERT INTO sct_commitments (wallet_id, position, commitment) VALUES ($1, $2, $3)
+            ON CONFLICT DO NOTHING",
+        )
+        .bind(&self.1 .0[..])
+        .bind(u64::from(position) as i64)
+        .bind(&<[u8; 32]>::from(commitment)[..])
+        .execute(self.0.as_mut())
+        .await
+        .context("failed to insert commitment")?;
+
+        Ok(())
+    }
+
+    async fn delete_range(
+        &mut self,
+        below_height: u8,
+        positions: Range<Position>,
+    ) -> Result<(), Self::Error> {
+        sqlx::query(
+            "DELETE FROM sct_hashes
+            WHERE wallet_id = $1 AND position >= $2 AND position < $3 AND height < $4",
+        )
+        .bind(&self.1 .0[..])
+        .bind(u64::from(positions.start) as i64)
+        .bind(u64::from(positions.end) as i64)
+        .bind(below_height as i16)
+        .execute(self.0.as_mut())
+        .await
+        .context("failed to delete hashes")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1942 @@
+use std::{collections::BTreeMap, str::FromStr, time::Duration};
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use camino::Utf8Path;
+use decaf377::Fq;
+use once_cell::sync::Lazy;
+use penumbra_auction::auction::AuctionId;
+use r2d2_sqlite::{
+    rusqlite::{OpenFlags, OptionalExtension},
+    SqliteConnectionManager,
+};
+use sha2::{Digest, Sha256};
+use tokio::task::spawn_blocking;
+
+use penumbra_app::params::AppParameters;
+use penumbra_asset::{asset, asset::Id, asset::Metadata, Value};
+use penumbra_dex::{
+    lp::position::{self, Position, State},
+    BatchSwapOutputData, TradingPair,
+};
+use penumbra_fee::GasPrices;
+use penumbra_keys::{
+    keys::{AddressIndex, WalletId},
+    Address, FullViewingKey, PaymentRequest,
+};
+use penumbra_proto::DomainType;
+use penumbra_sct::{CommitmentSource, Nullifier};
+use penumbra_shielded_pool::{fmd, note, Note, Rseed};
+use penumbra_stake::{DelegationToken, IdentityKey};
+use penumbra_tct as tct;
+use penumbra_transaction::Transaction;
+use sct::TreeStore;
+use tct::StateCommitment;
+
+use super::{BalanceEntry, Birthday, StorageBackend};
+use crate::{sync::FilteredBlock, SpendableNoteRecord, SwapRecord};
+
+mod encryption;
+mod sct;
+
+pub use encryption::DatabaseKey;
+
+/// The hash of the schema for the database.
+static SCHEMA_HASH: Lazy<String> =
+    Lazy::new(|| hex::encode(Sha256::digest(include_str!("sqlite/schema.sql"))));
+
+/// A [`StorageBackend`] keeping the view database in a sqlite file, or in memory.
+#[derive(Clone)]
+pub struct SqliteBackend {
+    pool: r2d2::Pool<SqliteConnectionManager>,
+}
+
+impl SqliteBackend {
+    fn connect(
+        path: Option<impl AsRef<Utf8Path>>,
+        key: Option<DatabaseKey>,
+    ) -> anyhow::Result<r2d2::Pool<SqliteConnectionManager>> {
+        if let Some(path) = path {
+            let key_pragma = encryption::key_pragma(path.as_ref(), key.as_ref())?;
+            let manager = SqliteConnectionManager::file(path.as_ref())
+                .with_flags(
+                    // Don't allow opening URIs, because they can change the behavior of the database; we
+                    // just want to open normal filepaths.
+                    OpenFlags::default() & !OpenFlags::SQLITE_OPEN_URI,
+                )
+                .with_init(move |conn| {
+                    if let Some(key_pragma) = &key_pragma {
+                        encryption::set_key(conn, key_pragma)?;
+                    }
+                    // "NORMAL" will be consistent, but maybe not durable -- this is fine,
+                    // since all our data is being synced from the chain, so if we lose a dbtx,
+                    // it's like we're resuming sync from a previous height.
+                    conn.execute_batch("PRAGMA journal_mode=WAL; PRAGMA synchronous=NORMAL;")?;
+                    // We use `prepare_cached` a fair amount: this is an overestimate of the number
+                    // of cached prepared statements likely to be used.
+                    conn.set_prepared_statement_cache_capacity(32);
+                    Ok(())
+                });
+            Ok(r2d2::Pool::builder()
+                // We set max_size=1 to avoid "database is locked" sqlite errors,
+                // when accessing across multiple threads.
+                .max_size(1)
+                .build(manager)?)
+        } else {
+            let manager = SqliteConnectionManager::memory();
+            // Max size needs to be set to 1, otherwise a new in-memory database is created for each
+            // connection to the pool, which results in very confusing errors.
+            //
+            // Lifetimes and timeouts are likewise configured to their maximum values, since
+            // the in-memory database will disappear on connection close.
+            Ok(r2d2::Pool::builder()
+                .max_size(1)
+                .min_idle(Some(1))
+                .max_lifetime(Some(Duration::MAX))
+                .idle_timeout(Some(Duration::MAX))
+                .build(manager)?)
+        }
+    }
+
+    /// Load the database at `path`, checking that it was created with the current schema.
+    ///
+    /// If the database is encrypted, the `key` it was created with is needed to open it.
+    pub async fn load(
+        path: impl AsRef<Utf8Path>,
+        key: Option<DatabaseKey>,
+    ) -> anyhow::Result<Self> {
+        let path = path.as_ref().to_owned();
+
+        spawn_blocking(move || {
+            // Connecting may derive the key of an encrypted database, which is slow on purpose.
+            let pool = Self::connect(Some(path), key)?;
+
+            // Check the version of the software used when first initializing this database.
+            // If it doesn't match the current version, we should report the error to the user.
+            let actual_schema_hash: String = pool
+                .get()?
+                .query_row("SELECT schema_hash FROM schema_hash", (), |row| {
+                    row.get("schema_hash")
+                })
+                .context("failed to query database schema version: the database was probably created by an old client version, and needs to be reset and resynchronized")?;
+
+            if actual_schema_hash != *SCHEMA_HASH {
+                let database_client_version: String = pool
+                    .get()?
+                    .query_row("SELECT client_version FROM client_version", (), |row| {
+                        row.get("client_version")
+                    })
+                    .context("failed to query client version: the database was probably created by an old client version, and needs to be reset and resynchronized")?;
+
+                anyhow::bail!(
+                    "can't load view database created by client version {} using client version {}: they have different schemata, so you need to reset your view database and resynchronize by running pcli view reset",
+                    database_client_version,
+                    env!("CARGO_PKG_VERSION"),
+                );
+            }
+
+            Ok(Self { pool })
+        })
+        .await?
+    }
+
+    /// Create a database at `path`, or in memory if there is no path, holding no wallets yet.
+    ///
+    /// If there is a `key`, a database at `path` is encrypted with it. A database in memory is
+    /// never encrypted.
+    pub async fn initialize(
+        storage_path: Option<impl AsRef<Utf8Path>>,
+        key: Option<DatabaseKey>,
+        params: AppParameters,
+    ) -> anyhow::Result<Self> {
+        let storage_path = storage_path.map(|path| path.as_ref().to_owned());
+
+        spawn_blocking(move || {
+            // Connect to the database (or create it)
+            let pool = Self::connect(storage_path, key)?;
+
+            // In one database transaction, populate everything
+            let mut conn = pool.get()?;
+            let tx = conn.transaction()?;
+
+            // Create the tables
+            tx.execute_batch(include_str!("sqlite/schema.sql"))?;
+
+            let params_bytes = params.encode_to_vec();
+            tx.execute(
+                "INSERT INTO kv (k, v) VALUES ('app_params', ?1)",
+                [&params_bytes[..]],
+            )?;
+
+            // Insert the schema hash into the database
+            tx.execute(
+                "INSERT INTO schema_hash (schema_hash) VALUES (?1)",
+                [&*SCHEMA_HASH],
+            )?;
+
+            // Insert the client version into the database
+            tx.execute(
+                "INSERT INTO client_version (client_version) VALUES (?1)",
+                [env!("CARGO_PKG_VERSION")],
+            )?;
+
+            tx.commit()?;
+            drop(conn);
+
+            Ok(Self { pool })
+        })
+        .await?
+    }
+
+    /// Reset the state commitment tree and sync height of a wallet to its birthday.
+    fn restart_from_birthday(
+        dbtx: &mut r2d2_sqlite::rusqlite::Transaction<'_>,
+        wallet: WalletId,
+    ) -> anyhow::Result<()> {
+        let (birthday, frontier): (i64, Option<Vec<u8>>) = dbtx.query_row(
+            "SELECT birthday, birthday_frontier FROM wallets WHERE wallet_id = ?1",
+            [&wallet.0[..]],
+            |row| Ok((row.get("birthday")?, row.get("birthday_frontier")?)),
+        )?;
+
+        for table in [
+            "sct_position",
+            "sct_forgotten",
+            "sct_hashes",
+            "sct_commitments",
+        ] {
+            dbtx.execute(
+                &format!("DELETE FROM {table} WHERE wallet_id = ?1"),
+                [&wallet.0[..]],
+            )?;
+        }
+        dbtx.execute(
+            "INSERT INTO sct_position (wallet_id, position) VALUES (?1, 0)",
+            [&wallet.0[..]],
+        )?;
+        dbtx.execute(
+            "INSERT INTO sct_forgotten (wallet_id, forgotten) VALUES (?1, 0)",
+            [&wallet.0[..]],
+        )?;
+
+        // Without a frontier, the wallet starts again from genesis.
+        let sync_height = match frontier {
+            Some(bytes) => {
+                let frontier: tct::Tree = bincode::deserialize(&bytes)?;
+                frontier.to_writer(&mut TreeStore(&mut *dbtx, wallet))?;
+                birthday - 1
+            }
+            None => -1,
+        };
+        dbtx.execute(
+            "UPDATE wallets SET sync_height = ?1 WHERE wallet_id = ?2",
+            (sync_height, &wallet.0[..]),
+        )?;
+
+        Ok(())
+    }
+
+    fn record_note_inner(
+        dbtx: &r2d2_sqlite::rusqlite::Transaction<'_>,
+        note: &Note,
+    ) -> anyhow::Result<()> {
+        let note_commitment = note.commit().0.to_bytes().to_vec();
+        let address = note.address().to_vec();
+        let amount = u128::from(note.amount()).to_be_bytes().to_vec();
+        let asset_id = note.asset_id().to_bytes().to_vec();
+        let rseed = note.rseed().to_bytes().to_vec();
+
+        dbtx.execute(
+            "INSERT INTO notes (note_commitment, address, amount, asset_id, rseed)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT (note_commitment)
+                DO UPDATE SET
+                address = excluded.address,
+                amount = excluded.amount,
+                asset_id = excluded.asset_id,
+                rseed = excluded.rseed",
+            (note_commitment, address, amount, asset_id, rseed),
+        )?;
+
+        Ok(())
+    }
+
+    /// Read one of the protobuf-encoded values in the kv table.
+    async fn kv<T: DomainType + Send + 'static>(
+        &self,
+        key: &'static str,
+    ) -> anyhow::Result<Option<T>>
+    where
+        anyhow::Error: From<<T as TryFrom<T::Proto>>::Error>,
+    {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached("SELECT v FROM kv WHERE k IS ?1 LIMIT 1")?
+                .query_row([key], |row| row.get::<_, Vec<u8>>("v"))
+                .optional()?
+                .map(|bytes| T::decode(bytes.as_slice()))
+                .transpose()
+        })
+        .await?
+    }
+}
+
+#[async_trait]
+impl StorageBackend for SqliteBackend {
+    async fn wallets(&self) -> anyhow::Result<Vec<WalletId>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare("SELECT wallet_id FROM wallets ORDER BY wallet_id")?
+                .query_and_then([], |row| {
+                    let bytes: Vec<u8> = row.get("wallet_id")?;
+                    Ok(WalletId(
+                        bytes
+                            .try_into()
+                            .map_err(|_| anyhow!("wallet id must be 32 bytes"))?,
+                    ))
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn add_wallet(&self, fvk: &FullViewingKey) -> anyhow::Result<WalletId> {
+        let pool = self.pool.clone();
+        let fvk = fvk.clone();
+
+        spawn_blocking(move || {
+            let mut conn = pool.get()?;
+            let dbtx = conn.transaction()?;
+            let wallet = fvk.wallet_id();
+            let inserted = dbtx.execute(
+                // Insert -1 as a signaling value for pre-genesis.
+                // We just have to be careful to treat negative values as None
+                // in sync_height.
+                "INSERT OR IGNORE INTO wallets (wallet_id, fvk, sync_height) VALUES (?1, ?2, -1)",
+                (&wallet.0[..], fvk.encode_to_vec()),
+            )?;
+            if inserted > 0 {
+                dbtx.execute(
+                    "INSERT INTO sct_position (wallet_id, position) VALUES (?1, 0)",
+                    [&wallet.0[..]],
+                )?;
+                dbtx.execute(
+                    "INSERT INTO sct_forgotten (wallet_id, forgotten) VALUES (?1, 0)",
+                    [&wallet.0[..]],
+                )?;
+            }
+            dbtx.commit()?;
+            anyhow::Ok(wallet)
+        })
+        .await?
+    }
+
+    async fn full_viewing_key(&self, wallet: WalletId) -> anyhow::Result<FullViewingKey> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let bytes = pool
+                .get()?
+                .prepare_cached("SELECT fvk FROM wallets WHERE wallet_id = ?1")?
+                .query_row([&wallet.0[..]], |row| row.get::<_, Option<Vec<u8>>>("fvk"))?
+                .ok_or_else(|| anyhow!("missing fvk in wallets table"))?;
+
+            FullViewingKey::decode(bytes.as_slice())
+        })
+        .await?
+    }
+
+    async fn sync_height(&self, wallet: WalletId) -> anyhow::Result<Option<u64>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let height: Option<i64> = pool
+                .get()?
+                .prepare_cached("SELECT sync_height FROM wallets WHERE wallet_id = ?1")?
+                .query_row([&wallet.0[..]], |row| row.get::<_, Option<i64>>(0))?;
+
+            anyhow::Ok(u64::try_from(height.ok_or_else(|| anyhow!("missing sync height"))?).ok())
+        })
+        .await?
+    }
+
+    async fn birthday(&self, wallet: WalletId) -> anyhow::Result<Birthday> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let (height, frontier): (i64, Option<Vec<u8>>) = pool
+                .get()?
+                .prepare_cached(
+                    "SELECT birthday, birthday_frontier FROM wallets WHERE wallet_id = ?1",
+                )?
+                .query_row([&wallet.0[..]], |row| {
+                    Ok((row.get("birthday")?, row.get("birthday_frontier")?))
+                })?;
+
+            Ok(Birthday {
+                height: height as u64,
+                frontier: frontier
+                    .map(|bytes| bincode::deserialize(&bytes))
+                    .transpose()?,
+            })
+        })
+        .await?
+    }
+
+    async fn replay_height(&self, wallet: WalletId) -> anyhow::Result<u64> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let height: i64 = pool
+                .get()?
+                .prepare_cached("SELECT replay_height FROM wallets WHERE wallet_id = ?1")?
+                .query_row([&wallet.0[..]], |row| row.get("replay_height"))?;
+
+            anyhow::Ok(height as u64)
+        })
+        .await?
+    }
+
+    async fn set_birthday(&self, wallet: WalletId, birthday: &Birthday) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+        let height = birthday.height as i64;
+        let frontier = birthday
+            .frontier
+            .as_ref()
+            .map(bincode::serialize)
+            .transpose()?;
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let mut dbtx = lock.transaction()?;
+
+            for table in [
+                "spendable_notes",
+                "swaps",
+                "tx",
+                "tx_by_nullifier",
+                "positions",
+                "position_updates",
+                "auctions",
+                "auction_updates",
+            ] {
+                dbtx.execute(
+                    &format!("DELETE FROM {table} WHERE wallet_id = ?1"),
+                    [&wallet.0[..]],
+                )?;
+            }
+            dbtx.execute(
+                "UPDATE wallets SET birthday = ?1, birthday_frontier = ?2, replay_height = ?1
+                WHERE wallet_id = ?3",
+                (height, frontier, &wallet.0[..]),
+            )?;
+            Self::restart_from_birthday(&mut dbtx, wallet)?;
+
+            dbtx.commit()?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    async fn rewind(&self, wallet: WalletId, height: u64) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+        let height = height as i64;
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let mut dbtx = lock.transaction()?;
+
+            dbtx.execute(
+                "DELETE FROM spendable_notes WHERE wallet_id = ?1 AND height_created >= ?2",
+                (&wallet.0[..], height),
+            )?;
+            dbtx.execute(
+                "UPDATE spendable_notes SET height_spent = NULL
+                WHERE wallet_id = ?1 AND height_spent >= ?2",
+                (&wallet.0[..], height),
+            )?;
+            dbtx.execute(
+                "DELETE FROM swaps WHERE wallet_id = ?1 AND height_created >= ?2",
+                (&wallet.0[..], height),
+            )?;
+            dbtx.execute(
+                "UPDATE swaps SET height_claimed = NULL
+                WHERE wallet_id = ?1 AND height_claimed >= ?2",
+                (&wallet.0[..], height),
+            )?;
+            dbtx.execute(
+                "DELETE FROM tx_by_nullifier WHERE wallet_id = ?1 AND tx_hash IN
+                (SELECT tx_hash FROM tx WHERE wallet_id = ?1 AND block_height >= ?2)",
+                (&wallet.0[..], height),
+            )?;
+            dbtx.execute(
+                "DELETE FROM tx WHERE wallet_id = ?1 AND block_height >= ?2",
+                (&wallet.0[..], height),
+            )?;
+            dbtx.execute(
+                "DELETE FROM position_updates WHERE wallet_id = ?1 AND height >= ?2",
+                (&wallet.0[..], height),
+            )?;
+            dbtx.execute(
+                "DELETE FROM auction_updates WHERE wallet_id = ?1 AND height >= ?2",
+                (&wallet.0[..], height),
+            )?;
+            // Positions and auctions go back to their latest remaining state, and are forgotten
+            // if they have none, while auctions forget the notes which were rewound.
+            for statement in [
+                "DELETE FROM positions WHERE wallet_id = ?1 AND position_id NOT IN
+                (SELECT position_id FROM position_updates WHERE wallet_id = ?1)",
+                "UPDATE positions SET position_state =
+                (SELECT position_state FROM position_updates
+                WHERE position_updates.wallet_id = positions.wallet_id
+                AND position_updates.position_id = positions.position_id
+                ORDER BY height DESC, seq DESC LIMIT 1)
+                WHERE wallet_id = ?1",
+                "DELETE FROM auctions WHERE wallet_id = ?1 AND auction_id NOT IN
+                (SELECT auction_id FROM auction_updates WHERE wallet_id = ?1)",
+                "UPDATE auctions SET auction_state =
+                (SELECT auction_state FROM auction_updates
+                WHERE auction_updates.wallet_id = auctions.wallet_id
+                AND auction_updates.auction_id = auctions.auction_id
+                ORDER BY height DESC, seq DESC LIMIT 1)
+                WHERE wallet_id = ?1",
+                "UPDATE auctions SET note_commitment = NULL
+                WHERE wallet_id = ?1 AND note_commitment NOT IN
+                (SELECT note_commitment FROM spendable_notes WHERE wallet_id = ?1)",
+            ] {
+                dbtx.execute(statement, [&wallet.0[..]])?;
+            }
+            // The blocks before the birthday were never scanned, so they are still replayed.
+            dbtx.execute(
+                "UPDATE wallets SET replay_height = MAX(birthday, ?1) WHERE wallet_id = ?2",
+                (height, &wallet.0[..]),
+            )?;
+            Self::restart_from_birthday(&mut dbtx, wallet)?;
+
+            dbtx.commit()?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    async fn app_params(&self) -> anyhow::Result<Option<AppParameters>> {
+        self.kv("app_params").await
+    }
+
+    async fn record_app_params(&self, params: &AppParameters) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+        let params_bytes = params.encode_to_vec();
+
+        spawn_blocking(move || {
+            pool.get()?.execute(
+                "INSERT INTO kv (k, v) VALUES ('app_params', ?1)
+                ON CONFLICT(k) DO UPDATE SET v = excluded.v",
+                [&params_bytes[..]],
+            )?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    async fn gas_prices(&self) -> anyhow::Result<Option<GasPrices>> {
+        self.kv("gas_prices").await
+    }
+
+    async fn fmd_parameters(&self) -> anyhow::Result<Option<fmd::Parameters>> {
+        self.kv("fmd_params").await
+    }
+
+    async fn state_commitment_tree(&self, wallet: WalletId) -> anyhow::Result<tct::Tree> {
+        let pool = self.pool.clone();
+        spawn_blocking(move || {
+            tct::Tree::from_reader(&mut TreeStore(&mut pool.get()?.transaction()?, wallet))
+        })
+        .await?
+    }
+
+    async fn unspent_balances(&self, wallet: WalletId) -> anyhow::Result<Vec<BalanceEntry>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let query = "SELECT notes.asset_id, notes.amount, spendable_notes.address_index
+                FROM    notes
+                JOIN    spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+                WHERE   spendable_notes.wallet_id = ?1
+                AND     spendable_notes.height_spent IS NULL";
+
+            tracing::debug!(?query);
+
+            pool.get()?
+                .prepare_cached(query)?
+                .query_and_then([&wallet.0[..]], |row| {
+                    let asset_id = row.get::<&str, Vec<u8>>("asset_id")?;
+                    let amount = row.get::<&str, Vec<u8>>("amount")?;
+                    let address_index = row.get::<&str, Vec<u8>>("address_index")?;
+
+                    anyhow::Ok(BalanceEntry {
+                        id: Id::try_from(asset_id.as_slice())?,
+                        amount: u128::from_be_bytes(
+                            amount
+                                .as_slice()
+                                .try_into()
+                                .expect("amount slice of incorrect length"),
+                        ),
+                        address_index: AddressIndex::try_from(address_index.as_slice())?,
+                    })
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn note_by_commitment(
+        &self,
+        wallet: WalletId,
+        note_commitment: StateCommitment,
+    ) -> anyhow::Result<Option<SpendableNoteRecord>> {
+        let pool = self.pool.clone();
+        let wallet = hex::encode(wallet.0);
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare(&format!(
+                    "SELECT
+                        notes.note_commitment,
+                        spendable_notes.height_created,
+                        notes.address,
+                        notes.amount,
+                        notes.asset_id,
+                        notes.rseed,
+                        spendable_notes.address_index,
+                        spendable_notes.source,
+                        spendable_notes.height_spent,
+                        spendable_notes.nullifier,
+                        spendable_notes.position,
+                        tx.return_address
+                    FROM notes
+                    JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+                    LEFT JOIN tx ON spendable_notes.wallet_id = tx.wallet_id
+                        AND spendable_notes.tx_hash = tx.tx_hash
+                    WHERE spendable_notes.wallet_id = x'{wallet}'
+                    AND notes.note_commitment = x'{}'",
+                    hex::encode(note_commitment.0.to_bytes())
+                ))?
+                .query_and_then((), |record| record.try_into())?
+                .next()
+                .transpose()
+        })
+        .await?
+    }
+
+    async fn note_by_nullifier(
+        &self,
+        wallet: WalletId,
+        nullifier: Nullifier,
+    ) -> anyhow::Result<Option<SpendableNoteRecord>> {
+        let pool = self.pool.clone();
+        let wallet = hex::encode(wallet.0);
+
+        let nullifier_bytes = nullifier.to_bytes().to_vec();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare(&format!(
+                    "SELECT
+                        notes.note_commitment,
+                        spendable_notes.height_created,
+                        notes.address,
+                        notes.amount,
+                        notes.asset_id,
+                        notes.rseed,
+                        spendable_notes.address_index,
+                        spendable_notes.source,
+                        spendable_notes.height_spent,
+                        spendable_notes.nullifier,
+                        spendable_notes.position,
+                        tx.return_address
+                    FROM notes
+                    JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+                    LEFT JOIN tx ON spendable_notes.wallet_id = tx.wallet_id
+                        AND spendable_notes.tx_hash = tx.tx_hash
+                    WHERE spendable_notes.wallet_id = x'{wallet}'
+                    AND hex(spendable_notes.nullifier) = \"{}\"",
+                    hex::encode_upper(nullifier_bytes)
+                ))?
+                .query_and_then((), |row| SpendableNoteRecord::try_from(row))?
+                .next()
+                .transpose()
+        })
+        .await?
+    }
+
+    async fn notes(
+        &self,
+        wallet: WalletId,
+        include_spent: bool,
+        asset_id: Option<asset::Id>,
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
+        // If set, return spent notes as well as unspent notes.
+        // bool include_spent = 2;
+        let spent_clause = match include_spent {
+            false => "NULL",
+            true => "height_spent",
+        };
+
+        // If set, only return notes with the specified asset id.
+        // core.crypto.v1.AssetId asset_id = 3;
+        let asset_clause = asset_id
+            .map(|id| format!("x'{}'", hex::encode(id.to_bytes())))
+            .unwrap_or_else(|| "asset_id".to_string());
+
+        // The address index is filtered by account by the caller, since we need to be indexing
+        // notes by *account*, not just by address index.
+
+        let pool = self.pool.clone();
+        let wallet = hex::encode(wallet.0);
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare(&format!(
+                    "SELECT notes.note_commitment,
+                        spendable_notes.height_created,
+                        notes.address,
+                        notes.amount,
+                        notes.asset_id,
+                        notes.rseed,
+                        spendable_notes.address_index,
+                        spendable_notes.source,
+                        spendable_notes.height_spent,
+                        spendable_notes.nullifier,
+                        spendable_notes.position,
+                        tx.return_address
+                FROM notes
+                JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+                LEFT JOIN tx ON spendable_notes.wallet_id = tx.wallet_id
+                    AND spendable_notes.tx_hash = tx.tx_hash
+                WHERE spendable_notes.wallet_id = x'{wallet}'
+                AND spendable_notes.height_spent IS {spent_clause}
+                AND notes.asset_id IS {asset_clause}"
+                ))?
+                .query_and_then((), |row| SpendableNoteRecord::try_from(row))?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn notes_for_voting(
+        &self,
+        wallet: WalletId,
+        address_index: Option<AddressIndex>,
+        votable_at_height: u64,
+    ) -> anyhow::Result<Vec<(SpendableNoteRecord, IdentityKey)>> {
+        // If set, only return notes with the specified address index.
+        // crypto.AddressIndex address_index = 3;
+        let address_clause = address_index
+            .map(|d| format!("x'{}'", hex::encode(d.to_bytes())))
+            .unwrap_or_else(|| "address_index".to_string());
+
+        let pool = self.pool.clone();
+        let wallet = hex::encode(wallet.0);
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let dbtx = lock.transaction()?;
+
+            let spendable_note_records: Vec<SpendableNoteRecord> = dbtx
+                .prepare(&format!(
+                    "SELECT notes.note_commitment,
+                        spendable_notes.height_created,
+                        notes.address,
+                        notes.amount,
+                        notes.asset_id,
+                        notes.rseed,
+                        spendable_notes.address_index,
+                        spendable_notes.source,
+                        spendable_notes.height_spent,
+                        spendable_notes.nullifier,
+                        spendable_notes.position
+                    FROM
+                        notes JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+                    WHERE
+                        spendable_notes.wallet_id = x'{wallet}'
+                        AND spendable_notes.address_index IS {address_clause}
+                        AND notes.asset_id IN (
+                            SELECT asset_id FROM assets WHERE denom LIKE '_delegation\\_%' ESCAPE '\\'
+                        )
+                        AND ((spendable_notes.height_spent IS NULL) OR (spendable_notes.height_spent > {votable_at_height}))
+                        AND (spendable_notes.height_created < {votable_at_height})
+                    ",
+                ))?
+                .query_and_then((), |row| row.try_into())?
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            // TODO: this could be internalized into the SQL query in principle, but it's easier to
+            // do it this way; if it becomes slow, we can do it better
+            let mut results = Vec::new();
+            for record in spendable_note_records {
+                let asset_id = record.note.asset_id().to_bytes().to_vec();
+                let denom: String = dbtx.query_row_and_then(
+                    "SELECT denom FROM assets WHERE asset_id = ?1",
+                    [asset_id],
+                    |row| row.get("denom"),
+                )?;
+
+                let identity_key = DelegationToken::from_str(&denom)
+                    .context("invalid delegation token denom")?
+                    .validator();
+
+                results.push((record, identity_key));
+            }
+
+            Ok(results)
+        }).await?
+    }
+
+    async fn notes_by_sender(
+        &self,
+        wallet: WalletId,
+        return_address: &Address,
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
+        let pool = self.pool.clone();
+
+        let query = "SELECT notes.note_commitment,
+            spendable_notes.height_created,
+            notes.address,
+            notes.amount,
+            notes.asset_id,
+            notes.rseed,
+            spendable_notes.address_index,
+            spendable_notes.source,
+            spendable_notes.height_spent,
+            spendable_notes.nullifier,
+            spendable_notes.position
+            FROM notes
+            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            JOIN tx ON spendable_notes.wallet_id = tx.wallet_id
+                AND spendable_notes.tx_hash = tx.tx_hash
+            WHERE spendable_notes.wallet_id = ?1 AND tx.return_address = ?2";
+
+        let return_address = return_address.to_vec();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare(query)?
+                .query_and_then((&wallet.0[..], return_address), |record| record.try_into())?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn notes_by_address(
+        &self,
+        wallet: WalletId,
+        address: &Address,
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
+        let pool = self.pool.clone();
+
+        let query = "SELECT notes.note_commitment,
+            spendable_notes.height_created,
+            notes.address,
+            notes.amount,
+            notes.asset_id,
+            notes.rseed,
+            spendable_notes.address_index,
+            spendable_notes.source,
+            spendable_notes.height_spent,
+            spendable_notes.nullifier,
+            spendable_notes.position
+            FROM notes
+            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            WHERE spendable_notes.wallet_id = ?1 AND notes.address = ?2";
+
+        let address = address.to_vec();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare(query)?
+                .query_and_then((&wallet.0[..], address), |record| record.try_into())?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn notes_by_memo(
+        &self,
+        wallet: WalletId,
+        memo_text: &str,
+    ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
+        let pool = self.pool.clone();
+
+        let query = "SELECT notes.note_commitment,
+            spendable_notes.height_created,
+            notes.address,
+            notes.amount,
+            notes.asset_id,
+            notes.rseed,
+            spendable_notes.address_index,
+            spendable_notes.source,
+            spendable_notes.height_spent,
+            spendable_notes.nullifier,
+            spendable_notes.position,
+            tx.return_address
+            FROM notes
+            JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+            JOIN tx ON spendable_notes.wallet_id = tx.wallet_id
+                AND spendable_notes.tx_hash = tx.tx_hash
+            WHERE spendable_notes.wallet_id = ?1 AND tx.memo_text = ?2";
+
+        let memo_text = memo_text.to_string();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare(query)?
+                .query_and_then((&wallet.0[..], memo_text), |record| record.try_into())?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn swap_by_commitment(
+        &self,
+        wallet: WalletId,
+        swap_commitment: StateCommitment,
+    ) -> anyhow::Result<Option<SwapRecord>> {
+        let pool = self.pool.clone();
+        let wallet = hex::encode(wallet.0);
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare(&format!(
+                    "SELECT * FROM swaps WHERE swaps.wallet_id = x'{wallet}' AND swaps.swap_commitment = x'{}'",
+                    hex::encode(swap_commitment.0.to_bytes())
+                ))?
+                .query_and_then((), |record| record.try_into())?
+                .next()
+                .transpose()
+        })
+        .await?
+    }
+
+    async fn unclaimed_swaps(&self, wallet: WalletId) -> anyhow::Result<Vec<SwapRecord>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare("SELECT * FROM swaps WHERE swaps.wallet_id = ?1 AND swaps.height_claimed is NULL")?
+                .query_and_then([&wallet.0[..]], |record| record.try_into())?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn nullifier_status(
+        &self,
+        wallet: WalletId,
+        nullifier: Nullifier,
+    ) -> anyhow::Result<Option<bool>> {
+        let pool = self.pool.clone();
+
+        let nullifier_bytes = nullifier.0.to_bytes().to_vec();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT height_spent FROM spendable_notes WHERE wallet_id = ?1 AND nullifier = ?2",
+                )?
+                .query_and_then((&wallet.0[..], nullifier_bytes), |row| {
+                    let height_spent: Option<u64> = row.get("height_spent")?;
+                    anyhow::Ok(height_spent.is_some())
+                })?
+                .next()
+                .transpose()
+        })
+        .await?
+    }
+
+    async fn filter_nullifiers(
+        &self,
+        wallet: WalletId,
+        nullifiers: Vec<Nullifier>,
+    ) -> anyhow::Result<Vec<Nullifier>> {
+        let pool = self.pool.clone();
+        let wallet = hex::encode(wallet.0);
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare(&format!(
+                    "SELECT nullifier FROM (
+                        SELECT nullifier FROM spendable_notes WHERE wallet_id = x'{wallet}'
+                        UNION SELECT nullifier FROM swaps WHERE wallet_id = x'{wallet}'
+                        UNION SELECT nullifier FROM tx_by_nullifier WHERE wallet_id = x'{wallet}'
+                    ) WHERE nullifier IN ({})",
+                    nullifiers
+                        .iter()
+                        .map(|x| format!("x'{}'", hex::encode(x.0.to_bytes())))
+                        .collect::<Vec<String>>()
+                        .join(",")
+                ))?
+                .query_and_then((), |row| {
+                    let nullifier: Vec<u8> = row.get("nullifier")?;
+                    nullifier.as_slice().try_into()
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn transaction_hashes(
+        &self,
+        wallet: WalletId,
+        start_height: u64,
+        end_height: u64,
+    ) -> anyhow::Result<Vec<(u64, Vec<u8>)>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT block_height, tx_hash
+                    FROM tx
+                    WHERE wallet_id = ?1 AND block_height BETWEEN ?2 AND ?3",
+                )?
+                .query_and_then(
+                    (&wallet.0[..], start_height as i64, end_height as i64),
+                    |row| {
+                        let block_height: u64 = row.get("block_height")?;
+                        let tx_hash: Vec<u8> = row.get("tx_hash")?;
+                        anyhow::Ok((block_height, tx_hash))
+                    },
+                )?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn transactions(
+        &self,
+        wallet: WalletId,
+        start_height: u64,
+        end_height: u64,
+    ) -> anyhow::Result<Vec<(u64, Vec<u8>, Transaction)>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT block_height, tx_hash, tx_bytes
+                    FROM tx
+                    WHERE wallet_id = ?1 AND block_height BETWEEN ?2 AND ?3",
+                )?
+                .query_and_then(
+                    (&wallet.0[..], start_height as i64, end_height as i64),
+                    |row| {
+                        let block_height: u64 = row.get("block_height")?;
+                        let tx_hash: Vec<u8> = row.get("tx_hash")?;
+                        let tx_bytes: Vec<u8> = row.get("tx_bytes")?;
+                        let tx = Transaction::decode(tx_bytes.as_slice())?;
+                        anyhow::Ok((block_height, tx_hash, tx))
+                    },
+                )?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn transaction_by_hash(
+        &self,
+        wallet: WalletId,
+        tx_hash: &[u8],
+    ) -> anyhow::Result<Option<(u64, Transaction)>> {
+        let pool = self.pool.clone();
+        let tx_hash = tx_hash.to_vec();
+
+        spawn_blocking(move || {
+            if let Some((block_height, tx_bytes)) = pool
+                .get()?
+                .prepare_cached(
+                    "SELECT block_height, tx_bytes FROM tx WHERE wallet_id = ?1 AND tx_hash = ?2",
+                )?
+                .query_row((&wallet.0[..], tx_hash), |row| {
+                    let block_height: u64 = row.get("block_height")?;
+                    let tx_bytes: Vec<u8> = row.get("tx_bytes")?;
+                    Ok((block_height, tx_bytes))
+                })
+                .optional()?
+            {
+                let tx = Transaction::decode(tx_bytes.as_slice())?;
+                Ok(Some((block_height, tx)))
+            } else {
+                Ok(None)
+            }
+        })
+        .await?
+    }
+
+    async fn transactions_matching_memo(
+        &self,
+        wallet: WalletId,
+        pattern: String,
+    ) -> anyhow::Result<Vec<(u64, Vec<u8>, Transaction, String)>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached("SELECT block_height, tx_hash, tx_bytes, memo_text FROM tx WHERE wallet_id = ?1 AND memo_text LIKE ?2 ESCAPE '\\'")?
+                .query_and_then((&wallet.0[..], pattern), |row| {
+                    let block_height: u64 = row.get("block_height")?;
+                    let tx_hash: Vec<u8> = row.get("tx_hash")?;
+                    let tx_bytes: Vec<u8> = row.get("tx_bytes")?;
+                    let tx = Transaction::decode(tx_bytes.as_slice())?;
+                    let memo_text: String = row.get("memo_text")?;
+                    anyhow::Ok((block_height, tx_hash, tx, memo_text))
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn all_assets(&self) -> anyhow::Result<Vec<Metadata>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached("SELECT metadata FROM assets")?
+                .query_and_then([], |row| {
+                    let metadata_json = row.get::<_, String>("metadata")?;
+                    let denom_metadata = serde_json::from_str(&metadata_json)?;
+
+                    anyhow::Ok(denom_metadata)
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn asset_by_id(&self, id: &Id) -> anyhow::Result<Option<Metadata>> {
+        let id = id.to_bytes().to_vec();
+
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached("SELECT metadata FROM assets WHERE asset_id = ?1")?
+                .query_and_then([id], |row| {
+                    let metadata_json = row.get::<_, String>("metadata")?;
+                    let denom_metadata = serde_json::from_str(&metadata_json)?;
+                    anyhow::Ok(denom_metadata)
+                })?
+                .next()
+                .transpose()
+        })
+        .await?
+    }
+
+    async fn assets_matching(&self, pattern: String) -> anyhow::Result<Vec<Metadata>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached("SELECT metadata FROM assets WHERE denom LIKE ?1 ESCAPE '\\'")?
+                .query_and_then([pattern], |row| {
+                    let metadata_json = row.get::<_, String>("metadata")?;
+                    let denom_metadata = serde_json::from_str(&metadata_json)?;
+                    anyhow::Ok(denom_metadata)
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn record_asset(&self, asset: Metadata) -> anyhow::Result<()> {
+        let asset_id = asset.id().to_bytes().to_vec();
+        let denom = asset.base_denom().denom;
+        let metadata_json = serde_json::to_string(&asset)?;
+
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "INSERT OR REPLACE INTO assets (asset_id, denom, metadata) VALUES (?1, ?2, ?3)",
+                    (asset_id, denom, metadata_json),
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn batch_swap_outputs(
+        &self,
+        start_height: u64,
+        end_height: u64,
+    ) -> anyhow::Result<Vec<BatchSwapOutputData>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT output_data FROM batch_swap_outputs
+                    WHERE height BETWEEN ?1 AND ?2
+                    ORDER BY height",
+                )?
+                .query_and_then((start_height as i64, end_height as i64), |row| {
+                    let output_data: Vec<u8> = row.get("output_data")?;
+                    BatchSwapOutputData::decode(output_data.as_slice())
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn record_auction_with_state(
+        &self,
+        wallet: WalletId,
+        auction_id: AuctionId,
+        auction_state: u64,
+        height: u64,
+    ) -> anyhow::Result<()> {
+        let auction_id = auction_id.0.to_vec();
+        let height = height as i64;
+
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let tx = lock.transaction()?;
+            tx.execute(
+                "INSERT OR IGNORE INTO auctions (wallet_id, auction_id, auction_state, note_commitment) VALUES (?1, ?2, ?3, NULL)",
+                (&wallet.0[..], auction_id.clone(), auction_state),
+            )?;
+            tx.execute(
+                "UPDATE auctions SET auction_state = ?3 WHERE wallet_id = ?1 AND auction_id = ?2",
+                (&wallet.0[..], auction_id.clone(), auction_state),
+            )
+                .map_err(anyhow::Error::from)?;
+            tx.execute(
+                "INSERT INTO auction_updates (wallet_id, auction_id, height, auction_state) VALUES (?1, ?2, ?3, ?4)",
+                (&wallet.0[..], auction_id, height, auction_state),
+            )?;
+
+            tx.commit()?;
+            Ok::<(), anyhow::Error>(())
+            })
+            .await??;
+
+        Ok(())
+    }
+
+    async fn update_auction_with_note_commitment(
+        &self,
+        wallet: WalletId,
+        auction_id: AuctionId,
+        note_commitment: StateCommitment,
+    ) -> anyhow::Result<()> {
+        let auction_id = auction_id.0.to_vec();
+        let blob_nc = note_commitment.0.to_bytes().to_vec();
+
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "UPDATE auctions SET (note_commitment) = ?1 WHERE wallet_id = ?2 AND auction_id = ?3",
+                    (blob_nc, &wallet.0[..], auction_id),
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn fetch_auctions_by_account(
+        &self,
+        wallet: WalletId,
+        account_filter: Option<AddressIndex>,
+        include_inactive: bool,
+    ) -> anyhow::Result<Vec<(AuctionId, SpendableNoteRecord, u64)>> {
+        let account_clause = account_filter
+            .map(|idx| {
+                format!(
+                    "AND spendable_notes.address_index = x'{}'",
+                    hex::encode(idx.to_bytes())
+                )
+            })
+            .unwrap_or_else(|| "".to_string());
+
+        let active_clause = if !include_inactive {
+            "AND auctions.auction_state = 0"
+        } else {
+            ""
+        };
+
+        let query = format!(
+            "SELECT auctions.auction_id, spendable_notes.*, notes.*, auctions.auction_state
+                 FROM auctions
+                 JOIN spendable_notes ON auctions.wallet_id = spendable_notes.wallet_id
+                    AND auctions.note_commitment = spendable_notes.note_commitment
+                 JOIN notes ON auctions.note_commitment = notes.note_commitment
+                 WHERE auctions.wallet_id = x'{wallet}'
+                 {account_clause}
+                 {active_clause}",
+            wallet = hex::encode(wallet.0),
+            account_clause = account_clause,
+            active_clause = active_clause,
+        );
+
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut conn = pool.get()?;
+            let tx = conn.transaction()?;
+
+            let spendable_note_records: Vec<(AuctionId, SpendableNoteRecord, u64)> = tx
+                .prepare(&query)?
+                .query_and_then((), |row| {
+                    let raw_auction_id: Vec<u8> = row.get("auction_id")?;
+                    let array_auction_id: [u8; 32] = raw_auction_id
+                        .try_into()
+                        .map_err(|_| anyhow!("auction id must be 32 bytes"))?;
+                    let auction_id = AuctionId(array_auction_id);
+                    let spendable_note_record: SpendableNoteRecord = row.try_into()?;
+                    let local_seq: u64 = row.get("auction_state")?;
+                    Ok((auction_id, spendable_note_record, local_seq))
+                })?
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            Ok(spendable_note_records)
+        })
+        .await?
+    }
+
+    async fn record_position(
+        &self,
+        wallet: WalletId,
+        position: Position,
+        height: u64,
+    ) -> anyhow::Result<()> {
+        let position_id = position.id().0.to_vec();
+
+        let position_state = position.state.to_string();
+        let trading_pair = position.phi.pair.to_string();
+        let height = height as i64;
+
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let tx = lock.transaction()?;
+            tx.execute(
+                "INSERT OR REPLACE INTO positions (wallet_id, position_id, position_state, trading_pair) VALUES (?1, ?2, ?3, ?4)",
+                (&wallet.0[..], position_id.clone(), position_state.clone(), trading_pair),
+            )?;
+            tx.execute(
+                "INSERT INTO position_updates (wallet_id, position_id, height, position_state) VALUES (?1, ?2, ?3, ?4)",
+                (&wallet.0[..], position_id, height, position_state),
+            )?;
+
+            tx.commit()?;
+            anyhow::Ok(())
+        })
+            .await??;
+
+        Ok(())
+    }
+
+    async fn update_position(
+        &self,
+        wallet: WalletId,
+        position_id: position::Id,
+        position_state: position::State,
+        height: u64,
+    ) -> anyhow::Result<()> {
+        let position_id = position_id.0.to_vec();
+        let position_state = position_state.to_string();
+        let height = height as i64;
+
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let tx = lock.transaction()?;
+            let updated = tx.execute(
+                "UPDATE positions SET (position_state) = ?1 WHERE wallet_id = ?2 AND position_id = ?3",
+                (position_state.clone(), &wallet.0[..], position_id.clone()),
+            )?;
+            // Only the positions of the wallet are tracked, so neither is their history.
+            if updated > 0 {
+                tx.execute(
+                    "INSERT INTO position_updates (wallet_id, position_id, height, position_state) VALUES (?1, ?2, ?3, ?4)",
+                    (&wallet.0[..], position_id, height, position_state),
+                )?;
+            }
+
+            tx.commit()?;
+            anyhow::Ok(())
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn owned_position_ids(
+        &self,
+        wallet: WalletId,
+        position_state: Option<State>,
+        trading_pair: Option<TradingPair>,
+    ) -> anyhow::Result<Vec<position::Id>> {
+        let pool = self.pool.clone();
+
+        let state_clause = match position_state {
+            Some(state) => format!("position_state = \"{}\"", state),
+            None => "".to_string(),
+        };
+
+        let pair_clause = match trading_pair {
+            Some(pair) => format!("trading_pair = \"{}\"", pair),
+            None => "".to_string(),
+        };
+
+        spawn_blocking(move || {
+            let mut q = "SELECT position_id FROM positions WHERE wallet_id = ?1".to_string();
+            match (position_state.is_some(), trading_pair.is_some()) {
+                (true, true) => {
+                    q = q + " AND " + &state_clause + " AND " + &pair_clause;
+                }
+                (true, false) => {
+                    q = q + " AND " + &state_clause;
+                }
+                (false, true) => {
+                    q = q + " AND " + &pair_clause;
+                }
+                (false, false) => (),
+            };
+
+            pool.get()?
+                .prepare_cached(&q)?
+                .query_and_then([&wallet.0[..]], |row| {
+                    let position_id: Vec<u8> = row.get("position_id")?;
+                    Ok(position::Id(position_id.as_slice().try_into()?))
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn contacts(&self, wallet: WalletId) -> anyhow::Result<Vec<(Address, String)>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT address, label FROM contacts WHERE wallet_id = ?1 ORDER BY label",
+                )?
+                .query_and_then([&wallet.0[..]], |row| {
+                    let address: Vec<u8> = row.get("address")?;
+                    let label: String = row.get("label")?;
+                    anyhow::Ok((Address::try_from(address.as_slice())?, label))
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn set_contact(
+        &self,
+        wallet: WalletId,
+        address: &Address,
+        label: String,
+    ) -> anyhow::Result<()> {
+        let address = address.to_vec();
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "INSERT INTO contacts (wallet_id, address, label) VALUES (?1, ?2, ?3)
+                    ON CONFLICT (wallet_id, address) DO UPDATE SET label = excluded.label",
+                    (&wallet.0[..], address, label),
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn delete_contact(&self, wallet: WalletId, address: &Address) -> anyhow::Result<bool> {
+        let address = address.to_vec();
+        let pool = self.pool.clone();
+
+        let deleted = spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "DELETE FROM contacts WHERE wallet_id = ?1 AND address = ?2",
+                    (&wallet.0[..], address),
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        Ok(deleted > 0)
+    }
+
+    async fn account_labels(&self, wallet: WalletId) -> anyhow::Result<BTreeMap<u32, String>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached("SELECT account, label FROM account_labels WHERE wallet_id = ?1")?
+                .query_and_then([&wallet.0[..]], |row| {
+                    let account: i64 = row.get("account")?;
+                    let label: String = row.get("label")?;
+                    anyhow::Ok((u32::try_from(account)?, label))
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn set_account_label(
+        &self,
+        wallet: WalletId,
+        account: u32,
+        label: String,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "INSERT INTO account_labels (wallet_id, account, label) VALUES (?1, ?2, ?3)
+                    ON CONFLICT (wallet_id, account) DO UPDATE SET label = excluded.label",
+                    (&wallet.0[..], i64::from(account), label),
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn delete_account_label(&self, wallet: WalletId, account: u32) -> anyhow::Result<bool> {
+        let pool = self.pool.clone();
+
+        let deleted = spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "DELETE FROM account_labels WHERE wallet_id = ?1 AND account = ?2",
+                    (&wallet.0[..], i64::from(account)),
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        Ok(deleted > 0)
+    }
+
+    async fn invoices(&self, wallet: WalletId) -> anyhow::Result<Vec<(PaymentRequest, bool)>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT payment_request, dedicated_address FROM invoices
+                    WHERE wallet_id = ?1 ORDER BY invoice_id",
+                )?
+                .query_and_then([&wallet.0[..]], |row| {
+                    let request: Vec<u8> = row.get("payment_request")?;
+                    let dedicated_address: bool = row.get("dedicated_address")?;
+                    anyhow::Ok((
+                        PaymentRequest::decode(request.as_slice())?,
+                        dedicated_address,
+                    ))
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    async fn record_invoice(
+        &self,
+        wallet: WalletId,
+        invoice_id: &str,
+        request: &PaymentRequest,
+        dedicated_address: bool,
+    ) -> anyhow::Result<()> {
+        let invoice_id = invoice_id.to_string();
+        let request = request.encode_to_vec();
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "INSERT INTO invoices (wallet_id, invoice_id, payment_request, dedicated_address)
+                    VALUES (?1, ?2, ?3, ?4)",
+                    (&wallet.0[..], invoice_id, request, dedicated_address),
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    async fn delete_invoice(&self, wallet: WalletId, invoice_id: &str) -> anyhow::Result<bool> {
+        let invoice_id = invoice_id.to_string();
+        let pool = self.pool.clone();
+
+        let deleted = spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "DELETE FROM invoices WHERE wallet_id = ?1 AND invoice_id = ?2",
+                    (&wallet.0[..], invoice_id),
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        Ok(deleted > 0)
+    }
+
+    async fn give_advice(&self, note: Note) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let dbtx = lock.transaction()?;
+
+            Self::record_note_inner(&dbtx, &note)?;
+
+            dbtx.commit()?;
+
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    async fn scan_advice(
+        &self,
+        wallet: WalletId,
+        note_commitments: Vec<note::StateCommitment>,
+    ) -> anyhow::Result<BTreeMap<note::StateCommitment, Note>> {
+        let pool = self.pool.clone();
+        let wallet = hex::encode(wallet.0);
+
+        // This query gives advice about notes which are known but which have not already been recorded as spendable,
+        // in part to avoid revealing information about which notes have been spent.
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare(&format!(
+                    "SELECT notes.note_commitment,
+                        notes.address,
+                        notes.amount,
+                        notes.asset_id,
+                        notes.rseed
+                    FROM notes
+                    LEFT OUTER JOIN spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+                        AND spendable_notes.wallet_id = x'{wallet}'
+                    WHERE (spendable_notes.note_commitment IS NULL) AND (notes.note_commitment IN ({}))",
+                    note_commitments
+                        .iter()
+                        .map(|cm| format!("x'{}'", hex::encode(cm.0.to_bytes())))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))?
+                .query_and_then((), |row| {
+                    let address = Address::try_from(row.get::<_, Vec<u8>>("address")?)?;
+                    let amount = row.get::<_, [u8; 16]>("amount")?;
+                    let amount_u128: u128 = u128::from_be_bytes(amount);
+                    let asset_id = asset::Id(Fq::from_bytes_checked(&row.get::<_, [u8; 32]>("asset_id")?).expect("asset id malformed"));
+                    let rseed = Rseed(row.get::<_, [u8; 32]>("rseed")?);
+                    let note = Note::from_parts(
+                        address,
+                        Value {
+                            amount: amount_u128.into(),
+                            asset_id,
+                        },
+                        rseed,
+                    )?;
+                    anyhow::Ok((note.commit(), note))
+                })?
+                .collect::<anyhow::Result<BTreeMap<_, _>>>()
+        }).await?
+    }
+
+    async fn record_block(
+        &self,
+        wallet: WalletId,
+        fvk: &FullViewingKey,
+        filtered_block: &FilteredBlock,
+        transactions: Vec<Transaction>,
+        app_parameters: Option<AppParameters>,
+        mut new_sct: tct::Tree,
+    ) -> anyhow::Result<tct::Tree> {
+        let pool = self.pool.clone();
+        let fvk = fvk.clone();
+        let filtered_block = filtered_block.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let mut dbtx = lock.transaction()?;
+
+            if let Some(params) = app_parameters {
+                let params_bytes = params.encode_to_vec();
+                // We expect app_params to be present already but may as well use an upsert
+                dbtx.execute(
+                    "INSERT INTO kv (k, v) VALUES ('app_params', ?1)
+                    ON CONFLICT(k) DO UPDATE SET v = excluded.v",
+                    [&params_bytes[..]],
+                )?;
+            }
+
+            // Insert new note records into storage
+            for note_record in filtered_block.new_notes.values() {
+                let note_commitment = note_record.note_commitment.0.to_bytes().to_vec();
+                let height_created = filtered_block.height as i64;
+                let address_index = note_record.address_index.to_bytes().to_vec();
+                let nullifier = note_record.nullifier.to_bytes().to_vec();
+                let position = (u64::from(note_record.position)) as i64;
+                let source = note_record.source.encode_to_vec();
+                // Check if the note is from a transaction, if so, include the tx hash (id)
+                let tx_hash = match note_record.source {
+                    CommitmentSource::Transaction { id } => id,
+                    _ => None,
+                };
+
+                // Record the inner note data in the notes table
+                Self::record_note_inner(&dbtx, &note_record.note)?;
+
+                dbtx.execute(
+                    "INSERT INTO spendable_notes
+                    (wallet_id, note_commitment, nullifier, position, height_created, address_index, source, height_spent, tx_hash)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, NULL, ?8)
+                    ON CONFLICT (wallet_id, note_commitment)
+                    DO UPDATE SET nullifier = excluded.nullifier,
+                    position = excluded.position,
+                    height_created = excluded.height_created,
+                    address_index = excluded.address_index,
+                    source = excluded.source,
+                    height_spent = excluded.height_spent,
+                    tx_hash = excluded.tx_hash",
+                    (
+                        &wallet.0[..],
+                        &note_commitment,
+                        &nullifier,
+                        &position,
+                        &height_created,
+                        &address_index,
+                        &source,
+                        // height_spent is NULL because the note is newly discovered
+                        &tx_hash,
+                    ),
+                )?;
+            }
+
+            // Insert new swap records into storage
+            for swap in filtered_block.new_swaps.values() {
+                let swap_commitment = swap.swap_commitment.0.to_bytes().to_vec();
+                let swap_bytes = swap.swap.encode_to_vec();
+                let position = (u64::from(swap.position)) as i64;
+                let nullifier = swap.nullifier.to_bytes().to_vec();
+                let source = swap.source.encode_to_vec();
+                let output_data = swap.output_data.encode_to_vec();
+                let height_created = filtered_block.height as i64;
+
+                dbtx.execute(
+                    "INSERT INTO swaps (wallet_id, swap_commitment, swap, position, nullifier, output_data, height_created, height_claimed, source)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, NULL, ?8)
+                    ON CONFLICT (wallet_id, swap_commitment)
+                    DO UPDATE SET swap = excluded.swap,
+                    position = excluded.position,
+                    nullifier = excluded.nullifier,
+                    output_data = excluded.output_data,
+                    height_created = excluded.height_created,
+                    height_claimed = excluded.height_claimed,
+                    source = excluded.source",
+                    (
+                        &wallet.0[..],
+                        &swap_commitment,
+                        &swap_bytes,
+                        &position,
+                        &nullifier,
+                        &output_data,
+                        &height_created,
+                        // height_claimed is NULL because the swap is newly discovered
+                        &source,
+                    ),
+                )?;
+            }
+
+            // The batch swap outputs are the same for every wallet scanning the block, so they may
+            // already be recorded.
+            for output_data in &filtered_block.batch_swap_outputs {
+                dbtx.execute(
+                    "INSERT INTO batch_swap_outputs (height, trading_pair, output_data)
+                    VALUES (?1, ?2, ?3)
+                    ON CONFLICT (height, trading_pair) DO NOTHING",
+                    (
+                        output_data.height as i64,
+                        output_data.trading_pair.encode_to_vec(),
+                        output_data.encode_to_vec(),
+                    ),
+                )?;
+            }
+
+            // Update any rows of the table with matching nullifiers to have height_spent
+            for nullifier in &filtered_block.spent_nullifiers {
+                let height_spent = filtered_block.height as i64;
+                let nullifier_bytes = nullifier.to_bytes().to_vec();
+
+                let spent_commitment: Option<StateCommitment> = dbtx.prepare_cached(
+                    "UPDATE spendable_notes SET height_spent = ?1 WHERE wallet_id = ?2 AND nullifier = ?3 RETURNING note_commitment"
+                )?
+                    .query_and_then(
+                        (height_spent, &wallet.0[..], &nullifier_bytes),
+                        |row| {
+                            let bytes: Vec<u8> = row.get("note_commitment")?;
+                            StateCommitment::try_from(&bytes[..]).context("invalid commitment bytes")
+                        },
+                    )?
+                    .next()
+                    .transpose()?;
+
+                let swap_commitment: Option<StateCommitment> = dbtx.prepare_cached(
+                    "UPDATE swaps SET height_claimed = ?1 WHERE wallet_id = ?2 AND nullifier = ?3 RETURNING swap_commitment"
+                )?
+                    .query_and_then(
+                        (height_spent, &wallet.0[..], &nullifier_bytes),
+                        |row| {
+                            let bytes: Vec<u8> = row.get("swap_commitment")?;
+                            StateCommitment::try_from(&bytes[..]).context("invalid commitment bytes")
+                        },
+                    )?
+                    .next()
+                    .transpose()?;
+
+                // Check denom type
+                let spent_denom: String
+                    = dbtx.prepare_cached(
+                    "SELECT denom FROM assets
+                        WHERE asset_id ==
+                            (SELECT asset_id FROM notes
+                             WHERE note_commitment ==
+                                (SELECT note_commitment FROM spendable_notes WHERE wallet_id = ?1 AND nullifier = ?2))"
+                )?
+                    .query_and_then(
+                        (&wallet.0[..], &nullifier_bytes),
+                        |row| row.get("denom"),
+                    )?
+                    .next()
+                    .transpose()?
+                    .unwrap_or("unknown".to_string());
+
+                // Mark spent notes as spent
+                if let Some(spent_commitment) = spent_commitment {
+                    tracing::debug!(?nullifier, ?spent_commitment, ?spent_denom, "detected spent note commitment");
+                    // Forget spent note commitments from the SCT unless they are delegation tokens,
+                    // which must be saved to allow voting on proposals that might or might not be
+                    // open presently
+
+                    if DelegationToken::from_str(&spent_denom).is_err() {
+                        tracing::debug!(?nullifier, ?spent_commitment, ?spent_denom, "forgetting spent note commitment");
+                        new_sct.forget(spent_commitment);
+                    }
+                };
+
+                // Mark spent swaps as spent
+                if let Some(spent_swap_commitment) = swap_commitment {
+                    tracing::debug!(?nullifier, ?spent_swap_commitment, "detected and forgetting spent swap commitment");
+                    new_sct.forget(spent_swap_commitment);
+                };
+            }
+
+            // Update SCT table with current SCT state
+            new_sct.to_writer(&mut TreeStore(&mut dbtx, wallet))?;
+
+            // Record all transactions
+            for transaction in transactions {
+                let tx_bytes = transaction.encode_to_vec();
+                // We have to create an explicit temporary borrow, because the sqlx api is bad (see above)
+                let tx_hash_owned = sha2::Sha256::digest(&tx_bytes);
+                let tx_hash = tx_hash_owned.as_slice();
+                let tx_block_height = filtered_block.height as i64;
+                let decrypted_memo = transaction.decrypt_memo(&fvk).ok();
+                let memo_text = decrypted_memo.clone().map_or(None,|x| Some(x.text().to_string()));
+                let return_address = decrypted_memo.map_or(None, |x| Some(x.return_address().to_vec()));
+
+                tracing::debug!(tx_hash = ?hex::encode(tx_hash), "recording extended transaction");
+
+                dbtx.execute(
+                    "INSERT OR IGNORE INTO tx (wallet_id, tx_hash, tx_bytes, block_height, return_address, memo_text) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    (&wallet.0[..], &tx_hash, &tx_bytes, tx_block_height, return_address, memo_text),
+                )?;
+
+                // Associate all of the spent nullifiers with the transaction by hash.
+                for nf in transaction.spent_nullifiers() {
+                    let nf_bytes = nf.0.to_bytes().to_vec();
+                    dbtx.execute(
+                        "INSERT OR IGNORE INTO tx_by_nullifier (wallet_id, nullifier, tx_hash) VALUES (?1, ?2, ?3)",
+                        (&wallet.0[..], &nf_bytes, &tx_hash),
+                    )?;
+                }
+            }
+
+            // Update FMD parameters if they've changed.
+            if filtered_block.fmd_parameters.is_some() {
+                let fmd_parameters_bytes =
+                    &fmd::Parameters::encode_to_vec(&filtered_block.fmd_parameters.ok_or_else(|| anyhow::anyhow!("missing fmd parameters in filtered block"))?)[..];
+
+                dbtx.execute(
+                    "INSERT INTO kv (k, v) VALUES ('fmd_params', ?1)
+                    ON CONFLICT(k) DO UPDATE SET v = excluded.v",
+                    [&fmd_parameters_bytes],
+                )?;
+            }
+
+            // Update gas prices if they've changed.
+            if filtered_block.gas_prices.is_some() {
+                let gas_prices_bytes =
+                    &GasPrices::encode_to_vec(&filtered_block.gas_prices.ok_or_else(|| anyhow::anyhow!("missing gas prices in filtered block"))?)[..];
+
+                dbtx.execute(
+                    "INSERT INTO kv (k, v) VALUES ('gas_prices', ?1)
+                    ON CONFLICT(k) DO UPDATE SET v = excluded.v",
+                    [&gas_prices_bytes],
+                )?;
+            }
+
+            // Record block height as latest synced height
+            let latest_sync_height = filtered_block.height as i64;
+            dbtx.execute(
+                "UPDATE wallets SET sync_height = ?1 WHERE wallet_id = ?2",
+                (latest_sync_height, &wallet.0[..]),
+            )?;
+
+            // Commit the changes to the database
+            dbtx.commit()?;
+
+            anyhow::Ok(new_sct)
+        })
+            .await?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::conformance;
+
+    #[tokio::test]
+    async fn conformance() -> anyhow::Result<()> {
+        conformance::run(|| SqliteBackend::initialize(None::<&str>, None, AppParameters::default()))
+            .await
+    }
+}
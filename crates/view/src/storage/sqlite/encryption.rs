@@ -0,0 +1,189 @@
+//! Encryption of the view database at rest, using SQLCipher.
+//!
+//! This needs the view database to be built with SQLCipher rather than plain sqlite, which is
+//! done by the `sqlcipher` feature of this crate.
+
+use std::{fmt, fs::File, io::Read};
+
+use anyhow::{anyhow, Context};
+use camino::Utf8Path;
+use r2d2_sqlite::rusqlite::{Connection, OpenFlags, OptionalExtension};
+use rand_core::{OsRng, RngCore};
+
+/// The size of the salt which SQLCipher keeps at the start of an encrypted database.
+const SALT_SIZE: usize = 16;
+const KEY_SIZE: usize = 32;
+
+/// The start of every unencrypted sqlite database, where SQLCipher would keep its salt.
+const PLAINTEXT_HEADER: &[u8; SALT_SIZE] = b"SQLite format 3\0";
+
+/// A key to encrypt the view database with.
+#[derive(Clone)]
+pub enum DatabaseKey {
+    /// A password, stretched into a key using Argon2id with the same parameters as an encrypted
+    /// custody config, salted by the database.
+    Password(String),
+    /// A key which is used as it is, such as one derived and stored elsewhere.
+    Raw([u8; KEY_SIZE]),
+}
+
+impl fmt::Debug for DatabaseKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DatabaseKey::Password(_) => f.write_str("DatabaseKey::Password(..)"),
+            DatabaseKey::Raw(_) => f.write_str("DatabaseKey::Raw(..)"),
+        }
+    }
+}
+
+impl DatabaseKey {
+    /// Derive the key for a database with the given salt.
+    fn derive(&self, salt: &[u8; SALT_SIZE]) -> anyhow::Result<[u8; KEY_SIZE]> {
+        let password = match self {
+            DatabaseKey::Raw(key) => return Ok(*key),
+            DatabaseKey::Password(password) => password,
+        };
+        // These follow https://datatracker.ietf.org/doc/html/rfc9106, and must never change, or
+        // existing databases could no longer be opened.
+        let params = argon2::Params::new(1 << 21, 1, 4, Some(KEY_SIZE))
+            .map_err(|e| anyhow!("invalid key derivation parameters: {e}"))?;
+        let mut key = [0u8; KEY_SIZE];
+        argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params)
+            .hash_password_into(password.as_bytes(), salt, &mut key)
+            .map_err(|e| anyhow!("failed to derive a key from the password: {e}"))?;
+        Ok(key)
+    }
+}
+
+/// Find the value of the SQLCipher `key` pragma which opens the database at `path`, or which
+/// creates it encrypted if it doesn't exist yet.
+///
+/// This checks that the database can be opened, so that a wrong key is reported before the
+/// database is used, and that a database which is encrypted is never opened without a key.
+pub(super) fn key_pragma(
+    path: &Utf8Path,
+    key: Option<&DatabaseKey>,
+) -> anyhow::Result<Option<String>> {
+    let header = read_header(path)?;
+    let Some(key) = key else {
+        if header.is_some_and(|header| &header != PLAINTEXT_HEADER) {
+            anyhow::bail!(
+                "the view database at {path} is encrypted, so a password is needed to open it"
+            );
+        }
+        return Ok(None);
+    };
+
+    // Without SQLCipher, the key would be silently ignored, leaving the database in plaintext.
+    Connection::open_in_memory()?
+        .query_row("PRAGMA cipher_version", (), |row| row.get::<_, String>(0))
+        .optional()?
+        .ok_or_else(|| {
+            anyhow!("the view database can't be encrypted, because it was built without SQLCipher")
+        })?;
+
+    let salt = match header {
+        Some(header) if &header == PLAINTEXT_HEADER => anyhow::bail!(
+            "the view database at {path} is not encrypted: it must be reset to encrypt it"
+        ),
+        Some(salt) => salt,
+        // SQLCipher uses the salt given with a raw key when it creates a database.
+        None => {
+            let mut salt = [0u8; SALT_SIZE];
+            OsRng.fill_bytes(&mut salt);
+            salt
+        }
+    };
+    let pragma = format!(
+        "x'{}{}'",
+        hex::encode(key.derive(&salt)?),
+        hex::encode(salt)
+    );
+
+    if header.is_some() {
+        let conn =
+            Connection::open_with_flags(path, OpenFlags::default() & !OpenFlags::SQLITE_OPEN_URI)?;
+        set_key(&conn, &pragma)?;
+        conn.query_row("SELECT count(*) FROM sqlite_master", (), |_| Ok(()))
+            .with_context(|| {
+                format!("cannot decrypt the view database at {path}: is the password wrong?")
+            })?;
+    }
+
+    Ok(Some(pragma))
+}
+
+/// Key a new connection to an encrypted database, before anything else is done with it.
+pub(super) fn set_key(conn: &Connection, pragma: &str) -> Result<(), r2d2_sqlite::rusqlite::Error> {
+    // The pragma is only hex digits, so it can't escape its quotes.
+    conn.execute_batch(&format!("PRAGMA key = \"{pragma}\";"))
+}
+
+/// Read the first bytes of the database at `path`, if it exists and has been written to.
+fn read_header(path: &Utf8Path) -> anyhow::Result<Option<[u8; SALT_SIZE]>> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e).with_context(|| format!("cannot open {path}")),
+    };
+    if file.metadata()?.len() == 0 {
+        return Ok(None);
+    }
+    let mut header = [0u8; SALT_SIZE];
+    file.read_exact(&mut header)
+        .with_context(|| format!("the view database at {path} is too short"))?;
+    Ok(Some(header))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_keys_are_used_as_they_are() -> anyhow::Result<()> {
+        let key = [7u8; KEY_SIZE];
+        assert_eq!(DatabaseKey::Raw(key).derive(&[0u8; SALT_SIZE])?, key);
+        Ok(())
+    }
+
+    #[test]
+    fn keys_are_not_debug_printed() {
+        let key = DatabaseKey::Password("hunter2".to_string());
+        assert!(!format!("{key:?}").contains("hunter2"));
+    }
+
+    #[cfg(feature = "sqlcipher")]
+    #[test]
+    fn encrypted_databases_need_their_key() -> anyhow::Result<()> {
+        let mut name = [0u8; 8];
+        OsRng.fill_bytes(&mut name);
+        let path = camino::Utf8PathBuf::from_path_buf(
+            std::env::temp_dir().join(format!("view-{}.sqlite", hex::encode(name))),
+        )
+        .map_err(|path| anyhow!("temporary path {path:?} is not UTF-8"))?;
+
+        // Raw keys skip the deliberately slow password stretching.
+        let key = DatabaseKey::Raw([1u8; KEY_SIZE]);
+        let pragma = key_pragma(&path, Some(&key))?.expect("a key gives a pragma");
+        let conn = Connection::open(&path)?;
+        set_key(&conn, &pragma)?;
+        conn.execute_batch("CREATE TABLE notes (id INTEGER); INSERT INTO notes VALUES (1);")?;
+        drop(conn);
+
+        let no_key = key_pragma(&path, None);
+        let wrong_key = key_pragma(&path, Some(&DatabaseKey::Raw([2u8; KEY_SIZE])));
+        let same_key = key_pragma(&path, Some(&key));
+        std::fs::remove_file(&path)?;
+
+        assert!(
+            no_key.is_err(),
+            "opened an encrypted database without a key"
+        );
+        assert!(
+            wrong_key.is_err(),
+            "opened an encrypted database with the wrong key"
+        );
+        assert_eq!(same_key?, Some(pragma));
+        Ok(())
+    }
+}
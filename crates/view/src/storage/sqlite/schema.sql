@@ -0,0 +1,241 @@
+-- The hash of this schema file
+CREATE TABLE schema_hash (schema_hash TEXT NOT NULL);
+
+-- The client version that created this database
+CREATE TABLE client_version (client_version TEXT NOT NULL);
+
+-- General-purpose blob storage
+CREATE TABLE kv (
+    k                       TEXT PRIMARY KEY NOT NULL,
+    v                       BLOB NOT NULL
+);
+
+-- The wallets whose private state is kept in this database. Every table below which holds
+-- private state is scoped by the wallet it belongs to.
+CREATE TABLE wallets (
+    wallet_id               BLOB PRIMARY KEY NOT NULL,
+    fvk                     BLOB NOT NULL,
+    -- the last height scanned for this wallet, or -1 before genesis
+    sync_height             BIGINT NOT NULL,
+    -- the height of the first block scanned for this wallet
+    birthday                BIGINT NOT NULL DEFAULT 0,
+    -- the bincode-encoded SCT at the end of the block before the birthday, if it was fetched
+    -- from a node rather than built by replaying the blocks from genesis
+    birthday_frontier       BLOB,
+    -- blocks below this height are replayed to rebuild the SCT, rather than scanned
+    replay_height           BIGINT NOT NULL DEFAULT 0
+);
+
+-- used for storing a cache of known assets
+CREATE TABLE assets (
+    asset_id                BLOB PRIMARY KEY NOT NULL,
+    denom                   TEXT NOT NULL,
+    metadata                TEXT NOT NULL
+);
+
+-- the outputs of the batch swaps in the blocks scanned for any wallet, which price the assets
+-- traded on the dex at each height
+CREATE TABLE batch_swap_outputs (
+    height                  BIGINT NOT NULL,
+    trading_pair            BLOB NOT NULL,
+    output_data             BLOB NOT NULL,
+    PRIMARY KEY (height, trading_pair)
+);
+
+-- the shape information about each wallet's sct, inserted along with the wallet,
+-- with a starting position of 0 and a starting forgotten version of 0
+CREATE TABLE sct_position (
+    wallet_id BLOB PRIMARY KEY NOT NULL,
+    position BIGINT
+);
+
+CREATE TABLE sct_forgotten (
+    wallet_id BLOB PRIMARY KEY NOT NULL,
+    forgotten BIGINT NOT NULL
+);
+
+-- the hashes for nodes in the sct
+CREATE TABLE sct_hashes (
+    wallet_id BLOB NOT NULL,
+    position BIGINT NOT NULL,
+    height   TINYINT NOT NULL,
+    hash     BLOB NOT NULL
+);
+
+-- these indices may help with 2-dimensional range deletion
+CREATE INDEX hash_position_idx ON sct_hashes ( wallet_id, position );
+--CREATE INDEX hash_height_idx ON sct_hashes ( height );
+
+-- all the commitments stored in the sct
+CREATE TABLE sct_commitments (
+    wallet_id BLOB NOT NULL,
+    position BIGINT NOT NULL,
+    commitment BLOB NOT NULL
+);
+
+CREATE INDEX commitment_position_idx ON sct_commitments ( wallet_id, position );
+
+-- look up transaction hashes by nullifier
+CREATE TABLE tx_by_nullifier (
+    wallet_id               BLOB NOT NULL,
+    nullifier               BLOB NOT NULL,
+    tx_hash                 BLOB NOT NULL,
+    PRIMARY KEY (wallet_id, nullifier)
+);
+
+-- list of all known relevant transactions
+CREATE TABLE tx (
+    wallet_id               BLOB NOT NULL,
+    tx_hash                 BLOB NOT NULL,
+    tx_bytes                BLOB NOT NULL,
+    block_height            BIGINT NOT NULL,
+    return_address          BLOB,
+    memo_text               TEXT,
+    PRIMARY KEY (wallet_id, tx_hash)
+);
+
+-- This table just records the mapping from note commitments to note plaintexts.
+-- This is also used as a way to give advice about out-of-band notes during scanning,
+-- by allowing the user to add notes to the database before they are scanned.
+-- Plaintexts are shared between wallets: whether a note is spendable by a wallet is
+-- recorded in the spendable_notes table.
+CREATE TABLE notes (
+    note_commitment         BLOB PRIMARY KEY NOT NULL,
+    address                 BLOB NOT NULL,
+    amount                  BLOB NOT NULL,
+    asset_id                BLOB NOT NULL,
+    rseed                   BLOB NOT NULL
+);
+
+-- general purpose note queries
+CREATE INDEX notes_idx ON notes (
+    address,
+    asset_id,
+    amount
+);
+
+-- Minimal data required for balance tracking
+-- Meant to represent notes which have been accepted into the note set
+CREATE TABLE spendable_notes (
+    wallet_id               BLOB NOT NULL,
+    note_commitment         BLOB NOT NULL,
+    -- the nullifier for this note, used to detect when it is spent
+    nullifier               BLOB NOT NULL,
+    -- the position of the note in the state commitment tree
+    position                BIGINT NOT NULL,
+    -- the height at which the note was created
+    height_created          BIGINT NOT NULL,
+    -- precomputed decryption of the diversifier
+    address_index           BLOB NOT NULL,
+    -- the source of the note (a tx hash or structured data jammed into one)
+    source                  BLOB NOT NULL,
+    -- null if unspent, otherwise spent at height_spent
+    height_spent            BIGINT,
+    -- null if note source is not a transaction, otherwise the tx hash
+    tx_hash                 BLOB,
+    PRIMARY KEY (wallet_id, note_commitment)
+);
+
+CREATE INDEX spendable_notes_by_nullifier_idx ON spendable_notes (
+    wallet_id,
+    nullifier
+);
+
+CREATE INDEX spendable_notes_by_source_idx ON spendable_notes (
+    source
+);
+
+-- general purpose note queries
+CREATE INDEX spendable_notes_idx ON spendable_notes (
+    wallet_id,
+    address_index,
+    height_created,
+    height_spent       -- null if unspent, so spent/unspent is first
+);
+
+-- This table records the mapping from swap commitments to swap plaintexts.
+-- For now we just store the swap plaintexts as a blob.
+CREATE TABLE swaps (
+    wallet_id               BLOB NOT NULL,
+    swap_commitment         BLOB NOT NULL,
+    swap                    BLOB NOT NULL,
+    position                BIGINT NOT NULL,
+    nullifier               BLOB NOT NULL,
+    output_data             BLOB NOT NULL,
+    height_created          BIGINT NOT NULL,
+    height_claimed          BIGINT,
+    source                  BLOB NOT NULL,
+    PRIMARY KEY (wallet_id, swap_commitment)
+);
+
+CREATE INDEX swaps_nullifier_idx ON swaps (wallet_id, nullifier);
+
+CREATE TABLE positions (
+     wallet_id              BLOB NOT NULL,
+     position_id            BLOB NOT NULL,
+     position_state         TEXT NOT NULL,
+     trading_pair           TEXT NOT NULL,
+     PRIMARY KEY (wallet_id, position_id)
+);
+
+-- This table records the user's own auction state, using the
+-- auction id as a primary key. An extra-column is available
+-- to cross-reference note commitments that is associated with
+-- the entry.
+CREATE TABLE auctions (
+     wallet_id              BLOB NOT NULL,
+     auction_id             BLOB NOT NULL,
+     auction_state          BIGINT NOT NULL,
+     note_commitment        BLOB,
+     PRIMARY KEY (wallet_id, auction_id)
+);
+
+-- The height of every change to the state of a position or an auction, so that a rewind can put
+-- them back to their state before the height it rewinds to.
+CREATE TABLE position_updates (
+     seq                    INTEGER PRIMARY KEY,
+     wallet_id              BLOB NOT NULL,
+     position_id            BLOB NOT NULL,
+     height                 BIGINT NOT NULL,
+     position_state         TEXT NOT NULL
+);
+
+CREATE INDEX position_updates_idx ON position_updates (wallet_id, position_id, height);
+
+CREATE TABLE auction_updates (
+     seq                    INTEGER PRIMARY KEY,
+     wallet_id              BLOB NOT NULL,
+     auction_id             BLOB NOT NULL,
+     height                 BIGINT NOT NULL,
+     auction_state          BIGINT NOT NULL
+);
+
+CREATE INDEX auction_updates_idx ON auction_updates (wallet_id, auction_id, height);
+
+-- The user's address book, with a label for each of the addresses they've saved. Like the account
+-- labels, it isn't derived from the chain, so it is kept when a wallet is rescanned.
+CREATE TABLE contacts (
+     wallet_id              BLOB NOT NULL,
+     address                BLOB NOT NULL,
+     label                  TEXT NOT NULL,
+     PRIMARY KEY (wallet_id, address)
+);
+
+-- The labels the user has given to the accounts of each wallet.
+CREATE TABLE account_labels (
+     wallet_id              BLOB NOT NULL,
+     account                BIGINT NOT NULL,
+     label                  TEXT NOT NULL,
+     PRIMARY KEY (wallet_id, account)
+);
+
+-- The invoices the user has created, with the payment request of each, encoded as a proto, which
+-- incoming notes are matched against. Like the address book, they aren't derived from the chain,
+-- so they are kept when a wallet is rescanned.
+CREATE TABLE invoices (
+     wallet_id              BLOB NOT NULL,
+     invoice_id             TEXT NOT NULL,
+     payment_request        BLOB NOT NULL,
+     dedicated_address      BOOLEAN NOT NULL,
+     PRIMARY KEY (wallet_id, invoice_id)
+);
@@ -5,14 +5,16 @@ use genawaiter::{rc::gen, yield_};
 use r2d2_sqlite::rusqlite::Transaction;
 
 use core::fmt::Debug;
+use penumbra_keys::keys::WalletId;
 use penumbra_tct::{
     storage::{Read, StoredPosition, Write},
     structure::Hash,
     Forgotten, Position, StateCommitment,
 };
 
+/// The state commitment tree of one wallet, stored in the database.
 #[derive(Debug)]
-pub struct TreeStore<'a, 'c: 'a>(pub &'a mut Transaction<'c>);
+pub struct TreeStore<'a, 'c: 'a>(pub &'a mut Transaction<'c>, pub WalletId);
 
 impl Read for TreeStore<'_, '_> {
     type Error = anyhow::Error;
@@ -29,10 +31,10 @@ impl Read for TreeStore<'_, '_> {
     fn position(&mut self) -> Result<StoredPosition, Self::Error> {
         let mut stmt = self
             .0
-            .prepare_cached("SELECT position FROM sct_position LIMIT 1")
+            .prepare_cached("SELECT position FROM sct_position WHERE wallet_id = ?1 LIMIT 1")
             .context("failed to prepare position query")?;
         let position = stmt
-            .query_row::<Option<u64>, _, _>([], |row| row.get("position"))
+            .query_row::<Option<u64>, _, _>([&self.1 .0[..]], |row| row.get("position"))
             .context("failed to query position")?
             .map(Position::from)
             .into();
@@ -42,10 +44,10 @@ impl Read for TreeStore<'_, '_> {
     fn forgotten(&mut self) -> Result<Forgotten, Self::Error> {
         let mut stmt = self
             .0
-            .prepare_cached("SELECT forgotten FROM sct_forgotten LIMIT 1")
+            .prepare_cached("SELECT forgotten FROM sct_forgotten WHERE wallet_id = ?1 LIMIT 1")
             .context("failed to prepare forgotten query")?;
         let forgotten = stmt
-            .query_row::<u64, _, _>([], |row| row.get("forgotten"))
+            .query_row::<u64, _, _>([&self.1 .0[..]], |row| row.get("forgotten"))
             .context("failed to query forgotten")?
             .into();
         Ok(forgotten)
@@ -57,11 +59,14 @@ impl Read for TreeStore<'_, '_> {
         let mut stmt = self
             .0
             .prepare_cached(
-                "SELECT hash FROM sct_hashes WHERE position = ?1 AND height = ?2 LIMIT 1",
+                "SELECT hash FROM sct_hashes
+                WHERE wallet_id = ?1 AND position = ?2 AND height = ?3 LIMIT 1",
             )
             .context("failed to prepare hash query")?;
         let bytes = stmt
-            .query_row::<Option<Vec<u8>>, _, _>((&position, &height), |row| row.get("hash"))
+            .query_row::<Option<Vec<u8>>, _, _>((&self.1 .0[..], &position, &height), |row| {
+                row.get("hash")
+            })
             .context("failed to query hash")?;
 
         bytes
@@ -83,11 +88,14 @@ impl Read for TreeStore<'_, '_> {
         // The iterator has to *own* the stmt because the rows borrow from it, so we use the
         // `genawaiter` crate to shove the entire preparation of the iterator into an (implicit)
         // async block, which handles the desuguaring to properly own the stmt for us.
+        let wallet = self.1;
         Box::new(
             gen!({
                 let mut stmt = match self
                     .0
-                    .prepare_cached("SELECT position, height, hash FROM sct_hashes")
+                    .prepare_cached(
+                        "SELECT position, height, hash FROM sct_hashes WHERE wallet_id = ?1",
+                    )
                     .context("failed to prepare hashes query")
                 {
                     Ok(stmt) => stmt,
@@ -100,7 +108,7 @@ impl Read for TreeStore<'_, '_> {
                 };
 
                 let rows = match stmt
-                    .query_and_then([], |row| {
+                    .query_and_then([&wallet.0[..]], |row| {
                         let position: i64 = row.get("position")?;
                         let height: u8 = row.get("height")?;
                         let hash: Vec<u8> = row.get("hash")?;
@@ -139,11 +147,15 @@ impl Read for TreeStore<'_, '_> {
 
         let mut stmt = self
             .0
-            .prepare_cached("SELECT commitment FROM sct_commitments WHERE position = ?1 LIMIT 1")
+            .prepare_cached(
+                "SELECT commitment FROM sct_commitments WHERE wallet_id = ?1 AND position = ?2 LIMIT 1",
+            )
             .context("failed to prepare commitment query")?;
 
         let bytes = stmt
-            .query_row::<Option<Vec<u8>>, _, _>((&position,), |row| row.get("commitment"))
+            .query_row::<Option<Vec<u8>>, _, _>((&self.1 .0[..], &position), |row| {
+                row.get("commitment")
+            })
             .context("failed to query commitment")?;
 
         bytes
@@ -159,11 +171,14 @@ impl Read for TreeStore<'_, '_> {
         // The iterator has to *own* the stmt because the rows borrow from it, so we use the
         // `genawaiter` crate to shove the entire preparation of the iterator into an (implicit)
         // async block, which handles the desuguaring to properly own the stmt for us.
+        let wallet = self.1;
         Box::new(
             gen!({
                 let mut stmt = match self
                     .0
-                    .prepare_cached("SELECT position, commitment FROM sct_commitments")
+                    .prepare_cached(
+                        "SELECT position, commitment FROM sct_commitments WHERE wallet_id = ?1",
+                    )
                     .context("failed to prepare commitments query")
                 {
                     Ok(stmt) => stmt,
@@ -176,7 +191,7 @@ impl Read for TreeStore<'_, '_> {
                 };
 
                 let rows = match stmt
-                    .query_and_then([], |row| {
+                    .query_and_then([&wallet.0[..]], |row| {
                         let position: i64 = row.get("position")?;
                         let commitment: Vec<u8> = row.get("commitment")?;
                         let commitment = <[u8; 32]>::try_from(commitment)
@@ -212,9 +227,9 @@ impl Write for TreeStore<'_, '_> {
         let position = Option::from(position).map(|p: Position| u64::from(p) as i64);
 
         self.0
-            .prepare_cached("UPDATE sct_position SET position = ?1")
+            .prepare_cached("UPDATE sct_position SET position = ?1 WHERE wallet_id = ?2")
             .context("failed to prepare position update")?
-            .execute([&position])?;
+            .execute((&position, &self.1 .0[..]))?;
 
         Ok(())
     }
@@ -223,9 +238,9 @@ impl Write for TreeStore<'_, '_> {
         let forgotten = u64::from(forgotten) as i64;
 
         self.0
-            .prepare_cached("UPDATE sct_forgotten SET forgotten = ?1")
+            .prepare_cached("UPDATE sct_forgotten SET forgotten = ?1 WHERE wallet_id = ?2")
             .context("failed to prepare forgotten update")?
-            .execute([&forgotten])?;
+            .execute((&forgotten, &self.1 .0[..]))?;
 
         Ok(())
     }
@@ -241,9 +256,9 @@ impl Write for TreeStore<'_, '_> {
         let hash = hash.to_bytes().to_vec();
 
         self.0.prepare_cached(
-            "INSERT INTO sct_hashes (position, height, hash) VALUES (?1, ?2, ?3) ON CONFLICT DO NOTHING"
+            "INSERT INTO sct_hashes (wallet_id, position, height, hash) VALUES (?1, ?2, ?3, ?4) ON CONFLICT DO NOTHING"
         ).context("failed to prepare hash insert")?
-            .execute((&position, &height, &hash))
+            .execute((&self.1 .0[..], &position, &height, &hash))
             .context("failed to insert hash")?;
 
         Ok(())
@@ -258,9 +273,9 @@ impl Write for TreeStore<'_, '_> {
         let commitment = <[u8; 32]>::from(commitment).to_vec();
 
         self.0.prepare_cached(
-            "INSERT INTO sct_commitments (position, commitment) VALUES (?1, ?2) ON CONFLICT DO NOTHING"
+            "INSERT INTO sct_commitments (wallet_id, position, commitment) VALUES (?1, ?2, ?3) ON CONFLICT DO NOTHING"
         ).context("failed to prepare commitment insert")?
-            .execute((&position, &commitment))
+            .execute((&self.1 .0[..], &position, &commitment))
             .context("failed to insert commitment")?;
 
         Ok(())
@@ -276,10 +291,11 @@ impl Write for TreeStore<'_, '_> {
 
         self.0
             .prepare_cached(
-                "DELETE FROM sct_hashes WHERE position >= ?1 AND position < ?2 AND height < ?3",
+                "DELETE FROM sct_hashes
+                WHERE wallet_id = ?1 AND position >= ?2 AND position < ?3 AND height < ?4",
             )
             .context("failed to prepare hash delete")?
-            .execute((&start, &end, &below_height))
+            .execute((&self.1 .0[..], &start, &end, &below_height))
             .context("failed to delete hashes")?;
 
         Ok(())
@@ -298,9 +314,23 @@ mod test {
         let mut db = r2d2_sqlite::rusqlite::Connection::open_in_memory().unwrap();
         let mut tx = db.transaction().unwrap();
         tx.execute_batch(include_str!("schema.sql")).unwrap();
+        let wallet = WalletId([0; 32]);
+        let other_wallet = WalletId([1; 32]);
+        for id in [wallet, other_wallet] {
+            tx.execute(
+                "INSERT INTO sct_position (wallet_id, position) VALUES (?1, 0)",
+                [&id.0[..]],
+            )
+            .unwrap();
+            tx.execute(
+                "INSERT INTO sct_forgotten (wallet_id, forgotten) VALUES (?1, 0)",
+                [&id.0[..]],
+            )
+            .unwrap();
+        }
 
         // Now we're exclusively going to talk to the db through the TreeStore:
-        let mut store = TreeStore(&mut tx);
+        let mut store = TreeStore(&mut tx, wallet);
 
         // Check that the currently stored tree is the empty tree:
         let deserialized = penumbra_tct::Tree::from_reader(&mut store).unwrap();
@@ -324,5 +354,10 @@ mod test {
         let deserialized = penumbra_tct::Tree::from_reader(&mut store).unwrap();
 
         assert_eq!(tree, deserialized);
+
+        // The tree of another wallet in the same database is unaffected:
+        let deserialized =
+            penumbra_tct::Tree::from_reader(&mut TreeStore(&mut tx, other_wallet)).unwrap();
+        assert_eq!(deserialized, penumbra_tct::Tree::new());
     }
 }
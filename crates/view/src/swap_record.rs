@@ -5,6 +5,7 @@ use penumbra_tct as tct;
 
 use r2d2_sqlite::rusqlite::Row;
 use serde::{Deserialize, Serialize};
+use sqlx::{postgres::PgRow, Row as _};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(try_from = "pb::SwapRecord", into = "pb::SwapRecord")]
@@ -83,3 +84,23 @@ impl TryFrom<&Row<'_>> for SwapRecord {
         })
     }
 }
+
+impl TryFrom<&PgRow> for SwapRecord {
+    type Error = anyhow::Error;
+
+    fn try_from(row: &PgRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            swap_commitment: row.try_get::<Vec<u8>, _>("swap_commitment")?[..].try_into()?,
+            height_claimed: row
+                .try_get::<Option<i64>, _>("height_claimed")?
+                .map(|height| height as u64),
+            position: (row.try_get::<i64, _>("position")? as u64).into(),
+            nullifier: row.try_get::<Vec<u8>, _>("nullifier")?[..].try_into()?,
+            output_data: BatchSwapOutputData::decode(
+                &row.try_get::<Vec<u8>, _>("output_data")?[..],
+            )?,
+            source: CommitmentSource::decode(&row.try_get::<Vec<u8>, _>("source")?[..])?,
+            swap: SwapPlaintext::decode(&row.try_get::<Vec<u8>, _>("swap")?[..])?,
+        })
+    }
+}
@@ -1,13 +1,16 @@
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    num::NonZeroUsize,
+    sync::Arc,
+};
 
 use penumbra_compact_block::{CompactBlock, StatePayload};
-use penumbra_dex::swap::{SwapPayload, SwapPlaintext};
+use penumbra_dex::{swap::SwapPlaintext, BatchSwapOutputData};
 use penumbra_fee::GasPrices;
 use penumbra_keys::FullViewingKey;
 use penumbra_sct::Nullifier;
-use penumbra_shielded_pool::{fmd, Note, NotePayload};
+use penumbra_shielded_pool::{fmd, Note};
 use penumbra_tct::{self as tct, StateCommitment};
-use tracing::Instrument;
 
 use crate::{SpendableNoteRecord, Storage, SwapRecord};
 
@@ -21,13 +24,100 @@ pub struct FilteredBlock {
     pub fmd_parameters: Option<fmd::Parameters>,
     pub app_parameters_updated: bool,
     pub gas_prices: Option<GasPrices>,
+    /// The outputs of the batch swaps executed in the block, which are recorded for every wallet,
+    /// since they price the assets traded on the DEX at that height.
+    pub batch_swap_outputs: Vec<BatchSwapOutputData>,
 }
 
-#[tracing::instrument(skip_all, fields(height = %height))]
+/// The notes and swaps in a block which one viewing key could decrypt.
+#[derive(Debug, Clone, Default)]
+pub struct Decryptions {
+    pub notes: BTreeMap<StateCommitment, Note>,
+    pub swaps: BTreeMap<StateCommitment, SwapPlaintext>,
+}
+
+/// Trial-decrypt the payloads of a block with each of the given viewing keys, returning the
+/// decryptions for each key, in the same order as the keys.
+///
+/// The payloads are split into a chunk for each core, and each chunk is decrypted with every key
+/// on the blocking thread pool, so a block is only processed once, however many wallets it is
+/// scanned for, and decryption doesn't hold up the async tasks fetching and committing blocks.
+#[tracing::instrument(skip_all, fields(height = %block.height, wallets = fvks.len()))]
+pub async fn trial_decrypt(fvks: Arc<[FullViewingKey]>, block: &CompactBlock) -> Vec<Decryptions> {
+    let mut decryptions = vec![Decryptions::default(); fvks.len()];
+
+    // Rolled-up payloads can't be decrypted, so don't bother sending them anywhere.
+    let payloads = block
+        .state_payloads
+        .iter()
+        .filter(|payload| !matches!(payload, StatePayload::RolledUp { .. }))
+        .cloned()
+        .collect::<Vec<_>>();
+    if payloads.is_empty() || fvks.is_empty() {
+        return decryptions;
+    }
+
+    let cores = std::thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let chunk_size = payloads.len().div_ceil(cores);
+    let tasks = payloads
+        .chunks(chunk_size)
+        .map(|chunk| {
+            let chunk = chunk.to_vec();
+            let fvks = fvks.clone();
+            let span = tracing::Span::current();
+            tokio::task::spawn_blocking(move || span.in_scope(|| decrypt_payloads(&fvks, &chunk)))
+        })
+        .collect::<Vec<_>>();
+
+    for task in tasks {
+        let chunk = task
+            .await
+            .expect("able to join tokio trial decryption handle");
+        for (decryptions, chunk) in decryptions.iter_mut().zip(chunk) {
+            decryptions.notes.extend(chunk.notes);
+            decryptions.swaps.extend(chunk.swaps);
+        }
+    }
+
+    decryptions
+}
+
+/// Trial-decrypt each of the payloads with every viewing key, returning the decryptions for each
+/// key, in the same order as the keys.
+fn decrypt_payloads(fvks: &[FullViewingKey], payloads: &[StatePayload]) -> Vec<Decryptions> {
+    fvks.iter()
+        .map(|fvk| {
+            let mut decryptions = Decryptions::default();
+            for payload in payloads {
+                match payload {
+                    StatePayload::Note { note, .. } => {
+                        if let Some(note) = note.trial_decrypt(fvk) {
+                            decryptions.notes.insert(note.commit(), note);
+                        }
+                    }
+                    StatePayload::Swap { swap, .. } => {
+                        if let Some(swap) = swap.trial_decrypt(fvk) {
+                            decryptions.swaps.insert(swap.swap_commitment(), swap);
+                        }
+                    }
+                    StatePayload::RolledUp { .. } => {}
+                }
+            }
+            decryptions
+        })
+        .collect()
+}
+
+/// Scan a block for one wallet, given the payloads its viewing key could decrypt.
+#[tracing::instrument(skip_all, fields(height = %block.height))]
 pub async fn scan_block(
     fvk: &FullViewingKey,
     state_commitment_tree: &mut tct::Tree,
-    CompactBlock {
+    block: &CompactBlock,
+    decryptions: Decryptions,
+    storage: &Storage,
+) -> anyhow::Result<FilteredBlock> {
+    let CompactBlock {
         height,
         state_payloads,
         nullifiers,
@@ -40,68 +130,25 @@ pub async fn scan_block(
         // TODO: do we need this, or is there a bug in scan_block?
         // proposal_started,
         ..
-    }: CompactBlock,
-    storage: &Storage,
-) -> anyhow::Result<FilteredBlock> {
-    // Trial-decrypt a note with our own specific viewing key
-    let trial_decrypt_note = |note_payload: NotePayload| -> tokio::task::JoinHandle<Option<Note>> {
-        // TODO: change fvk to Arc<FVK> in Worker and pass to scan_block as Arc
-        // need this so the task is 'static and not dependent on key lifetime
-        let fvk2 = fvk.clone();
-        tokio::spawn(
-            async move { note_payload.trial_decrypt(&fvk2) }.instrument(tracing::Span::current()),
-        )
-    };
-    // Trial-decrypt a swap with our own specific viewing key
-    let trial_decrypt_swap =
-        |swap_payload: SwapPayload| -> tokio::task::JoinHandle<Option<SwapPlaintext>> {
-            // TODO: change fvk to Arc<FVK> in Worker and pass to scan_block as Arc
-            // need this so the task is 'static and not dependent on key lifetime
-            let fvk2 = fvk.clone();
-            tokio::spawn(
-                async move { swap_payload.trial_decrypt(&fvk2) }
-                    .instrument(tracing::Span::current()),
-            )
-        };
+    } = block;
+    let height = *height;
 
     // Nullifiers we've found in this block
-    let spent_nullifiers: Vec<Nullifier> = nullifiers;
+    let spent_nullifiers: Vec<Nullifier> = nullifiers.clone();
 
-    // Trial-decrypt the notes in this block, keeping track of the ones that were meant for us
-    let mut note_decryptions = Vec::new();
-    let mut swap_decryptions = Vec::new();
-    let mut unknown_commitments = Vec::new();
-
-    for payload in state_payloads.iter() {
-        match payload {
-            StatePayload::Note { note, .. } => {
-                note_decryptions.push(trial_decrypt_note((**note).clone()));
-            }
-            StatePayload::Swap { swap, .. } => {
-                swap_decryptions.push(trial_decrypt_swap((**swap).clone()));
-            }
-            StatePayload::RolledUp { commitment, .. } => unknown_commitments.push(*commitment),
-        }
-    }
-    // Having started trial decryption in the background, ask the Storage for scanning advice:
+    let unknown_commitments = state_payloads
+        .iter()
+        .filter_map(|payload| match payload {
+            StatePayload::RolledUp { commitment, .. } => Some(*commitment),
+            _ => None,
+        })
+        .collect();
+    // Ask the Storage for scanning advice, keeping only the notes for this wallet, since other
+    // wallets may share the same database:
     let mut note_advice = storage.scan_advice(unknown_commitments).await?;
-    for decryption in note_decryptions {
-        if let Some(note) = decryption
-            .await
-            .expect("able to join tokio note decryption handle")
-        {
-            note_advice.insert(note.commit(), note);
-        }
-    }
-    let mut swap_advice = BTreeMap::new();
-    for decryption in swap_decryptions {
-        if let Some(swap) = decryption
-            .await
-            .expect("able to join tokio swap decryption handle")
-        {
-            swap_advice.insert(swap.swap_commitment(), swap);
-        }
-    }
+    note_advice.retain(|_, note| fvk.address_index(&note.address()).is_some());
+    note_advice.extend(decryptions.notes);
+    let swap_advice = decryptions.swaps;
 
     // Newly detected spendable notes.
     let mut new_notes = BTreeMap::new();
@@ -112,14 +159,14 @@ pub async fn scan_block(
         // If there are no notes we care about in this block, just insert the block root into the
         // tree instead of processing each commitment individually
         state_commitment_tree
-            .insert_block(block_root)
+            .insert_block(*block_root)
             .expect("inserting a block root must succeed");
     } else {
         // If we found at least one note for us in this block, we have to explicitly construct the
         // whole block in the SCT by inserting each commitment one at a time
         tracing::debug!("found at least one relevant SCT entry, reconstructing block subtree");
 
-        for payload in state_payloads.into_iter() {
+        for payload in state_payloads.iter() {
             // We need to insert each commitment, so use a match statement to ensure we
             // exhaustively cover all possible cases.
             match (
@@ -232,10 +279,74 @@ pub async fn scan_block(
         new_swaps,
         spent_nullifiers: filtered_nullifiers,
         height,
-        fmd_parameters,
-        app_parameters_updated,
-        gas_prices,
+        fmd_parameters: fmd_parameters.clone(),
+        app_parameters_updated: *app_parameters_updated,
+        gas_prices: gas_prices.clone(),
+        batch_swap_outputs: swap_outputs.values().cloned().collect(),
     };
 
     Ok(result)
 }
+
+/// Replay a block which is before the point a wallet is scanned from, to rebuild its state
+/// commitment tree.
+///
+/// The wallet's records for the block, if any, are already in its database, so nothing is
+/// decrypted: the commitments in `witnessed` are kept, and the rest are forgotten.
+#[tracing::instrument(skip_all, fields(height = %block.height))]
+pub fn replay_block(
+    state_commitment_tree: &mut tct::Tree,
+    block: &CompactBlock,
+    witnessed: &BTreeSet<StateCommitment>,
+) -> FilteredBlock {
+    let CompactBlock {
+        height,
+        state_payloads,
+        block_root,
+        epoch_root,
+        swap_outputs,
+        ..
+    } = block;
+
+    if state_payloads
+        .iter()
+        .all(|payload| !witnessed.contains(payload.commitment()))
+    {
+        state_commitment_tree
+            .insert_block(*block_root)
+            .expect("inserting a block root must succeed");
+    } else {
+        for payload in state_payloads.iter() {
+            let witness = if witnessed.contains(payload.commitment()) {
+                tct::Witness::Keep
+            } else {
+                tct::Witness::Forget
+            };
+            state_commitment_tree
+                .insert(witness, *payload.commitment())
+                .expect("inserting a commitment must succeed");
+        }
+        state_commitment_tree
+            .end_block()
+            .expect("ending the block must succeed");
+    }
+
+    if epoch_root.is_some() {
+        state_commitment_tree
+            .end_epoch()
+            .expect("ending the epoch must succeed");
+    }
+
+    // The chain parameters in the block are left alone, since they may since have changed, but
+    // the batch swap outputs are historical, so they're recorded in case they weren't already.
+    FilteredBlock {
+        new_notes: BTreeMap::new(),
+        new_swaps: BTreeMap::new(),
+        spent_nullifiers: Vec::new(),
+        height: *height,
+        fmd_parameters: None,
+        app_parameters_updated: false,
+        gas_prices: None,
+        batch_swap_outputs: swap_outputs.values().cloned().collect(),
+    }
+}
@@ -1,110 +1,246 @@
 use std::{
-    collections::BTreeSet,
+    collections::{BTreeMap, BTreeSet},
+    num::NonZeroUsize,
     sync::{Arc, Mutex},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use anyhow::Context;
+use futures::StreamExt;
 use penumbra_auction::auction::AuctionNft;
 use penumbra_compact_block::CompactBlock;
 use penumbra_dex::lp::{position, LpNft};
-use penumbra_keys::FullViewingKey;
-use penumbra_proto::core::{
-    app::v1::{
-        query_service_client::QueryServiceClient as AppQueryServiceClient,
-        TransactionsByHeightRequest,
-    },
-    component::{
-        compact_block::v1::{
-            query_service_client::QueryServiceClient as CompactBlockQueryServiceClient,
-            CompactBlockRangeRequest,
+use penumbra_keys::{keys::AddressIndex, FullViewingKey};
+use penumbra_proto::{
+    core::{
+        app::v1::{
+            query_service_client::QueryServiceClient as AppQueryServiceClient,
+            TransactionsByHeightRequest,
         },
-        shielded_pool::v1::{
-            query_service_client::QueryServiceClient as ShieldedPoolQueryServiceClient,
-            AssetMetadataByIdRequest,
+        component::{
+            compact_block::v1::{
+                query_service_client::QueryServiceClient as CompactBlockQueryServiceClient,
+                CompactBlockRangeRequest, CompactBlockRangeResponse,
+            },
+            shielded_pool::v1::{
+                query_service_client::QueryServiceClient as ShieldedPoolQueryServiceClient,
+                AssetMetadataByIdRequest,
+            },
         },
+        util::tendermint_proxy::v1::{
+            tendermint_proxy_service_client::TendermintProxyServiceClient, GetStatusRequest,
+        },
+    },
+    view::v1::{
+        detection_service_client::DetectionServiceClient, FilteredCompactBlockRangeRequest,
     },
 };
 use penumbra_sct::{CommitmentSource, Nullifier};
+use penumbra_tct::StateCommitment;
 use penumbra_transaction::Transaction;
 use tap::Tap;
-use tokio::sync::{watch, RwLock};
+use tokio::sync::{mpsc, watch, RwLock};
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::transport::Channel;
 use tracing::instrument;
 
 use crate::{
-    sync::{scan_block, FilteredBlock},
+    event::WalletEventKind,
+    metrics,
+    sync::{replay_block, scan_block, trial_decrypt, Decryptions, FilteredBlock},
     Storage,
 };
 
 // The maximum size of a compact block, in bytes (12MB).
 const MAX_CB_SIZE_BYTES: usize = 12 * 1024 * 1024;
 
-pub struct Worker {
+/// The number of blocks in each range requested while catching up with the chain.
+const PREFETCH_RANGE_SIZE: u64 = 500;
+
+/// The number of block ranges requested at once while catching up with the chain.
+const PREFETCH_RANGES: usize = 4;
+
+/// How often the worker reports the rate at which it processes blocks.
+const THROUGHPUT_REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The number of accounts of each wallet whose notes are detected by a detection service.
+///
+/// Notes sent to later accounts, or to one-time addresses, are missed unless the detection
+/// service happens to detect them by chance.
+pub const DETECTED_ACCOUNTS: u32 = 16;
+
+/// Checks that notes sent to an address of the given account are found when synchronizing
+/// through a detection service.
+///
+/// Only the detection keys of the first [`DETECTED_ACCOUNTS`] accounts are registered with the
+/// service, and each one-time address has a detection key of its own, so a wallet synchronizing
+/// through a detection service must not hand out any other address.
+pub fn check_detected(account: u32, ephemeral: bool) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        !ephemeral,
+        "notes sent to one-time addresses are missed when synchronizing through a detection service"
+    );
+    anyhow::ensure!(
+        account < DETECTED_ACCOUNTS,
+        "notes sent to account {account} are missed when synchronizing through a detection \
+         service, which only detects the notes of the first {DETECTED_ACCOUNTS} accounts"
+    );
+    Ok(())
+}
+
+/// The state the worker keeps for each wallet it scans blocks for.
+struct WalletScanner {
     storage: Storage,
     sct: Arc<RwLock<penumbra_tct::Tree>>,
-    fvk: FullViewingKey, // TODO: notifications (see TODOs on ViewService)
-    error_slot: Arc<Mutex<Option<anyhow::Error>>>,
+    fvk: FullViewingKey,
     sync_height_tx: watch::Sender<u64>,
+    /// The height of the next block to scan for this wallet.
+    next_height: u64,
+    /// Blocks below this height are only replayed to rebuild the SCT, rather than scanned.
+    replay_height: u64,
+    /// The commitments witnessed in the SCT while replaying blocks.
+    witnessed: BTreeSet<StateCommitment>,
+}
+
+/// The shared state of one wallet synchronized by a [`Worker`].
+pub struct WalletSync {
+    /// A shared, in-memory SCT instance.
+    pub sct: Arc<RwLock<penumbra_tct::Tree>>,
+    /// A channel for notifying the client of sync progress.
+    pub sync_height_rx: watch::Receiver<u64>,
+}
+
+/// A detection service which filters the compact blocks fetched by a [`Worker`].
+#[derive(Clone)]
+struct Detection {
+    /// Tonic channel to the detection service.
+    channel: Channel,
+    /// The detection keys of the addresses of every wallet's first accounts.
+    detection_keys: Vec<Vec<u8>>,
+}
+
+pub struct Worker {
+    wallets: Vec<WalletScanner>,
+    error_slot: Arc<Mutex<Option<anyhow::Error>>>,
     /// Tonic channel used to create GRPC clients.
     channel: Channel,
+    /// If set, compact blocks are fetched from this detection service rather than the node.
+    detection: Option<Detection>,
 }
 
 impl Worker {
-    /// Creates a new worker, returning:
+    /// Creates a new worker, which synchronizes each of the given wallets, returning:
     ///
     /// - the worker itself;
-    /// - a shared, in-memory SCT instance;
-    /// - a shared error slot;
-    /// - a channel for notifying the client of sync progress.
+    /// - the shared state of each wallet, in the same order as `storages`;
+    /// - a shared error slot.
+    ///
+    /// Each compact block is only fetched and trial-decrypted once, for all of the wallets.
+    ///
+    /// If a `detection` channel is given, compact blocks are fetched from that detection service,
+    /// so only the notes it detects for the first [`DETECTED_ACCOUNTS`] accounts of each wallet
+    /// are trial-decrypted. This fails if a wallet already holds unspent notes sent to other
+    /// addresses.
     #[instrument(skip_all)]
     pub async fn new(
-        storage: Storage,
+        storages: Vec<Storage>,
         channel: Channel,
-    ) -> Result<
-        (
-            Self,
-            Arc<RwLock<penumbra_tct::Tree>>,
-            Arc<Mutex<Option<anyhow::Error>>>,
-            watch::Receiver<u64>,
-        ),
-        anyhow::Error,
-    > {
+        detection: Option<Channel>,
+    ) -> Result<(Self, Vec<WalletSync>, Arc<Mutex<Option<anyhow::Error>>>), anyhow::Error> {
         tracing::trace!("constructing view server worker");
-        let fvk = storage
-            .full_viewing_key()
-            .await
-            .context("failed to retrieve full viewing key from storage")?
-            .tap(|_| tracing::debug!("retrieved full viewing key"));
-
-        // Create a shared, in-memory SCT.
-        let sct = Arc::new(RwLock::new(storage.state_commitment_tree().await?));
+        let mut wallets = Vec::new();
+        let mut syncs = Vec::new();
+        for storage in storages {
+            let fvk = storage
+                .full_viewing_key()
+                .await
+                .context("failed to retrieve full viewing key from storage")?
+                .tap(|_| tracing::debug!("retrieved full viewing key"));
+
+            // A wallet which already holds notes the detection service won't detect would miss
+            // any more notes sent to the same addresses, and couldn't witness the notes it holds
+            // when replaying blocks the service left their payloads out of.
+            if detection.is_some() {
+                for record in storage.notes(false, None, None, None).await? {
+                    check_detected(
+                        record.address_index.account,
+                        record.address_index.is_ephemeral(),
+                    )
+                    .context(
+                        "the wallet already holds notes which the detection service won't detect, \
+                         so it must synchronize directly from the node",
+                    )?;
+                }
+            }
+
+            // Create a shared, in-memory SCT.
+            let sct = Arc::new(RwLock::new(storage.state_commitment_tree().await?));
+            let last_sync_height = storage.last_sync_height().await?;
+            // Create a channel for the worker to notify of sync height changes.
+            let (sync_height_tx, mut sync_height_rx) =
+                watch::channel(last_sync_height.unwrap_or(0));
+            // Mark the current height as seen, since it's not new.
+            sync_height_rx.borrow_and_update();
+
+            let next_height = last_sync_height.map(|h| h + 1).unwrap_or(0);
+            let replay_height = storage.replay_height().await?;
+            let witnessed = if next_height < replay_height {
+                storage.witnessed_commitments().await?
+            } else {
+                BTreeSet::new()
+            };
+
+            wallets.push(WalletScanner {
+                storage,
+                sct: sct.clone(),
+                fvk,
+                sync_height_tx,
+                next_height,
+                replay_height,
+                witnessed,
+            });
+            syncs.push(WalletSync {
+                sct,
+                sync_height_rx,
+            });
+        }
+        let detection = detection.map(|channel| Detection {
+            channel,
+            detection_keys: wallets
+                .iter()
+                .flat_map(|wallet| {
+                    (0..DETECTED_ACCOUNTS).map(|account| {
+                        let (_, detection_key) =
+                            wallet.fvk.payment_address(AddressIndex::new(account));
+                        detection_key.to_bytes().to_vec()
+                    })
+                })
+                .collect(),
+        });
+
         // Create a shared error slot
         let error_slot = Arc::new(Mutex::new(None));
-        // Create a channel for the worker to notify of sync height changes.
-        let (sync_height_tx, mut sync_height_rx) =
-            watch::channel(storage.last_sync_height().await?.unwrap_or(0));
-        // Mark the current height as seen, since it's not new.
-        sync_height_rx.borrow_and_update();
 
         Ok((
             Self {
-                storage,
-                sct: sct.clone(),
-                fvk,
+                wallets,
                 error_slot: error_slot.clone(),
-                sync_height_tx,
                 channel,
+                detection,
             },
-            sct,
+            syncs,
             error_slot,
-            sync_height_rx,
         ))
     }
 
+    /// Finds the transactions in the block which are relevant to a wallet.
+    ///
+    /// All of the transactions in the block are fetched at most once, and kept in `fetched` for
+    /// the next wallet scanning the same block.
     pub async fn fetch_transactions(
         &self,
         filtered_block: &mut FilteredBlock,
+        fetched: &mut Option<Vec<Transaction>>,
     ) -> anyhow::Result<Vec<Transaction>> {
         let spent_nullifiers = filtered_block
             .spent_nullifiers
@@ -135,8 +271,10 @@ impl Worker {
             "fetching full transaction data"
         );
 
-        let all_transactions =
-            fetch_transactions(self.channel.clone(), filtered_block.height).await?;
+        if fetched.is_none() {
+            *fetched = Some(fetch_transactions(self.channel.clone(), filtered_block.height).await?);
+        }
+        let all_transactions = fetched.as_ref().expect("transactions were just fetched");
 
         let mut transactions = Vec::new();
 
@@ -172,7 +310,7 @@ impl Worker {
             }
 
             if relevant {
-                transactions.push(tx);
+                transactions.push(tx.clone());
             }
         }
 
@@ -188,43 +326,96 @@ impl Worker {
         // Do a single sync run, up to whatever the latest block height is
         tracing::info!("starting client sync");
 
-        let start_height = self
-            .storage
-            .last_sync_height()
-            .await?
-            .map(|h| h + 1)
-            .unwrap_or(0);
-
-        let mut client = CompactBlockQueryServiceClient::new(self.channel.clone())
-            .max_decoding_message_size(MAX_CB_SIZE_BYTES);
-        let mut stream = client
-            .compact_block_range(tonic::Request::new(CompactBlockRangeRequest {
-                start_height,
-                end_height: 0,
-                // Instruct the server to keep feeding us blocks as they're created.
-                keep_alive: true,
-            }))
-            .await?
-            .into_inner();
-
-        // Spawn a task to consume items from the stream (somewhat)
-        // independently of the execution of the block scanning.  This has two
-        // purposes: first, it allows buffering to smooth performance; second,
-        // it makes it slightly more difficult for a remote server to observe
-        // the exact timings of the scanning of each CompactBlock.
-        let (tx, mut buffered_stream) = tokio::sync::mpsc::channel(1000);
+        // The height of the next block to scan for each wallet.
+        let mut next_heights = Vec::new();
+        for wallet in &self.wallets {
+            next_heights.push(
+                wallet
+                    .storage
+                    .last_sync_height()
+                    .await?
+                    .map(|h| h + 1)
+                    .unwrap_or(0),
+            );
+        }
+        // Start from the wallet furthest behind: the others skip the blocks they've already scanned.
+        let start_height = next_heights.iter().copied().min().unwrap_or(0);
+        // The height from which each wallet trial-decrypts blocks, rather than skipping or
+        // replaying them.
+        let scan_heights = self
+            .wallets
+            .iter()
+            .zip(&next_heights)
+            .map(|(wallet, &next_height)| next_height.max(wallet.replay_height))
+            .collect::<Vec<_>>();
+
+        // Spawn a task to fetch blocks from the node (somewhat) independently of
+        // the execution of the block scanning.  This has two purposes: first, it
+        // allows buffering to smooth performance; second, it makes it slightly
+        // more difficult for a remote server to observe the exact timings of the
+        // scanning of each CompactBlock.
+        let (tx, buffered_stream) = mpsc::channel(1000);
+        let channel = self.channel.clone();
+        let detection = self.detection.clone();
         tokio::spawn(async move {
-            while let Some(block) = stream.message().await.transpose() {
-                if tx.send(block).await.is_err() {
-                    break;
-                }
+            let fetched = match detection {
+                Some(detection) => fetch_detected_blocks(detection, start_height, &tx).await,
+                None => fetch_blocks(channel, start_height, &tx).await,
+            };
+            if let Err(e) = fetched {
+                // If the worker has stopped listening, there's nobody to report the error to.
+                let _ = tx.send(Err(e)).await;
             }
         });
 
+        // Trial-decrypt several blocks at once, ahead of the block being committed, while still
+        // committing them in order.
+        let fvks = self
+            .wallets
+            .iter()
+            .map(|wallet| wallet.fvk.clone())
+            .collect::<Vec<_>>();
+        let mut decrypted_stream = ReceiverStream::new(buffered_stream)
+            .map(move |block: anyhow::Result<CompactBlock>| {
+                // The wallets which will scan this block, rather than skip or replay it.
+                let scanning = match &block {
+                    Ok(block) if block.requires_scanning() => scan_heights
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, &scan_height)| scan_height <= block.height)
+                        .map(|(i, _)| i)
+                        .collect::<Vec<_>>(),
+                    _ => Vec::new(),
+                };
+                let fvks = scanning
+                    .iter()
+                    .map(|&i| fvks[i].clone())
+                    .collect::<Arc<[_]>>();
+                async move {
+                    let block = block?;
+                    if scanning.is_empty() {
+                        return anyhow::Ok((block, BTreeMap::new()));
+                    }
+                    let start = Instant::now();
+                    let decryptions = trial_decrypt(fvks, &block).await;
+                    metrics::histogram!(metrics::VIEW_SYNC_TRIAL_DECRYPT_DURATION)
+                        .record(start.elapsed());
+                    anyhow::Ok((
+                        block,
+                        scanning
+                            .into_iter()
+                            .zip(decryptions)
+                            .collect::<BTreeMap<_, _>>(),
+                    ))
+                }
+            })
+            .buffered(decrypt_ahead());
+
         let mut expected_height = start_height;
+        let mut throughput = Throughput::new();
 
-        while let Some(block) = buffered_stream.recv().await {
-            let block: CompactBlock = block?.try_into()?;
+        while let Some(decrypted) = decrypted_stream.next().await {
+            let (block, mut decryptions) = decrypted?;
 
             let height = block.height;
             if height != expected_height {
@@ -232,196 +423,73 @@ impl Worker {
                 continue;
             }
             expected_height += 1;
+            let start = Instant::now();
 
-            // Lock the SCT only while processing this block.
-            let mut sct_guard = self.sct.write().await;
+            // Only the wallets which haven't scanned this block yet need to scan it.
+            let due = (0..self.wallets.len())
+                .filter(|&i| next_heights[i] == height)
+                .collect::<Vec<_>>();
 
             if !block.requires_scanning() {
-                // Optimization: if the block is empty, seal the in-memory SCT,
-                // and skip touching the database:
-                sct_guard.end_block()?;
-                // We also need to end the epoch, since if there are no funding streams, then an
-                // epoch boundary won't necessarily require scanning:
-                if block.epoch_root.is_some() {
-                    sct_guard
-                        .end_epoch()
-                        .expect("ending the epoch must succeed");
-                }
-                self.storage.record_empty_block(height).await?;
-                // Notify all watchers of the new height we just recorded.
-                self.sync_height_tx.send(height)?;
-            } else {
-                // Otherwise, scan the block and commit its changes:
-                let mut filtered_block =
-                    scan_block(&self.fvk, &mut sct_guard, block, &self.storage).await?;
-
-                // Download any transactions we detected.
-                let transactions = self.fetch_transactions(&mut filtered_block).await?;
-
-                // LPNFT asset IDs won't be known to the chain, so we need to pre-populate them in the local
-                // registry based on transaction contents.
-                for transaction in &transactions {
-                    for action in transaction.actions() {
-                        match action {
-                            penumbra_transaction::Action::PositionOpen(position_open) => {
-                                let position_id = position_open.position.id();
-
-                                // Record every possible permutation.
-                                let lp_nft = LpNft::new(position_id, position::State::Opened);
-                                let _id = lp_nft.asset_id();
-                                let denom = lp_nft.denom();
-                                self.storage.record_asset(denom).await?;
-
-                                let lp_nft = LpNft::new(position_id, position::State::Closed);
-                                let _id = lp_nft.asset_id();
-                                let denom = lp_nft.denom();
-                                self.storage.record_asset(denom).await?;
-
-                                let lp_nft = LpNft::new(
-                                    position_id,
-                                    position::State::Withdrawn { sequence: 0 },
-                                );
-                                let _id = lp_nft.asset_id();
-                                let denom = lp_nft.denom();
-                                self.storage.record_asset(denom).await?;
-
-                                // Record the position itself
-                                self.storage
-                                    .record_position(position_open.position.clone())
-                                    .await?;
-                            }
-                            penumbra_transaction::Action::PositionClose(position_close) => {
-                                let position_id = position_close.position_id;
-
-                                // Update the position record
-                                self.storage
-                                    .update_position(position_id, position::State::Closed)
-                                    .await?;
-                            }
-                            penumbra_transaction::Action::PositionWithdraw(position_withdraw) => {
-                                let position_id = position_withdraw.position_id;
-
-                                // Record the LPNFT for the current sequence number.
-                                let state = position::State::Withdrawn {
-                                    sequence: position_withdraw.sequence,
-                                };
-                                let lp_nft = LpNft::new(position_id, state);
-                                let denom = lp_nft.denom();
-                                self.storage.record_asset(denom).await?;
-
-                                // Update the position record
-                                self.storage.update_position(position_id, state).await?;
-                            }
-                            penumbra_transaction::Action::ActionDutchAuctionSchedule(
-                                schedule_da,
-                            ) => {
-                                let auction_id = schedule_da.description.id();
-                                let auction_nft_opened = AuctionNft::new(auction_id, 0);
-                                let nft_metadata_opened = auction_nft_opened.metadata.clone();
-
-                                self.storage.record_asset(nft_metadata_opened).await?;
-
-                                self.storage
-                                    .record_auction_with_state(
-                                        schedule_da.description.id(),
-                                        0u64, // Opened
-                                    )
-                                    .await?;
-                            }
-                            penumbra_transaction::Action::ActionDutchAuctionEnd(end_da) => {
-                                let auction_id = end_da.auction_id;
-                                let auction_nft_closed = AuctionNft::new(auction_id, 1);
-                                let nft_metadata_closed = auction_nft_closed.metadata.clone();
-
-                                self.storage.record_asset(nft_metadata_closed).await?;
-
-                                self.storage
-                                    .record_auction_with_state(end_da.auction_id, 1)
-                                    .await?;
-                            }
-                            penumbra_transaction::Action::ActionDutchAuctionWithdraw(
-                                withdraw_da,
-                            ) => {
-                                let auction_id = withdraw_da.auction_id;
-                                let auction_nft_withdrawn =
-                                    AuctionNft::new(auction_id, withdraw_da.seq);
-                                let nft_metadata_withdrawn = auction_nft_withdrawn.metadata.clone();
-
-                                self.storage.record_asset(nft_metadata_withdrawn).await?;
-                                self.storage
-                                    .record_auction_with_state(auction_id, withdraw_da.seq)
-                                    .await?;
-                            }
-                            _ => (),
-                        };
+                for &i in &due {
+                    let wallet = &self.wallets[i];
+                    // Lock the SCT only while processing this block.
+                    let mut sct_guard = wallet.sct.write().await;
+                    // Optimization: if the block is empty, insert its root into the in-memory
+                    // SCT, and skip touching the database. A detection service leaves out the
+                    // payloads of blocks where it detected nothing, so the root may not be empty:
+                    sct_guard.insert_block(block.block_root)?;
+                    // We also need to end the epoch, since if there are no funding streams, then an
+                    // epoch boundary won't necessarily require scanning:
+                    if block.epoch_root.is_some() {
+                        sct_guard
+                            .end_epoch()
+                            .expect("ending the epoch must succeed");
                     }
+                    wallet.storage.record_empty_block(height).await?;
+                    // Notify all watchers of the new height we just recorded.
+                    wallet.sync_height_tx.send(height)?;
+                    next_heights[i] = height + 1;
                 }
-
-                // Record any new assets we detected.
-                for note_record in filtered_block.new_notes.values() {
-                    // If the asset is already known, skip it, unless there's useful information
-                    // to cross-reference.
-                    if let Some(note_denom) = self
-                        .storage
-                        .asset_by_id(&note_record.note.asset_id())
-                        .await?
-                    {
-                        // If the asset metata is for an auction, we record the associated note commitment
-                        // in the auction state table to cross reference with SNRs.
-                        if note_denom.is_auction_nft() {
-                            let note_commitment = note_record.note_commitment;
-                            let auction_nft: AuctionNft = note_denom.try_into()?;
-                            self.storage
-                                .update_auction_with_note_commitment(
-                                    auction_nft.id,
-                                    note_commitment,
-                                )
-                                .await?;
-                        }
-                        continue;
-                    } else {
-                        // If the asset is unknown, we may be able to query for its denom metadata and store that.
-
-                        let mut client = ShieldedPoolQueryServiceClient::new(self.channel.clone());
-                        if let Some(denom_metadata) = client
-                            .asset_metadata_by_id(AssetMetadataByIdRequest {
-                                asset_id: Some(note_record.note.asset_id().into()),
-                            })
-                            .await?
-                            .into_inner()
-                            .denom_metadata
-                        {
-                            // If we get metadata: great, record it.
-                            self.storage
-                                .record_asset(denom_metadata.try_into()?)
-                                .await?;
-                        } else {
-                            tracing::warn!(asset_id = ?note_record.note.asset_id(), "received unknown asset ID with no available metadata");
-                        }
-                    }
+            } else if !due.is_empty() {
+                // The wallets which have already scanned this block, or which start after it,
+                // only replay it to rebuild their SCT:
+                let (replaying, scanning): (Vec<usize>, Vec<usize>) = due
+                    .iter()
+                    .partition(|&&i| height < self.wallets[i].replay_height);
+                for &i in &replaying {
+                    self.replay_wallet(&self.wallets[i], &block).await?;
+                    next_heights[i] = height + 1;
                 }
 
-                // Commit the block to the database.
-                self.storage
-                    .record_block(
-                        filtered_block.clone(),
-                        transactions,
-                        &mut sct_guard,
-                        self.channel.clone(),
-                    )
-                    .await?;
-                // Notify all watchers of the new height we just recorded.
-                self.sync_height_tx.send(filtered_block.height)?;
+                // Otherwise, scan the block with the payloads each wallet could decrypt, and
+                // commit its changes:
+                let mut fetched = None;
+                for &i in &scanning {
+                    let decryptions = decryptions
+                        .remove(&i)
+                        .with_context(|| format!("block {height} was not trial-decrypted"))?;
+                    self.scan_wallet(&self.wallets[i], &block, decryptions, &mut fetched)
+                        .await?;
+                    next_heights[i] = height + 1;
+                }
             }
             #[cfg(feature = "sct-divergence-check")]
-            sct_divergence_check(self.channel.clone(), height, sct_guard.root()).await?;
+            for &i in &due {
+                let root = self.wallets[i].sct.read().await.root();
+                sct_divergence_check(self.channel.clone(), height, root).await?;
+            }
 
-            // Release the SCT RwLock
-            drop(sct_guard);
+            metrics::histogram!(metrics::VIEW_SYNC_COMMIT_DURATION).record(start.elapsed());
+            throughput.record(height);
 
             // Check if we should stop waiting for blocks to arrive, because the view
             // services are dropped and we're supposed to shut down.
-            if self.sync_height_tx.is_closed() {
+            if self
+                .wallets
+                .iter()
+                .all(|wallet| wallet.sync_height_tx.is_closed())
+            {
                 return Ok(());
             }
         }
@@ -429,6 +497,246 @@ impl Worker {
         Ok(())
     }
 
+    /// Replays a block for one wallet, and commits the changes to its SCT.
+    async fn replay_wallet(
+        &self,
+        wallet: &WalletScanner,
+        block: &CompactBlock,
+    ) -> anyhow::Result<()> {
+        // Lock the SCT only while processing this block.
+        let mut sct_guard = wallet.sct.write().await;
+
+        let filtered_block = replay_block(&mut sct_guard, block, &wallet.witnessed);
+        wallet
+            .storage
+            .record_block(
+                filtered_block,
+                Vec::new(),
+                &mut sct_guard,
+                self.channel.clone(),
+            )
+            .await?;
+        // Notify all watchers of the new height we just recorded.
+        wallet.sync_height_tx.send(block.height)?;
+
+        Ok(())
+    }
+
+    /// Scans a block for one wallet, and commits its changes.
+    async fn scan_wallet(
+        &self,
+        wallet: &WalletScanner,
+        block: &CompactBlock,
+        decryptions: Decryptions,
+        fetched: &mut Option<Vec<Transaction>>,
+    ) -> anyhow::Result<()> {
+        // Lock the SCT only while processing this block.
+        let mut sct_guard = wallet.sct.write().await;
+
+        let mut filtered_block = scan_block(
+            &wallet.fvk,
+            &mut sct_guard,
+            block,
+            decryptions,
+            &wallet.storage,
+        )
+        .await?;
+
+        // Download any transactions we detected.
+        let transactions = self
+            .fetch_transactions(&mut filtered_block, fetched)
+            .await?;
+
+        // The changes to the wallet's positions and auctions, to notify subscribers of once the
+        // block is committed.
+        let mut events = Vec::new();
+
+        // LPNFT asset IDs won't be known to the chain, so we need to pre-populate them in the local
+        // registry based on transaction contents.
+        for transaction in &transactions {
+            for action in transaction.actions() {
+                match action {
+                    penumbra_transaction::Action::PositionOpen(position_open) => {
+                        let position_id = position_open.position.id();
+
+                        // Record every possible permutation.
+                        let lp_nft = LpNft::new(position_id, position::State::Opened);
+                        let _id = lp_nft.asset_id();
+                        let denom = lp_nft.denom();
+                        wallet.storage.record_asset(denom).await?;
+
+                        let lp_nft = LpNft::new(position_id, position::State::Closed);
+                        let _id = lp_nft.asset_id();
+                        let denom = lp_nft.denom();
+                        wallet.storage.record_asset(denom).await?;
+
+                        let lp_nft =
+                            LpNft::new(position_id, position::State::Withdrawn { sequence: 0 });
+                        let _id = lp_nft.asset_id();
+                        let denom = lp_nft.denom();
+                        wallet.storage.record_asset(denom).await?;
+
+                        // Record the position itself
+                        wallet
+                            .storage
+                            .record_position(position_open.position.clone(), filtered_block.height)
+                            .await?;
+                        events.push(WalletEventKind::PositionUpdated {
+                            position_id,
+                            state: position_open.position.state,
+                        });
+                    }
+                    penumbra_transaction::Action::PositionClose(position_close) => {
+                        let position_id = position_close.position_id;
+
+                        // Update the position record
+                        wallet
+                            .storage
+                            .update_position(
+                                position_id,
+                                position::State::Closed,
+                                filtered_block.height,
+                            )
+                            .await?;
+                        events.push(WalletEventKind::PositionUpdated {
+                            position_id,
+                            state: position::State::Closed,
+                        });
+                    }
+                    penumbra_transaction::Action::PositionWithdraw(position_withdraw) => {
+                        let position_id = position_withdraw.position_id;
+
+                        // Record the LPNFT for the current sequence number.
+                        let state = position::State::Withdrawn {
+                            sequence: position_withdraw.sequence,
+                        };
+                        let lp_nft = LpNft::new(position_id, state);
+                        let denom = lp_nft.denom();
+                        wallet.storage.record_asset(denom).await?;
+
+                        // Update the position record
+                        wallet
+                            .storage
+                            .update_position(position_id, state, filtered_block.height)
+                            .await?;
+                        events.push(WalletEventKind::PositionUpdated { position_id, state });
+                    }
+                    penumbra_transaction::Action::ActionDutchAuctionSchedule(schedule_da) => {
+                        let auction_id = schedule_da.description.id();
+                        let auction_nft_opened = AuctionNft::new(auction_id, 0);
+                        let nft_metadata_opened = auction_nft_opened.metadata.clone();
+
+                        wallet.storage.record_asset(nft_metadata_opened).await?;
+
+                        wallet
+                            .storage
+                            .record_auction_with_state(
+                                schedule_da.description.id(),
+                                0u64, // Opened
+                                filtered_block.height,
+                            )
+                            .await?;
+                        events.push(WalletEventKind::AuctionUpdated { auction_id, seq: 0 });
+                    }
+                    penumbra_transaction::Action::ActionDutchAuctionEnd(end_da) => {
+                        let auction_id = end_da.auction_id;
+                        let auction_nft_closed = AuctionNft::new(auction_id, 1);
+                        let nft_metadata_closed = auction_nft_closed.metadata.clone();
+
+                        wallet.storage.record_asset(nft_metadata_closed).await?;
+
+                        wallet
+                            .storage
+                            .record_auction_with_state(end_da.auction_id, 1, filtered_block.height)
+                            .await?;
+                        events.push(WalletEventKind::AuctionUpdated { auction_id, seq: 1 });
+                    }
+                    penumbra_transaction::Action::ActionDutchAuctionWithdraw(withdraw_da) => {
+                        let auction_id = withdraw_da.auction_id;
+                        let auction_nft_withdrawn = AuctionNft::new(auction_id, withdraw_da.seq);
+                        let nft_metadata_withdrawn = auction_nft_withdrawn.metadata.clone();
+
+                        wallet.storage.record_asset(nft_metadata_withdrawn).await?;
+                        wallet
+                            .storage
+                            .record_auction_with_state(
+                                auction_id,
+                                withdraw_da.seq,
+                                filtered_block.height,
+                            )
+                            .await?;
+                        events.push(WalletEventKind::AuctionUpdated {
+                            auction_id,
+                            seq: withdraw_da.seq,
+                        });
+                    }
+                    _ => (),
+                };
+            }
+        }
+
+        // Record any new assets we detected.
+        for note_record in filtered_block.new_notes.values() {
+            // If the asset is already known, skip it, unless there's useful information
+            // to cross-reference.
+            if let Some(note_denom) = self
+                .storage
+                .asset_by_id(&note_record.note.asset_id())
+                .await?
+            {
+                // If the asset metata is for an auction, we record the associated note commitment
+                // in the auction state table to cross reference with SNRs.
+                if note_denom.is_auction_nft() {
+                    let note_commitment = note_record.note_commitment;
+                    let auction_nft: AuctionNft = note_denom.try_into()?;
+                    wallet
+                        .storage
+                        .update_auction_with_note_commitment(auction_nft.id, note_commitment)
+                        .await?;
+                }
+                continue;
+            } else {
+                // If the asset is unknown, we may be able to query for its denom metadata and store that.
+
+                let mut client = ShieldedPoolQueryServiceClient::new(self.channel.clone());
+                if let Some(denom_metadata) = client
+                    .asset_metadata_by_id(AssetMetadataByIdRequest {
+                        asset_id: Some(note_record.note.asset_id().into()),
+                    })
+                    .await?
+                    .into_inner()
+                    .denom_metadata
+                {
+                    // If we get metadata: great, record it.
+                    wallet
+                        .storage
+                        .record_asset(denom_metadata.try_into()?)
+                        .await?;
+                } else {
+                    tracing::warn!(asset_id = ?note_record.note.asset_id(), "received unknown asset ID with no available metadata");
+                }
+            }
+        }
+
+        // Commit the block to the database.
+        wallet
+            .storage
+            .record_block(
+                filtered_block.clone(),
+                transactions,
+                &mut sct_guard,
+                self.channel.clone(),
+            )
+            .await?;
+        for event in events {
+            wallet.storage.notify(filtered_block.height, event);
+        }
+        // Notify all watchers of the new height we just recorded.
+        wallet.sync_height_tx.send(filtered_block.height)?;
+
+        Ok(())
+    }
+
     pub async fn run(mut self) -> anyhow::Result<()> {
         loop {
             // Do a single sync run, recording any errors.
@@ -447,6 +755,168 @@ impl Worker {
     }
 }
 
+/// The number of blocks trial-decrypted at once, ahead of the block being committed.
+fn decrypt_ahead() -> usize {
+    std::thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Tracks the rate at which the worker processes blocks, and reports it periodically.
+struct Throughput {
+    since: Instant,
+    blocks: u64,
+}
+
+impl Throughput {
+    fn new() -> Self {
+        Self {
+            since: Instant::now(),
+            blocks: 0,
+        }
+    }
+
+    /// Records that the block at `height` has been processed.
+    fn record(&mut self, height: u64) {
+        metrics::counter!(metrics::VIEW_SYNC_BLOCKS_TOTAL).increment(1);
+        metrics::gauge!(metrics::VIEW_SYNC_HEIGHT).set(height as f64);
+        self.blocks += 1;
+
+        let elapsed = self.since.elapsed();
+        if elapsed >= THROUGHPUT_REPORT_INTERVAL {
+            let blocks_per_second = self.blocks as f64 / elapsed.as_secs_f64();
+            metrics::gauge!(metrics::VIEW_SYNC_BLOCKS_PER_SECOND).set(blocks_per_second);
+            tracing::info!(height, blocks_per_second, "sync progress");
+            *self = Self::new();
+        }
+    }
+}
+
+/// Fetches the compact blocks from `start_height` onwards, sending them to `tx` in order, and then
+/// keeps following the chain as new blocks are created.
+///
+/// The blocks up to the latest height are requested in ranges, several ranges at once, so that
+/// round trips to the node overlap while catching up.
+async fn fetch_blocks(
+    channel: Channel,
+    start_height: u64,
+    tx: &mpsc::Sender<anyhow::Result<CompactBlock>>,
+) -> anyhow::Result<()> {
+    let latest_height = latest_block_height(channel.clone()).await?;
+
+    let ranges = (start_height..=latest_height)
+        .step_by(PREFETCH_RANGE_SIZE as usize)
+        .map(|start| (start, (start + PREFETCH_RANGE_SIZE - 1).min(latest_height)));
+    let mut ranges = futures::stream::iter(ranges)
+        .map(|(start, end)| fetch_block_range(channel.clone(), start, end))
+        .buffered(PREFETCH_RANGES);
+    let mut next_height = start_height;
+    'ranges: while let Some(blocks) = ranges.next().await {
+        for block in blocks? {
+            // If the node hasn't caught up with the height it reported, a range may come back
+            // short, in which case the rest of the blocks are followed as they're created.
+            if block.height != next_height {
+                break 'ranges;
+            }
+            next_height += 1;
+            if tx.send(Ok(block)).await.is_err() {
+                return Ok(());
+            }
+        }
+    }
+
+    let mut stream = block_range_stream(
+        channel.clone(),
+        next_height,
+        0,
+        // Instruct the server to keep feeding us blocks as they're created.
+        true,
+    )
+    .await?;
+    while let Some(block) = stream.message().await? {
+        if tx.send(block.try_into()).await.is_err() {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Fetches every compact block in the inclusive range of heights.
+async fn fetch_block_range(
+    channel: Channel,
+    start_height: u64,
+    end_height: u64,
+) -> anyhow::Result<Vec<CompactBlock>> {
+    let mut stream = block_range_stream(channel, start_height, end_height, false).await?;
+    let mut blocks = Vec::new();
+    while let Some(block) = stream.message().await? {
+        blocks.push(block.try_into()?);
+    }
+    Ok(blocks)
+}
+
+/// Fetches the compact blocks from `start_height` onwards from a detection service, following new
+/// blocks as they're created.
+async fn fetch_detected_blocks(
+    detection: Detection,
+    start_height: u64,
+    tx: &mpsc::Sender<anyhow::Result<CompactBlock>>,
+) -> anyhow::Result<()> {
+    let mut client =
+        DetectionServiceClient::new(detection.channel).max_decoding_message_size(MAX_CB_SIZE_BYTES);
+    let mut stream = client
+        .filtered_compact_block_range(tonic::Request::new(FilteredCompactBlockRangeRequest {
+            detection_keys: detection.detection_keys,
+            start_height,
+            end_height: 0,
+            keep_alive: true,
+        }))
+        .await
+        .context("could not fetch blocks from the detection service")?
+        .into_inner();
+    while let Some(response) = stream.message().await? {
+        let block = response
+            .compact_block
+            .context("detection service sent an empty response")?
+            .try_into();
+        if tx.send(block).await.is_err() {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+async fn block_range_stream(
+    channel: Channel,
+    start_height: u64,
+    end_height: u64,
+    keep_alive: bool,
+) -> anyhow::Result<tonic::Streaming<CompactBlockRangeResponse>> {
+    let mut client =
+        CompactBlockQueryServiceClient::new(channel).max_decoding_message_size(MAX_CB_SIZE_BYTES);
+    let stream = client
+        .compact_block_range(tonic::Request::new(CompactBlockRangeRequest {
+            start_height,
+            end_height,
+            keep_alive,
+        }))
+        .await?
+        .into_inner();
+    Ok(stream)
+}
+
+/// Fetches the height of the latest block committed by the node.
+async fn latest_block_height(channel: Channel) -> anyhow::Result<u64> {
+    let mut client = TendermintProxyServiceClient::new(channel);
+    let sync_info = client
+        .get_status(GetStatusRequest {})
+        .await?
+        .into_inner()
+        .sync_info
+        .context("could not parse sync_info in gRPC response")?;
+    Ok(sync_info.latest_block_height)
+}
+
 // Fetches all transactions in the block.
 async fn fetch_transactions(
     channel: Channel,
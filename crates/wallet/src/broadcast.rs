@@ -0,0 +1,67 @@
+use anyhow::{Context, Result};
+use rand_core::{CryptoRng, RngCore};
+
+use penumbra_custody::CustodyClient;
+use penumbra_keys::FullViewingKey;
+use penumbra_proto::view::v1::broadcast_transaction_response::Status as BroadcastStatus;
+use penumbra_transaction::{txhash::TransactionId, Transaction};
+use penumbra_view::{PlanSequence, ViewClient};
+
+use crate::build_transaction;
+
+/// Broadcast a transaction, and wait for it to be confirmed and detected by the view service.
+pub async fn broadcast_transaction<V>(
+    view: &mut V,
+    transaction: Transaction,
+) -> Result<TransactionId>
+where
+    V: ViewClient,
+{
+    let mut rsp = view.broadcast_transaction(transaction, true).await?;
+    while let Some(rsp) = rsp.message().await? {
+        match rsp
+            .status
+            .context("empty BroadcastTransactionResponse message")?
+        {
+            BroadcastStatus::BroadcastSuccess(_) => {}
+            BroadcastStatus::Confirmed(confirmed) => {
+                return confirmed
+                    .id
+                    .context("detected transaction missing id")?
+                    .try_into();
+            }
+        }
+    }
+
+    anyhow::bail!("should have received BroadcastTransaction status or error")
+}
+
+/// Build, broadcast, and await each transaction of a sequence in turn, returning the ids of the
+/// transactions.
+///
+/// Each transaction of the sequence is only planned once the one before it has been detected by
+/// the view service, since it spends the change of that transaction.
+pub async fn build_and_broadcast_sequence<V, C, R>(
+    fvk: &FullViewingKey,
+    view: &mut V,
+    custody: &mut C,
+    mut sequence: PlanSequence<R>,
+) -> Result<Vec<TransactionId>>
+where
+    V: ViewClient,
+    C: CustodyClient,
+    R: RngCore + CryptoRng,
+{
+    let mut ids = Vec::new();
+    while let Some(plan) = sequence.next_plan(view).await? {
+        tracing::debug!(
+            index = ids.len(),
+            last = sequence.is_finished(),
+            "building transaction of sequence"
+        );
+        let transaction = build_transaction(fvk, view, custody, plan).await?;
+        ids.push(broadcast_transaction(view, transaction).await?);
+    }
+
+    Ok(ids)
+}
@@ -1,6 +1,8 @@
 #![deny(clippy::unwrap_used)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+mod broadcast;
 mod build;
+pub use broadcast::{broadcast_transaction, build_and_broadcast_sequence};
 pub use build::build_transaction;
 
 pub mod plan;